@@ -3,8 +3,8 @@
 mod network_test;
 
 use super::*;
-use crate::utils::parallel_into_collect;
-use hashbrown::HashMap;
+use crate::utils::{map_reduce, parallel_into_collect};
+use hashbrown::{HashMap, HashSet};
 use rand::prelude::SliceRandom;
 use std::cmp::Ordering;
 use std::ops::Deref;
@@ -33,6 +33,8 @@ where
     time: usize,
     /// A rebalance memory.
     rebalance_memory: usize,
+    /// Max amount of inputs trained together within one batch.
+    batch_size: usize,
 }
 
 /// GSOM network configuration.
@@ -47,6 +49,8 @@ pub struct NetworkConfig {
     pub rebalance_memory: usize,
     /// If set to true, initial nodes have error set to the value equal to growing threshold.
     pub has_initial_error: bool,
+    /// Max amount of inputs trained together within one batch.
+    pub batch_size: usize,
 }
 
 impl<I, S, F> Network<I, S, F>
@@ -61,6 +65,7 @@ where
 
         assert!(roots.iter().all(|r| r.weights().len() == dimension));
         assert!(config.distribution_factor > 0. && config.distribution_factor < 1.);
+        assert!(config.batch_size > 0);
 
         let growing_threshold = -1. * dimension as f64 * config.spread_factor.log2();
         let initial_error = if config.has_initial_error { growing_threshold } else { 0. };
@@ -74,6 +79,7 @@ where
             storage_factory,
             time: 0,
             rebalance_memory: config.rebalance_memory,
+            batch_size: config.batch_size,
         }
     }
 
@@ -85,7 +91,11 @@ where
     }
 
     /// Stores multiple inputs into the network.
-    pub fn store_batch<T: Sized + Send + Sync>(&mut self, item_data: Vec<T>, time: usize, map_func: fn(T) -> I) {
+    pub fn store_batch<T, M>(&mut self, item_data: Vec<T>, time: usize, map_func: M)
+    where
+        T: Sized + Send + Sync,
+        M: Fn(T) -> I + Send + Sync,
+    {
         self.time = time;
         self.train_batch(item_data, true, map_func);
     }
@@ -98,6 +108,28 @@ where
         self.compact(node_filter);
     }
 
+    /// Replaces network nodes with new ones which have given coordinates and weights, e.g. to restore
+    /// a previously learned topology. Already stored inputs are retrained using new nodes.
+    pub fn restore(&mut self, nodes: Vec<(Coordinate, Vec<f64>)>) -> Result<(), String> {
+        let coordinates = nodes.iter().map(|(coordinate, _)| coordinate.clone()).collect::<HashSet<_>>();
+        if coordinates.len() != nodes.len() || coordinates.len() < 4 {
+            return Err("cannot restore network: at least four nodes with unique coordinates expected".to_string());
+        }
+
+        if nodes.iter().any(|(_, weights)| weights.len() != self.dimension) {
+            return Err(format!("cannot restore network: weights dimension is expected to be {}", self.dimension));
+        }
+
+        let inputs =
+            self.nodes.iter_mut().flat_map(|(_, node)| node.write().unwrap().storage.drain(0..)).collect::<Vec<_>>();
+
+        self.nodes.drain().for_each(|(_, mut node)| Self::disconnect_node(&mut node));
+        nodes.into_iter().for_each(|(coordinate, weights)| self.insert(coordinate, weights.as_slice()));
+        inputs.into_iter().for_each(|input| self.train(input, false));
+
+        Ok(())
+    }
+
     /// Finds node by its coordinate.
     pub fn find(&self, coordinate: &Coordinate) -> Option<&NodeLink<I, S>> {
         self.nodes.get(coordinate)
@@ -135,24 +167,57 @@ where
         let bmu = self.find_bmu(&input);
         let error = bmu.read().unwrap().distance(input.weights());
 
-        self.update(&bmu, &input, error, is_new_input);
+        self.update(&bmu, input.weights(), error, if is_new_input { 1 } else { 0 });
 
         bmu.write().unwrap().storage.add(input);
     }
 
-    /// Trains network on inputs.
-    fn train_batch<T: Send + Sync>(&mut self, item_data: Vec<T>, is_new_input: bool, map_func: fn(T) -> I) {
-        let nodes_data = parallel_into_collect(item_data, |item| {
-            let input = map_func(item);
-            let bmu = self.find_bmu(&input);
-            let error = bmu.read().unwrap().distance(input.weights());
-            (bmu, error, input)
-        });
+    /// Trains network on inputs split into mini-batches. Best matching units of all batch inputs are
+    /// found in parallel using the same network state, then each unit is updated once using the mean
+    /// weights and the total error of its inputs.
+    fn train_batch<T, M>(&mut self, item_data: Vec<T>, is_new_input: bool, map_func: M)
+    where
+        T: Send + Sync,
+        M: Fn(T) -> I + Send + Sync,
+    {
+        let mut item_data = item_data.into_iter();
+
+        loop {
+            let batch = item_data.by_ref().take(self.batch_size).collect::<Vec<_>>();
+            if batch.is_empty() {
+                break;
+            }
 
-        nodes_data.into_iter().for_each(|(bmu, error, input)| {
-            self.update(&bmu, &input, error, is_new_input);
-            bmu.write().unwrap().storage.add(input);
-        });
+            let nodes = self.nodes.values().cloned().collect::<Vec<_>>();
+            let nodes_data = parallel_into_collect(batch, |item| {
+                let input = map_func(item);
+                let (bmu, error) = Self::find_bmu_parallel(nodes.as_slice(), &input);
+                (bmu, error, input)
+            });
+
+            // NOTE keep groups in order of appearance to have deterministic updates
+            let groups = nodes_data.into_iter().fold(
+                Vec::<(NodeLink<I, S>, f64, Vec<I>)>::new(),
+                |mut groups, (bmu, error, input)| {
+                    match groups.iter_mut().find(|(node, _, _)| Arc::ptr_eq(node, &bmu)) {
+                        Some((_, total_error, inputs)) => {
+                            *total_error += error;
+                            inputs.push(input);
+                        }
+                        None => groups.push((bmu, error, vec![input])),
+                    }
+                    groups
+                },
+            );
+
+            groups.into_iter().for_each(|(bmu, error, inputs)| {
+                let weights = get_mean_weights(inputs.as_slice(), self.dimension);
+                self.update(&bmu, weights.as_slice(), error, if is_new_input { inputs.len() } else { 0 });
+
+                let mut bmu = bmu.write().unwrap();
+                inputs.into_iter().for_each(|input| bmu.storage.add(input));
+            });
+        }
     }
 
     /// Finds the best matching unit within the map for the given input.
@@ -165,16 +230,34 @@ where
             .expect("no nodes")
     }
 
+    /// Finds the best matching unit and its distance to the given input searching across nodes in parallel.
+    fn find_bmu_parallel(nodes: &[NodeLink<I, S>], input: &I) -> (NodeLink<I, S>, f64) {
+        map_reduce(
+            nodes,
+            |node| Some((node.clone(), node.read().unwrap().distance(input.weights()))),
+            || None,
+            |left, right| match (left, right) {
+                (Some(left), Some(right)) => {
+                    Some(if right.1.partial_cmp(&left.1).unwrap_or(Ordering::Greater) == Ordering::Less {
+                        right
+                    } else {
+                        left
+                    })
+                }
+                (left, right) => left.or(right),
+            },
+        )
+        .expect("no nodes")
+    }
+
     /// Updates network according to the error.
-    fn update(&mut self, node: &NodeLink<I, S>, input: &I, error: f64, is_new_input: bool) {
+    fn update(&mut self, node: &NodeLink<I, S>, weights: &[f64], error: f64, hits: usize) {
         let (exceeds_ae, is_boundary) = {
             let mut node = node.write().unwrap();
             node.error += error;
 
-            // NOTE update usage statistics only for a new input
-            if is_new_input {
-                node.new_hit(self.time);
-            }
+            // NOTE update usage statistics only for new inputs
+            (0..hits).for_each(|_| node.new_hit(self.time));
 
             (node.error > self.growing_threshold, node.topology.is_boundary())
         };
@@ -222,9 +305,9 @@ where
         let mut node = node.write().unwrap();
         let learning_rate = self.learning_rate * (1. - 3.8 / (self.nodes.len() as f64));
 
-        node.adjust(input.weights(), learning_rate);
+        node.adjust(weights, learning_rate);
         (node.topology.neighbours().map(|n| n.write().unwrap())).for_each(|mut neighbor| {
-            neighbor.adjust(input.weights(), learning_rate);
+            neighbor.adjust(weights, learning_rate);
         });
     }
 
@@ -364,6 +447,17 @@ where
     }
 }
 
+/// Returns mean weights of given inputs.
+fn get_mean_weights<I: Input>(inputs: &[I], dimension: usize) -> Vec<f64> {
+    let mut weights = inputs.iter().fold(vec![0.; dimension], |mut acc, input| {
+        acc.iter_mut().zip(input.weights().iter()).for_each(|(acc, value)| *acc += value);
+        acc
+    });
+    weights.iter_mut().for_each(|value| *value /= inputs.len() as f64);
+
+    weights
+}
+
 impl<I, S, F> Drop for Network<I, S, F>
 where
     I: Input,
@@ -3,5 +3,8 @@
 mod distance;
 pub use self::distance::*;
 
+mod pca;
+pub use self::pca::*;
+
 mod statistics;
 pub use self::statistics::*;
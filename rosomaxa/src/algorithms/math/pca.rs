@@ -0,0 +1,80 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/math/pca_test.rs"]
+mod pca_test;
+
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+
+/// Estimates up to `count` principal components of given data samples using power iteration with
+/// deflation on covariance matrix. Components are returned as unit vectors in order of explained
+/// variance; components with (almost) zero variance are omitted.
+pub fn get_principal_components(samples: &[Vec<f64>], count: usize) -> Vec<Vec<f64>> {
+    const MAX_ITERATIONS: usize = 100;
+    const EPSILON: f64 = 1E-9;
+
+    let dimension = samples.first().map_or(0, |sample| sample.len());
+    if samples.len() < 2 || dimension == 0 || samples.iter().any(|sample| sample.len() != dimension) {
+        return vec![];
+    }
+
+    let mut covariance = get_covariance_matrix(samples, dimension);
+
+    (0..count.min(dimension))
+        .map_while(|_| {
+            // NOTE start from the axis with the highest remaining variance to avoid orthogonal start vector
+            let axis = (0..dimension).max_by(|&a, &b| compare_floats(covariance[a][a], covariance[b][b]))?;
+            let mut component = (0..dimension).map(|idx| if idx == axis { 1. } else { 0. }).collect::<Vec<_>>();
+            let mut eigenvalue = 0.;
+
+            for _ in 0..MAX_ITERATIONS {
+                let product = multiply(&covariance, &component);
+                let norm = product.iter().map(|value| value * value).sum::<f64>().sqrt();
+                if norm < EPSILON {
+                    return None;
+                }
+
+                let next = product.into_iter().map(|value| value / norm).collect::<Vec<_>>();
+                let change = next.iter().zip(component.iter()).map(|(a, b)| (a - b).abs()).sum::<f64>();
+
+                component = next;
+                eigenvalue = norm;
+
+                if change < EPSILON {
+                    break;
+                }
+            }
+
+            if compare_floats(eigenvalue, EPSILON) != Ordering::Greater {
+                return None;
+            }
+
+            // NOTE remove found component from covariance matrix to find the next one
+            (0..dimension).for_each(|row| {
+                (0..dimension).for_each(|col| covariance[row][col] -= eigenvalue * component[row] * component[col])
+            });
+
+            Some(component)
+        })
+        .collect()
+}
+
+fn get_covariance_matrix(samples: &[Vec<f64>], dimension: usize) -> Vec<Vec<f64>> {
+    let size = samples.len() as f64;
+    let means =
+        (0..dimension).map(|idx| samples.iter().map(|sample| sample[idx]).sum::<f64>() / size).collect::<Vec<_>>();
+
+    (0..dimension)
+        .map(|row| {
+            (0..dimension)
+                .map(|col| {
+                    samples.iter().map(|sample| (sample[row] - means[row]) * (sample[col] - means[col])).sum::<f64>()
+                        / size
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn multiply(matrix: &[Vec<f64>], vector: &[f64]) -> Vec<f64> {
+    matrix.iter().map(|row| row.iter().zip(vector.iter()).map(|(a, b)| a * b).sum()).collect()
+}
@@ -0,0 +1,114 @@
+//! Contains acceptance strategies which decide whether a worse solution can replace the current one.
+
+#[cfg(test)]
+#[path = "../../tests/unit/evolution/acceptance_test.rs"]
+mod acceptance_test;
+
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+/// Specifies an acceptance strategy which decides whether a candidate solution, which is worse
+/// than the current one, is accepted as a new current solution.
+pub trait AcceptanceStrategy: Send + Sync {
+    /// Returns true if a worse candidate should be accepted. `relative_delta` is a positive relative
+    /// difference between candidate and current solution fitness, `progress` is a termination estimate
+    /// in range `[0, 1]`.
+    fn is_accepted(&self, relative_delta: f64, progress: f64, random: &(dyn Random + Send + Sync)) -> bool;
+}
+
+/// Implements simulated annealing acceptance: a worse solution is accepted with probability
+/// `exp(-delta / T)` where temperature `T` is decreased geometrically from initial to final value.
+pub struct SimulatedAnnealing {
+    initial_temperature: f64,
+    final_temperature: f64,
+}
+
+impl SimulatedAnnealing {
+    /// Creates a new instance of `SimulatedAnnealing`.
+    pub fn new(initial_temperature: f64, final_temperature: f64) -> Result<Self, String> {
+        if initial_temperature <= 0. || final_temperature <= 0. || final_temperature > initial_temperature {
+            return Err(format!(
+                "invalid temperature schedule: initial={}, final={}",
+                initial_temperature, final_temperature
+            ));
+        }
+
+        Ok(Self { initial_temperature, final_temperature })
+    }
+
+    /// Returns temperature for given progress.
+    pub fn get_temperature(&self, progress: f64) -> f64 {
+        let progress = progress.clamp(0., 1.);
+        self.initial_temperature * (self.final_temperature / self.initial_temperature).powf(progress)
+    }
+}
+
+impl AcceptanceStrategy for SimulatedAnnealing {
+    fn is_accepted(&self, relative_delta: f64, progress: f64, random: &(dyn Random + Send + Sync)) -> bool {
+        let probability = (-relative_delta / self.get_temperature(progress)).exp();
+
+        random.uniform_real(0., 1.) < probability
+    }
+}
+
+/// Implements threshold accepting: a worse solution is accepted deterministically when its relative
+/// difference is within a threshold which is decreased linearly from initial to final value.
+pub struct ThresholdAccepting {
+    initial_threshold: f64,
+    final_threshold: f64,
+}
+
+impl ThresholdAccepting {
+    /// Creates a new instance of `ThresholdAccepting`.
+    pub fn new(initial_threshold: f64, final_threshold: f64) -> Result<Self, String> {
+        if initial_threshold < 0. || final_threshold < 0. || final_threshold > initial_threshold {
+            return Err(format!(
+                "invalid threshold schedule: initial={}, final={}",
+                initial_threshold, final_threshold
+            ));
+        }
+
+        Ok(Self { initial_threshold, final_threshold })
+    }
+
+    /// Returns threshold for given progress.
+    pub fn get_threshold(&self, progress: f64) -> f64 {
+        let progress = progress.clamp(0., 1.);
+        self.initial_threshold - (self.initial_threshold - self.final_threshold) * progress
+    }
+}
+
+impl AcceptanceStrategy for ThresholdAccepting {
+    fn is_accepted(&self, relative_delta: f64, progress: f64, _: &(dyn Random + Send + Sync)) -> bool {
+        relative_delta <= self.get_threshold(progress)
+    }
+}
+
+/// Checks whether candidate can replace current solution: better or equal candidates are always
+/// accepted, worse ones are delegated to the acceptance strategy using relative difference of the
+/// first objective (in hierarchy) where solutions are different.
+pub fn is_candidate_accepted<O, S>(
+    objective: &O,
+    acceptance: &dyn AcceptanceStrategy,
+    candidate: &S,
+    current: &S,
+    progress: f64,
+    random: &(dyn Random + Send + Sync),
+) -> bool
+where
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    if objective.total_order(candidate, current) != Ordering::Greater {
+        return true;
+    }
+
+    objective
+        .objectives()
+        .find(|objective| objective.total_order(candidate, current) != Ordering::Equal)
+        .map(|objective| {
+            let (candidate, current) = (objective.fitness(candidate), objective.fitness(current));
+            (candidate - current).abs() / current.abs().max(f64::EPSILON)
+        })
+        .map_or(false, |relative_delta| acceptance.is_accepted(relative_delta, progress, random))
+}
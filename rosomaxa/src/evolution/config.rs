@@ -2,6 +2,7 @@ use crate::evolution::*;
 use crate::hyper::*;
 use crate::termination::*;
 use std::hash::Hash;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 
 /// A configuration which controls evolution execution.
@@ -78,6 +79,8 @@ where
     pub context: Vec<Box<dyn HeuristicContextProcessing<Context = C, Objective = O, Solution = S> + Send + Sync>>,
     /// A solution post processing.
     pub solution: Vec<Box<dyn HeuristicSolutionProcessing<Solution = S> + Send + Sync>>,
+    /// A processing which is run when population selection phase is changed.
+    pub phase: Vec<Box<dyn HeuristicPhaseProcessing<Context = C, Objective = O, Solution = S> + Send + Sync>>,
 }
 
 /// Provides configurable way to build evolution configuration using fluent interface style.
@@ -92,10 +95,13 @@ where
     max_time: Option<usize>,
     min_cv: Option<(String, usize, f64, bool, K)>,
     target_proximity: Option<(Vec<f64>, f64)>,
+    interrupt: Option<Arc<AtomicBool>>,
     heuristic: Option<Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>>,
     context: Option<C>,
     termination: Option<Box<dyn Termination<Context = C, Objective = O>>>,
     strategy: Option<Box<dyn EvolutionStrategy<Context = C, Objective = O, Solution = S>>>,
+    restarts: usize,
+    restart_factory: Option<RestartFactory<C, O, S>>,
 
     heuristic_operators: Option<HeuristicOperators<C, O, S>>,
     heuristic_group: Option<HeuristicGroup<C, O, S>>,
@@ -103,6 +109,7 @@ where
     objective: Option<Arc<dyn HeuristicObjective<Solution = S>>>,
 
     telemetry: Option<Telemetry<C, O, S>>,
+    solution_callbacks: Vec<SolutionCallback<S>>,
 
     initial: InitialConfig<C, O, S>,
     processing: ProcessingConfig<C, O, S>,
@@ -121,16 +128,20 @@ where
             max_time: None,
             min_cv: None,
             target_proximity: None,
+            interrupt: None,
             heuristic: None,
             context: None,
             termination: None,
             strategy: None,
+            restarts: 1,
+            restart_factory: None,
             heuristic_operators: None,
             heuristic_group: None,
             objective: None,
             telemetry: None,
+            solution_callbacks: vec![],
             initial: InitialConfig { operators: vec![], max_size: 4, quota: 0.05, individuals: vec![] },
-            processing: ProcessingConfig { context: vec![], solution: vec![] },
+            processing: ProcessingConfig { context: vec![], solution: vec![], phase: vec![] },
         }
     }
 }
@@ -166,6 +177,13 @@ where
         self
     }
 
+    /// Sets interruption flag as termination criteria: evolution is stopped as soon as the flag
+    /// is set and the best known solution is returned. Default is None.
+    pub fn with_interrupt(mut self, interrupt: Arc<AtomicBool>) -> Self {
+        self.interrupt = Some(interrupt);
+        self
+    }
+
     /// Sets initial parameters used to construct initial population.
     pub fn with_initial(mut self, max_size: usize, quota: f64, operators: InitialOperators<C, O, S>) -> Self {
         self.initial.max_size = max_size;
@@ -209,6 +227,13 @@ where
         self
     }
 
+    /// Adds a callback which is called with the best known solution and generation statistics each
+    /// time the best known solution is improved, e.g. to stream intermediate solutions during long runs.
+    pub fn with_solution_callback(mut self, callback: SolutionCallback<S>) -> Self {
+        self.solution_callbacks.push(callback);
+        self
+    }
+
     /// Sets termination.
     pub fn with_termination(mut self, termination: Box<dyn Termination<Context = C, Objective = O>>) -> Self {
         self.termination = Some(termination);
@@ -233,6 +258,22 @@ where
         self
     }
 
+    /// Splits the search budget (e.g. max time or max generations) into given amount of independent
+    /// restarts and returns the overall best solution. Outcome of each restart is reported within
+    /// telemetry metrics. Requires restart factory to be set and cannot be combined with a custom
+    /// strategy. Default is 1 (no restarts).
+    pub fn with_restarts(mut self, restarts: usize) -> Self {
+        self.restarts = restarts;
+        self
+    }
+
+    /// Sets a factory which creates heuristic context and heuristic for each restart.
+    /// See `with_restarts`.
+    pub fn with_restart_factory(mut self, restart_factory: RestartFactory<C, O, S>) -> Self {
+        self.restart_factory = Some(restart_factory);
+        self
+    }
+
     /// Sets heuristic operators.
     pub fn with_operators(mut self, heuristic_operators: HeuristicOperators<C, O, S>) -> Self {
         self.heuristic_operators = Some(heuristic_operators);
@@ -251,7 +292,7 @@ where
         let telemetry = Telemetry::new(TelemetryMode::None);
         let telemetry = self.telemetry.as_ref().unwrap_or(&telemetry);
 
-        let terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>> =
+        let mut terminations: Vec<Box<dyn Termination<Context = C, Objective = O> + Send + Sync>> =
             match (self.max_generations, self.max_time, &self.min_cv, &self.target_proximity) {
                 (None, None, None, None) => {
                     telemetry.log("configured to use default max-generations (3000) and max-time (300secs)");
@@ -308,6 +349,11 @@ where
                 }
             };
 
+        if let Some(interrupt) = self.interrupt.clone() {
+            telemetry.log("configured to use interruption");
+            terminations.push(Box::new(Interruption::new(interrupt)));
+        }
+
         Ok(Box::new(CompositeTermination::new(terminations)))
     }
 
@@ -316,7 +362,8 @@ where
         let termination = self.get_termination()?;
 
         let context = self.context.ok_or_else(|| "missing heuristic context".to_string())?;
-        let telemetry = self.telemetry.unwrap_or_else(|| Telemetry::new(TelemetryMode::None));
+        let mut telemetry = self.telemetry.unwrap_or_else(|| Telemetry::new(TelemetryMode::None));
+        self.solution_callbacks.into_iter().for_each(|callback| telemetry.add_solution_callback(callback));
 
         Ok(EvolutionConfig {
             initial: self.initial,
@@ -336,11 +383,21 @@ where
                 ))
             },
             context,
-            strategy: if let Some(strategy) = self.strategy {
-                telemetry.log("configured to use custom strategy");
-                strategy
-            } else {
-                Box::new(RunSimple::new(1))
+            strategy: match (self.strategy, self.restarts) {
+                (Some(_), restarts) if restarts > 1 => {
+                    return Err("restarts cannot be used with custom strategy".to_string());
+                }
+                (Some(strategy), _) => {
+                    telemetry.log("configured to use custom strategy");
+                    strategy
+                }
+                (None, restarts) if restarts > 1 => {
+                    telemetry.log(format!("configured to use restarts: {}", restarts).as_str());
+                    let restart_factory =
+                        self.restart_factory.ok_or_else(|| "missing restart factory for restarts".to_string())?;
+                    Box::new(RunRestarts::new(1, restarts, restart_factory))
+                }
+                (None, _) => Box::new(RunSimple::new(1)),
             },
             termination,
             processing: self.processing,
@@ -0,0 +1,217 @@
+#[cfg(test)]
+#[path = "../../tests/unit/evolution/islands_test.rs"]
+mod islands_test;
+
+use crate::evolution::simulator::{on_generation, should_add_solution, should_stop};
+use crate::evolution::{EvolutionResult, EvolutionStrategy, Telemetry};
+use crate::prelude::*;
+use crate::utils::{parallel_foreach_mut, Timer};
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+/// A type alias for hyper heuristic used by a single island.
+pub type IslandHeuristic<C, O, S> = Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>;
+
+/// A factory function which creates heuristic context and hyper heuristic for an additional island
+/// from the context of the first one, so all islands can share the same (pre-processed) problem and
+/// environment. Each island has to own its population, so returned context should not share it with
+/// others. Telemetry of additional islands is not reported.
+pub type IslandFactory<C, O, S> = Box<dyn Fn(&C) -> (C, IslandHeuristic<C, O, S>) + Send + Sync>;
+
+/// An evolution algorithm which runs several independent populations (islands) in parallel and
+/// periodically migrates the best individual of each island to the next one (ring topology).
+/// This allows to utilize many cores better than parallelism within a single generation as
+/// islands evolve without synchronization between migrations.
+pub struct RunIslands<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    desired_solutions_amount: usize,
+    island_count: usize,
+    migration_interval: usize,
+    island_factory: IslandFactory<C, O, S>,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+impl<C, O, S> RunIslands<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `RunIslands`. The first island uses context and heuristic passed to
+    /// the strategy, the rest `island_count - 1` islands are created by `island_factory` and
+    /// seeded with the individuals of the first one. Migration happens every `migration_interval`
+    /// generations.
+    pub fn new(
+        desired_solutions_amount: usize,
+        island_count: usize,
+        migration_interval: usize,
+        island_factory: IslandFactory<C, O, S>,
+    ) -> Self {
+        Self {
+            desired_solutions_amount,
+            island_count: island_count.max(1),
+            migration_interval: migration_interval.max(1),
+            island_factory,
+            _marker: (Default::default(), Default::default(), Default::default()),
+        }
+    }
+}
+
+impl<C, O, S> EvolutionStrategy for RunIslands<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn run(
+        &self,
+        heuristic_ctx: Self::Context,
+        heuristic: Box<
+            dyn HyperHeuristic<Context = Self::Context, Objective = Self::Objective, Solution = Self::Solution>,
+        >,
+        termination: Box<dyn Termination<Context = Self::Context, Objective = Self::Objective>>,
+        telemetry: Telemetry<Self::Context, Self::Objective, Self::Solution>,
+    ) -> EvolutionResult<Self::Solution> {
+        let seeds =
+            heuristic_ctx.population().ranked().map(|(individual, _)| individual.deep_copy()).collect::<Vec<_>>();
+
+        let others = (1..self.island_count)
+            .map(|_| {
+                let (mut context, heuristic) = (self.island_factory)(&heuristic_ctx);
+                context.population_mut().add_all(seeds.iter().map(|seed| seed.deep_copy()).collect());
+
+                Island::new(context, heuristic, Telemetry::new_with_sinks(vec![]))
+            })
+            .collect::<Vec<_>>();
+
+        // NOTE island is wrapped into mutex as hyper heuristic is not required to be Sync
+        let mut islands = std::iter::once(Island::new(heuristic_ctx, heuristic, telemetry))
+            .chain(others)
+            .map(Mutex::new)
+            .collect::<Vec<_>>();
+
+        let termination = Mutex::new(termination);
+        let migration_interval = self.migration_interval;
+
+        loop {
+            parallel_foreach_mut(islands.as_mut_slice(), |island| {
+                island.get_mut().unwrap().evolve(&termination, migration_interval)
+            });
+
+            if islands.iter_mut().any(|island| island.get_mut().unwrap().is_terminated) {
+                break;
+            }
+
+            migrate(islands.as_mut_slice());
+        }
+
+        let mut islands = islands.into_iter().map(|island| island.into_inner().unwrap());
+        let mut main = islands.next().expect("at least one island");
+
+        let others_best = islands.filter_map(|island| island.get_best()).collect::<Vec<_>>();
+        main.context.population_mut().add_all(others_best);
+
+        main.telemetry.on_result(&main.context);
+        main.telemetry.on_checkpoint(&main.context, main.heuristic.as_ref(), true);
+
+        let solutions = main
+            .context
+            .population()
+            .ranked()
+            .map(|(solution, _)| solution.deep_copy())
+            .take(self.desired_solutions_amount)
+            .collect();
+
+        Ok((solutions, main.telemetry.take_metrics()))
+    }
+}
+
+struct Island<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    context: C,
+    heuristic: IslandHeuristic<C, O, S>,
+    telemetry: Telemetry<C, O, S>,
+    is_terminated: bool,
+}
+
+impl<C, O, S> Island<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    fn new(context: C, heuristic: IslandHeuristic<C, O, S>, telemetry: Telemetry<C, O, S>) -> Self {
+        Self { context, heuristic, telemetry, is_terminated: false }
+    }
+
+    /// Runs evolution for given amount of generations or till termination criteria is met.
+    fn evolve(&mut self, termination: &Mutex<Box<dyn Termination<Context = C, Objective = O>>>, generations: usize) {
+        for _ in 0..generations {
+            if should_stop(&mut self.context, termination.lock().unwrap().as_ref()) {
+                self.is_terminated = true;
+                break;
+            }
+
+            let generation_time = Timer::start();
+
+            let parents = self.context.population().select().collect();
+            let offspring = self.heuristic.search(&self.context, parents);
+            self.telemetry.on_search(&self.context, self.heuristic.as_ref());
+
+            let is_improved = if should_add_solution(&self.context.environment().quota, self.context.population()) {
+                self.context.population_mut().add_all(offspring)
+            } else {
+                false
+            };
+
+            on_generation(
+                &mut self.context,
+                &mut self.telemetry,
+                termination.lock().unwrap().as_ref(),
+                generation_time,
+                is_improved,
+            );
+            self.telemetry.on_operator_weights(&self.context, self.heuristic.as_ref());
+            self.telemetry.on_checkpoint(&self.context, self.heuristic.as_ref(), false);
+        }
+    }
+
+    fn get_best(&self) -> Option<S> {
+        self.context.population().ranked().next().map(|(individual, _)| individual.deep_copy())
+    }
+}
+
+/// Sends a copy of the best individual of each island to the next island in the ring.
+fn migrate<C, O, S>(islands: &mut [Mutex<Island<C, O, S>>])
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    if islands.len() < 2 {
+        return;
+    }
+
+    let migrants = islands.iter_mut().map(|island| island.get_mut().unwrap().get_best()).collect::<Vec<_>>();
+
+    migrants.into_iter().enumerate().for_each(|(idx, migrant)| {
+        if let Some(migrant) = migrant {
+            let island = islands[(idx + 1) % islands.len()].get_mut().unwrap();
+            if should_add_solution(&island.context.environment().quota, island.context.population()) {
+                island.context.population_mut().add(migrant);
+            }
+        }
+    });
+}
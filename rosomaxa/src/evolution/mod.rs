@@ -8,14 +8,26 @@ pub use self::config::*;
 mod simulator;
 pub use self::simulator::*;
 
+mod islands;
+pub use self::islands::*;
+
+mod restarts;
+pub use self::restarts::*;
+
 pub mod telemetry;
 pub use self::telemetry::*;
 
+pub mod sinks;
+pub use self::sinks::*;
+
+pub mod acceptance;
+pub use self::acceptance::*;
+
 /// Defines evolution result type.
 pub type EvolutionResult<S> = Result<(Vec<S>, Option<TelemetryMetrics>), String>;
 
 /// An evolution algorithm strategy.
-pub trait EvolutionStrategy {
+pub trait EvolutionStrategy: Send {
     /// A heuristic context type.
     type Context: HeuristicContext<Objective = Self::Objective, Solution = Self::Solution>;
     /// A heuristic objective type.
@@ -48,6 +60,20 @@ pub trait HeuristicContextProcessing {
     fn pre_process(&self, context: Self::Context) -> Self::Context;
 }
 
+/// Provides the way to modify context when population changes its selection phase, e.g. to enable
+/// more expensive search operators only in exploitation phase.
+pub trait HeuristicPhaseProcessing {
+    /// A heuristic context type.
+    type Context: HeuristicContext<Objective = Self::Objective, Solution = Self::Solution>;
+    /// A heuristic objective type.
+    type Objective: HeuristicObjective<Solution = Self::Solution>;
+    /// A solution type.
+    type Solution: HeuristicSolution;
+
+    /// Processes a context after population has moved from `previous` to `current` selection phase.
+    fn on_phase_change(&self, context: &mut Self::Context, previous: SelectionPhase, current: SelectionPhase);
+}
+
 /// Provides the way to modify solution before returning it.
 pub trait HeuristicSolutionProcessing {
     /// A solution type.
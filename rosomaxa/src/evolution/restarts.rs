@@ -0,0 +1,180 @@
+#[cfg(test)]
+#[path = "../../tests/unit/evolution/restarts_test.rs"]
+mod restarts_test;
+
+use crate::evolution::simulator::{on_generation, should_add_solution, should_stop};
+use crate::evolution::{EvolutionResult, EvolutionStrategy, IslandHeuristic, Telemetry, TelemetryRestart};
+use crate::prelude::*;
+use crate::utils::Timer;
+use std::marker::PhantomData;
+
+/// A factory function which creates heuristic context and hyper heuristic for a restart with given
+/// sequence number. A context of the previous restart is passed, so its (possibly preprocessed)
+/// problem definition can be reused. Returned context has to own a new population with at least
+/// one initial individual and is expected to use a differently seeded random generator.
+pub type RestartFactory<C, O, S> = Box<dyn Fn(&C, usize) -> (C, IslandHeuristic<C, O, S>) + Send + Sync>;
+
+/// An evolution algorithm which splits the search budget into several independent restarts run one
+/// after another and returns the best individuals found by all of them. This is a simple robustness
+/// mechanism against unlucky runs which are stuck in a poor region of the search space.
+pub struct RunRestarts<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    desired_solutions_amount: usize,
+    restart_count: usize,
+    restart_factory: RestartFactory<C, O, S>,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+impl<C, O, S> RunRestarts<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `RunRestarts`. The first restart uses context and heuristic passed to
+    /// the strategy, the rest `restart_count - 1` restarts are created by `restart_factory`. Each
+    /// restart gets an equal share of the termination estimate, e.g. of max time or max generations.
+    /// When termination is reached before the whole budget is used, e.g. due to search stagnation,
+    /// only the current restart is stopped. Exhausted budget, quota or interruption stop all of them.
+    pub fn new(
+        desired_solutions_amount: usize,
+        restart_count: usize,
+        restart_factory: RestartFactory<C, O, S>,
+    ) -> Self {
+        Self {
+            desired_solutions_amount,
+            restart_count: restart_count.max(1),
+            restart_factory,
+            _marker: (Default::default(), Default::default(), Default::default()),
+        }
+    }
+}
+
+impl<C, O, S> EvolutionStrategy for RunRestarts<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn run(
+        &self,
+        heuristic_ctx: Self::Context,
+        heuristic: Box<
+            dyn HyperHeuristic<Context = Self::Context, Objective = Self::Objective, Solution = Self::Solution>,
+        >,
+        termination: Box<dyn Termination<Context = Self::Context, Objective = Self::Objective>>,
+        telemetry: Telemetry<Self::Context, Self::Objective, Self::Solution>,
+    ) -> EvolutionResult<Self::Solution> {
+        let mut heuristic_ctx = heuristic_ctx;
+        let mut heuristic = heuristic;
+        let mut telemetry = telemetry;
+
+        let mut bests = Vec::<S>::new();
+        let mut restarts = Vec::<TelemetryRestart>::with_capacity(self.restart_count);
+
+        for number in 0..self.restart_count {
+            if number > 0 {
+                bests.extend(get_best_individuals(&heuristic_ctx, self.desired_solutions_amount));
+
+                let (context, restart_heuristic) = (self.restart_factory)(&heuristic_ctx, number);
+                heuristic_ctx = context;
+                heuristic = restart_heuristic;
+            }
+
+            let restart_time = Timer::start();
+            let mut generations = 0;
+            let estimate_limit = (number + 1) as f64 / self.restart_count as f64;
+
+            let is_terminated = loop {
+                if should_stop(&mut heuristic_ctx, termination.as_ref()) {
+                    let is_quota_reached =
+                        heuristic_ctx.environment().quota.as_ref().map_or(false, |quota| quota.is_reached());
+
+                    // NOTE termination within restart's budget share is scoped to the current restart
+                    break is_quota_reached || termination.estimate(&heuristic_ctx) >= 1.;
+                }
+
+                if termination.estimate(&heuristic_ctx) >= estimate_limit {
+                    break false;
+                }
+
+                let generation_time = Timer::start();
+
+                let parents = heuristic_ctx.population().select().collect();
+                let offspring = heuristic.search(&heuristic_ctx, parents);
+                telemetry.on_search(&heuristic_ctx, heuristic.as_ref());
+
+                let is_improved = if should_add_solution(&heuristic_ctx.environment().quota, heuristic_ctx.population())
+                {
+                    heuristic_ctx.population_mut().add_all(offspring)
+                } else {
+                    false
+                };
+
+                on_generation(&mut heuristic_ctx, &mut telemetry, termination.as_ref(), generation_time, is_improved);
+                telemetry.on_operator_weights(&heuristic_ctx, heuristic.as_ref());
+                telemetry.on_checkpoint(&heuristic_ctx, heuristic.as_ref(), false);
+
+                generations += 1;
+            };
+
+            let restart = TelemetryRestart {
+                number,
+                generations,
+                duration: restart_time.elapsed_secs_as_f64(),
+                fitness: heuristic_ctx
+                    .population()
+                    .ranked()
+                    .next()
+                    .map(|(best, _)| best.get_fitness().collect())
+                    .unwrap_or_default(),
+            };
+
+            telemetry.log(
+                format!(
+                    "restart {} finished: generations: {}, duration: {:.3}s, best fitness: {:?}",
+                    restart.number, restart.generations, restart.duration, restart.fitness
+                )
+                .as_str(),
+            );
+
+            restarts.push(restart);
+
+            if is_terminated {
+                break;
+            }
+        }
+
+        // NOTE best individuals of previous restarts are ranked together with the last one's population
+        heuristic_ctx.population_mut().add_all(bests);
+
+        telemetry.on_result(&heuristic_ctx);
+        telemetry.on_checkpoint(&heuristic_ctx, heuristic.as_ref(), true);
+
+        let solutions = get_best_individuals(&heuristic_ctx, self.desired_solutions_amount);
+
+        let metrics = telemetry.take_metrics().map(|mut metrics| {
+            metrics.restarts = restarts;
+            metrics
+        });
+
+        Ok((solutions, metrics))
+    }
+}
+
+fn get_best_individuals<C, O, S>(heuristic_ctx: &C, amount: usize) -> Vec<S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    heuristic_ctx.population().ranked().map(|(solution, _)| solution.deep_copy()).take(amount).collect()
+}
@@ -1,4 +1,4 @@
-use crate::evolution::{EvolutionResult, EvolutionStrategy, Telemetry};
+use crate::evolution::{is_candidate_accepted, AcceptanceStrategy, EvolutionResult, EvolutionStrategy, Telemetry};
 use crate::prelude::*;
 use crate::utils::{Quota, Timer};
 use std::marker::PhantomData;
@@ -50,6 +50,7 @@ where
             });
 
         let hooks = config.processing;
+        hooks.phase.into_iter().for_each(|processing| config.telemetry.add_phase_processing(processing));
         let random = config.context.environment().random.clone();
 
         let heuristic_ctx = config.context;
@@ -121,6 +122,7 @@ where
     S: HeuristicSolution,
 {
     desired_solutions_amount: usize,
+    acceptance: Option<Box<dyn AcceptanceStrategy>>,
     _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
 }
 
@@ -132,7 +134,18 @@ where
 {
     /// Creates a new instance of `RunSimple`.
     pub fn new(desired_solutions_amount: usize) -> Self {
-        Self { desired_solutions_amount, _marker: (Default::default(), Default::default(), Default::default()) }
+        Self {
+            desired_solutions_amount,
+            acceptance: None,
+            _marker: (Default::default(), Default::default(), Default::default()),
+        }
+    }
+
+    /// Creates a new instance of `RunSimple` which keeps a current solution and uses acceptance
+    /// strategy to decide whether offspring replaces it. This way, worse solutions can be explored
+    /// early in the search, while the population still keeps the best ones found.
+    pub fn new_with_acceptance(desired_solutions_amount: usize, acceptance: Box<dyn AcceptanceStrategy>) -> Self {
+        Self { acceptance: Some(acceptance), ..Self::new(desired_solutions_amount) }
     }
 }
 
@@ -158,13 +171,26 @@ where
         let mut heuristic_ctx = heuristic_ctx;
         let mut heuristic = heuristic;
         let mut telemetry = telemetry;
+        let mut current: Option<S> = None;
 
         while !should_stop(&mut heuristic_ctx, termination.as_ref()) {
             let generation_time = Timer::start();
 
-            let parents = heuristic_ctx.population().select().collect();
+            let mut parents: Vec<_> = heuristic_ctx.population().select().collect();
+
+            if let Some(current) = current.as_ref() {
+                // NOTE current solution is explored instead of the first selected one
+                if let Some(first) = parents.first_mut() {
+                    *first = current;
+                }
+            }
 
             let offspring = heuristic.search(&heuristic_ctx, parents);
+            telemetry.on_search(&heuristic_ctx, heuristic.as_ref());
+
+            if let Some(acceptance) = self.acceptance.as_ref() {
+                current = accept_offspring(&heuristic_ctx, acceptance.as_ref(), current, offspring.as_slice());
+            }
 
             let is_improved = if should_add_solution(&heuristic_ctx.environment().quota, heuristic_ctx.population()) {
                 heuristic_ctx.population_mut().add_all(offspring)
@@ -173,9 +199,12 @@ where
             };
 
             on_generation(&mut heuristic_ctx, &mut telemetry, termination.as_ref(), generation_time, is_improved);
+            telemetry.on_operator_weights(&heuristic_ctx, heuristic.as_ref());
+            telemetry.on_checkpoint(&heuristic_ctx, heuristic.as_ref(), false);
         }
 
         telemetry.on_result(&heuristic_ctx);
+        telemetry.on_checkpoint(&heuristic_ctx, heuristic.as_ref(), true);
 
         let solutions = heuristic_ctx
             .population()
@@ -199,7 +228,35 @@ where
     }
 }
 
-fn should_stop<C, O, S>(heuristic_ctx: &mut C, termination: &(dyn Termination<Context = C, Objective = O>)) -> bool
+fn accept_offspring<C, O, S>(
+    heuristic_ctx: &C,
+    acceptance: &dyn AcceptanceStrategy,
+    current: Option<S>,
+    offspring: &[S],
+) -> Option<S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    let current = current.or_else(|| heuristic_ctx.population().ranked().next().map(|(best, _)| best.deep_copy()));
+    let progress = heuristic_ctx.statistics().termination_estimate;
+    let random = heuristic_ctx.environment().random.as_ref();
+
+    offspring.iter().fold(current, |current, candidate| match current {
+        Some(current)
+            if !is_candidate_accepted(heuristic_ctx.objective(), acceptance, candidate, &current, progress, random) =>
+        {
+            Some(current)
+        }
+        _ => Some(candidate.deep_copy()),
+    })
+}
+
+pub(crate) fn should_stop<C, O, S>(
+    heuristic_ctx: &mut C,
+    termination: &(dyn Termination<Context = C, Objective = O>),
+) -> bool
 where
     C: HeuristicContext<Objective = O, Solution = S>,
     O: HeuristicObjective<Solution = S>,
@@ -211,7 +268,7 @@ where
     is_terminated || is_quota_reached
 }
 
-fn should_add_solution<O, S>(
+pub(crate) fn should_add_solution<O, S>(
     quota: &Option<Arc<dyn Quota + Send + Sync>>,
     population: &(dyn HeuristicPopulation<Objective = O, Individual = S>),
 ) -> bool
@@ -226,7 +283,7 @@ where
     is_population_empty || !is_quota_reached
 }
 
-fn on_generation<C, O, S>(
+pub(crate) fn on_generation<C, O, S>(
     heuristic_ctx: &mut C,
     telemetry: &mut Telemetry<C, O, S>,
     termination: &(dyn Termination<Context = C, Objective = O>),
@@ -241,6 +298,12 @@ fn on_generation<C, O, S>(
 
     let statistics = telemetry.on_generation(heuristic_ctx, termination_estimate, generation_time, is_improved);
 
+    let previous_phase = heuristic_ctx.population().selection_phase();
     heuristic_ctx.population_mut().on_generation(&statistics);
     *heuristic_ctx.statistics_mut() = statistics;
+
+    let current_phase = heuristic_ctx.population().selection_phase();
+    if previous_phase != current_phase {
+        telemetry.on_phase_change(heuristic_ctx, previous_phase, current_phase);
+    }
 }
@@ -0,0 +1,808 @@
+//! Contains default telemetry sinks: logging, metrics collection, writing into file, streaming
+//! JSON lines, exporting progress metrics and callback.
+
+#[cfg(test)]
+#[path = "../../tests/unit/evolution/sinks_test.rs"]
+mod sinks_test;
+
+use super::telemetry::*;
+use crate::prelude::*;
+use crate::utils::Timer;
+use std::fmt::Write as FmtWrite;
+use std::io::Write;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+/// A telemetry sink which receives events about algorithm execution. Implement it to integrate
+/// the solver with a custom monitoring stack. All methods have an empty default implementation,
+/// so only events of interest should be handled.
+pub trait TelemetrySink: Send + Sync {
+    /// A heuristic context type.
+    type Context: HeuristicContext<Objective = Self::Objective, Solution = Self::Solution>;
+    /// A heuristic objective type.
+    type Objective: HeuristicObjective<Solution = Self::Solution>;
+    /// A heuristic solution type.
+    type Solution: HeuristicSolution;
+
+    /// Called when initial solution is created. `time` specifies time since evolution started.
+    fn on_initial(
+        &mut self,
+        _solution: &Self::Solution,
+        _item_idx: usize,
+        _total_items: usize,
+        _item_time: &Timer,
+        _time: &Timer,
+    ) {
+    }
+
+    /// Called after heuristic search is performed with names of applied operators. It is called
+    /// before `on_generation` of the same generation and only if heuristic tracks operators.
+    fn on_search(&mut self, _heuristic_ctx: &Self::Context, _operators: &[String]) {}
+
+    /// Called on each generation with its statistics.
+    fn on_generation(
+        &mut self,
+        _heuristic_ctx: &Self::Context,
+        _statistics: &HeuristicStatistics,
+        _generation_time: &Timer,
+        _is_improved: bool,
+    ) {
+    }
+
+    /// Called when population selection phase is changed.
+    fn on_phase_change(&mut self, _previous: SelectionPhase, _current: SelectionPhase, _time: &Timer) {}
+
+    /// Called when heuristic reports weights of its operators.
+    fn on_operator_weights(&mut self, _heuristic_ctx: &Self::Context, _weights: &[(String, f64)], _time: &Timer) {}
+
+    /// Called when evolution is finished.
+    fn on_result(&mut self, _heuristic_ctx: &Self::Context, _time: &Timer) {}
+
+    /// Called with an informational message.
+    fn on_log(&self, _message: &str) {}
+
+    /// Returns metrics collected by the sink, if any.
+    fn take_metrics(&mut self) -> Option<TelemetryMetrics> {
+        None
+    }
+}
+
+/// A boxed telemetry sink type alias.
+pub type BoxedTelemetrySink<C, O, S> = Box<dyn TelemetrySink<Context = C, Objective = O, Solution = S>>;
+
+/// A telemetry sink which writes information about algorithm execution using logger.
+pub struct LogSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    logger: InfoLogger,
+    log_best: usize,
+    log_population: usize,
+    dump_population: bool,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+impl<C, O, S> LogSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `LogSink`. `log_best` and `log_population` specify how often best
+    /// individual and the whole population are logged, `dump_population` specifies whether
+    /// population internal state should be logged too.
+    pub fn new(logger: InfoLogger, log_best: usize, log_population: usize, dump_population: bool) -> Self {
+        Self { logger, log_best, log_population, dump_population, _marker: Default::default() }
+    }
+
+    fn log(&self, message: &str) {
+        self.logger.deref()(message)
+    }
+
+    fn log_individual(&self, metrics: &TelemetryIndividual, gen_info: Option<(usize, &Timer, &Timer)>) {
+        self.log(
+            format!(
+                "{} rank: {}, fitness: ({}), improvement: {:.3}%",
+                gen_info.map_or("\t".to_string(), |(gen, gen_time, time)| format!(
+                    "[{}s] generation {} took {}ms,",
+                    time.elapsed_secs(),
+                    gen,
+                    gen_time.elapsed_millis()
+                )),
+                metrics.rank,
+                format_fitness(metrics.fitness.iter().cloned()),
+                metrics.improvement,
+            )
+            .as_str(),
+        );
+    }
+
+    fn log_population(&self, heuristic_ctx: &C, improvement_ratios: (f64, f64), time: &Timer, should_dump: bool) {
+        let generation = heuristic_ctx.statistics().generation;
+
+        self.log(
+            format!(
+                "[{}s] population state (phase: {}, speed: {:.2} gen/sec, improvement ratio: {:.3}:{:.3}):",
+                time.elapsed_secs(),
+                get_phase_name(heuristic_ctx.population().selection_phase()),
+                generation as f64 / time.elapsed_secs_as_f64(),
+                improvement_ratios.0,
+                improvement_ratios.1,
+            )
+            .as_str(),
+        );
+
+        heuristic_ctx.population().ranked().for_each(|(solution, rank)| {
+            self.log_individual(&get_individual_metrics(heuristic_ctx, solution, rank), None)
+        });
+
+        if should_dump {
+            self.log(&format!("\t{}", get_population_state(heuristic_ctx)));
+        }
+    }
+}
+
+impl<C, O, S> TelemetrySink for LogSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn on_initial(&mut self, solution: &S, item_idx: usize, total_items: usize, item_time: &Timer, time: &Timer) {
+        self.log(
+            format!(
+                "[{}s] created {} of {} initial solutions in {}ms",
+                time.elapsed_secs(),
+                item_idx + 1,
+                total_items,
+                item_time.elapsed_millis(),
+            )
+            .as_str(),
+        );
+        self.log(format!("\tfitness: ({})", format_fitness(solution.get_fitness())).as_str());
+    }
+
+    fn on_generation(&mut self, heuristic_ctx: &C, statistics: &HeuristicStatistics, generation_time: &Timer, _: bool) {
+        let generation = statistics.generation;
+
+        if let Some((best_individual, rank)) = heuristic_ctx.population().ranked().next() {
+            if generation % self.log_best == 0 {
+                self.log_individual(
+                    &get_individual_metrics(heuristic_ctx, best_individual, rank),
+                    Some((generation, generation_time, &statistics.time)),
+                )
+            }
+
+            if generation % self.log_population == 0 {
+                let improvement_ratios = (statistics.improvement_all_ratio, statistics.improvement_1000_ratio);
+                self.log_population(heuristic_ctx, improvement_ratios, &statistics.time, self.dump_population);
+            }
+        } else {
+            self.log("no progress yet");
+        }
+    }
+
+    fn on_phase_change(&mut self, previous: SelectionPhase, current: SelectionPhase, time: &Timer) {
+        self.log(
+            format!(
+                "[{}s] selection phase changed: {} -> {}",
+                time.elapsed_secs(),
+                get_phase_name(previous),
+                get_phase_name(current)
+            )
+            .as_str(),
+        );
+    }
+
+    fn on_operator_weights(&mut self, heuristic_ctx: &C, weights: &[(String, f64)], time: &Timer) {
+        if heuristic_ctx.statistics().generation % self.log_population == 0 {
+            let weights = weights.iter().map(|(name, weight)| format!("{}={:.3}", name, weight)).collect::<Vec<_>>();
+            self.log(format!("[{}s] operator weights: {}", time.elapsed_secs(), weights.join(", ")).as_str());
+        }
+    }
+
+    fn on_result(&mut self, heuristic_ctx: &C, time: &Timer) {
+        let statistics = heuristic_ctx.statistics();
+        let improvement_ratios = (statistics.improvement_all_ratio, statistics.improvement_1000_ratio);
+        self.log_population(heuristic_ctx, improvement_ratios, time, false);
+
+        let generations = statistics.generation;
+        let speed = generations as f64 / time.elapsed_secs_as_f64();
+
+        self.log(
+            format!("[{}s] total generations: {}, speed: {:.2} gen/sec", time.elapsed_secs(), generations, speed)
+                .as_str(),
+        );
+    }
+
+    fn on_log(&self, message: &str) {
+        self.log(message)
+    }
+}
+
+/// A telemetry sink which collects metrics about algorithm execution.
+pub struct MetricsSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    metrics: Option<TelemetryMetrics>,
+    track_population: usize,
+    snapshot_population: Option<usize>,
+    is_last_improved: bool,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+impl<C, O, S> MetricsSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `MetricsSink`. `track_population` specifies how often population
+    /// is tracked and `snapshot_population` specifies how often snapshot of the whole population
+    /// fitness is taken.
+    pub fn new(track_population: usize, snapshot_population: Option<usize>) -> Self {
+        Self {
+            metrics: Some(TelemetryMetrics {
+                duration: 0,
+                generations: 0,
+                speed: 0.0,
+                evolution: vec![],
+                tiers: vec![],
+                snapshots: vec![],
+                operator_weights: vec![],
+                restarts: vec![],
+            }),
+            track_population,
+            snapshot_population,
+            is_last_improved: false,
+            _marker: Default::default(),
+        }
+    }
+
+    fn track_population(&mut self, heuristic_ctx: &C, improvement_ratios: (f64, f64), time: &Timer) {
+        let individuals = heuristic_ctx
+            .population()
+            .ranked()
+            .map(|(solution, rank)| get_individual_metrics(heuristic_ctx, solution, rank))
+            .collect::<Vec<_>>();
+        let generation = TelemetryGeneration {
+            number: heuristic_ctx.statistics().generation,
+            timestamp: time.elapsed_secs_as_f64(),
+            i_all_ratio: improvement_ratios.0,
+            i_1000_ratio: improvement_ratios.1,
+            is_improvement: self.is_last_improved,
+            population: TelemetryPopulation { individuals },
+        };
+
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.evolution.push(generation);
+        }
+    }
+}
+
+impl<C, O, S> TelemetrySink for MetricsSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn on_generation(&mut self, heuristic_ctx: &C, statistics: &HeuristicStatistics, _: &Timer, is_improved: bool) {
+        let generation = statistics.generation;
+        let timestamp = statistics.time.elapsed_secs_as_f64();
+
+        self.is_last_improved = is_improved;
+
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.generations = generation;
+        }
+
+        if let Some((best_individual, _)) = heuristic_ctx.population().ranked().next() {
+            let fitness = heuristic_ctx.objective().get_tier_fitness(best_individual);
+            if let Some(metrics) = self.metrics.as_mut() {
+                if metrics.tiers.last().map_or(true, |last| last.fitness != fitness) {
+                    metrics.tiers.push(TelemetryTiers { number: generation, timestamp, fitness });
+                }
+            }
+
+            if generation % self.track_population == 0 {
+                let improvement_ratios = (statistics.improvement_all_ratio, statistics.improvement_1000_ratio);
+                self.track_population(heuristic_ctx, improvement_ratios, &statistics.time);
+            }
+
+            if self.snapshot_population.map_or(false, |interval| generation % interval.max(1) == 0) {
+                let fitness = heuristic_ctx.population().get_fitness_snapshot();
+                if let Some(metrics) = self.metrics.as_mut() {
+                    metrics.snapshots.push(TelemetrySnapshot { number: generation, timestamp, fitness });
+                }
+            }
+        }
+    }
+
+    fn on_operator_weights(&mut self, heuristic_ctx: &C, weights: &[(String, f64)], time: &Timer) {
+        if let Some(metrics) = self.metrics.as_mut() {
+            if metrics.operator_weights.last().map_or(true, |last| last.weights != weights) {
+                metrics.operator_weights.push(TelemetryOperatorWeights {
+                    number: heuristic_ctx.statistics().generation,
+                    timestamp: time.elapsed_secs_as_f64(),
+                    weights: weights.to_vec(),
+                });
+            }
+        }
+    }
+
+    fn on_result(&mut self, heuristic_ctx: &C, time: &Timer) {
+        let statistics = heuristic_ctx.statistics();
+        let generations = statistics.generation;
+
+        if generations % self.track_population != 0 {
+            let improvement_ratios = (statistics.improvement_all_ratio, statistics.improvement_1000_ratio);
+            self.track_population(heuristic_ctx, improvement_ratios, time);
+        }
+
+        if let Some(metrics) = self.metrics.as_mut() {
+            metrics.duration = time.elapsed_secs() as usize;
+            metrics.speed = generations as f64 / time.elapsed_secs_as_f64();
+        }
+    }
+
+    fn take_metrics(&mut self) -> Option<TelemetryMetrics> {
+        self.metrics.take()
+    }
+}
+
+/// A telemetry sink which writes the same information as `LogSink` into a file or any other writer,
+/// one message per line.
+pub struct FileSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    inner: LogSink<C, O, S>,
+}
+
+impl<C, O, S> FileSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `FileSink`. See `LogSink` for parameters description.
+    pub fn new(writer: Box<dyn Write + Send>, log_best: usize, log_population: usize, dump_population: bool) -> Self {
+        let writer = Arc::new(Mutex::new(writer));
+        let logger: InfoLogger = Arc::new(move |message: &str| {
+            let mut writer = writer.lock().unwrap();
+            // NOTE telemetry should not stop the search, so write errors are ignored
+            let _ = writeln!(writer, "{}", message).and_then(|_| writer.flush());
+        });
+
+        Self { inner: LogSink::new(logger, log_best, log_population, dump_population) }
+    }
+}
+
+impl<C, O, S> TelemetrySink for FileSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn on_initial(&mut self, solution: &S, item_idx: usize, total_items: usize, item_time: &Timer, time: &Timer) {
+        self.inner.on_initial(solution, item_idx, total_items, item_time, time)
+    }
+
+    fn on_generation(
+        &mut self,
+        heuristic_ctx: &C,
+        statistics: &HeuristicStatistics,
+        generation_time: &Timer,
+        is_improved: bool,
+    ) {
+        self.inner.on_generation(heuristic_ctx, statistics, generation_time, is_improved)
+    }
+
+    fn on_phase_change(&mut self, previous: SelectionPhase, current: SelectionPhase, time: &Timer) {
+        self.inner.on_phase_change(previous, current, time)
+    }
+
+    fn on_operator_weights(&mut self, heuristic_ctx: &C, weights: &[(String, f64)], time: &Timer) {
+        self.inner.on_operator_weights(heuristic_ctx, weights, time)
+    }
+
+    fn on_result(&mut self, heuristic_ctx: &C, time: &Timer) {
+        self.inner.on_result(heuristic_ctx, time)
+    }
+
+    fn on_log(&self, message: &str) {
+        self.inner.on_log(message)
+    }
+}
+
+/// A telemetry sink which appends one JSON object per generation into a file or any other writer,
+/// so the progress of long runs can be monitored and analyzed without keeping it in memory.
+/// Each object has the following fields:
+/// - `generation`: generation sequence number
+/// - `timestamp`: time since evolution started in seconds
+/// - `duration`: generation duration in milliseconds
+/// - `isImproved`: whether the generation is considered as improvement
+/// - `improvement`: overall and last 1000 generations improvement ratios
+/// - `best`: fitness values of the best individual, if any
+/// - `population`: population size and its selection phase
+/// - `operators`: names of operators applied by heuristic, if it tracks them
+pub struct JsonLinesSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    writer: Mutex<Box<dyn Write + Send>>,
+    log_generation: usize,
+    operators: Option<Vec<String>>,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+impl<C, O, S> JsonLinesSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `JsonLinesSink`. `log_generation` specifies how often generation
+    /// is written.
+    pub fn new(writer: Box<dyn Write + Send>, log_generation: usize) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+            log_generation: log_generation.max(1),
+            operators: None,
+            _marker: Default::default(),
+        }
+    }
+
+    fn write_line(&self, line: &str) {
+        let mut writer = self.writer.lock().unwrap();
+        // NOTE telemetry should not stop the search, so write errors are ignored
+        let _ = writeln!(writer, "{}", line).and_then(|_| writer.flush());
+    }
+}
+
+impl<C, O, S> TelemetrySink for JsonLinesSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn on_search(&mut self, _: &C, operators: &[String]) {
+        self.operators = Some(operators.to_vec());
+    }
+
+    fn on_generation(
+        &mut self,
+        heuristic_ctx: &C,
+        statistics: &HeuristicStatistics,
+        generation_time: &Timer,
+        is_improved: bool,
+    ) {
+        let operators = self.operators.take();
+
+        if statistics.generation % self.log_generation != 0 {
+            return;
+        }
+
+        let population = heuristic_ctx.population();
+        let best = population.ranked().next().map_or("null".to_string(), |(best, _)| {
+            format!("[{}]", best.get_fitness().map(format_json_number).collect::<Vec<_>>().join(","))
+        });
+        let operators = operators.map_or("null".to_string(), |operators| {
+            format!("[{}]", operators.iter().map(|name| format_json_string(name)).collect::<Vec<_>>().join(","))
+        });
+
+        let line = format!(
+            r#"{{"generation":{},"timestamp":{},"duration":{},"isImproved":{},"improvement":{{"all":{},"last1000":{}}},"best":{},"population":{{"size":{},"phase":"{}"}},"operators":{}}}"#,
+            statistics.generation,
+            format_json_number(statistics.time.elapsed_secs_as_f64()),
+            generation_time.elapsed_millis(),
+            is_improved,
+            format_json_number(statistics.improvement_all_ratio),
+            format_json_number(statistics.improvement_1000_ratio),
+            best,
+            population.size(),
+            get_phase_name(population.selection_phase()),
+            operators,
+        );
+
+        self.write_line(line.as_str());
+    }
+}
+
+/// A function which receives progress metrics rendered in OpenMetrics text format.
+pub type ProgressMetricsExporter = Box<dyn Fn(&str) + Send + Sync>;
+
+/// Keeps the latest solver progress values: generation counter, best fitness, improvement ratios
+/// and selection phase. It is cheap to clone and all clones share the same values, so it can be
+/// kept by a solver service and rendered in Prometheus/OpenMetrics text format on request.
+#[derive(Clone, Default)]
+pub struct ProgressMetrics {
+    state: Arc<Mutex<ProgressMetricsState>>,
+}
+
+#[derive(Default)]
+struct ProgressMetricsState {
+    generations: usize,
+    best_fitness: Option<f64>,
+    improvement_all_ratio: f64,
+    improvement_1000_ratio: f64,
+    last_improvement: usize,
+    phase: Option<SelectionPhase>,
+    elapsed: f64,
+}
+
+impl ProgressMetrics {
+    /// Renders metrics in OpenMetrics text format which is also understood by Prometheus.
+    pub fn render(&self) -> String {
+        let state = self.state.lock().unwrap();
+        let mut text = String::new();
+
+        let mut add_metric = |name: &str, kind: &str, help: &str, samples: &[(&str, f64)]| {
+            writeln!(text, "# HELP {} {}", name, help).unwrap();
+            writeln!(text, "# TYPE {} {}", name, kind).unwrap();
+            samples.iter().for_each(|(suffix, value)| writeln!(text, "{}{} {}", name, suffix, value).unwrap());
+        };
+
+        add_metric("solver_generations", "counter", "Amount of generations.", &[("_total", state.generations as f64)]);
+        if let Some(best_fitness) = state.best_fitness {
+            add_metric("solver_best_fitness", "gauge", "Fitness of the best known solution.", &[("", best_fitness)]);
+        }
+        add_metric(
+            "solver_improvement_ratio",
+            "gauge",
+            "Ratio of generations with improvement.",
+            &[(r#"{window="all"}"#, state.improvement_all_ratio), (r#"{window="1000"}"#, state.improvement_1000_ratio)],
+        );
+        add_metric(
+            "solver_generations_since_improvement",
+            "gauge",
+            "Amount of generations since the last improvement.",
+            &[("", state.generations.saturating_sub(state.last_improvement) as f64)],
+        );
+        add_metric(
+            "solver_phase",
+            "gauge",
+            "Current population selection phase.",
+            &[SelectionPhase::Initial, SelectionPhase::Exploration, SelectionPhase::Exploitation].map(|phase| {
+                let value = if state.phase == Some(phase) { 1. } else { 0. };
+                (get_phase_label(phase), value)
+            }),
+        );
+        add_metric("solver_elapsed_seconds", "gauge", "Time since evolution started.", &[("", state.elapsed)]);
+
+        text.push_str("# EOF\n");
+
+        text
+    }
+}
+
+/// A telemetry sink which updates progress metrics on each generation and, optionally, exports
+/// them periodically, e.g. into a file scraped by a metrics collector.
+pub struct ProgressMetricsSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    metrics: ProgressMetrics,
+    exporter: Option<(usize, ProgressMetricsExporter)>,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+impl<C, O, S> ProgressMetricsSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `ProgressMetricsSink` which updates given metrics.
+    pub fn new(metrics: ProgressMetrics) -> Self {
+        Self { metrics, exporter: None, _marker: Default::default() }
+    }
+
+    /// Creates a new instance of `ProgressMetricsSink` which exports rendered metrics every
+    /// `interval` generations and at the end of evolution.
+    pub fn new_with_exporter(metrics: ProgressMetrics, interval: usize, exporter: ProgressMetricsExporter) -> Self {
+        Self { metrics, exporter: Some((interval.max(1), exporter)), _marker: Default::default() }
+    }
+
+    fn export(&self) {
+        if let Some((_, exporter)) = self.exporter.as_ref() {
+            exporter(self.metrics.render().as_str())
+        }
+    }
+}
+
+impl<C, O, S> TelemetrySink for ProgressMetricsSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn on_generation(&mut self, heuristic_ctx: &C, statistics: &HeuristicStatistics, _: &Timer, is_improved: bool) {
+        {
+            let mut state = self.metrics.state.lock().unwrap();
+            state.generations = statistics.generation + 1;
+            state.best_fitness =
+                heuristic_ctx.population().ranked().next().map(|(best, _)| heuristic_ctx.objective().fitness(best));
+            state.improvement_all_ratio = statistics.improvement_all_ratio;
+            state.improvement_1000_ratio = statistics.improvement_1000_ratio;
+            state.phase = Some(heuristic_ctx.population().selection_phase());
+            state.elapsed = statistics.time.elapsed_secs_as_f64();
+
+            if is_improved {
+                state.last_improvement = state.generations;
+            }
+        }
+
+        if self.exporter.as_ref().map_or(false, |(interval, _)| statistics.generation % interval == 0) {
+            self.export();
+        }
+    }
+
+    fn on_phase_change(&mut self, _: SelectionPhase, current: SelectionPhase, _: &Timer) {
+        self.metrics.state.lock().unwrap().phase = Some(current);
+    }
+
+    fn on_result(&mut self, _: &C, time: &Timer) {
+        self.metrics.state.lock().unwrap().elapsed = time.elapsed_secs_as_f64();
+        self.export();
+    }
+}
+
+/// A telemetry sink which calls a callback on each generation.
+pub struct CallbackSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    callback: GenerationCallback<C>,
+    _marker: (PhantomData<O>, PhantomData<S>),
+}
+
+impl<C, O, S> CallbackSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `CallbackSink`.
+    pub fn new(callback: GenerationCallback<C>) -> Self {
+        Self { callback, _marker: Default::default() }
+    }
+}
+
+impl<C, O, S> TelemetrySink for CallbackSink<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn on_generation(&mut self, heuristic_ctx: &C, statistics: &HeuristicStatistics, _: &Timer, is_improved: bool) {
+        (self.callback)(heuristic_ctx, statistics, is_improved)
+    }
+}
+
+fn get_individual_metrics<C, O, S>(heuristic_ctx: &C, solution: &S, rank: usize) -> TelemetryIndividual
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    let fitness = solution.get_fitness().collect::<Vec<_>>();
+
+    let (_, improvement) = get_fitness_value(heuristic_ctx, solution);
+
+    TelemetryIndividual { rank, improvement, fitness }
+}
+
+fn get_fitness_value<C, O, S>(heuristic_ctx: &C, solution: &S) -> (f64, f64)
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    let fitness_value = heuristic_ctx.objective().fitness(solution);
+
+    let fitness_change = heuristic_ctx
+        .population()
+        .ranked()
+        .next()
+        .map(|(best_ctx, _)| heuristic_ctx.objective().fitness(best_ctx))
+        .map(|best_fitness| (fitness_value - best_fitness) / best_fitness * 100.)
+        .unwrap_or(0.);
+
+    (fitness_value, fitness_change)
+}
+
+fn get_population_state<C, O, S>(heuristic_ctx: &C) -> String
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    let mut state = String::new();
+    write!(state, "{}", heuristic_ctx.population()).unwrap();
+
+    state
+}
+
+fn get_phase_name(phase: SelectionPhase) -> &'static str {
+    match phase {
+        SelectionPhase::Initial => "initial",
+        SelectionPhase::Exploration => "exploration",
+        SelectionPhase::Exploitation => "exploitation",
+    }
+}
+
+fn get_phase_label(phase: SelectionPhase) -> &'static str {
+    match phase {
+        SelectionPhase::Initial => r#"{phase="initial"}"#,
+        SelectionPhase::Exploration => r#"{phase="exploration"}"#,
+        SelectionPhase::Exploitation => r#"{phase="exploitation"}"#,
+    }
+}
+
+fn format_json_number(value: f64) -> String {
+    if value.is_finite() {
+        format!("{}", value)
+    } else {
+        "null".to_string()
+    }
+}
+
+fn format_json_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    value.chars().for_each(|c| match c {
+        '"' => result.push_str("\\\""),
+        '\\' => result.push_str("\\\\"),
+        '\n' => result.push_str("\\n"),
+        '\r' => result.push_str("\\r"),
+        '\t' => result.push_str("\\t"),
+        c if (c as u32) < 0x20 => write!(result, "\\u{:04x}", c as u32).unwrap(),
+        c => result.push(c),
+    });
+    result.push('"');
+
+    result
+}
+
+fn format_fitness(fitness: impl Iterator<Item = f64>) -> String {
+    fitness.map(|v| format!("{:.3}", v)).collect::<Vec<_>>().join(", ")
+}
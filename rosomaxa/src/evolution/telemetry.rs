@@ -4,11 +4,11 @@
 #[path = "../../tests/unit/evolution/telemetry_test.rs"]
 mod telemetry_test;
 
+use super::sinks::*;
 use crate::prelude::*;
 use crate::utils::Timer;
-use std::fmt::Write;
+use std::io::Write;
 use std::marker::PhantomData;
-use std::ops::Deref;
 
 /// Encapsulates different measurements regarding algorithm evaluation.
 pub struct TelemetryMetrics {
@@ -20,6 +20,14 @@ pub struct TelemetryMetrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<TelemetryGeneration>,
+    /// Best individual fitness per objective tier. Tracked on each generation, but kept only when changed.
+    pub tiers: Vec<TelemetryTiers>,
+    /// Periodic snapshots of the whole population fitness.
+    pub snapshots: Vec<TelemetrySnapshot>,
+    /// Weights of heuristic operators learned by adaptive heuristic. Kept only when changed.
+    pub operator_weights: Vec<TelemetryOperatorWeights>,
+    /// Outcomes of independent restarts. Empty if evolution is run without restarts.
+    pub restarts: Vec<TelemetryRestart>,
 }
 
 /// Represents information about generation.
@@ -38,6 +46,48 @@ pub struct TelemetryGeneration {
     pub population: TelemetryPopulation,
 }
 
+/// Represents fitness of the best individual per objective tier at given generation.
+pub struct TelemetryTiers {
+    /// Generation sequence number.
+    pub number: usize,
+    /// Time since evolution started.
+    pub timestamp: f64,
+    /// Fitness values of objectives grouped by tiers.
+    pub fitness: Vec<Vec<f64>>,
+}
+
+/// Represents fitness of all individuals kept by population at given generation.
+pub struct TelemetrySnapshot {
+    /// Generation sequence number.
+    pub number: usize,
+    /// Time since evolution started.
+    pub timestamp: f64,
+    /// Fitness values of all individuals.
+    pub fitness: Vec<Vec<f64>>,
+}
+
+/// Represents weights of heuristic operators at given generation.
+pub struct TelemetryOperatorWeights {
+    /// Generation sequence number.
+    pub number: usize,
+    /// Time since evolution started.
+    pub timestamp: f64,
+    /// Operator names with their weights.
+    pub weights: Vec<(String, f64)>,
+}
+
+/// Represents an outcome of a single independent restart.
+pub struct TelemetryRestart {
+    /// Restart sequence number.
+    pub number: usize,
+    /// Amount of generations run within the restart.
+    pub generations: usize,
+    /// Restart duration in seconds.
+    pub duration: f64,
+    /// Fitness values of the best individual found by the restart.
+    pub fitness: Vec<f64>,
+}
+
 /// Keeps essential information about particular individual in population.
 pub struct TelemetryIndividual {
     /// Rank in population.
@@ -54,7 +104,23 @@ pub struct TelemetryPopulation {
     pub individuals: Vec<TelemetryIndividual>,
 }
 
-/// Specifies a telemetry mode.
+/// A callback which is called on each generation with heuristic context, its statistics and
+/// a flag whether the generation is considered as improvement.
+pub type GenerationCallback<C> = Box<dyn Fn(&C, &HeuristicStatistics, bool) + Send + Sync>;
+
+/// A callback which is called with the best known solution and generation statistics each time
+/// the best known solution is improved.
+pub type SolutionCallback<S> = Box<dyn Fn(&S, &HeuristicStatistics) + Send + Sync>;
+
+/// A callback which is called periodically with heuristic context and serialized state of hyper
+/// heuristic in order to create a checkpoint of the evolution.
+pub type CheckpointCallback<C> = Box<dyn Fn(&C, Option<String>) + Send + Sync>;
+
+/// A processing which is called when population selection phase is changed.
+pub type PhaseProcessing<C, O, S> =
+    Box<dyn HeuristicPhaseProcessing<Context = C, Objective = O, Solution = S> + Send + Sync>;
+
+/// Specifies a telemetry mode which is used to create default telemetry sinks.
 pub enum TelemetryMode {
     /// No telemetry at all.
     None,
@@ -73,6 +139,15 @@ pub enum TelemetryMode {
     OnlyMetrics {
         /// Specifies how often population is tracked.
         track_population: usize,
+        /// Specifies how often snapshot of the whole population is taken. No snapshots if not set.
+        snapshot_population: Option<usize>,
+    },
+    /// Only streaming of generation statistics as JSON lines.
+    OnlyJsonLines {
+        /// A writer which receives one JSON object per line.
+        writer: Box<dyn Write + Send>,
+        /// Specifies how often generation is written.
+        log_generation: usize,
     },
     /// Both logging and metrics collection.
     All {
@@ -84,46 +159,158 @@ pub enum TelemetryMode {
         log_population: usize,
         /// Specifies how often population is tracked.
         track_population: usize,
+        /// Specifies how often snapshot of the whole population is taken. No snapshots if not set.
+        snapshot_population: Option<usize>,
         /// Specifies whether population should be dumped.
         dump_population: bool,
     },
 }
 
-/// Provides way to collect metrics and write information into log.
+/// Provides way to collect metrics and write information into log. All information is passed to
+/// telemetry sinks, so the way how it is processed can be customized by implementing `TelemetrySink`.
 pub struct Telemetry<C, O, S>
 where
     C: HeuristicContext<Objective = O, Solution = S>,
     O: HeuristicObjective<Solution = S>,
     S: HeuristicSolution,
 {
-    metrics: TelemetryMetrics,
     time: Timer,
-    mode: TelemetryMode,
     improvement_tracker: ImprovementTracker,
     speed_tracker: SpeedTracker,
     next_generation: Option<usize>,
+    sinks: Vec<BoxedTelemetrySink<C, O, S>>,
+    phase_processing: Vec<PhaseProcessing<C, O, S>>,
+    checkpoint: Option<(usize, CheckpointCallback<C>)>,
     _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
 }
 
+impl<C, O, S> Telemetry<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S> + 'static,
+    O: HeuristicObjective<Solution = S> + 'static,
+    S: HeuristicSolution + 'static,
+{
+    /// Creates a new instance of `Telemetry` with default sinks specified by telemetry mode.
+    pub fn new(mode: TelemetryMode) -> Self {
+        let sinks: Vec<BoxedTelemetrySink<C, O, S>> = match mode {
+            TelemetryMode::None => vec![],
+            TelemetryMode::OnlyLogging { logger, log_best, log_population, dump_population } => {
+                vec![Box::new(LogSink::new(logger, log_best, log_population, dump_population))]
+            }
+            TelemetryMode::OnlyMetrics { track_population, snapshot_population } => {
+                vec![Box::new(MetricsSink::new(track_population, snapshot_population))]
+            }
+            TelemetryMode::OnlyJsonLines { writer, log_generation } => {
+                vec![Box::new(JsonLinesSink::new(writer, log_generation))]
+            }
+            TelemetryMode::All {
+                logger,
+                log_best,
+                log_population,
+                track_population,
+                snapshot_population,
+                dump_population,
+            } => vec![
+                Box::new(LogSink::new(logger, log_best, log_population, dump_population)),
+                Box::new(MetricsSink::new(track_population, snapshot_population)),
+            ],
+        };
+
+        Self::new_with_sinks(sinks)
+    }
+
+    /// Adds a callback which is called on each generation regardless of telemetry mode.
+    pub fn add_generation_callback(&mut self, callback: GenerationCallback<C>) {
+        self.add_sink(Box::new(CallbackSink::new(callback)));
+    }
+
+    /// Adds a callback which is called with the best known solution when it is improved.
+    pub fn add_solution_callback(&mut self, callback: SolutionCallback<S>) {
+        self.add_generation_callback(Box::new(move |heuristic_ctx: &C, statistics, is_improved| {
+            if is_improved {
+                if let Some((best, _)) = heuristic_ctx.population().ranked().next() {
+                    callback(best, statistics)
+                }
+            }
+        }));
+    }
+}
+
 impl<C, O, S> Telemetry<C, O, S>
 where
     C: HeuristicContext<Objective = O, Solution = S>,
     O: HeuristicObjective<Solution = S>,
     S: HeuristicSolution,
 {
-    /// Creates a new instance of `Telemetry`.
-    pub fn new(mode: TelemetryMode) -> Self {
+    /// Creates a new instance of `Telemetry` with given sinks.
+    pub fn new_with_sinks(sinks: Vec<BoxedTelemetrySink<C, O, S>>) -> Self {
         Self {
             time: Timer::start(),
-            metrics: TelemetryMetrics { duration: 0, generations: 0, speed: 0.0, evolution: vec![] },
-            mode,
             improvement_tracker: ImprovementTracker::new(1000),
             speed_tracker: SpeedTracker::default(),
             next_generation: None,
+            sinks,
+            phase_processing: Vec::default(),
+            checkpoint: None,
             _marker: Default::default(),
         }
     }
 
+    /// Adds a telemetry sink.
+    pub fn add_sink(&mut self, sink: BoxedTelemetrySink<C, O, S>) {
+        self.sinks.push(sink);
+    }
+
+    /// Adds a processing which is called when population selection phase is changed regardless of telemetry mode.
+    pub fn add_phase_processing(&mut self, processing: PhaseProcessing<C, O, S>) {
+        self.phase_processing.push(processing);
+    }
+
+    /// Sets a callback which is called every `interval` generations and at the end of evolution
+    /// in order to create a checkpoint.
+    pub fn set_checkpoint_callback(&mut self, interval: usize, callback: CheckpointCallback<C>) {
+        self.checkpoint = Some((interval.max(1), callback));
+    }
+
+    /// Reports weights of heuristic operators if heuristic adapts them.
+    pub fn on_operator_weights(
+        &mut self,
+        heuristic_ctx: &C,
+        heuristic: &dyn HyperHeuristic<Context = C, Objective = O, Solution = S>,
+    ) {
+        if let Some(weights) = heuristic.get_operator_weights() {
+            let time = &self.time;
+            self.sinks.iter_mut().for_each(|sink| sink.on_operator_weights(heuristic_ctx, weights.as_slice(), time));
+        }
+    }
+
+    /// Reports operators applied by heuristic during the last search if heuristic tracks them.
+    pub fn on_search(
+        &mut self,
+        heuristic_ctx: &C,
+        heuristic: &dyn HyperHeuristic<Context = C, Objective = O, Solution = S>,
+    ) {
+        if let Some(operators) = heuristic.get_last_operators() {
+            self.sinks.iter_mut().for_each(|sink| sink.on_search(heuristic_ctx, operators.as_slice()));
+        }
+    }
+
+    /// Reports checkpoint if it is time to create one or if evolution is finished.
+    pub fn on_checkpoint(
+        &self,
+        heuristic_ctx: &C,
+        heuristic: &dyn HyperHeuristic<Context = C, Objective = O, Solution = S>,
+        is_final: bool,
+    ) {
+        if let Some((interval, callback)) = &self.checkpoint {
+            let generation = heuristic_ctx.statistics().generation;
+
+            if is_final || (generation > 0 && generation % interval == 0) {
+                callback(heuristic_ctx, heuristic.save_state());
+            }
+        }
+    }
+
     /// Starts telemetry reporting.
     pub fn start(&mut self) {
         self.time = Timer::start();
@@ -131,22 +318,8 @@ where
 
     /// Reports initial solution statistics.
     pub fn on_initial(&mut self, solution: &S, item_idx: usize, total_items: usize, item_time: Timer) {
-        match &self.mode {
-            TelemetryMode::OnlyLogging { .. } | TelemetryMode::All { .. } => {
-                self.log(
-                    format!(
-                        "[{}s] created {} of {} initial solutions in {}ms",
-                        self.time.elapsed_secs(),
-                        item_idx + 1,
-                        total_items,
-                        item_time.elapsed_millis(),
-                    )
-                    .as_str(),
-                );
-                self.log(format!("\tfitness: ({})", format_fitness(solution.get_fitness())).as_str());
-            }
-            _ => {}
-        };
+        let time = &self.time;
+        self.sinks.iter_mut().for_each(|sink| sink.on_initial(solution, item_idx, total_items, &item_time, time));
     }
 
     /// Reports generation statistics.
@@ -159,7 +332,6 @@ where
     ) -> HeuristicStatistics {
         let generation = self.next_generation.unwrap_or(0);
 
-        self.metrics.generations = generation;
         self.improvement_tracker.track(generation, is_improved);
         self.speed_tracker.track(generation, termination_estimate);
         self.next_generation = Some(generation + 1);
@@ -173,153 +345,37 @@ where
             termination_estimate,
         };
 
-        let (log_best, log_population, track_population, should_dump_population) = match &self.mode {
-            TelemetryMode::None => return statistics,
-            TelemetryMode::OnlyLogging { log_best, log_population, dump_population, .. } => {
-                (Some(log_best), Some(log_population), None, *dump_population)
-            }
-            TelemetryMode::OnlyMetrics { track_population, .. } => (None, None, Some(track_population), false),
-            TelemetryMode::All { log_best, log_population, track_population, dump_population, .. } => {
-                (Some(log_best), Some(log_population), Some(track_population), *dump_population)
-            }
-        };
-
-        if let Some((best_individual, rank)) = heuristic_ctx.population().ranked().next() {
-            let should_log_best = generation % *log_best.unwrap_or(&usize::MAX) == 0;
-            let should_log_population = generation % *log_population.unwrap_or(&usize::MAX) == 0;
-            let should_track_population = generation % *track_population.unwrap_or(&usize::MAX) == 0;
-
-            if should_log_best {
-                self.log_individual(
-                    &self.get_individual_metrics(heuristic_ctx, best_individual, rank),
-                    Some((generation, generation_time)),
-                )
-            }
-
-            self.on_population(heuristic_ctx, should_log_population, should_track_population, should_dump_population);
-        } else {
-            self.log("no progress yet");
-        }
+        self.sinks
+            .iter_mut()
+            .for_each(|sink| sink.on_generation(heuristic_ctx, &statistics, &generation_time, is_improved));
 
         statistics
     }
 
-    /// Reports population state.
-    fn on_population(
-        &mut self,
-        heuristic_ctx: &C,
-        should_log_population: bool,
-        should_track_population: bool,
-        should_dump_population: bool,
-    ) {
-        if !should_log_population && !should_track_population {
-            return;
-        }
-
-        let generation = heuristic_ctx.statistics().generation;
-
-        if should_log_population {
-            self.log(
-                format!(
-                    "[{}s] population state (phase: {}, speed: {:.2} gen/sec, improvement ratio: {:.3}:{:.3}):",
-                    self.time.elapsed_secs(),
-                    get_selection_phase(heuristic_ctx),
-                    generation as f64 / self.time.elapsed_secs_as_f64(),
-                    self.improvement_tracker.i_all_ratio,
-                    self.improvement_tracker.i_1000_ratio,
-                )
-                .as_str(),
-            );
-        }
-
-        let individuals = heuristic_ctx
-            .population()
-            .ranked()
-            .map(|(insertion_ctx, rank)| self.get_individual_metrics(heuristic_ctx, insertion_ctx, rank))
-            .collect::<Vec<_>>();
+    /// Reports a change of population selection phase.
+    pub fn on_phase_change(&mut self, heuristic_ctx: &mut C, previous: SelectionPhase, current: SelectionPhase) {
+        let time = &self.time;
+        self.sinks.iter_mut().for_each(|sink| sink.on_phase_change(previous, current, time));
 
-        if should_log_population {
-            individuals.iter().for_each(|metrics| self.log_individual(metrics, None));
-            if should_dump_population {
-                self.log(&format!("\t{}", get_population_state(heuristic_ctx)));
-            }
-        }
-
-        if should_track_population {
-            self.metrics.evolution.push(TelemetryGeneration {
-                number: generation,
-                timestamp: self.time.elapsed_secs_as_f64(),
-                i_all_ratio: self.improvement_tracker.i_all_ratio,
-                i_1000_ratio: self.improvement_tracker.i_1000_ratio,
-                is_improvement: self.improvement_tracker.is_last_improved,
-                population: TelemetryPopulation { individuals },
-            });
-        }
+        self.phase_processing
+            .iter()
+            .for_each(|processing| processing.on_phase_change(heuristic_ctx, previous, current));
     }
 
     /// Reports final statistic.
     pub fn on_result(&mut self, heuristic_ctx: &C) {
-        let generations = heuristic_ctx.statistics().generation;
-
-        let (should_log_population, should_track_population) = match &self.mode {
-            TelemetryMode::OnlyLogging { .. } => (true, false),
-            TelemetryMode::OnlyMetrics { track_population, .. } => (false, generations % track_population != 0),
-            TelemetryMode::All { track_population, .. } => (true, generations % track_population != 0),
-            _ => return,
-        };
-
-        self.on_population(heuristic_ctx, should_log_population, should_track_population, false);
-
-        let elapsed = self.time.elapsed_secs() as usize;
-        let speed = generations as f64 / self.time.elapsed_secs_as_f64();
-
-        self.log(format!("[{}s] total generations: {}, speed: {:.2} gen/sec", elapsed, generations, speed).as_str());
-
-        self.metrics.duration = elapsed;
-        self.metrics.speed = speed;
+        let time = &self.time;
+        self.sinks.iter_mut().for_each(|sink| sink.on_result(heuristic_ctx, time));
     }
 
-    /// Gets metrics.
-    pub fn take_metrics(self) -> Option<TelemetryMetrics> {
-        match &self.mode {
-            TelemetryMode::OnlyMetrics { .. } | TelemetryMode::All { .. } => Some(self.metrics),
-            _ => None,
-        }
+    /// Gets metrics collected by the first sink which collects them.
+    pub fn take_metrics(mut self) -> Option<TelemetryMetrics> {
+        self.sinks.iter_mut().find_map(|sink| sink.take_metrics())
     }
 
     /// Writes log message.
     pub fn log(&self, message: &str) {
-        match &self.mode {
-            TelemetryMode::OnlyLogging { logger, .. } => logger.deref()(message),
-            TelemetryMode::All { logger, .. } => logger.deref()(message),
-            _ => {}
-        }
-    }
-
-    fn get_individual_metrics(&self, heuristic_ctx: &C, solution: &S, rank: usize) -> TelemetryIndividual {
-        let fitness = solution.get_fitness().collect::<Vec<_>>();
-
-        let (_, improvement) = get_fitness_value(heuristic_ctx, solution);
-
-        TelemetryIndividual { rank, improvement, fitness }
-    }
-
-    fn log_individual(&self, metrics: &TelemetryIndividual, gen_info: Option<(usize, Timer)>) {
-        self.log(
-            format!(
-                "{} rank: {}, fitness: ({}), improvement: {:.3}%",
-                gen_info.map_or("\t".to_string(), |(gen, gen_time)| format!(
-                    "[{}s] generation {} took {}ms,",
-                    self.time.elapsed_secs(),
-                    gen,
-                    gen_time.elapsed_millis()
-                )),
-                metrics.rank,
-                format_fitness(metrics.fitness.iter().cloned()),
-                metrics.improvement,
-            )
-            .as_str(),
-        );
+        self.sinks.iter().for_each(|sink| sink.on_log(message));
     }
 }
 
@@ -398,51 +454,3 @@ impl SpeedTracker {
         self.speed.clone()
     }
 }
-
-fn get_fitness_value<C, O, S>(heuristic_ctx: &C, solution: &S) -> (f64, f64)
-where
-    C: HeuristicContext<Objective = O, Solution = S>,
-    O: HeuristicObjective<Solution = S>,
-    S: HeuristicSolution,
-{
-    let fitness_value = heuristic_ctx.objective().fitness(solution);
-
-    let fitness_change = heuristic_ctx
-        .population()
-        .ranked()
-        .next()
-        .map(|(best_ctx, _)| heuristic_ctx.objective().fitness(best_ctx))
-        .map(|best_fitness| (fitness_value - best_fitness) / best_fitness * 100.)
-        .unwrap_or(0.);
-
-    (fitness_value, fitness_change)
-}
-
-fn get_population_state<C, O, S>(heuristic_ctx: &C) -> String
-where
-    C: HeuristicContext<Objective = O, Solution = S>,
-    O: HeuristicObjective<Solution = S>,
-    S: HeuristicSolution,
-{
-    let mut state = String::new();
-    write!(state, "{}", heuristic_ctx.population()).unwrap();
-
-    state
-}
-
-fn get_selection_phase<C, O, S>(heuristic_ctx: &C) -> &str
-where
-    C: HeuristicContext<Objective = O, Solution = S>,
-    O: HeuristicObjective<Solution = S>,
-    S: HeuristicSolution,
-{
-    match heuristic_ctx.population().selection_phase() {
-        SelectionPhase::Initial => "initial",
-        SelectionPhase::Exploration => "exploration",
-        SelectionPhase::Exploitation => "exploitation",
-    }
-}
-
-fn format_fitness(fitness: impl Iterator<Item = f64>) -> String {
-    fitness.map(|v| format!("{:.3}", v)).collect::<Vec<_>>().join(", ")
-}
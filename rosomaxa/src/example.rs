@@ -7,7 +7,7 @@ mod example_test;
 use crate::evolution::*;
 use crate::get_default_population;
 use crate::hyper::*;
-use crate::population::{DominanceOrder, DominanceOrdered, RosomaxaWeighted, Shuffled};
+use crate::population::{DominanceOrder, DominanceOrdered, RosomaxaCompressible, RosomaxaWeighted, Shuffled};
 use crate::prelude::*;
 use crate::utils::Noise;
 use hashbrown::{HashMap, HashSet};
@@ -160,6 +160,8 @@ impl RosomaxaWeighted for VectorSolution {
     }
 }
 
+impl RosomaxaCompressible for VectorSolution {}
+
 impl VectorSolution {
     /// Creates a new instance of `VectorSolution`.
     pub fn new(data: Vec<f64>, objective: Arc<VectorObjective>) -> Self {
@@ -248,6 +250,7 @@ pub struct Solver {
     min_cv: Option<(String, usize, f64, bool)>,
     target_proximity: Option<(Vec<f64>, f64)>,
     operators: Vec<(TargetHeuristicOperator, String, f64)>,
+    islands: Option<(usize, usize)>,
 }
 
 impl Default for Solver {
@@ -261,6 +264,7 @@ impl Default for Solver {
             min_cv: None,
             target_proximity: None,
             operators: vec![],
+            islands: None,
         }
     }
 }
@@ -302,6 +306,12 @@ impl Solver {
         self
     }
 
+    /// Sets island model parameters: amount of islands and migration interval in generations.
+    pub fn with_islands(mut self, island_count: usize, migration_interval: usize) -> Self {
+        self.islands = Some((island_count, migration_interval));
+        self
+    }
+
     /// Sets objective function.
     pub fn with_objective_fun(mut self, objective_func: VectorFunction) -> Self {
         self.objective_func = Some(objective_func);
@@ -315,24 +325,8 @@ impl Solver {
         // build instances of implementation types from submitted data
         let func = self.objective_func.ok_or_else(|| "objective function must be set".to_string())?;
         let objective = Arc::new(VectorObjective::new(func));
-        let heuristic = Box::new(MultiSelective::new(
-            Box::new(DynamicSelective::new(
-                self.operators.iter().map(|(op, name, _)| (op.clone(), name.clone())).collect(),
-                environment.random.clone(),
-            )),
-            Box::new(StaticSelective::new(
-                self.operators
-                    .iter()
-                    .map(|(op, _, probability)| {
-                        let random = environment.random.clone();
-                        let probability = *probability;
-                        let probability_func: HeuristicProbability<VectorContext, VectorObjective, VectorSolution> =
-                            (Box::new(move |_, _| random.is_hit(probability)), Default::default());
-                        (op.clone(), probability_func)
-                    })
-                    .collect(),
-            )),
-        ));
+        let heuristic = create_heuristic(self.operators.as_slice(), environment.clone());
+        let dimension = self.initial_solutions.first().map_or(0, |solution| solution.len());
         let initial_operators = self
             .initial_solutions
             .into_iter()
@@ -343,7 +337,7 @@ impl Solver {
         // create a heuristic context
         let context = VectorContext::new(
             objective.clone(),
-            get_default_population::<VectorContext, _, _>(objective.clone(), environment.clone()),
+            get_default_population::<VectorContext, _, _>(objective.clone(), environment.clone(), dimension),
             environment.clone(),
         );
 
@@ -356,7 +350,24 @@ impl Solver {
         });
 
         // build evolution config using fluent interface
-        let config = EvolutionConfigBuilder::default()
+        let builder = EvolutionConfigBuilder::default();
+        let builder = if let Some((island_count, migration_interval)) = self.islands {
+            let operators = self.operators;
+            let island_factory: IslandFactory<_, _, _> = Box::new(move |main_ctx: &VectorContext| {
+                let (objective, environment) = (main_ctx.objective.clone(), main_ctx.environment.clone());
+                let population =
+                    get_default_population::<VectorContext, _, _>(objective.clone(), environment.clone(), dimension);
+                let context = VectorContext::new(objective.clone(), population, environment.clone());
+
+                (context, create_heuristic(operators.as_slice(), environment))
+            });
+
+            builder.with_strategy(Box::new(RunIslands::new(1, island_count, migration_interval, island_factory)))
+        } else {
+            builder
+        };
+
+        let config = builder
             .with_heuristic(heuristic)
             .with_objective(objective)
             .with_context(context)
@@ -383,6 +394,30 @@ impl Solver {
     }
 }
 
+fn create_heuristic(
+    operators: &[(TargetHeuristicOperator, String, f64)],
+    environment: Arc<Environment>,
+) -> IslandHeuristic<VectorContext, VectorObjective, VectorSolution> {
+    Box::new(MultiSelective::new(
+        Box::new(DynamicSelective::new(
+            operators.iter().map(|(op, name, _)| (op.clone(), name.clone())).collect(),
+            environment.random.clone(),
+        )),
+        Box::new(StaticSelective::new(
+            operators
+                .iter()
+                .map(|(op, _, probability)| {
+                    let random = environment.random.clone();
+                    let probability = *probability;
+                    let probability_func: HeuristicProbability<VectorContext, VectorObjective, VectorSolution> =
+                        (Box::new(move |_, _| random.is_hit(probability)), Default::default());
+                    (op.clone(), probability_func)
+                })
+                .collect(),
+        )),
+    ))
+}
+
 /// Creates multidimensional Rosenbrock function, also referred to as the Valley or Banana function.
 /// The function is usually evaluated on the hypercube xi ∈ [-5, 10], for all i = 1, …, d, although
 /// it may be restricted to the hypercube xi ∈ [-2.048, 2.048], for all i = 1, …, d.
@@ -22,6 +22,7 @@ where
     heuristic_simulator: Simulator<SearchState>,
     initial_estimates: HashMap<SearchState, ActionEstimates<SearchState>>,
     action_registry: SearchActionRegistry<C, O, S>,
+    last_operators: Vec<String>,
 }
 
 impl<C, O, S> HyperHeuristic for DynamicSelective<C, O, S>
@@ -51,24 +52,96 @@ where
                         _ => SearchState::BestKnown,
                     },
                     solution: Some(solution.deep_copy()),
+                    heuristic_idx: None,
                 })
             })
             .collect();
 
-        let individuals = self
-            .heuristic_simulator
-            .run_episodes(agents, heuristic_ctx.environment().parallelism.clone(), |state, values| match state {
+        let agents = self.heuristic_simulator.run_episodes(
+            agents,
+            heuristic_ctx.environment().parallelism.clone(),
+            |state, values| match state {
                 SearchState::BestKnown => values.iter().max_by(|a, b| compare_floats(**a, **b)).cloned().unwrap_or(0.),
                 _ => values.iter().sum::<f64>() / values.len() as f64,
-            })
-            .into_iter()
-            .filter_map(|agent| agent.solution)
+            },
+        );
+
+        self.last_operators = agents
+            .iter()
+            .filter_map(|agent| agent.heuristic_idx)
+            .map(|heuristic_idx| registry.heuristics[heuristic_idx].1.clone())
             .collect();
 
+        let individuals = agents.into_iter().filter_map(|agent| agent.solution).collect();
+
         try_exchange_estimates(&mut self.heuristic_simulator);
 
         individuals
     }
+
+    fn save_state(&self) -> Option<String> {
+        let state_estimates = self.heuristic_simulator.get_state_estimates();
+
+        let states = SearchState::all()
+            .iter()
+            .enumerate()
+            .filter_map(|(state_idx, state)| state_estimates.get(state).map(|estimates| (state_idx, estimates)))
+            .map(|(state_idx, estimates)| {
+                let mut actions = estimates
+                    .data()
+                    .iter()
+                    .map(|(action, estimate)| match action {
+                        SearchAction::Search { heuristic_idx } => (*heuristic_idx, *estimate),
+                    })
+                    .collect::<Vec<_>>();
+                actions.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                let actions = actions.iter().map(|(idx, estimate)| format!("{}={}", idx, estimate)).collect::<Vec<_>>();
+
+                format!("{}:{}", state_idx, actions.join(","))
+            })
+            .collect::<Vec<_>>();
+
+        Some(states.join(";"))
+    }
+
+    fn restore_state(&mut self, state: &str) -> Result<(), String> {
+        let operators = self.action_registry.heuristics.len();
+        let parse_err = |part: &str| format!("cannot parse dynamic selective heuristic state: '{}'", part);
+
+        state.split(';').filter(|part| !part.is_empty()).try_for_each(|part| {
+            let (state_idx, actions) = part.split_once(':').ok_or_else(|| parse_err(part))?;
+            let state = state_idx
+                .parse::<usize>()
+                .ok()
+                .and_then(|state_idx| SearchState::all().get(state_idx).cloned())
+                .ok_or_else(|| parse_err(part))?;
+
+            let estimates = actions
+                .split(',')
+                .filter(|action| !action.is_empty())
+                .map(|action| {
+                    let (heuristic_idx, estimate) = action.split_once('=').ok_or_else(|| parse_err(action))?;
+                    let heuristic_idx = heuristic_idx.parse::<usize>().map_err(|_| parse_err(action))?;
+                    let estimate = estimate.parse::<f64>().map_err(|_| parse_err(action))?;
+
+                    if heuristic_idx >= operators {
+                        return Err(format!("unknown heuristic operator index in state: {}", heuristic_idx));
+                    }
+
+                    Ok((SearchAction::Search { heuristic_idx }, estimate))
+                })
+                .collect::<Result<HashMap<_, _>, String>>()?;
+
+            self.heuristic_simulator.set_action_estimates(state, ActionEstimates::from(estimates));
+
+            Ok(())
+        })
+    }
+
+    fn get_last_operators(&self) -> Option<Vec<String>> {
+        Some(self.last_operators.clone())
+    }
 }
 
 impl<C, O, S> DynamicSelective<C, O, S>
@@ -101,6 +174,7 @@ where
             .into_iter()
             .collect(),
             action_registry: SearchActionRegistry { heuristics: operators },
+            last_operators: Vec::default(),
         }
     }
 }
@@ -121,6 +195,20 @@ enum SearchState {
     Stagnated,
 }
 
+impl SearchState {
+    /// Returns all states in the order used to serialize them.
+    fn all() -> [SearchState; 6] {
+        [
+            SearchState::BestKnown,
+            SearchState::Diverse,
+            SearchState::BestMajorImprovement,
+            SearchState::BestMinorImprovement,
+            SearchState::DiverseImprovement,
+            SearchState::Stagnated,
+        ]
+    }
+}
+
 impl State for SearchState {
     type Action = SearchAction;
 
@@ -163,6 +251,7 @@ where
     state: SearchState,
     original: &'a S,
     solution: Option<S>,
+    heuristic_idx: Option<usize>,
 }
 
 impl<'a, C, O, S> Agent<SearchState> for SearchAgent<'a, C, O, S>
@@ -184,6 +273,7 @@ where
             SearchAction::Search { heuristic_idx } => {
                 let solution = self.solution.as_ref().unwrap();
                 let (heuristic, _) = &self.registry.heuristics[*heuristic_idx];
+                self.heuristic_idx = Some(*heuristic_idx);
 
                 heuristic.search(self.heuristic_ctx, solution)
             }
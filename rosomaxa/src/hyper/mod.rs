@@ -24,7 +24,7 @@ pub trait HeuristicOperator {
 }
 
 /// Represents a hyper heuristic functionality.
-pub trait HyperHeuristic {
+pub trait HyperHeuristic: Send {
     /// A heuristic context type.
     type Context: HeuristicContext<Objective = Self::Objective, Solution = Self::Solution>;
     /// A heuristic objective type.
@@ -34,6 +34,29 @@ pub trait HyperHeuristic {
 
     /// Performs a new search in the solution space using selected solutions.
     fn search(&mut self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution>;
+
+    /// Serializes internal state (e.g. learned operator statistics) in order to create a checkpoint.
+    /// Returns `None` if heuristic has no state worth to be saved.
+    fn save_state(&self) -> Option<String> {
+        None
+    }
+
+    /// Restores internal state previously serialized by `save_state`.
+    fn restore_state(&mut self, _state: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Returns current weights of heuristic operators with their names, if heuristic adapts them
+    /// during the search. Returns `None` by default.
+    fn get_operator_weights(&self) -> Option<Vec<(String, f64)>> {
+        None
+    }
+
+    /// Returns names of heuristic operators applied during the last search, if heuristic tracks
+    /// them. Returns `None` by default.
+    fn get_last_operators(&self) -> Option<Vec<String>> {
+        None
+    }
 }
 
 /// A selective heuristic which uses dynamic or static selective heuristic depending on search performance.
@@ -76,6 +99,39 @@ where
 
         self.actual.search(heuristic_ctx, solutions)
     }
+
+    fn get_last_operators(&self) -> Option<Vec<String>> {
+        self.actual.get_last_operators()
+    }
+
+    fn save_state(&self) -> Option<String> {
+        // NOTE keep the original order of heuristics regardless of the current search mode
+        let (init, fallback) =
+            if self.is_slow_search { (&self.slow, &self.actual) } else { (&self.actual, &self.slow) };
+
+        match (init.save_state(), fallback.save_state()) {
+            (None, None) => None,
+            (init, fallback) => Some(format!("{}\t{}", init.unwrap_or_default(), fallback.unwrap_or_default())),
+        }
+    }
+
+    fn restore_state(&mut self, state: &str) -> Result<(), String> {
+        let (init, fallback) =
+            state.split_once('\t').ok_or_else(|| "cannot restore multi selective heuristic state".to_string())?;
+
+        let (actual, slow) =
+            if self.is_slow_search { (&mut self.slow, &mut self.actual) } else { (&mut self.actual, &mut self.slow) };
+
+        if !init.is_empty() {
+            actual.restore_state(init)?;
+        }
+
+        if !fallback.is_empty() {
+            slow.restore_state(fallback)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<C, O, S> MultiSelective<C, O, S>
@@ -41,6 +41,10 @@
 #[macro_use]
 pub mod helpers;
 
+#[cfg(test)]
+#[path = "../tests/unit/lib_test.rs"]
+mod lib_test;
+
 pub mod algorithms;
 pub mod evolution;
 pub mod example;
@@ -66,7 +70,13 @@ pub trait HeuristicSolution: Send + Sync {
 }
 
 /// Represents a heuristic objective function.
-pub trait HeuristicObjective: MultiObjective + Send + Sync {}
+pub trait HeuristicObjective: MultiObjective + Send + Sync {
+    /// Returns fitness values of a given solution grouped by objective tiers (levels of objective
+    /// hierarchy). By default, each objective is considered as a separate tier.
+    fn get_tier_fitness(&self, solution: &Self::Solution) -> Vec<Vec<f64>> {
+        self.objectives().map(|objective| vec![objective.fitness(solution)]).collect()
+    }
+}
 
 /// Represents heuristic context.
 pub trait HeuristicContext: Send + Sync {
@@ -161,24 +171,57 @@ pub fn get_default_selection_size(environment: &Environment) -> usize {
     environment.parallelism.available_cpus().min(8)
 }
 
-/// Gets default population algorithm.
+/// Min time budget (in seconds) required to train rosomaxa's network properly.
+const MIN_ROSOMAXA_TIME: f64 = 30.;
+
+/// Max size of single objective problem which is solved using elitism population.
+const SMALL_PROBLEM_SIZE: usize = 20;
+
+/// Gets default population algorithm which is selected automatically based on problem size,
+/// amount of objectives and time budget defined by environment's quota. The decision is logged
+/// using environment's logger. To override it, pass a heuristic context with a different
+/// population to the evolution config builder.
 pub fn get_default_population<C, O, S>(
     objective: Arc<O>,
     environment: Arc<Environment>,
+    problem_size: usize,
 ) -> Box<dyn HeuristicPopulation<Objective = O, Individual = S> + Send + Sync>
 where
     C: HeuristicContext<Objective = O, Solution = S> + 'static,
     O: HeuristicObjective<Solution = S> + Shuffled + 'static,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered + 'static,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered + 'static,
 {
     let selection_size = get_default_selection_size(environment.as_ref());
-    if selection_size == 1 {
-        Box::new(Greedy::new(objective, 1, None))
-    } else {
-        let config = RosomaxaConfig::new_with_defaults(selection_size);
-        let population =
-            Rosomaxa::new(objective, environment, config).expect("cannot create rosomaxa with default configuration");
-
-        Box::new(population)
-    }
+    let objectives = objective.objectives().count();
+    let time_limit = environment.quota.as_ref().and_then(|quota| quota.time_limit());
+
+    let is_short_time = time_limit.map_or(false, |limit| limit < MIN_ROSOMAXA_TIME);
+    let is_small_problem = problem_size < SMALL_PROBLEM_SIZE && objectives < 2;
+
+    let (population, reason): (Box<dyn HeuristicPopulation<Objective = O, Individual = S> + Send + Sync>, _) =
+        if selection_size == 1 {
+            (Box::new(Greedy::new(objective, 1, None)), "greedy due to single selection")
+        } else if is_short_time || is_small_problem {
+            let population = Elitism::new(objective, environment.random.clone(), 4, selection_size);
+            (
+                Box::new(population),
+                if is_short_time { "elitism due to short time" } else { "elitism due to small size" },
+            )
+        } else {
+            let config = RosomaxaConfig::new_with_defaults(selection_size);
+            let population = Rosomaxa::new(objective, environment.clone(), config)
+                .expect("cannot create rosomaxa with default configuration");
+
+            (Box::new(population), "rosomaxa")
+        };
+
+    (environment.logger)(
+        format!(
+            "selected population: {}, problem size: {}, objectives: {}, time limit: {:?}",
+            reason, problem_size, objectives, time_limit
+        )
+        .as_str(),
+    );
+
+    population
 }
@@ -164,6 +164,19 @@ where
         self.individuals.drain(range).collect()
     }
 
+    /// Removes all individuals and returns them together with their rank and fitness values, ordered
+    /// from the best to the worst. Individuals are unique as population does not keep duplicates.
+    pub fn drain_ranked(&mut self) -> Vec<(S, usize, Vec<f64>)> {
+        self.individuals
+            .drain(..)
+            .map(|individual| {
+                let rank = individual.get_order().rank;
+                let fitness = individual.get_fitness().collect();
+                (individual, rank, fitness)
+            })
+            .collect()
+    }
+
     fn sort(&mut self) {
         let objective = self.objective.clone();
 
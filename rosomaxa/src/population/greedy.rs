@@ -30,7 +30,7 @@ where
 
     fn add_all(&mut self, individuals: Vec<Self::Individual>) -> bool {
         #[allow(clippy::unnecessary_fold)]
-        individuals.into_iter().fold(false, |acc, individual| acc || self.add(individual))
+        individuals.into_iter().map(|individual| self.add(individual)).fold(false, |a, b| a | b)
     }
 
     fn add(&mut self, individual: Self::Individual) -> bool {
@@ -101,4 +101,16 @@ where
     pub fn new(objective: Arc<O>, selection_size: usize, best_known: Option<S>) -> Self {
         Self { objective, selection_size, best_known }
     }
+
+    /// Removes the best known individual and returns it together with its rank and fitness values.
+    pub fn drain_ranked(&mut self) -> Vec<(S, usize, Vec<f64>)> {
+        self.best_known
+            .take()
+            .into_iter()
+            .map(|individual| {
+                let fitness = individual.get_fitness().collect();
+                (individual, 0, fitness)
+            })
+            .collect()
+    }
 }
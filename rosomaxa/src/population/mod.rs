@@ -11,15 +11,17 @@ pub use self::greedy::Greedy;
 
 mod rosomaxa;
 pub use self::rosomaxa::Rosomaxa;
+pub use self::rosomaxa::RosomaxaCompressible;
 pub use self::rosomaxa::RosomaxaConfig;
 pub use self::rosomaxa::RosomaxaWeighted;
+pub use self::rosomaxa::WeightReduction;
 
 use crate::prelude::*;
 use std::cmp::Ordering;
 use std::fmt::Display;
 
 /// Specifies a selection phase.
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SelectionPhase {
     /// A phase of building an initial solution(-s).
     Initial,
@@ -62,4 +64,21 @@ pub trait HeuristicPopulation: Display + Send + Sync {
 
     /// Returns a current selection phase.
     fn selection_phase(&self) -> SelectionPhase;
+
+    /// Returns fitness values of all individuals kept by the population, including ones which are
+    /// not part of ranked subset. By default, only ranked individuals are returned.
+    fn get_fitness_snapshot(&self) -> Vec<Vec<f64>> {
+        self.ranked().map(|(individual, _)| individual.get_fitness().collect()).collect()
+    }
+
+    /// Serializes internal state (e.g. learned network weights) in order to create a checkpoint.
+    /// Individuals are not included. Returns `None` if population has no state worth to be saved.
+    fn save_state(&self) -> Option<String> {
+        None
+    }
+
+    /// Restores internal state previously serialized by `save_state`.
+    fn restore_state(&mut self, _state: &str) -> Result<(), String> {
+        Ok(())
+    }
 }
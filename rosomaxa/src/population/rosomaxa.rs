@@ -4,13 +4,13 @@ mod rosomaxa_test;
 
 use super::*;
 use crate::algorithms::gsom::*;
-use crate::algorithms::math::relative_distance;
+use crate::algorithms::math::{get_principal_components, get_stdev, relative_distance};
 use crate::population::elitism::{DominanceOrdered, Shuffled};
 use crate::utils::{Environment, Random};
 use rand::prelude::SliceRandom;
 use std::convert::TryInto;
 use std::fmt::Formatter;
-use std::ops::RangeBounds;
+use std::ops::{Deref, RangeBounds};
 use std::sync::Arc;
 
 /// Specifies rosomaxa configuration settings.
@@ -35,6 +35,10 @@ pub struct RosomaxaConfig {
     pub rebalance_count: usize,
     /// A ratio of exploration phase.
     pub exploration_ratio: f64,
+    /// A batch size used to train GSOM on multiple individuals at once.
+    pub batch_size: usize,
+    /// A reduction of solution weights applied before they are used by GSOM.
+    pub weight_reduction: WeightReduction,
 }
 
 impl RosomaxaConfig {
@@ -52,34 +56,61 @@ impl RosomaxaConfig {
             rebalance_memory: 100,
             rebalance_count: 2,
             exploration_ratio: 0.9,
+            batch_size: selection_size.max(1),
+            weight_reduction: WeightReduction::None,
         }
     }
 }
 
+/// Specifies how solution weights are reduced before they are used to place solutions in GSOM.
+/// With many weight dimensions, e.g. on problems with many objectives, distances between weights
+/// become less distinctive and the network tends to grow without producing meaningful clusters.
+#[derive(Clone, Debug)]
+pub enum WeightReduction {
+    /// Weights are used as is.
+    None,
+    /// Only weights with given indices are used. Indices are checked against weights of solutions
+    /// known when network is created: if any is out of range, weights are used as is.
+    Subset(Vec<usize>),
+    /// Weights are projected on given amount of principal components. Components are estimated only
+    /// once, using solutions known when network is created (at least four), and are not refitted
+    /// later, so the projection reflects the initial part of the search.
+    Pca(usize),
+}
+
 /// Specifies behavior which returns a weights used to distinguish different solutions.
 pub trait RosomaxaWeighted {
     /// Returns a weights used to distinguish different solutions.
     fn weights(&self) -> Vec<f64>;
 }
 
+/// Specifies behavior which allows to reduce memory footprint of solutions stored in network nodes.
+/// A compressed solution should still be comparable using objective and weights, but all other data
+/// can be restored lazily, e.g. when the solution is selected and deep copied.
+pub trait RosomaxaCompressible {
+    /// Compresses solution. Default implementation does nothing.
+    fn compress(&mut self) {}
+}
+
 /// Implements custom algorithm, code name Routing Optimizations with Self Organizing
 /// MAps and eXtrAs (pronounced as "rosomaha", from russian "росомаха" - "wolverine").
 pub struct Rosomaxa<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     objective: Arc<O>,
     environment: Arc<Environment>,
     config: RosomaxaConfig,
     elite: Elitism<O, S>,
     phase: RosomaxaPhases<O, S>,
+    restored_nodes: Option<Vec<(Coordinate, Vec<f64>)>>,
 }
 
 impl<O, S> HeuristicPopulation for Rosomaxa<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     type Objective = O;
     type Individual = S;
@@ -98,8 +129,10 @@ where
             RosomaxaPhases::Initial { solutions: known_individuals } => {
                 known_individuals.extend(individuals.into_iter())
             }
-            RosomaxaPhases::Exploration { network, statistics, .. } => {
-                network.store_batch(individuals, statistics.generation, IndividualInput::new);
+            RosomaxaPhases::Exploration { network, statistics, reducer, .. } => {
+                network.store_batch(individuals, statistics.generation, |individual| {
+                    IndividualInput::new(individual, reducer.as_ref())
+                });
             }
             RosomaxaPhases::Exploitation { .. } => {}
         }
@@ -117,8 +150,8 @@ where
 
         match &mut self.phase {
             RosomaxaPhases::Initial { solutions: individuals } => individuals.push(individual),
-            RosomaxaPhases::Exploration { network, statistics, .. } => {
-                network.store(IndividualInput::new(individual), statistics.generation)
+            RosomaxaPhases::Exploration { network, statistics, reducer, .. } => {
+                network.store(IndividualInput::new(individual, reducer.as_ref()), statistics.generation)
             }
             RosomaxaPhases::Exploitation { .. } => {}
         }
@@ -191,6 +224,74 @@ where
             RosomaxaPhases::Exploitation { .. } => SelectionPhase::Exploitation,
         }
     }
+
+    fn get_fitness_snapshot(&self) -> Vec<Vec<f64>> {
+        let get_fitness = |population: &Elitism<O, S>| {
+            population.ranked().map(|(individual, _)| individual.get_fitness().collect()).collect::<Vec<_>>()
+        };
+
+        let mut snapshot = get_fitness(&self.elite);
+
+        if let RosomaxaPhases::Exploration { network, .. } = &self.phase {
+            network.get_nodes().for_each(|node| snapshot.extend(get_fitness(&node.read().unwrap().storage.population)));
+        }
+
+        snapshot
+    }
+
+    fn save_state(&self) -> Option<String> {
+        let mut nodes = match (&self.phase, &self.restored_nodes) {
+            (RosomaxaPhases::Exploration { network, .. }, _) => network
+                .iter()
+                .map(|(coordinate, node)| (coordinate.clone(), node.read().unwrap().weights.clone()))
+                .collect::<Vec<_>>(),
+            (RosomaxaPhases::Initial { .. }, Some(nodes)) => nodes.clone(),
+            _ => return None,
+        };
+        nodes.sort_by(|(Coordinate(a_x, a_y), _), (Coordinate(b_x, b_y), _)| (a_x, a_y).cmp(&(b_x, b_y)));
+
+        let nodes = nodes
+            .iter()
+            .map(|(Coordinate(x, y), weights)| {
+                let weights = weights.iter().map(|weight| weight.to_string()).collect::<Vec<_>>();
+                format!("{},{}:{}", x, y, weights.join(","))
+            })
+            .collect::<Vec<_>>();
+
+        Some(nodes.join(";"))
+    }
+
+    fn restore_state(&mut self, state: &str) -> Result<(), String> {
+        let parse_err = |part: &str| format!("cannot parse rosomaxa network state: '{}'", part);
+
+        let nodes = state
+            .split(';')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let (coordinate, weights) = part.split_once(':').ok_or_else(|| parse_err(part))?;
+                let (x, y) = coordinate.split_once(',').ok_or_else(|| parse_err(part))?;
+                let coordinate = Coordinate(
+                    x.parse::<i32>().map_err(|_| parse_err(part))?,
+                    y.parse::<i32>().map_err(|_| parse_err(part))?,
+                );
+                let weights = weights
+                    .split(',')
+                    .map(|weight| weight.parse::<f64>().map_err(|_| parse_err(part)))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok((coordinate, weights))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        match &mut self.phase {
+            RosomaxaPhases::Exploration { network, .. } => network.restore(nodes),
+            _ => {
+                // NOTE network is created lazily, so nodes are applied once it is built
+                self.restored_nodes = Some(nodes);
+                Ok(())
+            }
+        }
+    }
 }
 
 type IndividualNetwork<O, S> = Network<IndividualInput<S>, IndividualStorage<O, S>, IndividualStorageFactory<O, S>>;
@@ -198,23 +299,62 @@ type IndividualNetwork<O, S> = Network<IndividualInput<S>, IndividualStorage<O,
 impl<O, S> Rosomaxa<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     /// Creates a new instance of `Rosomaxa`.
     pub fn new(objective: Arc<O>, environment: Arc<Environment>, config: RosomaxaConfig) -> Result<Self, String> {
-        if config.elite_size < 1 || config.node_size < 1 || config.selection_size < 2 {
+        if config.elite_size < 1 || config.node_size < 1 || config.selection_size < 2 || config.batch_size < 1 {
             return Err("Rosomaxa algorithm requires some parameters to be above thresholds".to_string());
         }
 
+        match &config.weight_reduction {
+            WeightReduction::Subset(indices) if indices.is_empty() => {
+                return Err("Rosomaxa weight reduction requires non-empty subset of weights".to_string())
+            }
+            WeightReduction::Pca(dimensions) if *dimensions < 1 => {
+                return Err("Rosomaxa weight reduction requires at least one principal component".to_string())
+            }
+            _ => {}
+        }
+
         Ok(Self {
             objective: objective.clone(),
             environment: environment.clone(),
             elite: Elitism::new(objective, environment.random.clone(), config.elite_size, config.selection_size),
             phase: RosomaxaPhases::Initial { solutions: vec![] },
+            restored_nodes: None,
             config,
         })
     }
 
+    /// Removes all individuals kept in elite and network nodes and returns them together with their
+    /// rank and fitness values, ordered from the best to the worst. Individuals are ranked together,
+    /// so duplicates between elite and network nodes are removed. Learned network state is dropped
+    /// too, so it should be saved beforehand if needed.
+    pub fn drain_ranked(&mut self) -> Vec<(S, usize, Vec<f64>)> {
+        let mut individuals = self.elite.drain(..);
+
+        // NOTE population starts from scratch, but learned network state can be saved beforehand
+        match std::mem::replace(&mut self.phase, RosomaxaPhases::Initial { solutions: vec![] }) {
+            RosomaxaPhases::Initial { solutions } => individuals.extend(solutions),
+            RosomaxaPhases::Exploration { network, .. } => network
+                .get_nodes()
+                .for_each(|node| individuals.extend(node.write().unwrap().storage.population.drain(..))),
+            RosomaxaPhases::Exploitation { .. } => {}
+        }
+
+        // NOTE rank individuals from elite and network nodes together
+        let mut population = Elitism::new(
+            self.objective.clone(),
+            self.environment.random.clone(),
+            individuals.len().max(1),
+            self.config.selection_size,
+        );
+        population.add_all(individuals);
+
+        population.drain_ranked()
+    }
+
     fn update_phase(&mut self, statistics: &HeuristicStatistics) {
         let selection_size = match statistics.speed {
             HeuristicSpeed::Slow(ratio) => (self.config.selection_size as f64 * ratio).max(1.).round() as usize,
@@ -224,16 +364,32 @@ where
         match &mut self.phase {
             RosomaxaPhases::Initial { solutions: individuals, .. } => {
                 if individuals.len() >= 4 {
+                    let reducer = WeightReducer::new(&self.config.weight_reduction, individuals.as_slice())
+                        .unwrap_or_else(|err| {
+                            self.environment.logger.deref()(&format!("cannot apply weight reduction: {}", err));
+                            WeightReducer::Identity
+                        });
+                    let reducer = Arc::new(reducer);
                     let mut network = Self::create_network(
                         self.objective.clone(),
                         self.environment.clone(),
                         &self.config,
+                        reducer.clone(),
                         individuals.drain(0..4).collect(),
                     );
-                    individuals.drain(0..).for_each(|individual| network.store(IndividualInput::new(individual), 0));
+                    individuals
+                        .drain(0..)
+                        .for_each(|individual| network.store(IndividualInput::new(individual, reducer.as_ref()), 0));
+
+                    if let Some(nodes) = self.restored_nodes.take() {
+                        if let Err(err) = network.restore(nodes) {
+                            self.environment.logger.deref()(&format!("cannot apply restored state: {}", err));
+                        }
+                    }
 
                     self.phase = RosomaxaPhases::Exploration {
                         network,
+                        reducer,
                         coordinates: vec![],
                         statistics: statistics.clone(),
                         selection_size,
@@ -245,6 +401,7 @@ where
                 coordinates,
                 statistics: old_statistics,
                 selection_size: old_selection_size,
+                ..
             } => {
                 let exploration_ratio = match old_statistics.speed {
                     HeuristicSpeed::Slow(ratio) => self.config.exploration_ratio * ratio,
@@ -392,9 +549,13 @@ where
         objective: Arc<O>,
         environment: Arc<Environment>,
         config: &RosomaxaConfig,
+        reducer: Arc<WeightReducer>,
         individuals: Vec<S>,
     ) -> IndividualNetwork<O, S> {
-        let inputs_vec = individuals.into_iter().map(IndividualInput::new).collect::<Vec<_>>();
+        let inputs_vec = individuals
+            .into_iter()
+            .map(|individual| IndividualInput::new(individual, reducer.as_ref()))
+            .collect::<Vec<_>>();
 
         let inputs_slice = inputs_vec.into_boxed_slice();
         let inputs_array: Box<[IndividualInput<S>; 4]> = match inputs_slice.try_into() {
@@ -407,6 +568,7 @@ where
             reshuffling_probability: config.objective_reshuffling,
             random: environment.random.clone(),
             objective,
+            reducer,
         };
 
         Network::new(
@@ -417,6 +579,7 @@ where
                 learning_rate: config.learning_rate,
                 rebalance_memory: config.rebalance_memory,
                 has_initial_error: true,
+                batch_size: config.batch_size,
             },
             storage_factory,
         )
@@ -426,7 +589,7 @@ where
 impl<O, S> Display for Rosomaxa<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match &self.phase {
@@ -443,13 +606,14 @@ where
 enum RosomaxaPhases<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     Initial {
         solutions: Vec<S>,
     },
     Exploration {
         network: IndividualNetwork<O, S>,
+        reducer: Arc<WeightReducer>,
         coordinates: Vec<(Coordinate, f64, usize)>,
         statistics: HeuristicStatistics,
         selection_size: usize,
@@ -461,7 +625,7 @@ where
 
 struct IndividualInput<S>
 where
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     weights: Vec<f64>,
     individual: S,
@@ -469,16 +633,16 @@ where
 
 impl<S> IndividualInput<S>
 where
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
-    pub fn new(individual: S) -> Self {
-        Self { weights: individual.weights(), individual }
+    pub fn new(individual: S, reducer: &WeightReducer) -> Self {
+        Self { weights: reducer.reduce(individual.weights()), individual }
     }
 }
 
 impl<S> Input for IndividualInput<S>
 where
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     fn weights(&self) -> &[f64] {
         self.weights.as_slice()
@@ -488,52 +652,61 @@ where
 struct IndividualStorageFactory<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     node_size: usize,
     reshuffling_probability: f64,
     random: Arc<dyn Random + Send + Sync>,
     objective: Arc<O>,
+    reducer: Arc<WeightReducer>,
 }
 
 impl<O, S> StorageFactory<IndividualInput<S>, IndividualStorage<O, S>> for IndividualStorageFactory<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     fn eval(&self) -> IndividualStorage<O, S> {
         let mut elitism = Elitism::new(self.objective.clone(), self.random.clone(), self.node_size, self.node_size);
         if self.random.is_hit(self.reshuffling_probability) {
             elitism.shuffle_objective();
         }
-        IndividualStorage { population: elitism }
+        IndividualStorage { population: elitism, reducer: self.reducer.clone() }
     }
 }
 
 struct IndividualStorage<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     population: Elitism<O, S>,
+    reducer: Arc<WeightReducer>,
 }
 
 impl<O, S> Storage for IndividualStorage<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     type Item = IndividualInput<S>;
 
     fn add(&mut self, input: Self::Item) {
-        self.population.add(input.individual);
+        let mut individual = input.individual;
+        individual.compress();
+
+        self.population.add(individual);
     }
 
     fn drain<R>(&mut self, range: R) -> Vec<Self::Item>
     where
         R: RangeBounds<usize>,
     {
-        self.population.drain(range).into_iter().map(IndividualInput::new).collect()
+        self.population
+            .drain(range)
+            .into_iter()
+            .map(|individual| IndividualInput::new(individual, self.reducer.as_ref()))
+            .collect()
     }
 
     fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
@@ -548,9 +721,81 @@ where
 impl<O, S> Display for IndividualStorage<O, S>
 where
     O: HeuristicObjective<Solution = S> + Shuffled,
-    S: HeuristicSolution + RosomaxaWeighted + DominanceOrdered,
+    S: HeuristicSolution + RosomaxaWeighted + RosomaxaCompressible + DominanceOrdered,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.population)
     }
 }
+
+/// Reduces dimensionality of solution weights according to `WeightReduction` settings.
+enum WeightReducer {
+    Identity,
+    Subset(Vec<usize>),
+    Projection { scales: Vec<f64>, components: Vec<Vec<f64>> },
+}
+
+impl WeightReducer {
+    fn new<S: RosomaxaWeighted>(reduction: &WeightReduction, individuals: &[S]) -> Result<Self, String> {
+        match reduction {
+            WeightReduction::None => Ok(Self::Identity),
+            WeightReduction::Subset(indices) => {
+                let dimension = individuals.first().map_or(0, |individual| individual.weights().len());
+
+                match indices.iter().find(|&&idx| idx >= dimension) {
+                    Some(idx) => Err(format!("subset index {} is out of range, weights size is {}", idx, dimension)),
+                    None => Ok(Self::Subset(indices.clone())),
+                }
+            }
+            WeightReduction::Pca(dimensions) => {
+                let samples = individuals.iter().map(|individual| individual.weights()).collect::<Vec<_>>();
+                let dimension = samples.first().map_or(0, |weights| weights.len());
+
+                if dimension <= *dimensions {
+                    return Ok(Self::Identity);
+                }
+
+                // NOTE weights have different scales, so standardize them before estimating components
+                let scales = (0..dimension)
+                    .map(|idx| {
+                        let stdev =
+                            get_stdev(samples.iter().map(|weights| weights[idx]).collect::<Vec<_>>().as_slice());
+                        if stdev > 0. {
+                            1. / stdev
+                        } else {
+                            0.
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let samples =
+                    samples.iter().map(|weights| scale(weights.as_slice(), scales.as_slice())).collect::<Vec<_>>();
+                let components = get_principal_components(samples.as_slice(), *dimensions);
+
+                if components.is_empty() {
+                    Ok(Self::Identity)
+                } else {
+                    Ok(Self::Projection { scales, components })
+                }
+            }
+        }
+    }
+
+    fn reduce(&self, weights: Vec<f64>) -> Vec<f64> {
+        match self {
+            Self::Identity => weights,
+            Self::Subset(indices) => indices.iter().filter_map(|idx| weights.get(*idx).cloned()).collect(),
+            // NOTE weights are not centered to keep their magnitude as relative distance is used by network
+            Self::Projection { scales, components } => {
+                let weights = scale(weights.as_slice(), scales.as_slice());
+                components
+                    .iter()
+                    .map(|component| component.iter().zip(weights.iter()).map(|(a, b)| a * b).sum())
+                    .collect()
+            }
+        }
+    }
+}
+
+fn scale(weights: &[f64], scales: &[f64]) -> Vec<f64> {
+    weights.iter().zip(scales.iter()).map(|(weight, scale)| weight * scale).collect()
+}
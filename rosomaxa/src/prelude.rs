@@ -10,12 +10,14 @@ pub use crate::Stateful;
 pub use crate::evolution::EvolutionConfig;
 pub use crate::evolution::EvolutionConfigBuilder;
 pub use crate::evolution::HeuristicContextProcessing;
+pub use crate::evolution::HeuristicPhaseProcessing;
 pub use crate::evolution::HeuristicSolutionProcessing;
 pub use crate::evolution::InitialOperators;
 
 pub use crate::population::HeuristicPopulation;
 pub use crate::population::RosomaxaConfig;
 pub use crate::population::SelectionPhase;
+pub use crate::population::WeightReduction;
 
 pub use crate::hyper::HeuristicOperator;
 pub use crate::hyper::HyperHeuristic;
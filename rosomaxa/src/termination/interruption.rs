@@ -0,0 +1,58 @@
+#[cfg(test)]
+#[path = "../../tests/unit/termination/interruption_test.rs"]
+mod interruption_test;
+
+use super::*;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A termination criteria which is in terminated state when interruption flag is set externally,
+/// e.g. by a hosting service which wants to abort the search and get the best known solution.
+pub struct Interruption<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    interrupt: Arc<AtomicBool>,
+    _marker: (PhantomData<C>, PhantomData<O>, PhantomData<S>),
+}
+
+impl<C, O, S> Interruption<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `Interruption`.
+    pub fn new(interrupt: Arc<AtomicBool>) -> Self {
+        Self { interrupt, _marker: (Default::default(), Default::default(), Default::default()) }
+    }
+
+    fn is_interrupted(&self) -> bool {
+        self.interrupt.load(Ordering::Relaxed)
+    }
+}
+
+impl<C, O, S> Termination for Interruption<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+
+    fn is_termination(&self, _: &mut Self::Context) -> bool {
+        self.is_interrupted()
+    }
+
+    fn estimate(&self, _: &Self::Context) -> f64 {
+        if self.is_interrupted() {
+            1.
+        } else {
+            0.
+        }
+    }
+}
@@ -16,7 +16,7 @@ where
     C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
     O: HeuristicObjective<Solution = S>,
     S: HeuristicSolution,
-    K: Hash + Eq + Clone,
+    K: Hash + Eq + Clone + Send + Sync,
 {
     interval_type: IntervalType,
     threshold: f64,
@@ -35,7 +35,7 @@ where
     C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
     O: HeuristicObjective<Solution = S>,
     S: HeuristicSolution,
-    K: Hash + Eq + Clone,
+    K: Hash + Eq + Clone + Send + Sync,
 {
     /// Creates a new instance of `MinVariation` with sample interval type.
     pub fn new_with_sample(sample: usize, threshold: f64, is_global: bool, key: K) -> Self {
@@ -143,7 +143,7 @@ where
     C: HeuristicContext<Objective = O, Solution = S> + Stateful<Key = K>,
     O: HeuristicObjective<Solution = S>,
     S: HeuristicSolution,
-    K: Hash + Eq + Clone,
+    K: Hash + Eq + Clone + Send + Sync,
 {
     type Context = C;
     type Objective = O;
@@ -4,7 +4,7 @@
 use crate::prelude::*;
 
 /// A trait which specifies criteria when metaheuristic should stop searching for improved solution.
-pub trait Termination {
+pub trait Termination: Send {
     /// A heuristic objective function type.
     type Context: HeuristicContext<Objective = Self::Objective>;
 
@@ -27,6 +27,9 @@ pub use self::max_generation::MaxGeneration;
 mod max_time;
 pub use self::max_time::MaxTime;
 
+mod interruption;
+pub use self::interruption::Interruption;
+
 mod target_proximity;
 pub use self::target_proximity::TargetProximity;
 
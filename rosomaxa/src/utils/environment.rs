@@ -1,7 +1,8 @@
 //! Contains environment specific logic.
 
 use crate::utils::{DefaultRandom, Random, ThreadPool, Timer};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// A logger type which is called with various information.
 pub type InfoLogger = Arc<dyn Fn(&str) + Send + Sync>;
@@ -11,6 +12,11 @@ pub type InfoLogger = Arc<dyn Fn(&str) + Send + Sync>;
 pub trait Quota: Send + Sync {
     /// Returns true when computation should be stopped.
     fn is_reached(&self) -> bool;
+
+    /// Returns time limit in seconds if quota is time based.
+    fn time_limit(&self) -> Option<f64> {
+        None
+    }
 }
 
 /// Keeps track of environment specific information which influences algorithm behavior.
@@ -30,6 +36,13 @@ pub struct Environment {
 
     /// A boolean flag which signalizes that experimental behavior is allowed.
     pub is_experimental: bool,
+
+    /// A boolean flag which signalizes that any internal inconsistency should stop algorithm
+    /// immediately with an error instead of producing a corrupted result. Default is false.
+    pub is_strict: bool,
+
+    /// Keeps track of internal inconsistency detected in strict mode.
+    pub strict_violation: Arc<StrictViolation>,
 }
 
 impl Environment {
@@ -49,7 +62,15 @@ impl Environment {
         logger: InfoLogger,
         is_experimental: bool,
     ) -> Self {
-        Self { random, quota, parallelism, logger, is_experimental }
+        Self {
+            random,
+            quota,
+            parallelism,
+            logger,
+            is_experimental,
+            is_strict: false,
+            strict_violation: Arc::new(StrictViolation::default()),
+        }
     }
 }
 
@@ -65,6 +86,37 @@ impl Default for Environment {
     }
 }
 
+/// Keeps the first internal inconsistency reported in strict mode, see `Environment::is_strict`.
+#[derive(Default)]
+pub struct StrictViolation {
+    flag: Arc<AtomicBool>,
+    message: Mutex<Option<String>>,
+}
+
+impl StrictViolation {
+    /// Reports internal inconsistency. Only the first reported message is kept.
+    pub fn report(&self, message: String) {
+        let mut current = self.message.lock().unwrap();
+        if current.is_none() {
+            *current = Some(message);
+            self.flag.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Returns a flag which is set when any inconsistency is reported.
+    pub fn flag(&self) -> Arc<AtomicBool> {
+        self.flag.clone()
+    }
+
+    /// Takes reported message, if any, and resets the state.
+    pub fn take(&self) -> Option<String> {
+        let message = self.message.lock().unwrap().take();
+        self.flag.store(false, Ordering::Relaxed);
+
+        message
+    }
+}
+
 /// A time quota.
 pub struct TimeQuota {
     start: Timer,
@@ -82,6 +134,10 @@ impl Quota for TimeQuota {
     fn is_reached(&self) -> bool {
         self.start.elapsed_secs_as_f64() > self.limit_in_secs
     }
+
+    fn time_limit(&self) -> Option<f64> {
+        Some(self.limit_in_secs)
+    }
 }
 
 /// Specifies data parallelism settings.
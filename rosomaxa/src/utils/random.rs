@@ -3,6 +3,7 @@
 mod random_test;
 
 use rand::prelude::*;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Provides the way to use randomized values in generic way.
 pub trait Random {
@@ -49,6 +50,20 @@ pub trait Random {
             .1
     }
 
+    /// Returns a seed used to initialize RNG, if it is specified.
+    fn get_seed(&self) -> Option<u64> {
+        None
+    }
+
+    /// Returns a state of random generator which can be used to continue the same sequence of
+    /// random values later, see `restore_state`. Returns none if generator is not reproducible.
+    fn save_state(&self) -> Option<u64> {
+        None
+    }
+
+    /// Restores a state of random generator returned by `save_state`.
+    fn restore_state(&self, _state: u64) {}
+
     /// Returns RNG.
     fn get_rng(&self) -> StdRng;
 }
@@ -57,19 +72,36 @@ pub trait Random {
 #[derive(Default)]
 pub struct DefaultRandom {
     seed: Option<u64>,
+    sequence: AtomicU64,
 }
 
 impl DefaultRandom {
-    /// Creates a new instance `DefaultRandom` with seed.
+    /// Creates a new instance `DefaultRandom` with seed. Each RNG is initialized with the next value
+    /// of the sequence derived from the seed, so the same values are produced as long as RNGs are
+    /// requested in the same order.
     pub fn new_with_seed(seed: u64) -> Self {
-        Self { seed: Some(seed) }
+        Self { seed: Some(seed), sequence: AtomicU64::new(0) }
     }
 }
 
 impl Random for DefaultRandom {
+    fn get_seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    fn save_state(&self) -> Option<u64> {
+        self.seed.map(|_| self.sequence.load(Ordering::Relaxed))
+    }
+
+    fn restore_state(&self, state: u64) {
+        self.sequence.store(state, Ordering::Relaxed);
+    }
+
     fn get_rng(&self) -> StdRng {
         if let Some(ref seed) = self.seed {
-            StdRng::seed_from_u64(*seed)
+            // NOTE sequence value is multiplied by an odd constant to avoid overlapping of sequences with close seeds
+            let sequence = self.sequence.fetch_add(1, Ordering::Relaxed);
+            StdRng::seed_from_u64(seed.wrapping_add(sequence.wrapping_mul(0x9E37_79B9_7F4A_7C15)))
         } else {
             StdRng::from_rng(thread_rng()).expect("cannot get RNG")
         }
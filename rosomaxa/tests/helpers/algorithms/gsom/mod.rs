@@ -81,6 +81,7 @@ pub fn create_test_network(has_initial_error: bool) -> Network<Data, DataStorage
             learning_rate: 0.1,
             rebalance_memory: 500,
             has_initial_error,
+            batch_size: 4,
         },
         DataStorageFactory,
     )
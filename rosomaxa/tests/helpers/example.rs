@@ -16,7 +16,9 @@ pub fn create_heuristic_context_with_solutions(
     let environment = Arc::new(Environment::default());
     let objective = Arc::new(VectorObjective::new(objective_func));
 
-    let mut population = get_default_population::<VectorContext, _, _>(objective.clone(), environment.clone());
+    let dimension = solutions.first().map_or(0, |data| data.len());
+    let mut population =
+        get_default_population::<VectorContext, _, _>(objective.clone(), environment.clone(), dimension);
 
     let solutions = solutions.into_iter().map(|data| VectorSolution::new(data, objective.clone())).collect();
     population.add_all(solutions);
@@ -1,3 +1,5 @@
+use super::get_mean_weights;
+use crate::algorithms::gsom::Coordinate;
 use crate::helpers::algorithms::gsom::{create_test_network, Data};
 use crate::utils::{DefaultRandom, Random};
 
@@ -44,3 +46,71 @@ fn can_use_initial_error_parameter_impl(has_initial_error: bool, size: usize) {
 
     assert_eq!(network.size(), size);
 }
+
+parameterized_test! {can_train_network_using_batches, batch_size, {
+    can_train_network_using_batches_impl(batch_size);
+}}
+
+can_train_network_using_batches! {
+    case01: 1,
+    case02: 4,
+    case03: 64,
+}
+
+fn can_train_network_using_batches_impl(batch_size: usize) {
+    let mut network = create_test_network(false);
+    network.batch_size = batch_size;
+    let samples = (0..30)
+        .map(|idx| match idx % 3 {
+            0 => Data::new(1.0, 0.0, 0.0),
+            1 => Data::new(0.0, 1.0, 0.0),
+            _ => Data::new(0.0, 0.0, 1.0),
+        })
+        .collect::<Vec<_>>();
+
+    network.store_batch(samples, 1, |data| data);
+
+    assert_eq!(network.get_current_time(), 1);
+    assert_eq!(network.get_nodes().map(|node| node.read().unwrap().total_hits).sum::<usize>(), 30);
+}
+
+#[test]
+fn can_get_mean_weights() {
+    let inputs = vec![Data::new(1.0, 0.0, 2.0), Data::new(0.0, 1.0, 4.0)];
+
+    let weights = get_mean_weights(inputs.as_slice(), 3);
+
+    assert_eq!(weights, vec![0.5, 0.5, 3.0]);
+}
+
+#[test]
+fn can_restore_network() {
+    let mut network = create_test_network(false);
+    let nodes =
+        (0..3).flat_map(|x| (0..2).map(move |y| (Coordinate(x, y), vec![x as f64, y as f64, 0.]))).collect::<Vec<_>>();
+
+    network.restore(nodes).unwrap();
+
+    assert_eq!(network.size(), 6);
+    let node = network.find(&Coordinate(1, 1)).unwrap().read().unwrap();
+    assert!(node.topology.left.is_some() && node.topology.right.is_some() && node.topology.down.is_some());
+    assert!(node.topology.up.is_none());
+    assert_eq!(node.weights.iter().map(|v| v.round()).collect::<Vec<_>>(), vec![1., 1., 0.]);
+}
+
+parameterized_test! {can_reject_invalid_network_state, nodes, {
+    can_reject_invalid_network_state_impl(nodes);
+}}
+
+can_reject_invalid_network_state! {
+    case01_too_few_nodes: vec![(Coordinate(0, 0), vec![0., 0., 0.])],
+    case02_wrong_dimension: (0..4).map(|x| (Coordinate(x, 0), vec![0., 0.])).collect(),
+    case03_duplicates: (0..4).map(|_| (Coordinate(0, 0), vec![0., 0., 0.])).collect(),
+}
+
+fn can_reject_invalid_network_state_impl(nodes: Vec<(Coordinate, Vec<f64>)>) {
+    let mut network = create_test_network(false);
+
+    assert!(network.restore(nodes).is_err());
+    assert_eq!(network.size(), 4);
+}
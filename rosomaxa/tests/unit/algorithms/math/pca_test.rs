@@ -0,0 +1,39 @@
+use super::*;
+
+fn assert_component(actual: &[f64], expected: &[f64]) {
+    // NOTE component direction is defined up to its sign
+    let sign = if actual.iter().zip(expected.iter()).map(|(a, b)| a * b).sum::<f64>() < 0. { -1. } else { 1. };
+
+    actual
+        .iter()
+        .zip(expected.iter())
+        .for_each(|(a, b)| assert!((sign * a - b).abs() < 1E-6, "{:?} vs {:?}", actual, expected));
+}
+
+#[test]
+fn can_get_principal_components_of_correlated_data() {
+    let samples = vec![vec![1., 2., 5.], vec![2., 4., 5.], vec![3., 6., 5.], vec![4., 8., 5.]];
+
+    let components = get_principal_components(samples.as_slice(), 3);
+
+    assert_eq!(components.len(), 1);
+    assert_component(components[0].as_slice(), &[1. / 5_f64.sqrt(), 2. / 5_f64.sqrt(), 0.]);
+}
+
+#[test]
+fn can_get_principal_components_in_order_of_variance() {
+    let samples = vec![vec![1., 10.], vec![-1., 10.], vec![1., -10.], vec![-1., -10.]];
+
+    let components = get_principal_components(samples.as_slice(), 2);
+
+    assert_eq!(components.len(), 2);
+    assert_component(components[0].as_slice(), &[0., 1.]);
+    assert_component(components[1].as_slice(), &[1., 0.]);
+}
+
+#[test]
+fn can_handle_degenerate_samples() {
+    assert!(get_principal_components(&[vec![1., 2.]], 1).is_empty());
+    assert!(get_principal_components(&[vec![1., 2.], vec![1., 2.]], 1).is_empty());
+    assert!(get_principal_components(&[vec![1., 2.], vec![1.]], 1).is_empty());
+}
@@ -0,0 +1,91 @@
+use super::*;
+use crate::example::*;
+use crate::helpers::utils::create_test_random;
+use crate::helpers::utils::random::FakeRandom;
+use std::sync::Arc;
+
+parameterized_test! {can_use_simulated_annealing, (relative_delta, progress, random_value, expected), {
+    can_use_simulated_annealing_impl(relative_delta, progress, random_value, expected);
+}}
+
+can_use_simulated_annealing! {
+    case_01: (0.5, 0., 0.5, true),
+    case_02: (0.5, 0., 0.7, false),
+    case_03: (0.5, 1., 0.01, false),
+    case_04: (0.001, 1., 0.5, true),
+}
+
+fn can_use_simulated_annealing_impl(relative_delta: f64, progress: f64, random_value: f64, expected: bool) {
+    let random = FakeRandom::new(vec![], vec![random_value]);
+    let acceptance = SimulatedAnnealing::new(1., 0.01).unwrap();
+
+    let result = acceptance.is_accepted(relative_delta, progress, &random);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_use_threshold_accepting, (relative_delta, progress, expected), {
+    can_use_threshold_accepting_impl(relative_delta, progress, expected);
+}}
+
+can_use_threshold_accepting! {
+    case_01: (0.05, 0., true),
+    case_02: (0.05, 0.5, true),
+    case_03: (0.06, 0.5, false),
+    case_04: (0.05, 1., false),
+}
+
+fn can_use_threshold_accepting_impl(relative_delta: f64, progress: f64, expected: bool) {
+    let acceptance = ThresholdAccepting::new(0.1, 0.).unwrap();
+
+    let result = acceptance.is_accepted(relative_delta, progress, create_test_random().as_ref());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_decrease_temperature_geometrically() {
+    let acceptance = SimulatedAnnealing::new(1., 0.01).unwrap();
+
+    assert!((acceptance.get_temperature(0.) - 1.).abs() < 1E-9);
+    assert!((acceptance.get_temperature(0.5) - 0.1).abs() < 1E-9);
+    assert!((acceptance.get_temperature(1.) - 0.01).abs() < 1E-9);
+    assert!((acceptance.get_temperature(2.) - 0.01).abs() < 1E-9);
+}
+
+#[test]
+fn can_reject_invalid_schedules() {
+    assert!(SimulatedAnnealing::new(0., 0.).is_err());
+    assert!(SimulatedAnnealing::new(0.1, 1.).is_err());
+    assert!(ThresholdAccepting::new(-0.1, 0.).is_err());
+    assert!(ThresholdAccepting::new(0.1, 0.2).is_err());
+}
+
+parameterized_test! {can_accept_candidate, (candidate, progress, expected), {
+    can_accept_candidate_impl(candidate, progress, expected);
+}}
+
+can_accept_candidate! {
+    case_01_better: (vec![0.5, 1.], 1., true),
+    case_02_equal: (vec![1., 1.], 1., true),
+    case_03_worse_early: (vec![1., 1.5], 0., true),
+    case_04_worse_late: (vec![1., 1.5], 1., false),
+}
+
+fn can_accept_candidate_impl(candidate: Vec<f64>, progress: f64, expected: bool) {
+    let objective = Arc::new(VectorObjective::new(Arc::new(|data: &[f64]| data.iter().sum())));
+    let acceptance = ThresholdAccepting::new(0.3, 0.).unwrap();
+    let current = VectorSolution::new(vec![1., 1.], objective.clone());
+    let candidate = VectorSolution::new(candidate, objective.clone());
+
+    let result = is_candidate_accepted(
+        objective.as_ref(),
+        &acceptance,
+        &candidate,
+        &current,
+        progress,
+        create_test_random().as_ref(),
+    );
+
+    assert_eq!(result, expected);
+}
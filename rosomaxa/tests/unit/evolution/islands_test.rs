@@ -0,0 +1,65 @@
+use super::*;
+use crate::evolution::TelemetryMode;
+use crate::example::*;
+use crate::helpers::example::{create_default_heuristic_context, create_heuristic_context_with_solutions};
+use crate::termination::MaxGeneration;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+struct NoopHeuristic;
+
+impl HyperHeuristic for NoopHeuristic {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&mut self, _: &Self::Context, _: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        vec![]
+    }
+}
+
+fn create_island(data: Vec<f64>) -> Mutex<Island<VectorContext, VectorObjective, VectorSolution>> {
+    let context = create_heuristic_context_with_solutions(vec![data], create_rosenbrock_function());
+
+    Mutex::new(Island::new(context, Box::new(NoopHeuristic), Telemetry::new(TelemetryMode::None)))
+}
+
+fn get_best_data(island: &mut Mutex<Island<VectorContext, VectorObjective, VectorSolution>>) -> Vec<f64> {
+    island.get_mut().unwrap().get_best().expect("no best").data
+}
+
+#[test]
+fn can_migrate_best_individuals_in_ring() {
+    let mut islands = vec![create_island(vec![2., 2.]), create_island(vec![1., 1.]), create_island(vec![3., 3.])];
+
+    migrate(islands.as_mut_slice());
+
+    assert_eq!(get_best_data(&mut islands[0]), vec![2., 2.]);
+    assert_eq!(get_best_data(&mut islands[1]), vec![1., 1.]);
+    assert_eq!(get_best_data(&mut islands[2]), vec![1., 1.]);
+}
+
+#[test]
+fn can_run_islands_and_return_best_individual() {
+    let factory_calls = Arc::new(AtomicUsize::new(0));
+    let island_factory: IslandFactory<_, _, _> = Box::new({
+        let factory_calls = factory_calls.clone();
+        move |main_ctx: &VectorContext| {
+            // NOTE main island context is passed to the factory
+            assert_eq!(main_ctx.population().size(), 1);
+            factory_calls.fetch_add(1, Ordering::Relaxed);
+            let heuristic: IslandHeuristic<_, _, _> = Box::new(NoopHeuristic);
+            (create_default_heuristic_context(), heuristic)
+        }
+    });
+    let strategy = RunIslands::new(1, 3, 2, island_factory);
+    let context = create_heuristic_context_with_solutions(vec![vec![2., 2.]], create_rosenbrock_function());
+
+    let (solutions, _) = strategy
+        .run(context, Box::new(NoopHeuristic), Box::new(MaxGeneration::new(5)), Telemetry::new(TelemetryMode::None))
+        .expect("cannot run islands");
+
+    assert_eq!(factory_calls.load(Ordering::Relaxed), 2);
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].data, vec![2., 2.]);
+}
@@ -0,0 +1,109 @@
+use super::*;
+use crate::evolution::TelemetryMode;
+use crate::example::*;
+use crate::helpers::example::create_heuristic_context_with_solutions;
+use crate::termination::{Interruption, MaxGeneration};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+struct NoopHeuristic;
+
+impl HyperHeuristic for NoopHeuristic {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn search(&mut self, _: &Self::Context, _: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        vec![]
+    }
+}
+
+struct StagnationTermination;
+
+impl Termination for StagnationTermination {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+
+    fn is_termination(&self, _: &mut Self::Context) -> bool {
+        true
+    }
+
+    fn estimate(&self, _: &Self::Context) -> f64 {
+        0.
+    }
+}
+
+fn create_restart_factory() -> RestartFactory<VectorContext, VectorObjective, VectorSolution> {
+    Box::new(|_, _| {
+        let heuristic: IslandHeuristic<_, _, _> = Box::new(NoopHeuristic);
+        (create_heuristic_context_with_solutions(vec![vec![3., 3.]], create_rosenbrock_function()), heuristic)
+    })
+}
+
+#[test]
+fn can_run_restarts_and_return_overall_best_individual() {
+    let factory_calls = Arc::new(AtomicUsize::new(0));
+    let restart_factory: RestartFactory<_, _, _> = Box::new({
+        let factory_calls = factory_calls.clone();
+        move |_, number| {
+            factory_calls.fetch_add(1, Ordering::Relaxed);
+            let data = if number == 1 { vec![1., 1.] } else { vec![3., 3.] };
+            let heuristic: IslandHeuristic<_, _, _> = Box::new(NoopHeuristic);
+            (create_heuristic_context_with_solutions(vec![data], create_rosenbrock_function()), heuristic)
+        }
+    });
+    let strategy = RunRestarts::new(1, 3, restart_factory);
+    let context = create_heuristic_context_with_solutions(vec![vec![2., 2.]], create_rosenbrock_function());
+
+    let (solutions, _) = strategy
+        .run(context, Box::new(NoopHeuristic), Box::new(MaxGeneration::new(6)), Telemetry::new(TelemetryMode::None))
+        .expect("cannot run restarts");
+
+    assert_eq!(factory_calls.load(Ordering::Relaxed), 2);
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].data, vec![1., 1.]);
+}
+
+#[test]
+fn can_split_generations_between_restarts_and_collect_their_metrics() {
+    let strategy = RunRestarts::new(1, 2, create_restart_factory());
+    let context = create_heuristic_context_with_solutions(vec![vec![2., 2.]], create_rosenbrock_function());
+    let telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 1000, snapshot_population: None });
+
+    let (_, metrics) = strategy
+        .run(context, Box::new(NoopHeuristic), Box::new(MaxGeneration::new(10)), telemetry)
+        .expect("cannot run restarts");
+
+    let restarts = metrics.expect("no metrics").restarts;
+    assert_eq!(restarts.len(), 2);
+    assert_eq!(restarts.iter().map(|restart| restart.number).collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(restarts.iter().map(|restart| restart.generations).collect::<Vec<_>>(), vec![6, 5]);
+    assert!(restarts.iter().all(|restart| !restart.fitness.is_empty()));
+}
+
+parameterized_test! {can_scope_termination_to_restart, (is_interrupted, expected_restarts), {
+    can_scope_termination_to_restart_impl(is_interrupted, expected_restarts);
+}}
+
+can_scope_termination_to_restart! {
+    case01_stagnation: (false, 3),
+    case02_interruption: (true, 1),
+}
+
+fn can_scope_termination_to_restart_impl(is_interrupted: bool, expected_restarts: usize) {
+    let strategy = RunRestarts::new(1, 3, create_restart_factory());
+    let context = create_heuristic_context_with_solutions(vec![vec![2., 2.]], create_rosenbrock_function());
+    let telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 1000, snapshot_population: None });
+    let termination: Box<dyn Termination<Context = _, Objective = _>> = if is_interrupted {
+        Box::new(Interruption::new(Arc::new(AtomicBool::new(true))))
+    } else {
+        Box::new(StagnationTermination)
+    };
+
+    let (_, metrics) =
+        strategy.run(context, Box::new(NoopHeuristic), termination, telemetry).expect("cannot run restarts");
+
+    let restarts = metrics.expect("no metrics").restarts;
+    assert_eq!(restarts.len(), expected_restarts);
+    assert!(restarts.iter().all(|restart| restart.generations == 0));
+}
@@ -0,0 +1,179 @@
+use super::*;
+use crate::example::*;
+use crate::helpers::example::{create_default_heuristic_context, create_heuristic_context_with_solutions};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Clone, Default)]
+struct SharedWriter {
+    buffer: Arc<Mutex<Vec<u8>>>,
+}
+
+impl Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct CountingSink {
+    generations: Arc<AtomicUsize>,
+    results: Arc<AtomicUsize>,
+    messages: Arc<AtomicUsize>,
+}
+
+impl TelemetrySink for CountingSink {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn on_generation(&mut self, _: &Self::Context, _: &HeuristicStatistics, _: &Timer, _: bool) {
+        self.generations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_result(&mut self, _: &Self::Context, _: &Timer) {
+        self.results.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_log(&self, _: &str) {
+        self.messages.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn can_use_custom_sink() {
+    let context = create_default_heuristic_context();
+    let sink = CountingSink::default();
+    let (generations, results, messages) = (sink.generations.clone(), sink.results.clone(), sink.messages.clone());
+    let mut telemetry = Telemetry::new_with_sinks(vec![Box::new(sink)]);
+
+    telemetry.log("message");
+    (0..3).for_each(|_| {
+        telemetry.on_generation(&context, 0., Timer::start(), false);
+    });
+    telemetry.on_result(&context);
+
+    assert_eq!(generations.load(Ordering::Relaxed), 3);
+    assert_eq!(results.load(Ordering::Relaxed), 1);
+    assert_eq!(messages.load(Ordering::Relaxed), 1);
+    assert!(telemetry.take_metrics().is_none());
+}
+
+#[test]
+fn can_write_log_messages_into_file_sink() {
+    let context = create_heuristic_context_with_solutions(vec![vec![2., 2.]], create_rosenbrock_function());
+    let writer = SharedWriter::default();
+    let mut telemetry =
+        Telemetry::new_with_sinks(vec![Box::new(FileSink::new(Box::new(writer.clone()), 1, 100, false))]);
+
+    telemetry.log("first message");
+    telemetry.on_generation(&context, 0., Timer::start(), false);
+    telemetry.on_phase_change(
+        &mut create_default_heuristic_context(),
+        SelectionPhase::Initial,
+        SelectionPhase::Exploration,
+    );
+
+    let content = String::from_utf8(writer.buffer.lock().unwrap().clone()).unwrap();
+    let lines = content.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "first message");
+    assert!(lines[1].contains("generation 0 took"));
+    assert!(lines.last().unwrap().contains("selection phase changed: initial -> exploration"));
+}
+
+#[test]
+fn can_take_metrics_from_metrics_sink_only_once() {
+    let context = create_heuristic_context_with_solutions(vec![vec![2., 2.]], create_rosenbrock_function());
+    let mut sink = MetricsSink::new(1, None);
+
+    let statistics = HeuristicStatistics::default();
+    sink.on_generation(&context, &statistics, &Timer::start(), true);
+
+    let metrics = sink.take_metrics().expect("no metrics");
+    assert_eq!(metrics.evolution.len(), 1);
+    assert!(metrics.evolution[0].is_improvement);
+    assert!(sink.take_metrics().is_none());
+}
+
+#[test]
+fn can_keep_operator_weights_in_metrics_only_when_changed() {
+    let context = create_default_heuristic_context();
+    let mut sink = MetricsSink::new(1, None);
+    let first = vec![("a".to_string(), 1.), ("b".to_string(), 1.)];
+    let second = vec![("a".to_string(), 2.), ("b".to_string(), 0.5)];
+
+    sink.on_operator_weights(&context, first.as_slice(), &Timer::start());
+    sink.on_operator_weights(&context, first.as_slice(), &Timer::start());
+    sink.on_operator_weights(&context, second.as_slice(), &Timer::start());
+
+    let metrics = sink.take_metrics().expect("no metrics");
+    assert_eq!(metrics.operator_weights.len(), 2);
+    assert_eq!(metrics.operator_weights[0].weights, first);
+    assert_eq!(metrics.operator_weights[1].weights, second);
+}
+
+#[test]
+fn can_write_generations_into_json_lines_sink() {
+    let context = create_heuristic_context_with_solutions(vec![vec![2., 2.]], create_rosenbrock_function());
+    let writer = SharedWriter::default();
+    let mut sink = JsonLinesSink::new(Box::new(writer.clone()), 2);
+    let operators = vec!["first".to_string(), "with \"quotes\"".to_string()];
+
+    (0..4).for_each(|generation| {
+        let statistics = HeuristicStatistics { generation, ..HeuristicStatistics::default() };
+        if generation == 0 {
+            sink.on_search(&context, operators.as_slice());
+        }
+        sink.on_generation(&context, &statistics, &Timer::start(), generation == 0);
+    });
+
+    let content = String::from_utf8(writer.buffer.lock().unwrap().clone()).unwrap();
+    let lines = content.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with(r#"{"generation":0,"#));
+    assert!(lines[0].contains(r#""isImproved":true"#));
+    assert!(lines[0].contains(r#""best":[401]"#));
+    assert!(lines[0].contains(r#""population":{"size":1,"#));
+    assert!(lines[0].ends_with(r#""operators":["first","with \"quotes\""]}"#));
+    assert!(lines[1].starts_with(r#"{"generation":2,"#));
+    assert!(lines[1].ends_with(r#""operators":null}"#));
+}
+
+#[test]
+fn can_render_progress_metrics() {
+    let context = create_heuristic_context_with_solutions(vec![vec![2., 2.]], create_rosenbrock_function());
+    let metrics = ProgressMetrics::default();
+    let exported = Arc::new(Mutex::new(Vec::<String>::new()));
+    let exporter = {
+        let exported = exported.clone();
+        Box::new(move |text: &str| exported.lock().unwrap().push(text.to_string()))
+    };
+    let mut sink = ProgressMetricsSink::new_with_exporter(metrics.clone(), 2, exporter);
+
+    (0..3).for_each(|generation| {
+        let statistics = HeuristicStatistics {
+            generation,
+            improvement_all_ratio: 0.5,
+            improvement_1000_ratio: 0.25,
+            ..HeuristicStatistics::default()
+        };
+        sink.on_generation(&context, &statistics, &Timer::start(), generation == 0);
+    });
+    sink.on_phase_change(SelectionPhase::Initial, SelectionPhase::Exploration, &Timer::start());
+
+    let text = metrics.render();
+    let lines = text.lines().collect::<Vec<_>>();
+    assert!(lines.contains(&"# TYPE solver_generations counter"));
+    assert!(lines.contains(&"solver_generations_total 3"));
+    assert!(lines.contains(&"solver_best_fitness 401"));
+    assert!(lines.contains(&r#"solver_improvement_ratio{window="all"} 0.5"#));
+    assert!(lines.contains(&r#"solver_improvement_ratio{window="1000"} 0.25"#));
+    assert!(lines.contains(&"solver_generations_since_improvement 2"));
+    assert!(lines.contains(&r#"solver_phase{phase="initial"} 0"#));
+    assert!(lines.contains(&r#"solver_phase{phase="exploration"} 1"#));
+    assert_eq!(lines.last(), Some(&"# EOF"));
+    assert_eq!(exported.lock().unwrap().len(), 2);
+}
@@ -1,5 +1,95 @@
+use super::*;
+use crate::example::*;
+use crate::helpers::example::{create_default_heuristic_context, create_heuristic_context_with_solutions};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
 #[test]
 #[ignore]
 fn can_use_telemetry() {
     todo!()
 }
+
+#[test]
+fn can_call_generation_callback_regardless_of_mode() {
+    let context = create_default_heuristic_context();
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut telemetry = Telemetry::new(TelemetryMode::None);
+    telemetry.add_generation_callback(Box::new({
+        let counter = counter.clone();
+        move |_, statistics, is_improved| {
+            assert_eq!(statistics.generation, counter.load(Ordering::Relaxed));
+            assert_eq!(is_improved, statistics.generation % 2 == 0);
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }));
+
+    (0..3).for_each(|generation| {
+        telemetry.on_generation(&context, 0., Timer::start(), generation % 2 == 0);
+    });
+
+    assert_eq!(counter.load(Ordering::Relaxed), 3);
+}
+
+struct TestPhaseProcessing {
+    phases: Arc<std::sync::Mutex<Vec<(SelectionPhase, SelectionPhase)>>>,
+}
+
+impl HeuristicPhaseProcessing for TestPhaseProcessing {
+    type Context = VectorContext;
+    type Objective = VectorObjective;
+    type Solution = VectorSolution;
+
+    fn on_phase_change(&self, _: &mut Self::Context, previous: SelectionPhase, current: SelectionPhase) {
+        self.phases.lock().unwrap().push((previous, current));
+    }
+}
+
+#[test]
+fn can_call_phase_processing_regardless_of_mode() {
+    let mut context = create_default_heuristic_context();
+    let phases = Arc::new(std::sync::Mutex::new(vec![]));
+    let mut telemetry = Telemetry::new(TelemetryMode::None);
+    telemetry.add_phase_processing(Box::new(TestPhaseProcessing { phases: phases.clone() }));
+
+    telemetry.on_phase_change(&mut context, SelectionPhase::Initial, SelectionPhase::Exploration);
+    telemetry.on_phase_change(&mut context, SelectionPhase::Exploration, SelectionPhase::Exploitation);
+
+    assert_eq!(
+        phases.lock().unwrap().as_slice(),
+        &[
+            (SelectionPhase::Initial, SelectionPhase::Exploration),
+            (SelectionPhase::Exploration, SelectionPhase::Exploitation)
+        ]
+    );
+}
+
+#[test]
+fn can_track_tier_fitness_only_when_changed() {
+    let context = create_heuristic_context_with_solutions(vec![vec![2., 2.]], create_rosenbrock_function());
+    let mut telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 100, snapshot_population: None });
+
+    (0..3).for_each(|_| {
+        telemetry.on_generation(&context, 0., Timer::start(), false);
+    });
+
+    let tiers = telemetry.take_metrics().expect("no metrics").tiers;
+    assert_eq!(tiers.len(), 1);
+    assert_eq!(tiers[0].number, 0);
+    assert_eq!(tiers[0].fitness, vec![vec![401.]]);
+}
+
+#[test]
+fn can_take_population_snapshots_when_enabled() {
+    let context = create_heuristic_context_with_solutions(vec![vec![2., 2.]], create_rosenbrock_function());
+    let mut telemetry =
+        Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 100, snapshot_population: Some(2) });
+
+    (0..5).for_each(|_| {
+        telemetry.on_generation(&context, 0., Timer::start(), false);
+    });
+
+    let snapshots = telemetry.take_metrics().expect("no metrics").snapshots;
+    assert_eq!(snapshots.iter().map(|snapshot| snapshot.number).collect::<Vec<_>>(), vec![0, 2, 4]);
+    assert_eq!(snapshots[0].fitness, vec![vec![401.]]);
+}
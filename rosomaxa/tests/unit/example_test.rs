@@ -50,3 +50,22 @@ fn can_solve_rosenbrock() {
     let (_, fitness) = solutions.first().unwrap();
     assert!(*fitness < 0.01);
 }
+
+#[test]
+fn can_solve_rosenbrock_using_islands() {
+    let random = Arc::new(DefaultRandom::default());
+    let (solutions, _) = Solver::default()
+        .with_objective_fun(create_rosenbrock_function())
+        .with_init_solutions(vec![vec![2., 2.]])
+        .with_operator(just_noise(1., (-0.05, 0.05), random.clone()), "first", 1.)
+        .with_operator(dimen_noise(1., (-0.1, 0.1), 0, random.clone()), "second", 0.5)
+        .with_operator(dimen_noise(1., (-0.1, 0.1), 1, random.clone()), "third", 0.25)
+        .with_islands(3, 10)
+        .with_termination(Some(5), Some(1000), None, None)
+        .solve()
+        .expect("cannot build and use solver");
+
+    assert_eq!(solutions.len(), 1);
+    let (_, fitness) = solutions.first().unwrap();
+    assert!(*fitness < 0.01);
+}
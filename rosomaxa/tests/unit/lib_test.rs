@@ -0,0 +1,40 @@
+use super::*;
+use crate::example::{create_rosenbrock_function, VectorContext, VectorObjective};
+use crate::utils::{DefaultRandom, Parallelism, Quota, TimeQuota};
+use std::sync::Mutex;
+
+parameterized_test! {can_select_default_population, (max_time, problem_size, expected), {
+    can_select_default_population_impl(max_time, problem_size, expected);
+}}
+
+can_select_default_population! {
+    case_01: (Some(10), 100, "elitism due to short time"),
+    case_02: (Some(10), 10, "elitism due to short time"),
+    case_03: (None, 10, "elitism due to small size"),
+    case_04: (Some(300), 10, "elitism due to small size"),
+    case_05: (None, 100, "rosomaxa"),
+    case_06: (Some(300), 100, "rosomaxa"),
+}
+
+fn can_select_default_population_impl(max_time: Option<usize>, problem_size: usize, expected: &str) {
+    let messages = Arc::new(Mutex::new(Vec::<String>::new()));
+    let environment = Arc::new(Environment::new(
+        Arc::new(DefaultRandom::default()),
+        max_time.map::<Arc<dyn Quota + Send + Sync>, _>(|time| Arc::new(TimeQuota::new(time as f64))),
+        Parallelism::default(),
+        Arc::new({
+            let messages = messages.clone();
+            move |msg: &str| messages.lock().unwrap().push(msg.to_string())
+        }),
+        false,
+    ));
+    let objective = Arc::new(VectorObjective::new(create_rosenbrock_function()));
+    let expected =
+        if get_default_selection_size(environment.as_ref()) == 1 { "greedy due to single selection" } else { expected };
+
+    let _ = get_default_population::<VectorContext, _, _>(objective, environment, problem_size);
+
+    let messages = messages.lock().unwrap();
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].starts_with(format!("selected population: {},", expected).as_str()));
+}
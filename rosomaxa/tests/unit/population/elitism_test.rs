@@ -124,3 +124,21 @@ fn can_handle_empty() {
 
     assert!(parents.is_empty());
 }
+
+#[test]
+fn can_drain_ranked_individuals() {
+    let (objective, mut population) = create_objective_population(3, 1);
+    population.add_all(vec![
+        VectorSolution::new(vec![-0.5, -0.5], objective.clone()),
+        VectorSolution::new(vec![0., 0.], objective.clone()),
+        VectorSolution::new(vec![0.5, 0.5], objective.clone()),
+    ]);
+
+    let individuals = population.drain_ranked();
+
+    assert_eq!(population.size(), 0);
+    assert_eq!(
+        individuals.iter().map(|(_, rank, fitness)| (*rank, fitness.clone())).collect::<Vec<_>>(),
+        vec![(0, vec![1.]), (1, vec![6.5]), (2, vec![58.5])]
+    );
+}
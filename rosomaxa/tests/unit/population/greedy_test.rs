@@ -22,3 +22,17 @@ fn can_keep_best_solution() {
     assert_eq!(population.size(), 1);
     assert_eq!(get_best_fitness(&population), 401.);
 }
+
+#[test]
+fn can_drain_best_known_solution() {
+    let objective = Arc::new(VectorObjective::new(create_rosenbrock_function()));
+    let mut population = Greedy::<_, _>::new(objective.clone(), 1, None);
+    population.add(VectorSolution::new(vec![2., 2.], objective.clone()));
+
+    let individuals = population.drain_ranked();
+
+    assert_eq!(population.size(), 0);
+    assert_eq!(individuals.len(), 1);
+    assert_eq!((individuals[0].1, individuals[0].2.clone()), (0, vec![401.]));
+    assert!(population.drain_ranked().is_empty());
+}
@@ -150,3 +150,109 @@ fn can_get_population_shuffle_amount() {
         100
     );
 }
+
+#[test]
+fn can_save_and_restore_network_state() {
+    let fill_population = |objective: &Arc<VectorObjective>, rosomaxa: &mut Rosomaxa<_, _>| {
+        (0..6).for_each(|idx| {
+            let value = idx as f64 - 3.;
+            rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+            rosomaxa.update_phase(&create_statistics(0.5, idx))
+        });
+    };
+    let get_coordinates = |rosomaxa: &Rosomaxa<_, _>| {
+        let mut coordinates = get_network(rosomaxa).get_coordinates().map(|c| (c.0, c.1)).collect::<Vec<_>>();
+        coordinates.sort();
+        coordinates
+    };
+    let (objective, mut original) = create_rosomaxa(10);
+    assert!(original.save_state().is_none());
+    fill_population(&objective, &mut original);
+    let state = original.save_state().expect("state should be saved in exploration phase");
+
+    let (objective, mut restored) = create_rosomaxa(10);
+    restored.restore_state(state.as_str()).unwrap();
+    assert_eq!(restored.save_state(), Some(state));
+    fill_population(&objective, &mut restored);
+
+    assert_eq!(restored.selection_phase(), SelectionPhase::Exploration);
+    assert_eq!(get_coordinates(&restored), get_coordinates(&original));
+}
+
+#[test]
+fn can_reject_invalid_network_state() {
+    let (_, mut rosomaxa) = create_rosomaxa(10);
+
+    assert!(rosomaxa.restore_state("0,0:1,a").is_err());
+}
+
+#[test]
+fn can_drain_individuals_from_elite_and_network() {
+    let (objective, mut rosomaxa) = create_rosomaxa(10);
+    (0..10).for_each(|idx| {
+        let value = idx as f64 / 10.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, value], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.75, idx))
+    });
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+    let snapshot_size = rosomaxa.get_fitness_snapshot().len();
+
+    let individuals = rosomaxa.drain_ranked();
+
+    assert_eq!(rosomaxa.size(), 0);
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Initial);
+    assert!(individuals.len() > rosomaxa.config.elite_size);
+    assert!(individuals.len() <= snapshot_size);
+    assert_eq!(individuals.first().map(|(_, rank, _)| *rank), Some(0));
+    assert!(individuals.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+}
+
+parameterized_test! {can_reduce_weights, (weight_reduction, expected_dimension), {
+    can_reduce_weights_impl(weight_reduction, expected_dimension);
+}}
+
+can_reduce_weights! {
+    case01_none: (WeightReduction::None, 3),
+    case02_subset: (WeightReduction::Subset(vec![0, 2]), 2),
+    case03_subset_out_of_range: (WeightReduction::Subset(vec![1, 3]), 3),
+    case04_pca: (WeightReduction::Pca(1), 1),
+    case05_pca_above_dimension: (WeightReduction::Pca(4), 3),
+}
+
+fn can_reduce_weights_impl(weight_reduction: WeightReduction, expected_dimension: usize) {
+    let mut config = RosomaxaConfig::new_with_defaults(4);
+    config.weight_reduction = weight_reduction;
+    let objective = Arc::new(VectorObjective::new(create_rosenbrock_function()));
+    let mut rosomaxa = Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config).unwrap();
+
+    (0..6).for_each(|idx| {
+        let value = idx as f64 + 1.;
+        rosomaxa.add_all(vec![VectorSolution::new(vec![value, 2. * value, 1.], objective.clone())]);
+        rosomaxa.update_phase(&create_statistics(0.5, idx))
+    });
+
+    assert_eq!(rosomaxa.selection_phase(), SelectionPhase::Exploration);
+    assert!(get_network(&rosomaxa).get_nodes().all(|node| node.read().unwrap().weights.len() == expected_dimension));
+}
+
+#[test]
+fn can_reject_invalid_weight_reduction() {
+    let objective = Arc::new(VectorObjective::new(create_rosenbrock_function()));
+    let create = |weight_reduction: WeightReduction| {
+        let mut config = RosomaxaConfig::new_with_defaults(4);
+        config.weight_reduction = weight_reduction;
+        Rosomaxa::new(objective.clone(), Arc::new(Environment::default()), config)
+    };
+
+    assert!(create(WeightReduction::Subset(vec![])).is_err());
+    assert!(create(WeightReduction::Pca(0)).is_err());
+}
+
+#[test]
+fn can_reject_zero_batch_size() {
+    let objective = Arc::new(VectorObjective::new(create_rosenbrock_function()));
+    let mut config = RosomaxaConfig::new_with_defaults(4);
+    config.batch_size = 0;
+
+    assert!(Rosomaxa::new(objective, Arc::new(Environment::default()), config).is_err());
+}
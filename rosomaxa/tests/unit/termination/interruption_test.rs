@@ -0,0 +1,33 @@
+use super::*;
+use crate::helpers::example::create_default_heuristic_context;
+
+parameterized_test! {can_detect_termination, (is_interrupted, expected), {
+    can_detect_termination_impl(is_interrupted, expected);
+}}
+
+can_detect_termination! {
+    case_01: (true, (true, 1.)),
+    case_02: (false, (false, 0.)),
+}
+
+fn can_detect_termination_impl(is_interrupted: bool, expected: (bool, f64)) {
+    let mut context = create_default_heuristic_context();
+    let interrupt = Arc::new(AtomicBool::new(is_interrupted));
+    let termination = Interruption::<_, _, _>::new(interrupt);
+
+    let result = (termination.is_termination(&mut context), termination.estimate(&context));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_react_on_interruption_set_later() {
+    let mut context = create_default_heuristic_context();
+    let interrupt = Arc::new(AtomicBool::new(false));
+    let termination = Interruption::<_, _, _>::new(interrupt.clone());
+    assert!(!termination.is_termination(&mut context));
+
+    interrupt.store(true, Ordering::Relaxed);
+
+    assert!(termination.is_termination(&mut context));
+}
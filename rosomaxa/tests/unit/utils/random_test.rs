@@ -20,3 +20,31 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+#[test]
+fn can_reproduce_values_with_the_same_seed() {
+    let get_values = |random: DefaultRandom| (0..10).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>();
+
+    let first = get_values(DefaultRandom::new_with_seed(42));
+    let second = get_values(DefaultRandom::new_with_seed(42));
+    let third = get_values(DefaultRandom::new_with_seed(43));
+
+    assert_eq!(first, second);
+    assert_ne!(first, third);
+    assert!(first.iter().any(|value| *value != first[0]));
+}
+
+#[test]
+fn can_continue_values_from_restored_state() {
+    let get_values = |random: &DefaultRandom| (0..10).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>();
+    let original = DefaultRandom::new_with_seed(42);
+    get_values(&original);
+    let state = original.save_state().expect("seeded random should have state");
+    let expected = get_values(&original);
+
+    let restored = DefaultRandom::new_with_seed(42);
+    restored.restore_state(state);
+
+    assert_eq!(get_values(&restored), expected);
+    assert!(DefaultRandom::default().save_state().is_none());
+}
@@ -3,62 +3,198 @@
 mod analyze_test;
 
 use super::*;
-use vrp_cli::extensions::analyze::get_clusters;
+use vrp_cli::extensions::analyze::{
+    get_capacity_analysis, get_clusters, get_feasible_vehicles, get_matrix_analysis, get_unassigned_analysis,
+};
+use vrp_pragmatic::format::problem::MatrixConsistencySettings;
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
+const SOLUTION_ARG_NAME: &str = "solution";
 const MATRIX_ARG_NAME: &str = "matrix";
 const MIN_POINTS_ARG_NAME: &str = "min-points";
 const EPSILON_ARG_NAME: &str = "epsilon";
+const ASYMMETRY_TOLERANCE_ARG_NAME: &str = "asymmetry-tolerance";
+const TRIANGLE_TOLERANCE_ARG_NAME: &str = "triangle-tolerance";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
 
 pub fn get_analyze_app() -> Command<'static> {
-    Command::new("analyze").about("Provides helper functionality to analyze problem or solution").subcommand(
-        Command::new("clusters")
-            .about("Analyzes job clusters")
-            .arg(
-                Arg::new(FORMAT_ARG_NAME)
-                    .help("Specifies input type")
-                    .required(true)
-                    .possible_values(&["pragmatic"])
-                    .index(1),
-            )
-            .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
-            .arg(
-                Arg::new(MIN_POINTS_ARG_NAME)
-                    .help("Minimum cluster size")
-                    .short('c')
-                    .default_value("3")
-                    .long(MIN_POINTS_ARG_NAME)
-                    .required(false)
-                    .takes_value(true),
-            )
-            .arg(
-                Arg::new(EPSILON_ARG_NAME)
-                    .help("Epsilon parameter in DBSCAN")
-                    .short('e')
-                    .long(EPSILON_ARG_NAME)
-                    .required(false)
-                    .takes_value(true),
-            )
-            .arg(
-                Arg::new(MATRIX_ARG_NAME)
-                    .help("Specifies path to file with routing matrix")
-                    .short('m')
-                    .long(MATRIX_ARG_NAME)
-                    .multiple_values(true)
-                    .required(false)
-                    .takes_value(true),
-            )
-            .arg(
-                Arg::new(OUT_RESULT_ARG_NAME)
-                    .help("Specifies path to the file for result output")
-                    .short('o')
-                    .long(OUT_RESULT_ARG_NAME)
-                    .required(true)
-                    .takes_value(true),
-            ),
-    )
+    Command::new("analyze")
+        .about("Provides helper functionality to analyze problem or solution")
+        .subcommand(
+            Command::new("clusters")
+                .about("Analyzes job clusters")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .possible_values(&["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(MIN_POINTS_ARG_NAME)
+                        .help("Minimum cluster size")
+                        .short('c')
+                        .default_value("3")
+                        .long(MIN_POINTS_ARG_NAME)
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(EPSILON_ARG_NAME)
+                        .help("Epsilon parameter in DBSCAN")
+                        .short('e')
+                        .long(EPSILON_ARG_NAME)
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .multiple_values(true)
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            Command::new("feasibility")
+                .about("Analyzes which vehicle shifts can serve each job in isolation")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .possible_values(&["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .multiple_values(true)
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            Command::new("capacity")
+                .about("Analyzes whether total demand of jobs fits into total fleet capacity")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .possible_values(&["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            Command::new("unassigned")
+                .about("Analyzes why unassigned jobs of the solution cannot be served by each vehicle shift")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .possible_values(&["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(SOLUTION_ARG_NAME)
+                        .help("Sets solution file")
+                        .short('s')
+                        .long(SOLUTION_ARG_NAME)
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .multiple_values(true)
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            Command::new("matrix")
+                .about("Analyzes routing matrix consistency and reports suspicious cells")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .possible_values(&["pragmatic"])
+                        .index(1),
+                )
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .multiple_values(true)
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(ASYMMETRY_TOLERANCE_ARG_NAME)
+                        .help("Max allowed relative difference between values in opposite directions")
+                        .long(ASYMMETRY_TOLERANCE_ARG_NAME)
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(TRIANGLE_TOLERANCE_ARG_NAME)
+                        .help("Max allowed relative excess of direct value over detour via another location")
+                        .long(TRIANGLE_TOLERANCE_ARG_NAME)
+                        .required(false)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(true)
+                        .takes_value(true),
+                ),
+        )
 }
 
 pub fn run_analyze(
@@ -92,6 +228,108 @@ pub fn run_analyze(
 
             geo_writer.write_all(clusters.as_bytes()).map_err(|err| format!("cannot write result: '{}'", err))
         }
+        Some(("feasibility", feasibility_matches)) => {
+            let problem_path = feasibility_matches.value_of(PROBLEM_ARG_NAME).unwrap();
+            let problem_format = feasibility_matches.value_of(FORMAT_ARG_NAME).unwrap();
+
+            if problem_format != "pragmatic" {
+                return Err(format!("unknown problem format: '{}'", problem_format));
+            }
+
+            let problem_reader = BufReader::new(open_file(problem_path, "problem"));
+
+            let matrices_readers = feasibility_matches
+                .values_of(MATRIX_ARG_NAME)
+                .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+
+            let feasibility = get_feasible_vehicles(problem_reader, matrices_readers)
+                .map_err(|err| format!("cannot get feasible vehicles: '{}'", err))?;
+
+            let out_result =
+                feasibility_matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_writer = out_writer_func(out_result);
+
+            out_writer.write_all(feasibility.as_bytes()).map_err(|err| format!("cannot write result: '{}'", err))
+        }
+        Some(("capacity", capacity_matches)) => {
+            let problem_path = capacity_matches.value_of(PROBLEM_ARG_NAME).unwrap();
+            let problem_format = capacity_matches.value_of(FORMAT_ARG_NAME).unwrap();
+
+            if problem_format != "pragmatic" {
+                return Err(format!("unknown problem format: '{}'", problem_format));
+            }
+
+            let problem_reader = BufReader::new(open_file(problem_path, "problem"));
+
+            let capacity =
+                get_capacity_analysis(problem_reader).map_err(|err| format!("cannot analyze capacity: '{}'", err))?;
+
+            let out_result = capacity_matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_writer = out_writer_func(out_result);
+
+            out_writer.write_all(capacity.as_bytes()).map_err(|err| format!("cannot write result: '{}'", err))
+        }
+        Some(("unassigned", unassigned_matches)) => {
+            let problem_path = unassigned_matches.value_of(PROBLEM_ARG_NAME).unwrap();
+            let problem_format = unassigned_matches.value_of(FORMAT_ARG_NAME).unwrap();
+
+            if problem_format != "pragmatic" {
+                return Err(format!("unknown problem format: '{}'", problem_format));
+            }
+
+            let problem_reader = BufReader::new(open_file(problem_path, "problem"));
+            let solution_path = unassigned_matches.value_of(SOLUTION_ARG_NAME).unwrap();
+            let solution_reader = BufReader::new(open_file(solution_path, "solution"));
+
+            let matrices_readers = unassigned_matches
+                .values_of(MATRIX_ARG_NAME)
+                .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+
+            let unassigned = get_unassigned_analysis(problem_reader, solution_reader, matrices_readers)
+                .map_err(|err| format!("cannot analyze unassigned jobs: '{}'", err))?;
+
+            let out_result =
+                unassigned_matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_writer = out_writer_func(out_result);
+
+            out_writer.write_all(unassigned.as_bytes()).map_err(|err| format!("cannot write result: '{}'", err))
+        }
+        Some(("matrix", matrix_matches)) => {
+            let matrix_format = matrix_matches.value_of(FORMAT_ARG_NAME).unwrap();
+
+            if matrix_format != "pragmatic" {
+                return Err(format!("unknown matrix format: '{}'", matrix_format));
+            }
+
+            let matrices_readers = matrix_matches
+                .values_of(MATRIX_ARG_NAME)
+                .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect())
+                .unwrap_or_default();
+
+            let defaults = MatrixConsistencySettings::default();
+            let settings = MatrixConsistencySettings {
+                asymmetry_tolerance: parse_float_value::<f64>(
+                    matrix_matches,
+                    ASYMMETRY_TOLERANCE_ARG_NAME,
+                    "asymmetry tolerance",
+                )?
+                .unwrap_or(defaults.asymmetry_tolerance),
+                triangle_tolerance: parse_float_value::<f64>(
+                    matrix_matches,
+                    TRIANGLE_TOLERANCE_ARG_NAME,
+                    "triangle tolerance",
+                )?
+                .unwrap_or(defaults.triangle_tolerance),
+            };
+
+            let analysis = get_matrix_analysis(matrices_readers, &settings)
+                .map_err(|err| format!("cannot analyze routing matrix: '{}'", err))?;
+
+            let out_result = matrix_matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_writer = out_writer_func(out_result);
+
+            out_writer.write_all(analysis.as_bytes()).map_err(|err| format!("cannot write result: '{}'", err))
+        }
         _ => Err("no argument with analyze subcommand was used. Use -h to print help information".to_string()),
     }
 }
@@ -10,11 +10,12 @@ use std::io::{BufReader, BufWriter, Write};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use vrp_cli::core::solver::TargetHeuristic;
-use vrp_cli::extensions::solve::config::create_builder_from_config_file;
+use vrp_cli::extensions::solve::bundle::{write_bundle, ReproductionBundle};
+use vrp_cli::extensions::solve::config::{create_builder_from_config, read_config, Config, EnvironmentConfig};
 use vrp_cli::scientific::tsplib::{TsplibProblem, TsplibSolution};
 use vrp_cli::{get_errors_serialized, get_locations_serialized};
 use vrp_core::construction::heuristics::InsertionContext;
-use vrp_core::models::problem::ProblemObjective;
+use vrp_core::models::problem::{MatrixMemoryDimension, ProblemObjective};
 use vrp_core::prelude::*;
 use vrp_core::rosomaxa::evolution::*;
 use vrp_core::rosomaxa::{get_default_population, get_default_selection_size};
@@ -41,6 +42,11 @@ const PARALLELISM_ARG_NAME: &str = "parallelism";
 const HEURISTIC_ARG_NAME: &str = "heuristic";
 const EXPERIMENTAL_ARG_NAME: &str = "experimental";
 const ROUNDED_ARG_NAME: &str = "round";
+const BUNDLE_ARG_NAME: &str = "bundle";
+const ROUNDING_MODE_ARG_NAME: &str = "rounding-mode";
+const PRECISE_STATISTIC_ARG_NAME: &str = "precise-statistic";
+const COMPACTNESS_ARG_NAME: &str = "compactness";
+const SEED_ARG_NAME: &str = "seed";
 
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
@@ -69,6 +75,7 @@ type FormatMap<'a> = HashMap<&'a str, (ProblemReader, InitSolutionReader, Soluti
 
 fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>) {
     if cfg!(feature = "scientific-format") {
+        use vrp_scientific::lilim::read_init_solution as read_init_lilim;
         use vrp_scientific::lilim::{LilimProblem, LilimSolution};
         use vrp_scientific::solomon::read_init_solution as read_init_solomon;
         use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
@@ -82,8 +89,9 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
                     assert!(matrices.is_none());
                     BufReader::new(problem).read_solomon(is_rounded)
                 })),
-                InitSolutionReader(Box::new(move |file, problem| {
-                    read_init_solomon(BufReader::new(file), problem, random.clone())
+                InitSolutionReader(Box::new({
+                    let random = random.clone();
+                    move |file, problem| read_init_solomon(BufReader::new(file), problem, random.clone())
                 })),
                 SolutionWriter(Box::new(|_, solution, cost, _, writer, _| (&solution, cost).write_solomon(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
@@ -96,7 +104,9 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
                     assert!(matrices.is_none());
                     BufReader::new(problem).read_lilim(is_rounded)
                 })),
-                InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
+                InitSolutionReader(Box::new(move |file, problem| {
+                    read_init_lilim(BufReader::new(file), problem, random.clone())
+                })),
                 SolutionWriter(Box::new(|_, solution, cost, _, writer, _| (&solution, cost).write_lilim(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
@@ -116,10 +126,21 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
     }
 }
 
-fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>) {
+fn add_pragmatic(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>) {
     use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
-    use vrp_pragmatic::format::solution::PragmaticSolution;
+    use vrp_pragmatic::format::solution::{PragmaticSolution, RoundingMode, SolutionWriterSettings};
+
+    let settings = SolutionWriterSettings {
+        rounding: match matches.value_of(ROUNDING_MODE_ARG_NAME) {
+            Some("round") => RoundingMode::Round,
+            Some("ceil") => RoundingMode::Ceil,
+            Some("floor") => RoundingMode::Floor,
+            _ => RoundingMode::Truncate,
+        },
+        precise_statistic: matches.is_present(PRECISE_STATISTIC_ARG_NAME),
+        compactness: matches.is_present(COMPACTNESS_ARG_NAME),
+    };
 
     formats.insert(
         "pragmatic",
@@ -136,16 +157,21 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>)
             InitSolutionReader(Box::new(move |file, problem| {
                 read_init_pragmatic(BufReader::new(file), problem, random.clone())
             })),
-            SolutionWriter(Box::new(|problem, solution, cost, metrics, default_writer, geojson_writer| {
+            SolutionWriter(Box::new(move |problem, solution, cost, metrics, default_writer, geojson_writer| {
                 geojson_writer
                     .map_or(Ok(()), |geojson_writer| (&solution, cost).write_geo_json(problem, geojson_writer))
                     .and_then(|_| {
                         if let Some(metrics) = metrics {
-                            (&solution, cost, &metrics).write_pragmatic_json(problem, default_writer)
+                            (&solution, cost, &metrics).write_pragmatic_json_with_settings(
+                                problem,
+                                &settings,
+                                default_writer,
+                            )
                         } else {
-                            (&solution, cost).write_pragmatic_json(problem, default_writer)
+                            (&solution, cost).write_pragmatic_json_with_settings(problem, &settings, default_writer)
                         }
                     })
+                    .map_err(String::from)
             })),
             LocationWriter(Box::new(|problem, writer| {
                 let mut writer = writer;
@@ -162,7 +188,7 @@ fn get_formats<'a>(matches: &ArgMatches, random: Arc<dyn Random + Send + Sync>)
     let mut formats = FormatMap::default();
 
     add_scientific(&mut formats, matches, random.clone());
-    add_pragmatic(&mut formats, random);
+    add_pragmatic(&mut formats, matches, random);
 
     formats
 }
@@ -296,9 +322,19 @@ pub fn get_solve_app() -> Command<'static> {
                 .long(HEURISTIC_ARG_NAME)
                 .short('e')
                 .required(false)
-                .possible_values(&["default", "dynamic", "static"])
+                .possible_values(&["default", "dynamic", "static", "guided", "alns"])
                 .default_value("default"),
         )
+        .arg(
+            Arg::new(SEED_ARG_NAME)
+                .help(
+                    "Specifies a seed of random generator. Search runs on a single thread, parallelism settings \
+                     are ignored. Overrides a seed from config.",
+                )
+                .long(SEED_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
         .arg(
             Arg::new(EXPERIMENTAL_ARG_NAME)
                 .help("Specifies whether experimental (unstable) features are enabled.")
@@ -313,6 +349,35 @@ pub fn get_solve_app() -> Command<'static> {
                 .required(false)
                 .takes_value(false),
         )
+        .arg(
+            Arg::new(ROUNDING_MODE_ARG_NAME)
+                .help("Specifies rounding mode of distances and durations in solution statistic. Applicable only for pragmatic format.")
+                .long(ROUNDING_MODE_ARG_NAME)
+                .required(false)
+                .possible_values(&["truncate", "round", "ceil", "floor"])
+                .default_value("truncate"),
+        )
+        .arg(
+            Arg::new(PRECISE_STATISTIC_ARG_NAME)
+                .help("Specifies whether statistic without rounding is reported in solution extras. Applicable only for pragmatic format.")
+                .long(PRECISE_STATISTIC_ARG_NAME)
+                .required(false)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::new(COMPACTNESS_ARG_NAME)
+                .help("Specifies whether compactness of each tour is reported in solution extras. Applicable only for pragmatic format.")
+                .long(COMPACTNESS_ARG_NAME)
+                .required(false)
+                .takes_value(false),
+        )
+        .arg(
+            Arg::new(BUNDLE_ARG_NAME)
+                .help("Specifies path to file for reproduction bundle output: it contains all inputs of the run")
+                .long(BUNDLE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
 }
 
 /// Runs solver commands.
@@ -345,6 +410,7 @@ pub fn run_solve(
     });
 
     let is_check_requested = matches.is_present(CHECK_ARG_NAME);
+    let is_logging_enabled = matches.is_present(LOG_ARG_NAME);
     let min_cv = get_min_cv(matches)?;
     let init_solution = matches.value_of(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
     let init_size = get_init_size(matches)?;
@@ -354,6 +420,8 @@ pub fn run_solve(
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
     let mode = matches.value_of(SEARCH_MODE_ARG_NAME);
+    let bundle_path = matches.value_of(BUNDLE_ARG_NAME);
+    let seed = get_seed(matches)?;
 
     match formats.get(problem_format) {
         Some((problem_reader, init_reader, solution_writer, locations_writer)) => {
@@ -366,6 +434,14 @@ pub fn run_solve(
                 match problem_reader.0(problem_file, matrix_files) {
                     Ok(problem) => {
                         let problem = Arc::new(problem);
+
+                        if let Some(memory) = problem.extras.get_matrix_memory().filter(|_| is_logging_enabled) {
+                            (environment.logger)(&format!(
+                                "estimated routing matrix memory usage: {:.1} MiB",
+                                memory as f64 / (1024. * 1024.)
+                            ));
+                        }
+
                         let solutions = init_solution
                             .map(|file| {
                                 init_reader.0(file, problem.clone())
@@ -381,7 +457,9 @@ pub fn run_solve(
                             .unwrap_or_else(|| Ok(Vec::new()))?;
 
                         let solver = if let Some(config) = config {
-                            create_builder_from_config_file(problem.clone(), BufReader::new(config))
+                            read_config(BufReader::new(config))
+                                .map(|config| with_seed(config, seed))
+                                .and_then(|config| create_builder_from_config(problem.clone(), &config))
                                 .and_then(|builder| builder.build())
                                 .map(|config| Solver::new(problem.clone(), config))
                                 .map_err(|err| format!("cannot read config: '{}'", err))?
@@ -394,17 +472,24 @@ pub fn run_solve(
                                 .with_min_cv(min_cv, "min_cv".to_string())
                                 .with_context(RefinementContext::new(
                                     problem.clone(),
-                                    get_population(mode, problem.objective.clone(), environment.clone()),
+                                    get_population(mode, problem.clone(), environment.clone()),
                                     environment.clone(),
                                 ))
-                                .with_heuristic(get_heuristic(matches, problem.clone(), environment)?)
+                                .with_heuristic(get_heuristic(matches, problem.clone(), environment.clone())?)
                                 .build()?;
 
                             Solver::new(problem.clone(), config)
                         };
 
+                        let result = solver.solve();
+
+                        if let Some(bundle_path) = bundle_path {
+                            let error = result.as_ref().err().cloned();
+                            write_bundle_with_args(matches, bundle_path, environment.as_ref(), error)?;
+                        }
+
                         let (solution, cost, metrics) =
-                            solver.solve().map_err(|err| format!("cannot find any solution: '{}'", err))?;
+                            result.map_err(|err| format!("cannot find any solution: '{}'", err))?;
 
                         solution_writer.0(&problem, solution, cost, metrics, out_buffer, geo_buffer).unwrap();
 
@@ -461,9 +546,15 @@ fn get_init_size(matches: &ArgMatches) -> Result<Option<usize>, String> {
 
 fn get_environment(matches: &ArgMatches, max_time: Option<usize>) -> Result<Arc<Environment>, String> {
     let quota = Some(create_interruption_quota(max_time));
+    let seed = get_seed(matches)?;
+    let random: Arc<dyn Random + Send + Sync> = match seed {
+        Some(seed) => Arc::new(DefaultRandom::new_with_seed(seed)),
+        None => Arc::new(DefaultRandom::default()),
+    };
 
     matches
         .value_of(PARALLELISM_ARG_NAME)
+        .filter(|_| seed.is_none())
         .map(|arg| {
             if let [num_thread_pools, threads_per_pool] =
                 arg.split(',').filter_map(|line| line.parse::<usize>().ok()).collect::<Vec<_>>().as_slice()
@@ -476,18 +567,28 @@ fn get_environment(matches: &ArgMatches, max_time: Option<usize>) -> Result<Arc<
                 };
                 let is_experimental = matches.is_present(EXPERIMENTAL_ARG_NAME);
 
-                Ok(Arc::new(Environment::new(
-                    Arc::new(DefaultRandom::default()),
-                    quota.clone(),
-                    parallelism,
-                    logger,
-                    is_experimental,
-                )))
+                Ok(Arc::new(Environment::new(random.clone(), quota.clone(), parallelism, logger, is_experimental)))
             } else {
                 Err("cannot parse parallelism parameter".to_string())
             }
         })
-        .unwrap_or_else(|| Ok(Arc::new(Environment { quota, ..Environment::default() })))
+        .unwrap_or_else(|| Ok(Arc::new(Environment { random, quota, ..Environment::default() })))
+}
+
+/// Overrides seed specified in config with the given one.
+fn with_seed(config: Config, seed: Option<u64>) -> Config {
+    match seed {
+        Some(seed) => {
+            let environment = config.environment.unwrap_or_default();
+
+            Config { environment: Some(EnvironmentConfig { seed: Some(seed), ..environment }), ..config }
+        }
+        None => config,
+    }
+}
+
+fn get_seed(matches: &ArgMatches) -> Result<Option<u64>, String> {
+    parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed")
 }
 
 fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
@@ -496,11 +597,9 @@ fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
         .map(|paths: Values| paths.map(|path| open_file(path, "routing matrix")).collect())
 }
 
-fn get_population(
-    mode: Option<&str>,
-    objective: Arc<ProblemObjective>,
-    environment: Arc<Environment>,
-) -> TargetPopulation {
+fn get_population(mode: Option<&str>, problem: Arc<Problem>, environment: Arc<Environment>) -> TargetPopulation {
+    let objective = problem.objective.clone();
+
     match mode {
         Some("deep") => Box::new(ElitismPopulation::new(
             objective,
@@ -508,7 +607,7 @@ fn get_population(
             4,
             get_default_selection_size(environment.as_ref()),
         )),
-        _ => get_default_population::<RefinementContext, _, _>(objective, environment),
+        _ => get_default_population::<RefinementContext, _, _>(objective, environment, problem.jobs.size()),
     }
 }
 
@@ -520,11 +619,32 @@ fn get_heuristic(
     match matches.value_of(HEURISTIC_ARG_NAME) {
         Some("dynamic") => Ok(get_dynamic_heuristic(problem, environment)),
         Some("static") => Ok(get_static_heuristic(problem, environment)),
+        Some("guided") => Ok(get_guided_local_search_heuristic(problem, environment)),
+        Some("alns") => Ok(get_alns_heuristic(problem, environment)),
         Some(name) if name != "default" => Err(format!("unknown heuristic type name: '{}'", name)),
         _ => Ok(get_dynamic_heuristic(problem, environment)),
     }
 }
 
+fn write_bundle_with_args(
+    matches: &ArgMatches,
+    bundle_path: &str,
+    environment: &Environment,
+    error: Option<String>,
+) -> Result<(), String> {
+    let read_file =
+        |path: &str| std::fs::read_to_string(path).map_err(|err| format!("cannot read '{}' for bundle: {}", path, err));
+
+    let format = matches.value_of(FORMAT_ARG_NAME).unwrap();
+    let problem = read_file(matches.value_of(PROBLEM_ARG_NAME).unwrap())?;
+    let matrices = matches.values_of(MATRIX_ARG_NAME).map_or(Ok(vec![]), |paths| paths.map(read_file).collect())?;
+    let config = matches.value_of(CONFIG_ARG_NAME).map(read_file).transpose()?;
+
+    let bundle = ReproductionBundle::new(format, problem, matrices, config, environment).with_error(error);
+
+    write_bundle(&bundle, create_write_buffer(Some(create_file(bundle_path, "bundle"))))
+}
+
 fn check_pragmatic_solution_with_args(matches: &ArgMatches) -> Result<(), String> {
     check_solution(matches, "pragmatic", PROBLEM_ARG_NAME, OUT_RESULT_ARG_NAME, MATRIX_ARG_NAME)
 }
@@ -541,6 +661,10 @@ pub fn create_interruption_quota(max_time: Option<usize>) -> Arc<dyn Quota + Sen
             self.inner.as_ref().map_or(false, |inner| inner.is_reached())
                 || self.should_interrupt.load(Ordering::Relaxed)
         }
+
+        fn time_limit(&self) -> Option<f64> {
+            self.inner.as_ref().and_then(|inner| inner.time_limit())
+        }
     }
 
     let inner = max_time.map::<Arc<dyn Quota + Send + Sync>, _>(|time| Arc::new(TimeQuota::new(time as f64)));
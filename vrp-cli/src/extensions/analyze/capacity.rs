@@ -0,0 +1,17 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/capacity_test.rs"]
+mod capacity_test;
+
+use std::io::{BufReader, Read};
+use vrp_pragmatic::format::problem::{check_capacity_feasibility, deserialize_problem};
+use vrp_pragmatic::format::FormatError;
+
+/// Compares total demand of jobs with total fleet capacity, overall and per required skills, and
+/// reports the shortfall per dimension with suggested amount of extra vehicles per vehicle type.
+pub fn get_capacity_analysis<F: Read>(problem_reader: BufReader<F>) -> Result<String, String> {
+    let problem = deserialize_problem(problem_reader).map_err(|errs| FormatError::format_many(&errs, ","))?;
+
+    let report = check_capacity_feasibility(&problem);
+
+    serde_json::to_string_pretty(&report).map_err(|err| format!("cannot serialize capacity report: '{}'", err))
+}
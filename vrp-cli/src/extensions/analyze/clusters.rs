@@ -2,15 +2,14 @@
 #[path = "../../../tests/unit/extensions/analyze/clusters_test.rs"]
 mod clusters_test;
 
+use super::get_core_problem;
 use std::io::{BufReader, BufWriter, Read};
 use std::sync::Arc;
 use vrp_core::construction::clustering::dbscan::create_job_clusters;
 use vrp_core::models::common::IdDimension;
 use vrp_core::models::problem::get_job_locations;
-use vrp_core::models::Problem;
 use vrp_core::utils::Environment;
 use vrp_pragmatic::format::get_coord_index;
-use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
 use vrp_pragmatic::format::solution::serialize_named_locations_as_geojson;
 use vrp_pragmatic::format::FormatError;
 
@@ -55,18 +54,3 @@ pub fn get_clusters<F: Read>(
 
     Ok(buffer)
 }
-
-fn get_core_problem<F: Read>(
-    problem_reader: BufReader<F>,
-    matrices_readers: Option<Vec<BufReader<F>>>,
-) -> Result<Problem, Vec<FormatError>> {
-    let problem = deserialize_problem(problem_reader)?;
-
-    let matrices = matrices_readers.map(|matrices| {
-        matrices.into_iter().map(|file| deserialize_matrix(BufReader::new(file))).collect::<Result<Vec<_>, _>>()
-    });
-
-    let matrices = if let Some(matrices) = matrices { Some(matrices?) } else { None };
-
-    (problem, matrices).read_pragmatic()
-}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/feasibility_test.rs"]
+mod feasibility_test;
+
+use super::get_core_problem;
+use serde::Serialize;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::{get_feasible_actors_per_job, InsertionContext};
+use vrp_core::models::common::{IdDimension, ValueDimension};
+use vrp_core::utils::Environment;
+use vrp_pragmatic::format::dimens::*;
+use vrp_pragmatic::format::FormatError;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FeasibleVehicle {
+    vehicle_id: String,
+    shift_index: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JobFeasibility {
+    job_id: String,
+    vehicles: Vec<FeasibleVehicle>,
+}
+
+/// Gets vehicle shifts which can serve each job in isolation. Jobs with empty list of vehicles
+/// cannot be assigned at all.
+pub fn get_feasible_vehicles<F: Read>(
+    problem_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<String, String> {
+    let problem = Arc::new(
+        get_core_problem(problem_reader, matrices_readers).map_err(|errs| FormatError::format_many(&errs, ","))?,
+    );
+
+    let insertion_ctx = InsertionContext::new(problem, Arc::new(Environment::default()));
+
+    let mut feasibility = get_feasible_actors_per_job(&insertion_ctx)
+        .into_iter()
+        // NOTE skip vehicle specific jobs such as breaks, reloads, etc.
+        .filter(|(job, _)| job.dimens().get_dimen(&JOB_VEHICLE_ID_KEY).is_none())
+        .filter_map(|(job, actors)| {
+            let mut vehicles = actors
+                .iter()
+                .filter_map(|actor| {
+                    let dimens = &actor.vehicle.dimens;
+                    dimens.get_id().cloned().zip(dimens.get_dimen(&SHIFT_INDEX_KEY).cloned())
+                })
+                .map(|(vehicle_id, shift_index)| FeasibleVehicle { vehicle_id, shift_index })
+                .collect::<Vec<_>>();
+            vehicles.sort_by(|a, b| a.vehicle_id.cmp(&b.vehicle_id).then(a.shift_index.cmp(&b.shift_index)));
+
+            job.dimens().get_id().cloned().map(|job_id| JobFeasibility { job_id, vehicles })
+        })
+        .collect::<Vec<_>>();
+    feasibility.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    serde_json::to_string_pretty(&feasibility).map_err(|err| format!("cannot serialize feasibility: '{}'", err))
+}
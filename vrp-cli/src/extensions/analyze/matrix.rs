@@ -0,0 +1,25 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/matrix_test.rs"]
+mod matrix_test;
+
+use std::io::{BufReader, Read};
+use vrp_pragmatic::format::problem::{check_matrix_consistency, deserialize_matrix, MatrixConsistencySettings};
+use vrp_pragmatic::format::FormatError;
+
+/// Checks consistency of routing matrices and returns per-cell diagnostics: asymmetric values,
+/// triangle inequality violations and zero durations with nonzero distances.
+pub fn get_matrix_analysis<F: Read>(
+    matrices_readers: Vec<BufReader<F>>,
+    settings: &MatrixConsistencySettings,
+) -> Result<String, String> {
+    let issues = matrices_readers
+        .into_iter()
+        .map(|reader| deserialize_matrix(reader).map_err(|errs| FormatError::format_many(&errs, ",")))
+        .map(|matrix| matrix.and_then(|matrix| check_matrix_consistency(&matrix, settings)))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    serde_json::to_string_pretty(&issues).map_err(|err| format!("cannot serialize matrix issues: '{}'", err))
+}
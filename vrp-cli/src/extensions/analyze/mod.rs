@@ -1,4 +1,36 @@
 //! Provides functionality for problem/solution analysis.
 
+mod capacity;
+pub use self::capacity::get_capacity_analysis;
+
 mod clusters;
 pub use self::clusters::get_clusters;
+
+mod feasibility;
+pub use self::feasibility::get_feasible_vehicles;
+
+mod matrix;
+pub use self::matrix::get_matrix_analysis;
+
+mod unassigned;
+pub use self::unassigned::get_unassigned_analysis;
+
+use std::io::{BufReader, Read};
+use vrp_core::models::Problem;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
+use vrp_pragmatic::format::FormatError;
+
+fn get_core_problem<F: Read>(
+    problem_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<Problem, Vec<FormatError>> {
+    let problem = deserialize_problem(problem_reader)?;
+
+    let matrices = matrices_readers.map(|matrices| {
+        matrices.into_iter().map(|file| deserialize_matrix(BufReader::new(file))).collect::<Result<Vec<_>, _>>()
+    });
+
+    let matrices = if let Some(matrices) = matrices { Some(matrices?) } else { None };
+
+    (problem, matrices).read_pragmatic()
+}
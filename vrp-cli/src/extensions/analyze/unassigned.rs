@@ -0,0 +1,29 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/unassigned_test.rs"]
+mod unassigned_test;
+
+use super::get_core_problem;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::utils::Environment;
+use vrp_pragmatic::format::solution::{analyze_unassigned, read_init_solution};
+use vrp_pragmatic::format::FormatError;
+
+/// Analyzes unassigned jobs of the given solution: each job is evaluated against every vehicle
+/// shift and failure reasons are reported per vehicle shift with suggested relaxations.
+pub fn get_unassigned_analysis<F: Read, S: Read>(
+    problem_reader: BufReader<F>,
+    solution_reader: BufReader<S>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+) -> Result<String, String> {
+    let problem = Arc::new(
+        get_core_problem(problem_reader, matrices_readers).map_err(|errs| FormatError::format_many(&errs, ","))?,
+    );
+
+    let environment = Arc::new(Environment::default());
+    let solution = read_init_solution(solution_reader, problem.clone(), environment.random.clone())?;
+
+    let unassigned = analyze_unassigned(problem, solution, environment);
+
+    serde_json::to_string_pretty(&unassigned).map_err(|err| format!("cannot serialize unassigned jobs: '{}'", err))
+}
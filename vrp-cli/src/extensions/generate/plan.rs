@@ -37,6 +37,7 @@ pub(crate) fn generate_plan(
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            parking: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -45,6 +46,8 @@ pub(crate) fn generate_plan(
                         get_random_item(demands.as_slice(), &rnd).cloned()
                     },
                     order: task.order,
+                    skills: task.skills.clone(),
+                    not_before: None,
                 })
                 .collect::<Vec<_>>()
         })
@@ -68,11 +71,15 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                buffer: job_proto.buffer,
+                is_prioritized: job_proto.is_prioritized,
+                sync: job_proto.sync.clone(),
+                transfer: job_proto.transfer.clone(),
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, areas: None, clustering: None })
+    Ok(Plan { jobs, relations: None, areas: None, clustering: None, hints: None, readonly_routes: None })
 }
 
 fn get_location_fn(
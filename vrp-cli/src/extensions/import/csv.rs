@@ -71,9 +71,12 @@ mod actual {
                 duration: job.duration as f64 * 60.,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                parking: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             order: None,
+            skills: None,
+            not_before: None,
         };
 
         let get_tasks = |jobs: &Vec<&CsvJob>, filter: Box<dyn Fn(&CsvJob) -> bool>| {
@@ -102,6 +105,10 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                buffer: None,
+                is_prioritized: None,
+                sync: None,
+                transfer: None,
             })
             .collect();
 
@@ -118,21 +125,37 @@ mod actual {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                     profile: VehicleProfile { matrix: vehicle.profile, scale: None },
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    costs: VehicleCosts {
+                        fixed: Some(25.),
+                        distance: 0.0002,
+                        time: 0.005,
+                        waiting: None,
+                        depot_waiting: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
                             latest: None,
+                            optimize_departure: None,
                             location: depot_location.clone(),
                         },
-                        end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: vehicle.tw_end,
+                            location: depot_location,
+                            alternatives: None,
+                        }),
                         dispatch: None,
                         breaks: None,
                         reloads: None,
+                        loading: None,
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
                     limits: None,
+                    carry_load: None,
+                    echelon: None,
+                    service_time_factor: None,
                 }
             })
             .collect();
@@ -159,12 +182,22 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, areas: None, clustering: None },
+            plan: Plan { jobs, relations: None, areas: None, clustering: None, hints: None, readonly_routes: None },
             fleet: Fleet {
                 vehicles,
-                profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile {
+                        name,
+                        speed: None,
+                        detour_factor: None,
+                        time_of_day: None,
+                        leg_overrides: None,
+                    })
+                    .collect(),
             },
             objectives: None,
+            units: None,
         })
     }
 }
@@ -8,4 +8,6 @@ pub mod check;
 pub mod generate;
 
 pub mod import;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod routing;
 pub mod solve;
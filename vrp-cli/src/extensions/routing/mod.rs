@@ -0,0 +1,4 @@
+//! Routing matrix helpers.
+
+mod osrm;
+pub use self::osrm::*;
@@ -0,0 +1,200 @@
+//! Builds routing matrices using OSRM table service.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/routing/osrm_test.rs"]
+mod osrm_test;
+
+pub use self::actual::fetch_table;
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use vrp_pragmatic::format::problem::{Matrix, Problem};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::get_unique_locations;
+
+/// Specifies OSRM table service settings.
+#[derive(Clone, Debug)]
+pub struct OsrmConfig {
+    /// A service url, e.g. `http://localhost:5000`.
+    pub url: String,
+    /// Max amount of locations used as sources or destinations within one request.
+    pub chunk_size: usize,
+    /// Maps profile names used in the problem to OSRM profiles. Not mapped profile name is used as is.
+    pub profiles: HashMap<String, String>,
+}
+
+impl OsrmConfig {
+    /// Creates a new instance of `OsrmConfig` with default chunk size and no profile mapping.
+    pub fn new(url: &str) -> Self {
+        Self { url: url.trim_end_matches('/').to_string(), chunk_size: 100, profiles: Default::default() }
+    }
+}
+
+/// A response of OSRM table service.
+#[derive(Clone, Debug, Deserialize)]
+pub struct OsrmTable {
+    /// A response code, `Ok` when request is successful.
+    pub code: String,
+    /// An error message.
+    pub message: Option<String>,
+    /// Durations in seconds, null when route cannot be found.
+    pub durations: Option<Vec<Vec<Option<f64>>>>,
+    /// Distances in meters, null when route cannot be found.
+    pub distances: Option<Vec<Vec<Option<f64>>>>,
+}
+
+/// Creates routing matrices for all problem's profiles using OSRM table service.
+/// All problem locations are expected to be geocoordinates.
+pub fn create_osrm_matrices(problem: &Problem, config: &OsrmConfig) -> Result<Vec<Matrix>, String> {
+    create_matrices(problem, config, &fetch_table)
+}
+
+/// Creates routing matrices using given function which fetches table for the url.
+pub(crate) fn create_matrices(
+    problem: &Problem,
+    config: &OsrmConfig,
+    fetch_fn: &dyn Fn(&str) -> Result<OsrmTable, String>,
+) -> Result<Vec<Matrix>, String> {
+    if config.chunk_size == 0 {
+        return Err("chunk size should be greater than zero".to_string());
+    }
+
+    let coordinates = get_unique_locations(problem)
+        .into_iter()
+        .map(|location| match location {
+            Location::Coordinate { lat, lng } => Ok((lat, lng)),
+            Location::Reference { .. } => Err("OSRM table service requires geocoordinates only".to_string()),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    problem
+        .fleet
+        .profiles
+        .iter()
+        .map(|profile| {
+            let osrm_profile = config.profiles.get(&profile.name).unwrap_or(&profile.name);
+            let mut matrix = create_matrix(config, osrm_profile.as_str(), coordinates.as_slice(), fetch_fn)?;
+            matrix.profile = Some(profile.name.clone());
+
+            Ok(matrix)
+        })
+        .collect()
+}
+
+/// Creates OSRM table service url for given sources and destinations.
+pub(crate) fn create_table_url(
+    config: &OsrmConfig,
+    profile: &str,
+    sources: &[(f64, f64)],
+    destinations: &[(f64, f64)],
+) -> String {
+    let coordinates =
+        sources.iter().chain(destinations.iter()).map(|(lat, lng)| format!("{},{}", lng, lat)).collect::<Vec<_>>();
+    let join_indices = |range: std::ops::Range<usize>| range.map(|idx| idx.to_string()).collect::<Vec<_>>().join(";");
+
+    format!(
+        "{}/table/v1/{}/{}?sources={}&destinations={}&annotations=duration,distance",
+        config.url,
+        profile,
+        coordinates.join(";"),
+        join_indices(0..sources.len()),
+        join_indices(sources.len()..sources.len() + destinations.len()),
+    )
+}
+
+fn create_matrix(
+    config: &OsrmConfig,
+    profile: &str,
+    coordinates: &[(f64, f64)],
+    fetch_fn: &dyn Fn(&str) -> Result<OsrmTable, String>,
+) -> Result<Matrix, String> {
+    let size = coordinates.len();
+    let mut travel_times = vec![0; size * size];
+    let mut distances = vec![0; size * size];
+    let mut error_codes = vec![0; size * size];
+
+    // NOTE public OSRM instances limit amount of locations in one request, so the table is split into chunks
+    let chunks = coordinates.chunks(config.chunk_size).enumerate().collect::<Vec<_>>();
+    for (source_chunk_idx, sources) in chunks.iter() {
+        for (destination_chunk_idx, destinations) in chunks.iter() {
+            let url = create_table_url(config, profile, sources, destinations);
+            let table = fetch_fn(url.as_str())?;
+
+            if table.code != "Ok" {
+                return Err(format!(
+                    "OSRM table service returned '{}' code: '{}'",
+                    table.code,
+                    table.message.unwrap_or_default()
+                ));
+            }
+
+            let table_durations = table.durations.ok_or_else(|| "OSRM response has no durations".to_string())?;
+            let table_distances = table.distances.ok_or_else(|| "OSRM response has no distances".to_string())?;
+
+            for (source_offset, (duration_row, distance_row)) in
+                table_durations.iter().zip(table_distances.iter()).enumerate()
+            {
+                for (destination_offset, (duration, distance)) in
+                    duration_row.iter().zip(distance_row.iter()).enumerate()
+                {
+                    let from = source_chunk_idx * config.chunk_size + source_offset;
+                    let to = destination_chunk_idx * config.chunk_size + destination_offset;
+
+                    if from >= size || to >= size {
+                        return Err("OSRM response has unexpected table size".to_string());
+                    }
+
+                    let idx = from * size + to;
+                    match (duration, distance) {
+                        (Some(duration), Some(distance)) => {
+                            travel_times[idx] = duration.round() as i64;
+                            distances[idx] = distance.round() as i64;
+                        }
+                        _ => error_codes[idx] = 1,
+                    }
+                }
+            }
+        }
+    }
+
+    let has_errors = error_codes.iter().any(|code| *code != 0);
+
+    Ok(Matrix {
+        profile: None,
+        timestamp: None,
+        travel_times,
+        distances,
+        error_codes: if has_errors { Some(error_codes) } else { None },
+        units: None,
+        tolls: None,
+    })
+}
+
+#[cfg(feature = "osrm")]
+mod actual {
+    use super::OsrmTable;
+
+    /// Fetches routing table from OSRM service using given url.
+    pub fn fetch_table(url: &str) -> Result<OsrmTable, String> {
+        let response = match ureq::get(url).call() {
+            Ok(response) => response,
+            // NOTE OSRM returns error details within response body
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => return Err(format!("cannot call OSRM table service: '{}'", err)),
+        };
+
+        let body = response.into_string().map_err(|err| format!("cannot read OSRM response: '{}'", err))?;
+
+        serde_json::from_str(body.as_str()).map_err(|err| format!("cannot deserialize OSRM response: '{}'", err))
+    }
+}
+
+#[cfg(not(feature = "osrm"))]
+mod actual {
+    use super::OsrmTable;
+
+    /// A stub method for fetching routing table from OSRM service.
+    pub fn fetch_table(_url: &str) -> Result<OsrmTable, String> {
+        Err("osrm feature is not included".to_string())
+    }
+}
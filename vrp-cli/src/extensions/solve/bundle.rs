@@ -0,0 +1,68 @@
+//! Provides the way to export all inputs of a solver run into a single self-contained file, so
+//! the run can be replayed exactly, e.g. when a user reports an issue.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/bundle_test.rs"]
+mod bundle_test;
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, BufWriter, Read, Write};
+use vrp_core::rosomaxa::prelude::Environment;
+
+/// Contains everything needed to reproduce a solver run.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReproductionBundle {
+    /// A version of the solver which was used.
+    pub version: String,
+    /// A problem format, e.g. `pragmatic`, `solomon`.
+    pub format: String,
+    /// A seed of random generator, if it was specified.
+    pub seed: Option<u64>,
+    /// A problem definition as it was passed to the solver.
+    pub problem: String,
+    /// Routing matrices as they were passed to the solver.
+    #[serde(default)]
+    pub matrices: Vec<String>,
+    /// An algorithm configuration, if it was used.
+    pub config: Option<String>,
+    /// An error returned by the solver, if any.
+    pub error: Option<String>,
+}
+
+impl ReproductionBundle {
+    /// Creates a new instance of `ReproductionBundle` for a run in given environment.
+    pub fn new(
+        format: &str,
+        problem: String,
+        matrices: Vec<String>,
+        config: Option<String>,
+        environment: &Environment,
+    ) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            format: format.to_string(),
+            seed: environment.random.get_seed(),
+            problem,
+            matrices,
+            config,
+            error: None,
+        }
+    }
+
+    /// Sets an error returned by the solver.
+    pub fn with_error(mut self, error: Option<String>) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+/// Writes reproduction bundle as json.
+pub fn write_bundle<W: Write>(bundle: &ReproductionBundle, writer: BufWriter<W>) -> Result<(), String> {
+    serde_json::to_writer_pretty(writer, bundle).map_err(|err| format!("cannot write bundle: {}", err))
+}
+
+/// Reads reproduction bundle from json.
+pub fn read_bundle<R: Read>(reader: BufReader<R>) -> Result<ReproductionBundle, String> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot read bundle: {}", err))
+}
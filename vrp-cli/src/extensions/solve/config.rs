@@ -9,16 +9,20 @@ mod config_test;
 extern crate serde_json;
 
 use serde::Deserialize;
-use std::io::{BufReader, Read};
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter, Read};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::common::SingleDimLoad;
-use vrp_core::models::problem::ProblemObjective;
+use vrp_core::models::problem::{ProblemObjective, TieBreaker};
 use vrp_core::prelude::*;
-use vrp_core::rosomaxa::evolution::{InitialOperator, Telemetry, TelemetryMode};
-use vrp_core::rosomaxa::get_default_selection_size;
+use vrp_core::rosomaxa::evolution::{
+    InitialOperator, IslandFactory, JsonLinesSink, ProgressMetrics, ProgressMetricsExporter, ProgressMetricsSink,
+    RunIslands, Telemetry, TelemetryMode,
+};
 use vrp_core::rosomaxa::prelude::*;
 use vrp_core::rosomaxa::utils::*;
+use vrp_core::rosomaxa::{get_default_population, get_default_selection_size};
 use vrp_core::solver::search::*;
 use vrp_core::solver::RecreateInitialOperator;
 use vrp_core::solver::*;
@@ -36,6 +40,8 @@ pub struct Config {
     pub environment: Option<EnvironmentConfig>,
     /// Specifies telemetry configuration.
     pub telemetry: Option<TelemetryConfig>,
+    /// Specifies final solution polishing configuration.
+    pub polishing: Option<PolishingConfig>,
 }
 
 /// An evolution configuration.
@@ -44,6 +50,35 @@ pub struct Config {
 pub struct EvolutionConfig {
     pub initial: Option<InitialConfig>,
     pub population: Option<PopulationType>,
+    pub islands: Option<IslandsConfig>,
+    /// Specifies tie-breakers applied in given order to solutions with equal fitness, so that results
+    /// are stable across runs. No tie-breaking by default.
+    pub tie_breaking: Option<Vec<TieBreakingType>>,
+}
+
+/// A tie-breaker type for solutions with equal fitness.
+#[derive(Clone, Deserialize, Debug)]
+pub enum TieBreakingType {
+    /// Prefers solution with fewer routes.
+    #[serde(rename(deserialize = "fewer-routes"))]
+    FewerRoutes,
+    /// Prefers solution with the earliest finish of the latest tour.
+    #[serde(rename(deserialize = "earlier-finish"))]
+    EarlierFinish,
+    /// Prefers solution with lexicographically smaller job assignment.
+    #[serde(rename(deserialize = "lexicographic"))]
+    Lexicographic,
+}
+
+/// An island model configuration: several populations evolve in parallel and exchange their best
+/// individuals periodically. Additional islands use default population and hyper heuristic.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IslandsConfig {
+    /// Amount of islands.
+    pub count: usize,
+    /// Amount of generations between migrations. Default is 100.
+    pub migration_interval: Option<usize>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -93,6 +128,28 @@ pub enum PopulationType {
         rebalance_count: Option<usize>,
         /// An exploration phase ratio. Default is 0.9.
         exploration_ratio: Option<f64>,
+        /// A batch size used to train network. Default is selection size.
+        batch_size: Option<usize>,
+        /// A reduction of solution weights used to train network. Default is no reduction.
+        weight_reduction: Option<WeightReductionType>,
+    },
+}
+
+/// A reduction of solution weights used by rosomaxa population.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum WeightReductionType {
+    /// Only weights with given indices are used.
+    #[serde(rename(deserialize = "subset"))]
+    Subset {
+        /// Indices of weights to be used.
+        indices: Vec<usize>,
+    },
+    /// Weights are projected on given amount of principal components.
+    #[serde(rename(deserialize = "pca"))]
+    Pca {
+        /// Amount of principal components.
+        dimensions: usize,
     },
 }
 
@@ -139,6 +196,27 @@ pub enum HyperType {
     /// learning technics.
     #[serde(rename(deserialize = "dynamic-selective"))]
     DynamicSelective,
+
+    /// A hyper heuristic which uses guided local search: long arcs are penalized when the search
+    /// is stuck in local optimum, so inner operators are guided away from them.
+    #[serde(rename(deserialize = "guided-local-search"))]
+    GuidedLocalSearch {
+        /// Amount of inner search iterations per solution. Default is 5.
+        iterations: Option<usize>,
+        /// A penalty weight relatively to an average arc cost. Default is 0.3.
+        alpha: Option<f64>,
+    },
+
+    /// A hyper heuristic which uses adaptive large neighborhood search: ruin and recreate methods
+    /// are selected using weights adapted to their recent performance.
+    #[serde(rename(deserialize = "alns"))]
+    #[serde(rename_all = "camelCase")]
+    AdaptiveLargeNeighborhoodSearch {
+        /// Amount of ruin and recreate applications after which weights are updated. Default is 100.
+        segment_size: Option<usize>,
+        /// Controls how quickly weights react to the recent performance, in range [0, 1]. Default is 0.1.
+        reaction_factor: Option<f64>,
+    },
 }
 
 /// A operator configuration.
@@ -328,6 +406,9 @@ pub enum LocalOperatorType {
 
     #[serde(rename(deserialize = "sequence"))]
     Sequence { weight: usize },
+
+    #[serde(rename(deserialize = "two-opt"))]
+    TwoOpt { weight: usize },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -354,11 +435,24 @@ pub struct VariationConfig {
     is_global: bool,
 }
 
+/// A final solution polishing config.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PolishingConfig {
+    /// Specifies whether exhaustive polishing of the final solution is enabled.
+    pub enabled: bool,
+    /// Specifies time budget of polishing in seconds. Default is 10.
+    pub max_time: Option<usize>,
+}
+
 /// A telemetry config.
 #[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct TelemetryConfig {
     progress: Option<ProgressConfig>,
     metrics: Option<MetricsConfig>,
+    json_lines: Option<JsonLinesConfig>,
+    open_metrics: Option<OpenMetricsConfig>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -381,11 +475,35 @@ pub struct MetricsConfig {
     enabled: bool,
     /// Specifies how often population is tracked. Default is 1000 (generations).
     track_population: Option<usize>,
+    /// Specifies how often fitness of the whole population is captured. Default is none (no snapshots).
+    snapshot_population: Option<usize>,
 }
 
-/// An environment specific configuration.
 #[derive(Clone, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
+pub struct JsonLinesConfig {
+    /// Specifies whether streaming of generation statistics as JSON lines is enabled. Default is false.
+    enabled: bool,
+    /// Specifies a path to the file where JSON lines are appended.
+    path: String,
+    /// Specifies how often generation is written. Default is 1 (each generation).
+    log_generation: Option<usize>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenMetricsConfig {
+    /// Specifies whether export of progress metrics in OpenMetrics text format is enabled. Default is false.
+    enabled: bool,
+    /// Specifies a path to the file which is overwritten with the latest metrics.
+    path: String,
+    /// Specifies how often metrics are exported. Default is 100 (generations).
+    export_generation: Option<usize>,
+}
+
+/// An environment specific configuration.
+#[derive(Clone, Default, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct EnvironmentConfig {
     /// Specifies a data parallelism configuration.
     pub parallelism: Option<ParallelismConfig>,
@@ -395,6 +513,13 @@ pub struct EnvironmentConfig {
 
     /// Specifies experimental behavior flag.
     pub is_experimental: Option<bool>,
+
+    /// Specifies strict mode flag: any internal inconsistency stops solver with an error.
+    pub is_strict: Option<bool>,
+
+    /// Specifies a seed of random generator. When it is set, the search runs on a single thread and
+    /// parallelism settings are ignored.
+    pub seed: Option<u64>,
 }
 
 /// Data parallelism configuration.
@@ -429,6 +554,31 @@ pub struct NameWeight {
     pub weight: usize,
 }
 
+fn configure_tie_breaking(problem: Arc<Problem>, evolution_config: &Option<EvolutionConfig>) -> Arc<Problem> {
+    let tie_breakers = match evolution_config.as_ref().and_then(|config| config.tie_breaking.as_ref()) {
+        Some(tie_breaking) => tie_breaking
+            .iter()
+            .map(|tie_breaker| match tie_breaker {
+                TieBreakingType::FewerRoutes => TieBreaker::FewerRoutes,
+                TieBreakingType::EarlierFinish => TieBreaker::EarlierFinish,
+                TieBreakingType::Lexicographic => TieBreaker::Lexicographic,
+            })
+            .collect(),
+        None => return problem,
+    };
+
+    Arc::new(Problem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        constraint: problem.constraint.clone(),
+        activity: problem.activity.clone(),
+        transport: problem.transport.clone(),
+        objective: Arc::new(problem.objective.as_ref().clone().with_tie_breakers(tie_breakers)),
+        extras: problem.extras.clone(),
+    })
+}
+
 fn configure_from_evolution(
     mut builder: ProblemConfigBuilder,
     problem: Arc<Problem>,
@@ -467,6 +617,28 @@ fn configure_from_evolution(
             );
         }
 
+        if let Some(islands) = &config.islands {
+            // NOTE use problem and environment of the main island as its problem is already pre-processed
+            let island_factory: IslandFactory<_, _, _> = Box::new(move |main_ctx: &RefinementContext| {
+                let (problem, environment) = (main_ctx.problem.clone(), main_ctx.environment.clone());
+                let population = get_default_population::<RefinementContext, _, _>(
+                    problem.objective.clone(),
+                    environment.clone(),
+                    problem.jobs.size(),
+                );
+                let context = RefinementContext::new(problem.clone(), population, environment.clone());
+
+                (context, get_default_heuristic(problem, environment))
+            });
+
+            builder = builder.with_strategy(Box::new(RunIslands::new(
+                1,
+                islands.count,
+                islands.migration_interval.unwrap_or(100),
+                island_factory,
+            )));
+        }
+
         if let Some(variation) = &config.population {
             let default_selection_size = get_default_selection_size(environment.as_ref());
             let population = match &variation {
@@ -492,6 +664,8 @@ fn configure_from_evolution(
                     rebalance_memory,
                     rebalance_count,
                     exploration_ratio,
+                    batch_size,
+                    weight_reduction,
                 } => {
                     let mut config = RosomaxaConfig::new_with_defaults(default_selection_size);
                     if let Some(selection_size) = selection_size {
@@ -524,6 +698,15 @@ fn configure_from_evolution(
                     if let Some(exploration_ratio) = exploration_ratio {
                         config.exploration_ratio = *exploration_ratio;
                     }
+                    if let Some(batch_size) = batch_size {
+                        config.batch_size = *batch_size;
+                    }
+                    if let Some(weight_reduction) = weight_reduction {
+                        config.weight_reduction = match weight_reduction {
+                            WeightReductionType::Subset { indices } => WeightReduction::Subset(indices.clone()),
+                            WeightReductionType::Pca { dimensions } => WeightReduction::Pca(*dimensions),
+                        };
+                    }
 
                     Box::new(RosomaxaPopulation::new(problem.objective.clone(), environment.clone(), config)?)
                 }
@@ -561,6 +744,24 @@ fn configure_from_hyper(
                 let dynamic_selective = get_dynamic_heuristic(problem, environment);
                 builder = builder.with_heuristic(dynamic_selective);
             }
+            HyperType::GuidedLocalSearch { iterations, alpha } => {
+                let guided_local_search = get_guided_local_search_heuristic_with_params(
+                    problem,
+                    environment,
+                    iterations.unwrap_or(5),
+                    alpha.unwrap_or(0.3),
+                );
+                builder = builder.with_heuristic(guided_local_search);
+            }
+            HyperType::AdaptiveLargeNeighborhoodSearch { segment_size, reaction_factor } => {
+                let alns = get_alns_heuristic_with_params(
+                    problem,
+                    environment,
+                    segment_size.unwrap_or(100),
+                    reaction_factor.unwrap_or(0.1),
+                );
+                builder = builder.with_heuristic(alns);
+            }
         }
     }
 
@@ -581,6 +782,18 @@ fn configure_from_termination(
     builder
 }
 
+fn configure_from_polishing(
+    builder: ProblemConfigBuilder,
+    polishing_config: &Option<PolishingConfig>,
+) -> ProblemConfigBuilder {
+    match polishing_config {
+        Some(PolishingConfig { enabled: true, max_time }) => {
+            builder.with_processing(create_polishing_processing(max_time.unwrap_or(10) as f64))
+        }
+        _ => builder,
+    }
+}
+
 fn create_recreate_method(
     method: &RecreateMethod,
     environment: Arc<Environment>,
@@ -727,6 +940,7 @@ fn create_local_search(
                 (Arc::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
             }
             LocalOperatorType::Sequence { weight } => (Arc::new(ExchangeSequence::default()), *weight),
+            LocalOperatorType::TwoOpt { weight } => (Arc::new(ExchangeTwoOpt::default()), *weight),
         })
         .collect::<Vec<_>>();
 
@@ -737,14 +951,16 @@ fn configure_from_telemetry(
     builder: ProblemConfigBuilder,
     environment: Arc<Environment>,
     telemetry_config: &Option<TelemetryConfig>,
-) -> ProblemConfigBuilder {
+) -> Result<ProblemConfigBuilder, String> {
     const LOG_BEST: usize = 100;
     const LOG_POPULATION: usize = 1000;
     const TRACK_POPULATION: usize = 1000;
 
-    let create_metrics = |track_population: &Option<usize>| TelemetryMode::OnlyMetrics {
-        track_population: track_population.unwrap_or(TRACK_POPULATION),
-    };
+    let create_metrics =
+        |track_population: &Option<usize>, snapshot_population: &Option<usize>| TelemetryMode::OnlyMetrics {
+            track_population: track_population.unwrap_or(TRACK_POPULATION),
+            snapshot_population: *snapshot_population,
+        };
 
     let create_progress = |log_best: &Option<usize>, log_population: &Option<usize>, dump_population: &Option<bool>| {
         TelemetryMode::OnlyLogging {
@@ -756,29 +972,70 @@ fn configure_from_telemetry(
     };
 
     let telemetry_mode = match telemetry_config.as_ref().map(|t| (&t.progress, &t.metrics)) {
-        Some((None, Some(MetricsConfig { enabled, track_population }))) if *enabled => create_metrics(track_population),
+        Some((None, Some(MetricsConfig { enabled, track_population, snapshot_population }))) if *enabled => {
+            create_metrics(track_population, snapshot_population)
+        }
         Some((Some(ProgressConfig { enabled, log_best, log_population, dump_population }), None)) if *enabled => {
             create_progress(log_best, log_population, dump_population)
         }
         Some((
             Some(ProgressConfig { enabled: progress_enabled, log_best, log_population, dump_population }),
-            Some(MetricsConfig { enabled: metrics_enabled, track_population }),
+            Some(MetricsConfig { enabled: metrics_enabled, track_population, snapshot_population }),
         )) => match (progress_enabled, metrics_enabled) {
             (true, true) => TelemetryMode::All {
                 logger: environment.logger.clone(),
                 log_best: log_best.unwrap_or(LOG_BEST),
                 log_population: log_population.unwrap_or(LOG_POPULATION),
                 track_population: track_population.unwrap_or(TRACK_POPULATION),
+                snapshot_population: *snapshot_population,
                 dump_population: dump_population.unwrap_or(false),
             },
             (true, false) => create_progress(log_best, log_population, dump_population),
-            (false, true) => create_metrics(track_population),
+            (false, true) => create_metrics(track_population, snapshot_population),
             _ => TelemetryMode::None,
         },
         _ => TelemetryMode::None,
     };
 
-    builder.with_telemetry(Telemetry::new(telemetry_mode))
+    let mut telemetry = Telemetry::new(telemetry_mode);
+
+    if let Some(JsonLinesConfig { enabled, path, log_generation }) =
+        telemetry_config.as_ref().and_then(|t| t.json_lines.as_ref())
+    {
+        if *enabled {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map_err(|err| format!("cannot open telemetry file '{}': '{}'", path, err))?;
+
+            telemetry
+                .add_sink(Box::new(JsonLinesSink::new(Box::new(BufWriter::new(file)), log_generation.unwrap_or(1))));
+        }
+    }
+
+    if let Some(OpenMetricsConfig { enabled, path, export_generation }) =
+        telemetry_config.as_ref().and_then(|t| t.open_metrics.as_ref())
+    {
+        if *enabled {
+            File::create(path).map_err(|err| format!("cannot create metrics file '{}': '{}'", path, err))?;
+
+            let path = path.clone();
+            let exporter: ProgressMetricsExporter = Box::new(move |text: &str| {
+                // NOTE write into temporary file first, so a collector never reads partially written metrics
+                let tmp_path = format!("{}.tmp", path);
+                let _ = std::fs::write(&tmp_path, text).and_then(|_| std::fs::rename(&tmp_path, &path));
+            });
+
+            telemetry.add_sink(Box::new(ProgressMetricsSink::new_with_exporter(
+                ProgressMetrics::default(),
+                export_generation.unwrap_or(100),
+                exporter,
+            )));
+        }
+    }
+
+    Ok(builder.with_telemetry(telemetry))
 }
 
 fn configure_from_environment(
@@ -787,7 +1044,18 @@ fn configure_from_environment(
 ) -> Arc<Environment> {
     let mut environment = Environment::new_with_time_quota(max_time);
 
-    if let Some(parallelism) = environment_config.as_ref().and_then(|c| c.parallelism.as_ref()) {
+    let seed = environment_config.as_ref().and_then(|c| c.seed);
+
+    if let Some(seed) = seed {
+        environment.random = Arc::new(DefaultRandom::new_with_seed(seed));
+    }
+
+    // NOTE wasm32 target is single threaded, so parallelism settings are ignored there
+    if let Some(parallelism) = environment_config
+        .as_ref()
+        .and_then(|c| c.parallelism.as_ref())
+        .filter(|_| seed.is_none() && !cfg!(target_arch = "wasm32"))
+    {
         // TODO validate parameters
         environment.parallelism = Parallelism::new(parallelism.num_thread_pools, parallelism.threads_per_pool);
     }
@@ -804,6 +1072,10 @@ fn configure_from_environment(
         environment.is_experimental = is_experimental;
     }
 
+    if let Some(is_strict) = environment_config.as_ref().and_then(|c| c.is_strict) {
+        environment.is_strict = is_strict;
+    }
+
     Arc::new(environment)
 }
 
@@ -825,14 +1097,16 @@ where
 
 /// Creates a solver `Builder` from config.
 pub fn create_builder_from_config(problem: Arc<Problem>, config: &Config) -> Result<ProblemConfigBuilder, String> {
+    let problem = configure_tie_breaking(problem, &config.evolution);
     let environment =
         configure_from_environment(&config.environment, config.termination.as_ref().and_then(|t| t.max_time));
     let mut builder = create_default_config_builder(problem.clone(), environment.clone());
 
-    builder = configure_from_telemetry(builder, environment.clone(), &config.telemetry);
+    builder = configure_from_telemetry(builder, environment.clone(), &config.telemetry)?;
     builder = configure_from_evolution(builder, problem.clone(), environment.clone(), &config.evolution)?;
     builder = configure_from_hyper(builder, problem, environment, &config.hyper)?;
     builder = configure_from_termination(builder, &config.termination);
+    builder = configure_from_polishing(builder, &config.polishing);
 
     Ok(builder)
 }
@@ -0,0 +1,229 @@
+//! Provides the way to solve a dynamic problem using rolling horizon approach: the problem is
+//! solved repeatedly over a sliding time window while already executed route prefixes are
+//! committed and newly arrived jobs are taken into account.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/horizon_test.rs"]
+mod horizon_test;
+
+use crate::extensions::solve::config::{create_builder_from_config, Config};
+use crate::get_errors_serialized;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use vrp_core::prelude::Solver;
+use vrp_pragmatic::format::is_reserved_job_id;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::solution::{create_solution, Solution, Tour};
+use vrp_pragmatic::parse_time_safe as parse_time;
+
+/// Specifies rolling horizon parameters.
+#[derive(Clone, Debug)]
+pub struct RollingHorizon {
+    /// A duration of the planning horizon in seconds: only jobs which can be started within it
+    /// are planned on each iteration.
+    pub horizon: f64,
+    /// A duration between two planning iterations in seconds. Activities planned to be started
+    /// within it are committed and cannot be changed by next iterations.
+    pub step: f64,
+    /// Job release times in RFC3339 format: a job is not known before its release time.
+    /// Jobs without release time are known from the beginning.
+    pub release_times: HashMap<String, String>,
+}
+
+/// Solves problem using rolling horizon approach. Planning starts at the earliest vehicle shift
+/// start and is repeated each `step` seconds until all jobs are considered. On each iteration,
+/// only released jobs which can be started within the horizon are planned, job activities of
+/// previous solution started before the next iteration are locked as strict route prefixes.
+/// When no new job becomes visible after the step, the next iteration starts at the time when
+/// the earliest hidden job becomes visible. Returns solution found on the last iteration.
+pub fn solve_with_rolling_horizon(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    horizon: &RollingHorizon,
+    config: &Config,
+) -> Result<Solution, String> {
+    if horizon.step <= 0. || horizon.horizon < horizon.step {
+        return Err("rolling horizon step should be positive and not greater than horizon".to_string());
+    }
+
+    let release_times = horizon
+        .release_times
+        .iter()
+        .map(|(job_id, time)| parse_time(time).map(|time| (job_id.clone(), time)))
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+    let mut time = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .map(|shift| parse_time(&shift.start.earliest))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .reduce(f64::min)
+        .ok_or_else(|| "cannot use rolling horizon without vehicle shifts".to_string())?;
+
+    let mut prefixes = Vec::<Relation>::new();
+
+    // NOTE each iteration makes at least one more job visible, so this limit is never reached normally
+    let max_iterations = problem.plan.jobs.len() + 1;
+
+    for _ in 0..max_iterations {
+        let committed = prefixes.iter().flat_map(|relation| relation.jobs.iter()).collect::<HashSet<_>>();
+
+        let (visible, hidden): (Vec<_>, Vec<_>) = problem
+            .plan
+            .jobs
+            .iter()
+            .filter(|job| !committed.contains(&job.id))
+            .map(|job| get_visible_time(job, &release_times, horizon.horizon).map(|visible_time| (job, visible_time)))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .partition(|(_, visible_time)| *visible_time <= time);
+
+        let jobs = problem
+            .plan
+            .jobs
+            .iter()
+            .filter(|job| committed.contains(&job.id))
+            .chain(visible.into_iter().map(|(job, _)| job))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let sub_problem = create_sub_problem(&problem, jobs, prefixes);
+        let solution = solve_problem(sub_problem, matrices.clone(), config)?;
+
+        let next_visible_time = match hidden.iter().map(|(_, visible_time)| *visible_time).reduce(f64::min) {
+            Some(next_visible_time) => next_visible_time,
+            None => return Ok(solution),
+        };
+
+        time = (time + horizon.step).max(next_visible_time);
+        prefixes = solution
+            .tours
+            .iter()
+            .map(|tour| get_committed_prefix(tour, time))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+    }
+
+    Err(format!("rolling horizon is not finished within {} iterations", max_iterations))
+}
+
+fn create_sub_problem(problem: &Problem, jobs: Vec<Job>, prefixes: Vec<Relation>) -> Problem {
+    let job_ids = jobs.iter().map(|job| job.id.as_str()).collect::<HashSet<_>>();
+    let committed = prefixes.iter().flat_map(|relation| relation.jobs.iter().cloned()).collect::<HashSet<_>>();
+
+    let relations = problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .filter_map(|relation| {
+            let relation_jobs = relation
+                .jobs
+                .iter()
+                .filter(|job_id| {
+                    is_reserved_job_id(job_id) || (job_ids.contains(job_id.as_str()) && !committed.contains(*job_id))
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if relation_jobs.iter().any(|job_id| !is_reserved_job_id(job_id)) {
+                Some(Relation { jobs: relation_jobs, ..relation.clone() })
+            } else {
+                None
+            }
+        })
+        .chain(prefixes)
+        .collect::<Vec<_>>();
+
+    Problem {
+        plan: Plan {
+            jobs,
+            relations: if relations.is_empty() { None } else { Some(relations) },
+            ..problem.plan.clone()
+        },
+        ..problem.clone()
+    }
+}
+
+fn solve_problem(problem: Problem, matrices: Option<Vec<Matrix>>, config: &Config) -> Result<Solution, String> {
+    let problem = Arc::new((problem, matrices).read_pragmatic().map_err(|errors| get_errors_serialized(&errors))?);
+
+    let (solution, _, _) = create_builder_from_config(problem.clone(), config)
+        .and_then(|builder| builder.build())
+        .map(|config| Solver::new(problem.clone(), config))
+        .and_then(|solver| solver.solve())?;
+
+    Ok(create_solution(problem.as_ref(), &solution, None))
+}
+
+/// Returns a strict relation which locks job activities started before given time. Jobs with
+/// multiple activities are committed only when all of their activities are started.
+fn get_committed_prefix(tour: &Tour, time: f64) -> Result<Option<Relation>, String> {
+    let activities = tour
+        .stops
+        .iter()
+        .flat_map(|stop| {
+            let arrival = &stop.schedule().arrival;
+            stop.activities().iter().filter(|activity| !is_reserved_job_id(&activity.job_id)).map(move |activity| {
+                let start = activity.time.as_ref().map_or(arrival, |interval| &interval.start);
+                parse_time(start).map(|start| (activity.job_id.clone(), start))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let totals = activities.iter().fold(HashMap::<&String, usize>::new(), |mut acc, (job_id, _)| {
+        *acc.entry(job_id).or_insert(0) += 1;
+        acc
+    });
+
+    let mut started = HashMap::<&String, usize>::new();
+    let mut size = 0;
+    for (idx, (job_id, start)) in activities.iter().enumerate() {
+        if *start >= time {
+            break;
+        }
+
+        *started.entry(job_id).or_insert(0) += 1;
+
+        if started.iter().all(|(job_id, count)| totals.get(job_id) == Some(count)) {
+            size = idx + 1;
+        }
+    }
+
+    Ok(if size > 0 {
+        Some(Relation {
+            type_field: RelationType::Strict,
+            jobs: std::iter::once("departure".to_string())
+                .chain(activities.into_iter().take(size).map(|(job_id, _)| job_id))
+                .collect(),
+            vehicle_id: tour.vehicle_id.clone(),
+            shift_index: Some(tour.shift_index),
+        })
+    } else {
+        None
+    })
+}
+
+/// Returns the time when the job becomes visible: it is released and its earliest time window
+/// starts within the horizon.
+fn get_visible_time(job: &Job, release_times: &HashMap<String, f64>, horizon: f64) -> Result<f64, String> {
+    let release = release_times.get(&job.id).cloned().unwrap_or(f64::MIN);
+
+    let earliest = [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+        .iter()
+        .flat_map(|tasks| tasks.iter().flatten())
+        .flat_map(|task| task.places.iter())
+        .flat_map(|place| place.times.iter().flatten())
+        .filter_map(|tw| tw.first())
+        .map(|start| parse_time(start))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .reduce(f64::min);
+
+    Ok(earliest.map_or(release, |earliest| release.max(earliest - horizon)))
+}
@@ -1,3 +1,5 @@
 //! Solve command helpers
 
+pub mod bundle;
 pub mod config;
+pub mod horizon;
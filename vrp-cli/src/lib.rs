@@ -35,9 +35,11 @@ pub mod extensions;
 use crate::extensions::import::import_problem;
 use crate::extensions::solve::config::{create_builder_from_config, Config};
 use std::io::{BufReader, BufWriter};
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::prelude::Solver;
+use vrp_core::rosomaxa::prelude::Objective;
 use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem};
 use vrp_pragmatic::format::solution::PragmaticSolution;
 use vrp_pragmatic::format::FormatError;
@@ -189,15 +191,10 @@ mod interop {
             let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len as usize).to_vec() };
             let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
 
-            let result =
-                if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
-                    .map_err(|errors| get_errors_serialized(&errors))
-                    .and_then(|problem| {
-                        read_config(BufReader::new(to_string(config).as_bytes()))
-                            .map_err(|err| to_config_error(err.as_str()))
-                            .map(|config| (problem, config))
-                    })
-                    .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config));
+            let result = read_config(BufReader::new(to_string(config).as_bytes()))
+                .map_err(SolveError::Config)
+                .and_then(|config| get_pragmatic_solution_serialized(problem, matrices, config, None, None))
+                .map_err(|err| err.to_message(get_errors_serialized));
 
             call_back(result, success, failure);
         });
@@ -388,29 +385,46 @@ mod wasm {
         }
     }
 
-    /// Solves Vehicle Routing Problem passed in `pragmatic` format.
+    /// Wraps javascript function to be used as progress callback.
+    struct JsProgress(js_sys::Function);
+
+    // NOTE wasm32 target is single threaded, so the function is never accessed from another thread
+    unsafe impl Send for JsProgress {}
+    unsafe impl Sync for JsProgress {}
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. An optional `progress` function
+    /// is called on each generation with generation number and the best known cost.
     #[wasm_bindgen]
-    pub fn solve_pragmatic(problem: &JsValue, matrices: &JsValue, config: &JsValue) -> Result<JsValue, JsValue> {
+    pub fn solve_pragmatic(
+        problem: &JsValue,
+        matrices: &JsValue,
+        config: &JsValue,
+        progress: Option<js_sys::Function>,
+    ) -> Result<JsValue, JsValue> {
         let problem: Problem = problem.into_serde().map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
-
         let matrices: Vec<Matrix> = matrices.into_serde().map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
 
-        let problem = Arc::new(
-            if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }.map_err(
-                |errors| {
-                    JsValue::from_str(errors.iter().map(|err| err.to_json()).collect::<Vec<_>>().join("\n").as_str())
-                },
-            )?,
-        );
+        let progress = progress.map::<ProgressCallback, _>(|progress| {
+            let progress = JsProgress(progress);
+            Box::new(move |generation, cost| {
+                progress
+                    .0
+                    .call2(&JsValue::NULL, &JsValue::from(generation as u32), &JsValue::from(cost))
+                    .map(|_| ())
+                    .map_err(|err| format!("{:?}", err))
+            })
+        });
 
-        let config: Config = config
+        config
             .into_serde()
-            .map_err(|err| to_config_error(&err.to_string()))
-            .map_err(|err| JsValue::from_str(err.as_str()))?;
-
-        get_solution_serialized(problem, config)
-            .map(|problem| JsValue::from_str(problem.as_str()))
-            .map_err(|err| JsValue::from_str(err.as_str()))
+            .map_err(|err| SolveError::Config(err.to_string()))
+            .and_then(|config| get_pragmatic_solution_serialized(problem, matrices, config, None, progress))
+            .map(|solution| JsValue::from_str(solution.as_str()))
+            .map_err(|err| {
+                let message =
+                    err.to_message(|errors| errors.iter().map(|err| err.to_json()).collect::<Vec<_>>().join("\n"));
+                JsValue::from_str(message.as_str())
+            })
     }
 }
 
@@ -426,28 +440,96 @@ pub fn get_locations_serialized(problem: &Problem) -> Result<String, String> {
     Ok(buffer)
 }
 
-/// Gets solution serialized in json.
-pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Result<String, String> {
-    let (solution, cost, metrics) = create_builder_from_config(problem.clone(), &config)
-        .and_then(|builder| builder.build())
-        .map(|config| Solver::new(problem.clone(), config))
-        .and_then(|solver| solver.solve())
-        .map_err(|err| {
-            FormatError::new(
+/// A callback which is called on each generation with generation number and the best known cost.
+/// An error returned by the callback is logged and does not stop the search.
+pub type ProgressCallback = Box<dyn Fn(usize, f64) -> Result<(), String> + Send + Sync>;
+
+/// Specifies a reason why a problem in `pragmatic` format cannot be solved.
+#[derive(Debug)]
+pub enum SolveError {
+    /// A problem or routing matrices cannot be read.
+    Problem(Vec<FormatError>),
+    /// An algorithm configuration cannot be read or solver cannot be built from it.
+    Config(String),
+    /// The solver has failed to find a solution or it cannot be serialized.
+    Solver(String),
+}
+
+impl SolveError {
+    /// Converts error to a message: problem errors are formatted using `format_errors` function,
+    /// other errors are serialized in json.
+    pub fn to_message<F: Fn(&[FormatError]) -> String>(self, format_errors: F) -> String {
+        match self {
+            SolveError::Problem(errors) => format_errors(&errors),
+            SolveError::Config(err) => to_config_error(err.as_str()),
+            SolveError::Solver(err) => FormatError::new(
                 "E0003".to_string(),
                 "cannot find any solution".to_string(),
                 format!("please submit a bug and share original problem and routing matrix. Error: '{}'", err),
             )
-            .to_json()
-        })?;
+            .to_json(),
+        }
+    }
+}
+
+/// Gets solution serialized in json.
+pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Result<String, String> {
+    solve_problem(problem, config, None, None).map_err(|err| err.to_message(get_errors_serialized))
+}
+
+/// Solves problem passed in `pragmatic` format with optional routing matrices and gets solution
+/// serialized in json. This is a common entry point for language bindings: if `interrupt` flag is
+/// set, the search is stopped and the best known solution is returned; if `progress` callback is
+/// specified, it is called on each generation.
+pub fn get_pragmatic_solution_serialized<P, M>(
+    problem: P,
+    matrices: Vec<M>,
+    config: Config,
+    interrupt: Option<Arc<AtomicBool>>,
+    progress: Option<ProgressCallback>,
+) -> Result<String, SolveError>
+where
+    P: PragmaticProblem,
+    (P, Vec<M>): PragmaticProblem,
+{
+    let problem = if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+        .map_err(SolveError::Problem)?;
+
+    solve_problem(Arc::new(problem), config, interrupt, progress)
+}
+
+fn solve_problem(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    interrupt: Option<Arc<AtomicBool>>,
+    progress: Option<ProgressCallback>,
+) -> Result<String, SolveError> {
+    let builder = create_builder_from_config(problem.clone(), &config).map_err(SolveError::Config)?;
+    let builder = if let Some(interrupt) = interrupt { builder.with_interrupt(interrupt) } else { builder };
+    let mut config = builder.build().map_err(SolveError::Config)?;
+
+    if let Some(progress) = progress {
+        let objective = problem.objective.clone();
+        config.telemetry.add_generation_callback(Box::new(move |refinement_ctx, statistics, _| {
+            if let Some((insertion_ctx, _)) = refinement_ctx.population.ranked().next() {
+                // NOTE errors in user defined callback should not break the search
+                if let Err(err) = progress(statistics.generation, objective.fitness(insertion_ctx)) {
+                    (refinement_ctx.environment.logger)(format!("progress callback has failed: {}", err).as_str());
+                }
+            }
+        }));
+    }
+
+    let (solution, cost, metrics) = Solver::new(problem.clone(), config).solve().map_err(SolveError::Solver)?;
 
     let mut buffer = String::new();
     let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
     if let Some(metrics) = metrics {
-        (&solution, cost, &metrics).write_pragmatic_json(&problem, writer)?;
+        (&solution, cost, &metrics).write_pragmatic_json(&problem, writer)
     } else {
-        (&solution, cost).write_pragmatic_json(&problem, writer)?;
+        (&solution, cost).write_pragmatic_json(&problem, writer)
     }
+    .map_err(|err| SolveError::Solver(err.to_string()))?;
 
     Ok(buffer)
 }
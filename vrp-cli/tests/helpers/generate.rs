@@ -12,19 +12,29 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        buffer: None,
+        is_prioritized: None,
+        sync: None,
+        transfer: None,
     }
 }
 
 pub fn create_empty_job_task() -> JobTask {
-    JobTask { places: vec![], demand: None, order: None }
+    JobTask { places: vec![], demand: None, order: None, skills: None, not_before: None }
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        tag: None,
+        parking: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, areas: None, clustering: None }
+    Plan { jobs: vec![], relations: None, areas: None, clustering: None, hints: None, readonly_routes: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -32,26 +42,31 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-        costs: VehicleCosts { fixed: None, distance: 1., time: 0. },
+        costs: VehicleCosts { fixed: None, distance: 1., time: 0., waiting: None, depot_waiting: None },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
                 latest: None,
+                optimize_departure: None,
                 location: Location::Coordinate { lat: 0.0, lng: 0.0 },
             },
             end: None,
             dispatch: None,
             breaks: None,
             reloads: None,
+            loading: None,
         }],
         capacity: vec![10],
         skills: None,
         limits: None,
+        carry_load: None,
+        echelon: None,
+        service_time_factor: None,
     }
 }
 
 pub fn create_test_vehicle_profile() -> MatrixProfile {
-    MatrixProfile { name: "car".to_string(), speed: None }
+    MatrixProfile { name: "car".to_string(), speed: None, detour_factor: None, time_of_day: None, leg_overrides: None }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
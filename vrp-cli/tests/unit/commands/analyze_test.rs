@@ -1,6 +1,8 @@
 use super::*;
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
 
 struct DummyWrite {}
 
@@ -30,6 +32,75 @@ fn can_run_analyze_clusters() {
     run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
 }
 
+#[test]
+fn can_run_analyze_feasibility() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "analyze",
+        "feasibility",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+}
+
+#[test]
+fn can_run_analyze_capacity() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "analyze",
+        "capacity",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+}
+
+#[test]
+fn can_run_analyze_unassigned() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "analyze",
+        "unassigned",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution",
+        PRAGMATIC_SOLUTION_PATH,
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+}
+
+#[test]
+fn can_run_analyze_matrix() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "analyze",
+        "matrix",
+        "pragmatic",
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--triangle-tolerance",
+        "0.2",
+        "--out-result",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+}
+
 #[test]
 fn can_detect_wrong_argument() {
     let args = vec!["analyze", "clusters", "solomon", PRAGMATIC_PROBLEM_PATH, "--out-result", "/some/path"];
@@ -75,10 +75,34 @@ fn can_specify_round_setting() {
 }
 
 #[test]
-fn can_specify_heuristic_setting() {
+fn can_specify_rounding_mode_setting() {
     for (mode, result) in
-        vec![("default", Some(())), ("dynamic", Some(())), ("static", Some(())), ("ggg", None), ("multi", None)]
+        vec![("truncate", Some(())), ("round", Some(())), ("ceil", Some(())), ("floor", Some(())), ("up", None)]
     {
+        let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--rounding-mode", mode, "--precise-statistic"];
+        assert_eq!(get_solve_app().try_get_matches_from(args).ok().map(|_| ()), result);
+    }
+}
+
+#[test]
+fn can_specify_compactness_setting() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--compactness"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    assert!(matches.is_present(COMPACTNESS_ARG_NAME));
+}
+
+#[test]
+fn can_specify_heuristic_setting() {
+    for (mode, result) in vec![
+        ("default", Some(())),
+        ("dynamic", Some(())),
+        ("static", Some(())),
+        ("guided", Some(())),
+        ("alns", Some(())),
+        ("ggg", None),
+        ("multi", None),
+    ] {
         let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--heuristic", mode];
         assert_eq!(get_solve_app().try_get_matches_from(args).ok().map(|_| ()), result);
     }
@@ -132,3 +156,55 @@ fn can_specify_cv() {
         assert_eq!(min_cv, result);
     }
 }
+
+#[test]
+fn can_export_reproduction_bundle() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let bundle_path = tmpfile.path().to_str().unwrap();
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--max-generations", "1", "--bundle", bundle_path];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    let bundle =
+        vrp_cli::extensions::solve::bundle::read_bundle(BufReader::new(File::open(bundle_path).unwrap())).unwrap();
+    assert_eq!(bundle.format, "pragmatic");
+    assert_eq!(bundle.problem, std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap());
+    assert!(bundle.matrices.is_empty());
+    assert!(bundle.config.is_none());
+    assert!(bundle.error.is_none());
+}
+
+#[test]
+fn can_specify_seed() {
+    for (params, result) in vec![
+        (vec!["--seed", "42"], Ok((Some(42), 0_usize))),
+        (vec!["--seed", "42", "--parallelism", "3,1"], Ok((Some(42), 0_usize))),
+        (vec!["--parallelism", "3,1"], Ok((None, 3_usize))),
+        (
+            vec!["--seed", "abc"],
+            Err("cannot get integer value, error: 'invalid digit found in string': 'seed'".to_string()),
+        ),
+    ] {
+        let matches = get_solomon_matches(params.as_slice());
+
+        let environment =
+            get_environment(&matches, None).map(|e| (e.random.get_seed(), e.parallelism.thread_pool_size()));
+
+        assert_eq!(environment, result);
+    }
+}
+
+#[test]
+fn can_override_config_seed() {
+    assert_eq!(with_seed(Config::default(), Some(42)).environment.and_then(|e| e.seed), Some(42));
+    assert_eq!(with_seed(Config::default(), None).environment.and_then(|e| e.seed), None);
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_seed() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--max-generations", "1", "--seed", "42"];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
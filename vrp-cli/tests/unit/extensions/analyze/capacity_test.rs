@@ -0,0 +1,16 @@
+use super::*;
+use serde_json::Value;
+use std::fs::File;
+
+#[test]
+fn can_get_capacity_analysis() {
+    let problem = BufReader::new(
+        File::open("../examples/data/pragmatic/basics/skills.basic.problem.json").expect("cannot read problem file"),
+    );
+
+    let analysis = get_capacity_analysis(problem).expect("cannot get capacity analysis");
+
+    let analysis: Value = serde_json::from_str(analysis.as_str()).expect("cannot parse analysis");
+    assert!(analysis["overall"]["shortfall"].as_array().unwrap().iter().all(|value| value.as_i64() == Some(0)));
+    assert_eq!(analysis["skills"].as_array().unwrap().len(), 2);
+}
@@ -0,0 +1,24 @@
+use super::*;
+use serde_json::Value;
+use std::fs::File;
+
+#[test]
+pub fn can_get_feasible_vehicles() {
+    let problem = BufReader::new(
+        File::open("../examples/data/pragmatic/basics/skills.basic.problem.json").expect("cannot read problem file"),
+    );
+
+    let feasibility = get_feasible_vehicles(problem, None).expect("cannot get feasible vehicles");
+
+    let feasibility: Value = serde_json::from_str(feasibility.as_str()).expect("cannot parse feasibility");
+    let feasibility = feasibility
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|job| {
+            let vehicles = job["vehicles"].as_array().unwrap();
+            (job["jobId"].as_str().unwrap(), vehicles.iter().map(|v| v["vehicleId"].as_str().unwrap()).collect())
+        })
+        .collect::<Vec<(_, Vec<_>)>>();
+    assert_eq!(feasibility, vec![("job1", vec!["vehicle_with_fridge_1"]), ("job2", vec!["vehicle_with_handyman_1"])]);
+}
@@ -0,0 +1,32 @@
+use super::*;
+use serde_json::Value;
+use std::fs::File;
+
+#[test]
+fn can_get_matrix_analysis_for_example_matrix() {
+    let matrix =
+        BufReader::new(File::open("../examples/data/pragmatic/simple.basic.matrix.json").expect("cannot read matrix"));
+
+    let analysis = get_matrix_analysis(vec![matrix], &MatrixConsistencySettings::default()).expect("cannot analyze");
+
+    let analysis: Value = serde_json::from_str(analysis.as_str()).expect("cannot parse analysis");
+    assert!(analysis.is_array());
+}
+
+#[test]
+fn can_report_cell_diagnostics() {
+    let matrix = r#"{"profile": "car", "travelTimes": [0, 0, 0, 0], "distances": [0, 10, 10, 0]}"#;
+
+    let analysis = get_matrix_analysis(vec![BufReader::new(matrix.as_bytes())], &MatrixConsistencySettings::default())
+        .expect("cannot analyze");
+
+    let analysis: Value = serde_json::from_str(analysis.as_str()).expect("cannot parse analysis");
+    let issues = analysis.as_array().unwrap();
+    assert_eq!(issues.len(), 2);
+    assert_eq!(issues[0]["profile"], "car");
+    assert_eq!(issues[0]["kind"], "zeroDuration");
+    assert_eq!(issues[0]["metric"], "duration");
+    assert_eq!(issues[0]["from"], 0);
+    assert_eq!(issues[0]["to"], 1);
+    assert_eq!(issues[0]["reference"], 10);
+}
@@ -0,0 +1,56 @@
+use super::*;
+use serde_json::Value;
+use std::fs::File;
+use vrp_pragmatic::format::solution::{serialize_solution, Solution};
+
+fn create_empty_solution() -> String {
+    let solution = Solution {
+        statistic: Default::default(),
+        tours: vec![],
+        unassigned: None,
+        violations: None,
+        extras: None,
+        units: None,
+    };
+
+    let mut buffer = String::new();
+    let writer = unsafe { std::io::BufWriter::new(buffer.as_mut_vec()) };
+    serialize_solution(writer, &solution).expect("cannot serialize solution");
+
+    buffer
+}
+
+#[test]
+pub fn can_get_unassigned_analysis() {
+    let problem = BufReader::new(
+        File::open("../examples/data/pragmatic/basics/skills.basic.problem.json").expect("cannot read problem file"),
+    );
+    let solution = create_empty_solution();
+
+    let unassigned =
+        get_unassigned_analysis(problem, BufReader::new(solution.as_bytes()), None).expect("cannot analyze solution");
+
+    let unassigned: Value = serde_json::from_str(unassigned.as_str()).expect("cannot parse result");
+    let unassigned = unassigned
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|job| {
+            let reasons = job["reasons"].as_array().unwrap();
+            (
+                job["jobId"].as_str().unwrap(),
+                reasons
+                    .iter()
+                    .map(|reason| (reason["code"].as_str().unwrap(), reason["details"]["vehicleId"].as_str().unwrap()))
+                    .collect(),
+            )
+        })
+        .collect::<Vec<(_, Vec<_>)>>();
+    assert_eq!(
+        unassigned,
+        vec![
+            ("job1", vec![("SKILL_CONSTRAINT", "vehicle_with_handyman_1")]),
+            ("job2", vec![("SKILL_CONSTRAINT", "vehicle_with_fridge_1")])
+        ]
+    );
+}
@@ -8,9 +8,16 @@ fn can_generate_fleet_of_specific_size() {
         plan: create_empty_plan(),
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![MatrixProfile { name: "normal_car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "normal_car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: None,
+                leg_overrides: None,
+            }],
         },
         objectives: None,
+        units: None,
     };
 
     let generated = generate_fleet(&prototype, 2);
@@ -8,6 +8,8 @@ fn can_generate_bounding_box() {
         relations: None,
         areas: None,
         clustering: None,
+        hints: None,
+        readonly_routes: None,
     };
 
     let ((min_lat, min_lng), (max_lat, max_lng)) = get_bounding_box_from_plan(&plan);
@@ -25,6 +27,8 @@ fn can_get_bounding_box_from_size() {
         relations: None,
         areas: None,
         clustering: None,
+        hints: None,
+        readonly_routes: None,
     };
 
     let ((min_lat, min_lng), (max_lat, max_lng)) = get_bounding_box_from_size(&plan, 100.);
@@ -16,6 +16,7 @@ fn can_generate_jobs_with_time_windows() {
         },
         fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![create_test_vehicle_profile()] },
         objectives: None,
+        units: None,
     };
 
     let result =
@@ -0,0 +1,148 @@
+use super::*;
+use crate::helpers::generate::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use vrp_pragmatic::format::problem::*;
+
+fn create_test_problem(lngs: Vec<f64>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: lngs
+                .into_iter()
+                .enumerate()
+                .map(|(idx, lng)| Job { id: format!("job{}", idx + 1), ..create_test_job(0., lng) })
+                .collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![create_test_vehicle_profile()] },
+        objectives: None,
+        units: None,
+    }
+}
+
+fn get_query_indices(url: &str, name: &str) -> Vec<usize> {
+    let query = url.split('?').nth(1).unwrap();
+    let value = query.split('&').find_map(|pair| pair.strip_prefix(&format!("{}=", name))).unwrap();
+
+    value.split(';').map(|idx| idx.parse().unwrap()).collect()
+}
+
+/// Emulates OSRM table service: duration is a difference between longitudes, distance is twice bigger.
+fn fake_fetch(url: &str) -> Result<OsrmTable, String> {
+    let coordinates = url.split("/table/v1/car/").nth(1).unwrap().split('?').next().unwrap();
+    let lngs =
+        coordinates.split(';').map(|pair| pair.split(',').next().unwrap().parse().unwrap()).collect::<Vec<f64>>();
+
+    let sources = get_query_indices(url, "sources");
+    let destinations = get_query_indices(url, "destinations");
+    let get_table = |factor: f64| {
+        sources
+            .iter()
+            .map(|from| destinations.iter().map(|to| Some((lngs[*from] - lngs[*to]).abs() * factor)).collect())
+            .collect()
+    };
+
+    Ok(OsrmTable {
+        code: "Ok".to_string(),
+        message: None,
+        durations: Some(get_table(1000.)),
+        distances: Some(get_table(2000.)),
+    })
+}
+
+#[test]
+fn can_create_table_url() {
+    let config = OsrmConfig::new("http://localhost:5000/");
+
+    let url = create_table_url(&config, "car", &[(52.1, 13.1)], &[(52.2, 13.2), (52.3, 13.3)]);
+
+    assert_eq!(
+        url,
+        "http://localhost:5000/table/v1/car/13.1,52.1;13.2,52.2;13.3,52.3\
+         ?sources=0&destinations=1;2&annotations=duration,distance"
+    );
+}
+
+#[test]
+fn can_create_matrices_using_chunks() {
+    let problem = create_test_problem(vec![0.001, 0.002, 0.003, 0.004]);
+    let config = OsrmConfig { chunk_size: 2, ..OsrmConfig::new("http://localhost:5000") };
+    let calls = AtomicUsize::new(0);
+    let fetch_fn = |url: &str| {
+        calls.fetch_add(1, Ordering::Relaxed);
+        fake_fetch(url)
+    };
+
+    let matrices = create_matrices(&problem, &config, &fetch_fn).unwrap();
+
+    assert_eq!(calls.load(Ordering::Relaxed), 9);
+    assert_eq!(matrices.len(), 1);
+    let matrix = matrices.first().unwrap();
+    let lngs = get_unique_locations(&problem)
+        .into_iter()
+        .map(|location| match location {
+            Location::Coordinate { lng, .. } => lng,
+            Location::Reference { .. } => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+    let expected_durations = lngs
+        .iter()
+        .flat_map(|from| lngs.iter().map(move |to| ((from - to).abs() * 1000.).round() as i64))
+        .collect::<Vec<_>>();
+    assert_eq!(lngs.len(), 5);
+    assert_eq!(matrix.profile, Some("car".to_string()));
+    assert_eq!(matrix.travel_times, expected_durations);
+    assert_eq!(matrix.distances, expected_durations.iter().map(|duration| duration * 2).collect::<Vec<_>>());
+    assert!(matrix.error_codes.is_none());
+}
+
+#[test]
+fn can_mark_unreachable_locations_with_error_codes() {
+    let problem = create_test_problem(vec![0.001]);
+    let config = OsrmConfig::new("http://localhost:5000");
+    let fetch_fn = |_: &str| {
+        Ok(OsrmTable {
+            code: "Ok".to_string(),
+            message: None,
+            durations: Some(vec![vec![Some(0.), None], vec![Some(10.), Some(0.)]]),
+            distances: Some(vec![vec![Some(0.), None], vec![Some(20.), Some(0.)]]),
+        })
+    };
+
+    let matrices = create_matrices(&problem, &config, &fetch_fn).unwrap();
+
+    let matrix = matrices.first().unwrap();
+    assert_eq!(matrix.travel_times, vec![0, 0, 10, 0]);
+    assert_eq!(matrix.distances, vec![0, 0, 20, 0]);
+    assert_eq!(matrix.error_codes, Some(vec![0, 1, 0, 0]));
+}
+
+#[test]
+fn can_return_error_from_service() {
+    let problem = create_test_problem(vec![0.001]);
+    let config = OsrmConfig::new("http://localhost:5000");
+    let fetch_fn = |_: &str| {
+        Ok(OsrmTable {
+            code: "TooBig".to_string(),
+            message: Some("Too many table coordinates".to_string()),
+            durations: None,
+            distances: None,
+        })
+    };
+
+    let result = create_matrices(&problem, &config, &fetch_fn);
+
+    assert_eq!(
+        result.err(),
+        Some("OSRM table service returned 'TooBig' code: 'Too many table coordinates'".to_string())
+    );
+}
+
+#[test]
+fn can_return_error_for_location_references() {
+    let mut problem = create_test_problem(vec![0.001]);
+    problem.fleet.vehicles[0].shifts[0].start.location = Location::Reference { index: 0 };
+
+    let result = create_matrices(&problem, &OsrmConfig::new("http://localhost:5000"), &fake_fetch);
+
+    assert!(result.is_err());
+}
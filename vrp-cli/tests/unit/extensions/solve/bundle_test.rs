@@ -0,0 +1,28 @@
+use super::*;
+use std::sync::Arc;
+use vrp_core::utils::DefaultRandom;
+
+#[test]
+fn can_write_and_read_bundle() {
+    let environment = Environment { random: Arc::new(DefaultRandom::new_with_seed(42)), ..Environment::default() };
+    let bundle = ReproductionBundle::new(
+        "pragmatic",
+        "{\"plan\":{}}".to_string(),
+        vec!["{\"profile\":\"car\"}".to_string()],
+        None,
+        &environment,
+    )
+    .with_error(Some("strict mode violation".to_string()));
+    let mut buffer = Vec::new();
+
+    write_bundle(&bundle, BufWriter::new(&mut buffer)).unwrap();
+    let result = read_bundle(BufReader::new(buffer.as_slice())).unwrap();
+
+    assert_eq!(result.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(result.format, "pragmatic");
+    assert_eq!(result.seed, Some(42));
+    assert_eq!(result.problem, bundle.problem);
+    assert_eq!(result.matrices, bundle.matrices);
+    assert_eq!(result.config, None);
+    assert_eq!(result.error, Some("strict mode violation".to_string()));
+}
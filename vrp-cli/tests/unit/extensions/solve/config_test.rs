@@ -16,6 +16,19 @@ fn can_read_full_config() {
     let metrics = telemetry.metrics.unwrap();
     assert!(!metrics.enabled);
     assert_eq!(metrics.track_population, Some(1000));
+    assert_eq!(metrics.snapshot_population, Some(5000));
+    let json_lines = telemetry.json_lines.unwrap();
+    assert!(!json_lines.enabled);
+    assert_eq!(json_lines.path, "telemetry.jsonl");
+    assert_eq!(json_lines.log_generation, Some(1));
+    let open_metrics = telemetry.open_metrics.unwrap();
+    assert!(!open_metrics.enabled);
+    assert_eq!(open_metrics.path, "solver.prom");
+    assert_eq!(open_metrics.export_generation, Some(100));
+
+    let polishing = config.polishing.expect("no polishing config");
+    assert!(!polishing.enabled);
+    assert_eq!(polishing.max_time, Some(10));
 
     let evolution_config = config.evolution.expect("no evolution config");
 
@@ -41,6 +54,8 @@ fn can_read_full_config() {
             rebalance_memory,
             rebalance_count,
             exploration_ratio,
+            batch_size,
+            weight_reduction,
         } => {
             assert_eq!(selection_size, Some(8));
             assert_eq!(max_elite_size, Some(2));
@@ -52,10 +67,22 @@ fn can_read_full_config() {
             assert_eq!(rebalance_memory, Some(100));
             assert_eq!(rebalance_count, Some(2));
             assert_eq!(exploration_ratio, Some(0.9));
+            assert_eq!(batch_size, Some(8));
+            assert!(matches!(weight_reduction, Some(WeightReductionType::Pca { dimensions: 4 })));
         }
         _ => unreachable!(),
     }
 
+    let islands = evolution_config.islands.expect("no islands config");
+    assert_eq!(islands.count, 2);
+    assert_eq!(islands.migration_interval, Some(100));
+
+    let tie_breaking = evolution_config.tie_breaking.expect("no tie breaking config");
+    assert!(matches!(
+        tie_breaking.as_slice(),
+        [TieBreakingType::FewerRoutes, TieBreakingType::EarlierFinish, TieBreakingType::Lexicographic]
+    ));
+
     let hyper_config = config.hyper.expect("cannot get hyper");
     match hyper_config {
         HyperType::StaticSelective { operators } => {
@@ -100,12 +127,12 @@ fn can_read_full_config() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.01);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
-                    assert_eq!(inners.len(), 4);
+                    assert_eq!(inners.len(), 5);
                 }
                 _ => unreachable!(),
             }
         }
-        HyperType::DynamicSelective => unreachable!(),
+        _ => unreachable!(),
     }
 
     let termination = config.termination.expect("no termination config");
@@ -114,6 +141,7 @@ fn can_read_full_config() {
 
     let environment = config.environment.expect("no environment config");
     assert_eq!(environment.is_experimental, Some(false));
+    assert_eq!(environment.is_strict, Some(false));
 
     let parallelism = environment.parallelism.expect("no parallelism config");
     assert_eq!(parallelism.num_thread_pools, 6);
@@ -132,6 +160,7 @@ fn can_create_default_config() {
     assert!(config.hyper.is_none());
     assert!(config.termination.is_none());
     assert!(config.telemetry.is_none());
+    assert!(config.polishing.is_none());
 }
 
 fn as_scalar_probability(probability: &OperatorProbabilityType) -> f64 {
@@ -140,3 +169,33 @@ fn as_scalar_probability(probability: &OperatorProbabilityType) -> f64 {
         _ => unreachable!(),
     }
 }
+
+#[test]
+fn can_read_guided_local_search_config() {
+    let json = r#"{ "hyper": { "type": "guided-local-search", "iterations": 3, "alpha": 0.2 } }"#;
+
+    let config = read_config(BufReader::new(json.as_bytes())).unwrap();
+
+    match config.hyper {
+        Some(HyperType::GuidedLocalSearch { iterations, alpha }) => {
+            assert_eq!(iterations, Some(3));
+            assert_eq!(alpha, Some(0.2));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn can_read_alns_config() {
+    let json = r#"{ "hyper": { "type": "alns", "segmentSize": 50, "reactionFactor": 0.2 } }"#;
+
+    let config = read_config(BufReader::new(json.as_bytes())).unwrap();
+
+    match config.hyper {
+        Some(HyperType::AdaptiveLargeNeighborhoodSearch { segment_size, reaction_factor }) => {
+            assert_eq!(segment_size, Some(50));
+            assert_eq!(reaction_factor, Some(0.2));
+        }
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+use crate::extensions::solve::config::TerminationConfig;
+use crate::helpers::generate::*;
+use vrp_pragmatic::format::Location;
+
+fn create_job(id: &str, lng: f64) -> Job {
+    Job {
+        id: id.to_string(),
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace { location: Location::Coordinate { lat: 0., lng }, ..create_empty_job_place() }],
+            demand: Some(vec![1]),
+            ..create_empty_job_task()
+        }]),
+        ..create_empty_job()
+    }
+}
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_job("job1", 0.02), create_job("job2", 0.01)], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![create_test_vehicle_profile()] },
+        objectives: None,
+        units: None,
+    }
+}
+
+fn create_test_config() -> Config {
+    Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(10), variation: None }),
+        ..Config::default()
+    }
+}
+
+fn get_job_ids(solution: &Solution) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| !is_reserved_job_id(&activity.job_id))
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+#[test]
+fn can_commit_route_prefix_before_job_is_released() {
+    let horizon = RollingHorizon {
+        horizon: 3600.,
+        step: 1800.,
+        release_times: vec![("job2".to_string(), "2020-05-01T10:00:00Z".to_string())].into_iter().collect(),
+    };
+
+    let solution = solve_with_rolling_horizon(create_test_problem(), None, &horizon, &create_test_config()).unwrap();
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_job_ids(&solution), vec!["job1".to_string(), "job2".to_string()]);
+}
+
+#[test]
+fn can_solve_problem_without_release_times() {
+    let horizon = RollingHorizon { horizon: 3600., step: 1800., release_times: Default::default() };
+
+    let solution = solve_with_rolling_horizon(create_test_problem(), None, &horizon, &create_test_config()).unwrap();
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_job_ids(&solution), vec!["job2".to_string(), "job1".to_string()]);
+}
+
+#[test]
+fn can_skip_iterations_without_new_jobs() {
+    let horizon = RollingHorizon {
+        horizon: 3600.,
+        step: 1800.,
+        release_times: vec![("job2".to_string(), "2020-06-01T10:00:00Z".to_string())].into_iter().collect(),
+    };
+
+    let solution = solve_with_rolling_horizon(create_test_problem(), None, &horizon, &create_test_config()).unwrap();
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_job_ids(&solution), vec!["job1".to_string(), "job2".to_string()]);
+}
+
+#[test]
+fn can_reject_invalid_horizon() {
+    let horizon = RollingHorizon { horizon: 1800., step: 3600., release_times: Default::default() };
+
+    let result = solve_with_rolling_horizon(create_test_problem(), None, &horizon, &create_test_config());
+
+    assert!(result.is_err());
+}
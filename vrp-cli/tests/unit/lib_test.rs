@@ -1,6 +1,8 @@
 use super::*;
+use crate::extensions::solve::config::TerminationConfig;
 use crate::helpers::generate::{create_empty_plan, create_test_job, create_test_vehicle_type};
-use vrp_pragmatic::format::problem::{Fleet, MatrixProfile, Plan};
+use std::sync::Mutex;
+use vrp_pragmatic::format::problem::{Fleet, Matrix, MatrixProfile, Plan};
 
 #[test]
 fn can_get_locations_serialized() {
@@ -8,6 +10,7 @@ fn can_get_locations_serialized() {
         plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], ..create_empty_plan() },
         fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![] },
         objectives: None,
+        units: None,
     };
 
     let locations = get_locations_serialized(&problem).unwrap().replace(" ", "").replace("\n", "");
@@ -21,9 +24,16 @@ fn can_get_solution_serialized() {
         plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: None,
+                leg_overrides: None,
+            }],
         },
         objectives: None,
+        units: None,
     };
     let problem = Arc::new(problem.read_pragmatic().unwrap());
 
@@ -36,6 +46,56 @@ fn can_get_solution_serialized() {
     assert!(solution.contains("stops"));
 }
 
+#[test]
+fn can_get_pragmatic_solution_serialized_with_progress() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: None,
+                leg_overrides: None,
+            }],
+        },
+        objectives: None,
+        units: None,
+    };
+    let config = Config {
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(3), variation: None }),
+        ..Config::default()
+    };
+    let generations = Arc::new(Mutex::new(Vec::new()));
+    let progress: ProgressCallback = Box::new({
+        let generations = generations.clone();
+        move |generation, _| {
+            generations.lock().unwrap().push(generation);
+            Err("callback error".to_string())
+        }
+    });
+
+    let solution =
+        get_pragmatic_solution_serialized(problem, Vec::<Matrix>::new(), config, None, Some(progress)).unwrap();
+
+    assert!(solution.contains("tours"));
+    assert_eq!(*generations.lock().unwrap(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn can_get_solve_error_message() {
+    let problem_error = FormatError::new("code0".to_string(), "cause0".to_string(), "action0".to_string());
+
+    let problem_message = SolveError::Problem(vec![problem_error]).to_message(get_errors_serialized);
+    let config_message = SolveError::Config("some error".to_string()).to_message(get_errors_serialized);
+    let solver_message = SolveError::Solver("some error".to_string()).to_message(get_errors_serialized);
+
+    assert_eq!(problem_message, "code0, cause: \'cause0\', action: \'action0\'.");
+    assert!(config_message.contains("E0004"));
+    assert!(solver_message.contains("E0003"));
+}
+
 #[test]
 fn can_get_errors_serialized() {
     let errors = vec![
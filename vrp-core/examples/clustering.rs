@@ -0,0 +1,91 @@
+//! This example shows how to use vicinity clustering: jobs close to each other are grouped into
+//! clusters before the search and visited as one stop, clusters are unpacked back in the final solution.
+//!
+//! Run it with `cargo run --example clustering`.
+
+#[cfg(test)]
+#[path = "../tests/examples/clustering_test.rs"]
+mod clustering_test;
+
+mod common;
+use crate::common::*;
+use std::cmp::Ordering;
+use std::sync::Arc;
+use vrp_core::construction::clustering::vicinity::*;
+use vrp_core::models::common::{IdDimension, Profile};
+use vrp_core::models::problem::Job;
+use vrp_core::models::{Extras, Problem, Solution};
+use vrp_core::prelude::*;
+use vrp_core::solver::processing::VicinityDimension;
+
+fn create_cluster_config() -> ClusterConfig {
+    let by_id = |left: &Job, right: &Job| left.dimens().get_id().cmp(&right.dimens().get_id());
+
+    ClusterConfig {
+        profile: Profile::default(),
+        threshold: ThresholdPolicy {
+            moving_duration: 5.,
+            moving_distance: 5.,
+            min_shared_time: None,
+            smallest_time_window: None,
+            max_jobs_per_cluster: Some(4),
+        },
+        visiting: VisitPolicy::ClosedContinuation,
+        serving: ServingPolicy::Original { parking: 5. },
+        filtering: FilterPolicy { job_filter: Arc::new(|_| true), actor_filter: Arc::new(|_| true) },
+        building: BuilderPolicy {
+            ordering_global: Arc::new(move |(left_job, left_candidates), (right_job, right_candidates)| {
+                left_candidates.len().cmp(&right_candidates.len()).then_with(|| by_id(left_job, right_job))
+            }),
+            ordering_local: Arc::new(move |left, right| {
+                match compare_floats(left.commute.forward.duration, right.commute.forward.duration) {
+                    Ordering::Equal => by_id(&left.job, &right.job),
+                    order => order,
+                }
+            }),
+        },
+    }
+}
+
+fn create_example_problem() -> Arc<Problem> {
+    // NOTE two groups of jobs located close to each other
+    let coordinates =
+        vec![(0., 0.), (20., 0.), (21., 0.), (20., 1.), (21., 1.), (0., 20.), (1., 20.), (0., 21.), (1., 21.)];
+
+    let jobs = (1..coordinates.len())
+        .map(|location| create_delivery_job(format!("job{}", location).as_str(), location, 1, None))
+        .collect::<Vec<Job>>();
+
+    let fleet = create_fleet(vec![create_vehicle("vehicle_1", "vehicle", 10, (10., 1.), 0)]);
+
+    let mut extras = Extras::default();
+    extras.set_cluster_config(create_cluster_config());
+
+    create_problem(&coordinates, fleet, jobs, None, vec![], extras)
+}
+
+fn get_clusters(problem: Arc<Problem>) -> Vec<Vec<String>> {
+    let config = problem.extras.get_cluster_config().expect("no cluster config").clone();
+
+    create_job_clusters(problem, Arc::new(Environment::default()), &config)
+        .into_iter()
+        .map(|(_, jobs)| jobs.iter().filter_map(|job| job.dimens().get_id().cloned()).collect())
+        .collect()
+}
+
+fn solve_example() -> Result<(Arc<Problem>, Solution, f64), String> {
+    let problem = create_example_problem();
+    let (solution, cost) = solve_problem(problem.clone())?;
+
+    Ok((problem, solution, cost))
+}
+
+fn main() -> Result<(), String> {
+    get_clusters(create_example_problem()).iter().for_each(|cluster| println!("cluster: {}", cluster.join(",")));
+
+    let (_, solution, cost) = solve_example()?;
+
+    print_solution(&solution, cost);
+
+    Ok(())
+}
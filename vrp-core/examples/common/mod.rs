@@ -0,0 +1,204 @@
+//! Contains helper functionality shared by examples: problem definition with euclidean distances
+//! and solver invocation with a small generation limit.
+
+#![allow(dead_code)]
+
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::{Extras, Problem, Solution};
+use vrp_core::prelude::*;
+
+/// A code of time window constraint violation.
+pub const TIME_CONSTRAINT_CODE: i32 = 1;
+/// A code of distance limit constraint violation.
+pub const DISTANCE_LIMIT_CONSTRAINT_CODE: i32 = 2;
+/// A code of duration limit constraint violation.
+pub const DURATION_LIMIT_CONSTRAINT_CODE: i32 = 3;
+/// A code of capacity constraint violation.
+pub const CAPACITY_CONSTRAINT_CODE: i32 = 4;
+
+/// Coordinates of all locations used in examples: location index is a position in this list.
+pub type Coordinates = Vec<(f64, f64)>;
+
+/// Creates a delivery job with given demand at specific location.
+pub fn create_delivery_job(id: &str, location: Location, demand: i32, time: Option<(f64, f64)>) -> Job {
+    let mut dimens = Dimensions::default();
+    dimens.set_id(id);
+    dimens.set_demand(Demand {
+        pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+        delivery: (SingleDimLoad::new(demand), SingleDimLoad::default()),
+    });
+
+    let times = time.map_or_else(
+        || vec![TimeSpan::Window(TimeWindow::max())],
+        |(start, end)| vec![TimeSpan::Window(TimeWindow::new(start, end))],
+    );
+
+    Job::Single(Arc::new(Single { places: vec![Place { location: Some(location), duration: 10., times }], dimens }))
+}
+
+/// Creates a vehicle of given type with one shift which starts and ends at the same location.
+pub fn create_vehicle(id: &str, type_id: &str, capacity: i32, costs: (f64, f64), depot: Location) -> Arc<Vehicle> {
+    let mut dimens = Dimensions::default();
+    dimens.set_id(id);
+    dimens.set_value("type_id", type_id.to_string());
+    dimens.set_capacity(SingleDimLoad::new(capacity));
+
+    let (fixed, per_distance) = costs;
+
+    Arc::new(Vehicle {
+        profile: Profile::default(),
+        costs: Costs {
+            fixed,
+            per_distance,
+            per_driving_time: 0.,
+            per_waiting_time: 0.,
+            per_depot_waiting_time: 0.,
+            per_service_time: 0.,
+        },
+        dimens,
+        details: vec![VehicleDetail {
+            start: Some(VehiclePlace { location: depot, time: TimeInterval { earliest: Some(0.), latest: None } }),
+            end: Some(VehiclePlace { location: depot, time: TimeInterval { earliest: None, latest: Some(1000.) } }),
+        }],
+    })
+}
+
+/// Creates a fleet where actors are grouped by vehicle type.
+pub fn create_fleet(vehicles: Vec<Arc<Vehicle>>) -> Arc<Fleet> {
+    let driver = Arc::new(Driver {
+        costs: Costs {
+            fixed: 0.,
+            per_distance: 0.,
+            per_driving_time: 0.,
+            per_waiting_time: 0.,
+            per_depot_waiting_time: 0.,
+            per_service_time: 0.,
+        },
+        dimens: Default::default(),
+        details: vec![],
+    });
+
+    Arc::new(Fleet::new(
+        vec![driver],
+        vehicles,
+        Box::new(|actors| {
+            let types = actors
+                .iter()
+                .filter_map(|actor| actor.vehicle.dimens.get_value::<String>("type_id").cloned())
+                .collect::<Vec<_>>();
+
+            Box::new(move |actor| {
+                let type_id = actor.vehicle.dimens.get_value::<String>("type_id");
+                types.iter().position(|t| Some(t) == type_id).unwrap_or(0)
+            })
+        }),
+    ))
+}
+
+/// Creates a routing matrix transport cost using euclidean distances between coordinates.
+pub fn create_transport(coordinates: &[(f64, f64)]) -> Arc<dyn TransportCost + Send + Sync> {
+    let distances = coordinates
+        .iter()
+        .flat_map(|&(x1, y1)| coordinates.iter().map(move |&(x2, y2)| ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt()))
+        .collect::<Vec<_>>();
+
+    create_matrix_transport_cost(vec![MatrixData::new(0, None, distances.clone(), distances)])
+        .expect("cannot create transport costs")
+}
+
+/// Creates a problem with transport and capacity constraints plus additional constraint modules.
+pub fn create_problem(
+    coordinates: &[(f64, f64)],
+    fleet: Arc<Fleet>,
+    jobs: Vec<Job>,
+    multi_trip: Option<Arc<dyn MultiTrip<SingleDimLoad> + Send + Sync>>,
+    modules: Vec<Arc<dyn ConstraintModule + Send + Sync>>,
+    extras: Extras,
+) -> Arc<Problem> {
+    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(SimpleActivityCost::default());
+    let transport = create_transport(coordinates);
+
+    let mut constraint = ConstraintPipeline::default();
+    constraint.add_module(Arc::new(TransportConstraintModule::new(
+        transport.clone(),
+        activity.clone(),
+        Arc::new(|_| (None, None)),
+        TIME_CONSTRAINT_CODE,
+        DISTANCE_LIMIT_CONSTRAINT_CODE,
+        DURATION_LIMIT_CONSTRAINT_CODE,
+    )));
+    constraint.add_module(Arc::new(match multi_trip {
+        Some(multi_trip) => CapacityConstraintModule::<SingleDimLoad>::new_with_multi_trip(
+            activity.clone(),
+            transport.clone(),
+            CAPACITY_CONSTRAINT_CODE,
+            multi_trip,
+        ),
+        None => CapacityConstraintModule::<SingleDimLoad>::new(
+            activity.clone(),
+            transport.clone(),
+            CAPACITY_CONSTRAINT_CODE,
+        ),
+    }));
+    modules.into_iter().for_each(|module| {
+        constraint.add_module(module);
+    });
+
+    Arc::new(Problem {
+        jobs: Arc::new(Jobs::new(fleet.as_ref(), jobs, &transport)),
+        fleet,
+        locks: vec![],
+        constraint: Arc::new(constraint),
+        activity,
+        transport,
+        objective: Arc::new(ProblemObjective::default()),
+        extras: Arc::new(extras),
+    })
+}
+
+/// Solves the problem using default metaheuristic with small amount of generations.
+pub fn solve_problem(problem: Arc<Problem>) -> Result<(Solution, f64), String> {
+    let environment = Arc::new(Environment::default());
+    let config = create_default_config_builder(problem.clone(), environment).with_max_generations(Some(100)).build()?;
+
+    let (solution, cost, _) = Solver::new(problem, config).solve()?;
+
+    Ok((solution, cost))
+}
+
+/// Returns job ids served by each vehicle in the order of visiting.
+pub fn get_routes(solution: &Solution) -> Vec<(String, Vec<String>)> {
+    let mut routes = solution
+        .routes
+        .iter()
+        .map(|route| {
+            let vehicle_id = route.actor.vehicle.dimens.get_id().cloned().unwrap_or_default();
+            let job_ids = route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.job.as_ref())
+                .filter_map(|single| single.dimens.get_id().cloned())
+                .collect::<Vec<_>>();
+
+            (vehicle_id, job_ids)
+        })
+        .collect::<Vec<_>>();
+
+    routes.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    routes
+}
+
+/// Prints routes of the solution to the standard output.
+pub fn print_solution(solution: &Solution, cost: f64) {
+    println!("cost: {}", cost);
+    get_routes(solution).iter().for_each(|(vehicle_id, job_ids)| {
+        println!("{}: {}", vehicle_id, job_ids.join(","));
+    });
+    solution.unassigned.iter().for_each(|(job, code)| {
+        println!("unassigned {} with code {}", job.dimens().get_id().cloned().unwrap_or_default(), code);
+    });
+}
@@ -0,0 +1,130 @@
+//! This example shows how to extend the solver with a custom constraint module: hazardous jobs
+//! can be served only by vehicles which have a certification for it.
+//!
+//! Run it with `cargo run --example custom_constraint`.
+
+#[cfg(test)]
+#[path = "../tests/examples/custom_constraint_test.rs"]
+mod custom_constraint_test;
+
+mod common;
+use crate::common::*;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::{Job, Single};
+use vrp_core::models::{Problem, Solution};
+
+/// A code of hazardous constraint violation.
+const HAZARDOUS_CONSTRAINT_CODE: i32 = 5;
+
+/// A module which allows to assign hazardous jobs only to certified vehicles.
+struct HazardousModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl HazardousModule {
+    fn new(code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(HazardousHardRouteConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for HazardousModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        if is_hazardous(&source) == is_hazardous(&candidate) {
+            Ok(source)
+        } else {
+            Err(HAZARDOUS_CONSTRAINT_CODE)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct HazardousHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for HazardousHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let is_certified = ctx.route.actor.vehicle.dimens.get_value::<bool>("certified").cloned().unwrap_or(false);
+
+        if is_hazardous(job) && !is_certified {
+            Some(RouteConstraintViolation { code: self.code })
+        } else {
+            None
+        }
+    }
+}
+
+fn is_hazardous(job: &Job) -> bool {
+    job.dimens().get_value::<bool>("hazardous").cloned().unwrap_or(false)
+}
+
+fn create_example_problem() -> Arc<Problem> {
+    let coordinates = vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (-10., 0.), (-10., -10.), (0., -10.)];
+
+    let jobs = (1..coordinates.len())
+        .map(|location| {
+            let job = create_delivery_job(format!("job{}", location).as_str(), location, 1, None);
+
+            // NOTE mark every second job as hazardous
+            if location % 2 == 0 {
+                let single = job.to_single();
+                let mut dimens = single.dimens.clone();
+                dimens.set_value("hazardous", true);
+
+                Job::Single(Arc::new(Single { places: single.places.clone(), dimens }))
+            } else {
+                job
+            }
+        })
+        .collect::<Vec<Job>>();
+
+    let mut certified = create_vehicle("certified_1", "certified", 10, (100., 1.), 0);
+    Arc::get_mut(&mut certified).expect("vehicle is shared").dimens.set_value("certified", true);
+
+    let fleet = create_fleet(vec![create_vehicle("regular_1", "regular", 10, (10., 1.), 0), certified]);
+
+    create_problem(
+        &coordinates,
+        fleet,
+        jobs,
+        None,
+        vec![Arc::new(HazardousModule::new(HAZARDOUS_CONSTRAINT_CODE))],
+        Default::default(),
+    )
+}
+
+fn solve_example() -> Result<(Arc<Problem>, Solution, f64), String> {
+    let problem = create_example_problem();
+    let (solution, cost) = solve_problem(problem.clone())?;
+
+    Ok((problem, solution, cost))
+}
+
+fn main() -> Result<(), String> {
+    let (_, solution, cost) = solve_example()?;
+
+    print_solution(&solution, cost);
+
+    Ok(())
+}
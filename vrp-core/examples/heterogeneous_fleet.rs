@@ -0,0 +1,46 @@
+//! This example shows how to solve a problem with heterogeneous fleet: vehicle types differ in
+//! capacity and costs, so the solver has to pick a proper mix of vehicles to serve all jobs.
+//!
+//! Run it with `cargo run --example heterogeneous_fleet`.
+
+#[cfg(test)]
+#[path = "../tests/examples/heterogeneous_fleet_test.rs"]
+mod heterogeneous_fleet_test;
+
+mod common;
+use crate::common::*;
+use std::sync::Arc;
+use vrp_core::models::problem::Job;
+use vrp_core::models::{Problem, Solution};
+
+fn create_example_problem() -> Arc<Problem> {
+    let coordinates = vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (-10., 0.), (-10., -10.), (0., -10.), (5., 5.)];
+
+    let jobs = (1..coordinates.len())
+        .map(|location| create_delivery_job(format!("job{}", location).as_str(), location, 3, None))
+        .collect::<Vec<Job>>();
+
+    let fleet = create_fleet(vec![
+        create_vehicle("small_1", "small", 4, (10., 1.), 0),
+        create_vehicle("small_2", "small", 4, (10., 1.), 0),
+        create_vehicle("small_3", "small", 4, (10., 1.), 0),
+        create_vehicle("large_1", "large", 12, (50., 2.), 0),
+    ]);
+
+    create_problem(&coordinates, fleet, jobs, None, vec![], Default::default())
+}
+
+fn solve_example() -> Result<(Arc<Problem>, Solution, f64), String> {
+    let problem = create_example_problem();
+    let (solution, cost) = solve_problem(problem.clone())?;
+
+    Ok((problem, solution, cost))
+}
+
+fn main() -> Result<(), String> {
+    let (_, solution, cost) = solve_example()?;
+
+    print_solution(&solution, cost);
+
+    Ok(())
+}
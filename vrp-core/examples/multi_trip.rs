@@ -0,0 +1,102 @@
+//! This example shows how to use multi trip functionality: a vehicle has small capacity, but it
+//! can return to the depot to reload goods, so all jobs are served within one tour.
+//!
+//! Run it with `cargo run --example multi_trip`.
+
+#[cfg(test)]
+#[path = "../tests/examples/multi_trip_test.rs"]
+mod multi_trip_test;
+
+mod common;
+use crate::common::*;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::RouteContext;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::{Job, Place, Single};
+use vrp_core::models::solution::{Activity, Route};
+use vrp_core::models::{Problem, Solution};
+
+/// A multi trip strategy which uses jobs with `reload` dimension as reload places of specific vehicle.
+struct DepotMultiTrip {}
+
+impl MultiTrip<SingleDimLoad> for DepotMultiTrip {
+    fn is_reload_job(&self, job: &Job) -> bool {
+        job.as_single().map_or(false, |single| self.is_reload_single(single))
+    }
+
+    fn is_reload_single(&self, single: &Single) -> bool {
+        single.dimens.get_value::<String>("reload").is_some()
+    }
+
+    fn is_assignable(&self, route: &Route, job: &Job) -> bool {
+        job.as_single().and_then(|single| single.dimens.get_value::<String>("reload"))
+            == route.actor.vehicle.dimens.get_id()
+    }
+
+    fn is_reload_needed(&self, current: &SingleDimLoad, max_capacity: &SingleDimLoad) -> bool {
+        current.value >= max_capacity.value
+    }
+
+    fn has_reloads(&self, route_ctx: &RouteContext) -> bool {
+        route_ctx
+            .state
+            .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+            .map_or(false, |intervals| intervals.len() > 1)
+    }
+
+    fn get_reload<'a>(&self, activity: &'a Activity) -> Option<&'a Arc<Single>> {
+        activity.job.as_ref().filter(|single| self.is_reload_single(single))
+    }
+
+    fn get_reloads<'a>(
+        &'a self,
+        route: &'a Route,
+        jobs: &'a [Job],
+    ) -> Box<dyn Iterator<Item = Job> + 'a + Send + Sync> {
+        Box::new(jobs.iter().filter(move |job| self.is_assignable(route, job)).cloned())
+    }
+}
+
+fn create_reload_job(id: &str, vehicle_id: &str, location: Location) -> Job {
+    let mut dimens = Dimensions::default();
+    dimens.set_id(id);
+    dimens.set_value("reload", vehicle_id.to_string());
+
+    Job::Single(Arc::new(Single {
+        places: vec![Place {
+            location: Some(location),
+            duration: 5.,
+            times: vec![TimeSpan::Window(TimeWindow::max())],
+        }],
+        dimens,
+    }))
+}
+
+fn create_example_problem() -> Arc<Problem> {
+    let coordinates = vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (-10., 0.), (-10., -10.), (0., -10.)];
+
+    let jobs = (1..coordinates.len())
+        .map(|location| create_delivery_job(format!("job{}", location).as_str(), location, 1, None))
+        .chain((1..=3).map(|idx| create_reload_job(format!("reload{}", idx).as_str(), "vehicle_1", 0)))
+        .collect::<Vec<Job>>();
+
+    let fleet = create_fleet(vec![create_vehicle("vehicle_1", "vehicle", 2, (10., 1.), 0)]);
+
+    create_problem(&coordinates, fleet, jobs, Some(Arc::new(DepotMultiTrip {})), vec![], Default::default())
+}
+
+fn solve_example() -> Result<(Arc<Problem>, Solution, f64), String> {
+    let problem = create_example_problem();
+    let (solution, cost) = solve_problem(problem.clone())?;
+
+    Ok((problem, solution, cost))
+}
+
+fn main() -> Result<(), String> {
+    let (_, solution, cost) = solve_example()?;
+
+    print_solution(&solution, cost);
+
+    Ok(())
+}
@@ -19,7 +19,7 @@ mod estimations;
 use self::estimations::*;
 use crate::models::solution::Commute;
 
-const CLUSTER_DIMENSION_KEY: &str = "cls";
+const CLUSTER_DIMENSION_KEY: DimenKey<Vec<ClusterInfo>> = DimenKey::new("cls");
 
 /// A trait to get or set cluster info.
 pub trait ClusterDimension {
@@ -31,12 +31,12 @@ pub trait ClusterDimension {
 
 impl ClusterDimension for Dimensions {
     fn set_cluster(&mut self, jobs: Vec<ClusterInfo>) -> &mut Self {
-        self.set_value(CLUSTER_DIMENSION_KEY, jobs);
+        self.set_dimen(&CLUSTER_DIMENSION_KEY, jobs);
         self
     }
 
     fn get_cluster(&self) -> Option<&Vec<ClusterInfo>> {
-        self.get_value(CLUSTER_DIMENSION_KEY)
+        self.get_dimen(&CLUSTER_DIMENSION_KEY)
     }
 }
 
@@ -5,9 +5,9 @@ mod capacity_test;
 use crate::construction::constraints::*;
 use crate::construction::heuristics::*;
 use crate::models::common::*;
-use crate::models::problem::{Job, Single};
+use crate::models::problem::{Actor, Job, Single};
 use crate::models::solution::{Activity, Route};
-use hashbrown::HashSet;
+use hashbrown::{HashMap, HashSet};
 use std::iter::{empty, once};
 use std::marker::PhantomData;
 use std::ops::Deref;
@@ -54,6 +54,11 @@ pub trait MultiTrip<T: LoadOps> {
         -> Box<dyn Iterator<Item = Job> + 'a + Send + Sync>;
 }
 
+/// A function which returns a key of the vehicle's tour sequence and order of the tour within it.
+/// Load remaining at the end of the tour is carried to the next tour in the same sequence instead of
+/// being unloaded. `None` means that load is not carried.
+pub type CarriedLoadResolver = Arc<dyn Fn(&Actor) -> Option<(String, usize)> + Send + Sync>;
+
 /// A module which ensures vehicle capacity limitation while serving customer's demand.
 pub struct CapacityConstraintModule<T: LoadOps> {
     code: i32,
@@ -63,6 +68,7 @@ pub struct CapacityConstraintModule<T: LoadOps> {
     transport: Arc<dyn TransportCost + Send + Sync>,
     constraints: Vec<ConstraintVariant>,
     multi_trip: Arc<dyn MultiTrip<T> + Send + Sync>,
+    carried_load: CarriedLoadResolver,
 }
 
 impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
@@ -72,7 +78,7 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
         transport: Arc<dyn TransportCost + Send + Sync>,
         code: i32,
     ) -> Self {
-        Self::new_with_multi_trip(activity, transport, code, Arc::new(NoMultiTrip { phantom: PhantomData }))
+        Self::new_with_multi_trip(activity, transport, code, Arc::new(NoMultiTrip::default()))
     }
 
     /// Creates a new instance of `CapacityConstraintModule` with multi trip (reload) functionality
@@ -81,10 +87,30 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
         transport: Arc<dyn TransportCost + Send + Sync>,
         code: i32,
         multi_trip: Arc<dyn MultiTrip<T> + Send + Sync>,
+    ) -> Self {
+        Self::new_with_carried_load(activity, transport, code, multi_trip, Arc::new(|_| None))
+    }
+
+    /// Creates a new instance of `CapacityConstraintModule` with multi trip (reload) functionality
+    /// where load remaining at the end of the tour is carried to the next tour of the same vehicle.
+    /// Carried load is unloaded at the first reload of the next tour.
+    pub fn new_with_carried_load(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+        multi_trip: Arc<dyn MultiTrip<T> + Send + Sync>,
+        carried_load: CarriedLoadResolver,
     ) -> Self {
         Self {
             code,
-            state_keys: vec![CURRENT_CAPACITY_KEY, MAX_FUTURE_CAPACITY_KEY, MAX_PAST_CAPACITY_KEY],
+            state_keys: vec![
+                CURRENT_CAPACITY_KEY,
+                MAX_FUTURE_CAPACITY_KEY,
+                MAX_PAST_CAPACITY_KEY,
+                INTERVAL_CAPACITY_KEY,
+                CARRIED_LOAD_KEY,
+                CARRIED_LOAD_LIMITS_KEY,
+            ],
             conditional: ConditionalJobModule::new(Box::new(ConcreteJobContextTransition {
                 remove_required: {
                     let multi_trip = multi_trip.clone();
@@ -104,6 +130,7 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
                 ConstraintVariant::HardRoute(Arc::new(CapacityHardRouteConstraint::<T> {
                     code,
                     multi_trip: multi_trip.clone(),
+                    carried_load: carried_load.clone(),
                 })),
                 ConstraintVariant::HardActivity(Arc::new(CapacityHardActivityConstraint::<T> {
                     code,
@@ -111,15 +138,27 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
                 })),
             ],
             multi_trip,
+            carried_load,
         }
     }
 
     fn recalculate_states(&self, ctx: &mut RouteContext) {
-        let (_, max_load) = self.actualize_intervals(ctx).into_iter().fold(
-            (T::default(), T::default()),
-            |(acc, max), (start_idx, end_idx)| {
+        let vehicle_capacity = ctx.route.actor.vehicle.dimens.get_capacity().cloned();
+        let carried = ctx.state.get_route_state::<T>(CARRIED_LOAD_KEY).cloned().unwrap_or_default();
+
+        let (_, max_load, _) = self.actualize_intervals(ctx).into_iter().fold(
+            (carried, 0_f64, vehicle_capacity),
+            |(acc, max_load, capacity), (start_idx, end_idx)| {
                 let (route, state) = ctx.as_mut();
 
+                // NOTE reload can change vehicle capacity for the rest of the tour
+                let capacity = route
+                    .tour
+                    .get(start_idx)
+                    .and_then(|activity| self.multi_trip.get_reload(activity))
+                    .and_then(|reload| reload.dimens.get_capacity().cloned())
+                    .or(capacity);
+
                 // determine static deliveries loaded at the begin and static pickups brought to the end
                 let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
                     (acc, T::default()),
@@ -143,6 +182,10 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
                         state.put_activity_state(CURRENT_CAPACITY_KEY, activity, current);
                         state.put_activity_state(MAX_PAST_CAPACITY_KEY, activity, max);
 
+                        if let Some(capacity) = capacity {
+                            state.put_activity_state(INTERVAL_CAPACITY_KEY, activity, capacity);
+                        }
+
                         (current, max)
                     },
                 );
@@ -154,15 +197,177 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
                         max
                     });
 
-                (current - end_pickup, current_max.max_load(max))
+                let max_load = capacity.map_or(max_load, |capacity| max_load.max(current_max.ratio(&capacity)));
+
+                // NOTE carried load is unloaded at the first reload
+                let unloaded = if start_idx == 0 { end_pickup + carried } else { end_pickup };
+
+                (current - unloaded, max_load, capacity)
             },
         );
 
-        if let Some(capacity) = ctx.route.actor.clone().vehicle.dimens.get_capacity() {
-            ctx.state_mut().put_route_state(MAX_LOAD_KEY, max_load.ratio(capacity));
+        if vehicle_capacity.is_some() {
+            ctx.state_mut().put_route_state(MAX_LOAD_KEY, max_load);
         }
     }
 
+    /// Propagates load remaining at the end of the tour to the next tour of the same vehicle.
+    fn update_carried_loads(&self, ctx: &mut SolutionContext) {
+        let sequences = ctx
+            .routes
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, route_ctx)| {
+                (self.carried_load)(route_ctx.route.actor.as_ref()).map(|(key, order)| (key, (order, idx)))
+            })
+            .fold(HashMap::<String, Vec<(usize, usize)>>::new(), |mut acc, (key, item)| {
+                acc.entry(key).or_insert_with(Vec::new).push(item);
+                acc
+            });
+
+        sequences.into_iter().for_each(|(_, mut sequence)| {
+            sequence.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            sequence.iter().fold(T::default(), |carried, &(_, idx)| {
+                let route_ctx = ctx.routes.get_mut(idx).unwrap();
+                if route_ctx.state.get_route_state::<T>(CARRIED_LOAD_KEY).cloned().unwrap_or_default() != carried {
+                    route_ctx.state_mut().put_route_state(CARRIED_LOAD_KEY, carried);
+                    self.recalculate_states(route_ctx);
+                }
+
+                Self::get_end_load(route_ctx)
+            });
+
+            sequence.iter().rev().fold(Vec::<(T, T)>::default(), |limits, &(_, idx)| {
+                let route_ctx = ctx.routes.get_mut(idx).unwrap();
+                if route_ctx.state.get_route_state::<Vec<(T, T)>>(CARRIED_LOAD_LIMITS_KEY) != Some(&limits) {
+                    route_ctx.state_mut().put_route_state(CARRIED_LOAD_LIMITS_KEY, limits.clone());
+                }
+
+                Self::get_carried_load_limits(route_ctx, limits)
+            });
+        });
+    }
+
+    /// Moves jobs of the tours which cannot handle load carried from the previous tours back to
+    /// required. This can happen when tours of the same vehicle are modified independently.
+    fn remove_carried_load_violations(&self, ctx: &mut SolutionContext) {
+        let locked = &ctx.locked;
+        let jobs = ctx
+            .routes
+            .iter_mut()
+            .filter(|route_ctx| {
+                route_ctx.state.get_route_state::<T>(CARRIED_LOAD_KEY).map_or(false, |carried| *carried != T::default())
+                    && route_ctx.state.get_route_state::<f64>(MAX_LOAD_KEY).map_or(false, |max_load| *max_load > 1.)
+            })
+            .flat_map(|route_ctx| {
+                let jobs = route_ctx
+                    .route
+                    .tour
+                    .jobs()
+                    .filter(|job| !locked.contains(job) && !self.multi_trip.is_reload_job(job))
+                    .collect::<Vec<_>>();
+
+                jobs.iter().for_each(|job| {
+                    route_ctx.route_mut().tour.remove(job);
+                });
+
+                jobs
+            })
+            .collect::<Vec<_>>();
+
+        ctx.required.extend(jobs.into_iter());
+    }
+
+    /// Returns load at the end of the tour.
+    fn get_end_load(ctx: &RouteContext) -> T {
+        ctx.route
+            .tour
+            .all_activities()
+            .last()
+            .and_then(|activity| ctx.state.get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Returns max load and capacity of the tours affected by load carried into given tour.
+    fn get_carried_load_limits(ctx: &RouteContext, limits: Vec<(T, T)>) -> Vec<(T, T)> {
+        let start = ctx.route.tour.start();
+        let max_load = start
+            .and_then(|start| ctx.state.get_activity_state::<T>(MAX_FUTURE_CAPACITY_KEY, start))
+            .cloned()
+            .unwrap_or_default();
+        let capacity = start.and_then(|start| Self::get_capacity(ctx, start)).cloned();
+        let has_reloads = ctx
+            .state
+            .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+            .map_or(false, |intervals| intervals.len() > 1);
+
+        match (capacity, has_reloads) {
+            (Some(capacity), true) => vec![(max_load, capacity)],
+            (Some(capacity), false) => once((max_load, capacity)).chain(limits.into_iter()).collect(),
+            (None, true) => vec![],
+            (None, false) => limits,
+        }
+    }
+
+    /// Returns load carried into the tour which has no jobs yet and limits of the next tours.
+    fn get_new_route_carried_load(
+        carried_load: &CarriedLoadResolver,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+    ) -> Option<(T, Vec<(T, T)>)> {
+        let (key, order) = (carried_load)(route_ctx.route.actor.as_ref())?;
+
+        let others = solution_ctx
+            .routes
+            .iter()
+            .filter_map(|other| {
+                (carried_load)(other.route.actor.as_ref())
+                    .filter(|(other_key, other_order)| *other_key == key && *other_order != order)
+                    .map(|(_, other_order)| (other_order, other))
+            })
+            .collect::<Vec<_>>();
+
+        let prev =
+            others.iter().filter(|(other_order, _)| *other_order < order).max_by_key(|(other_order, _)| *other_order);
+        let next =
+            others.iter().filter(|(other_order, _)| *other_order > order).min_by_key(|(other_order, _)| *other_order);
+
+        Some(match (prev, next) {
+            (Some((_, prev)), _) => (
+                Self::get_end_load(prev),
+                prev.state.get_route_state::<Vec<(T, T)>>(CARRIED_LOAD_LIMITS_KEY).cloned().unwrap_or_default(),
+            ),
+            (None, Some((_, next))) => {
+                let limits =
+                    next.state.get_route_state::<Vec<(T, T)>>(CARRIED_LOAD_LIMITS_KEY).cloned().unwrap_or_default();
+                (T::default(), Self::get_carried_load_limits(next, limits))
+            }
+            (None, None) => (T::default(), vec![]),
+        })
+    }
+
+    /// Checks whether static pickup inserted at given index increases load carried to the next tours
+    /// beyond their capacity.
+    fn has_carried_load_violation(ctx: &RouteContext, demand: Option<&Demand<T>>, insert_idx: usize) -> bool {
+        let pickup = match demand {
+            Some(demand) if demand.pickup.0.is_not_empty() => demand.pickup.0,
+            _ => return false,
+        };
+
+        let is_last_interval = ctx
+            .state
+            .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+            .and_then(|intervals| intervals.last())
+            .map_or(true, |(start_idx, _)| insert_idx >= *start_idx);
+
+        is_last_interval
+            && ctx.state.get_route_state::<Vec<(T, T)>>(CARRIED_LOAD_LIMITS_KEY).map_or(false, |limits| {
+                limits.iter().any(|(max_load, capacity)| !capacity.can_fit(&(*max_load + pickup)))
+            })
+    }
+
     fn actualize_intervals(&self, route_ctx: &mut RouteContext) -> Vec<(usize, usize)> {
         let (route, state) = route_ctx.as_mut();
         let intervals = route_intervals(route, {
@@ -181,7 +386,7 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
             .map(|end| {
                 self.multi_trip.is_reload_needed(
                     &ctx.state.get_activity_state(MAX_PAST_CAPACITY_KEY, end).cloned().unwrap_or_default(),
-                    ctx.route.actor.vehicle.dimens.get_capacity().unwrap(),
+                    Self::get_capacity(ctx, end).unwrap(),
                 )
             })
             .unwrap_or(false)
@@ -279,7 +484,7 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
             CapacityConstraintModule::<T>::has_demand_violation(
                 &ctx.state,
                 activity,
-                ctx.route.actor.vehicle.dimens.get_capacity(),
+                CapacityConstraintModule::<T>::get_capacity(ctx, activity),
                 demand,
                 true,
             )
@@ -301,6 +506,13 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
             .unwrap_or_else(|| has_demand_violation(ctx.route.tour.get(insert_idx.unwrap_or(0)).unwrap()).is_none())
     }
 
+    /// Returns vehicle capacity within reload interval of given activity.
+    fn get_capacity<'a>(ctx: &'a RouteContext, activity: &Activity) -> Option<&'a T> {
+        ctx.state
+            .get_activity_state::<T>(INTERVAL_CAPACITY_KEY, activity)
+            .or_else(|| ctx.route.actor.vehicle.dimens.get_capacity())
+    }
+
     fn get_demand(activity: &Activity) -> Option<&Demand<T>> {
         activity.job.as_ref().and_then(|job| job.dimens.get_demand())
     }
@@ -308,9 +520,10 @@ impl<T: LoadOps + 'static> CapacityConstraintModule<T> {
 
 impl<T: LoadOps> ConstraintModule for CapacityConstraintModule<T> {
     fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
-        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
-        self.accept_route_state(route_ctx);
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        self.update_carried_loads(solution_ctx);
 
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
         if self.multi_trip.is_reload_job(job) {
             // move all unassigned reloads back to ignored
             let jobs = self.multi_trip.get_reloads(&route_ctx.route, &solution_ctx.required).collect::<HashSet<_>>();
@@ -347,7 +560,10 @@ impl<T: LoadOps> ConstraintModule for CapacityConstraintModule<T> {
 
         ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
             self.recalculate_states(route_ctx);
-        })
+        });
+
+        self.update_carried_loads(ctx);
+        self.remove_carried_load_violations(ctx);
     }
 
     fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
@@ -405,10 +621,50 @@ impl<T: LoadOps> SoftRouteConstraint for CapacitySoftRouteConstraint<T> {
 struct CapacityHardRouteConstraint<T: LoadOps> {
     code: i32,
     multi_trip: Arc<dyn MultiTrip<T> + Send + Sync>,
+    carried_load: CarriedLoadResolver,
+}
+
+impl<T: LoadOps> CapacityHardRouteConstraint<T> {
+    /// Checks whether job fits into the tour without jobs taking into account carried load.
+    fn can_handle_carried_load(&self, solution_ctx: &SolutionContext, ctx: &RouteContext, job: &Job) -> bool {
+        if ctx.route.tour.has_jobs() {
+            return true;
+        }
+
+        let (carried, limits) =
+            match CapacityConstraintModule::<T>::get_new_route_carried_load(&self.carried_load, solution_ctx, ctx) {
+                Some(carried_load) => carried_load,
+                _ => return true,
+            };
+
+        let capacity: T = match ctx.route.actor.vehicle.dimens.get_capacity() {
+            Some(capacity) => *capacity,
+            _ => return true,
+        };
+
+        let demand = match job {
+            Job::Single(single) => single.dimens.get_demand().cloned().unwrap_or_default(),
+            Job::Multi(multi) => multi
+                .jobs
+                .iter()
+                .filter_map(|single| single.dimens.get_demand().cloned())
+                .fold(Demand::<T>::default(), |acc, demand| acc + demand),
+        };
+
+        capacity.can_fit(&(carried + demand.delivery.0))
+            && capacity.can_fit(&(carried + demand.pickup.0))
+            && capacity.can_fit(&(carried + demand.pickup.1))
+            && limits.iter().all(|(max_load, capacity)| capacity.can_fit(&(*max_load + demand.pickup.0)))
+    }
 }
 
 impl<T: LoadOps> HardRouteConstraint for CapacityHardRouteConstraint<T> {
-    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
         if self.multi_trip.is_reload_job(job) {
             return if self.multi_trip.is_assignable(&ctx.route, job) {
                 None
@@ -426,7 +682,7 @@ impl<T: LoadOps> HardRouteConstraint for CapacityHardRouteConstraint<T> {
             }),
         };
 
-        if can_handle {
+        if can_handle && self.can_handle_carried_load(solution_ctx, ctx, job) {
             None
         } else {
             Some(RouteConstraintViolation { code: self.code })
@@ -474,21 +730,35 @@ impl<T: LoadOps> HardActivityConstraint for CapacityHardActivityConstraint<T> {
             CapacityConstraintModule::<T>::has_demand_violation(
                 &route_ctx.state,
                 activity_ctx.prev,
-                route_ctx.route.actor.vehicle.dimens.get_capacity(),
+                CapacityConstraintModule::<T>::get_capacity(route_ctx, activity_ctx.prev),
                 demand,
                 !self.multi_trip.has_reloads(route_ctx),
             )
         };
 
+        let violation = violation.or_else(|| {
+            if CapacityConstraintModule::<T>::has_carried_load_violation(route_ctx, demand, activity_ctx.index) {
+                Some(true)
+            } else {
+                None
+            }
+        });
+
         violation.map(|stopped| ActivityConstraintViolation { code: self.code, stopped })
     }
 }
 
 /// A no multi trip strategy.
-struct NoMultiTrip<T: LoadOps> {
+pub struct NoMultiTrip<T: LoadOps> {
     phantom: PhantomData<T>,
 }
 
+impl<T: LoadOps> Default for NoMultiTrip<T> {
+    fn default() -> Self {
+        Self { phantom: PhantomData }
+    }
+}
+
 impl<T: LoadOps> MultiTrip<T> for NoMultiTrip<T> {
     fn is_reload_job(&self, _: &Job) -> bool {
         false
@@ -65,6 +65,12 @@ pub const TOTAL_DISTANCE_KEY: i32 = 3;
 pub const TOTAL_DURATION_KEY: i32 = 4;
 /// A key which track duration limit.
 pub const LIMIT_DURATION_KEY: i32 = 5;
+/// A key which tracks total cost.
+pub const TOTAL_COST_KEY: i32 = 6;
+/// A key which tracks amount of stops.
+pub const TOUR_STOPS_KEY: i32 = 7;
+/// A key which tracks jobs marked as no-show: visited, but not fulfilled.
+pub const NO_SHOW_JOBS_KEY: i32 = 8;
 
 /// A key which tracks current vehicle capacity.
 pub const CURRENT_CAPACITY_KEY: i32 = 11;
@@ -76,6 +82,14 @@ pub const MAX_PAST_CAPACITY_KEY: i32 = 13;
 pub const RELOAD_INTERVALS_KEY: i32 = 14;
 /// A key which tracks max load in tour.
 pub const MAX_LOAD_KEY: i32 = 15;
+/// A key which tracks vehicle capacity within reload interval.
+pub const INTERVAL_CAPACITY_KEY: i32 = 16;
+/// A key which tracks load carried into the tour from the previous tour of the same vehicle.
+pub const CARRIED_LOAD_KEY: i32 = 17;
+/// A key which tracks max load and capacity of the next tours affected by load carried out of the tour.
+pub const CARRIED_LOAD_LIMITS_KEY: i32 = 18;
+/// A key which tracks cost of route duration which exceeds soft shift time limit.
+pub const OVERTIME_COST_KEY: i32 = 19;
 
 mod pipeline;
 pub use self::pipeline::*;
@@ -92,6 +106,12 @@ pub use self::locking::*;
 mod tour_size;
 pub use self::tour_size::*;
 
+mod tour_cost;
+pub use self::tour_cost::*;
+
+mod tour_stops;
+pub use self::tour_stops::*;
+
 mod conditional;
 pub use self::conditional::*;
 
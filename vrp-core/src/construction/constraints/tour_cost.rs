@@ -0,0 +1,101 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/tour_cost_test.rs"]
+mod tour_cost_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::{Cost, Distance, Duration};
+use crate::models::problem::{Actor, Costs, Job, TransportCost};
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns tour cost limit for given actor.
+pub type TourCostResolver = Arc<dyn Fn(&Actor) -> Option<Cost> + Sync + Send>;
+
+/// Tracks total cost of each tour in route state and limits it using given resolver.
+/// Relies on distance and duration states, so it should be added after transport module.
+pub struct TourCostModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl TourCostModule {
+    /// Creates a new instance of `TourCostModule`.
+    pub fn new(limit_func: TourCostResolver, transport: Arc<dyn TransportCost + Send + Sync>, code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(TourCostHardActivityConstraint {
+                code,
+                limit_func,
+                transport,
+            }))],
+            state_keys: vec![TOTAL_COST_KEY],
+        }
+    }
+}
+
+impl ConstraintModule for TourCostModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let total_cost = ctx.get_route_cost();
+        ctx.state_mut().put_route_state(TOTAL_COST_KEY, total_cost);
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct TourCostHardActivityConstraint {
+    code: i32,
+    limit_func: TourCostResolver,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for TourCostHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let limit = (self.limit_func)(route_ctx.route.actor.as_ref())?;
+
+        let (change_distance, change_duration) =
+            calculate_travel_delta(self.transport.as_ref(), route_ctx.route.as_ref(), activity_ctx);
+
+        let actor = route_ctx.route.actor.as_ref();
+        let change_cost = get_travel_cost(&actor.vehicle.costs, change_distance, change_duration)
+            + get_travel_cost(&actor.driver.costs, change_distance, change_duration);
+
+        let current_cost = route_ctx
+            .state
+            .get_route_state::<Cost>(TOTAL_COST_KEY)
+            .cloned()
+            .unwrap_or_else(|| route_ctx.get_route_cost());
+
+        if current_cost + change_cost > limit {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns travel cost estimated the same way as `RouteContext::get_route_cost` does.
+fn get_travel_cost(costs: &Costs, distance: Distance, duration: Duration) -> Cost {
+    costs.per_distance * distance
+        + costs.per_driving_time.max(costs.per_service_time).max(costs.per_waiting_time) * duration
+}
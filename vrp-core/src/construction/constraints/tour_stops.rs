@@ -0,0 +1,115 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/constraints/tour_stops_test.rs"]
+mod tour_stops_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use crate::models::common::Location;
+use crate::models::problem::{Actor, Job};
+use crate::models::solution::{Activity, Tour};
+use hashbrown::HashSet;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// A function which returns tour stops limit for given actor.
+pub type TourStopsResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
+
+/// Limits amount of distinct stop locations with job activities per tour. A stop location is
+/// defined the same way as stops are formed in the solution output, so activities with commute
+/// are counted at their parking location.
+pub struct TourStopsModule {
+    state_keys: Vec<i32>,
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl TourStopsModule {
+    /// Creates a new instance of `TourStopsModule`.
+    pub fn new(limit_func: TourStopsResolver, code: i32) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(TourStopsHardActivityConstraint {
+                code,
+                limit_func,
+            }))],
+            state_keys: vec![TOUR_STOPS_KEY],
+        }
+    }
+}
+
+impl ConstraintModule for TourStopsModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        let locations = get_tour_stop_locations(&ctx.route.tour);
+        ctx.state_mut().put_route_state(TOUR_STOPS_KEY, locations);
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, _candidate: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.state_keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Checks whether activity starts a new stop when vehicle is at given location. Activity with
+/// non-zero commute starts a new stop only when vehicle is not at its forward commute location.
+pub fn is_new_stop(prev_location: Location, activity: &Activity) -> bool {
+    match activity.commute.as_ref() {
+        Some(commute) if !commute.is_zero_distance() => prev_location != commute.forward.location,
+        _ => prev_location != activity.place.location,
+    }
+}
+
+/// Returns location of the stop where given activity is performed.
+pub fn get_stop_location(activity: &Activity) -> Location {
+    match activity.commute.as_ref() {
+        Some(commute) if !commute.is_zero_distance() => commute.forward.location,
+        _ => activity.place.location,
+    }
+}
+
+/// Returns distinct stop locations with job activities in the tour.
+pub fn get_tour_stop_locations(tour: &Tour) -> HashSet<Location> {
+    tour.all_activities().filter(|activity| activity.job.is_some()).map(get_stop_location).collect()
+}
+
+struct TourStopsHardActivityConstraint {
+    code: i32,
+    limit_func: TourStopsResolver,
+}
+
+impl HardActivityConstraint for TourStopsHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let limit = (self.limit_func)(route_ctx.route.actor.as_ref())?;
+
+        let location = get_stop_location(activity_ctx.target);
+
+        let (is_known, current_stops) = match route_ctx.state.get_route_state::<HashSet<Location>>(TOUR_STOPS_KEY) {
+            Some(locations) => (locations.contains(&location), locations.len()),
+            None => {
+                let locations = get_tour_stop_locations(&route_ctx.route.tour);
+                (locations.contains(&location), locations.len())
+            }
+        };
+
+        if !is_known && current_stops + 1 > limit {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
@@ -4,8 +4,8 @@ mod transport_test;
 
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
-use crate::models::common::{Cost, Distance, Duration, Timestamp};
-use crate::models::problem::{ActivityCost, Actor, Job, Single, TransportCost, TravelTime};
+use crate::models::common::{Cost, DimenKey, Distance, Duration, Location, Timestamp, ValueDimension};
+use crate::models::problem::{is_depot_activity, ActivityCost, Actor, Job, Single, TransportCost, TravelTime};
 use crate::models::solution::{Activity, Route};
 use crate::models::OP_START_MSG;
 use rosomaxa::prelude::compare_floats;
@@ -15,6 +15,14 @@ use std::sync::Arc;
 
 // TODO revise rescheduling once routing is sensible to departure time
 
+/// A key of vehicle dimension which keeps candidate end locations of its tour. The cheapest one to
+/// reach from the last tour activity is chosen as tour end location.
+pub const VEHICLE_END_LOCATIONS_KEY: DimenKey<Vec<Location>> = DimenKey::new("end_locations");
+
+/// A key of vehicle dimension which enables search of the best departure time within shift departure
+/// window during insertion. Without it, departure is rescheduled only when duration limit is set.
+pub const VEHICLE_DEPARTURE_WINDOW_KEY: DimenKey<bool> = DimenKey::new("departure_window");
+
 /// A function which returns travel limits for given actor.
 pub type TravelLimitFunc = Arc<dyn Fn(&Actor) -> (Option<Distance>, Option<Duration>) + Send + Sync>;
 
@@ -40,6 +48,12 @@ impl ConstraintModule for TransportConstraintModule {
 
         Self::update_route_schedules(ctx, activity, transport);
         Self::update_route_states(ctx, activity, transport);
+        // NOTE departure window is searched for the best departure time after each insertion: route
+        // starts as early as possible and then departure is delayed to minimize waiting time.
+        if has_departure_window(&ctx.route.actor) {
+            Self::reset_departure_time(ctx, activity, transport);
+            Self::advance_departure_time(ctx, activity, transport, true);
+        }
         // NOTE Rescheduling during the insertion process makes sense only if the traveling limit
         // is set (for duration limit, not for distance).
         match (self.limit_func)(&ctx.route.actor) {
@@ -134,8 +148,16 @@ impl TransportConstraintModule {
         };
 
         let route = route_ctx.route.clone();
+        let end_idx = route.tour.total() - 1;
+        let has_end = route.actor.detail.end.is_some();
+
+        route_ctx.route_mut().tour.all_activities_mut().enumerate().skip(1).fold(init, |(loc, dep), (idx, a)| {
+            if has_end && idx == end_idx && a.job.is_none() {
+                if let Some(location) = get_end_location(&route, transport, loc, dep) {
+                    a.place.location = location;
+                }
+            }
 
-        route_ctx.route_mut().tour.all_activities_mut().skip(1).fold(init, |(loc, dep), a| {
             a.schedule.arrival = dep + transport.duration(&route, loc, a.place.location, TravelTime::Departure(dep));
             a.schedule.departure = activity.estimate_departure(&route, a, a.schedule.arrival);
 
@@ -150,14 +172,19 @@ impl TransportConstraintModule {
     ) {
         // update latest arrival and waiting states of non-terminate (jobs) activities
         let actor = route_ctx.route.actor.clone();
+        // NOTE tour end location can differ from actor's one when end location candidates are specified
+        let end_location =
+            actor.detail.end.as_ref().and_then(|_| route_ctx.route.tour.end()).map(|end| end.place.location);
         let init = (
             actor.detail.time.end,
-            actor
-                .detail
-                .end
-                .as_ref()
-                .unwrap_or_else(|| actor.detail.start.as_ref().unwrap_or_else(|| panic!("{}", OP_START_MSG)))
-                .location,
+            end_location.unwrap_or_else(|| {
+                actor
+                    .detail
+                    .end
+                    .as_ref()
+                    .unwrap_or_else(|| actor.detail.start.as_ref().unwrap_or_else(|| panic!("{}", OP_START_MSG)))
+                    .location
+            }),
             0_f64,
         );
 
@@ -187,7 +214,8 @@ impl TransportConstraintModule {
         let start = route.tour.start().unwrap();
         let end = route.tour.end().unwrap();
 
-        let total_dur = end.schedule.departure - start.schedule.departure;
+        // NOTE duration of the start activity is a loading time which precedes departure
+        let total_dur = end.schedule.departure - start.schedule.departure + start.place.duration;
 
         let init = (start.place.location, start.schedule.departure, Distance::default());
         let (_, _, total_dist) = route.tour.all_activities().skip(1).fold(init, |(loc, dep, total_dist), a| {
@@ -221,6 +249,23 @@ impl TransportConstraintModule {
         Self::try_update_route_departure(route_ctx, activity, transport, new_departure_time);
     }
 
+    /// Moves route's departure time back to the earliest allowed departure.
+    fn reset_departure_time(
+        route_ctx: &mut RouteContext,
+        activity: &(dyn ActivityCost + Send + Sync),
+        transport: &(dyn TransportCost + Send + Sync),
+    ) {
+        let new_departure_time = route_ctx.route.tour.start().and_then(|start| {
+            let earliest = get_earliest_departure_time(route_ctx.route.as_ref());
+            if compare_floats(earliest, start.schedule.departure) == Ordering::Less {
+                Some(earliest)
+            } else {
+                None
+            }
+        });
+        Self::try_update_route_departure(route_ctx, activity, transport, new_departure_time);
+    }
+
     fn try_update_route_departure(
         ctx: &mut RouteContext,
         activity: &(dyn ActivityCost + Send + Sync),
@@ -242,7 +287,7 @@ struct TimeHardRouteConstraint {
 
 impl HardRouteConstraint for TimeHardRouteConstraint {
     fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
-        let date = ctx.route.tour.start().unwrap().schedule.departure;
+        let date = get_earliest_departure_time(ctx.route.as_ref());
         let check_single = |single: &Arc<Single>| {
             single
                 .places
@@ -284,7 +329,13 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
         let target = activity_ctx.target;
         let next = activity_ctx.next;
 
-        let departure = prev.schedule.departure;
+        // NOTE route can depart earlier within departure window when target is inserted first
+        let is_first = route.tour.start().map_or(false, |start| std::ptr::eq(start, prev));
+        let departure = if is_first {
+            get_earliest_departure_time(route).min(prev.schedule.departure)
+        } else {
+            prev.schedule.departure
+        };
 
         if actor.detail.time.end < prev.place.time.start
             || actor.detail.time.end < target.place.time.start
@@ -325,6 +376,13 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
                 TravelTime::Departure(departure),
             );
 
+        let end_time_at_target = self.activity.estimate_departure(route, target, arr_time_at_target);
+
+        // NOTE the cheapest end location is chosen again when target becomes the last job activity
+        let next_act_location = next
+            .and_then(|next| get_next_end_location(route, self.transport.as_ref(), target, end_time_at_target, next))
+            .unwrap_or(next_act_location);
+
         let latest_departure_at_target = latest_arr_time_at_next
             - self.transport.duration(
                 route,
@@ -344,8 +402,6 @@ impl HardActivityConstraint for TimeHardActivityConstraint {
             return success();
         }
 
-        let end_time_at_target = self.activity.estimate_departure(route, target, arr_time_at_target);
-
         let arr_time_at_next = end_time_at_target
             + self.transport.duration(
                 route,
@@ -378,7 +434,8 @@ impl HardActivityConstraint for TravelHardActivityConstraint {
     ) -> Option<ActivityConstraintViolation> {
         let limit = (self.limit_func)(&route_ctx.route.actor);
         if limit.0.is_some() || limit.1.is_some() {
-            let (change_distance, change_duration) = self.calculate_travel(route_ctx.route.as_ref(), activity_ctx);
+            let (change_distance, change_duration) =
+                calculate_travel_delta(self.transport.as_ref(), route_ctx.route.as_ref(), activity_ctx);
 
             let curr_dis = route_ctx.state.get_route_state(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
             let curr_dur = route_ctx.state.get_route_state(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
@@ -397,54 +454,50 @@ impl HardActivityConstraint for TravelHardActivityConstraint {
     }
 }
 
-impl TravelHardActivityConstraint {
-    fn calculate_travel(&self, route: &Route, activity_ctx: &ActivityContext) -> (Distance, Duration) {
-        let prev = activity_ctx.prev;
-        let tar = activity_ctx.target;
-        let next = activity_ctx.next;
-
-        let prev_dep = prev.schedule.departure;
-
-        let (prev_to_tar_dis, prev_to_tar_dur) = self.calculate_leg_travel_info(route, prev, tar, prev_dep);
-        if next.is_none() {
-            return (prev_to_tar_dis, prev_to_tar_dur);
-        }
-
-        let next = next.unwrap();
-        let tar_dep = prev_dep + prev_to_tar_dur;
-
-        let (prev_to_next_dis, prev_to_next_dur) = self.calculate_leg_travel_info(route, prev, next, prev_dep);
-        let (tar_to_next_dis, tar_to_next_dur) = self.calculate_leg_travel_info(route, tar, next, tar_dep);
-
-        (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
+/// Calculates distance and duration change caused by insertion of target activity between
+/// previous and next activities.
+pub fn calculate_travel_delta(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    activity_ctx: &ActivityContext,
+) -> (Distance, Duration) {
+    let prev = activity_ctx.prev;
+    let tar = activity_ctx.target;
+    let next = activity_ctx.next;
+
+    let prev_dep = prev.schedule.departure;
+
+    let (prev_to_tar_dis, prev_to_tar_dur) = calculate_leg_travel_info(transport, route, prev, tar, prev_dep);
+    if next.is_none() {
+        return (prev_to_tar_dis, prev_to_tar_dur);
     }
 
-    fn calculate_leg_travel_info(
-        &self,
-        route: &Route,
-        first: &Activity,
-        second: &Activity,
-        departure: Timestamp,
-    ) -> (Distance, Duration) {
-        let first_to_second_dis = self.transport.distance(
-            route,
-            first.place.location,
-            second.place.location,
-            TravelTime::Departure(departure),
-        );
-        let first_to_second_dur = self.transport.duration(
-            route,
-            first.place.location,
-            second.place.location,
-            TravelTime::Departure(departure),
-        );
+    let next = next.unwrap();
+    let tar_dep = prev_dep + prev_to_tar_dur;
 
-        let second_arr = departure + first_to_second_dur;
-        let second_wait = (second.place.time.start - second_arr).max(0.);
-        let second_dep = second_arr + second_wait + second.place.duration;
+    let (prev_to_next_dis, prev_to_next_dur) = calculate_leg_travel_info(transport, route, prev, next, prev_dep);
+    let (tar_to_next_dis, tar_to_next_dur) = calculate_leg_travel_info(transport, route, tar, next, tar_dep);
 
-        (first_to_second_dis, second_dep - departure)
-    }
+    (prev_to_tar_dis + tar_to_next_dis - prev_to_next_dis, prev_to_tar_dur + tar_to_next_dur - prev_to_next_dur)
+}
+
+fn calculate_leg_travel_info(
+    transport: &(dyn TransportCost + Send + Sync),
+    route: &Route,
+    first: &Activity,
+    second: &Activity,
+    departure: Timestamp,
+) -> (Distance, Duration) {
+    let first_to_second_dis =
+        transport.distance(route, first.place.location, second.place.location, TravelTime::Departure(departure));
+    let first_to_second_dur =
+        transport.duration(route, first.place.location, second.place.location, TravelTime::Departure(departure));
+
+    let second_arr = departure + first_to_second_dur;
+    let second_wait = (second.place.time.start - second_arr).max(0.);
+    let second_dep = second_arr + second_wait + second.place.duration;
+
+    (first_to_second_dis, second_dep - departure)
 }
 
 /// Applies fixed cost for actor usage.
@@ -475,13 +528,15 @@ impl CostSoftActivityConstraint {
         time: Timestamp,
     ) -> (Cost, Cost, Timestamp) {
         let route = route_ctx.route.as_ref();
+        let end_location =
+            get_next_end_location(route, self.transport.as_ref(), start, time, end).unwrap_or(end.place.location);
 
-        let arrival = time
-            + self.transport.duration(route, start.place.location, end.place.location, TravelTime::Departure(time));
+        let arrival =
+            time + self.transport.duration(route, start.place.location, end_location, TravelTime::Departure(time));
         let departure = self.activity.estimate_departure(route, end, arrival);
 
         let transport_cost =
-            self.transport.cost(route, start.place.location, end.place.location, TravelTime::Departure(time));
+            self.transport.cost(route, start.place.location, end_location, TravelTime::Departure(time));
         let activity_cost = self.activity.cost(route, end, arrival);
 
         (transport_cost, activity_cost, departure)
@@ -516,8 +571,9 @@ impl SoftActivityConstraint for CostSoftActivityConstraint {
         let (tp_cost_old, act_cost_old, dep_time_old) =
             self.analyze_route_leg(route_ctx, prev, next, prev.schedule.departure);
 
-        let waiting_cost = waiting_time.min(0.0_f64.max(dep_time_right - dep_time_old))
-            * route_ctx.route.actor.vehicle.costs.per_waiting_time;
+        let waiting_rate =
+            route_ctx.route.actor.vehicle.costs.waiting_rate(is_depot_activity(route_ctx.route.actor.as_ref(), next));
+        let waiting_cost = waiting_time.min(0.0_f64.max(dep_time_right - dep_time_old)) * waiting_rate;
 
         let old_costs = tp_cost_old + act_cost_old + waiting_cost;
 
@@ -535,17 +591,11 @@ fn try_advance_departure_time(
     let first = route.tour.get(1)?;
     let start = route.tour.start()?;
 
-    let latest_allowed_departure = route.actor.detail.start.as_ref().and_then(|s| s.time.latest).unwrap_or(f64::MAX);
+    let latest_allowed_departure = get_latest_allowed_departure_time(route);
     let last_departure_time = start.schedule.departure;
 
     let new_departure_time = if optimize_whole_tour {
-        let (total_waiting_time, max_shift) =
-            route.tour.all_activities().rev().fold((0., f64::MAX), |(total_waiting_time, max_shift), activity| {
-                let waiting_time = (activity.place.time.start - activity.schedule.arrival).max(0.);
-                let remaining_time = (activity.place.time.end - activity.schedule.arrival - waiting_time).max(0.);
-
-                (total_waiting_time + waiting_time, waiting_time + remaining_time.min(max_shift))
-            });
+        let (total_waiting_time, max_shift) = get_departure_shift(route);
         let departure_shift = total_waiting_time.min(max_shift);
 
         (start.schedule.departure + departure_shift).min(latest_allowed_departure)
@@ -567,6 +617,109 @@ fn try_advance_departure_time(
     }
 }
 
+/// Returns the latest route's departure time which keeps its schedule feasible: departure can be
+/// delayed till that time without violating time windows of the tour activities.
+pub fn get_latest_departure_time(route: &Route) -> Option<Timestamp> {
+    let start = route.tour.start()?;
+    route.tour.get(1)?;
+
+    let latest_allowed_departure = get_latest_allowed_departure_time(route);
+    let (_, max_shift) = get_departure_shift(route);
+
+    Some((start.schedule.departure + max_shift).min(latest_allowed_departure))
+}
+
+/// Returns the cheapest tour end location to reach from given location among candidate end
+/// locations of the route's vehicle. Candidates which can be reached in time are preferred.
+fn get_end_location(
+    route: &Route,
+    transport: &(dyn TransportCost + Send + Sync),
+    from: Location,
+    departure: Timestamp,
+) -> Option<Location> {
+    let candidates = route.actor.vehicle.dimens.get_dimen(&VEHICLE_END_LOCATIONS_KEY)?;
+    let latest = route.actor.detail.time.end;
+
+    candidates
+        .iter()
+        .map(|&location| {
+            let arrival = departure + transport.duration(route, from, location, TravelTime::Departure(departure));
+            let cost = transport.cost(route, from, location, TravelTime::Departure(departure));
+
+            (location, arrival > latest, cost)
+        })
+        .min_by(|(_, a_late, a_cost), (_, b_late, b_cost)| a_late.cmp(b_late).then(compare_floats(*a_cost, *b_cost)))
+        .map(|(location, _, _)| location)
+}
+
+/// Returns the cheapest tour end location if next activity is the end of the tour.
+fn get_next_end_location(
+    route: &Route,
+    transport: &(dyn TransportCost + Send + Sync),
+    prev: &Activity,
+    departure: Timestamp,
+    next: &Activity,
+) -> Option<Location> {
+    let is_tour_end = next.job.is_none()
+        && route.actor.detail.end.is_some()
+        && route.tour.end().map_or(false, |end| std::ptr::eq(end, next));
+
+    if is_tour_end {
+        get_end_location(route, transport, prev.place.location, departure)
+    } else {
+        None
+    }
+}
+
+/// Returns true if actor has departure window: departure search is enabled for its vehicle and its
+/// departure time can be chosen between the earliest and the latest allowed departure.
+pub fn has_departure_window(actor: &Actor) -> bool {
+    let is_enabled = actor.vehicle.dimens.get_dimen(&VEHICLE_DEPARTURE_WINDOW_KEY).cloned().unwrap_or(false);
+
+    is_enabled
+        && actor.detail.start.as_ref().map_or(false, |start| match (start.time.earliest, start.time.latest) {
+            (Some(earliest), Some(latest)) => compare_floats(earliest, latest) == Ordering::Less,
+            _ => false,
+        })
+}
+
+/// Returns the earliest route's departure time: it can be earlier than the current one when
+/// actor has departure window.
+fn get_earliest_departure_time(route: &Route) -> Timestamp {
+    let (departure, loading) =
+        route.tour.start().map_or((0., 0.), |start| (start.schedule.departure, start.place.duration));
+
+    if has_departure_window(route.actor.as_ref()) {
+        route
+            .actor
+            .detail
+            .start
+            .as_ref()
+            .and_then(|start| start.time.earliest)
+            .map_or(departure, |e| (e + loading).min(departure))
+    } else {
+        departure
+    }
+}
+
+/// Returns the latest allowed route's departure time: duration of the start activity is treated
+/// as loading time which starts within the shift start time window.
+fn get_latest_allowed_departure_time(route: &Route) -> Timestamp {
+    let loading = route.tour.start().map_or(0., |start| start.place.duration);
+
+    route.actor.detail.start.as_ref().and_then(|s| s.time.latest).map_or(f64::MAX, |latest| latest + loading)
+}
+
+/// Returns total waiting time and max possible departure shift of the route.
+fn get_departure_shift(route: &Route) -> (Duration, Duration) {
+    route.tour.all_activities().rev().fold((0., f64::MAX), |(total_waiting_time, max_shift), activity| {
+        let waiting_time = (activity.place.time.start - activity.schedule.arrival).max(0.);
+        let remaining_time = (activity.place.time.end - activity.schedule.arrival - waiting_time).max(0.);
+
+        (total_waiting_time + waiting_time, waiting_time + remaining_time.min(max_shift))
+    })
+}
+
 fn try_recede_departure_time(route_ctx: &RouteContext) -> Option<Timestamp> {
     let first = route_ctx.route.tour.get(1)?;
     let start = route_ctx.route.tour.start()?;
@@ -574,7 +727,8 @@ fn try_recede_departure_time(route_ctx: &RouteContext) -> Option<Timestamp> {
     let max_change = *route_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, first)? - first.schedule.arrival;
 
     let earliest_allowed_departure =
-        route_ctx.route.actor.detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(start.place.time.start);
+        route_ctx.route.actor.detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(start.place.time.start)
+            + start.place.duration;
 
     let max_change = (start.schedule.departure - earliest_allowed_departure).min(max_change);
 
@@ -4,7 +4,7 @@ mod context_test;
 
 use crate::construction::constraints::*;
 use crate::construction::heuristics::factories::*;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, IdDimension, Load, MultiDimLoad, SingleDimLoad};
 use crate::models::problem::*;
 use crate::models::solution::*;
 use crate::models::{Extras, Problem, Solution};
@@ -15,6 +15,9 @@ use std::any::Any;
 use std::ops::Deref;
 use std::sync::Arc;
 
+/// A key to store weights of compressed solution. Its presence marks solution as compressed.
+const COMPRESSED_WEIGHTS_KEY: i32 = 2;
+
 /// A context which contains information needed for heuristic and metaheuristic.
 pub struct InsertionContext {
     /// Original problem.
@@ -33,6 +36,12 @@ impl InsertionContext {
         create_insertion_context(problem, environment)
     }
 
+    /// Creates insertion context for given problem with jobs from assignment hints inserted
+    /// into preferred routes when it is feasible.
+    pub fn new_with_hints(problem: Arc<Problem>, environment: Arc<Environment>) -> Self {
+        create_insertion_context_with_hints(problem, environment)
+    }
+
     /// Creates insertion context for given problem with empty solution.
     pub fn new_empty(problem: Arc<Problem>, environment: Arc<Environment>) -> Self {
         create_empty_insertion_context(problem, environment)
@@ -63,6 +72,79 @@ impl InsertionContext {
         self.solution.routes.iter_mut().for_each(|route_ctx| {
             constraint.accept_route_state(route_ctx);
         });
+
+        self.ensure_consistency();
+    }
+
+    /// Compresses context by removing activity states which can be restored from tours. Route and
+    /// solution states are kept, so compressed context is still comparable with others. Given weights
+    /// are kept as they cannot be calculated without activity states. Context is restored on deep copy.
+    pub(crate) fn compress_with_weights(&mut self, weights: Vec<f64>) {
+        self.solution.routes.iter_mut().for_each(|route_ctx| {
+            let mut state = RouteState::new_with_sizes((route_ctx.state.sizes().0, 0));
+            route_ctx.state.all_keys().for_each(|key| {
+                if let Some(value) = route_ctx.state.get_route_state_raw(key) {
+                    state.put_route_state_raw(key, value.clone());
+                }
+            });
+
+            route_ctx.state = Arc::new(state);
+            route_ctx.mark_stale(true);
+        });
+
+        self.solution.state.insert(COMPRESSED_WEIGHTS_KEY, Arc::new(weights));
+    }
+
+    /// Returns weights of compressed context or none if context is not compressed.
+    pub(crate) fn get_compressed_weights(&self) -> Option<&Vec<f64>> {
+        self.solution.state.get(&COMPRESSED_WEIGHTS_KEY).and_then(|s| s.downcast_ref::<Vec<f64>>())
+    }
+
+    /// Checks solution state consistency when strict mode is enabled, see `Environment::is_strict`.
+    /// Any detected inconsistency is reported to the environment, so the search is stopped with an error.
+    pub(crate) fn ensure_consistency(&self) {
+        if self.environment.is_strict {
+            if let Err(err) = self.validate_state() {
+                self.environment.strict_violation.report(format!("inconsistent solution state: {}", err));
+            }
+        }
+    }
+
+    /// Checks that solution state is internally consistent: schedules are monotonic and loads are
+    /// not negative.
+    fn validate_state(&self) -> Result<(), String> {
+        self.solution.routes.iter().try_for_each(|route_ctx| {
+            let tour = &route_ctx.route.tour;
+            let vehicle_id = route_ctx.route.actor.vehicle.dimens.get_id().cloned().unwrap_or_default();
+
+            tour.all_activities().enumerate().try_for_each(|(idx, activity)| {
+                let schedule = &activity.schedule;
+                let prev_departure = idx.checked_sub(1).and_then(|prev| tour.get(prev)).map(|a| a.schedule.departure);
+
+                if schedule.departure < schedule.arrival
+                    || prev_departure.map_or(false, |departure| schedule.arrival < departure)
+                {
+                    return Err(format!("non-monotonic schedule at activity {} in tour '{}'", idx, vehicle_id));
+                }
+
+                let state = &route_ctx.state;
+                let is_negative_load = state
+                    .get_activity_state::<SingleDimLoad>(CURRENT_CAPACITY_KEY, activity)
+                    .map(|load| !load.can_fit(&SingleDimLoad::default()))
+                    .or_else(|| {
+                        state
+                            .get_activity_state::<MultiDimLoad>(CURRENT_CAPACITY_KEY, activity)
+                            .map(|load| !load.can_fit(&MultiDimLoad::default()))
+                    })
+                    .unwrap_or(false);
+
+                if is_negative_load {
+                    return Err(format!("negative load at activity {} in tour '{}'", idx, vehicle_id));
+                }
+
+                Ok(())
+            })
+        })
     }
 
     /// Removes empty routes from solution context.
@@ -85,11 +167,19 @@ impl HeuristicSolution for InsertionContext {
     }
 
     fn deep_copy(&self) -> Self {
-        InsertionContext {
+        let mut insertion_ctx = InsertionContext {
             problem: self.problem.clone(),
             solution: self.solution.deep_copy(),
             environment: self.environment.clone(),
+        };
+
+        // NOTE compressed context has no activity states, so they are restored here
+        if insertion_ctx.solution.state.remove(&COMPRESSED_WEIGHTS_KEY).is_some() {
+            let constraint = insertion_ctx.problem.constraint.clone();
+            insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| constraint.accept_route_state(route_ctx));
         }
+
+        insertion_ctx
     }
 }
 
@@ -238,7 +328,11 @@ impl RouteContext {
         let distance = self.state.get_route_state::<f64>(TOTAL_DISTANCE_KEY).cloned().unwrap_or(0.);
         let duration = self.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
 
-        get_cost(&actor.vehicle.costs, distance, duration) + get_cost(&actor.driver.costs, distance, duration)
+        let overtime_cost = self.state.get_route_state::<f64>(OVERTIME_COST_KEY).cloned().unwrap_or(0.);
+
+        get_cost(&actor.vehicle.costs, distance, duration)
+            + get_cost(&actor.driver.costs, distance, duration)
+            + overtime_cost
     }
 
     /// Unwraps given `RouteContext` as pair of mutable references.
@@ -3,7 +3,7 @@ use crate::models::common::{Cost, Schedule};
 use crate::models::problem::*;
 use crate::models::solution::*;
 use crate::models::OP_START_MSG;
-use crate::models::{LockOrder, Problem, Solution};
+use crate::models::{AssignmentHint, LockOrder, Problem, Solution};
 use hashbrown::{HashMap, HashSet};
 use rosomaxa::prelude::Environment;
 use std::ops::Deref;
@@ -22,36 +22,23 @@ pub fn create_insertion_context(problem: Arc<Problem>, environment: Arc<Environm
     let state = Default::default();
 
     let mut sequence_job_usage: HashMap<Job, usize> = Default::default();
+    let mut relation_jobs: Vec<(Arc<Actor>, Job)> = Default::default();
 
     problem.locks.iter().for_each(|lock| {
         let actor = registry.available().find(|a| lock.condition.deref()(a.as_ref()));
         match (actor, lock.is_lazy) {
             (Some(actor), false) => {
                 registry.use_actor(&actor);
-                let mut route_ctx = RouteContext::new(actor);
+                let mut route_ctx = RouteContext::new(actor.clone());
                 let start = route_ctx.route.tour.start().unwrap_or_else(|| panic!("{}", OP_START_MSG)).place.location;
 
-                let create_activity = |single: Arc<Single>, previous_location: usize| {
-                    assert_eq!(single.places.len(), 1);
-                    assert_eq!(single.places.first().unwrap().times.len(), 1);
-
-                    let place = single.places.first().unwrap();
-                    let time = single.places.first().unwrap().times.first().unwrap();
-                    let time = time
-                        .as_time_window()
-                        .unwrap_or_else(|| panic!("Job with no time window is not supported in locks"));
-
-                    Activity {
-                        place: ActivityPlace {
-                            location: place.location.unwrap_or(previous_location),
-                            duration: place.duration,
-                            time,
-                        },
-                        schedule: Schedule { arrival: 0.0, departure: 0.0 },
-                        job: Some(single),
-                        commute: None,
-                    }
-                };
+                // NOTE jobs without order are inserted later respecting constraints, if they are not used in other details
+                let ordered_jobs = lock
+                    .details
+                    .iter()
+                    .filter(|detail| !matches!(detail.order, LockOrder::Any))
+                    .flat_map(|detail| detail.jobs.iter().cloned())
+                    .collect::<HashSet<_>>();
 
                 lock.details.iter().fold(start, |acc, detail| {
                     match detail.order {
@@ -60,12 +47,19 @@ pub fn create_insertion_context(problem: Arc<Problem>, environment: Arc<Environm
                     }
 
                     detail.jobs.iter().fold(acc, |acc, job| {
+                        if matches!(detail.order, LockOrder::Any) && !ordered_jobs.contains(job) {
+                            if !relation_jobs.iter().any(|(_, relation_job)| relation_job == job) {
+                                relation_jobs.push((actor.clone(), job.clone()));
+                            }
+                            return acc;
+                        }
+
                         let activity = match job {
-                            Job::Single(single) => create_activity(single.clone(), acc),
+                            Job::Single(single) => create_lock_activity(single.clone(), acc),
                             Job::Multi(multi) => {
                                 let idx = sequence_job_usage.get(job).cloned().unwrap_or(0);
                                 sequence_job_usage.insert(job.clone(), idx + 1);
-                                create_activity(multi.jobs.get(idx).unwrap().clone(), acc)
+                                create_lock_activity(multi.jobs.get(idx).unwrap().clone(), acc)
                             }
                         };
                         let last_location = activity.place.location;
@@ -109,6 +103,7 @@ pub fn create_insertion_context(problem: Arc<Problem>, environment: Arc<Environm
         environment,
     };
 
+    insert_relation_jobs(&mut insertion_ctx, relation_jobs);
     update_insertion_context(&mut insertion_ctx);
 
     insertion_ctx
@@ -159,6 +154,72 @@ pub fn create_insertion_context_from_solution(
     insertion_ctx
 }
 
+/// Creates insertion context and seeds it with jobs specified by assignment hints. Each job is inserted
+/// into the route of preferred actor respecting constraints, preferably after previously hinted one.
+pub fn create_insertion_context_with_hints(problem: Arc<Problem>, environment: Arc<Environment>) -> InsertionContext {
+    let mut insertion_ctx = create_insertion_context(problem.clone(), environment);
+
+    let hints = match problem.extras.get("assignment_hints").and_then(|s| s.downcast_ref::<Vec<AssignmentHint>>()) {
+        Some(hints) if !hints.is_empty() => hints,
+        _ => return insertion_ctx,
+    };
+
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+
+    prepare_insertion_ctx(&mut insertion_ctx);
+
+    hints.iter().for_each(|hint| {
+        hint.jobs.iter().for_each(|job| {
+            if !insertion_ctx.solution.required.contains(job) {
+                return;
+            }
+
+            let route_ctx = insertion_ctx
+                .solution
+                .routes
+                .iter()
+                .find(|route_ctx| hint.condition.deref()(route_ctx.route.actor.as_ref()))
+                .cloned()
+                .or_else(|| {
+                    let registry = &insertion_ctx.solution.registry;
+                    let actor = registry.resources().available().find(|actor| hint.condition.deref()(actor.as_ref()));
+                    actor.and_then(|actor| registry.next_with_actor(actor.as_ref()))
+                });
+
+            let route_ctx = match route_ctx {
+                Some(route_ctx) => route_ctx,
+                None => return,
+            };
+
+            let eval_ctx = EvaluationContext {
+                constraint: &problem.constraint,
+                job,
+                leg_selector: &leg_selector,
+                result_selector: &result_selector,
+            };
+
+            let result = [InsertionPosition::Last, InsertionPosition::Any].iter().fold(
+                InsertionResult::make_failure(),
+                |acc, position| match acc {
+                    InsertionResult::Success(_) => acc,
+                    InsertionResult::Failure(_) => {
+                        evaluate_job_insertion_in_route(&insertion_ctx, &eval_ctx, &route_ctx, *position, acc)
+                    }
+                },
+            );
+
+            if let InsertionResult::Success(success) = result {
+                apply_insertion_success(&mut insertion_ctx, success);
+            }
+        });
+    });
+
+    update_insertion_context(&mut insertion_ctx);
+
+    insertion_ctx
+}
+
 /// Creates an empty insertion context.
 pub fn create_empty_insertion_context(problem: Arc<Problem>, environment: Arc<Environment>) -> InsertionContext {
     InsertionContext {
@@ -195,3 +256,80 @@ fn update_insertion_context(insertion_ctx: &mut InsertionContext) {
     // promote all required to unassigned to have a valid statistics
     insertion_ctx.solution.unassigned.extend(insertion_ctx.solution.required.drain(0..).map(|job| (job, 0)));
 }
+
+/// Inserts jobs locked to actors without specific order into their routes: the best position is
+/// selected respecting constraints, if there is no feasible one, job is added to the end of the route.
+fn insert_relation_jobs(insertion_ctx: &mut InsertionContext, relation_jobs: Vec<(Arc<Actor>, Job)>) {
+    if relation_jobs.is_empty() {
+        return;
+    }
+
+    let problem = insertion_ctx.problem.clone();
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+
+    problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+
+    relation_jobs.into_iter().for_each(|(actor, job)| {
+        let route_index = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .position(|route_ctx| route_ctx.route.actor == actor)
+            .expect("cannot find route for locked actor");
+
+        let eval_ctx = EvaluationContext {
+            constraint: &problem.constraint,
+            job: &job,
+            leg_selector: &leg_selector,
+            result_selector: &result_selector,
+        };
+
+        let result = evaluate_job_insertion_in_route(
+            insertion_ctx,
+            &eval_ctx,
+            insertion_ctx.solution.routes.get(route_index).unwrap(),
+            InsertionPosition::Any,
+            InsertionResult::make_failure(),
+        );
+
+        match result {
+            InsertionResult::Success(success) => apply_insertion_success(insertion_ctx, success),
+            InsertionResult::Failure(_) => {
+                let route_ctx = insertion_ctx.solution.routes.get_mut(route_index).unwrap();
+                let singles = match &job {
+                    Job::Single(single) => vec![single.clone()],
+                    Job::Multi(multi) => multi.jobs.clone(),
+                };
+
+                singles.into_iter().for_each(|single| {
+                    let tour = &route_ctx.route.tour;
+                    let previous_location =
+                        tour.get(tour.job_activity_count()).map_or(0, |activity| activity.place.location);
+
+                    route_ctx.route_mut().tour.insert_last(create_lock_activity(single, previous_location));
+                });
+
+                problem.constraint.accept_insertion(&mut insertion_ctx.solution, route_index, &job);
+            }
+        }
+
+        problem.constraint.accept_solution_state(&mut insertion_ctx.solution);
+    });
+}
+
+fn create_lock_activity(single: Arc<Single>, previous_location: usize) -> Activity {
+    assert_eq!(single.places.len(), 1);
+    assert_eq!(single.places.first().unwrap().times.len(), 1);
+
+    let place = single.places.first().unwrap();
+    let time = single.places.first().unwrap().times.first().unwrap();
+    let time = time.as_time_window().unwrap_or_else(|| panic!("Job with no time window is not supported in locks"));
+
+    Activity {
+        place: ActivityPlace { location: place.location.unwrap_or(previous_location), duration: place.duration, time },
+        schedule: Schedule { arrival: 0.0, departure: 0.0 },
+        job: Some(single),
+        commute: None,
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/feasibility_test.rs"]
+mod feasibility_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::{Actor, Job};
+use rosomaxa::utils::parallel_collect;
+use std::sync::Arc;
+
+/// Returns actors which can serve given job in isolation: the job is evaluated for insertion into
+/// an empty route of each actor, so only constraints which do not depend on other jobs are
+/// considered (e.g. skills, areas, capacity, time windows reachability).
+pub fn get_feasible_actors(insertion_ctx: &InsertionContext, job: &Job) -> Vec<Arc<Actor>> {
+    evaluate_actors(insertion_ctx, job, false)
+        .into_iter()
+        .filter_map(|(actor, result)| match result {
+            InsertionResult::Success(_) => Some(actor),
+            InsertionResult::Failure(_) => None,
+        })
+        .collect()
+}
+
+/// Returns feasible actors for each required or unassigned job of the insertion context. Jobs without
+/// feasible actors cannot be served at all and can be detected before running an optimization.
+pub fn get_feasible_actors_per_job(insertion_ctx: &InsertionContext) -> Vec<(Job, Vec<Arc<Actor>>)> {
+    let solution = &insertion_ctx.solution;
+    let jobs = solution.required.iter().chain(solution.unassigned.keys()).cloned().collect::<Vec<_>>();
+
+    parallel_collect(&jobs, |job| (job.clone(), get_feasible_actors(insertion_ctx, job)))
+}
+
+/// Returns violated constraint code for each actor which cannot serve given job. The job is evaluated
+/// against actor's route from the solution or against an empty route if the actor is not used.
+/// Please note, that the job should not be kept as unassigned with positive code in the solution,
+/// otherwise its evaluation is skipped for unmodified routes.
+pub fn get_failed_actors(insertion_ctx: &InsertionContext, job: &Job) -> Vec<(Arc<Actor>, i32)> {
+    evaluate_actors(insertion_ctx, job, true)
+        .into_iter()
+        .filter_map(|(actor, result)| match result {
+            InsertionResult::Success(_) => None,
+            InsertionResult::Failure(failure) => Some((actor, failure.constraint)),
+        })
+        .collect()
+}
+
+fn evaluate_actors(
+    insertion_ctx: &InsertionContext,
+    job: &Job,
+    use_solution_routes: bool,
+) -> Vec<(Arc<Actor>, InsertionResult)> {
+    let leg_selector = VariableLegSelector::new(insertion_ctx.environment.random.clone());
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &leg_selector,
+        result_selector: &result_selector,
+    };
+
+    let solution = &insertion_ctx.solution;
+    let get_route = |actor: &Arc<Actor>| {
+        if use_solution_routes {
+            if let Some(route_ctx) = solution.routes.iter().find(|route_ctx| route_ctx.route.actor == *actor) {
+                return Some(route_ctx.clone());
+            }
+        }
+
+        solution.registry.next_with_actor(actor.as_ref())
+    };
+
+    solution
+        .registry
+        .resources()
+        .all()
+        .filter_map(|actor| get_route(&actor).map(|route_ctx| (actor, route_ctx)))
+        .map(|(actor, route_ctx)| {
+            let result = evaluate_job_insertion_in_route(
+                insertion_ctx,
+                &eval_ctx,
+                &route_ctx,
+                InsertionPosition::Any,
+                InsertionResult::make_failure(),
+            );
+
+            (actor, result)
+        })
+        .collect()
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/job_changes_test.rs"]
+mod job_changes_test;
+
+use crate::construction::constraints::NO_SHOW_JOBS_KEY;
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::{Duration, Timestamp};
+use crate::models::problem::Job;
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+/// Specifies changes of jobs which become known after the solution is built.
+#[derive(Default)]
+pub struct JobChanges {
+    /// Jobs which are cancelled by customers: they are removed from the solution and ignored further.
+    pub cancelled: Vec<Job>,
+    /// Jobs with customers absent at visit time: the visit has consumed vehicle's time, so it is kept
+    /// in the tour and locked, but the job is not fulfilled.
+    pub no_show: Vec<Job>,
+}
+
+/// Specifies a time slack created in the route by removed jobs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteSlack {
+    /// An index of the route in the solution.
+    pub route_idx: usize,
+    /// An index of the activity which follows removed activities.
+    pub activity_idx: usize,
+    /// A duration by which arrival at the activity is moved earlier.
+    pub duration: Duration,
+}
+
+/// Applies job changes to the existing solution: removes cancelled jobs, locks no-show visits,
+/// recomputes schedules of affected routes and returns created slacks which can be used to
+/// insert new jobs dynamically. No-show jobs are stored in solution state using `NO_SHOW_JOBS_KEY`.
+pub fn apply_job_changes(insertion_ctx: &mut InsertionContext, changes: &JobChanges) -> Vec<RouteSlack> {
+    let cancelled = changes.cancelled.iter().cloned().collect::<HashSet<_>>();
+    let constraint = insertion_ctx.problem.constraint.clone();
+
+    let slacks = insertion_ctx
+        .solution
+        .routes
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route.tour.jobs().any(|job| cancelled.contains(&job)))
+        .flat_map(|(route_idx, route_ctx)| {
+            // NOTE keep arrivals of remaining activities and remember which ones follow removed activities
+            let (old_arrivals, _) = route_ctx.route.tour.all_activities().fold(
+                (Vec::<(Timestamp, bool)>::new(), false),
+                |(mut acc, is_after_removed), activity| match activity.retrieve_job() {
+                    Some(job) if cancelled.contains(&job) => (acc, true),
+                    _ => {
+                        acc.push((activity.schedule.arrival, is_after_removed));
+                        (acc, false)
+                    }
+                },
+            );
+
+            cancelled.iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(job);
+            });
+            constraint.accept_route_state(route_ctx);
+
+            route_ctx
+                .route
+                .tour
+                .all_activities()
+                .zip(old_arrivals)
+                .enumerate()
+                .filter(|(_, (_, (_, is_after_removed)))| *is_after_removed)
+                .map(|(activity_idx, (activity, (old_arrival, _)))| RouteSlack {
+                    route_idx,
+                    activity_idx,
+                    duration: (old_arrival - activity.schedule.arrival).max(0.),
+                })
+                .filter(|slack| slack.duration > 0.)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let solution = &mut insertion_ctx.solution;
+    solution.required.retain(|job| !cancelled.contains(job));
+    solution.unassigned.retain(|job, _| !cancelled.contains(job));
+    solution.locked.retain(|job| !cancelled.contains(job));
+    solution.ignored.retain(|job| !cancelled.contains(job));
+    solution.ignored.extend(changes.cancelled.iter().cloned());
+
+    let mut no_show = solution
+        .state
+        .get(&NO_SHOW_JOBS_KEY)
+        .and_then(|value| value.downcast_ref::<HashSet<Job>>())
+        .cloned()
+        .unwrap_or_default();
+    no_show.extend(changes.no_show.iter().filter(|job| !cancelled.contains(job)).cloned());
+    solution.locked.extend(no_show.iter().cloned());
+    solution.state.insert(NO_SHOW_JOBS_KEY, Arc::new(no_show));
+
+    constraint.accept_solution_state(solution);
+
+    slacks
+}
+
+/// Returns jobs marked as no-show in the solution.
+pub fn get_no_show_jobs(insertion_ctx: &InsertionContext) -> HashSet<Job> {
+    insertion_ctx
+        .solution
+        .state
+        .get(&NO_SHOW_JOBS_KEY)
+        .and_then(|value| value.downcast_ref::<HashSet<Job>>())
+        .cloned()
+        .unwrap_or_default()
+}
@@ -10,6 +10,13 @@ mod evaluators;
 pub use self::evaluators::*;
 
 mod factories;
+pub(crate) use self::factories::create_insertion_context_from_solution;
+
+mod feasibility;
+pub use self::feasibility::*;
+
+mod job_changes;
+pub use self::job_changes::*;
 
 mod insertions;
 pub use self::insertions::*;
@@ -19,3 +26,6 @@ pub use self::metrics::*;
 
 mod selectors;
 pub use self::selectors::*;
+
+mod solution_editor;
+pub use self::solution_editor::*;
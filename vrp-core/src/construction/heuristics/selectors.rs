@@ -273,6 +273,22 @@ pub trait LegSelector {
     ) -> Box<dyn Iterator<Item = Leg<'a>> + 'a>;
 }
 
+/// Selects all legs of the tour: it is more expensive than sampling, but guarantees that no
+/// insertion position is missed.
+#[derive(Default)]
+pub struct AllLegSelector {}
+
+impl LegSelector for AllLegSelector {
+    fn get_legs<'a>(
+        &self,
+        route_ctx: &'a RouteContext,
+        _: &Job,
+        skip: usize,
+    ) -> Box<dyn Iterator<Item = Leg<'a>> + 'a> {
+        Box::new(route_ctx.route.tour.legs().skip(skip))
+    }
+}
+
 /// Selects different legs based on route and job size.
 pub struct VariableLegSelector {
     random: Arc<dyn Random + Send + Sync>,
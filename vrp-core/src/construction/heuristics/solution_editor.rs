@@ -0,0 +1,228 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/heuristics/solution_editor_test.rs"]
+mod solution_editor_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::{Actor, Job};
+use hashbrown::HashSet;
+use rosomaxa::prelude::HeuristicSolution;
+use std::sync::Arc;
+
+/// An unassigned code of the job removed by the editor. It is not positive, so the job is still evaluated
+/// for insertion in unmodified routes.
+pub const EDIT_REMOVAL_CODE: i32 = -1;
+
+/// Specifies a reason why solution edit is rejected.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EditError {
+    /// Job is not assigned to any route.
+    UnassignedJob,
+    /// Job is locked and cannot be moved.
+    LockedJob,
+    /// Actor is not available or insertion position is out of the route legs range.
+    InvalidPosition,
+    /// Edit violates a hard constraint with given code.
+    Constraint(i32),
+    /// Edit makes other assigned jobs unassigned when constraint states are accepted.
+    UnassignedOtherJobs,
+}
+
+/// Specifies a result of solution edit: a total cost of the updated solution or a rejection reason.
+pub type EditResult = Result<Cost, EditError>;
+
+/// Provides a way to edit solution manually, e.g. from interactive editing tools: each operation is
+/// evaluated against hard constraints on a copy of the solution and applied only when it is feasible,
+/// so the edited solution is always valid. Constraint states are accepted after each applied edit.
+pub struct SolutionEditor {
+    insertion_ctx: InsertionContext,
+}
+
+impl SolutionEditor {
+    /// Creates a new instance of `SolutionEditor`.
+    pub fn new(insertion_ctx: InsertionContext) -> Self {
+        Self { insertion_ctx }
+    }
+
+    /// Returns edited solution.
+    pub fn insertion_ctx(&self) -> &InsertionContext {
+        &self.insertion_ctx
+    }
+
+    /// Consumes editor and returns edited solution.
+    pub fn into_insertion_ctx(self) -> InsertionContext {
+        self.insertion_ctx
+    }
+
+    /// Returns total cost of the edited solution.
+    pub fn get_cost(&self) -> Cost {
+        self.insertion_ctx.solution.get_total_cost()
+    }
+
+    /// Moves assigned or unassigned job to the route of given actor, so that its first activity is inserted
+    /// at the leg with given index (zero means right after the tour start). The route is created if the actor
+    /// is not used yet.
+    pub fn move_job(&mut self, job: &Job, actor: &Arc<Actor>, position: usize) -> EditResult {
+        self.ensure_unlocked(job)?;
+
+        self.apply(&[job.clone()], |insertion_ctx| {
+            remove_jobs(insertion_ctx, &[job.clone()]);
+            insert_job(insertion_ctx, job, actor, position)
+        })
+    }
+
+    /// Swaps two assigned jobs: each job takes the position of the other one in its route.
+    pub fn swap_jobs(&mut self, first: &Job, second: &Job) -> EditResult {
+        self.ensure_unlocked(first)?;
+        self.ensure_unlocked(second)?;
+
+        let jobs = [first.clone(), second.clone()];
+        let (first_actor, first_leg) = self.get_job_leg(first, &jobs).ok_or(EditError::UnassignedJob)?;
+        let (second_actor, second_leg) = self.get_job_leg(second, &jobs).ok_or(EditError::UnassignedJob)?;
+
+        self.apply(&jobs, |insertion_ctx| {
+            remove_jobs(insertion_ctx, &jobs);
+
+            // NOTE insert into the later position first, so the earlier one is not shifted
+            if first_actor == second_actor && first_leg > second_leg {
+                insert_job(insertion_ctx, second, &first_actor, first_leg)?;
+                insert_job(insertion_ctx, first, &second_actor, second_leg)
+            } else {
+                insert_job(insertion_ctx, first, &second_actor, second_leg)?;
+                insert_job(insertion_ctx, second, &first_actor, first_leg)
+            }
+        })
+    }
+
+    /// Removes assigned job from its route and keeps it as unassigned.
+    pub fn remove_job(&mut self, job: &Job) -> EditResult {
+        self.ensure_unlocked(job)?;
+        self.get_job_leg(job, &[]).ok_or(EditError::UnassignedJob)?;
+
+        self.apply(&[job.clone()], |insertion_ctx| {
+            remove_jobs(insertion_ctx, &[job.clone()]);
+            insertion_ctx.solution.unassigned.insert(job.clone(), EDIT_REMOVAL_CODE);
+
+            Ok(())
+        })
+    }
+
+    fn ensure_unlocked(&self, job: &Job) -> Result<(), EditError> {
+        if self.insertion_ctx.solution.locked.contains(job) {
+            Err(EditError::LockedJob)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns route actor and an index of the leg where the first job activity is inserted when
+    /// given jobs are removed from the tour.
+    fn get_job_leg(&self, job: &Job, removed: &[Job]) -> Option<(Arc<Actor>, usize)> {
+        self.insertion_ctx.solution.routes.iter().find_map(|route_ctx| {
+            let tour = &route_ctx.route.tour;
+
+            tour.index(job).map(|activity_idx| {
+                let remaining = tour
+                    .all_activities()
+                    .take(activity_idx)
+                    .filter(|activity| activity.retrieve_job().map_or(true, |job| !removed.contains(&job)))
+                    .count();
+
+                (route_ctx.route.actor.clone(), remaining - 1)
+            })
+        })
+    }
+
+    /// Applies edit on a copy of the solution and keeps it only if edit succeeds and all assigned jobs,
+    /// except edited ones, are still assigned.
+    fn apply(
+        &mut self,
+        edited: &[Job],
+        edit: impl FnOnce(&mut InsertionContext) -> Result<(), EditError>,
+    ) -> EditResult {
+        let mut insertion_ctx = self.insertion_ctx.deep_copy();
+
+        edit(&mut insertion_ctx)?;
+        insertion_ctx.restore();
+
+        let assigned = get_assigned_jobs(&insertion_ctx);
+        let has_unassigned =
+            get_assigned_jobs(&self.insertion_ctx).iter().any(|job| !edited.contains(job) && !assigned.contains(job));
+
+        if has_unassigned {
+            return Err(EditError::UnassignedOtherJobs);
+        }
+
+        self.insertion_ctx = insertion_ctx;
+
+        Ok(self.get_cost())
+    }
+}
+
+fn get_assigned_jobs(insertion_ctx: &InsertionContext) -> HashSet<Job> {
+    insertion_ctx.solution.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).collect()
+}
+
+/// Removes given jobs from their routes and updates route and solution states.
+fn remove_jobs(insertion_ctx: &mut InsertionContext, jobs: &[Job]) {
+    let constraint = insertion_ctx.problem.constraint.clone();
+    let solution = &mut insertion_ctx.solution;
+
+    solution.routes.iter_mut().for_each(|route_ctx| {
+        let removed = jobs.iter().filter(|job| route_ctx.route_mut().tour.remove(job)).count();
+        if removed > 0 {
+            constraint.accept_route_state(route_ctx);
+        }
+    });
+
+    solution.required.retain(|job| !jobs.contains(job));
+    solution.unassigned.retain(|job, _| !jobs.contains(job));
+
+    constraint.accept_solution_state(solution);
+}
+
+/// Inserts job into the route of given actor at given leg if it is feasible.
+fn insert_job(
+    insertion_ctx: &mut InsertionContext,
+    job: &Job,
+    actor: &Arc<Actor>,
+    position: usize,
+) -> Result<(), EditError> {
+    let solution = &insertion_ctx.solution;
+    let route_ctx = solution
+        .routes
+        .iter()
+        .find(|route_ctx| route_ctx.route.actor == *actor)
+        .cloned()
+        .or_else(|| solution.registry.next_with_actor(actor.as_ref()))
+        .ok_or(EditError::InvalidPosition)?;
+
+    if position >= route_ctx.route.tour.legs().count() {
+        return Err(EditError::InvalidPosition);
+    }
+
+    let leg_selector = AllLegSelector::default();
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &leg_selector,
+        result_selector: &result_selector,
+    };
+
+    let result = evaluate_job_insertion_in_route(
+        insertion_ctx,
+        &eval_ctx,
+        &route_ctx,
+        InsertionPosition::Concrete(position),
+        InsertionResult::make_failure(),
+    );
+
+    match result {
+        InsertionResult::Success(success) => {
+            apply_insertion_success(insertion_ctx, success);
+            Ok(())
+        }
+        InsertionResult::Failure(failure) => Err(EditError::Constraint(failure.constraint)),
+    }
+}
@@ -8,6 +8,7 @@ use rosomaxa::prelude::compare_floats;
 use std::any::Any;
 use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 use std::sync::Arc;
 
 /// Specifies location type.
@@ -216,12 +217,49 @@ impl Eq for Schedule {}
 /// * tag.
 pub type Dimensions = HashMap<String, Arc<dyn Any + Send + Sync>>;
 
+/// A typed key of a value stored in dimensions. Unlike a plain string key, it binds the value type
+/// to the key at compile time, so reading a value with a wrong type is not possible.
+pub struct DimenKey<T> {
+    name: &'static str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> DimenKey<T> {
+    /// Creates a new instance of `DimenKey`.
+    pub const fn new(name: &'static str) -> Self {
+        Self { name, _marker: PhantomData }
+    }
+
+    /// Returns a name of the key.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<T> Clone for DimenKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DimenKey<T> {}
+
 /// A trait to return arbitrary typed value by its key.
 pub trait ValueDimension {
     /// Gets value from dimension with given key.
     fn get_value<T: 'static>(&self, key: &str) -> Option<&T>;
     /// Sets value in dimension with given key and value.
     fn set_value<T: 'static + Sync + Send>(&mut self, key: &str, value: T);
+
+    /// Gets value from dimension with given typed key.
+    fn get_dimen<T: 'static>(&self, key: &DimenKey<T>) -> Option<&T> {
+        self.get_value(key.name)
+    }
+
+    /// Sets value in dimension with given typed key and value.
+    fn set_dimen<T: 'static + Sync + Send>(&mut self, key: &DimenKey<T>, value: T) {
+        self.set_value(key.name, value)
+    }
 }
 
 impl ValueDimension for Dimensions {
@@ -94,6 +94,15 @@ pub struct Lock {
     pub is_lazy: bool,
 }
 
+/// Contains information about jobs which are preferred to be assigned to specific actor in given order.
+/// Unlike `Lock`, it is used only to seed initial solutions and does not restrict the search.
+pub struct AssignmentHint {
+    /// Specifies condition to find preferred actor.
+    pub condition: Arc<dyn Fn(&Actor) -> bool + Sync + Send>,
+    /// Specifies jobs in preferred order.
+    pub jobs: Vec<Job>,
+}
+
 impl LockDetail {
     /// Creates a new instance of `LockDetail`.
     pub fn new(order: LockOrder, position: LockPosition, jobs: Vec<Job>) -> Self {
@@ -107,3 +116,10 @@ impl Lock {
         Self { condition, details, is_lazy }
     }
 }
+
+impl AssignmentHint {
+    /// Creates a new instance of `AssignmentHint`.
+    pub fn new(condition: Arc<dyn Fn(&Actor) -> bool + Sync + Send>, jobs: Vec<Job>) -> Self {
+        Self { condition, jobs }
+    }
+}
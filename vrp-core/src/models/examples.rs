@@ -44,7 +44,14 @@ fn create_example_jobs(fleet: &Fleet, transport: &Arc<dyn TransportCost + Sync +
 /// Creates an example fleet used in documentation tests.
 fn create_example_fleet() -> Arc<Fleet> {
     let drivers = vec![Arc::new(Driver {
-        costs: Costs { fixed: 0., per_distance: 0., per_driving_time: 0., per_waiting_time: 0., per_service_time: 0. },
+        costs: Costs {
+            fixed: 0.,
+            per_distance: 0.,
+            per_driving_time: 0.,
+            per_waiting_time: 0.,
+            per_depot_waiting_time: 0.,
+            per_service_time: 0.,
+        },
         dimens: Default::default(),
         details: vec![],
     })];
@@ -52,7 +59,14 @@ fn create_example_fleet() -> Arc<Fleet> {
     vehicle_dimens.set_id("v1");
     let vehicles = vec![Arc::new(Vehicle {
         profile: Profile::default(),
-        costs: Costs { fixed: 0., per_distance: 1., per_driving_time: 0., per_waiting_time: 0., per_service_time: 0. },
+        costs: Costs {
+            fixed: 0.,
+            per_distance: 1.,
+            per_driving_time: 0.,
+            per_waiting_time: 0.,
+            per_depot_waiting_time: 0.,
+            per_service_time: 0.,
+        },
         dimens: vehicle_dimens,
         details: vec![VehicleDetail {
             start: Some(VehiclePlace { location: 0, time: TimeInterval::default() }),
@@ -2,11 +2,14 @@
 #[path = "../../../tests/unit/models/problem/costs_test.rs"]
 mod costs_test;
 
+use crate::construction::constraints::VEHICLE_END_LOCATIONS_KEY;
 use crate::construction::heuristics::InsertionContext;
 use crate::models::common::*;
-use crate::models::problem::{Actor, TargetObjective};
+use crate::models::problem::{Actor, TargetObjective, VehiclePlace};
 use crate::models::solution::{Activity, Route};
+use crate::models::Extras;
 use crate::solver::objectives::{TotalCost, TotalRoutes, TotalUnassignedJobs};
+use crate::utils::VrpError;
 use hashbrown::HashMap;
 use rand::prelude::SliceRandom;
 use rosomaxa::algorithms::nsga2::dominance_order;
@@ -17,15 +20,48 @@ use std::cmp::Ordering;
 use std::ops::Deref;
 use std::sync::Arc;
 
+const DAY_SECONDS: f64 = 86400.;
+
 /// A hierarchical multi objective for vehicle routing problem.
+#[derive(Clone)]
 pub struct ProblemObjective {
     objectives: Vec<Vec<TargetObjective>>,
+    tie_breakers: Vec<TieBreaker>,
+}
+
+/// Specifies how to select the final solution among solutions which are equal in terms of all objectives.
+/// Without tie-breaking, the selection depends on their order in population.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TieBreaker {
+    /// Prefers solution with fewer routes.
+    FewerRoutes,
+    /// Prefers solution which finishes earlier: the latest arrival at the end of a tour is compared.
+    EarlierFinish,
+    /// Prefers solution with lexicographically smaller job assignment: routes are compared by vehicle id
+    /// and ids of jobs in their order within tour.
+    Lexicographic,
 }
 
 impl ProblemObjective {
     /// Creates an instance of `InsertionObjective`.
     pub fn new(objectives: Vec<Vec<TargetObjective>>) -> Self {
-        Self { objectives }
+        Self { objectives, tie_breakers: vec![] }
+    }
+
+    /// Sets tie-breakers which are applied in given order to solutions equal in terms of all objectives.
+    pub fn with_tie_breakers(mut self, tie_breakers: Vec<TieBreaker>) -> Self {
+        self.tie_breakers = tie_breakers;
+        self
+    }
+
+    /// Orders solutions using tie-breakers only. It is not a part of `total_order` as solutions with
+    /// equal fitness are expected to be equal within population, so it is used to rank final results.
+    pub fn tie_break(&self, a: &InsertionContext, b: &InsertionContext) -> Ordering {
+        self.tie_breakers
+            .iter()
+            .map(|tie_breaker| tie_breaker.order(a, b))
+            .find(|order| *order != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
     }
 }
 
@@ -58,7 +94,14 @@ impl MultiObjective for ProblemObjective {
     }
 }
 
-impl HeuristicObjective for ProblemObjective {}
+impl HeuristicObjective for ProblemObjective {
+    fn get_tier_fitness(&self, solution: &Self::Solution) -> Vec<Vec<f64>> {
+        self.objectives
+            .iter()
+            .map(|objectives| objectives.iter().map(|objective| objective.fitness(solution)).collect())
+            .collect()
+    }
+}
 
 impl Shuffled for ProblemObjective {
     /// Returns a new instance of `ObjectiveCost` with shuffled objectives.
@@ -67,7 +110,7 @@ impl Shuffled for ProblemObjective {
 
         objectives.shuffle(&mut random.get_rng());
 
-        Self { objectives }
+        Self { objectives, tie_breakers: self.tie_breakers.clone() }
     }
 }
 
@@ -81,6 +124,63 @@ impl Default for ProblemObjective {
     }
 }
 
+impl TieBreaker {
+    fn order(&self, a: &InsertionContext, b: &InsertionContext) -> Ordering {
+        match self {
+            TieBreaker::FewerRoutes => a.solution.routes.len().cmp(&b.solution.routes.len()),
+            TieBreaker::EarlierFinish => compare_floats(get_finish_time(a), get_finish_time(b)),
+            TieBreaker::Lexicographic => {
+                let (routes_a, routes_b) = (get_sorted_routes(a), get_sorted_routes(b));
+
+                routes_a
+                    .iter()
+                    .zip(routes_b.iter())
+                    .map(|(route_a, route_b)| compare_routes(route_a, route_b))
+                    .find(|order| *order != Ordering::Equal)
+                    .unwrap_or_else(|| routes_a.len().cmp(&routes_b.len()))
+            }
+        }
+    }
+}
+
+fn get_finish_time(insertion_ctx: &InsertionContext) -> Timestamp {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .filter_map(|route_ctx| route_ctx.route.tour.end())
+        .map(|end| end.schedule.arrival)
+        .fold(0., f64::max)
+}
+
+/// Returns routes sorted by vehicle id and ids of jobs in their order within tour.
+fn get_sorted_routes(insertion_ctx: &InsertionContext) -> Vec<&Route> {
+    let mut routes = insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.as_ref()).collect::<Vec<_>>();
+    routes.sort_by(|a, b| compare_routes(a, b));
+
+    routes
+}
+
+/// Compares routes by vehicle id and then by job ids without collecting them.
+fn compare_routes(a: &Route, b: &Route) -> Ordering {
+    a.actor.vehicle.dimens.get_id().cmp(&b.actor.vehicle.dimens.get_id()).then_with(|| {
+        let mut jobs_a = a.tour.all_activities().filter_map(|activity| activity.retrieve_job());
+        let mut jobs_b = b.tour.all_activities().filter_map(|activity| activity.retrieve_job());
+
+        loop {
+            match (jobs_a.next(), jobs_b.next()) {
+                (Some(job_a), Some(job_b)) => match job_a.dimens().get_id().cmp(&job_b.dimens().get_id()) {
+                    Ordering::Equal => continue,
+                    order => return order,
+                },
+                (Some(_), None) => return Ordering::Greater,
+                (None, Some(_)) => return Ordering::Less,
+                (None, None) => return Ordering::Equal,
+            }
+        }
+    })
+}
+
 /// Specifies travel time type.
 #[derive(Copy, Clone)]
 pub enum TravelTime {
@@ -97,12 +197,19 @@ pub trait ActivityCost {
         let actor = route.actor.as_ref();
 
         let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0. };
-        let service = activity.place.duration;
+        let service = self.service_duration(route, activity);
 
-        waiting * (actor.driver.costs.per_waiting_time + actor.vehicle.costs.per_waiting_time)
+        let is_depot = is_depot_activity(actor, activity);
+
+        waiting * (actor.driver.costs.waiting_rate(is_depot) + actor.vehicle.costs.waiting_rate(is_depot))
             + service * (actor.driver.costs.per_service_time + actor.vehicle.costs.per_service_time)
     }
 
+    /// Returns duration of the activity service performed by route's actor.
+    fn service_duration(&self, _: &Route, activity: &Activity) -> Duration {
+        activity.place.duration
+    }
+
     /// Estimates departure time for activity and actor at given arrival time.
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp;
 
@@ -110,51 +217,103 @@ pub trait ActivityCost {
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp;
 }
 
+/// Checks whether activity is performed at actor's depot: it is either tour start or end, or
+/// it is located at actor's start, end or alternative end location.
+pub fn is_depot_activity(actor: &Actor, activity: &Activity) -> bool {
+    let is_depot_location =
+        |place: Option<&VehiclePlace>| place.map_or(false, |place| place.location == activity.place.location);
+    let is_alternative_end_location = || {
+        actor
+            .vehicle
+            .dimens
+            .get_dimen(&VEHICLE_END_LOCATIONS_KEY)
+            .map_or(false, |locations| locations.contains(&activity.place.location))
+    };
+
+    activity.job.is_none()
+        || is_depot_location(actor.detail.start.as_ref())
+        || is_depot_location(actor.detail.end.as_ref())
+        || is_alternative_end_location()
+}
+
 /// An actor independent activity costs.
 #[derive(Default)]
 pub struct SimpleActivityCost {}
 
 impl ActivityCost for SimpleActivityCost {
-    fn estimate_departure(&self, _: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
-        arrival.max(activity.place.time.start) + activity.place.duration
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        arrival.max(activity.place.time.start) + self.service_duration(route, activity)
     }
 
-    fn estimate_arrival(&self, _: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        activity.place.time.end.min(departure - activity.place.duration)
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        activity.place.time.end.min(departure - self.service_duration(route, activity))
+    }
+}
+
+/// Specifies reserved time span of an actor.
+#[derive(Clone, Debug)]
+pub struct ReservedTimeSpan {
+    /// A time when actor is not available.
+    pub time: TimeSpan,
+    /// Specifies whether reserved time can interrupt an ongoing activity. If not, the activity
+    /// has to be started after reserved time ends.
+    pub can_interrupt: bool,
+}
+
+impl ReservedTimeSpan {
+    /// Creates a new instance of `ReservedTimeSpan` which can interrupt an ongoing activity.
+    pub fn new(time: TimeSpan) -> Self {
+        Self { time, can_interrupt: true }
     }
 }
 
 /// Specifies reserved time index type.
-pub type ReservedTimesIndex = HashMap<Arc<Actor>, Vec<TimeSpan>>;
+pub type ReservedTimesIndex = HashMap<Arc<Actor>, Vec<ReservedTimeSpan>>;
 
 /// Specifies a function which returns an extra reserved time for given actor and time window
-/// which will be considered by specific costs.
-type ReservedTimesFunc = Arc<dyn Fn(&Route, &TimeWindow) -> Option<TimeWindow> + Send + Sync>;
+/// which will be considered by specific costs. Returned flag specifies whether reserved time
+/// can interrupt an ongoing activity.
+type ReservedTimesFunc = Arc<dyn Fn(&Route, &TimeWindow) -> Option<(TimeWindow, bool)> + Send + Sync>;
 
 /// Provides way to calculate activity costs which might contain reserved time.
 pub struct DynamicActivityCost {
     reserved_times_func: ReservedTimesFunc,
+    inner: Arc<dyn ActivityCost + Send + Sync>,
 }
 
 impl DynamicActivityCost {
     /// Creates a new instance of `DynamicActivityCost` with given reserved time function.
-    pub fn new(reserved_times_index: ReservedTimesIndex) -> Result<Self, String> {
-        Ok(Self { reserved_times_func: create_reserved_times_func(reserved_times_index)? })
+    pub fn new(
+        reserved_times_index: ReservedTimesIndex,
+        inner: Arc<dyn ActivityCost + Send + Sync>,
+    ) -> Result<Self, String> {
+        Ok(Self { reserved_times_func: create_reserved_times_func(reserved_times_index)?, inner })
     }
 }
 
 impl ActivityCost for DynamicActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        self.inner.cost(route, activity, arrival)
+    }
+
+    fn service_duration(&self, route: &Route, activity: &Activity) -> Duration {
+        self.inner.service_duration(route, activity)
+    }
+
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
         let activity_start = arrival.max(activity.place.time.start);
-        let departure = activity_start + activity.place.duration;
+        let departure = activity_start + self.service_duration(route, activity);
         let schedule = TimeWindow::new(arrival, departure);
 
-        self.reserved_times_func.deref()(route, &schedule).map_or(departure, |reserved_time: TimeWindow| {
+        self.reserved_times_func.deref()(route, &schedule).map_or(departure, |(reserved_time, can_interrupt)| {
             assert!(reserved_time.intersects(&schedule));
 
             let time_window = &activity.place.time;
 
-            let extra_duration = if reserved_time.start < time_window.start {
+            let extra_duration = if !can_interrupt {
+                // NOTE activity is not interrupted: it is started when reserved time is over
+                (reserved_time.end - activity_start).max(0.)
+            } else if reserved_time.start < time_window.start {
                 let waiting_time = TimeWindow::new(arrival, time_window.start);
                 let overlapping = waiting_time.overlapping(&reserved_time).map(|tw| tw.duration()).unwrap_or(0.);
 
@@ -175,12 +334,18 @@ impl ActivityCost for DynamicActivityCost {
     }
 
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        let arrival = activity.place.time.end.min(departure - activity.place.duration);
+        let duration = self.service_duration(route, activity);
+        let arrival = activity.place.time.end.min(departure - duration);
         let schedule = TimeWindow::new(arrival, departure);
 
-        self.reserved_times_func.deref()(route, &schedule).map_or(arrival, |reserved_time: TimeWindow| {
-            // TODO consider overlapping break with waiting time?
-            arrival - reserved_time.duration()
+        self.reserved_times_func.deref()(route, &schedule).map_or(arrival, |(reserved_time, can_interrupt)| {
+            if can_interrupt {
+                // TODO consider overlapping break with waiting time?
+                arrival - reserved_time.duration()
+            } else {
+                // NOTE activity is not interrupted: it has to be finished before reserved time starts
+                arrival.min(reserved_time.start - duration)
+            }
         })
     }
 }
@@ -245,7 +410,252 @@ impl TransportCost for DynamicTransportCost {
         };
 
         self.reserved_times_func.deref()(route, &time_window)
-            .map_or(duration, |reserved_time: TimeWindow| duration + reserved_time.duration())
+            .map_or(duration, |(reserved_time, _)| duration + reserved_time.duration())
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.inner.distance(route, from, to, travel_time)
+    }
+}
+
+/// Specifies a travel duration multiplier which is applied when travel time falls into a time of day
+/// interval. Interval bounds are specified in seconds since midnight.
+#[derive(Clone, Debug)]
+pub struct TimeOfDayMultiplier {
+    /// Interval start (inclusive).
+    pub start: f64,
+    /// Interval end (exclusive).
+    pub end: f64,
+    /// A factor applied to travel duration.
+    pub factor: f64,
+}
+
+/// Specifies time of day multipliers per routing profile index.
+pub type TimeOfDayIndex = HashMap<usize, Vec<TimeOfDayMultiplier>>;
+
+/// Provides a lightweight alternative to time dependent routing matrices: travel durations of one
+/// matrix are scaled using piecewise constant time of day multipliers defined per profile. Multiplier
+/// is selected using departure time, so a leg is not split on interval boundaries.
+/// Distances are not affected.
+pub struct TimeOfDayTransportCost {
+    multipliers: TimeOfDayIndex,
+    inner: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl TimeOfDayTransportCost {
+    /// Creates a new instance of `TimeOfDayTransportCost`.
+    pub fn new(mut multipliers: TimeOfDayIndex, inner: Arc<dyn TransportCost + Send + Sync>) -> Result<Self, String> {
+        multipliers.iter_mut().try_for_each(|(profile, intervals)| {
+            intervals.sort_by(|a, b| compare_floats(a.start, b.start));
+
+            let has_invalid = intervals.iter().any(|interval| {
+                interval.start < 0.
+                    || interval.end > DAY_SECONDS
+                    || interval.start >= interval.end
+                    || interval.factor <= 0.
+            });
+            let has_overlaps = intervals.windows(2).any(|pair| pair[0].end > pair[1].start);
+
+            if has_invalid || has_overlaps {
+                Err(format!("invalid time of day multipliers for profile with index '{}'", profile))
+            } else {
+                Ok(())
+            }
+        })?;
+
+        Ok(Self { multipliers, inner })
+    }
+
+    /// Returns a multiplier for given profile and time.
+    pub fn get_factor(&self, profile: &Profile, time: Timestamp) -> f64 {
+        let time_of_day = time.rem_euclid(DAY_SECONDS);
+
+        self.multipliers
+            .get(&profile.index)
+            .and_then(|intervals| {
+                intervals.iter().find(|interval| interval.start <= time_of_day && time_of_day < interval.end)
+            })
+            .map_or(1., |interval| interval.factor)
+    }
+}
+
+impl TransportCost for TimeOfDayTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let duration = self.inner.duration(route, from, to, travel_time);
+
+        // NOTE when arrival is known, departure is estimated using unscaled duration
+        let departure = match travel_time {
+            TravelTime::Arrival(arrival) => arrival - duration,
+            TravelTime::Departure(departure) => departure,
+        };
+
+        duration * self.get_factor(&route.actor.vehicle.profile, departure)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.inner.distance(route, from, to, travel_time)
+    }
+}
+
+/// Specifies fixed travel duration and, optionally, distance of a leg. Legs are keyed by profile
+/// index, start and end locations.
+pub type LegOverrideIndex = HashMap<(usize, Location, Location), (Duration, Option<Distance>)>;
+
+/// Provides a way to override routing data for specific legs (e.g. a ferry with fixed schedule or a
+/// known shortcut) using a sparse layer on top of another transport cost. Overridden durations are
+/// neither time dependent nor scaled by profile.
+pub struct LegOverrideTransportCost {
+    overrides: LegOverrideIndex,
+    inner: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl LegOverrideTransportCost {
+    /// Creates a new instance of `LegOverrideTransportCost`.
+    pub fn new(overrides: LegOverrideIndex, inner: Arc<dyn TransportCost + Send + Sync>) -> Result<Self, String> {
+        let invalid = overrides
+            .iter()
+            .find(|(_, (duration, distance))| *duration < 0. || distance.map_or(false, |distance| distance < 0.));
+
+        if let Some(((profile, from, to), _)) = invalid {
+            return Err(format!(
+                "leg override should have non-negative values: profile index '{}', from '{}', to '{}'",
+                profile, from, to
+            ));
+        }
+
+        Ok(Self { overrides, inner })
+    }
+
+    fn get_override(&self, profile: &Profile, from: Location, to: Location) -> Option<&(Duration, Option<Distance>)> {
+        self.overrides.get(&(profile.index, from, to))
+    }
+}
+
+impl TransportCost for LegOverrideTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.get_override(profile, from, to)
+            .map_or_else(|| self.inner.duration_approx(profile, from, to), |(duration, _)| *duration)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.get_override(profile, from, to)
+            .and_then(|(_, distance)| *distance)
+            .unwrap_or_else(|| self.inner.distance_approx(profile, from, to))
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.get_override(&route.actor.vehicle.profile, from, to)
+            .map_or_else(|| self.inner.duration(route, from, to, travel_time), |(duration, _)| *duration)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.get_override(&route.actor.vehicle.profile, from, to)
+            .and_then(|(_, distance)| *distance)
+            .unwrap_or_else(|| self.inner.distance(route, from, to, travel_time))
+    }
+}
+
+/// Specifies toll (monetary cost) matrices per routing profile index. Each matrix is a flattened
+/// square matrix with the same dimension as routing matrices.
+pub type TollIndex = HashMap<usize, Vec<Cost>>;
+
+/// Provides a way to include tolls into transport cost: toll of a leg is added to its cost as is,
+/// independently from distance and duration. As transport cost wrappers estimate cost using distance
+/// and duration only, it should be the outermost one.
+pub struct TollTransportCost {
+    tolls: TollIndex,
+    size: usize,
+    inner: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl TollTransportCost {
+    /// Creates a new instance of `TollTransportCost`.
+    pub fn new(tolls: TollIndex, inner: Arc<dyn TransportCost + Send + Sync>) -> Result<Self, String> {
+        let length = tolls.values().next().map_or(0, |tolls| tolls.len());
+        let size =
+            get_matrix_dimension(length).ok_or_else(|| format!("toll matrix is not square: '{}' values", length))?;
+
+        if tolls.values().any(|tolls| tolls.len() != length) {
+            return Err("toll matrix lengths don't match".to_string());
+        }
+
+        if tolls.values().flat_map(|tolls| tolls.iter()).any(|toll| *toll < 0.) {
+            return Err("toll should be non-negative".to_string());
+        }
+
+        Ok(Self { tolls, size, inner })
+    }
+
+    /// Returns toll of the leg between two locations for given profile. Staying at the same location,
+    /// e.g. visiting several walk-in jobs from the same parking, is free of charge.
+    pub fn toll(&self, profile: &Profile, from: Location, to: Location) -> Cost {
+        if from == to {
+            return 0.;
+        }
+
+        self.tolls.get(&profile.index).and_then(|tolls| tolls.get(from * self.size + to)).cloned().unwrap_or(0.)
+    }
+
+    /// Returns toll of the leg which starts at given location and ends at the stop of given activity.
+    /// No toll is charged when the activity is reached by commuting from the same stop.
+    pub fn leg_toll(&self, profile: &Profile, from: Location, activity: &Activity) -> Cost {
+        match activity.commute.as_ref() {
+            Some(commute) if !commute.is_zero_distance() => {
+                if from != commute.forward.location {
+                    self.toll(profile, from, commute.forward.location)
+                } else {
+                    0.
+                }
+            }
+            _ => self.toll(profile, from, activity.place.location),
+        }
+    }
+
+    /// Returns total toll of the route.
+    pub fn route_toll(&self, route: &Route) -> Cost {
+        let profile = &route.actor.vehicle.profile;
+
+        route
+            .tour
+            .legs()
+            .filter_map(|(activities, _)| match activities {
+                [from, to] => {
+                    let departure_location = from
+                        .commute
+                        .as_ref()
+                        .map_or(from.place.location, |commute| commute.departure_location(from.place.location));
+
+                    Some(self.leg_toll(profile, departure_location, to))
+                }
+                _ => None,
+            })
+            .sum()
+    }
+}
+
+impl TransportCost for TollTransportCost {
+    fn cost(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Cost {
+        self.inner.cost(route, from, to, travel_time) + self.toll(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.inner.duration(route, from, to, travel_time)
     }
 
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
@@ -272,43 +682,179 @@ impl MatrixData {
     }
 }
 
+/// Specifies how routing matrix values are kept in memory.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MatrixStorage {
+    /// Values are kept as 64-bit floats.
+    Full,
+    /// Values are kept as 32-bit floats, so memory usage is halved at the cost of precision.
+    Float,
+    /// Values are multiplied by the scale factor and kept as rounded 32-bit unsigned integers, so memory
+    /// usage is halved and precision is defined by the scale, e.g. scale 10 keeps one decimal digit.
+    /// Negative values are used to mark unreachable locations and are kept as is.
+    Quantized {
+        /// A scale factor.
+        scale: f64,
+    },
+}
+
+impl MatrixStorage {
+    /// Returns amount of bytes used to keep a single matrix value.
+    pub fn value_size(&self) -> usize {
+        match self {
+            Self::Full => std::mem::size_of::<f64>(),
+            Self::Float => std::mem::size_of::<f32>(),
+            Self::Quantized { .. } => std::mem::size_of::<u32>(),
+        }
+    }
+}
+
+impl Default for MatrixStorage {
+    fn default() -> Self {
+        Self::Full
+    }
+}
+
+const MATRIX_MEMORY_KEY: DimenKey<usize> = DimenKey::new("matrix_memory");
+
+/// A trait to get or set estimated memory usage of routing matrices.
+pub trait MatrixMemoryDimension {
+    /// Sets estimated memory usage of routing matrices in bytes.
+    fn set_matrix_memory(&mut self, bytes: usize) -> &mut Self;
+    /// Gets estimated memory usage of routing matrices in bytes.
+    fn get_matrix_memory(&self) -> Option<usize>;
+}
+
+impl MatrixMemoryDimension for Extras {
+    fn set_matrix_memory(&mut self, bytes: usize) -> &mut Self {
+        self.set_dimen(&MATRIX_MEMORY_KEY, bytes);
+        self
+    }
+
+    fn get_matrix_memory(&self) -> Option<usize> {
+        self.get_dimen(&MATRIX_MEMORY_KEY).cloned()
+    }
+}
+
+/// Returns dimension of a square matrix which has given amount of values or `None` if such
+/// matrix is not square.
+pub fn get_matrix_dimension(length: usize) -> Option<usize> {
+    // NOTE f64 square root is not exact for big values, so neighbours are checked too
+    let approx = (length as f64).sqrt().round() as usize;
+
+    (approx.saturating_sub(1)..=approx.saturating_add(1))
+        .find(|&size| size.checked_mul(size).map_or(false, |square| square == length))
+}
+
+/// Estimates amount of bytes needed to keep given amount of routing matrices (duration and distance
+/// pairs) with given dimension in memory. Returns `None` if the size cannot be addressed on the
+/// current platform.
+pub fn estimate_matrix_memory(size: usize, matrices: usize, storage: MatrixStorage) -> Option<usize> {
+    size.checked_mul(size)
+        .and_then(|values| values.checked_mul(2))
+        .and_then(|values| values.checked_mul(matrices))
+        .and_then(|values| values.checked_mul(storage.value_size()))
+}
+
+/// Keeps matrix values using a specific storage.
+enum MatrixValues {
+    Full(Vec<f64>),
+    Float(Vec<f32>),
+    Quantized(Vec<u32>, f64),
+}
+
+impl MatrixValues {
+    /// Marks negative values in quantized storage.
+    const NEGATIVE_VALUE: u32 = u32::MAX;
+
+    fn new(values: Vec<f64>, storage: MatrixStorage) -> Result<Self, String> {
+        Ok(match storage {
+            MatrixStorage::Full => Self::Full(values),
+            MatrixStorage::Float => Self::Float(values.into_iter().map(|value| value as f32).collect()),
+            MatrixStorage::Quantized { scale } => {
+                if scale <= 0. {
+                    return Err(format!("quantization scale should be positive, got: '{}'", scale));
+                }
+
+                let max_value = (Self::NEGATIVE_VALUE - 1) as f64;
+                let values = values
+                    .into_iter()
+                    .map(|value| match (value * scale).round() {
+                        _ if value < 0. => Ok(Self::NEGATIVE_VALUE),
+                        scaled if scaled <= max_value => Ok(scaled as u32),
+                        _ => Err(format!("value '{}' cannot be quantized using scale '{}'", value, scale)),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Self::Quantized(values, scale)
+            }
+        })
+    }
+
+    fn get(&self, idx: usize) -> f64 {
+        match self {
+            Self::Full(values) => *values.get(idx).unwrap(),
+            Self::Float(values) => *values.get(idx).unwrap() as f64,
+            Self::Quantized(values, scale) => match *values.get(idx).unwrap() {
+                Self::NEGATIVE_VALUE => -1.,
+                value => value as f64 / scale,
+            },
+        }
+    }
+}
+
 /// Creates time agnostic or time aware routing costs based on matrix data passed.
-pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+pub fn create_matrix_transport_cost(costs: Vec<MatrixData>) -> Result<Arc<dyn TransportCost + Send + Sync>, VrpError> {
+    create_matrix_transport_cost_with_storage(costs, MatrixStorage::default())
+}
+
+/// Creates time agnostic or time aware routing costs based on matrix data passed. Matrix values are
+/// kept in memory using given storage.
+pub fn create_matrix_transport_cost_with_storage(
+    costs: Vec<MatrixData>,
+    storage: MatrixStorage,
+) -> Result<Arc<dyn TransportCost + Send + Sync>, VrpError> {
     if costs.is_empty() {
-        return Err("no matrix data found".to_string());
+        return Err(VrpError::Matrix("no matrix data found".to_string()));
     }
 
-    let size = (costs.first().unwrap().durations.len() as f64).sqrt().round() as usize;
-
     if costs.iter().any(|matrix| matrix.distances.len() != matrix.durations.len()) {
-        return Err("distance and duration collections have different length".to_string());
+        return Err(VrpError::Matrix("distance and duration collections have different length".to_string()));
+    }
+
+    let length = costs.first().unwrap().durations.len();
+    let size = get_matrix_dimension(length)
+        .ok_or_else(|| VrpError::Matrix(format!("matrix is not square: '{}' values", length)))?;
+
+    if costs.iter().any(|matrix| matrix.distances.len() != length) {
+        return Err(VrpError::Matrix("distance lengths don't match".to_string()));
     }
 
-    if costs.iter().any(|matrix| (matrix.distances.len() as f64).sqrt().round() as usize != size) {
-        return Err("distance lengths don't match".to_string());
+    if costs.iter().any(|matrix| matrix.durations.len() != length) {
+        return Err(VrpError::Matrix("duration lengths don't match".to_string()));
     }
 
-    if costs.iter().any(|matrix| (matrix.durations.len() as f64).sqrt().round() as usize != size) {
-        return Err("duration lengths don't match".to_string());
+    if estimate_matrix_memory(size, costs.len(), storage).is_none() {
+        return Err(VrpError::Matrix(format!("matrix dimension '{}' is too big for this platform", size)));
     }
 
     Ok(if costs.iter().any(|costs| costs.timestamp.is_some()) {
-        Arc::new(TimeAwareMatrixTransportCost::new(costs, size)?)
+        Arc::new(TimeAwareMatrixTransportCost::new(costs, size, storage).map_err(VrpError::Matrix)?)
     } else {
-        Arc::new(TimeAgnosticMatrixTransportCost::new(costs, size)?)
+        Arc::new(TimeAgnosticMatrixTransportCost::new(costs, size, storage).map_err(VrpError::Matrix)?)
     })
 }
 
 /// A time agnostic matrix routing costs.
 struct TimeAgnosticMatrixTransportCost {
-    durations: Vec<Vec<Duration>>,
-    distances: Vec<Vec<Distance>>,
+    durations: Vec<MatrixValues>,
+    distances: Vec<MatrixValues>,
     size: usize,
 }
 
 impl TimeAgnosticMatrixTransportCost {
     /// Creates an instance of `TimeAgnosticMatrixTransportCost`.
-    pub fn new(costs: Vec<MatrixData>, size: usize) -> Result<Self, String> {
+    pub fn new(costs: Vec<MatrixData>, size: usize, storage: MatrixStorage) -> Result<Self, String> {
         let mut costs = costs;
         costs.sort_by(|a, b| a.index.cmp(&b.index));
 
@@ -320,12 +866,12 @@ impl TimeAgnosticMatrixTransportCost {
             return Err("duplicate profiles can be passed only for time aware routing".to_string());
         }
 
-        let (durations, distances) = costs.into_iter().fold((vec![], vec![]), |mut acc, data| {
-            acc.0.push(data.durations);
-            acc.1.push(data.distances);
+        let (durations, distances) = costs.into_iter().try_fold((vec![], vec![]), |mut acc, data| {
+            acc.0.push(MatrixValues::new(data.durations, storage)?);
+            acc.1.push(MatrixValues::new(data.distances, storage)?);
 
-            acc
-        });
+            Ok::<_, String>(acc)
+        })?;
 
         Ok(Self { durations, distances, size })
     }
@@ -333,11 +879,11 @@ impl TimeAgnosticMatrixTransportCost {
 
 impl TransportCost for TimeAgnosticMatrixTransportCost {
     fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
-        *self.durations.get(profile.index).unwrap().get(from * self.size + to).unwrap() * profile.scale
+        self.durations.get(profile.index).unwrap().get(from * self.size + to) * profile.scale
     }
 
     fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
-        *self.distances.get(profile.index).unwrap().get(from * self.size + to).unwrap()
+        self.distances.get(profile.index).unwrap().get(from * self.size + to)
     }
 
     fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
@@ -351,13 +897,20 @@ impl TransportCost for TimeAgnosticMatrixTransportCost {
 
 /// A time aware matrix costs.
 struct TimeAwareMatrixTransportCost {
-    costs: HashMap<usize, (Vec<u64>, Vec<MatrixData>)>,
+    costs: HashMap<usize, (Vec<u64>, Vec<TimedMatrixValues>)>,
     size: usize,
 }
 
+/// Keeps matrix values for specific time.
+struct TimedMatrixValues {
+    timestamp: Timestamp,
+    durations: MatrixValues,
+    distances: MatrixValues,
+}
+
 impl TimeAwareMatrixTransportCost {
     /// Creates an instance of `TimeAwareMatrixTransportCost`.
-    fn new(costs: Vec<MatrixData>, size: usize) -> Result<Self, String> {
+    fn new(costs: Vec<MatrixData>, size: usize, storage: MatrixStorage) -> Result<Self, String> {
         if costs.iter().any(|matrix| matrix.timestamp.is_none()) {
             return Err("time-aware routing requires all matrices to have timestamp".to_string());
         }
@@ -373,10 +926,20 @@ impl TimeAwareMatrixTransportCost {
             .map(|(profile, mut matrices)| {
                 matrices.sort_by(|a, b| (a.timestamp.unwrap() as u64).cmp(&(b.timestamp.unwrap() as u64)));
                 let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap() as u64).collect();
+                let matrices = matrices
+                    .into_iter()
+                    .map(|matrix| {
+                        Ok(TimedMatrixValues {
+                            timestamp: matrix.timestamp.unwrap(),
+                            durations: MatrixValues::new(matrix.durations, storage)?,
+                            distances: MatrixValues::new(matrix.distances, storage)?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
 
-                (profile, (timestamps, matrices))
+                Ok((profile, (timestamps, matrices)))
             })
-            .collect();
+            .collect::<Result<_, String>>()?;
 
         Ok(Self { costs, size })
     }
@@ -398,21 +961,18 @@ impl TimeAwareMatrixTransportCost {
 
         profile.scale
             * match timestamps.binary_search(&(timestamp as u64)) {
-                Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap(),
-                Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().durations.get(data_idx).unwrap(),
-                Err(matrix_idx) if matrix_idx == matrices.len() => {
-                    *matrices.last().unwrap().durations.get(data_idx).unwrap()
-                }
+                Ok(matrix_idx) => matrices.get(matrix_idx).unwrap().durations.get(data_idx),
+                Err(matrix_idx) if matrix_idx == 0 => matrices.first().unwrap().durations.get(data_idx),
+                Err(matrix_idx) if matrix_idx == matrices.len() => matrices.last().unwrap().durations.get(data_idx),
                 Err(matrix_idx) => {
                     let left_matrix = matrices.get(matrix_idx - 1).unwrap();
                     let right_matrix = matrices.get(matrix_idx).unwrap();
 
-                    let left_value = *matrices.get(matrix_idx - 1).unwrap().durations.get(data_idx).unwrap();
-                    let right_value = *matrices.get(matrix_idx).unwrap().durations.get(data_idx).unwrap();
+                    let left_value = matrices.get(matrix_idx - 1).unwrap().durations.get(data_idx);
+                    let right_value = matrices.get(matrix_idx).unwrap().durations.get(data_idx);
 
                     // perform linear interpolation
-                    let ratio = (timestamp - left_matrix.timestamp.unwrap())
-                        / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
+                    let ratio = (timestamp - left_matrix.timestamp) / (right_matrix.timestamp - left_matrix.timestamp);
 
                     left_value + ratio * (right_value - left_value)
                 }
@@ -435,12 +995,10 @@ impl TimeAwareMatrixTransportCost {
         let data_idx = from * self.size + to;
 
         match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => *matrices.get(matrix_idx).unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == 0 => *matrices.first().unwrap().distances.get(data_idx).unwrap(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                *matrices.last().unwrap().distances.get(data_idx).unwrap()
-            }
-            Err(matrix_idx) => *matrices.get(matrix_idx - 1).unwrap().distances.get(data_idx).unwrap(),
+            Ok(matrix_idx) => matrices.get(matrix_idx).unwrap().distances.get(data_idx),
+            Err(matrix_idx) if matrix_idx == 0 => matrices.first().unwrap().distances.get(data_idx),
+            Err(matrix_idx) if matrix_idx == matrices.len() => matrices.last().unwrap().distances.get(data_idx),
+            Err(matrix_idx) => matrices.get(matrix_idx - 1).unwrap().distances.get(data_idx),
         }
     }
 }
@@ -475,7 +1033,7 @@ fn create_reserved_times_func(reserved_times_index: ReservedTimesIndex) -> Resul
             let are_same_types = times.windows(2).all(|pair| {
                 if let [a, b] = pair {
                     matches!(
-                        (a, b),
+                        (&a.time, &b.time),
                         (TimeSpan::Window(_), TimeSpan::Window(_)) | (TimeSpan::Offset(_), TimeSpan::Offset(_))
                     )
                 } else {
@@ -488,23 +1046,26 @@ fn create_reserved_times_func(reserved_times_index: ReservedTimesIndex) -> Resul
             }
 
             times.sort_by(|a, b| {
-                let (a, b) = match (a, b) {
+                let (a, b) = match (&a.time, &b.time) {
                     (TimeSpan::Window(a), TimeSpan::Window(b)) => (a.start, b.start),
                     (TimeSpan::Offset(a), TimeSpan::Offset(b)) => (a.start, b.start),
                     _ => unreachable!(),
                 };
                 compare_floats(a, b)
             });
-            let has_no_intersections =
-                times
-                    .windows(2)
-                    .all(|pair| if let [a, b] = pair { !a.intersects(0., &b.to_time_window(0.)) } else { false });
+            let has_no_intersections = times.windows(2).all(|pair| {
+                if let [a, b] = pair {
+                    !a.time.intersects(0., &b.time.to_time_window(0.))
+                } else {
+                    false
+                }
+            });
 
             if has_no_intersections {
                 let (indices, intervals): (Vec<_>, Vec<_>) = times
                     .into_iter()
                     .map(|span| {
-                        let start = match &span {
+                        let start = match &span.time {
                             TimeSpan::Window(time) => time.start,
                             TimeSpan::Offset(time) => time.start,
                         };
@@ -529,8 +1090,10 @@ fn create_reserved_times_func(reserved_times_index: ReservedTimesIndex) -> Resul
             .and_then(|(indices, intervals)| {
                 // NOTE map absolute time window to time span's start/end
                 let (interval_start, interval_end) = match intervals.first() {
-                    Some(TimeSpan::Offset(_)) => (time_window.start - offset, time_window.end - offset),
-                    Some(TimeSpan::Window(_)) => (time_window.start, time_window.end),
+                    Some(ReservedTimeSpan { time: TimeSpan::Offset(_), .. }) => {
+                        (time_window.start - offset, time_window.end - offset)
+                    }
+                    Some(ReservedTimeSpan { time: TimeSpan::Window(_), .. }) => (time_window.start, time_window.end),
                     _ => unreachable!(),
                 };
 
@@ -540,7 +1103,7 @@ fn create_reserved_times_func(reserved_times_index: ReservedTimesIndex) -> Resul
                         .map(|idx| intervals.get(idx))
                         .find(|reserved_time| {
                             reserved_time.map_or(false, |reserved_time| {
-                                let (reserved_start, reserved_end) = match reserved_time {
+                                let (reserved_start, reserved_end) = match &reserved_time.time {
                                     TimeSpan::Offset(to) => (to.start, to.end),
                                     TimeSpan::Window(tw) => (tw.start, tw.end),
                                 };
@@ -553,6 +1116,6 @@ fn create_reserved_times_func(reserved_times_index: ReservedTimesIndex) -> Resul
                         .flatten(),
                 }
             })
-            .map(|span| span.to_time_window(offset))
+            .map(|span| (span.time.to_time_window(offset), span.can_interrupt))
     }))
 }
@@ -17,12 +17,25 @@ pub struct Costs {
     pub per_distance: f64,
     /// Cost per driving time unit.
     pub per_driving_time: f64,
-    /// Cost per waiting time unit.
+    /// Cost per waiting time unit at customer's place.
     pub per_waiting_time: f64,
+    /// Cost per waiting time unit at depot: actor's start or end location.
+    pub per_depot_waiting_time: f64,
     /// Cost per service time unit.
     pub per_service_time: f64,
 }
 
+impl Costs {
+    /// Returns cost per waiting time unit depending on whether waiting happens at depot or not.
+    pub fn waiting_rate(&self, is_depot: bool) -> f64 {
+        if is_depot {
+            self.per_depot_waiting_time
+        } else {
+            self.per_waiting_time
+        }
+    }
+}
+
 /// Represents driver detail (reserved for future use).
 #[derive(Clone, Hash, Eq, PartialEq)]
 pub struct DriverDetail {}
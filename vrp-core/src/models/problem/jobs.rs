@@ -175,7 +175,7 @@ impl Multi {
             let weak_multi = Arc::downgrade(&multi);
             let job: Arc<SingleConstruct> = unsafe { std::mem::transmute(job.clone()) };
             let dimens = unsafe { &mut *job.dimens.get() };
-            dimens.set_value("rf", weak_multi);
+            dimens.set_dimen(&MULTI_ROOT_KEY, weak_multi);
         });
 
         multi
@@ -183,7 +183,7 @@ impl Multi {
 
     /// Returns parent multi job for given sub-job.
     pub fn roots(single: &Single) -> Option<Arc<Multi>> {
-        single.dimens.get_value::<Weak<Multi>>("rf").and_then(|w| w.upgrade())
+        single.dimens.get_dimen(&MULTI_ROOT_KEY).and_then(|w| w.upgrade())
     }
 }
 
@@ -264,6 +264,8 @@ pub fn get_job_locations<'a>(job: &'a Job) -> Box<dyn Iterator<Item = Option<Loc
     }
 }
 
+const MULTI_ROOT_KEY: DimenKey<Weak<Multi>> = DimenKey::new("rf");
+
 // TODO: we don't know actual departure and zero-cost when we create job index.
 const DEFAULT_COST: Cost = 0.;
 const UNREACHABLE_COST: Cost = f64::MAX;
@@ -413,6 +415,7 @@ fn get_avg_profile_costs(fleet: &Fleet) -> HashMap<usize, Costs> {
                     per_distance: get_avg_by(costs, |c| c.per_distance),
                     per_driving_time: get_avg_by(costs, |c| c.per_driving_time),
                     per_waiting_time: get_avg_by(costs, |c| c.per_waiting_time),
+                    per_depot_waiting_time: get_avg_by(costs, |c| c.per_depot_waiting_time),
                     per_service_time: get_avg_by(costs, |c| c.per_service_time),
                 },
             )
@@ -8,3 +8,8 @@ pub use self::registry::Registry;
 
 mod tour;
 pub use self::tour::{Leg, Tour};
+
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "serde")]
+pub use self::serialization::deserialize_solution;
@@ -6,7 +6,9 @@ use rosomaxa::prelude::compare_floats;
 use std::cmp::Ordering;
 use std::sync::Arc;
 
-/// Specifies an extra commute information to reach the actual place.
+/// Specifies an extra commute information to reach the actual place. Commute either continues
+/// a stop of the previous activity (e.g. clustered jobs) or starts a new stop at forward commute
+/// location when vehicle is not there yet (e.g. a job reached by walking from a parking place).
 #[derive(Clone, Default)]
 pub struct Commute {
     /// An commute information to reach place from other location.
@@ -19,7 +21,7 @@ pub struct Commute {
 /// Commute information.
 #[derive(Clone)]
 pub struct CommuteInfo {
-    /// A previous or next location.
+    /// A previous or next location: a location of other activity or a parking location.
     pub location: Location,
 
     /// Travelled distance.
@@ -130,6 +132,16 @@ impl Commute {
     pub fn duration(&self) -> Duration {
         self.forward.duration + self.backward.duration
     }
+
+    /// Gets location where vehicle stays after the place is visited: either backward commute
+    /// location or the place location itself.
+    pub fn departure_location(&self, place_location: Location) -> Location {
+        if self.backward.is_zero_distance() {
+            place_location
+        } else {
+            self.backward.location
+        }
+    }
 }
 
 impl Default for CommuteInfo {
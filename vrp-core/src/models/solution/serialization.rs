@@ -0,0 +1,273 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/solution/serialization_test.rs"]
+mod serialization_test;
+
+use crate::models::common::*;
+use crate::models::problem::{Actor, Job, Multi, Single};
+use crate::models::solution::{Activity, Commute, CommuteInfo, Place, Registry, Route, Tour};
+use crate::models::{Problem, Solution};
+use hashbrown::HashMap;
+use rosomaxa::prelude::Random;
+use serde::de::Error as DeError;
+use serde::ser::Error as SerError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::Arc;
+
+/// A reference to the job by its id. Single job which is a part of multi job is referenced
+/// by multi job id and its index within multi job.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct JobRef {
+    id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    single: Option<usize>,
+}
+
+/// A reference to the actor by its vehicle id and actor detail.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ActorRef {
+    vehicle: String,
+    start: Option<Location>,
+    end: Option<Location>,
+    time: (Timestamp, Timestamp),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct CommuteData {
+    forward: (Location, Distance, Duration),
+    backward: (Location, Distance, Duration),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ActivityData {
+    location: Location,
+    duration: Duration,
+    time: (Timestamp, Timestamp),
+    schedule: (Timestamp, Timestamp),
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    job: Option<JobRef>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    commute: Option<CommuteData>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct RouteData {
+    actor: ActorRef,
+    activities: Vec<ActivityData>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct UnassignedData {
+    job: String,
+    code: i32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SolutionData {
+    routes: Vec<RouteData>,
+    unassigned: Vec<UnassignedData>,
+}
+
+impl Serialize for Activity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        to_activity_data(self).map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
+
+impl Serialize for Tour {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        to_activities_data(self).map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
+
+impl Serialize for Route {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        to_route_data(self).map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
+
+impl Serialize for Solution {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        to_solution_data(self).map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
+
+/// Deserializes a solution previously serialized using its `Serialize` implementation. Jobs and actors
+/// are resolved by their ids within the given problem, so it has to be the same problem which was used
+/// to build the serialized solution.
+pub fn deserialize_solution<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    problem: &Problem,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Result<Solution, D::Error> {
+    let data = SolutionData::deserialize(deserializer)?;
+
+    from_solution_data(data, problem, random).map_err(D::Error::custom)
+}
+
+fn to_job_id(job: &Job) -> Result<String, String> {
+    job.dimens().get_id().cloned().ok_or_else(|| "cannot serialize job without id".to_string())
+}
+
+fn to_job_ref(single: &Arc<Single>) -> Result<JobRef, String> {
+    match Multi::roots(single) {
+        Some(multi) => Ok(JobRef {
+            id: to_job_id(&Job::Multi(multi.clone()))?,
+            single: multi.jobs.iter().position(|job| Arc::ptr_eq(job, single)),
+        }),
+        None => Ok(JobRef { id: to_job_id(&Job::Single(single.clone()))?, single: None }),
+    }
+}
+
+fn to_activity_data(activity: &Activity) -> Result<ActivityData, String> {
+    let to_commute_info = |info: &CommuteInfo| (info.location, info.distance, info.duration);
+
+    Ok(ActivityData {
+        location: activity.place.location,
+        duration: activity.place.duration,
+        time: (activity.place.time.start, activity.place.time.end),
+        schedule: (activity.schedule.arrival, activity.schedule.departure),
+        job: activity.job.as_ref().map(to_job_ref).transpose()?,
+        commute: activity.commute.as_ref().map(|commute| CommuteData {
+            forward: to_commute_info(&commute.forward),
+            backward: to_commute_info(&commute.backward),
+        }),
+    })
+}
+
+fn to_activities_data(tour: &Tour) -> Result<Vec<ActivityData>, String> {
+    tour.all_activities().map(to_activity_data).collect()
+}
+
+fn to_actor_ref(actor: &Actor) -> Result<ActorRef, String> {
+    Ok(ActorRef {
+        vehicle: actor
+            .vehicle
+            .dimens
+            .get_id()
+            .cloned()
+            .ok_or_else(|| "cannot serialize vehicle without id".to_string())?,
+        start: actor.detail.start.as_ref().map(|place| place.location),
+        end: actor.detail.end.as_ref().map(|place| place.location),
+        time: (actor.detail.time.start, actor.detail.time.end),
+    })
+}
+
+fn to_route_data(route: &Route) -> Result<RouteData, String> {
+    Ok(RouteData { actor: to_actor_ref(route.actor.as_ref())?, activities: to_activities_data(&route.tour)? })
+}
+
+fn to_solution_data(solution: &Solution) -> Result<SolutionData, String> {
+    Ok(SolutionData {
+        routes: solution.routes.iter().map(to_route_data).collect::<Result<_, _>>()?,
+        unassigned: solution
+            .unassigned
+            .iter()
+            .map(|(job, code)| Ok(UnassignedData { job: to_job_id(job)?, code: *code }))
+            .collect::<Result<_, String>>()?,
+    })
+}
+
+fn from_job_ref(job_ref: &JobRef, jobs: &HashMap<String, Job>) -> Result<Arc<Single>, String> {
+    let job = jobs.get(&job_ref.id).ok_or_else(|| format!("cannot find job with id: '{}'", job_ref.id))?;
+
+    match (job, job_ref.single) {
+        (Job::Single(single), None) => Ok(single.clone()),
+        (Job::Multi(multi), Some(index)) => multi
+            .jobs
+            .get(index)
+            .cloned()
+            .ok_or_else(|| format!("cannot find single job at {} in multi job '{}'", index, job_ref.id)),
+        _ => Err(format!("job '{}' has unexpected type", job_ref.id)),
+    }
+}
+
+fn from_activity_data(data: ActivityData, jobs: &HashMap<String, Job>) -> Result<Activity, String> {
+    let to_commute_info =
+        |(location, distance, duration): (Location, Distance, Duration)| CommuteInfo { location, distance, duration };
+
+    Ok(Activity {
+        place: Place {
+            location: data.location,
+            duration: data.duration,
+            time: TimeWindow::new(data.time.0, data.time.1),
+        },
+        schedule: Schedule::new(data.schedule.0, data.schedule.1),
+        job: data.job.as_ref().map(|job_ref| from_job_ref(job_ref, jobs)).transpose()?,
+        commute: data.commute.map(|commute| Commute {
+            forward: to_commute_info(commute.forward),
+            backward: to_commute_info(commute.backward),
+        }),
+    })
+}
+
+fn from_actor_ref(actor_ref: &ActorRef, actors: &[Arc<Actor>]) -> Result<Arc<Actor>, String> {
+    actors
+        .iter()
+        .find(|actor| {
+            actor.vehicle.dimens.get_id() == Some(&actor_ref.vehicle)
+                && actor.detail.start.as_ref().map(|place| place.location) == actor_ref.start
+                && actor.detail.end.as_ref().map(|place| place.location) == actor_ref.end
+                && actor.detail.time.start == actor_ref.time.0
+                && actor.detail.time.end == actor_ref.time.1
+        })
+        .cloned()
+        .ok_or_else(|| format!("cannot find actor for vehicle with id: '{}'", actor_ref.vehicle))
+}
+
+fn from_route_data(data: RouteData, problem: &Problem, jobs: &HashMap<String, Job>) -> Result<Route, String> {
+    let actor = from_actor_ref(&data.actor, problem.fleet.actors.as_slice())?;
+
+    let mut tour = Tour::default();
+    for activity in data.activities {
+        let activity = from_activity_data(activity, jobs)?;
+        match (activity.job.is_some(), tour.total()) {
+            (true, 0) => return Err("tour should start with activity without job".to_string()),
+            (true, _) => tour.insert_last(activity),
+            (false, 0) => tour.set_start(activity),
+            (false, _) => tour.set_end(activity),
+        };
+    }
+
+    Ok(Route { actor, tour })
+}
+
+fn from_solution_data(
+    data: SolutionData,
+    problem: &Problem,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Result<Solution, String> {
+    let jobs = problem
+        .jobs
+        .all()
+        .filter_map(|job| job.dimens().get_id().cloned().map(|id| (id, job)))
+        .collect::<HashMap<_, _>>();
+
+    let mut registry = Registry::new(problem.fleet.as_ref(), random);
+
+    let routes = data
+        .routes
+        .into_iter()
+        .map(|route| {
+            let route = from_route_data(route, problem, &jobs)?;
+
+            if registry.use_actor(&route.actor) {
+                Ok(route)
+            } else {
+                Err("actor is used more than once".to_string())
+            }
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let unassigned = data
+        .unassigned
+        .into_iter()
+        .map(|unassigned| {
+            jobs.get(&unassigned.job)
+                .cloned()
+                .map(|job| (job, unassigned.code))
+                .ok_or_else(|| format!("cannot find job with id: '{}'", unassigned.job))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Solution { registry, routes, unassigned, extras: problem.extras.clone() })
+}
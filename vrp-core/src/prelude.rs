@@ -2,11 +2,15 @@
 
 // Reimport core types
 pub use crate::solver::create_default_config_builder;
+pub use crate::solver::create_preset_config_builder;
+pub use crate::solver::Preset;
 pub use crate::solver::Solver;
 
 pub use crate::models::Problem;
 pub use crate::models::Solution;
 
+pub use crate::utils::VrpError;
+
 pub use rosomaxa::evolution::EvolutionConfigBuilder;
 
 // Reimport rosomaxa utils
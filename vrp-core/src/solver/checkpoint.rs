@@ -0,0 +1,293 @@
+#[cfg(test)]
+#[path = "../../tests/unit/solver/checkpoint_test.rs"]
+mod checkpoint_test;
+
+use crate::construction::heuristics::{create_insertion_context_from_solution, InsertionContext};
+use crate::models::common::{Schedule, TimeWindow};
+use crate::models::problem::{Actor, Job, Single};
+use crate::models::solution::{Activity, Place, Registry, Route, Tour};
+use crate::models::{Problem, Solution};
+use crate::solver::RefinementContext;
+use hashbrown::{HashMap, HashSet};
+use rosomaxa::prelude::*;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+/// A version of checkpoint format.
+const CHECKPOINT_VERSION: &str = "2";
+
+/// Keeps evolution state needed to resume the search.
+pub(crate) struct Checkpoint {
+    /// A seed of random generator used by the search.
+    pub seed: Option<u64>,
+    /// A state of random generator used by the search.
+    pub random: Option<u64>,
+    /// A generation when checkpoint was created.
+    pub generation: usize,
+    /// A serialized state of hyper heuristic.
+    pub heuristic: Option<String>,
+    /// A serialized state of population.
+    pub population: Option<String>,
+    /// Elite solutions.
+    pub solutions: Vec<InsertionContext>,
+}
+
+/// Writes evolution state into the file at given path. The file is replaced atomically,
+/// so a previous checkpoint is kept if writing fails in the middle.
+pub(crate) fn write_checkpoint(
+    path: &Path,
+    refinement_ctx: &RefinementContext,
+    heuristic_state: Option<String>,
+) -> Result<(), String> {
+    let problem = refinement_ctx.problem.as_ref();
+    let job_index = get_job_index(problem);
+    let actor_index =
+        problem.fleet.actors.iter().enumerate().map(|(idx, actor)| (actor.clone(), idx)).collect::<HashMap<_, _>>();
+
+    let mut lines = vec![
+        format!("version {}", CHECKPOINT_VERSION),
+        format!("seed {}", refinement_ctx.environment.random.get_seed().map_or("none".to_string(), |s| s.to_string())),
+        format!("generation {}", refinement_ctx.statistics.generation),
+    ];
+
+    if let Some(state) = refinement_ctx.environment.random.save_state() {
+        lines.push(format!("random {}", state));
+    }
+
+    if let Some(state) = heuristic_state {
+        lines.push(format!("heuristic {}", state));
+    }
+
+    if let Some(state) = refinement_ctx.population.save_state() {
+        lines.push(format!("population {}", state));
+    }
+
+    lines.extend(
+        refinement_ctx
+            .population
+            .ranked()
+            .map(|(insertion_ctx, _)| format!("solution {}", encode_solution(insertion_ctx, &job_index, &actor_index))),
+    );
+
+    let tmp_path = path.with_extension("tmp");
+    let write_err = |err: std::io::Error| format!("cannot write checkpoint: {}", err);
+
+    let mut writer = BufWriter::new(File::create(&tmp_path).map_err(write_err)?);
+    lines.iter().try_for_each(|line| writeln!(writer, "{}", line)).map_err(write_err)?;
+    writer.flush().map_err(write_err)?;
+    drop(writer);
+
+    std::fs::rename(&tmp_path, path).map_err(write_err)
+}
+
+/// Reads evolution state from the file at given path.
+pub(crate) fn read_checkpoint(
+    path: &Path,
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+) -> Result<Checkpoint, String> {
+    let file = File::open(path).map_err(|err| format!("cannot open checkpoint: {}", err))?;
+
+    let jobs = problem.jobs.all().collect::<Vec<_>>();
+    let mut checkpoint = Checkpoint {
+        seed: None,
+        random: None,
+        generation: 0,
+        heuristic: None,
+        population: None,
+        solutions: Vec::default(),
+    };
+    let mut version = None;
+
+    BufReader::new(file).lines().try_for_each(|line| {
+        let line = line.map_err(|err| format!("cannot read checkpoint: {}", err))?;
+        let (key, value) = line.split_once(' ').unwrap_or((line.as_str(), ""));
+
+        match key {
+            "version" => version = Some(value.to_string()),
+            "seed" => checkpoint.seed = value.parse::<u64>().ok(),
+            "random" => {
+                checkpoint.random =
+                    Some(value.parse::<u64>().map_err(|_| format!("cannot parse random state: '{}'", value))?)
+            }
+            "generation" => {
+                checkpoint.generation =
+                    value.parse::<usize>().map_err(|_| format!("cannot parse generation: '{}'", value))?
+            }
+            "heuristic" => checkpoint.heuristic = Some(value.to_string()),
+            "population" => checkpoint.population = Some(value.to_string()),
+            "solution" => {
+                let (solution, ignored) =
+                    decode_solution(value, problem.as_ref(), jobs.as_slice(), environment.clone())?;
+
+                // NOTE ignored jobs are not part of the solution, so they have to be restored separately
+                let mut insertion_ctx =
+                    create_insertion_context_from_solution(problem.clone(), (solution, None), environment.clone());
+                insertion_ctx.solution.ignored = ignored;
+                insertion_ctx.restore();
+
+                checkpoint.solutions.push(insertion_ctx);
+            }
+            _ => return Err(format!("unknown checkpoint entry: '{}'", key)),
+        }
+
+        Ok(())
+    })?;
+
+    match version.as_deref() {
+        Some(CHECKPOINT_VERSION) => Ok(checkpoint),
+        _ => Err(format!("unsupported checkpoint version: {:?}", version)),
+    }
+}
+
+/// Encodes solution using indices of actors and jobs in the problem definition, so it can be
+/// decoded only using the same problem. Routes are followed by indices of ignored jobs.
+fn encode_solution(
+    insertion_ctx: &InsertionContext,
+    job_index: &HashMap<Job, usize>,
+    actor_index: &HashMap<Arc<Actor>, usize>,
+) -> String {
+    let routes = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .filter_map(|route_ctx| {
+            let route = route_ctx.route.as_ref();
+            let actor_idx = actor_index.get(&route.actor)?;
+            let departure = route.tour.start().map_or(0., |start| start.schedule.departure);
+
+            let activities = route
+                .tour
+                .all_activities()
+                .filter_map(|activity| {
+                    let single = activity.job.as_ref()?;
+                    let job = activity.retrieve_job()?;
+                    let job_idx = job_index.get(&job)?;
+                    let single_idx = match &job {
+                        Job::Single(_) => 0,
+                        Job::Multi(multi) => multi.jobs.iter().position(|s| Arc::ptr_eq(s, single))?,
+                    };
+
+                    Some(format!(
+                        "{}/{}/{}/{}/{}/{}",
+                        job_idx,
+                        single_idx,
+                        activity.place.location,
+                        activity.place.duration,
+                        activity.place.time.start,
+                        activity.place.time.end
+                    ))
+                })
+                .collect::<Vec<_>>();
+
+            Some(format!("{},{}:{}", actor_idx, departure, activities.join(";")))
+        })
+        .collect::<Vec<_>>();
+
+    let ignored = insertion_ctx
+        .solution
+        .ignored
+        .iter()
+        .filter_map(|job| job_index.get(job).map(|idx| idx.to_string()))
+        .collect::<Vec<_>>();
+
+    format!("{}#{}", routes.join("|"), ignored.join(","))
+}
+
+fn decode_solution(
+    value: &str,
+    problem: &Problem,
+    jobs: &[Job],
+    environment: Arc<Environment>,
+) -> Result<(Solution, Vec<Job>), String> {
+    let parse_err = |part: &str| format!("cannot parse solution in checkpoint: '{}'", part);
+    let (routes, ignored) = value.split_once('#').unwrap_or((value, ""));
+
+    let ignored = ignored
+        .split(',')
+        .filter(|idx| !idx.is_empty())
+        .map(|idx| idx.parse::<usize>().ok().and_then(|idx| jobs.get(idx)).cloned().ok_or_else(|| parse_err(idx)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut registry = Registry::new(&problem.fleet, environment.random.clone());
+    let mut added_jobs = HashSet::new();
+
+    let routes = routes
+        .split('|')
+        .filter(|route| !route.is_empty())
+        .map(|route| {
+            let (header, activities) = route.split_once(':').ok_or_else(|| parse_err(route))?;
+            let (actor_idx, departure) = header.split_once(',').ok_or_else(|| parse_err(header))?;
+
+            let actor = actor_idx
+                .parse::<usize>()
+                .ok()
+                .and_then(|idx| problem.fleet.actors.get(idx))
+                .ok_or_else(|| parse_err(header))?
+                .clone();
+            let departure = departure.parse::<f64>().map_err(|_| parse_err(header))?;
+
+            if !registry.use_actor(&actor) {
+                return Err(format!("actor is used more than once in checkpoint: '{}'", header));
+            }
+
+            let mut tour = Tour::new(&actor);
+            if let Some(start) = tour.all_activities_mut().next() {
+                start.schedule.departure = departure;
+            }
+
+            activities.split(';').filter(|activity| !activity.is_empty()).try_for_each(|activity| {
+                let (job, single) = decode_job(activity, jobs).ok_or_else(|| parse_err(activity))?;
+                let place = decode_place(activity).ok_or_else(|| parse_err(activity))?;
+
+                added_jobs.insert(job);
+                tour.insert_last(Activity { place, schedule: Schedule::new(0., 0.), job: Some(single), commute: None });
+
+                Ok::<_, String>(())
+            })?;
+
+            Ok(Route { actor, tour })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let unassigned = jobs
+        .iter()
+        .filter(|job| !added_jobs.contains(*job) && !ignored.contains(*job))
+        .map(|job| (job.clone(), 0))
+        .collect();
+
+    Ok((Solution { registry, routes, unassigned, extras: problem.extras.clone() }, ignored))
+}
+
+fn decode_job(activity: &str, jobs: &[Job]) -> Option<(Job, Arc<Single>)> {
+    let mut parts = activity.split('/');
+    let job = jobs.get(parts.next()?.parse::<usize>().ok()?)?;
+    let single_idx = parts.next()?.parse::<usize>().ok()?;
+
+    let single = match job {
+        Job::Single(single) if single_idx == 0 => single.clone(),
+        Job::Multi(multi) => multi.jobs.get(single_idx)?.clone(),
+        _ => return None,
+    };
+
+    Some((job.clone(), single))
+}
+
+fn decode_place(activity: &str) -> Option<Place> {
+    let parts = activity.split('/').skip(2).collect::<Vec<_>>();
+    if parts.len() != 4 {
+        return None;
+    }
+
+    Some(Place {
+        location: parts[0].parse().ok()?,
+        duration: parts[1].parse().ok()?,
+        time: TimeWindow::new(parts[2].parse().ok()?, parts[3].parse().ok()?),
+    })
+}
+
+fn get_job_index(problem: &Problem) -> HashMap<Job, usize> {
+    problem.jobs.all().enumerate().map(|(idx, job)| (job, idx)).collect()
+}
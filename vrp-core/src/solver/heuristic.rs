@@ -3,6 +3,7 @@ use crate::construction::heuristics::*;
 use crate::models::common::SingleDimLoad;
 use crate::models::problem::ProblemObjective;
 use crate::rosomaxa::get_default_selection_size;
+use crate::solver::hyper::*;
 use crate::solver::search::*;
 use rosomaxa::hyper::*;
 use rosomaxa::population::*;
@@ -36,6 +37,8 @@ pub type TargetCompositeTermination = CompositeTermination<RefinementContext, Pr
 pub type MaxTimeTermination = MaxTime<RefinementContext, ProblemObjective, InsertionContext>;
 /// A type for max generation termination.
 pub type MaxGenerationTermination = MaxGeneration<RefinementContext, ProblemObjective, InsertionContext>;
+/// A type for interruption termination.
+pub type InterruptionTermination = Interruption<RefinementContext, ProblemObjective, InsertionContext>;
 /// A type for min variation termination.
 pub type MinVariationTermination = MinVariation<RefinementContext, ProblemObjective, InsertionContext, String>;
 
@@ -49,13 +52,114 @@ pub type ProblemConfigBuilder = EvolutionConfigBuilder<RefinementContext, Proble
 
 /// Creates config builder with default settings.
 pub fn create_default_config_builder(problem: Arc<Problem>, environment: Arc<Environment>) -> ProblemConfigBuilder {
-    let population = get_default_population::<RefinementContext, _, _>(problem.objective.clone(), environment.clone());
+    let population = get_default_population::<RefinementContext, _, _>(
+        problem.objective.clone(),
+        environment.clone(),
+        problem.jobs.size(),
+    );
 
     ProblemConfigBuilder::default()
         .with_heuristic(get_default_heuristic(problem.clone(), environment.clone()))
         .with_context(RefinementContext::new(problem.clone(), population, environment.clone()))
         .with_initial(4, 0.05, create_default_init_operators(problem, environment))
         .with_processing(create_default_processing())
+        .with_restart_factory(create_restart_factory(Preset::Balanced))
+}
+
+/// Specifies a preset which bundles termination, population, heuristic operators and processing
+/// settings for a common scenario.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Preset {
+    /// Finds a feasible solution of decent quality as fast as possible.
+    FastFeasible,
+    /// Balances solution quality and running time. Corresponds to default settings.
+    Balanced,
+    /// Spends much more time to find a solution of high quality.
+    HighQuality,
+}
+
+/// Creates config builder with settings from given preset. Returned builder can be used to
+/// override any individual setting, e.g. termination criteria.
+pub fn create_preset_config_builder(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    preset: Preset,
+) -> ProblemConfigBuilder {
+    match preset {
+        Preset::FastFeasible => {
+            let population = Box::new(GreedyPopulation::new(problem.objective.clone(), 1, None));
+
+            ProblemConfigBuilder::default()
+                .with_heuristic(get_fast_heuristic(problem.clone(), environment.clone()))
+                .with_context(RefinementContext::new(problem.clone(), population, environment.clone()))
+                .with_initial(1, 0.05, create_default_init_operators(problem, environment))
+                .with_processing(create_fast_processing())
+                .with_restart_factory(create_restart_factory(preset))
+                .with_max_generations(Some(500))
+                .with_max_time(Some(30))
+                .with_min_cv(Some(("sample".to_string(), 200, 0.1, true)), "min_cv".to_string())
+        }
+        Preset::Balanced => create_default_config_builder(problem, environment)
+            .with_max_generations(Some(3000))
+            .with_max_time(Some(300))
+            .with_min_cv(Some(("sample".to_string(), 1000, 0.01, true)), "min_cv".to_string()),
+        Preset::HighQuality => {
+            let population = get_default_population::<RefinementContext, _, _>(
+                problem.objective.clone(),
+                environment.clone(),
+                problem.jobs.size(),
+            );
+
+            ProblemConfigBuilder::default()
+                .with_heuristic(get_default_heuristic(problem.clone(), environment.clone()))
+                .with_context(RefinementContext::new(problem.clone(), population, environment.clone()))
+                .with_initial(8, 0.1, create_default_init_operators(problem, environment))
+                .with_processing(create_default_processing())
+                .with_restart_factory(create_restart_factory(preset))
+                .with_max_generations(Some(20000))
+                .with_max_time(Some(1800))
+                .with_min_cv(Some(("period".to_string(), 300, 0.001, true)), "min_cv".to_string())
+        }
+    }
+}
+
+/// Creates a factory which builds context and heuristic for each solver restart (see `with_restarts`).
+/// Problem definition of the previous restart is reused, but population, heuristic and random
+/// generator are new, so restarts are independent from each other. A seeded random generator
+/// produces a derived seed, so the whole run stays reproducible.
+fn create_restart_factory(preset: Preset) -> RestartFactory<RefinementContext, ProblemObjective, InsertionContext> {
+    Box::new(move |refinement_ctx: &RefinementContext, number| {
+        let problem = refinement_ctx.problem.clone();
+        let random: Arc<dyn Random + Send + Sync> = match refinement_ctx.environment.random.get_seed() {
+            Some(seed) => Arc::new(DefaultRandom::new_with_seed(seed.wrapping_add(1))),
+            None => Arc::new(DefaultRandom::default()),
+        };
+        let environment = Arc::new(Environment { random, ..refinement_ctx.environment.as_ref().clone() });
+
+        let (population, heuristic): (TargetPopulation, TargetHeuristic) = match preset {
+            Preset::FastFeasible => (
+                Box::new(GreedyPopulation::new(problem.objective.clone(), 1, None)),
+                get_fast_heuristic(problem.clone(), environment.clone()),
+            ),
+            Preset::Balanced | Preset::HighQuality => (
+                get_default_population::<RefinementContext, _, _>(
+                    problem.objective.clone(),
+                    environment.clone(),
+                    problem.jobs.size(),
+                ),
+                get_default_heuristic(problem.clone(), environment.clone()),
+            ),
+        };
+
+        let mut refinement_ctx = RefinementContext::new(problem.clone(), population, environment.clone());
+
+        // NOTE use different construction heuristics to diversify starting points of restarts
+        let operators = create_default_init_operators(problem, environment);
+        let solution = operators[number % operators.len()].0.create(&refinement_ctx);
+        refinement_ctx.population.add(solution);
+
+        (refinement_ctx, heuristic)
+    })
 }
 
 /// Gets default heuristic.
@@ -93,6 +197,17 @@ pub fn get_static_heuristic(problem: Arc<Problem>, environment: Arc<Environment>
     get_static_heuristic_from_heuristic_group(heuristic_group)
 }
 
+/// Gets static heuristic which uses only ruin and recreate with occasional local search.
+pub fn get_fast_heuristic(problem: Arc<Problem>, environment: Arc<Environment>) -> TargetHeuristic {
+    let default_operator = statik::create_default_heuristic_operator(problem, environment.clone());
+    let local_search = statik::create_default_local_search(environment.clone());
+
+    get_static_heuristic_from_heuristic_group(vec![
+        (default_operator, create_scalar_operator_probability(1., environment.random.clone())),
+        (local_search, create_scalar_operator_probability(0.1, environment.random.clone())),
+    ])
+}
+
 /// Gets static heuristic using heuristic group.
 pub fn get_static_heuristic_from_heuristic_group(heuristic_group: TargetHeuristicGroup) -> TargetHeuristic {
     Box::new(StaticSelective::<RefinementContext, ProblemObjective, InsertionContext>::new(heuristic_group))
@@ -105,6 +220,46 @@ pub fn get_dynamic_heuristic(problem: Arc<Problem>, environment: Arc<Environment
     Box::new(DynamicSelective::<RefinementContext, ProblemObjective, InsertionContext>::new(operators, random))
 }
 
+/// Gets guided local search heuristic using default settings.
+pub fn get_guided_local_search_heuristic(problem: Arc<Problem>, environment: Arc<Environment>) -> TargetHeuristic {
+    get_guided_local_search_heuristic_with_params(problem, environment, 5, 0.3)
+}
+
+/// Gets guided local search heuristic with given amount of inner iterations and penalty factor.
+pub fn get_guided_local_search_heuristic_with_params(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    max_iterations: usize,
+    alpha: f64,
+) -> TargetHeuristic {
+    let default_operator = statik::create_default_heuristic_operator(problem, environment);
+    Box::new(GuidedLocalSearch::new(default_operator, max_iterations, alpha))
+}
+
+/// Gets adaptive large neighborhood search heuristic using default settings.
+pub fn get_alns_heuristic(problem: Arc<Problem>, environment: Arc<Environment>) -> TargetHeuristic {
+    get_alns_heuristic_with_params(problem, environment, 100, 0.1)
+}
+
+/// Gets adaptive large neighborhood search heuristic with given segment size and reaction factor.
+pub fn get_alns_heuristic_with_params(
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+    segment_size: usize,
+    reaction_factor: f64,
+) -> TargetHeuristic {
+    let recreates = dynamic::get_recreates(environment.random.clone());
+    let ruins = dynamic::get_ruins(problem, environment);
+
+    Box::new(AdaptiveLargeNeighborhoodSearch::new(
+        ruins,
+        recreates,
+        AlnsScores::default(),
+        segment_size,
+        reaction_factor,
+    ))
+}
+
 /// Creates elitism population algorithm.
 pub fn create_elitism_population(objective: Arc<ProblemObjective>, environment: Arc<Environment>) -> TargetPopulation {
     let selection_size = get_default_selection_size(environment.as_ref());
@@ -113,6 +268,10 @@ pub fn create_elitism_population(objective: Arc<ProblemObjective>, environment:
 
 impl RosomaxaWeighted for InsertionContext {
     fn weights(&self) -> Vec<f64> {
+        if let Some(weights) = self.get_compressed_weights() {
+            return weights.clone();
+        }
+
         vec![
             get_max_load_variance(self),
             get_duration_mean(self),
@@ -129,6 +288,15 @@ impl RosomaxaWeighted for InsertionContext {
     }
 }
 
+impl RosomaxaCompressible for InsertionContext {
+    fn compress(&mut self) {
+        if self.get_compressed_weights().is_none() {
+            let weights = self.weights();
+            self.compress_with_weights(weights);
+        }
+    }
+}
+
 impl DominanceOrdered for InsertionContext {
     fn get_order(&self) -> &DominanceOrder {
         self.solution.state.get(&SOLUTION_ORDER_KEY).and_then(|s| s.downcast_ref::<DominanceOrder>()).unwrap()
@@ -173,8 +341,14 @@ pub fn create_context_operator_probability(
 
 pub use self::builder::create_default_init_operators;
 pub use self::builder::create_default_processing;
+pub use self::builder::create_fast_processing;
+pub use self::builder::create_polishing_processing;
 pub use self::statik::create_default_heuristic_operator;
+pub use self::statik::create_default_local_search;
 pub use self::statik::create_default_random_ruin;
+pub use self::statik::create_fleet_minimization_operator;
+pub use self::statik::create_route_minimization_operator;
+pub use self::statik::create_sisr_operator;
 
 mod builder {
     use super::*;
@@ -206,12 +380,48 @@ mod builder {
     /// Create default processing.
     pub fn create_default_processing() -> ProcessingConfig<RefinementContext, ProblemObjective, InsertionContext> {
         ProcessingConfig {
-            context: vec![Box::new(VicinityClustering::default())],
+            context: vec![Box::new(VicinityClustering::default()), Box::new(WalkInCommute::default())],
             solution: vec![
+                Box::new(UnassignedReinsertion::default()),
                 Box::new(AdvanceDeparture::default()),
                 Box::new(UnassignmentReason::default()),
                 Box::new(VicinityClustering::default()),
+                Box::new(WalkInCommute::default()),
             ],
+            phase: vec![],
+        }
+    }
+
+    /// Creates default processing with exhaustive polishing of the final solution limited by time
+    /// budget in seconds.
+    pub fn create_polishing_processing(
+        max_time: f64,
+    ) -> ProcessingConfig<RefinementContext, ProblemObjective, InsertionContext> {
+        ProcessingConfig {
+            context: vec![Box::new(VicinityClustering::default()), Box::new(WalkInCommute::default())],
+            solution: vec![
+                Box::new(UnassignedReinsertion::default()),
+                Box::new(ExhaustivePolishing::new(max_time)),
+                Box::new(AdvanceDeparture::default()),
+                Box::new(UnassignmentReason::default()),
+                Box::new(VicinityClustering::default()),
+                Box::new(WalkInCommute::default()),
+            ],
+            phase: vec![],
+        }
+    }
+
+    /// Creates processing which skips optional solution improvement steps.
+    pub fn create_fast_processing() -> ProcessingConfig<RefinementContext, ProblemObjective, InsertionContext> {
+        ProcessingConfig {
+            context: vec![Box::new(VicinityClustering::default()), Box::new(WalkInCommute::default())],
+            solution: vec![
+                Box::new(UnassignedReinsertion::default()),
+                Box::new(UnassignmentReason::default()),
+                Box::new(VicinityClustering::default()),
+                Box::new(WalkInCommute::default()),
+            ],
+            phase: vec![],
         }
     }
 }
@@ -258,6 +468,7 @@ mod statik {
             (vec![(Arc::new(AdjustedStringRemoval::default()), 1.), (random_ruin.clone(), 0.1)], 100),
             (vec![(Arc::new(NeighbourRemoval::default()), 1.), (random_ruin.clone(), 0.1)], 10),
             (vec![(Arc::new(WorstJobRemoval::default()), 1.), (random_ruin.clone(), 0.1)], 10),
+            (vec![(Arc::new(ZoneRemoval::default()), 1.), (random_ruin.clone(), 0.1)], 5),
             (
                 vec![
                     (Arc::new(ClusterRemoval::new_with_defaults(problem, environment.clone())), 1.),
@@ -270,11 +481,52 @@ mod statik {
         ]));
 
         Arc::new(WeightedHeuristicOperator::new(
-            vec![Arc::new(RuinAndRecreate::new(ruin, recreate)), create_default_local_search(environment)],
-            vec![100, 10],
+            vec![
+                Arc::new(RuinAndRecreate::new(ruin, recreate)),
+                create_default_local_search(environment.clone()),
+                create_sisr_operator(environment.clone()),
+                create_fleet_minimization_operator(),
+                create_route_minimization_operator(),
+            ],
+            vec![100, 10, 10, 1, 1],
         ))
     }
 
+    /// Creates ruin and recreate operator which combines adjusted string removal with recreate with
+    /// blinks as described in SISR paper.
+    pub fn create_sisr_operator(environment: Arc<Environment>) -> TargetHeuristicOperator {
+        Arc::new(RuinAndRecreate::new(
+            create_sisr_ruin(),
+            Arc::new(RecreateWithBlinks::<SingleDimLoad>::new_with_defaults(environment.random.clone())),
+        ))
+    }
+
+    /// Creates SISR fleet minimization operator.
+    pub fn create_fleet_minimization_operator() -> TargetHeuristicOperator {
+        Arc::new(FleetMinimization::new(create_sisr_ruin(), 20))
+    }
+
+    /// Creates route minimization operator which uses ejection chains with a squeeze step.
+    pub fn create_route_minimization_operator() -> TargetHeuristicOperator {
+        Arc::new(RouteMinimization::new(
+            Arc::new(CompositeLocalOperator::new(
+                vec![
+                    (Arc::new(ExchangeInterRouteBest::default()), 100),
+                    (Arc::new(ExchangeSequence::default()), 100),
+                    (Arc::new(ExchangeTwoOpt::default()), 50),
+                ],
+                1,
+                2,
+            )),
+            100,
+            0.5,
+        ))
+    }
+
+    fn create_sisr_ruin() -> Arc<dyn Ruin + Send + Sync> {
+        Arc::new(CompositeRuin::new(vec![(Arc::new(AdjustedStringRemoval::default()), 1.)]))
+    }
+
     /// Creates default random ruin method.
     pub fn create_default_random_ruin() -> Arc<dyn Ruin + Send + Sync> {
         Arc::new(WeightedRuin::new(vec![
@@ -293,6 +545,7 @@ mod statik {
                 (Arc::new(ExchangeSwapStar::new(random)), 200),
                 (Arc::new(ExchangeInterRouteBest::default()), 100),
                 (Arc::new(ExchangeSequence::default()), 100),
+                (Arc::new(ExchangeTwoOpt::default()), 50),
                 (Arc::new(ExchangeInterRouteRandom::default()), 30),
                 (Arc::new(ExchangeIntraRouteRandom::default()), 30),
                 (Arc::new(RescheduleDeparture::default()), 20),
@@ -307,12 +560,8 @@ mod dynamic {
     use super::*;
     use crate::models::common::MultiDimLoad;
 
-    pub fn get_operators(
-        problem: Arc<Problem>,
-        environment: Arc<Environment>,
-    ) -> Vec<(TargetHeuristicOperator, String)> {
-        let random = environment.random.clone();
-        let recreates: Vec<(Arc<dyn Recreate + Send + Sync>, String)> = vec![
+    pub fn get_recreates(random: Arc<dyn Random + Send + Sync>) -> Vec<NamedRecreate> {
+        vec![
             (Arc::new(RecreateWithSkipBest::new(1, 2, random.clone())), "skip_best_1".to_string()),
             (Arc::new(RecreateWithSkipBest::new(1, 4, random.clone())), "skip_best_2".to_string()),
             (Arc::new(RecreateWithRegret::new(1, 3, random.clone())), "regret".to_string()),
@@ -336,13 +585,16 @@ mod dynamic {
                 )),
                 "skip_random".to_string(),
             ),
-            (Arc::new(RecreateWithSlice::new(random.clone())), "slice".to_string()),
-        ];
+            (Arc::new(RecreateWithSlice::new(random)), "slice".to_string()),
+        ]
+    }
 
+    pub fn get_ruins(problem: Arc<Problem>, environment: Arc<Environment>) -> Vec<NamedRuin> {
         let primary_ruins: Vec<(Arc<dyn Ruin + Send + Sync>, String)> = vec![
             (Arc::new(AdjustedStringRemoval::default()), "asr".to_string()),
             (Arc::new(NeighbourRemoval::default()), "neighbour_removal".to_string()),
             (Arc::new(WorstJobRemoval::default()), "worst_job".to_string()),
+            (Arc::new(ZoneRemoval::default()), "zone_removal".to_string()),
             (
                 Arc::new(ClusterRemoval::new_with_defaults(problem.clone(), environment.clone())),
                 "cluster_removal".to_string(),
@@ -356,7 +608,7 @@ mod dynamic {
         ];
 
         // NOTE we need to wrap any of ruin methods in composite which calls restore context before recreate
-        let ruins = primary_ruins
+        primary_ruins
             .iter()
             .flat_map(|(outer_ruin, outer_name)| {
                 secondary_ruins.iter().map(move |(inner_ruin, inner_name)| {
@@ -369,7 +621,16 @@ mod dynamic {
             .chain(primary_ruins.iter().chain(secondary_ruins.iter()).map::<(Arc<dyn Ruin + Send + Sync>, String), _>(
                 |(ruin, name)| (Arc::new(CompositeRuin::new(vec![(ruin.clone(), 1.)])), name.clone()),
             ))
-            .collect::<Vec<_>>();
+            .collect::<Vec<_>>()
+    }
+
+    pub fn get_operators(
+        problem: Arc<Problem>,
+        environment: Arc<Environment>,
+    ) -> Vec<(TargetHeuristicOperator, String)> {
+        let random = environment.random.clone();
+        let recreates = get_recreates(random.clone());
+        let ruins = get_ruins(problem.clone(), environment.clone());
 
         let inner_search = statik::create_default_heuristic_operator(problem, environment);
 
@@ -387,6 +648,7 @@ mod dynamic {
                 "local_exch_intra_route_random".to_string(),
             ),
             (Arc::new(LocalSearch::new(Arc::new(ExchangeSequence::default()))), "local_exch_sequence".to_string()),
+            (Arc::new(LocalSearch::new(Arc::new(ExchangeTwoOpt::default()))), "local_exch_two_opt".to_string()),
             (
                 Arc::new(LocalSearch::new(Arc::new(RescheduleDeparture::default()))),
                 "local_reschedule_departure".to_string(),
@@ -400,6 +662,9 @@ mod dynamic {
                 Arc::new(LocalSearch::new(Arc::new(ExchangeSwapStar::new(random.clone())))),
                 "local_swap_star".to_string(),
             ),
+            (statik::create_fleet_minimization_operator(), "fleet_minimization".to_string()),
+            (statik::create_route_minimization_operator(), "route_minimization".to_string()),
+            (Arc::new(PathRelinking::default()), "path_relinking".to_string()),
         ];
 
         recreates
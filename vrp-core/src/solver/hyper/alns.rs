@@ -0,0 +1,240 @@
+//! Contains an implementation of adaptive large neighborhood search (ALNS) idea.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/hyper/alns_test.rs"]
+mod alns_test;
+
+use crate::construction::heuristics::{finalize_insertion_ctx, InsertionContext};
+use crate::models::problem::ProblemObjective;
+use crate::solver::search::{Recreate, Ruin};
+use crate::solver::RefinementContext;
+use rosomaxa::prelude::*;
+use rosomaxa::utils::parallel_into_collect;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A named ruin method.
+pub type NamedRuin = (Arc<dyn Ruin + Send + Sync>, String);
+/// A named recreate method.
+pub type NamedRecreate = (Arc<dyn Recreate + Send + Sync>, String);
+
+/// Specifies scores given to operators depending on the search outcome.
+#[derive(Clone)]
+pub struct AlnsScores {
+    /// A score when a new best known solution is found.
+    pub best: f64,
+    /// A score when a solution better than the original one is found.
+    pub improvement: f64,
+    /// A score when a solution is not worse than the original one.
+    pub acceptance: f64,
+}
+
+impl Default for AlnsScores {
+    fn default() -> Self {
+        Self { best: 33., improvement: 9., acceptance: 1. }
+    }
+}
+
+/// Keeps weights of operators and collects their scores over a segment of iterations.
+pub struct SegmentWeights {
+    weights: Vec<f64>,
+    scores: Vec<f64>,
+    usages: Vec<usize>,
+}
+
+impl SegmentWeights {
+    /// Creates a new instance of `SegmentWeights` with equal weights for given amount of operators.
+    pub fn new(size: usize) -> Self {
+        Self { weights: vec![1.; size], scores: vec![0.; size], usages: vec![0; size] }
+    }
+
+    /// Returns current weights.
+    pub fn weights(&self) -> &[f64] {
+        self.weights.as_slice()
+    }
+
+    /// Selects operator index with probability proportional to its weight (roulette wheel).
+    pub fn select(&self, random: &(dyn Random + Send + Sync)) -> usize {
+        let total = self.weights.iter().sum::<f64>();
+        let mut value = random.uniform_real(0., total);
+
+        self.weights
+            .iter()
+            .position(|weight| {
+                value -= *weight;
+                value < 0.
+            })
+            .unwrap_or(self.weights.len() - 1)
+    }
+
+    /// Adds score of the operator within the current segment.
+    pub fn add_score(&mut self, index: usize, score: f64) {
+        self.scores[index] += score;
+        self.usages[index] += 1;
+    }
+
+    /// Updates weights of operators used within the current segment using `reaction_factor` which
+    /// controls how quickly weights react to the operator performance. Starts a new segment.
+    pub fn update(&mut self, reaction_factor: f64, min_weight: f64) {
+        self.weights
+            .iter_mut()
+            .zip(self.scores.iter().zip(self.usages.iter()))
+            .filter(|(_, (_, usages))| **usages > 0)
+            .for_each(|(weight, (score, usages))| {
+                let average = score / *usages as f64;
+                *weight = ((1. - reaction_factor) * *weight + reaction_factor * average).max(min_weight);
+            });
+
+        self.scores.iter_mut().for_each(|score| *score = 0.);
+        self.usages.iter_mut().for_each(|usages| *usages = 0);
+    }
+}
+
+/// A hyper-heuristic which implements adaptive large neighborhood search (ALNS) idea: ruin and
+/// recreate methods are selected independently with probability proportional to their weights.
+/// Each method is scored by the search outcome and, at the end of a segment of iterations, weights
+/// are adapted to the average scores, so more successful methods are selected more often.
+pub struct AdaptiveLargeNeighborhoodSearch {
+    ruins: Vec<NamedRuin>,
+    recreates: Vec<NamedRecreate>,
+    ruin_weights: SegmentWeights,
+    recreate_weights: SegmentWeights,
+    scores: AlnsScores,
+    segment_size: usize,
+    reaction_factor: f64,
+    iterations: usize,
+    last_operators: Vec<String>,
+}
+
+impl AdaptiveLargeNeighborhoodSearch {
+    /// Creates a new instance of `AdaptiveLargeNeighborhoodSearch`. `segment_size` specifies amount
+    /// of operator applications after which weights are updated, `reaction_factor` is in range `[0, 1]`
+    /// and controls how quickly weights follow the recent performance.
+    pub fn new(
+        ruins: Vec<NamedRuin>,
+        recreates: Vec<NamedRecreate>,
+        scores: AlnsScores,
+        segment_size: usize,
+        reaction_factor: f64,
+    ) -> Self {
+        assert!(!ruins.is_empty() && !recreates.is_empty());
+
+        let ruin_weights = SegmentWeights::new(ruins.len());
+        let recreate_weights = SegmentWeights::new(recreates.len());
+
+        Self {
+            ruins,
+            recreates,
+            ruin_weights,
+            recreate_weights,
+            scores,
+            segment_size: segment_size.max(1),
+            reaction_factor: reaction_factor.clamp(0., 1.),
+            iterations: 0,
+            last_operators: Vec::default(),
+        }
+    }
+
+    fn ruin_and_recreate(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> (InsertionContext, usize, usize, f64) {
+        let random = insertion_ctx.environment.random.as_ref();
+        let ruin_idx = self.ruin_weights.select(random);
+        let recreate_idx = self.recreate_weights.select(random);
+
+        let ruin = self.ruins[ruin_idx].0.as_ref();
+        let recreate = self.recreates[recreate_idx].0.as_ref();
+
+        let mut new_insertion_ctx = recreate.run(refinement_ctx, ruin.run(refinement_ctx, insertion_ctx.deep_copy()));
+        finalize_insertion_ctx(&mut new_insertion_ctx);
+
+        let score = self.get_score(refinement_ctx, insertion_ctx, &new_insertion_ctx);
+
+        (new_insertion_ctx, ruin_idx, recreate_idx, score)
+    }
+
+    fn get_score(
+        &self,
+        refinement_ctx: &RefinementContext,
+        original: &InsertionContext,
+        candidate: &InsertionContext,
+    ) -> f64 {
+        let objective = refinement_ctx.problem.objective.as_ref();
+        let is_new_best = refinement_ctx
+            .population()
+            .ranked()
+            .next()
+            .map_or(true, |(best, _)| objective.total_order(candidate, best) == Ordering::Less);
+
+        if is_new_best {
+            return self.scores.best;
+        }
+
+        match objective.total_order(candidate, original) {
+            Ordering::Less => self.scores.improvement,
+            Ordering::Equal => self.scores.acceptance,
+            Ordering::Greater => 0.,
+        }
+    }
+}
+
+impl HyperHeuristic for AdaptiveLargeNeighborhoodSearch {
+    type Context = RefinementContext;
+    type Objective = ProblemObjective;
+    type Solution = InsertionContext;
+
+    fn search(&mut self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        let this = &*self;
+        let results = parallel_into_collect(solutions.into_iter().enumerate().collect(), |(idx, solution)| {
+            heuristic_ctx
+                .environment
+                .parallelism
+                .thread_pool_execute(idx, || this.ruin_and_recreate(heuristic_ctx, solution))
+        });
+
+        self.last_operators.clear();
+
+        let solutions = results
+            .into_iter()
+            .map(|(solution, ruin_idx, recreate_idx, score)| {
+                self.last_operators.push(format!("{}+{}", self.ruins[ruin_idx].1, self.recreates[recreate_idx].1));
+                self.ruin_weights.add_score(ruin_idx, score);
+                self.recreate_weights.add_score(recreate_idx, score);
+                self.iterations += 1;
+
+                solution
+            })
+            .collect();
+
+        if self.iterations >= self.segment_size {
+            // NOTE keep weights positive, so each operator has a chance to be selected
+            let min_weight = 0.01;
+            self.ruin_weights.update(self.reaction_factor, min_weight);
+            self.recreate_weights.update(self.reaction_factor, min_weight);
+            self.iterations = 0;
+        }
+
+        solutions
+    }
+
+    fn get_operator_weights(&self) -> Option<Vec<(String, f64)>> {
+        let ruins = self
+            .ruins
+            .iter()
+            .zip(self.ruin_weights.weights().iter())
+            .map(|((_, name), weight)| (format!("ruin:{}", name), *weight));
+        let recreates = self
+            .recreates
+            .iter()
+            .zip(self.recreate_weights.weights().iter())
+            .map(|((_, name), weight)| (format!("recreate:{}", name), *weight));
+
+        Some(ruins.chain(recreates).collect())
+    }
+
+    fn get_last_operators(&self) -> Option<Vec<String>> {
+        Some(self.last_operators.clone())
+    }
+}
@@ -0,0 +1,4 @@
+//! Contains domain specific hyper-heuristics.
+
+mod alns;
+pub use self::alns::*;
@@ -94,25 +94,37 @@ extern crate rand;
 
 use crate::construction::heuristics::InsertionContext;
 use crate::models::common::Cost;
-use crate::models::problem::ProblemObjective;
+use crate::models::problem::{Job, ProblemObjective};
 use crate::models::{Problem, Solution};
-use crate::solver::search::Recreate;
-use hashbrown::HashMap;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use hashbrown::{HashMap, HashSet};
 use rosomaxa::evolution::*;
 use rosomaxa::get_default_population;
 use rosomaxa::prelude::*;
+use rosomaxa::utils::ThreadPool;
 use std::any::Any;
-use std::sync::Arc;
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Arc, Mutex};
 
 pub use self::heuristic::*;
 use rosomaxa::population::Rosomaxa;
 
+pub mod hyper;
 pub mod objectives;
 pub mod processing;
 pub mod search;
 
 mod heuristic;
 
+mod checkpoint;
+use self::checkpoint::*;
+
+mod progress;
+pub use self::progress::*;
+
 /// A key to store solution order information.
 const SOLUTION_ORDER_KEY: i32 = 1;
 
@@ -121,6 +133,10 @@ const BALANCE_MAX_LOAD_KEY: i32 = 20;
 const BALANCE_ACTIVITY_KEY: i32 = 21;
 const BALANCE_DISTANCE_KEY: i32 = 22;
 const BALANCE_DURATION_KEY: i32 = 23;
+const TARGET_STOPS_KEY: i32 = 24;
+
+/// Max amount of local search iterations used to repair solution after new jobs insertion.
+const INSERT_JOBS_REPAIR_ITERATIONS: usize = 100;
 
 /// A type which encapsulates information needed to perform solution refinement process.
 pub struct RefinementContext {
@@ -223,7 +239,8 @@ impl InitialOperator for RecreateInitialOperator {
     type Solution = InsertionContext;
 
     fn create(&self, heuristic_ctx: &Self::Context) -> Self::Solution {
-        let insertion_ctx = InsertionContext::new(heuristic_ctx.problem.clone(), heuristic_ctx.environment.clone());
+        let insertion_ctx =
+            InsertionContext::new_with_hints(heuristic_ctx.problem.clone(), heuristic_ctx.environment.clone());
         self.recreate.run(heuristic_ctx, insertion_ctx)
     }
 }
@@ -232,7 +249,8 @@ impl InitialOperator for RecreateInitialOperator {
 /// or error description, if solution cannot be found.
 ///
 /// A newly created builder instance is pre-configured with some reasonable defaults for mid-size
-/// problems (~200), so there is no need to call any of its methods.
+/// problems (~200), so there is no need to call any of its methods. Alternatively, a builder can be
+/// created from one of the presets for common scenarios using `create_preset_config_builder`.
 ///
 ///
 /// # Examples
@@ -277,17 +295,242 @@ impl Solver {
     }
 
     /// Solves a Vehicle Routing Problem and returns a _(solution, its cost)_ pair in case of success
-    /// or error description, if solution cannot be found.
-    pub fn solve(self) -> Result<(Solution, Cost, Option<TelemetryMetrics>), String> {
+    /// or error description, if solution cannot be found. In strict mode (see `Environment::is_strict`),
+    /// any internal inconsistency is returned as an error which contains the seed and the generation
+    /// needed to reproduce the issue. When random generator is seeded, the search runs on a single
+    /// thread, so random values are drawn in the same order on each run.
+    pub fn solve(mut self) -> SolverResult {
+        let environment = self.config.context.environment.clone();
+        if !environment.is_strict {
+            return self.run();
+        }
+
+        let violation = environment.strict_violation.clone();
+        violation.take();
+
+        // NOTE generation is not advanced after violation is reported, so it points to the failed one
+        let generation = Arc::new(AtomicUsize::new(0));
+        self.config.telemetry.add_generation_callback(Box::new({
+            let generation = generation.clone();
+            let flag = violation.flag();
+            move |_, statistics, _| {
+                if !flag.load(AtomicOrdering::Relaxed) {
+                    generation.store(statistics.generation + 1, AtomicOrdering::Relaxed)
+                }
+            }
+        }));
+
+        let inner = self.config.termination;
+        self.config.termination = Box::new(StrictTermination { inner, flag: violation.flag() });
+
+        let result = self.run();
+
+        match violation.take() {
+            Some(message) => Err(format!(
+                "strict mode violation: {}, seed: {}, generation: {}",
+                message,
+                environment.random.get_seed().map_or("none".to_string(), |seed| seed.to_string()),
+                generation.load(AtomicOrdering::Relaxed)
+            )),
+            None => result,
+        }
+    }
+
+    fn run(self) -> SolverResult {
+        // NOTE seeded run is executed on a single thread, so random values are drawn and results of
+        // parallel operations are reduced in the same order each time
+        if self.config.context.environment.random.get_seed().is_some() {
+            return ThreadPool::new(1).execute(move || self.evolve());
+        }
+
+        self.evolve()
+    }
+
+    fn evolve(self) -> SolverResult {
         let (mut solutions, metrics) = EvolutionSimulator::new(self.config)?.run()?;
 
-        // NOTE select the first best individual from population
-        let insertion_ctx = if solutions.is_empty() { None } else { solutions.drain(0..1).next() }
-            .ok_or_else(|| "cannot find any solution".to_string())?;
+        // NOTE select the best individual from population, tie-breakers are applied only here
+        let objective = self.problem.objective.as_ref();
+        let best_idx = solutions.first().map(|best| {
+            solutions
+                .iter()
+                .enumerate()
+                .take_while(|(_, other)| objective.total_order(best, other) == Ordering::Equal)
+                .min_by(|(_, a), (_, b)| objective.tie_break(a, b))
+                .map_or(0, |(idx, _)| idx)
+        });
+        let insertion_ctx =
+            best_idx.map(|idx| solutions.swap_remove(idx)).ok_or_else(|| "cannot find any solution".to_string())?;
 
         let solution = insertion_ctx.solution.to_solution(self.problem.extras.clone());
         let cost = self.problem.objective.fitness(&insertion_ctx);
 
         Ok((solution, cost, metrics))
     }
+
+    /// Runs the solver on a dedicated thread and returns a future of its result within a receiver
+    /// of progress events which are sent on each generation. This allows to surface live progress
+    /// without polling, e.g. when the solver is embedded into a web service.
+    pub fn solve_async(mut self) -> (SolverFuture, Receiver<SolverProgress>) {
+        let (sender, receiver) = channel();
+        let sender = Mutex::new(sender);
+        let problem = self.problem.clone();
+
+        self.config.telemetry.add_generation_callback(Box::new(
+            move |refinement_ctx: &RefinementContext, statistics, is_improved| {
+                let progress = refinement_ctx.population.ranked().next().map(|(insertion_ctx, _)| SolverProgress {
+                    generation: statistics.generation,
+                    elapsed: statistics.time.elapsed_secs_as_f64(),
+                    termination_estimate: statistics.termination_estimate,
+                    is_improvement: is_improved,
+                    cost: problem.objective.fitness(insertion_ctx),
+                    solution: if is_improved {
+                        Some(insertion_ctx.solution.to_solution(problem.extras.clone()))
+                    } else {
+                        None
+                    },
+                });
+
+                // NOTE ignore error which happens when receiver is dropped
+                if let Some(progress) = progress {
+                    let _ = sender.lock().unwrap().send(progress);
+                }
+            },
+        ));
+
+        (SolverFuture::spawn(move || self.solve()), receiver)
+    }
+
+    /// Saves evolution state (elite solutions, hyper heuristic and population state, random seed and
+    /// its state) into the file at given path every `interval` generations and when search is finished,
+    /// so a long running search can be resumed later using `restore_state`.
+    pub fn save_state(mut self, path: PathBuf, interval: usize) -> Self {
+        self.config.telemetry.set_checkpoint_callback(
+            interval,
+            Box::new(move |refinement_ctx: &RefinementContext, heuristic_state| {
+                if let Err(err) = write_checkpoint(path.as_path(), refinement_ctx, heuristic_state) {
+                    (refinement_ctx.environment.logger)(&err);
+                }
+            }),
+        );
+
+        self
+    }
+
+    /// Restores evolution state from the file created by `save_state`. Saved elite solutions are
+    /// used as initial individuals. The problem definition is expected to be the same as the one
+    /// used to save the state. Random generator state is restored only when the seed is the same.
+    pub fn restore_state(mut self, path: &Path) -> Result<Self, String> {
+        let environment = self.config.context.environment.clone();
+        let checkpoint = read_checkpoint(path, self.problem.clone(), environment.clone())?;
+
+        if checkpoint.seed != environment.random.get_seed() {
+            (environment.logger)(&format!(
+                "checkpoint was created with different seed: {:?}, search is not reproducible",
+                checkpoint.seed
+            ));
+        } else if let Some(state) = checkpoint.random {
+            environment.random.restore_state(state);
+        }
+
+        if let Some(state) = checkpoint.heuristic.as_ref() {
+            self.config.heuristic.restore_state(state)?;
+        }
+
+        if let Some(state) = checkpoint.population.as_ref() {
+            self.config.context.population.restore_state(state)?;
+        }
+
+        (environment.logger)(&format!(
+            "resuming search from generation {} with {} solution(-s)",
+            checkpoint.generation,
+            checkpoint.solutions.len()
+        ));
+
+        let initial = &mut self.config.initial;
+        initial.max_size = initial.max_size.max(checkpoint.solutions.len());
+        initial.individuals = checkpoint.solutions.into_iter().chain(initial.individuals.drain(0..)).collect();
+
+        Ok(self)
+    }
+
+    /// Inserts newly arrived jobs into existing solution without solving the problem from scratch
+    /// and returns a _(updated solution, marginal cost)_ pair in case of success. New jobs are
+    /// inserted using cheapest insertion heuristic, then the solution is repaired by local search
+    /// operators until iteration limit or environment's quota is reached.
+    ///
+    /// NOTE new jobs are not added to problem's jobs collection, so only operators which do not
+    /// rely on jobs neighbourhood are used here.
+    pub fn insert_jobs(self, solution: &Solution, jobs: Vec<Job>) -> Result<(Solution, Cost), String> {
+        let existing = solution
+            .routes
+            .iter()
+            .flat_map(|route| route.tour.jobs())
+            .chain(solution.unassigned.iter().map(|(job, _)| job.clone()))
+            .collect::<HashSet<_>>();
+
+        if jobs.iter().any(|job| existing.contains(job)) {
+            return Err("cannot insert job which is already present in the solution".to_string());
+        }
+
+        let problem = self.problem;
+        let refinement_ctx = self.config.context;
+        let environment = refinement_ctx.environment.clone();
+
+        let solution = Solution {
+            registry: solution.registry.deep_copy(),
+            routes: solution.routes.iter().map(|route| route.deep_copy()).collect(),
+            unassigned: solution.unassigned.clone(),
+            extras: solution.extras.clone(),
+        };
+        let original_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+        let original_cost = problem.objective.fitness(&original_ctx);
+
+        let mut insertion_ctx = original_ctx.deep_copy();
+        insertion_ctx.solution.required.extend(jobs);
+        let insertion_ctx = RecreateWithCheapest::new(environment.random.clone()).run(&refinement_ctx, insertion_ctx);
+
+        let local_search = create_default_local_search(environment.clone());
+        let insertion_ctx = (0..INSERT_JOBS_REPAIR_ITERATIONS)
+            .take_while(|_| !environment.quota.as_ref().map_or(false, |quota| quota.is_reached()))
+            .fold(insertion_ctx, |best_ctx, _| {
+                let new_ctx = local_search.search(&refinement_ctx, &best_ctx);
+                match problem.objective.total_order(&new_ctx, &best_ctx) {
+                    Ordering::Less => new_ctx,
+                    _ => best_ctx,
+                }
+            });
+
+        let solution = insertion_ctx.solution.to_solution(problem.extras.clone());
+        let cost = problem.objective.fitness(&insertion_ctx) - original_cost;
+
+        Ok((solution, cost))
+    }
+}
+
+/// A termination which additionally stops the search as soon as strict mode violation is reported.
+struct StrictTermination {
+    inner: Box<dyn Termination<Context = RefinementContext, Objective = ProblemObjective>>,
+    flag: Arc<AtomicBool>,
 }
+
+impl Termination for StrictTermination {
+    type Context = RefinementContext;
+    type Objective = ProblemObjective;
+
+    fn is_termination(&self, heuristic_ctx: &mut Self::Context) -> bool {
+        self.flag.load(AtomicOrdering::Relaxed) || self.inner.is_termination(heuristic_ctx)
+    }
+
+    fn estimate(&self, heuristic_ctx: &Self::Context) -> f64 {
+        if self.flag.load(AtomicOrdering::Relaxed) {
+            1.
+        } else {
+            self.inner.estimate(heuristic_ctx)
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/unit/solver/solver_test.rs"]
+mod solver_test;
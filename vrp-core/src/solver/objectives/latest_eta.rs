@@ -0,0 +1,194 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/latest_eta_test.rs"]
+mod latest_eta_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::Timestamp;
+use crate::models::problem::*;
+use crate::models::solution::{Activity, Route};
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies a function which returns true if job activity should be completed as early as possible.
+pub type PriorityFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+
+/// Allows to serve prioritized jobs as early as possible: the objective minimizes the latest
+/// completion time (departure) among all prioritized job activities in the solution.
+pub struct LatestEta {}
+
+impl LatestEta {
+    /// Creates _(constraint, objective)_ type pair which minimizes the latest completion time of
+    /// prioritized jobs.
+    pub fn new_minimized(
+        priority_fn: PriorityFn,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        state_key: i32,
+        code: i32,
+    ) -> (TargetConstraint, TargetObjective) {
+        let constraint = LatestEtaConstraint {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(LatestEtaSoftActivityConstraint {
+                priority_fn: priority_fn.clone(),
+                transport,
+                state_key,
+            }))],
+            keys: vec![state_key],
+            priority_fn: priority_fn.clone(),
+            code,
+        };
+
+        let objective = LatestEtaObjective { priority_fn, state_key };
+
+        (Arc::new(constraint), Arc::new(objective))
+    }
+}
+
+struct LatestEtaConstraint {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    priority_fn: PriorityFn,
+    code: i32,
+}
+
+impl ConstraintModule for LatestEtaConstraint {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        if let Some(state_key) = self.keys.first() {
+            let latest = get_route_latest_eta(ctx.route.tour.all_activities(), &self.priority_fn);
+            ctx.state_mut().put_route_state(*state_key, latest);
+        }
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        if let Some(state_key) = self.keys.first() {
+            let latest = get_latest_eta(ctx.routes.as_slice(), &self.priority_fn);
+            ctx.state.insert(*state_key, Arc::new(latest));
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        let is_prioritized = |job: &Job| job.as_single().map_or(false, |single| (self.priority_fn)(single.as_ref()));
+
+        if is_prioritized(&source) == is_prioritized(&candidate) {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct LatestEtaSoftActivityConstraint {
+    priority_fn: PriorityFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    state_key: i32,
+}
+
+impl SoftActivityConstraint for LatestEtaSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let route = route_ctx.route.as_ref();
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let latest = route_ctx.state.get_route_state::<Option<Timestamp>>(self.state_key).cloned().flatten();
+        let is_target_prioritized = target.job.as_ref().map_or(false, |single| (self.priority_fn)(single.as_ref()));
+
+        if latest.is_none() && !is_target_prioritized {
+            return 0.;
+        }
+
+        let departure = get_departure(route, self.transport.as_ref(), prev, prev.schedule.departure, target);
+
+        // NOTE insertion delays the rest of the tour: the delay is estimated on the next activity
+        // and applied to the latest prioritized activity if it is scheduled after insertion point
+        let shifted_latest = latest.map(|latest| match activity_ctx.next {
+            Some(next) if compare_floats(prev.schedule.departure, latest) == Ordering::Less => {
+                let next_departure = get_departure(route, self.transport.as_ref(), target, departure, next);
+                latest + (next_departure - next.schedule.departure).max(0.)
+            }
+            _ => latest,
+        });
+
+        let new_latest = match (shifted_latest, is_target_prioritized) {
+            (Some(shifted_latest), true) => shifted_latest.max(departure),
+            (Some(shifted_latest), false) => shifted_latest,
+            (None, _) => departure,
+        };
+        let old_latest = latest.unwrap_or_else(|| route.tour.start().map_or(0., |start| start.schedule.departure));
+
+        // NOTE use route cost based penalty as the objective is expected to be more important than cost
+        let max_cost = route_ctx.get_route_cost();
+        let penalty = if compare_floats(max_cost, 0.) == Ordering::Equal {
+            let actor = route.actor.as_ref();
+            actor.vehicle.costs.per_driving_time + actor.driver.costs.per_driving_time
+        } else {
+            max_cost * 2.
+        };
+
+        (new_latest - old_latest).max(0.) * penalty
+    }
+}
+
+struct LatestEtaObjective {
+    priority_fn: PriorityFn,
+    state_key: i32,
+}
+
+impl Objective for LatestEtaObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let solution = &solution.solution;
+
+        solution
+            .state
+            .get(&self.state_key)
+            .and_then(|s| s.downcast_ref::<Timestamp>())
+            .cloned()
+            .unwrap_or_else(|| get_latest_eta(solution.routes.as_slice(), &self.priority_fn))
+    }
+}
+
+/// Returns departure time from target activity when it is visited right after given activity.
+fn get_departure(
+    route: &Route,
+    transport: &(dyn TransportCost + Send + Sync),
+    activity: &Activity,
+    departure: Timestamp,
+    target: &Activity,
+) -> Timestamp {
+    let arrival = departure
+        + transport.duration(route, activity.place.location, target.place.location, TravelTime::Departure(departure));
+
+    arrival.max(target.place.time.start) + target.place.duration
+}
+
+fn get_route_latest_eta<'a>(
+    activities: impl Iterator<Item = &'a Activity>,
+    priority_fn: &PriorityFn,
+) -> Option<Timestamp> {
+    activities
+        .filter(|activity| activity.job.as_ref().map_or(false, |single| priority_fn(single.as_ref())))
+        .map(|activity| activity.schedule.departure)
+        .max_by(|a, b| compare_floats(*a, *b))
+}
+
+fn get_latest_eta(routes: &[RouteContext], priority_fn: &PriorityFn) -> Timestamp {
+    routes
+        .iter()
+        .filter_map(|route_ctx| get_route_latest_eta(route_ctx.route.tour.all_activities(), priority_fn))
+        .max_by(|a, b| compare_floats(*a, *b))
+        .unwrap_or(0.)
+}
@@ -6,6 +6,12 @@ use std::cmp::Ordering;
 mod generic_value;
 pub use self::generic_value::*;
 
+mod latest_eta;
+pub use self::latest_eta::*;
+
+mod schedule_buffer;
+pub use self::schedule_buffer::*;
+
 mod total_routes;
 pub use self::total_routes::TotalRoutes;
 
@@ -0,0 +1,221 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/schedule_buffer_test.rs"]
+mod schedule_buffer_test;
+
+use crate::construction::constraints::*;
+use crate::construction::heuristics::*;
+use crate::models::common::{Duration, Timestamp};
+use crate::models::problem::*;
+use crate::models::solution::Activity;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+
+/// Specifies a function which returns uncertainty buffer of the job: an extra duration which is
+/// not counted as serving time, but should be kept as a slack in the schedule after job activity.
+pub type BufferFn = Arc<dyn Fn(&Single) -> Option<Duration> + Send + Sync>;
+
+/// Allows to keep uncertainty buffers after job activities: a buffer is respected when job activity
+/// can be delayed by buffer duration without violating time windows of the rest of the tour.
+/// Buffers are soft: violations are counted by the objective.
+pub struct ScheduleBuffer {}
+
+impl ScheduleBuffer {
+    /// Creates _(constraint, objective)_ type pair which maximizes amount of respected buffers.
+    pub fn new_maximized(
+        buffer_fn: BufferFn,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        state_key: i32,
+        code: i32,
+    ) -> (TargetConstraint, TargetObjective) {
+        let constraint = ScheduleBufferConstraint {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(ScheduleBufferSoftActivityConstraint {
+                buffer_fn: buffer_fn.clone(),
+                transport: transport.clone(),
+            }))],
+            keys: vec![state_key],
+            buffer_fn: buffer_fn.clone(),
+            transport: transport.clone(),
+            code,
+        };
+
+        let objective = ScheduleBufferObjective { buffer_fn, transport, state_key };
+
+        (Arc::new(constraint), Arc::new(objective))
+    }
+}
+
+struct ScheduleBufferConstraint {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    buffer_fn: BufferFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    code: i32,
+}
+
+impl ConstraintModule for ScheduleBufferConstraint {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        if let Some(state_key) = self.keys.first() {
+            let violations = get_violations(ctx.routes.as_slice(), &self.buffer_fn, self.transport.as_ref());
+            ctx.state.insert(*state_key, Arc::new(violations));
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        let get_buffer = |job: &Job| job.as_single().and_then(|single| (self.buffer_fn)(single.as_ref()));
+
+        match (get_buffer(&source), get_buffer(&candidate)) {
+            (_, None) => Ok(source),
+            (Some(source_buffer), Some(candidate_buffer))
+                if compare_floats(source_buffer, candidate_buffer) == Ordering::Equal =>
+            {
+                Ok(source)
+            }
+            _ => Err(self.code),
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct ScheduleBufferSoftActivityConstraint {
+    buffer_fn: BufferFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SoftActivityConstraint for ScheduleBufferSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let route = route_ctx.route.as_ref();
+        let prev = activity_ctx.prev;
+        let target = activity_ctx.target;
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+        let departure = arrival.max(target.place.time.start) + target.place.duration;
+
+        let target_slack = activity_ctx
+            .next
+            .map(|next| get_slack(route_ctx, self.transport.as_ref(), target, departure, next))
+            .unwrap_or(Duration::MAX);
+
+        // NOTE insertion affects buffer of previous activity too: it can be delayed only while
+        // target's time window allows it and waiting time is not consumed
+        let prev_slack =
+            (target.place.time.end - arrival).min((target.place.time.start - arrival).max(0.) + target_slack);
+        let prev_old_slack = activity_ctx
+            .next
+            .map(|next| get_slack(route_ctx, self.transport.as_ref(), prev, prev.schedule.departure, next))
+            .unwrap_or(Duration::MAX);
+
+        let missing = get_missing(self.get_buffer(target), target_slack)
+            + get_missing(self.get_buffer(prev), prev_slack)
+            - get_missing(self.get_buffer(prev), prev_old_slack);
+
+        let actor = route.actor.as_ref();
+        missing * (actor.vehicle.costs.per_driving_time + actor.driver.costs.per_driving_time)
+    }
+}
+
+impl ScheduleBufferSoftActivityConstraint {
+    fn get_buffer(&self, activity: &Activity) -> Option<Duration> {
+        activity.job.as_ref().and_then(|single| (self.buffer_fn)(single.as_ref()))
+    }
+}
+
+struct ScheduleBufferObjective {
+    buffer_fn: BufferFn,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+    state_key: i32,
+}
+
+impl Objective for ScheduleBufferObjective {
+    type Solution = InsertionContext;
+
+    fn fitness(&self, solution: &Self::Solution) -> f64 {
+        let solution = &solution.solution;
+
+        solution
+            .state
+            .get(&self.state_key)
+            .and_then(|s| s.downcast_ref::<usize>())
+            .cloned()
+            .unwrap_or_else(|| get_violations(solution.routes.as_slice(), &self.buffer_fn, self.transport.as_ref()))
+            as f64
+    }
+}
+
+/// Returns a time which can be spent after departure from given activity without violating
+/// time windows of the rest of the tour.
+fn get_slack(
+    route_ctx: &RouteContext,
+    transport: &(dyn TransportCost + Send + Sync),
+    activity: &Activity,
+    departure: Timestamp,
+    next: &Activity,
+) -> Duration {
+    let latest_arrival = route_ctx
+        .state
+        .get_activity_state::<Timestamp>(LATEST_ARRIVAL_KEY, next)
+        .cloned()
+        .unwrap_or(next.place.time.end);
+
+    let arrival = departure
+        + transport.duration(
+            route_ctx.route.as_ref(),
+            activity.place.location,
+            next.place.location,
+            TravelTime::Departure(departure),
+        );
+
+    latest_arrival - arrival
+}
+
+fn get_missing(buffer: Option<Duration>, slack: Duration) -> Duration {
+    buffer.map_or(0., |buffer| (buffer - slack).max(0.))
+}
+
+fn get_violations(
+    routes: &[RouteContext],
+    buffer_fn: &BufferFn,
+    transport: &(dyn TransportCost + Send + Sync),
+) -> usize {
+    routes
+        .iter()
+        .map(|route_ctx| {
+            let route = route_ctx.route.as_ref();
+
+            route
+                .tour
+                .all_activities()
+                .zip(route.tour.all_activities().skip(1))
+                .filter_map(|(activity, next)| {
+                    activity
+                        .job
+                        .as_ref()
+                        .and_then(|single| buffer_fn(single.as_ref()))
+                        .map(|buffer| (activity, next, buffer))
+                })
+                .filter(|(activity, next, buffer)| {
+                    let slack = get_slack(route_ctx, transport, activity, activity.schedule.departure, next);
+                    compare_floats(slack, *buffer) == Ordering::Less
+                })
+                .count()
+        })
+        .sum()
+}
@@ -5,7 +5,7 @@ mod total_transport_test;
 use super::*;
 use crate::construction::constraints::{TOTAL_DISTANCE_KEY, TOTAL_DURATION_KEY};
 use crate::models::common::Cost;
-use crate::models::problem::TargetObjective;
+use crate::models::problem::{TargetObjective, TollTransportCost};
 use rosomaxa::prelude::*;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -40,6 +40,20 @@ impl TotalDuration {
     }
 }
 
+/// An objective function for total toll minimization as a target.
+pub struct TotalToll;
+
+impl TotalToll {
+    /// Creates an objective to minimize total toll.
+    pub fn minimize(toll_cost: Arc<TollTransportCost>) -> TargetObjective {
+        Arc::new(TotalTransport {
+            fitness: Arc::new(move |insertion_ctx| {
+                insertion_ctx.solution.routes.iter().map(|route_ctx| toll_cost.route_toll(&route_ctx.route)).sum()
+            }),
+        })
+    }
+}
+
 struct TotalTransport {
     fitness: Arc<dyn Fn(&InsertionContext) -> f64 + Send + Sync>,
 }
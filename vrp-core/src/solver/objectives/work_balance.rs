@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/objectives/work_balance_test.rs"]
+mod work_balance_test;
+
 use crate::construction::constraints::*;
 use crate::construction::heuristics::{RouteContext, SolutionContext};
 use crate::models::common::{CapacityDimension, LoadOps};
@@ -85,6 +89,28 @@ impl WorkBalance {
         Self::new_transport_balanced(threshold, TOTAL_DURATION_KEY, BALANCE_DURATION_KEY)
     }
 
+    /// Creates _(constraint, objective)_  type pair which pulls amount of stops in each tour towards
+    /// `target` value. Deviation within `tolerance` is not penalized, both under- and over-shooting
+    /// beyond it are penalized linearly.
+    pub fn new_target_stops(target: usize, tolerance: usize) -> (TargetConstraint, TargetObjective) {
+        let get_penalty = move |stops: usize| get_stop_deviation(stops, target, tolerance) as f64;
+
+        GenericValue::new_constrained_objective(
+            None,
+            Arc::new(|source, _| Ok(source)),
+            Arc::new(move |rc: &RouteContext| get_penalty(get_stop_count(rc))),
+            Arc::new(move |ctx: &SolutionContext| ctx.routes.iter().map(|rc| get_penalty(get_stop_count(rc))).sum()),
+            Arc::new(move |solution_ctx, route_ctx, _, _| {
+                let stops = get_stop_count(route_ctx);
+                // NOTE penalty of an empty tour is not a part of the solution yet
+                let current = if stops == 0 { 0. } else { get_penalty(stops) };
+
+                (get_penalty(stops + 1) - current) * solution_ctx.get_max_cost()
+            }),
+            TARGET_STOPS_KEY,
+        )
+    }
+
     fn new_transport_balanced(
         threshold: Option<f64>,
         transport_state_key: i32,
@@ -111,3 +137,26 @@ impl WorkBalance {
         )
     }
 }
+
+/// Returns amount of stops in the tour: consecutive job activities at the same location are
+/// considered as one stop.
+fn get_stop_count(route_ctx: &RouteContext) -> usize {
+    let (stops, _) = route_ctx.route.tour.all_activities().filter(|activity| activity.job.is_some()).fold(
+        (0, None),
+        |(stops, last_location), activity| {
+            if last_location == Some(activity.place.location) {
+                (stops, last_location)
+            } else {
+                (stops + 1, Some(activity.place.location))
+            }
+        },
+    );
+
+    stops
+}
+
+fn get_stop_deviation(stops: usize, target: usize, tolerance: usize) -> usize {
+    let deviation = if stops > target { stops - target } else { target - stops };
+
+    deviation.saturating_sub(tolerance)
+}
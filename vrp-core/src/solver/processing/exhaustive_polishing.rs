@@ -0,0 +1,217 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/exhaustive_polishing_test.rs"]
+mod exhaustive_polishing_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::models::common::Cost;
+use crate::models::problem::Job;
+use rosomaxa::utils::Timer;
+use std::cmp::Ordering;
+
+/// Polishes the final solution using exhaustive inter-route local search: relocate, swap(1,1) and
+/// swap(2,1) moves are evaluated for all routes and all movable jobs, then the best improving one
+/// is applied. This is repeated until no improvement is found or time budget is exceeded, so it is
+/// quite expensive, but helps to get maximally tight routes at the end of the search.
+pub struct ExhaustivePolishing {
+    max_time: f64,
+}
+
+impl ExhaustivePolishing {
+    /// Creates a new instance of `ExhaustivePolishing` with time budget specified in seconds.
+    pub fn new(max_time: f64) -> Self {
+        Self { max_time }
+    }
+}
+
+impl Default for ExhaustivePolishing {
+    fn default() -> Self {
+        Self::new(10.)
+    }
+}
+
+impl HeuristicSolutionProcessing for ExhaustivePolishing {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let timer = Timer::start();
+        let is_time_over = || timer.elapsed_secs_as_f64() > self.max_time;
+
+        let mut insertion_ctx = solution;
+
+        while !is_time_over() {
+            let new_insertion_ctx = find_best_move(&insertion_ctx, &is_time_over)
+                .and_then(|exchange| apply_move(&insertion_ctx, &exchange));
+
+            match new_insertion_ctx {
+                Some(new_insertion_ctx)
+                    if insertion_ctx.problem.objective.total_order(&new_insertion_ctx, &insertion_ctx)
+                        == Ordering::Less =>
+                {
+                    insertion_ctx = new_insertion_ctx
+                }
+                _ => break,
+            }
+        }
+
+        insertion_ctx
+    }
+}
+
+/// Specifies jobs which are moved between two routes: jobs from the first route are moved to the
+/// second one and vice versa.
+struct Move {
+    first: (usize, Vec<Job>),
+    second: (usize, Vec<Job>),
+}
+
+fn find_best_move(insertion_ctx: &InsertionContext, is_time_over: &dyn Fn() -> bool) -> Option<Move> {
+    let routes = &insertion_ctx.solution.routes;
+    let removals = routes
+        .iter()
+        .map(|route_ctx| get_removal_candidates(insertion_ctx, route_ctx))
+        .collect::<Vec<Vec<(Vec<Job>, RouteContext)>>>();
+
+    let mut best: Option<(Move, Cost)> = None;
+
+    for (first_idx, first_route) in routes.iter().enumerate() {
+        for (second_idx, second_route) in routes.iter().enumerate().filter(|(idx, _)| *idx != first_idx) {
+            if is_time_over() {
+                return best.map(|(exchange, _)| exchange);
+            }
+
+            let original_cost = get_route_cost(first_route) + get_route_cost(second_route);
+            let second_candidates = std::iter::once((Vec::default(), second_route.clone()))
+                .chain(removals[second_idx].iter().filter(|(jobs, _)| jobs.len() == 1).cloned())
+                .collect::<Vec<_>>();
+
+            for (first_jobs, first_removed) in removals[first_idx].iter() {
+                // NOTE evaluate relocate, swap(1,1) and swap(2,1) only
+                let second_candidates =
+                    second_candidates.iter().filter(|(second_jobs, _)| first_jobs.len() == 1 || second_jobs.len() == 1);
+
+                for (second_jobs, second_removed) in second_candidates {
+                    let new_cost = insert_jobs(insertion_ctx, first_removed, second_jobs).and_then(|first_inserted| {
+                        insert_jobs(insertion_ctx, second_removed, first_jobs)
+                            .map(|second_inserted| get_route_cost(&first_inserted) + get_route_cost(&second_inserted))
+                    });
+
+                    let delta = match new_cost {
+                        Some(new_cost) => new_cost - original_cost,
+                        None => continue,
+                    };
+
+                    let is_better = best.as_ref().map_or(true, |(_, best_delta)| delta < *best_delta);
+                    if delta < -f64::EPSILON && is_better {
+                        let exchange =
+                            Move { first: (first_idx, first_jobs.clone()), second: (second_idx, second_jobs.clone()) };
+                        best = Some((exchange, delta));
+                    }
+                }
+            }
+        }
+    }
+
+    best.map(|(exchange, _)| exchange)
+}
+
+/// Returns route copies without single movable job or two consecutive movable jobs.
+fn get_removal_candidates(insertion_ctx: &InsertionContext, route_ctx: &RouteContext) -> Vec<(Vec<Job>, RouteContext)> {
+    let locked = &insertion_ctx.solution.locked;
+    let jobs = route_ctx.route.tour.jobs().collect::<Vec<_>>();
+
+    let singles = jobs.iter().filter(|job| !locked.contains(*job)).map(|job| vec![job.clone()]);
+    let pairs = jobs.windows(2).filter(|pair| pair.iter().all(|job| !locked.contains(job))).map(|pair| pair.to_vec());
+
+    singles
+        .chain(pairs)
+        .map(|jobs| {
+            let mut route_ctx = route_ctx.deep_copy();
+            jobs.iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(job);
+            });
+            insertion_ctx.problem.constraint.accept_route_state(&mut route_ctx);
+
+            (jobs, route_ctx)
+        })
+        .collect()
+}
+
+/// Inserts jobs one by one at their best positions into the route copy.
+fn insert_jobs(insertion_ctx: &InsertionContext, route_ctx: &RouteContext, jobs: &[Job]) -> Option<RouteContext> {
+    jobs.iter().try_fold(route_ctx.deep_copy(), |route_ctx, job| {
+        let success = evaluate_insertion(insertion_ctx, &route_ctx, job)?;
+
+        let mut route_ctx = success.context;
+        let route = route_ctx.route_mut();
+        success.activities.into_iter().for_each(|(activity, index)| {
+            route.tour.insert_at(activity, index + 1);
+        });
+        insertion_ctx.problem.constraint.accept_route_state(&mut route_ctx);
+
+        Some(route_ctx)
+    })
+}
+
+fn apply_move(insertion_ctx: &InsertionContext, exchange: &Move) -> Option<InsertionContext> {
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+    let constraint = new_insertion_ctx.problem.constraint.clone();
+
+    let (first_idx, first_jobs) = &exchange.first;
+    let (second_idx, second_jobs) = &exchange.second;
+    let first_actor = new_insertion_ctx.solution.routes[*first_idx].route.actor.clone();
+    let second_actor = new_insertion_ctx.solution.routes[*second_idx].route.actor.clone();
+
+    for (route_idx, jobs) in [(*first_idx, first_jobs), (*second_idx, second_jobs)] {
+        let route_ctx = &mut new_insertion_ctx.solution.routes[route_idx];
+        jobs.iter().for_each(|job| {
+            route_ctx.route_mut().tour.remove(job);
+        });
+        constraint.accept_route_state(route_ctx);
+    }
+
+    for (actor, jobs) in [(first_actor, second_jobs), (second_actor, first_jobs)] {
+        for job in jobs.iter() {
+            let route_ctx =
+                new_insertion_ctx.solution.routes.iter().find(|route_ctx| route_ctx.route.actor == actor)?;
+            let success = evaluate_insertion(&new_insertion_ctx, route_ctx, job)?;
+
+            apply_insertion_success(&mut new_insertion_ctx, success);
+        }
+    }
+
+    finalize_insertion_ctx(&mut new_insertion_ctx);
+
+    Some(new_insertion_ctx)
+}
+
+fn evaluate_insertion(
+    insertion_ctx: &InsertionContext,
+    route_ctx: &RouteContext,
+    job: &Job,
+) -> Option<InsertionSuccess> {
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &AllLegSelector::default(),
+        result_selector: &BestResultSelector::default(),
+    };
+
+    evaluate_job_insertion_in_route(
+        insertion_ctx,
+        &eval_ctx,
+        route_ctx,
+        InsertionPosition::Any,
+        InsertionResult::make_failure(),
+    )
+    .into_success()
+}
+
+fn get_route_cost(route_ctx: &RouteContext) -> Cost {
+    // NOTE empty route is removed from the solution, so it has no cost
+    if route_ctx.route.tour.has_jobs() {
+        route_ctx.get_route_cost()
+    } else {
+        0.
+    }
+}
@@ -6,8 +6,17 @@ use rosomaxa::prelude::*;
 mod advance_departure;
 pub use self::advance_departure::AdvanceDeparture;
 
+mod exhaustive_polishing;
+pub use self::exhaustive_polishing::ExhaustivePolishing;
+
+mod unassigned_reinsertion;
+pub use self::unassigned_reinsertion::UnassignedReinsertion;
+
 mod unassignment_reason;
 pub use self::unassignment_reason::UnassignmentReason;
 
 mod vicinity_clustering;
 pub use self::vicinity_clustering::{VicinityClustering, VicinityDimension};
+
+mod walk_in_commute;
+pub use self::walk_in_commute::{WalkInCommute, WalkInDimension};
@@ -0,0 +1,53 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/unassigned_reinsertion_test.rs"]
+mod unassigned_reinsertion_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::utils::Environment;
+use hashbrown::HashMap;
+use std::sync::Arc;
+
+/// Tries to insert unassigned jobs into the final solution using cheapest insertion which evaluates
+/// all routes and all insertion positions. This might help when a job was left unassigned due
+/// to sampling of insertion positions or noise in result selection used during the search.
+#[derive(Default)]
+pub struct UnassignedReinsertion {}
+
+impl HeuristicSolutionProcessing for UnassignedReinsertion {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let mut insertion_ctx = solution;
+
+        if insertion_ctx.solution.unassigned.is_empty() {
+            return insertion_ctx;
+        }
+
+        // NOTE unassigned jobs with positive code are not evaluated in unmodified routes, so move
+        // them to required and keep their codes to restore them if insertion fails again
+        let codes = insertion_ctx.solution.unassigned.drain().collect::<HashMap<_, _>>();
+        insertion_ctx.solution.required.extend(codes.keys().cloned());
+
+        // NOTE final step is mandatory, so it should not be affected by execution quota
+        let environment = insertion_ctx.environment.clone();
+        insertion_ctx.environment = Arc::new(Environment { quota: None, ..environment.as_ref().clone() });
+
+        let mut insertion_ctx = InsertionHeuristic::default().process(
+            insertion_ctx,
+            &AllJobSelector::default(),
+            &AllRouteSelector::default(),
+            &AllLegSelector::default(),
+            &BestResultSelector::default(),
+        );
+
+        insertion_ctx.environment = environment;
+        insertion_ctx.solution.unassigned.iter_mut().for_each(|(job, code)| {
+            if *code <= 0 {
+                *code = codes.get(job).cloned().unwrap_or(*code);
+            }
+        });
+
+        insertion_ctx
+    }
+}
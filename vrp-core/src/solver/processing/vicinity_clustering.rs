@@ -4,7 +4,7 @@ mod vicinity_clustering_test;
 
 use super::*;
 use crate::construction::clustering::vicinity::*;
-use crate::models::common::{Schedule, ValueDimension};
+use crate::models::common::{DimenKey, Schedule, ValueDimension};
 use crate::models::problem::{Jobs, ProblemObjective};
 use crate::models::solution::{Activity, Place};
 use crate::models::{Extras, Problem};
@@ -13,6 +13,7 @@ use hashbrown::{HashMap, HashSet};
 use std::sync::Arc;
 
 const ORIG_PROBLEM_KEY: &str = "orig_problem";
+const VICINITY_KEY: DimenKey<ClusterConfig> = DimenKey::new("vicinity");
 
 /// A trait to get or set vicinity config.
 pub trait VicinityDimension {
@@ -24,12 +25,12 @@ pub trait VicinityDimension {
 
 impl VicinityDimension for Extras {
     fn set_cluster_config(&mut self, config: ClusterConfig) -> &mut Self {
-        self.set_value("vicinity", config);
+        self.set_dimen(&VICINITY_KEY, config);
         self
     }
 
     fn get_cluster_config(&self) -> Option<&ClusterConfig> {
-        self.get_value("vicinity")
+        self.get_dimen(&VICINITY_KEY)
     }
 }
 
@@ -0,0 +1,208 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/walk_in_commute_test.rs"]
+mod walk_in_commute_test;
+
+use super::*;
+use crate::models::common::*;
+use crate::models::problem::{Job, Jobs, Place, ProblemObjective, Single};
+use crate::models::solution::{Activity, Commute, Place as ActivityPlace};
+use crate::models::{Extras, Problem};
+use crate::solver::RefinementContext;
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+const ORIG_PROBLEM_KEY: &str = "walk_in_orig_problem";
+const WALK_IN_KEY: DimenKey<Vec<(usize, Commute)>> = DimenKey::new("walk_in");
+const WALK_IN_JOB_KEY: DimenKey<Arc<Single>> = DimenKey::new("walk_in_job");
+
+/// A trait to get or set walk-in commute of job places.
+pub trait WalkInDimension {
+    /// Sets walk-in commutes as a list of job place index and its commute. A commute location is a
+    /// parking location where vehicle stops, the place itself is reached by walking.
+    fn set_walk_in(&mut self, commutes: Vec<(usize, Commute)>) -> &mut Self;
+    /// Gets walk-in commutes.
+    fn get_walk_in(&self) -> Option<&Vec<(usize, Commute)>>;
+}
+
+impl WalkInDimension for Dimensions {
+    fn set_walk_in(&mut self, commutes: Vec<(usize, Commute)>) -> &mut Self {
+        self.set_dimen(&WALK_IN_KEY, commutes);
+        self
+    }
+
+    fn get_walk_in(&self) -> Option<&Vec<(usize, Commute)>> {
+        self.get_dimen(&WALK_IN_KEY)
+    }
+}
+
+/// Provides way to visit job places by walking from a parking location: on pre processing, such
+/// places are moved to their parking locations with service time extended by commute duration,
+/// on post processing, original places are restored and commute is assigned to activities.
+/// Limitations:
+/// - only single jobs are supported
+#[derive(Default)]
+pub struct WalkInCommute {}
+
+impl HeuristicContextProcessing for WalkInCommute {
+    type Context = RefinementContext;
+    type Objective = ProblemObjective;
+    type Solution = InsertionContext;
+
+    fn pre_process(&self, context: Self::Context) -> Self::Context {
+        let problem = context.problem.clone();
+
+        let has_walk_in =
+            problem.jobs.all().any(|job| job.as_single().map_or(false, |single| single.dimens.get_walk_in().is_some()));
+
+        if !has_walk_in {
+            return context;
+        }
+
+        let jobs = problem
+            .jobs
+            .all()
+            .map(|job| match job.as_single() {
+                Some(single) if single.dimens.get_walk_in().is_some() => create_parking_job(single),
+                _ => job,
+            })
+            .collect();
+
+        let mut extras: Extras = problem.extras.iter().map(|(k, v)| (k.clone(), v.clone())).collect::<HashMap<_, _>>();
+        extras.insert(ORIG_PROBLEM_KEY.to_string(), problem.clone());
+
+        let problem = Arc::new(Problem {
+            fleet: problem.fleet.clone(),
+            jobs: Arc::new(Jobs::new(problem.fleet.as_ref(), jobs, &problem.transport)),
+            locks: problem.locks.clone(),
+            constraint: problem.constraint.clone(),
+            activity: problem.activity.clone(),
+            transport: problem.transport.clone(),
+            objective: problem.objective.clone(),
+            extras: Arc::new(extras),
+        });
+
+        RefinementContext { problem, ..context }
+    }
+}
+
+impl HeuristicSolutionProcessing for WalkInCommute {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let mut insertion_ctx = solution;
+
+        let orig_problem = if let Some(orig_problem) =
+            insertion_ctx.problem.extras.get(ORIG_PROBLEM_KEY).cloned().and_then(|any| any.downcast::<Problem>().ok())
+        {
+            orig_problem
+        } else {
+            return insertion_ctx;
+        };
+
+        insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| {
+            #[allow(clippy::needless_collect)]
+            let activities = route_ctx
+                .route
+                .tour
+                .all_activities()
+                .enumerate()
+                .filter_map(|(idx, activity)| {
+                    activity
+                        .job
+                        .as_ref()
+                        .and_then(|job| job.dimens.get_dimen(&WALK_IN_JOB_KEY).map(|orig_job| (job, orig_job)))
+                        .map(|(job, orig_job)| (idx, restore_activity(activity, job, orig_job)))
+                })
+                .collect::<Vec<_>>();
+
+            activities.into_iter().for_each(|(activity_idx, activity)| {
+                route_ctx.route_mut().tour.remove_activity_at(activity_idx);
+                route_ctx.route_mut().tour.insert_at(activity, activity_idx);
+            });
+        });
+
+        insertion_ctx.solution.unassigned =
+            insertion_ctx.solution.unassigned.iter().map(|(job, code)| (restore_job(job), *code)).collect();
+
+        insertion_ctx.solution.required = insertion_ctx.solution.required.iter().map(restore_job).collect();
+        insertion_ctx.solution.ignored = insertion_ctx.solution.ignored.iter().map(restore_job).collect();
+
+        insertion_ctx.problem = orig_problem;
+
+        insertion_ctx
+    }
+}
+
+/// Creates a job which has walk-in places moved to their parking locations.
+fn create_parking_job(single: &Arc<Single>) -> Job {
+    let commutes = single.dimens.get_walk_in().expect("no walk-in commute");
+
+    let places = single
+        .places
+        .iter()
+        .enumerate()
+        .map(|(place_idx, place)| match commutes.iter().find(|(idx, _)| *idx == place_idx) {
+            Some((_, commute)) => {
+                // NOTE service can be started only after walking to the place
+                let forward = commute.forward.duration;
+                let times = place
+                    .times
+                    .iter()
+                    .map(|time| match time {
+                        TimeSpan::Window(tw) => TimeSpan::Window(TimeWindow::new(tw.start - forward, tw.end - forward)),
+                        TimeSpan::Offset(to) => TimeSpan::Offset(TimeOffset::new(to.start - forward, to.end - forward)),
+                    })
+                    .collect();
+
+                Place { location: Some(commute.forward.location), duration: place.duration + commute.duration(), times }
+            }
+            None => place.clone(),
+        })
+        .collect();
+
+    let mut dimens = single.dimens.clone();
+    dimens.set_dimen(&WALK_IN_JOB_KEY, single.clone());
+
+    Job::Single(Arc::new(Single { places, dimens }))
+}
+
+/// Creates an activity with original job and place, including walk-in commute if it was used.
+fn restore_activity(activity: &Activity, parking_job: &Single, orig_job: &Arc<Single>) -> Activity {
+    let place_idx = parking_job.places.iter().position(|place| {
+        place.location == Some(activity.place.location)
+            && compare_floats(place.duration, activity.place.duration) == Ordering::Equal
+    });
+
+    let commute = place_idx.and_then(|place_idx| {
+        orig_job
+            .dimens
+            .get_walk_in()
+            .and_then(|commutes| commutes.iter().find(|(idx, _)| *idx == place_idx))
+            .map(|(_, commute)| (place_idx, commute.clone()))
+    });
+
+    let (place, commute) = match commute {
+        Some((place_idx, commute)) => {
+            let place = orig_job.places.get(place_idx).expect("wrong place index");
+            let forward = commute.forward.duration;
+
+            let place = ActivityPlace {
+                location: place.location.expect("walk-in place should have location"),
+                duration: place.duration,
+                time: TimeWindow::new(activity.place.time.start + forward, activity.place.time.end + forward),
+            };
+
+            (place, Some(commute))
+        }
+        None => (activity.place.clone(), None),
+    };
+
+    Activity { place, schedule: activity.schedule.clone(), job: Some(orig_job.clone()), commute }
+}
+
+fn restore_job(job: &Job) -> Job {
+    job.as_single()
+        .and_then(|single| single.dimens.get_dimen(&WALK_IN_JOB_KEY))
+        .map_or_else(|| job.clone(), |single| Job::Single(single.clone()))
+}
@@ -0,0 +1,79 @@
+use crate::models::common::Cost;
+use crate::models::Solution;
+use rosomaxa::evolution::TelemetryMetrics;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A result type returned by the solver.
+pub type SolverResult = Result<(Solution, Cost, Option<TelemetryMetrics>), String>;
+
+/// Represents a solver progress reported on each generation.
+pub struct SolverProgress {
+    /// Generation sequence number.
+    pub generation: usize,
+    /// Time since evolution started in seconds.
+    pub elapsed: f64,
+    /// A relative estimation till termination in the `[0, 1]` range.
+    pub termination_estimate: f64,
+    /// True if this generation is considered as improvement.
+    pub is_improvement: bool,
+    /// A cost of the best known solution.
+    pub cost: Cost,
+    /// The best known solution. It is reported only when generation is an improvement.
+    pub solution: Option<Solution>,
+}
+
+/// A future which is resolved when the solver, running on a dedicated thread, is finished.
+pub struct SolverFuture {
+    state: Arc<Mutex<SolverFutureState>>,
+}
+
+#[derive(Default)]
+struct SolverFutureState {
+    result: Option<SolverResult>,
+    waker: Option<Waker>,
+}
+
+impl SolverFuture {
+    /// Runs given solve function on a new thread and returns a future of its result.
+    pub(crate) fn spawn<F>(solve_fn: F) -> Self
+    where
+        F: FnOnce() -> SolverResult + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(SolverFutureState::default()));
+
+        std::thread::spawn({
+            let state = state.clone();
+            move || {
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(solve_fn))
+                    .unwrap_or_else(|_| Err("solver has panicked".to_string()));
+
+                let mut state = state.lock().unwrap();
+                state.result = Some(result);
+
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            }
+        });
+
+        Self { state }
+    }
+}
+
+impl Future for SolverFuture {
+    type Output = SolverResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(result) = state.result.take() {
+            Poll::Ready(result)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
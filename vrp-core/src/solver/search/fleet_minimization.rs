@@ -0,0 +1,150 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/fleet_minimization_test.rs"]
+mod fleet_minimization_test;
+
+use crate::construction::heuristics::*;
+use crate::models::common::SingleDimLoad;
+use crate::models::problem::{Actor, Job, ProblemObjective};
+use crate::solver::search::{Recreate, RecreateWithBlinks, Ruin};
+use crate::solver::RefinementContext;
+use hashbrown::{HashMap, HashSet};
+use rand::prelude::SliceRandom;
+use rosomaxa::prelude::*;
+use std::sync::Arc;
+
+/// A heuristic operator which implements fleet minimization phase as described in "Slack Induction
+/// by String Removals for Vehicle Routing Problems" (aka SISR) paper by Jan Christiaens, Greet Vanden Berghe.
+///
+/// The operator removes a route with the least amount of jobs and tries to reinsert its jobs into
+/// remaining routes using ruin and recreate with blinks. Jobs are recreated in order of their absence
+/// counters: how many times a job was left unassigned. When all jobs are assigned, the next route is
+/// removed. Amount of used vehicles is never increased during this phase.
+pub struct FleetMinimization {
+    ruin: Arc<dyn Ruin + Send + Sync>,
+    max_iterations: usize,
+}
+
+impl FleetMinimization {
+    /// Creates a new instance of `FleetMinimization`. `ruin` should restore solution context
+    /// (e.g. be wrapped into `CompositeRuin`).
+    pub fn new(ruin: Arc<dyn Ruin + Send + Sync>, max_iterations: usize) -> Self {
+        Self { ruin, max_iterations }
+    }
+}
+
+impl HeuristicOperator for FleetMinimization {
+    type Context = RefinementContext;
+    type Objective = ProblemObjective;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+        let baseline = get_unassigned_jobs(solution).len();
+
+        let mut best = solution.deep_copy();
+        let mut current = solution.deep_copy();
+        let mut absences: HashMap<Job, usize> = HashMap::default();
+
+        if !remove_smallest_route(&mut current) {
+            return best;
+        }
+
+        for _ in 0..self.max_iterations {
+            let recreate = RecreateWithBlinks::<SingleDimLoad>::new(
+                vec![(Box::new(AbsenceJobSelector { absences: absences.clone() }), 1)],
+                current.environment.random.clone(),
+            );
+
+            let mut candidate = recreate.run(refinement_ctx, self.ruin.run(refinement_ctx, current.deep_copy()));
+            finalize_insertion_ctx(&mut candidate);
+
+            let candidate_unassigned = get_unassigned_jobs(&candidate);
+            let current_unassigned = get_unassigned_jobs(&current);
+            let get_absence_sum =
+                |jobs: &[Job]| jobs.iter().map(|job| absences.get(job).cloned().unwrap_or(0)).sum::<usize>();
+
+            let is_accepted = candidate_unassigned.len() < current_unassigned.len()
+                || get_absence_sum(candidate_unassigned.as_slice()) < get_absence_sum(current_unassigned.as_slice());
+
+            let is_feasible = candidate_unassigned.len() <= baseline;
+            candidate_unassigned.into_iter().for_each(|job| *absences.entry(job).or_insert(0) += 1);
+
+            if is_feasible {
+                best = candidate.deep_copy();
+                current = candidate;
+
+                if !remove_smallest_route(&mut current) {
+                    break;
+                }
+            } else if is_accepted {
+                current = candidate;
+            }
+        }
+
+        release_unused_actors(&mut best);
+
+        best
+    }
+}
+
+/// Selects jobs in order of their absence counters: jobs which were unassigned more often go first.
+struct AbsenceJobSelector {
+    absences: HashMap<Job, usize>,
+}
+
+impl JobSelector for AbsenceJobSelector {
+    fn select<'a>(&'a self, ctx: &'a mut InsertionContext) -> Box<dyn Iterator<Item = Job> + 'a> {
+        ctx.solution.required.shuffle(&mut ctx.environment.random.get_rng());
+        ctx.solution.required.sort_by(|a, b| {
+            let get_absence = |job: &Job| self.absences.get(job).cloned().unwrap_or(0);
+            get_absence(b).cmp(&get_absence(a))
+        });
+
+        Box::new(ctx.solution.required.iter().cloned())
+    }
+}
+
+fn get_unassigned_jobs(insertion_ctx: &InsertionContext) -> Vec<Job> {
+    let solution = &insertion_ctx.solution;
+    solution.unassigned.keys().chain(solution.required.iter()).cloned().collect()
+}
+
+/// Removes a route with the least amount of jobs keeping its actor used, so recreate cannot open
+/// any new route. Returns false if there is no route to be removed.
+pub(crate) fn remove_smallest_route(insertion_ctx: &mut InsertionContext) -> bool {
+    let solution = &mut insertion_ctx.solution;
+    let locked = &solution.locked;
+
+    let route_idx = solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route.tour.jobs().all(|job| !locked.contains(&job)))
+        .min_by_key(|(_, route_ctx)| route_ctx.route.tour.job_count())
+        .map(|(idx, _)| idx);
+
+    if let Some(route_idx) = route_idx.filter(|_| solution.routes.len() > 1) {
+        let route_ctx = solution.routes.remove(route_idx);
+        solution.required.extend(route_ctx.route.tour.jobs());
+
+        let available = solution.registry.resources().available().collect::<Vec<_>>();
+        available.into_iter().for_each(|actor| {
+            solution.registry.use_route(&RouteContext::new(actor));
+        });
+
+        insertion_ctx.restore();
+
+        true
+    } else {
+        false
+    }
+}
+
+/// Makes actors which are not used by any route available again.
+pub(crate) fn release_unused_actors(insertion_ctx: &mut InsertionContext) {
+    let solution = &mut insertion_ctx.solution;
+    let used: HashSet<Arc<Actor>> = solution.routes.iter().map(|route_ctx| route_ctx.route.actor.clone()).collect();
+
+    let unused = solution.registry.resources().all().filter(|actor| !used.contains(actor)).collect::<Vec<_>>();
+    unused.into_iter().for_each(|actor| solution.registry.free_route(&RouteContext::new(actor)));
+}
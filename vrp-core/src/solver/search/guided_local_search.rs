@@ -0,0 +1,186 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/guided_local_search_test.rs"]
+mod guided_local_search_test;
+
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::{Cost, Location};
+use crate::models::problem::ProblemObjective;
+use crate::solver::{RefinementContext, TargetHeuristicOperator};
+use crate::utils::compare_floats;
+use hashbrown::HashMap;
+use rosomaxa::prelude::*;
+use rosomaxa::utils::parallel_into_collect;
+use std::cmp::Ordering;
+
+/// An arc between two locations which is used as a solution feature.
+type Edge = (Location, Location);
+
+/// A hyper-heuristic which implements guided local search (GLS) idea: arcs between locations are
+/// solution features and, when inner search gets stuck in local optimum, arcs with maximum utility
+/// (long arcs which are not penalized yet) are penalized. Penalties are added to the solution cost
+/// which is used to accept a new current solution, so the search is guided away from such arcs.
+/// The best solution is still selected using the original objective.
+pub struct GuidedLocalSearch {
+    inner_search: TargetHeuristicOperator,
+    max_iterations: usize,
+    alpha: f64,
+    penalties: HashMap<Edge, usize>,
+}
+
+impl GuidedLocalSearch {
+    /// Creates a new instance of `GuidedLocalSearch`. `max_iterations` specifies amount of inner search
+    /// runs per solution and `alpha` specifies penalty weight relatively to an average arc cost.
+    pub fn new(inner_search: TargetHeuristicOperator, max_iterations: usize, alpha: f64) -> Self {
+        Self { inner_search, max_iterations, alpha, penalties: HashMap::default() }
+    }
+
+    /// Returns amount of penalties accumulated for the arc.
+    pub fn get_penalty(&self, from: Location, to: Location) -> usize {
+        self.penalties.get(&(from, to)).cloned().unwrap_or(0)
+    }
+
+    fn guided_search(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+    ) -> (InsertionContext, Vec<Edge>) {
+        let objective = refinement_ctx.problem.objective.as_ref();
+        let lambda = self.alpha * get_average_arc_cost(insertion_ctx);
+
+        let mut penalties = self.penalties.clone();
+        let mut penalized = vec![];
+
+        let mut current = insertion_ctx.deep_copy();
+        let mut best = insertion_ctx.deep_copy();
+
+        for _ in 0..self.max_iterations {
+            let candidate = self.inner_search.search(refinement_ctx, &current);
+
+            if objective.total_order(&candidate, &best) == Ordering::Less {
+                best = candidate.deep_copy();
+            }
+
+            if is_accepted(objective, &candidate, &current, &penalties, lambda) {
+                current = candidate;
+            } else {
+                // NOTE local optimum is reached: penalize the features with maximum utility
+                get_max_utility_arcs(&current, &penalties).into_iter().for_each(|arc| {
+                    *penalties.entry(arc).or_insert(0) += 1;
+                    penalized.push(arc);
+                });
+            }
+        }
+
+        (best, penalized)
+    }
+}
+
+impl HyperHeuristic for GuidedLocalSearch {
+    type Context = RefinementContext;
+    type Objective = ProblemObjective;
+    type Solution = InsertionContext;
+
+    fn search(&mut self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
+        let this = &*self;
+        let results = parallel_into_collect(solutions.into_iter().enumerate().collect(), |(idx, solution)| {
+            heuristic_ctx
+                .environment
+                .parallelism
+                .thread_pool_execute(idx, || this.guided_search(heuristic_ctx, solution))
+        });
+
+        let (solutions, penalized): (Vec<_>, Vec<_>) = results.into_iter().unzip();
+
+        penalized.into_iter().flatten().for_each(|arc| {
+            *self.penalties.entry(arc).or_insert(0) += 1;
+        });
+
+        solutions
+    }
+}
+
+/// Checks whether candidate is better than current solution using objective augmented with penalties.
+fn is_accepted(
+    objective: &ProblemObjective,
+    candidate: &InsertionContext,
+    current: &InsertionContext,
+    penalties: &HashMap<Edge, usize>,
+    lambda: f64,
+) -> bool {
+    if objective.total_order(candidate, current) == Ordering::Less {
+        return true;
+    }
+
+    // NOTE penalties are applied only to the cost, so unassigned jobs are compared first
+    let get_unassigned = |insertion_ctx: &InsertionContext| {
+        insertion_ctx.solution.unassigned.len() + insertion_ctx.solution.required.len()
+    };
+    let get_augmented_cost = |insertion_ctx: &InsertionContext| {
+        let penalty = get_arcs(insertion_ctx)
+            .into_iter()
+            .map(|(arc, _)| penalties.get(&arc).cloned().unwrap_or(0) as f64)
+            .sum::<f64>();
+
+        insertion_ctx.solution.get_total_cost() + lambda * penalty
+    };
+
+    get_unassigned(candidate) == get_unassigned(current)
+        && compare_floats(get_augmented_cost(candidate), get_augmented_cost(current)) == Ordering::Less
+}
+
+/// Returns arcs which have maximum utility: `cost / (1 + penalty)`.
+fn get_max_utility_arcs(insertion_ctx: &InsertionContext, penalties: &HashMap<Edge, usize>) -> Vec<Edge> {
+    let utilities = get_arcs(insertion_ctx)
+        .into_iter()
+        .map(|(arc, cost)| (arc, cost / (1. + penalties.get(&arc).cloned().unwrap_or(0) as f64)))
+        .collect::<Vec<_>>();
+
+    let max_utility = utilities.iter().map(|(_, utility)| *utility).max_by(|a, b| compare_floats(*a, *b));
+
+    max_utility
+        .filter(|max_utility| compare_floats(*max_utility, 0.) == Ordering::Greater)
+        .map(|max_utility| {
+            utilities
+                .into_iter()
+                .filter(|(_, utility)| compare_floats(*utility, max_utility) == Ordering::Equal)
+                .map(|(arc, _)| arc)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn get_average_arc_cost(insertion_ctx: &InsertionContext) -> Cost {
+    let arcs = get_arcs(insertion_ctx);
+
+    if arcs.is_empty() {
+        0.
+    } else {
+        arcs.iter().map(|(_, cost)| *cost).sum::<Cost>() / arcs.len() as f64
+    }
+}
+
+/// Returns all arcs used by the solution with their distance cost.
+fn get_arcs(insertion_ctx: &InsertionContext) -> Vec<(Edge, Cost)> {
+    let transport = insertion_ctx.problem.transport.as_ref();
+
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let route = &route_ctx.route;
+            let vehicle = route.actor.vehicle.as_ref();
+
+            route
+                .tour
+                .all_activities()
+                .zip(route.tour.all_activities().skip(1))
+                .map(|(from, to)| (from.place.location, to.place.location))
+                .filter(|(from, to)| from != to)
+                .map(move |(from, to)| {
+                    let cost = transport.distance_approx(&vehicle.profile, from, to) * vehicle.costs.per_distance;
+                    ((from, to), cost)
+                })
+        })
+        .collect()
+}
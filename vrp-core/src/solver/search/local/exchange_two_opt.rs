@@ -0,0 +1,141 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/exchange_two_opt_test.rs"]
+mod exchange_two_opt_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::solver::search::LocalOperator;
+use crate::solver::RefinementContext;
+use rosomaxa::prelude::*;
+
+/// A local search operator which applies 2-opt move inside one route: it reverses a segment of
+/// the tour which gives the biggest distance reduction, so crossing route parts are untangled.
+/// Jobs of the reversed segment are reinserted one by one, so hard activity constraints are respected.
+pub struct ExchangeTwoOpt {
+    max_segment_size: usize,
+}
+
+impl ExchangeTwoOpt {
+    /// Creates a new instance of `ExchangeTwoOpt`.
+    pub fn new(max_segment_size: usize) -> Self {
+        assert!(max_segment_size > 1);
+
+        Self { max_segment_size }
+    }
+}
+
+impl Default for ExchangeTwoOpt {
+    fn default() -> Self {
+        Self::new(32)
+    }
+}
+
+impl LocalOperator for ExchangeTwoOpt {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        if !insertion_ctx.solution.required.is_empty() {
+            return None;
+        }
+
+        let route_indices = (0..insertion_ctx.solution.routes.len()).collect::<Vec<_>>();
+        let random = insertion_ctx.environment.random.clone();
+        let start = if route_indices.is_empty() { 0 } else { random.uniform_int(0, route_indices.len() as i32 - 1) };
+
+        // NOTE start from random route and take the first one which can be improved
+        route_indices.iter().cycle().skip(start as usize).take(route_indices.len()).find_map(|&route_idx| {
+            let (start, end) = find_best_segment(insertion_ctx, route_idx, self.max_segment_size)?;
+
+            reverse_segment(insertion_ctx, route_idx, start, end)
+        })
+    }
+}
+
+/// Finds activity indices of the segment which reversal gives the biggest distance reduction.
+fn find_best_segment(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    max_segment_size: usize,
+) -> Option<(usize, usize)> {
+    let route = &insertion_ctx.solution.routes.get(route_idx)?.route;
+    let transport = insertion_ctx.problem.transport.as_ref();
+    let profile = &route.actor.vehicle.profile;
+    let locked = &insertion_ctx.solution.locked;
+
+    let last = route.tour.job_activity_count();
+    let is_movable = |idx: usize| {
+        route
+            .tour
+            .get(idx)
+            .and_then(|activity| activity.retrieve_job())
+            .map_or(false, |job| matches!(job, Job::Single(_)) && !locked.contains(&job))
+    };
+    let location = |idx: usize| route.tour.get(idx).map(|activity| activity.place.location);
+    let distance = |from: usize, to: usize| match (location(from), location(to)) {
+        (Some(from), Some(to)) => transport.distance_approx(profile, from, to),
+        _ => 0.,
+    };
+
+    (1..=last)
+        .filter(|&start| is_movable(start))
+        .flat_map(|start| {
+            (start + 1..=last.min(start + max_segment_size - 1))
+                .take_while(move |&end| is_movable(end))
+                .map(move |end| (start, end))
+        })
+        .map(|(start, end)| {
+            let gain = distance(start - 1, start) + distance(end, end + 1)
+                - distance(start - 1, end)
+                - distance(start, end + 1);
+
+            (start, end, gain)
+        })
+        .filter(|(_, _, gain)| *gain > 0.)
+        .max_by(|(_, _, a), (_, _, b)| compare_floats(*a, *b))
+        .map(|(start, end, _)| (start, end))
+}
+
+/// Removes jobs of the segment and inserts them back in reversed order.
+fn reverse_segment(
+    insertion_ctx: &InsertionContext,
+    route_idx: usize,
+    start: usize,
+    end: usize,
+) -> Option<InsertionContext> {
+    let mut new_insertion_ctx = insertion_ctx.deep_copy();
+
+    let route_ctx = new_insertion_ctx.solution.routes.get_mut(route_idx)?;
+    let jobs = (start..=end).filter_map(|idx| route_ctx.route.tour.get(idx)?.retrieve_job()).collect::<Vec<_>>();
+    jobs.iter().for_each(|job| {
+        route_ctx.route_mut().tour.remove(job);
+    });
+    new_insertion_ctx.problem.constraint.accept_route_state(route_ctx);
+
+    let leg_selector = VariableLegSelector::new(new_insertion_ctx.environment.random.clone());
+    let result_selector = BestResultSelector::default();
+
+    for (offset, job) in jobs.iter().rev().enumerate() {
+        let eval_ctx = EvaluationContext {
+            constraint: &new_insertion_ctx.problem.constraint,
+            job,
+            leg_selector: &leg_selector,
+            result_selector: &result_selector,
+        };
+
+        let insertion = evaluate_job_insertion_in_route(
+            &new_insertion_ctx,
+            &eval_ctx,
+            new_insertion_ctx.solution.routes.get(route_idx)?,
+            InsertionPosition::Concrete(start - 1 + offset),
+            InsertionResult::make_failure(),
+        );
+
+        match insertion {
+            InsertionResult::Success(success) => apply_insertion_success(&mut new_insertion_ctx, success),
+            // NOTE reversed segment violates some hard constraint, so the move is discarded
+            InsertionResult::Failure(_) => return None,
+        }
+    }
+
+    finalize_insertion_ctx(&mut new_insertion_ctx);
+
+    Some(new_insertion_ctx)
+}
@@ -18,6 +18,9 @@ pub use self::exchange_sequence::*;
 mod exchange_swap_star;
 pub use self::exchange_swap_star::*;
 
+mod exchange_two_opt;
+pub use self::exchange_two_opt::*;
+
 mod reschedule_departure;
 pub use self::reschedule_departure::*;
 
@@ -75,7 +78,7 @@ impl LocalOperator for CompositeLocalOperator {
 }
 
 /// Applies insertion success by creating a new route context from it.
-fn apply_insertion(insertion_ctx: &mut InsertionContext, success: InsertionSuccess) {
+pub(crate) fn apply_insertion(insertion_ctx: &mut InsertionContext, success: InsertionSuccess) {
     let route_index =
         insertion_ctx.solution.routes.iter().position(|ctx| ctx.route.actor == success.context.route.actor).unwrap();
 
@@ -44,5 +44,6 @@ impl LocalOperator for RescheduleDeparture {
 }
 
 fn can_recede_departure(start: &Activity, earliest: f64) -> bool {
-    compare_floats(start.schedule.departure, earliest) != Ordering::Equal
+    // NOTE start activity duration is a loading time which delays the earliest departure
+    compare_floats(start.schedule.departure, earliest + start.place.duration) != Ordering::Equal
 }
@@ -26,12 +26,24 @@ pub(crate) use self::utils::*;
 mod decompose_search;
 pub use self::decompose_search::DecomposeSearch;
 
+mod fleet_minimization;
+pub use self::fleet_minimization::FleetMinimization;
+
+mod guided_local_search;
+pub use self::guided_local_search::GuidedLocalSearch;
+
 mod infeasible_search;
 pub use self::infeasible_search::InfeasibleSearch;
 
 mod local_search;
 pub use self::local_search::LocalSearch;
 
+mod path_relinking;
+pub use self::path_relinking::PathRelinking;
+
+mod route_minimization;
+pub use self::route_minimization::RouteMinimization;
+
 mod ruin_recreate;
 pub use self::ruin_recreate::RuinAndRecreate;
 
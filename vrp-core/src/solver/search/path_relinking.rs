@@ -0,0 +1,150 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/path_relinking_test.rs"]
+mod path_relinking_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::{Actor, Job, ProblemObjective};
+use crate::solver::RefinementContext;
+use hashbrown::HashMap;
+use rand::prelude::SliceRandom;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A heuristic operator which implements path relinking between elite solutions: it takes a guiding
+/// solution from the population and moves jobs of the given (initiating) solution, one by one, to
+/// the routes they are assigned to in the guiding solution. The best intermediate solution found on
+/// the path is returned, so it is fed back into the population.
+pub struct PathRelinking {
+    elite_size: usize,
+    max_moves: usize,
+}
+
+impl PathRelinking {
+    /// Creates a new instance of `PathRelinking`. `elite_size` specifies how many best ranked
+    /// individuals are considered as guiding solutions, `max_moves` limits length of the path.
+    pub fn new(elite_size: usize, max_moves: usize) -> Self {
+        Self { elite_size, max_moves }
+    }
+}
+
+impl Default for PathRelinking {
+    fn default() -> Self {
+        Self::new(4, 32)
+    }
+}
+
+impl HeuristicOperator for PathRelinking {
+    type Context = RefinementContext;
+    type Objective = ProblemObjective;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+
+        let guides = refinement_ctx
+            .population
+            .ranked()
+            .take(self.elite_size)
+            .map(|(individual, _)| individual)
+            .filter(|individual| !get_moves(solution, individual).is_empty())
+            .collect::<Vec<_>>();
+
+        if guides.is_empty() {
+            return solution.deep_copy();
+        }
+
+        let guide = guides[solution.environment.random.uniform_int(0, guides.len() as i32 - 1) as usize];
+
+        relink(solution, guide, self.max_moves).unwrap_or_else(|| solution.deep_copy())
+    }
+}
+
+/// Moves jobs of the initiating solution towards their routes in the guiding solution and returns
+/// the best intermediate solution, if any move was made.
+fn relink(initiating: &InsertionContext, guiding: &InsertionContext, max_moves: usize) -> Option<InsertionContext> {
+    let objective = initiating.problem.objective.clone();
+
+    let mut moves = get_moves(initiating, guiding);
+    moves.shuffle(&mut initiating.environment.random.get_rng());
+
+    let mut current = initiating.deep_copy();
+
+    moves.into_iter().take(max_moves).fold(None, |best: Option<InsertionContext>, (job, actor)| {
+        if !move_job(&mut current, &job, &actor) {
+            return best;
+        }
+
+        let mut candidate = current.deep_copy();
+        candidate.restore();
+        finalize_insertion_ctx(&mut candidate);
+
+        match best {
+            Some(best) if objective.total_order(&best, &candidate) != Ordering::Greater => Some(best),
+            _ => Some(candidate),
+        }
+    })
+}
+
+/// Returns jobs which are assigned to different actors in the guiding solution, together with these
+/// actors.
+fn get_moves(initiating: &InsertionContext, guiding: &InsertionContext) -> Vec<(Job, Arc<Actor>)> {
+    let current = initiating
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.jobs().map(move |job| (job, route_ctx.route.actor.clone())))
+        .collect::<HashMap<_, _>>();
+    let locked = &initiating.solution.locked;
+
+    guiding
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route.tour.jobs().map(move |job| (job, route_ctx.route.actor.clone())))
+        .filter(|(job, _)| !locked.contains(job))
+        .filter(|(job, actor)| current.get(job).map_or(true, |current| current != actor))
+        .collect()
+}
+
+/// Inserts job into the route of given actor in the best position and removes it from its current
+/// route. Returns false if the insertion is not feasible.
+fn move_job(insertion_ctx: &mut InsertionContext, job: &Job, actor: &Arc<Actor>) -> bool {
+    let target = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .find(|route_ctx| route_ctx.route.actor == *actor)
+        .cloned()
+        .or_else(|| insertion_ctx.solution.registry.next_with_actor(actor.as_ref()));
+
+    let target = if let Some(target) = target { target } else { return false };
+
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &AllLegSelector::default(),
+        result_selector: &BestResultSelector::default(),
+    };
+
+    let success = match evaluate_job_insertion_in_route(
+        insertion_ctx,
+        &eval_ctx,
+        &target,
+        InsertionPosition::Any,
+        InsertionResult::make_failure(),
+    ) {
+        InsertionResult::Success(success) => success,
+        InsertionResult::Failure(_) => return false,
+    };
+
+    if let Some(source) = insertion_ctx.solution.routes.iter_mut().find(|route_ctx| route_ctx.route.tour.contains(job))
+    {
+        source.route_mut().tour.remove(job);
+        insertion_ctx.problem.constraint.accept_route_state(source);
+    }
+
+    apply_insertion_success(insertion_ctx, success);
+
+    true
+}
@@ -0,0 +1,205 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/route_minimization_test.rs"]
+mod route_minimization_test;
+
+use super::fleet_minimization::{release_unused_actors, remove_smallest_route};
+use super::local::apply_insertion;
+use crate::construction::heuristics::*;
+use crate::models::problem::{Job, ProblemObjective};
+use crate::solver::search::LocalOperator;
+use crate::solver::RefinementContext;
+use hashbrown::HashMap;
+use rand::prelude::SliceRandom;
+use rosomaxa::prelude::*;
+use rosomaxa::utils::map_reduce;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A heuristic operator which tries to reduce amount of used vehicles by emptying the smallest route
+/// and reinserting its jobs into remaining routes using ejection chains as described in "A powerful
+/// route minimization heuristic for the vehicle routing problem with time windows" by Yuichi Nagata,
+/// Olli Bräysy.
+///
+/// Jobs of the removed route are kept in ejection pool and reinserted one by one:
+/// - the cheapest feasible insertion is applied if it exists
+/// - otherwise, remaining routes are squeezed by local search to create a room for the job
+/// - otherwise, the job is inserted instead of a job from some route which is moved to the pool.
+///   Ejected jobs are selected by the least amount of their previous ejections to avoid cycling.
+///
+/// When the pool is empty, the next smallest route is removed. The operator is applied only at
+/// early stage of the search, so the solution converges to fewer vehicles before cost polishing.
+pub struct RouteMinimization {
+    squeeze: Arc<dyn LocalOperator + Send + Sync>,
+    max_iterations: usize,
+    max_progress: f64,
+}
+
+impl RouteMinimization {
+    /// Creates a new instance of `RouteMinimization`. `max_progress` specifies search progress
+    /// ratio (termination estimate) after which the operator does nothing.
+    pub fn new(squeeze: Arc<dyn LocalOperator + Send + Sync>, max_iterations: usize, max_progress: f64) -> Self {
+        Self { squeeze, max_iterations, max_progress }
+    }
+}
+
+impl HeuristicOperator for RouteMinimization {
+    type Context = RefinementContext;
+    type Objective = ProblemObjective;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+
+        let mut best = solution.deep_copy();
+
+        let has_unassigned = !solution.solution.unassigned.is_empty() || !solution.solution.required.is_empty();
+        if has_unassigned || refinement_ctx.statistics().termination_estimate > self.max_progress {
+            return best;
+        }
+
+        let mut current = solution.deep_copy();
+        let mut pool = match take_smallest_route(&mut current) {
+            Some(pool) => pool,
+            None => return best,
+        };
+        let mut ejections: HashMap<Job, usize> = HashMap::default();
+
+        for _ in 0..self.max_iterations {
+            let job = match pool.pop() {
+                Some(job) => job,
+                None => {
+                    finalize_insertion_ctx(&mut current);
+                    best = current.deep_copy();
+
+                    match take_smallest_route(&mut current) {
+                        Some(jobs) => {
+                            pool = jobs;
+                            continue;
+                        }
+                        None => break,
+                    }
+                }
+            };
+
+            if let Some(success) = find_insertion(&current, &job) {
+                apply_insertion(&mut current, success);
+                continue;
+            }
+
+            if let Some((squeezed, success)) = self
+                .squeeze
+                .explore(refinement_ctx, &current)
+                .and_then(|squeezed| find_insertion(&squeezed, &job).map(|success| (squeezed, success)))
+            {
+                current = squeezed;
+                apply_insertion(&mut current, success);
+                continue;
+            }
+
+            match find_ejection(&current, &job, &ejections) {
+                Some((ejected, success)) => {
+                    apply_insertion(&mut current, success);
+                    *ejections.entry(ejected.clone()).or_insert(0) += 1;
+                    pool.push(ejected);
+                }
+                None => break,
+            }
+        }
+
+        release_unused_actors(&mut best);
+
+        best
+    }
+}
+
+/// Removes the smallest route and returns its jobs in random order.
+fn take_smallest_route(insertion_ctx: &mut InsertionContext) -> Option<Vec<Job>> {
+    if remove_smallest_route(insertion_ctx) {
+        let mut jobs = insertion_ctx.solution.required.drain(0..).collect::<Vec<_>>();
+        jobs.shuffle(&mut insertion_ctx.environment.random.get_rng());
+
+        Some(jobs)
+    } else {
+        None
+    }
+}
+
+/// Finds the cheapest feasible insertion of the job into existing routes.
+fn find_insertion(insertion_ctx: &InsertionContext, job: &Job) -> Option<InsertionSuccess> {
+    insertion_ctx.solution.routes.iter().fold(None, |acc, route_ctx| {
+        reduce_success(acc, evaluate_insertion(insertion_ctx, route_ctx, job), |success| success.cost)
+    })
+}
+
+/// Finds a job which removal from its route allows to insert the given job there. Candidates are
+/// compared by amount of their previous ejections, then by insertion cost.
+fn find_ejection(
+    insertion_ctx: &InsertionContext,
+    job: &Job,
+    ejections: &HashMap<Job, usize>,
+) -> Option<(Job, InsertionSuccess)> {
+    let locked = &insertion_ctx.solution.locked;
+    let get_ejections = |job: &Job| ejections.get(job).cloned().unwrap_or(0);
+
+    let candidates = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .flat_map(|(route_idx, route_ctx)| {
+            route_ctx.route.tour.jobs().filter(|job| !locked.contains(job)).map(move |job| (route_idx, job))
+        })
+        .collect::<Vec<_>>();
+
+    map_reduce(
+        candidates.as_slice(),
+        |(route_idx, ejected)| {
+            let mut route_ctx = insertion_ctx.solution.routes[*route_idx].deep_copy();
+            route_ctx.route_mut().tour.remove(ejected);
+            insertion_ctx.problem.constraint.accept_route_state(&mut route_ctx);
+
+            evaluate_insertion(insertion_ctx, &route_ctx, job).map(|success| (ejected.clone(), success))
+        },
+        || None,
+        |left, right| reduce_success(left, right, |(ejected, success)| (get_ejections(ejected) as f64, success.cost)),
+    )
+}
+
+fn evaluate_insertion(
+    insertion_ctx: &InsertionContext,
+    route_ctx: &RouteContext,
+    job: &Job,
+) -> Option<InsertionSuccess> {
+    let eval_ctx = EvaluationContext {
+        constraint: &insertion_ctx.problem.constraint,
+        job,
+        leg_selector: &AllLegSelector::default(),
+        result_selector: &BestResultSelector::default(),
+    };
+
+    match evaluate_job_insertion_in_route(
+        insertion_ctx,
+        &eval_ctx,
+        route_ctx,
+        InsertionPosition::Any,
+        InsertionResult::make_failure(),
+    ) {
+        InsertionResult::Success(success) => Some(success),
+        InsertionResult::Failure(_) => None,
+    }
+}
+
+fn reduce_success<T, K, F>(left: Option<T>, right: Option<T>, key_fn: F) -> Option<T>
+where
+    K: PartialOrd,
+    F: Fn(&T) -> K,
+{
+    match (left, right) {
+        (Some(left), Some(right)) => match key_fn(&right).partial_cmp(&key_fn(&left)) {
+            Some(Ordering::Less) => Some(right),
+            _ => Some(left),
+        },
+        (left, None) => left,
+        (None, right) => right,
+    }
+}
@@ -28,6 +28,9 @@ pub use self::random_job_removal::RandomJobRemoval;
 
 mod worst_jobs_removal;
 pub use self::worst_jobs_removal::WorstJobRemoval;
+
+mod zone_removal;
+pub use self::zone_removal::{CoordinatesDimension, LocationCoordinates, ZoneRemoval};
 use crate::models::problem::{Actor, Job};
 use hashbrown::HashSet;
 
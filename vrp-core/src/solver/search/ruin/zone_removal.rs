@@ -0,0 +1,211 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/ruin/zone_removal_test.rs"]
+mod zone_removal_test;
+
+use super::*;
+use crate::construction::heuristics::InsertionContext;
+use crate::models::common::{DimenKey, Location, ValueDimension};
+use crate::models::problem::Job;
+use crate::models::Extras;
+use crate::solver::RefinementContext;
+use hashbrown::HashSet;
+use rosomaxa::prelude::*;
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+
+const COORDINATES_KEY: DimenKey<LocationCoordinates> = DimenKey::new("location_coordinates");
+
+/// Keeps planar coordinates of locations which are used by geometry aware search operators.
+pub struct LocationCoordinates {
+    coordinates: Vec<Option<(f64, f64)>>,
+}
+
+impl LocationCoordinates {
+    /// Creates a new instance of `LocationCoordinates` where coordinates are indexed by location.
+    pub fn new(coordinates: Vec<Option<(f64, f64)>>) -> Self {
+        Self { coordinates }
+    }
+
+    /// Returns coordinates of the location, if known.
+    pub fn get(&self, location: Location) -> Option<(f64, f64)> {
+        self.coordinates.get(location).cloned().flatten()
+    }
+}
+
+/// A trait to get or set location coordinates.
+pub trait CoordinatesDimension {
+    /// Sets location coordinates.
+    fn set_location_coordinates(&mut self, coordinates: LocationCoordinates) -> &mut Self;
+    /// Gets location coordinates.
+    fn get_location_coordinates(&self) -> Option<&LocationCoordinates>;
+}
+
+impl CoordinatesDimension for Extras {
+    fn set_location_coordinates(&mut self, coordinates: LocationCoordinates) -> &mut Self {
+        self.set_dimen(&COORDINATES_KEY, coordinates);
+        self
+    }
+
+    fn get_location_coordinates(&self) -> Option<&LocationCoordinates> {
+        self.get_dimen(&COORDINATES_KEY)
+    }
+}
+
+/// A ruin strategy which removes all jobs inside a random geographic zone (rectangle, circle or
+/// polygon) of the plan area. Zone size is defined relatively to the plan area and decreases with
+/// search progress. Requires location coordinates to be set in problem extras, otherwise, no job
+/// is removed.
+pub struct ZoneRemoval {
+    limits: RuinLimits,
+    max_ratio: f64,
+    min_ratio: f64,
+}
+
+impl ZoneRemoval {
+    /// Creates a new instance of `ZoneRemoval`. Zone size, as a ratio of plan area size, is decreased
+    /// from `max_ratio` to `min_ratio` with search progress.
+    pub fn new(limits: RuinLimits, max_ratio: f64, min_ratio: f64) -> Self {
+        assert!(min_ratio > 0. && min_ratio <= max_ratio);
+
+        Self { limits, max_ratio, min_ratio }
+    }
+
+    fn get_ratio(&self, progress: f64) -> f64 {
+        self.max_ratio - (self.max_ratio - self.min_ratio) * progress.clamp(0., 1.)
+    }
+}
+
+impl Default for ZoneRemoval {
+    fn default() -> Self {
+        Self::new(RuinLimits::new(8, 64, 0.2, 8), 0.3, 0.05)
+    }
+}
+
+impl Ruin for ZoneRemoval {
+    fn run(&self, refinement_ctx: &RefinementContext, mut insertion_ctx: InsertionContext) -> InsertionContext {
+        let problem = insertion_ctx.problem.clone();
+        let coordinates = match problem.extras.get_location_coordinates() {
+            Some(coordinates) => coordinates,
+            None => return insertion_ctx,
+        };
+
+        let locked = &insertion_ctx.solution.locked;
+        let points = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route.tour.all_activities())
+            .filter_map(|activity| activity.retrieve_job().map(|job| (job, activity.place.location)))
+            .filter(|(job, _)| !locked.contains(job))
+            .filter_map(|(job, location)| coordinates.get(location).map(|point| (job, point)))
+            .collect::<Vec<_>>();
+
+        if points.is_empty() {
+            return insertion_ctx;
+        }
+
+        let random = insertion_ctx.environment.random.clone();
+        let ratio = self.get_ratio(refinement_ctx.statistics().termination_estimate);
+        let (_, center) = points[random.uniform_int(0, points.len() as i32 - 1) as usize];
+        let zone = Zone::new_random(center, get_size(points.as_slice(), ratio), random.as_ref());
+
+        let mut candidates = points.into_iter().filter(|(_, point)| zone.contains(*point)).collect::<Vec<_>>();
+        candidates.sort_by(|(_, a), (_, b)| {
+            get_distance(*a, center).partial_cmp(&get_distance(*b, center)).unwrap_or(Ordering::Equal)
+        });
+
+        let tracker = self.limits.get_tracker();
+        let mut visited = HashSet::new();
+
+        candidates
+            .into_iter()
+            .filter(|(job, _)| visited.insert(job.clone()))
+            .take_while(|_| tracker.is_not_limit(self.limits.max_ruined_activities))
+            .for_each(|(job, _)| {
+                let route = insertion_ctx.solution.routes.iter_mut().find(|rc| rc.route.tour.contains(&job));
+
+                if let Some(rc) = route {
+                    rc.route_mut().tour.remove(&job);
+
+                    tracker.add_actor(rc.route.actor.clone());
+                    tracker.add_job(job);
+                }
+            });
+
+        tracker.iterate_removed_jobs(|job| insertion_ctx.solution.required.push(job.clone()));
+
+        insertion_ctx
+    }
+}
+
+/// A geometric zone used to select jobs.
+enum Zone {
+    Rectangle { center: (f64, f64), half_size: (f64, f64) },
+    Circle { center: (f64, f64), radius: f64 },
+    Polygon { vertices: Vec<(f64, f64)> },
+}
+
+impl Zone {
+    fn new_random(center: (f64, f64), size: (f64, f64), random: &(dyn Random + Send + Sync)) -> Self {
+        let half_size = (size.0 / 2., size.1 / 2.);
+        let radius = half_size.0.max(half_size.1);
+
+        match random.uniform_int(0, 2) {
+            0 => Zone::Rectangle { center, half_size },
+            1 => Zone::Circle { center, radius },
+            _ => {
+                // NOTE angles are spaced with jitter, so the gap between vertices is less than PI and
+                // the center is always inside the polygon
+                let count = random.uniform_int(4, 6) as usize;
+                let sector = 2. * PI / count as f64;
+
+                let vertices = (0..count)
+                    .map(|idx| {
+                        let angle = sector * idx as f64 + random.uniform_real(0., sector);
+                        let distance = radius * random.uniform_real(0.5, 1.);
+                        (center.0 + distance * angle.cos(), center.1 + distance * angle.sin())
+                    })
+                    .collect();
+
+                Zone::Polygon { vertices }
+            }
+        }
+    }
+
+    fn contains(&self, point: (f64, f64)) -> bool {
+        match self {
+            Zone::Rectangle { center, half_size } => {
+                (point.0 - center.0).abs() <= half_size.0 && (point.1 - center.1).abs() <= half_size.1
+            }
+            Zone::Circle { center, radius } => get_distance(point, *center) <= *radius,
+            Zone::Polygon { vertices } => {
+                // NOTE use ray casting algorithm
+                let (x, y) = point;
+                (0..vertices.len()).fold(false, |is_inside, idx| {
+                    let (xi, yi) = vertices[idx];
+                    let (xj, yj) = vertices[(idx + vertices.len() - 1) % vertices.len()];
+
+                    if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+                        !is_inside
+                    } else {
+                        is_inside
+                    }
+                })
+            }
+        }
+    }
+}
+
+/// Returns zone size using bounding box of given points.
+fn get_size(points: &[(Job, (f64, f64))], ratio: f64) -> (f64, f64) {
+    let (min_x, min_y, max_x, max_y) =
+        points.iter().fold((f64::MAX, f64::MAX, f64::MIN, f64::MIN), |(min_x, min_y, max_x, max_y), (_, (x, y))| {
+            (min_x.min(*x), min_y.min(*y), max_x.max(*x), max_y.max(*y))
+        });
+
+    ((max_x - min_x) * ratio, (max_y - min_y) * ratio)
+}
+
+fn get_distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+#[path = "../../tests/unit/utils/error_test.rs"]
+mod error_test;
+
+use std::fmt;
+
+/// Specifies an error which can happen when vrp problem is defined, solved or its solution is written.
+/// Display text contains only an error message, so it can be used as is in formatted error output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VrpError {
+    /// Problem definition is not valid.
+    Validation(String),
+    /// Routing matrix data is not valid.
+    Matrix(String),
+    /// Constraint, objective or solver configuration is not valid.
+    Config(String),
+    /// Reading or writing data has failed.
+    Io(String),
+}
+
+impl VrpError {
+    /// Returns an error message.
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Validation(message) | Self::Matrix(message) | Self::Config(message) | Self::Io(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for VrpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for VrpError {}
+
+impl From<VrpError> for String {
+    fn from(error: VrpError) -> Self {
+        error.to_string()
+    }
+}
@@ -3,8 +3,10 @@
 // Reimport rosomaxa utils
 pub use rosomaxa::utils::*;
 
+pub use self::error::VrpError;
 pub use self::mutability::*;
 pub use self::types::Either;
 
+mod error;
 mod mutability;
 mod types;
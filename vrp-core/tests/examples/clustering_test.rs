@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn can_create_clusters_in_clustering_example() {
+    let mut clusters = get_clusters(create_example_problem())
+        .into_iter()
+        .map(|mut cluster| {
+            cluster.sort();
+            cluster
+        })
+        .collect::<Vec<_>>();
+    clusters.sort();
+
+    assert_eq!(clusters, vec![vec!["job1", "job2", "job3", "job4"], vec!["job5", "job6", "job7", "job8"]]);
+}
+
+#[test]
+fn can_solve_clustering_example() {
+    let (_, solution, _) = solve_example().expect("cannot solve example");
+
+    assert!(solution.unassigned.is_empty());
+
+    let mut job_ids =
+        get_routes(&solution).into_iter().flat_map(|(_, job_ids)| job_ids.into_iter()).collect::<Vec<_>>();
+    job_ids.sort();
+
+    assert_eq!(job_ids, (1..=8).map(|idx| format!("job{}", idx)).collect::<Vec<_>>());
+}
@@ -0,0 +1,11 @@
+use super::*;
+
+#[test]
+fn can_solve_custom_constraint_example() {
+    let (_, solution, _) = solve_example().expect("cannot solve example");
+
+    assert!(solution.unassigned.is_empty());
+    get_routes(&solution).into_iter().filter(|(vehicle_id, _)| vehicle_id != "certified_1").for_each(|(_, job_ids)| {
+        assert!(job_ids.iter().all(|job_id| !["job2", "job4", "job6"].contains(&job_id.as_str())));
+    });
+}
@@ -0,0 +1,22 @@
+use super::*;
+use vrp_core::models::common::*;
+
+#[test]
+fn can_solve_heterogeneous_fleet_example() {
+    let (_, solution, _) = solve_example().expect("cannot solve example");
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 4);
+    solution.routes.iter().for_each(|route| {
+        let capacity: &SingleDimLoad = route.actor.vehicle.dimens.get_capacity().expect("no capacity");
+        let load = route
+            .tour
+            .all_activities()
+            .filter_map(|activity| activity.job.as_ref())
+            .filter_map(|single| single.dimens.get_demand())
+            .map(|demand: &Demand<SingleDimLoad>| demand.delivery.0.value)
+            .sum::<i32>();
+
+        assert!(load <= capacity.value);
+    });
+}
@@ -0,0 +1,16 @@
+use super::*;
+
+#[test]
+fn can_solve_multi_trip_example() {
+    let (_, solution, _) = solve_example().expect("cannot solve example");
+
+    assert!(solution.unassigned.is_empty());
+    assert_eq!(solution.routes.len(), 1);
+
+    let job_ids = get_routes(&solution).into_iter().flat_map(|(_, job_ids)| job_ids.into_iter()).collect::<Vec<_>>();
+    let trips =
+        job_ids.split(|job_id| job_id.starts_with("reload")).filter(|trip| !trip.is_empty()).collect::<Vec<_>>();
+
+    assert_eq!(trips.iter().map(|trip| trip.len()).sum::<usize>(), 6);
+    assert!(trips.iter().all(|trip| trip.len() <= 2));
+}
@@ -1,10 +1,10 @@
 use crate::construction::constraints::*;
-use crate::construction::heuristics::{ActivityContext, RouteState};
+use crate::construction::heuristics::{ActivityContext, RouteState, SolutionContext};
 use crate::helpers::construction::constraints::*;
 use crate::helpers::models::domain::create_empty_solution_context;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
-use crate::models::common::{Demand, DemandDimension, SingleDimLoad};
+use crate::models::common::{Demand, DemandDimension, IdDimension, SingleDimLoad};
 use crate::models::problem::{Job, Vehicle};
 use crate::models::solution::Activity;
 use std::sync::Arc;
@@ -185,3 +185,123 @@ fn can_merge_jobs_with_demand_impl(
         (Err(result), Err(expected)) => assert_eq!(result, expected),
     }
 }
+
+fn create_carried_load_solution_ctx(shifts: Vec<(&str, Vec<i32>)>) -> (SolutionContext, ConstraintPipeline) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(shifts.iter().map(|(id, _)| VehicleBuilder::default().id(id).capacity(10).build()).collect())
+        .build();
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes = shifts
+        .into_iter()
+        .filter(|(_, sizes)| !sizes.is_empty())
+        .map(|(id, sizes)| {
+            create_route_context_with_activities(
+                &fleet,
+                id,
+                sizes
+                    .into_iter()
+                    .map(|size| test_activity_with_job(test_single_with_simple_demand(create_simple_demand(size))))
+                    .collect(),
+            )
+        })
+        .collect();
+
+    let pipeline = create_constraint_pipeline_with_module(Arc::new(
+        CapacityConstraintModule::<SingleDimLoad>::new_with_carried_load(
+            TestActivityCost::new_shared(),
+            TestTransportCost::new_shared(),
+            2,
+            Arc::new(NoMultiTrip::default()),
+            Arc::new(|actor| {
+                let id = actor.vehicle.dimens.get_id().unwrap();
+                Some(("v".to_string(), id[1..].parse::<usize>().unwrap()))
+            }),
+        ),
+    ));
+    solution_ctx.routes.iter_mut().for_each(|route_ctx| pipeline.accept_route_state(route_ctx));
+    pipeline.accept_solution_state(&mut solution_ctx);
+
+    (solution_ctx, pipeline)
+}
+
+#[test]
+fn can_carry_load_to_next_shift() {
+    let (solution_ctx, _) =
+        create_carried_load_solution_ctx(vec![("v0", vec![4]), ("v1", vec![-3, 2]), ("v2", vec![1])]);
+
+    let get_carried = |idx: usize| {
+        solution_ctx.routes[idx].state.get_route_state::<SingleDimLoad>(CARRIED_LOAD_KEY).map_or(0, |load| load.value)
+    };
+    assert_eq!(get_carried(0), 0);
+    assert_eq!(get_carried(1), 4);
+    assert_eq!(get_carried(2), 6);
+
+    let route_ctx = &solution_ctx.routes[1];
+    let tour = &route_ctx.route.tour;
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, &route_ctx.state, tour.start()), 7);
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, &route_ctx.state, tour.get(1)), 4);
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, &route_ctx.state, tour.get(2)), 6);
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, &route_ctx.state, tour.end()), 6);
+}
+
+parameterized_test! {can_evaluate_carried_load_on_activity, (size, expected), {
+    can_evaluate_carried_load_on_activity_impl(size, expected);
+}}
+
+can_evaluate_carried_load_on_activity! {
+    case01: (3, None),
+    case02: (4, create_activity_violation(true)),
+    case03: (-3, None),
+}
+
+fn can_evaluate_carried_load_on_activity_impl(size: i32, expected: Option<ActivityConstraintViolation>) {
+    let (solution_ctx, pipeline) = create_carried_load_solution_ctx(vec![("v0", vec![4]), ("v1", vec![-3, 2])]);
+    let route_ctx = &solution_ctx.routes[0];
+    let target = test_activity_with_job(test_single_with_simple_demand(create_simple_demand(size)));
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route.tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(2),
+    };
+
+    let result = pipeline.evaluate_hard_activity(route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_carried_load_on_new_route, (size, expected), {
+    can_evaluate_carried_load_on_new_route_impl(size, expected);
+}}
+
+can_evaluate_carried_load_on_new_route! {
+    case01: (-2, None),
+    case02: (-3, Some(RouteConstraintViolation { code: 2 })),
+    case03: (1, None),
+    case04: (2, Some(RouteConstraintViolation { code: 2 })),
+}
+
+fn can_evaluate_carried_load_on_new_route_impl(size: i32, expected: Option<RouteConstraintViolation>) {
+    let (solution_ctx, pipeline) =
+        create_carried_load_solution_ctx(vec![("v0", vec![8]), ("v1", vec![]), ("v2", vec![1])]);
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(VehicleBuilder::default().id("v1").capacity(10).build())
+        .build();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let job = Job::Single(test_single_with_simple_demand(create_simple_demand(size)));
+
+    let result = pipeline.evaluate_hard_route(&solution_ctx, &route_ctx, &job);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_remove_jobs_from_shift_which_cannot_handle_carried_load() {
+    let (solution_ctx, _) = create_carried_load_solution_ctx(vec![("v0", vec![8]), ("v1", vec![5, -1])]);
+
+    assert_eq!(solution_ctx.routes[0].route.tour.job_count(), 1);
+    assert_eq!(solution_ctx.routes[1].route.tour.job_count(), 0);
+    assert_eq!(solution_ctx.required.len(), 2);
+}
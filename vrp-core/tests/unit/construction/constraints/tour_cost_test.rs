@@ -0,0 +1,62 @@
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext, RouteState};
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Cost, Location};
+use std::sync::Arc;
+
+fn create_route_ctx(total_cost: Option<Cost>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut state = RouteState::default();
+    state.put_route_state(TOTAL_DISTANCE_KEY, 25.);
+    state.put_route_state(TOTAL_DURATION_KEY, 25.);
+    if let Some(total_cost) = total_cost {
+        state.put_route_state(TOTAL_COST_KEY, total_cost);
+    }
+
+    RouteContext::new_with_state(Arc::new(create_route_with_activities(&fleet, "v1", vec![])), Arc::new(state))
+}
+
+parameterized_test! {can_limit_tour_cost, (total_cost, location, limit, expected), {
+    can_limit_tour_cost_impl(total_cost, location, limit, expected);
+}}
+
+can_limit_tour_cost! {
+    case01: (Some(100.), 55, Some(150.), None),
+    case02: (Some(100.), 55, Some(130.), Some(1)),
+    case03: (Some(100.), 55, None, None),
+    case04: (None, 55, Some(150.), None),
+    case05: (None, 60, Some(150.), Some(1)),
+}
+
+fn can_limit_tour_cost_impl(total_cost: Option<Cost>, location: Location, limit: Option<Cost>, expected: Option<i32>) {
+    let route_ctx = create_route_ctx(total_cost);
+    let pipeline = create_constraint_pipeline_with_module(Arc::new(TourCostModule::new(
+        Arc::new(move |_| limit),
+        TestTransportCost::new_shared(),
+        1,
+    )));
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: &test_activity_with_location(50),
+            target: &test_activity_with_location(location),
+            next: Some(&test_activity_with_location(50)),
+        },
+    );
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_track_tour_cost_in_route_state() {
+    let mut route_ctx = create_route_ctx(None);
+    let module = TourCostModule::new(Arc::new(|_| None), TestTransportCost::new_shared(), 1);
+
+    module.accept_route_state(&mut route_ctx);
+
+    assert_eq!(route_ctx.state.get_route_state::<Cost>(TOTAL_COST_KEY).cloned(), Some(100.));
+}
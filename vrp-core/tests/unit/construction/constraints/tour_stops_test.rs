@@ -0,0 +1,74 @@
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{ActivityContext, RouteContext};
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::Location;
+use std::sync::Arc;
+
+fn create_route_ctx(locations: Vec<Location>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let activities = locations.into_iter().map(test_activity_with_location).collect();
+
+    create_route_context_with_activities(&fleet, "v1", activities)
+}
+
+parameterized_test! {can_count_tour_stops, (locations, expected), {
+    can_count_tour_stops_impl(locations, expected);
+}}
+
+can_count_tour_stops! {
+    case01: (vec![], 0),
+    case02: (vec![10, 20], 2),
+    case03: (vec![10, 10, 20], 2),
+    case04: (vec![0, 10, 10], 2),
+    case05: (vec![10, 20, 10], 2),
+}
+
+fn can_count_tour_stops_impl(locations: Vec<Location>, expected: usize) {
+    let route_ctx = create_route_ctx(locations);
+
+    assert_eq!(get_tour_stop_locations(&route_ctx.route.tour).len(), expected);
+}
+
+parameterized_test! {can_limit_tour_stops, (locations, index, location, limit, expected), {
+    can_limit_tour_stops_impl(locations, index, location, limit, expected);
+}}
+
+can_limit_tour_stops! {
+    case01_same_as_next: (vec![10, 20], 0, 10, Some(2), None),
+    case02_same_as_prev: (vec![10, 20], 1, 10, Some(2), None),
+    case03_new_stop: (vec![10, 20], 1, 15, Some(2), Some(1)),
+    case04_new_stop_at_end: (vec![10, 20], 2, 30, Some(2), Some(1)),
+    case05_new_stop_in_limit: (vec![10, 20], 2, 30, Some(3), None),
+    case06_split_stop: (vec![10, 10], 1, 15, Some(1), Some(1)),
+    case07_split_stop_in_limit: (vec![10, 10], 1, 15, Some(2), None),
+    case08_no_limit: (vec![10, 20], 1, 15, None, None),
+    case09_visited_location: (vec![10, 20], 2, 10, Some(2), None),
+    case10_visited_location_in_between: (vec![10, 20, 30], 1, 30, Some(3), None),
+}
+
+fn can_limit_tour_stops_impl(
+    locations: Vec<Location>,
+    index: usize,
+    location: Location,
+    limit: Option<usize>,
+    expected: Option<i32>,
+) {
+    let mut route_ctx = create_route_ctx(locations);
+    let module = TourStopsModule::new(Arc::new(move |_| limit), 1);
+    module.accept_route_state(&mut route_ctx);
+    let pipeline = create_constraint_pipeline_with_module(Arc::new(module));
+
+    let result = pipeline.evaluate_hard_activity(
+        &route_ctx,
+        &ActivityContext {
+            index,
+            prev: route_ctx.route.tour.get(index).unwrap(),
+            target: &test_activity_with_location(location),
+            next: route_ctx.route.tour.get(index + 1),
+        },
+    );
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
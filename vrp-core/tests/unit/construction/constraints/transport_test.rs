@@ -3,7 +3,7 @@ use crate::construction::heuristics::*;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::models::common::*;
-use crate::models::problem::{VehicleDetail, VehiclePlace};
+use crate::models::problem::{SimpleActivityCost, VehicleDetail, VehiclePlace};
 use std::sync::Arc;
 
 type VehicleData = (Location, Location, Timestamp, Timestamp);
@@ -27,11 +27,11 @@ fn create_detail(
 }
 
 mod timing {
-    use super::super::{try_advance_departure_time, try_recede_departure_time};
+    use super::super::{get_latest_departure_time, try_advance_departure_time, try_recede_departure_time};
     use super::*;
     use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
     use crate::helpers::models::domain::{create_empty_solution_context, test_random};
-    use crate::models::problem::Vehicle;
+    use crate::models::problem::{Fleet, Vehicle};
     use crate::models::solution::{Activity, Place, Registry};
     use rosomaxa::prelude::compare_floats;
     use std::cmp::Ordering;
@@ -282,6 +282,165 @@ mod timing {
         }
     }
 
+    parameterized_test! {can_get_latest_departure_time, (latest, tws, expected), {
+        let tws = tws.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect::<Vec<_>>();
+        can_get_latest_departure_time_impl(latest, tws, expected);
+    }}
+
+    can_get_latest_departure_time! {
+        case01: (None, vec![(0., 100.), (25., 100.), (0., 100.)], Some(70.)),
+        case02: (Some(50.), vec![(0., 100.), (25., 100.), (0., 100.)], Some(50.)),
+        case03: (None, vec![(0., 15.), (25., 100.), (0., 100.)], Some(5.)),
+        case04: (None, vec![(0., 100.), (0., 100.), (0., 40.)], Some(10.)),
+    }
+
+    fn can_get_latest_departure_time_impl(latest: Option<f64>, tws: Vec<TimeWindow>, expected: Option<f64>) {
+        if let [tw1, tw2, tw3] = tws.as_slice() {
+            let fleet = FleetBuilder::default()
+                .add_driver(test_driver())
+                .add_vehicle(Vehicle {
+                    details: vec![VehicleDetail {
+                        start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(0.), latest } }),
+                        ..test_vehicle_detail()
+                    }],
+                    ..test_vehicle_with_id("v1")
+                })
+                .build();
+            let mut route_ctx = create_route_context_with_activities(
+                &fleet,
+                "v1",
+                vec![
+                    test_activity_with_location_and_tw(10, tw1.clone()),
+                    test_activity_with_location_and_tw(20, tw2.clone()),
+                    test_activity_with_location_and_tw(30, tw3.clone()),
+                ],
+            );
+            TransportConstraintModule::update_route_schedules(
+                &mut route_ctx,
+                &TestActivityCost::default(),
+                &TestTransportCost::default(),
+            );
+
+            let departure_time = get_latest_departure_time(route_ctx.route.as_ref());
+
+            assert_eq!(departure_time, expected);
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn create_fleet_with_departure_window(is_enabled: bool) -> Fleet {
+        let mut vehicle = Vehicle {
+            details: vec![VehicleDetail {
+                start: Some(VehiclePlace {
+                    location: 0,
+                    time: TimeInterval { earliest: Some(0.), latest: Some(100.) },
+                }),
+                ..test_vehicle_detail()
+            }],
+            ..test_vehicle_with_id("v1")
+        };
+        if is_enabled {
+            vehicle.dimens.set_dimen(&VEHICLE_DEPARTURE_WINDOW_KEY, true);
+        }
+
+        FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build()
+    }
+
+    parameterized_test! {can_search_departure_time_within_window, (is_enabled, departure, tw_start, expected), {
+        can_search_departure_time_within_window_impl(is_enabled, departure, tw_start, expected);
+    }}
+
+    can_search_departure_time_within_window! {
+        case01_delay_departure: (true, 0., 50., 40.),
+        case02_reset_departure: (true, 45., 20., 10.),
+        case03_keep_departure: (true, 0., 5., 0.),
+        case04_disabled_keep_departure: (false, 0., 50., 0.),
+        case05_disabled_keep_departure: (false, 45., 20., 45.),
+    }
+
+    fn can_search_departure_time_within_window_impl(is_enabled: bool, departure: f64, tw_start: f64, expected: f64) {
+        let fleet = create_fleet_with_departure_window(is_enabled);
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![test_activity_with_location_and_tw(10, TimeWindow::new(tw_start, 100.))],
+        );
+        route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = departure;
+
+        create_constraint_pipeline_with_transport().accept_route_state(&mut route_ctx);
+
+        assert_eq!(route_ctx.route.tour.start().unwrap().schedule.departure, expected);
+    }
+
+    parameterized_test! {can_use_earliest_departure_only_after_tour_start, (is_enabled, prev_index, expected), {
+        can_use_earliest_departure_only_after_tour_start_impl(is_enabled, prev_index, expected);
+    }}
+
+    can_use_earliest_departure_only_after_tour_start! {
+        case01_first_enabled: (true, 0, None),
+        case02_first_disabled: (false, 0, Some(ActivityConstraintViolation { code: 1, stopped: false })),
+        case03_second_enabled: (true, 1, Some(ActivityConstraintViolation { code: 1, stopped: false })),
+    }
+
+    fn can_use_earliest_departure_only_after_tour_start_impl(
+        is_enabled: bool,
+        prev_index: usize,
+        expected: Option<ActivityConstraintViolation>,
+    ) {
+        let fleet = create_fleet_with_departure_window(is_enabled);
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![test_activity_with_location_and_tw(10, TimeWindow::new(50., 100.))],
+        );
+        route_ctx.route_mut().tour.get_mut(0).unwrap().schedule.departure = 40.;
+        let pipeline = create_constraint_pipeline_with_transport();
+        pipeline.accept_route_state(&mut route_ctx);
+        assert_eq!(route_ctx.route.tour.start().unwrap().schedule.departure, 40.);
+
+        let prev = route_ctx.route.tour.get(prev_index).unwrap();
+        let target = test_activity_with_location_and_tw(5, TimeWindow::new(0., 20.));
+        let next = route_ctx.route.tour.get(prev_index + 1);
+        let activity_ctx = ActivityContext { index: prev_index, prev, target: &target, next };
+
+        let result = pipeline.evaluate_hard_activity(&route_ctx, &activity_ctx);
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_choose_cheapest_end_location, (job_location, candidates, expected), {
+        can_choose_cheapest_end_location_impl(job_location, candidates, expected);
+    }}
+
+    can_choose_cheapest_end_location! {
+        case01_closer_alternative: (10, vec![0, 12, 30], (12, 12.)),
+        case02_closer_default: (10, vec![0, 25], (0, 20.)),
+        case03_no_candidates: (10, vec![], (0, 20.)),
+    }
+
+    fn can_choose_cheapest_end_location_impl(
+        job_location: Location,
+        candidates: Vec<Location>,
+        expected: (Location, f64),
+    ) {
+        let mut vehicle = test_vehicle_with_id("v1");
+        if !candidates.is_empty() {
+            vehicle.dimens.set_dimen(&VEHICLE_END_LOCATIONS_KEY, candidates);
+        }
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+        let mut route_ctx = create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![test_activity_with_location_and_duration(job_location, 0.)],
+        );
+
+        create_constraint_pipeline_with_transport().accept_route_state(&mut route_ctx);
+
+        let end = route_ctx.route.tour.end().unwrap();
+        assert_eq!((end.place.location, end.schedule.arrival), expected);
+    }
+
     parameterized_test! {can_recede_departure_time, (earliest, start_departure, latest_first_arrival, tw, duration_limit, expected), {
         can_recede_departure_time_impl(earliest, start_departure, latest_first_arrival, TimeWindow::new(tw.0, tw.1), duration_limit, expected);
     }}
@@ -431,13 +590,14 @@ mod traveling {
 mod time_dependent {
     use super::*;
     use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
-    use crate::models::problem::{DynamicActivityCost, DynamicTransportCost};
+    use crate::models::problem::{DynamicActivityCost, DynamicTransportCost, ReservedTimeSpan};
     use hashbrown::HashMap;
 
     fn create_constraint_pipeline_and_route(
         vehicle_detail_data: VehicleData,
         activities: Vec<ActivityData>,
         reserved_time: TimeWindow,
+        can_interrupt: bool,
     ) -> (ConstraintPipeline, RouteContext) {
         let (location_start, location_end, time_start, time_end) = vehicle_detail_data;
 
@@ -459,7 +619,8 @@ mod time_dependent {
             .actors
             .first()
             .map(|actor| {
-                vec![(actor.clone(), vec![TimeSpan::Window(reserved_time)])].into_iter().collect::<HashMap<_, _>>()
+                let reserved_time = ReservedTimeSpan { time: TimeSpan::Window(reserved_time), can_interrupt };
+                vec![(actor.clone(), vec![reserved_time])].into_iter().collect::<HashMap<_, _>>()
             })
             .unwrap();
         let route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
@@ -468,7 +629,7 @@ mod time_dependent {
             Arc::new(
                 DynamicTransportCost::new(reserved_times.clone(), Arc::new(TestTransportCost::default())).unwrap(),
             ),
-            Arc::new(DynamicActivityCost::new(reserved_times).unwrap()),
+            Arc::new(DynamicActivityCost::new(reserved_times, Arc::new(SimpleActivityCost::default())).unwrap()),
             Arc::new(|_| (None, None)),
             1,
             2,
@@ -526,7 +687,7 @@ mod time_dependent {
         expected_schedules: Vec<(Timestamp, Timestamp)>,
     ) {
         let (pipeline, mut route_ctx) =
-            create_constraint_pipeline_and_route(vehicle_detail_data, activities, reserved_time);
+            create_constraint_pipeline_and_route(vehicle_detail_data, activities, reserved_time, true);
         pipeline.accept_route_state(&mut route_ctx);
 
         let schedules = get_schedules(&route_ctx);
@@ -536,6 +697,33 @@ mod time_dependent {
         assert_eq!(late_arrival_result, late_arrival_expected);
     }
 
+    parameterized_test! {can_update_state_for_non_interrupting_reserved_time, (reserved_time, activities, expected_schedules), {
+        let reserved_time =  TimeWindow::new(reserved_time.0, reserved_time.1);
+        can_update_state_for_non_interrupting_reserved_time_impl(reserved_time, activities, expected_schedules);
+    }}
+
+    can_update_state_for_non_interrupting_reserved_time! {
+        case01_inside_service: ((25., 30.), vec![(20, (0., 100.), 10.)], vec![(0., 0.), (20., 40.), (60., 60.)]),
+        case02_inside_waiting: ((25., 30.), vec![(20, (35., 100.), 10.)], vec![(0., 0.), (20., 45.), (65., 65.)]),
+        case03_overlaps_waiting: ((30., 40.), vec![(20, (35., 100.), 10.)], vec![(0., 0.), (20., 50.), (70., 70.)]),
+        case04_two_inside_service: ((35., 40.), vec![(10, (0., 20.), 10.), (20, (0., 50.), 10.)],
+                                    vec![(0., 0.), (10., 20.), (30., 50.), (70., 70.)]),
+    }
+
+    fn can_update_state_for_non_interrupting_reserved_time_impl(
+        reserved_time: TimeWindow,
+        activities: Vec<ActivityData>,
+        expected_schedules: Vec<(Timestamp, Timestamp)>,
+    ) {
+        let (pipeline, mut route_ctx) =
+            create_constraint_pipeline_and_route((0, 0, 0., 100.), activities, reserved_time, false);
+        pipeline.accept_route_state(&mut route_ctx);
+
+        let schedules = get_schedules(&route_ctx);
+
+        assert_eq!(schedules, expected_schedules);
+    }
+
     parameterized_test! {can_evaluate_activity, (vehicle_detail_data, reserved_time, target, activities, expected_schedules), {
         let reserved_time =  TimeWindow::new(reserved_time.0, reserved_time.1);
         can_evaluate_activity_impl(vehicle_detail_data, reserved_time, target, activities, expected_schedules);
@@ -605,7 +793,7 @@ mod time_dependent {
         expected_schedules: Vec<(Timestamp, Timestamp)>,
     ) {
         let (pipeline, mut route_ctx) =
-            create_constraint_pipeline_and_route(vehicle_detail_data, activities, reserved_time);
+            create_constraint_pipeline_and_route(vehicle_detail_data, activities, reserved_time, true);
         pipeline.accept_route_state(&mut route_ctx);
         let (loc, (start, end), dur) = target;
         let prev = route_ctx.route.tour.get(0).unwrap();
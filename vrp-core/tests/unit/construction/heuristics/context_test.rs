@@ -1,5 +1,10 @@
-use crate::construction::heuristics::RouteState;
+use crate::construction::constraints::WAITING_KEY;
+use crate::construction::heuristics::{InsertionContext, RouteState};
 use crate::helpers::models::solution::{create_empty_route_ctx, test_activity};
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use rosomaxa::population::{RosomaxaCompressible, RosomaxaWeighted};
+use rosomaxa::prelude::*;
+use std::sync::Arc;
 
 #[test]
 fn can_put_and_get_activity_state() {
@@ -94,3 +99,40 @@ fn can_use_stale_flag() {
     assert!(route_ctx_clone.is_stale());
     assert!(!route_ctx_fork.is_stale());
 }
+
+#[test]
+fn can_compress_and_restore_insertion_context() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+    let get_activity_states = |insertion_ctx: &InsertionContext| {
+        insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| {
+                route_ctx
+                    .route
+                    .tour
+                    .all_activities()
+                    .filter_map(move |activity| route_ctx.state.get_activity_state::<f64>(WAITING_KEY, activity))
+            })
+            .count()
+    };
+    let fitness = insertion_ctx.get_fitness().collect::<Vec<_>>();
+    let weights = insertion_ctx.weights();
+    assert!(get_activity_states(&insertion_ctx) > 0);
+
+    RosomaxaCompressible::compress(&mut insertion_ctx);
+
+    assert_eq!(get_activity_states(&insertion_ctx), 0);
+    assert_eq!(insertion_ctx.get_fitness().collect::<Vec<_>>(), fitness);
+    assert_eq!(insertion_ctx.weights(), weights);
+
+    let restored_ctx = insertion_ctx.deep_copy();
+
+    assert!(restored_ctx.get_compressed_weights().is_none());
+    assert!(get_activity_states(&restored_ctx) > 0);
+    assert_eq!(restored_ctx.get_fitness().collect::<Vec<_>>(), fitness);
+    assert_eq!(restored_ctx.weights(), weights);
+}
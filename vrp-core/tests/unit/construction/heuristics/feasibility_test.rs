@@ -0,0 +1,109 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::models::common::{IdDimension, TimeInterval, TimeWindow};
+use crate::models::problem::{Vehicle, VehicleDetail, VehiclePlace};
+use crate::models::solution::Registry;
+use rosomaxa::prelude::Environment;
+
+fn create_vehicle_with_shift_end(id: &str, end: f64) -> Vehicle {
+    Vehicle {
+        details: vec![VehicleDetail {
+            end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(end) } }),
+            ..test_vehicle_detail()
+        }],
+        ..test_vehicle_with_id(id)
+    }
+}
+
+fn get_actor_ids(actors: &[Arc<Actor>]) -> Vec<String> {
+    let mut ids = actors.iter().map(|actor| actor.vehicle.dimens.get_id().unwrap().clone()).collect::<Vec<_>>();
+    ids.sort();
+
+    ids
+}
+
+parameterized_test! {can_get_feasible_actors, (location, tw, expected), {
+    can_get_feasible_actors_impl(location, tw, expected);
+}}
+
+can_get_feasible_actors! {
+    case01: (10, (0., 100.), vec!["v1", "v2"]),
+    case02: (30, (0., 100.), vec!["v1"]),
+    case03: (10, (200., 300.), vec!["v1"]),
+    case04: (10, (2000., 3000.), vec![]),
+}
+
+fn can_get_feasible_actors_impl(location: usize, tw: (f64, f64), expected: Vec<&str>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_vehicle_with_shift_end("v1", 1000.))
+        .add_vehicle(create_vehicle_with_shift_end("v2", 50.))
+        .build();
+    let registry = Registry::new(&fleet, Environment::default().random);
+    let insertion_ctx = create_insertion_context(registry, create_constraint_pipeline_with_transport(), vec![]);
+    let job =
+        SingleBuilder::default().location(Some(location)).times(vec![TimeWindow::new(tw.0, tw.1)]).build_as_job_ref();
+
+    let actors = get_feasible_actors(&insertion_ctx, &job);
+
+    assert_eq!(get_actor_ids(actors.as_slice()), expected);
+}
+
+#[test]
+fn can_get_feasible_actors_per_job() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_vehicle_with_shift_end("v1", 1000.))
+        .add_vehicle(create_vehicle_with_shift_end("v2", 50.))
+        .build();
+    let registry = Registry::new(&fleet, Environment::default().random);
+    let mut insertion_ctx = create_insertion_context(registry, create_constraint_pipeline_with_transport(), vec![]);
+    insertion_ctx.solution.required = vec![
+        SingleBuilder::default().id("job1").location(Some(10)).build_as_job_ref(),
+        SingleBuilder::default().id("job2").location(Some(30)).build_as_job_ref(),
+    ];
+
+    let result = get_feasible_actors_per_job(&insertion_ctx)
+        .into_iter()
+        .map(|(job, actors)| (get_job_id(&job).clone(), get_actor_ids(actors.as_slice())))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        result,
+        vec![
+            ("job1".to_string(), vec!["v1".to_string(), "v2".to_string()]),
+            ("job2".to_string(), vec!["v1".to_string()])
+        ]
+    );
+}
+
+parameterized_test! {can_get_failed_actors, (location, expected), {
+    can_get_failed_actors_impl(location, expected);
+}}
+
+can_get_failed_actors! {
+    case01: (10, vec![]),
+    case02: (30, vec![("v2", 1)]),
+    case03: (600, vec![("v1", 1), ("v2", 1)]),
+}
+
+fn can_get_failed_actors_impl(location: usize, expected: Vec<(&str, i32)>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_vehicle_with_shift_end("v1", 1000.))
+        .add_vehicle(create_vehicle_with_shift_end("v2", 50.))
+        .build();
+    let registry = Registry::new(&fleet, Environment::default().random);
+    let insertion_ctx = create_insertion_context(registry, create_constraint_pipeline_with_transport(), vec![]);
+    let job = SingleBuilder::default().location(Some(location)).build_as_job_ref();
+
+    let mut result = get_failed_actors(&insertion_ctx, &job)
+        .into_iter()
+        .map(|(actor, code)| (actor.vehicle.dimens.get_id().unwrap().clone(), code))
+        .collect::<Vec<_>>();
+    result.sort();
+
+    assert_eq!(result, expected.into_iter().map(|(id, code)| (id.to_string(), code)).collect::<Vec<_>>());
+}
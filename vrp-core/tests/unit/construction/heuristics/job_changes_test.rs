@@ -0,0 +1,54 @@
+use super::*;
+use crate::helpers::models::domain::{get_customer_ids_from_jobs, get_customer_ids_from_routes};
+use crate::helpers::solver::*;
+use rosomaxa::prelude::Environment;
+
+fn create_insertion_ctx(jobs_order: Vec<&str>) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 1, false);
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+    rearrange_jobs_in_routes(&mut insertion_ctx, &[jobs_order]);
+
+    insertion_ctx
+}
+
+#[test]
+fn can_remove_cancelled_jobs_and_return_slack() {
+    let mut insertion_ctx = create_insertion_ctx(vec!["c0", "c3", "c1", "c2", "c4"]);
+    let cancelled = get_jobs_by_ids(&insertion_ctx, &["c3"]);
+
+    let slacks = apply_job_changes(&mut insertion_ctx, &JobChanges { cancelled, no_show: vec![] });
+
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), vec![vec!["c0", "c1", "c2", "c4"]]);
+    assert_eq!(get_customer_ids_from_jobs(insertion_ctx.solution.ignored.as_slice()), vec!["c3"]);
+    assert_eq!(slacks, vec![RouteSlack { route_idx: 0, activity_idx: 2, duration: 4. }]);
+    assert_eq!(insertion_ctx.solution.routes[0].route.tour.get(2).unwrap().schedule.arrival, 1.);
+}
+
+#[test]
+fn can_keep_no_show_jobs_in_tour() {
+    let mut insertion_ctx = create_insertion_ctx(vec!["c0", "c1", "c2", "c3", "c4"]);
+    let no_show = get_jobs_by_ids(&insertion_ctx, &["c2"]);
+
+    let slacks = apply_job_changes(&mut insertion_ctx, &JobChanges { cancelled: vec![], no_show: no_show.clone() });
+
+    assert!(slacks.is_empty());
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), vec![vec!["c0", "c1", "c2", "c3", "c4"]]);
+    assert!(insertion_ctx.solution.locked.contains(&no_show[0]));
+    let no_show_jobs = get_no_show_jobs(&insertion_ctx);
+    assert_eq!(no_show_jobs.len(), 1);
+    assert!(no_show_jobs.contains(&no_show[0]));
+}
+
+#[test]
+fn can_ignore_no_show_when_job_is_cancelled() {
+    let mut insertion_ctx = create_insertion_ctx(vec!["c0", "c1", "c2", "c3", "c4"]);
+    let jobs = get_jobs_by_ids(&insertion_ctx, &["c4"]);
+
+    let slacks = apply_job_changes(&mut insertion_ctx, &JobChanges { cancelled: jobs.clone(), no_show: jobs });
+
+    assert_eq!(slacks, vec![RouteSlack { route_idx: 0, activity_idx: 5, duration: 2. }]);
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), vec![vec!["c0", "c1", "c2", "c3"]]);
+    assert!(insertion_ctx.solution.locked.is_empty());
+    assert!(get_no_show_jobs(&insertion_ctx).is_empty());
+}
@@ -0,0 +1,279 @@
+use super::*;
+use crate::construction::constraints::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::models::domain::{get_customer_id, get_customer_ids_from_routes};
+use crate::helpers::models::problem::test_single_with_id_and_location;
+use crate::helpers::solver::*;
+use crate::models::common::{TimeSpan, TimeWindow};
+use crate::models::Problem;
+use rosomaxa::prelude::Environment;
+use std::slice::Iter;
+
+const ASSIGNED_JOBS_KEY: i32 = 1000;
+
+/// Keeps amount of assigned jobs in solution state and rejects insertions when it is stale. Optionally,
+/// unassigns a follower job when its leader job is not the first one in the route.
+struct AssignedJobsModule {
+    follower: Option<(&'static str, &'static str)>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl AssignedJobsModule {
+    fn new(follower: Option<(&'static str, &'static str)>) -> Self {
+        Self {
+            follower,
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(AssignedJobsHardRouteConstraint {}))],
+            keys: vec![ASSIGNED_JOBS_KEY],
+        }
+    }
+}
+
+impl ConstraintModule for AssignedJobsModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        if let Some((leader, follower)) = self.follower {
+            let mut removed = vec![];
+            ctx.routes.iter_mut().for_each(|route_ctx| {
+                let jobs = route_ctx.route.tour.all_activities().filter_map(|a| a.retrieve_job()).collect::<Vec<_>>();
+                let follower = jobs.iter().find(|job| get_customer_id(job) == follower);
+                let is_led = jobs.first().map_or(false, |job| get_customer_id(job) == leader);
+
+                if let Some(follower) = follower.filter(|_| !is_led) {
+                    route_ctx.route_mut().tour.remove(follower);
+                    removed.push(follower.clone());
+                }
+            });
+            ctx.unassigned.extend(removed.into_iter().map(|job| (job, 1)));
+        }
+
+        let assigned = get_assigned_count(ctx);
+        ctx.state.insert(ASSIGNED_JOBS_KEY, Arc::new(assigned));
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct AssignedJobsHardRouteConstraint {}
+
+impl HardRouteConstraint for AssignedJobsHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        _: &RouteContext,
+        _: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let assigned = solution_ctx.state.get(&ASSIGNED_JOBS_KEY).and_then(|value| value.downcast_ref::<usize>());
+
+        match assigned {
+            Some(&assigned) if assigned != get_assigned_count(solution_ctx) => {
+                Some(RouteConstraintViolation { code: 1 })
+            }
+            _ => None,
+        }
+    }
+}
+
+fn get_assigned_count(solution_ctx: &SolutionContext) -> usize {
+    solution_ctx.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum()
+}
+
+fn create_solution_editor(rows: usize, cols: usize) -> SolutionEditor {
+    create_solution_editor_with_module(rows, cols, None)
+}
+
+fn create_solution_editor_with_module(rows: usize, cols: usize, module: Option<AssignedJobsModule>) -> SolutionEditor {
+    let (problem, solution) = generate_matrix_routes(
+        rows,
+        cols,
+        false,
+        |id, location| {
+            let mut single = test_single_with_id_and_location(id, location);
+            // NOTE job c1 can be served only at the beginning of the first tour
+            if id == "c1" {
+                Arc::get_mut(&mut single).unwrap().places[0].times = vec![TimeSpan::Window(TimeWindow::new(0., 1.))];
+            }
+            single
+        },
+        |v| v,
+        |data| (data.clone(), data),
+    );
+
+    let problem = if let Some(module) = module {
+        let mut constraint = create_constraint_pipeline_with_transport();
+        constraint.add_module(Arc::new(module));
+        Problem { constraint: Arc::new(constraint), ..problem }
+    } else {
+        problem
+    };
+
+    SolutionEditor::new(InsertionContext::new_from_solution(
+        Arc::new(problem),
+        (solution, None),
+        Arc::new(Environment::default()),
+    ))
+}
+
+fn get_job(editor: &SolutionEditor, job_id: &str) -> Job {
+    get_jobs_by_ids(editor.insertion_ctx(), &[job_id]).pop().unwrap()
+}
+
+fn get_actor(editor: &SolutionEditor, route_idx: usize) -> Arc<Actor> {
+    editor.insertion_ctx().solution.routes[route_idx].route.actor.clone()
+}
+
+#[test]
+fn can_move_job_within_route() {
+    let mut editor = create_solution_editor(5, 1);
+    let original_cost = editor.get_cost();
+    let (job, actor) = (get_job(&editor, "c2"), get_actor(&editor, 0));
+
+    let result = editor.move_job(&job, &actor, 0);
+
+    assert_eq!(get_customer_ids_from_routes(editor.insertion_ctx()), vec![vec!["c2", "c0", "c1", "c3", "c4"]]);
+    assert_eq!(result, Ok(editor.get_cost()));
+    assert!(editor.get_cost() > original_cost);
+}
+
+#[test]
+fn can_move_job_to_another_route() {
+    let mut editor = create_solution_editor(2, 2);
+    let (job, actor) = (get_job(&editor, "c3"), get_actor(&editor, 0));
+
+    let result = editor.move_job(&job, &actor, 0);
+
+    assert!(result.is_ok());
+    assert_eq!(get_customer_ids_from_routes(editor.insertion_ctx()), vec![vec!["c3", "c0", "c1"], vec!["c2"]]);
+}
+
+#[test]
+fn can_reject_infeasible_move() {
+    let mut editor = create_solution_editor(5, 1);
+    let original_cost = editor.get_cost();
+    let (job, actor) = (get_job(&editor, "c1"), get_actor(&editor, 0));
+
+    let result = editor.move_job(&job, &actor, 4);
+
+    assert!(matches!(result, Err(EditError::Constraint(_))));
+    assert_eq!(get_customer_ids_from_routes(editor.insertion_ctx()), vec![vec!["c0", "c1", "c2", "c3", "c4"]]);
+    assert_eq!(editor.get_cost(), original_cost);
+}
+
+#[test]
+fn can_reject_invalid_position() {
+    let mut editor = create_solution_editor(2, 1);
+    let (job, actor) = (get_job(&editor, "c0"), get_actor(&editor, 0));
+
+    let result = editor.move_job(&job, &actor, 5);
+
+    assert_eq!(result, Err(EditError::InvalidPosition));
+}
+
+#[test]
+fn can_reject_locked_job() {
+    let mut editor = create_solution_editor(2, 1);
+    let (job, actor) = (get_job(&editor, "c0"), get_actor(&editor, 0));
+    let mut insertion_ctx = editor.into_insertion_ctx();
+    insertion_ctx.solution.locked.insert(job.clone());
+    editor = SolutionEditor::new(insertion_ctx);
+
+    assert_eq!(editor.move_job(&job, &actor, 1), Err(EditError::LockedJob));
+    assert_eq!(editor.remove_job(&job), Err(EditError::LockedJob));
+}
+
+parameterized_test! {can_swap_jobs, (rows, cols, first, second, expected), {
+    can_swap_jobs_impl(rows, cols, first, second, expected);
+}}
+
+can_swap_jobs! {
+    case01_same_route: (5, 1, "c2", "c4", vec![vec!["c0", "c1", "c4", "c3", "c2"]]),
+    case02_same_route_reversed: (5, 1, "c4", "c2", vec![vec!["c0", "c1", "c4", "c3", "c2"]]),
+    case03_same_route_adjacent: (5, 1, "c2", "c3", vec![vec!["c0", "c1", "c3", "c2", "c4"]]),
+    case04_different_routes: (2, 2, "c0", "c3", vec![vec!["c3", "c1"], vec!["c2", "c0"]]),
+}
+
+fn can_swap_jobs_impl(rows: usize, cols: usize, first: &str, second: &str, expected: Vec<Vec<&str>>) {
+    let mut editor = create_solution_editor(rows, cols);
+    let (first, second) = (get_job(&editor, first), get_job(&editor, second));
+
+    let result = editor.swap_jobs(&first, &second);
+
+    assert_eq!(result, Ok(editor.get_cost()));
+    assert_eq!(get_customer_ids_from_routes(editor.insertion_ctx()), expected);
+}
+
+#[test]
+fn can_remove_job() {
+    let mut editor = create_solution_editor(2, 2);
+    let job = get_job(&editor, "c2");
+
+    let result = editor.remove_job(&job);
+
+    assert_eq!(result, Ok(editor.get_cost()));
+    assert_eq!(get_customer_ids_from_routes(editor.insertion_ctx()), vec![vec!["c0", "c1"], vec!["c3"]]);
+    assert!(editor.insertion_ctx().solution.unassigned.contains_key(&job));
+    assert_eq!(editor.remove_job(&job), Err(EditError::UnassignedJob));
+}
+
+#[test]
+fn can_move_unassigned_job_back() {
+    let mut editor = create_solution_editor(2, 1);
+    let (job, actor) = (get_job(&editor, "c1"), get_actor(&editor, 0));
+    editor.remove_job(&job).unwrap();
+
+    let result = editor.move_job(&job, &actor, 1);
+
+    assert_eq!(result, Ok(editor.get_cost()));
+    assert_eq!(get_customer_ids_from_routes(editor.insertion_ctx()), vec![vec!["c0", "c1"]]);
+    assert!(editor.insertion_ctx().solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_remove_job_with_edit_removal_code() {
+    let mut editor = create_solution_editor(2, 1);
+    let job = get_job(&editor, "c1");
+
+    editor.remove_job(&job).unwrap();
+
+    assert_eq!(editor.insertion_ctx().solution.unassigned.get(&job), Some(&EDIT_REMOVAL_CODE));
+}
+
+#[test]
+fn can_use_updated_solution_state_when_job_is_moved() {
+    let mut editor = create_solution_editor_with_module(3, 2, Some(AssignedJobsModule::new(None)));
+    let (job, actor) = (get_job(&editor, "c4"), get_actor(&editor, 0));
+
+    let result = editor.move_job(&job, &actor, 1);
+
+    assert_eq!(result, Ok(editor.get_cost()));
+    assert_eq!(
+        get_customer_ids_from_routes(editor.insertion_ctx()),
+        vec![vec!["c0", "c4", "c1", "c2"], vec!["c3", "c5"]]
+    );
+}
+
+#[test]
+fn can_reject_edit_which_unassigns_other_jobs() {
+    let mut editor = create_solution_editor_with_module(3, 1, Some(AssignedJobsModule::new(Some(("c0", "c1")))));
+    let original_cost = editor.get_cost();
+    let (job, actor) = (get_job(&editor, "c0"), get_actor(&editor, 0));
+
+    let result = editor.move_job(&job, &actor, 1);
+
+    assert_eq!(result, Err(EditError::UnassignedOtherJobs));
+    assert_eq!(get_customer_ids_from_routes(editor.insertion_ctx()), vec![vec!["c0", "c1", "c2"]]);
+    assert_eq!(editor.get_cost(), original_cost);
+}
@@ -49,3 +49,20 @@ mod time_window {
         assert_eq!(time.duration(), expected);
     }
 }
+
+mod dimensions {
+    use super::*;
+
+    const TEST_KEY: DimenKey<usize> = DimenKey::new("test");
+
+    #[test]
+    fn can_use_typed_dimension_key() {
+        let mut dimens = Dimensions::default();
+
+        dimens.set_dimen(&TEST_KEY, 42_usize);
+
+        assert_eq!(dimens.get_dimen(&TEST_KEY), Some(&42));
+        assert_eq!(dimens.get_value::<usize>(TEST_KEY.name()), Some(&42));
+        assert!(dimens.get_value::<String>(TEST_KEY.name()).is_none());
+    }
+}
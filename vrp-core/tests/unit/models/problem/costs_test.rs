@@ -1,5 +1,14 @@
 use super::*;
-use crate::helpers::models::solution::{create_empty_route_ctx, test_actor_with_profile};
+use crate::helpers::models::problem::{
+    empty_costs, test_driver_with_costs, test_fleet, test_single_with_id, test_vehicle, DEFAULT_ACTOR_LOCATION,
+};
+use crate::helpers::models::solution::{
+    create_empty_route_ctx, create_route_context_with_activities, create_route_with_activities, test_activity_with_job,
+    test_activity_with_location, test_activity_with_location_and_tw, test_activity_with_location_tw_and_duration,
+    test_actor, test_actor_with_profile,
+};
+use crate::models::problem::{Costs, Vehicle};
+use crate::models::solution::{Commute, CommuteInfo};
 
 fn create_matrix_data(
     profile: Profile,
@@ -23,7 +32,7 @@ fn can_detect_dimensions_mismatch() {
             create_matrix_data(Profile::default(), Some(1.), (0., 1), (0., 2)),
         ])
         .err(),
-        Some("distance and duration collections have different length".to_string())
+        Some(VrpError::Matrix("distance and duration collections have different length".to_string()))
     );
 }
 
@@ -33,8 +42,12 @@ fn can_return_error_when_mixing_timestamps() {
     let p1 = Profile::new(1, None);
 
     assert_eq!(
-        TimeAwareMatrixTransportCost::new(vec![create_matrix_data(Profile::default(), None, (0., 1), (0., 1))], 1)
-            .err(),
+        TimeAwareMatrixTransportCost::new(
+            vec![create_matrix_data(Profile::default(), None, (0., 1), (0., 1))],
+            1,
+            MatrixStorage::Full
+        )
+        .err(),
         Some("time-aware routing requires all matrices to have timestamp".to_string())
     );
 
@@ -45,13 +58,19 @@ fn can_return_error_when_mixing_timestamps() {
                 create_matrix_data(p0.clone(), None, (0., 1), (0., 1))
             ],
             1,
+            MatrixStorage::Full,
         )
         .err(),
         Some("time-aware routing requires all matrices to have timestamp".to_string())
     );
 
     assert_eq!(
-        TimeAwareMatrixTransportCost::new(vec![create_matrix_data(p0.clone(), Some(0.), (0., 1), (0., 1))], 1).err(),
+        TimeAwareMatrixTransportCost::new(
+            vec![create_matrix_data(p0.clone(), Some(0.), (0., 1), (0., 1))],
+            1,
+            MatrixStorage::Full
+        )
+        .err(),
         Some("should not use time aware matrix routing with single matrix".to_string())
     );
 
@@ -63,6 +82,7 @@ fn can_return_error_when_mixing_timestamps() {
                 create_matrix_data(p1, Some(0.), (1., 1), (1., 1)),         //
             ],
             1,
+            MatrixStorage::Full,
         )
         .err(),
         Some("should not use time aware matrix routing with single matrix".to_string())
@@ -84,6 +104,7 @@ fn can_interpolate_durations() {
             create_matrix_data(p1.clone(), Some(10.), (400., 2), (5., 2)),
         ],
         2,
+        MatrixStorage::Full,
     )
     .unwrap();
 
@@ -102,6 +123,240 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
 }
 
+fn create_time_of_day_index(profile: usize, intervals: Vec<(f64, f64, f64)>) -> TimeOfDayIndex {
+    let multipliers = intervals.into_iter().map(|(start, end, factor)| TimeOfDayMultiplier { start, end, factor });
+
+    vec![(profile, multipliers.collect())].into_iter().collect()
+}
+
+parameterized_test! {can_apply_time_of_day_multipliers, (travel_time, expected), {
+    can_apply_time_of_day_multipliers_impl(travel_time, expected);
+}}
+
+can_apply_time_of_day_multipliers! {
+    case01_before: (TravelTime::Departure(6. * 3600.), 100.),
+    case02_within: (TravelTime::Departure(7. * 3600.), 130.),
+    case03_end_exclusive: (TravelTime::Departure(9. * 3600.), 100.),
+    case04_next_day: (TravelTime::Departure(86400. + 8. * 3600.), 130.),
+    case05_second_interval: (TravelTime::Departure(17. * 3600.), 150.),
+    case06_arrival: (TravelTime::Arrival(9. * 3600.), 130.),
+}
+
+fn can_apply_time_of_day_multipliers_impl(travel_time: TravelTime, expected: Duration) {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let inner =
+        create_matrix_transport_cost(vec![create_matrix_data(Profile::default(), None, (100., 4), (10., 4))]).unwrap();
+    let multipliers = create_time_of_day_index(0, vec![(16. * 3600., 19. * 3600., 1.5), (7. * 3600., 9. * 3600., 1.3)]);
+    let costs = TimeOfDayTransportCost::new(multipliers, inner).unwrap();
+
+    assert_eq!(costs.duration(&route, 0, 1, travel_time), expected);
+    assert_eq!(costs.distance(&route, 0, 1, travel_time), 10.);
+    assert_eq!(costs.duration_approx(&route.actor.vehicle.profile, 0, 1), 100.);
+}
+
+parameterized_test! {can_detect_invalid_time_of_day_multipliers, (intervals, is_valid), {
+    can_detect_invalid_time_of_day_multipliers_impl(intervals, is_valid);
+}}
+
+can_detect_invalid_time_of_day_multipliers! {
+    case01_valid: (vec![(0., 3600., 1.2), (3600., 7200., 0.8)], true),
+    case02_overlap: (vec![(0., 3601., 1.2), (3600., 7200., 0.8)], false),
+    case03_empty_interval: (vec![(3600., 3600., 1.2)], false),
+    case04_beyond_day: (vec![(3600., 86401., 1.2)], false),
+    case05_non_positive_factor: (vec![(0., 3600., 0.)], false),
+}
+
+fn can_detect_invalid_time_of_day_multipliers_impl(intervals: Vec<(f64, f64, f64)>, is_valid: bool) {
+    let inner =
+        create_matrix_transport_cost(vec![create_matrix_data(Profile::default(), None, (1., 1), (1., 1))]).unwrap();
+
+    let result = TimeOfDayTransportCost::new(create_time_of_day_index(0, intervals), inner);
+
+    assert_eq!(result.is_ok(), is_valid);
+}
+
+#[test]
+fn can_add_toll_to_transport_cost() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let inner =
+        create_matrix_transport_cost(vec![create_matrix_data(Profile::default(), None, (100., 4), (10., 4))]).unwrap();
+    let costs = TollTransportCost::new(vec![(0, vec![2., 5., 0., 0.])].into_iter().collect(), inner.clone()).unwrap();
+
+    let travel_time = TravelTime::Departure(0.);
+    assert_eq!(costs.toll(&profile, 0, 1), 5.);
+    assert_eq!(costs.toll(&Profile::new(1, None), 0, 1), 0.);
+    assert_eq!(costs.toll(&profile, 0, 0), 0.);
+    assert_eq!(costs.cost(&route, 0, 0, travel_time), inner.cost(&route, 0, 0, travel_time));
+    assert_eq!(costs.cost(&route, 0, 1, travel_time), inner.cost(&route, 0, 1, travel_time) + 5.);
+    assert_eq!(costs.cost(&route, 1, 0, travel_time), inner.cost(&route, 1, 0, travel_time));
+    assert_eq!(costs.duration(&route, 0, 1, travel_time), 100.);
+    assert_eq!(costs.distance(&route, 0, 1, travel_time), 10.);
+}
+
+#[test]
+fn can_calculate_route_toll() {
+    let fleet = test_fleet();
+    let route = create_route_with_activities(
+        &fleet,
+        "v1",
+        vec![test_activity_with_location(1), test_activity_with_location(2)],
+    );
+    let inner =
+        create_matrix_transport_cost(vec![create_matrix_data(Profile::default(), None, (1., 9), (1., 9))]).unwrap();
+    let tolls = vec![(0, vec![0., 1., 2., 3., 4., 5., 6., 7., 8.])].into_iter().collect();
+    let costs = TollTransportCost::new(tolls, inner).unwrap();
+
+    assert_eq!(costs.route_toll(&route), 1. + 5. + 6.);
+}
+
+#[test]
+fn can_calculate_route_toll_with_commute() {
+    let fleet = test_fleet();
+    let commute_info = CommuteInfo { location: 1, distance: 1., duration: 1. };
+    let route = create_route_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_location(1),
+            Activity {
+                commute: Some(Commute { forward: commute_info.clone(), backward: commute_info }),
+                ..test_activity_with_location(2)
+            },
+        ],
+    );
+    let inner =
+        create_matrix_transport_cost(vec![create_matrix_data(Profile::default(), None, (1., 9), (1., 9))]).unwrap();
+    let tolls = vec![(0, vec![0., 1., 2., 3., 4., 5., 6., 7., 8.])].into_iter().collect();
+    let costs = TollTransportCost::new(tolls, inner).unwrap();
+
+    assert_eq!(costs.route_toll(&route), 1. + 3.);
+}
+
+parameterized_test! {can_detect_invalid_tolls, tolls, {
+    can_detect_invalid_tolls_impl(tolls);
+}}
+
+can_detect_invalid_tolls! {
+    case01_not_square: vec![(0, vec![0., 1., 2.])],
+    case02_different_lengths: vec![(0, vec![0.; 4]), (1, vec![0.; 9])],
+    case03_negative: vec![(0, vec![0., -1., 0., 0.])],
+}
+
+fn can_detect_invalid_tolls_impl(tolls: Vec<(usize, Vec<Cost>)>) {
+    let inner =
+        create_matrix_transport_cost(vec![create_matrix_data(Profile::default(), None, (1., 4), (1., 4))]).unwrap();
+
+    let result = TollTransportCost::new(tolls.into_iter().collect(), inner);
+
+    assert!(result.is_err());
+}
+
+parameterized_test! {can_override_legs, (from, to, expected), {
+    can_override_legs_impl(from, to, expected);
+}}
+
+can_override_legs! {
+    case01_duration_and_distance: (0, 1, (50., 5.)),
+    case02_only_duration: (1, 0, (70., 10.)),
+    case03_not_overridden: (1, 1, (100., 10.)),
+}
+
+fn can_override_legs_impl(from: Location, to: Location, expected: (Duration, Distance)) {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let inner =
+        create_matrix_transport_cost(vec![create_matrix_data(Profile::default(), None, (100., 4), (10., 4))]).unwrap();
+    let overrides =
+        vec![((0, 0, 1), (50., Some(5.))), ((0, 1, 0), (70., None)), ((1, 1, 1), (1., Some(1.)))].into_iter().collect();
+    let costs = LegOverrideTransportCost::new(overrides, inner).unwrap();
+
+    let travel_time = TravelTime::Departure(0.);
+    assert_eq!(
+        (costs.duration(&route, from, to, travel_time), costs.distance(&route, from, to, travel_time)),
+        expected
+    );
+    assert_eq!((costs.duration_approx(&profile, from, to), costs.distance_approx(&profile, from, to)), expected);
+}
+
+#[test]
+fn can_detect_invalid_leg_overrides() {
+    let inner =
+        create_matrix_transport_cost(vec![create_matrix_data(Profile::default(), None, (1., 1), (1., 1))]).unwrap();
+
+    let result = LegOverrideTransportCost::new(vec![((0, 0, 1), (-1., None))].into_iter().collect(), inner);
+
+    assert!(result.is_err());
+}
+
+parameterized_test! {can_use_matrix_storage, (storage, expected), {
+    can_use_matrix_storage_impl(storage, expected);
+}}
+
+can_use_matrix_storage! {
+    case01_full: (MatrixStorage::Full, Some((vec![0., 10.25, -1., 3.33333], vec![0., 20.5, -1., 6.66666]))),
+    case02_float: (MatrixStorage::Float, Some((vec![0., 10.25, -1., 3.33333], vec![0., 20.5, -1., 6.66666]))),
+    case03_quantized: (MatrixStorage::Quantized { scale: 10. }, Some((vec![0., 10.3, -1., 3.3], vec![0., 20.5, -1., 6.7]))),
+    case04_quantized_overflow: (MatrixStorage::Quantized { scale: 1E9 }, None),
+    case05_quantized_invalid: (MatrixStorage::Quantized { scale: 0. }, None),
+}
+
+fn can_use_matrix_storage_impl(storage: MatrixStorage, expected: Option<(Vec<f64>, Vec<f64>)>) {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let profile = route.actor.vehicle.profile.clone();
+    let matrix_data = MatrixData::new(0, None, vec![0., 10.25, -1., 3.33333], vec![0., 20.5, -1., 6.66666]);
+
+    let result = create_matrix_transport_cost_with_storage(vec![matrix_data], storage);
+
+    match (result, expected) {
+        (Ok(transport), Some((expected_durations, expected_distances))) => {
+            let pairs = [(0, 0), (0, 1), (1, 0), (1, 1)];
+            let round = |value: f64| (value * 1E5).round() / 1E5;
+            let durations = pairs.iter().map(|&(from, to)| round(transport.duration_approx(&profile, from, to)));
+            let distances =
+                pairs.iter().map(|&(from, to)| round(transport.distance(&route, from, to, TravelTime::Departure(0.))));
+
+            assert_eq!(durations.collect::<Vec<_>>(), expected_durations);
+            assert_eq!(distances.collect::<Vec<_>>(), expected_distances);
+        }
+        (Err(_), None) => {}
+        (result, _) => unreachable!("unexpected result: {}", result.is_ok()),
+    }
+}
+
+parameterized_test! {can_get_matrix_dimension, (length, expected), {
+    assert_eq!(get_matrix_dimension(length), expected);
+}}
+
+can_get_matrix_dimension! {
+    case01_empty: (0, Some(0)),
+    case02_single: (1, Some(1)),
+    case03_small: (4, Some(2)),
+    case04_not_square: (5, None),
+    case05_big: (90_000_000_000, Some(300_000)),
+    case06_big_not_square: (90_000_000_001, None),
+}
+
+parameterized_test! {can_estimate_matrix_memory, (size, matrices, storage, expected), {
+    assert_eq!(estimate_matrix_memory(size, matrices, storage), expected);
+}}
+
+can_estimate_matrix_memory! {
+    case01_full: (100, 2, MatrixStorage::Full, Some(320_000)),
+    case02_float: (100, 2, MatrixStorage::Float, Some(160_000)),
+    case03_quantized: (100, 1, MatrixStorage::Quantized { scale: 10. }, Some(80_000)),
+    case04_big: (300_000, 1, MatrixStorage::Float, Some(720_000_000_000)),
+    case05_overflow: (usize::MAX / 2, 1, MatrixStorage::Full, None),
+}
+
+#[test]
+fn can_detect_non_square_matrix() {
+    assert_eq!(
+        create_matrix_transport_cost(vec![create_matrix_data(Profile::default(), None, (0., 3), (0., 3))]).err(),
+        Some(VrpError::Matrix("matrix is not square: '3' values".to_string()))
+    );
+}
+
 parameterized_test! {can_search_for_reserved_time, (times, tests), {
     can_search_for_reserved_time_impl(times, tests);
 }}
@@ -118,7 +373,11 @@ fn can_search_for_reserved_time_impl(times: Vec<(f64, f64)>, tests: Vec<((f64, f
     let route = create_empty_route_ctx().route;
     let reserved_times = vec![(
         route.actor.clone(),
-        times.iter().cloned().map(|(start, end)| TimeSpan::Window(TimeWindow::new(start, end))).collect::<Vec<_>>(),
+        times
+            .iter()
+            .cloned()
+            .map(|(start, end)| ReservedTimeSpan::new(TimeSpan::Window(TimeWindow::new(start, end))))
+            .collect::<Vec<_>>(),
     )]
     .into_iter()
     .collect();
@@ -130,7 +389,7 @@ fn can_search_for_reserved_time_impl(times: Vec<(f64, f64)>, tests: Vec<((f64, f
             let interval = TimeWindow::new(*s, *e);
             let expected = expected.and_then(|idx| times.get(idx)).map(|(s, e)| TimeWindow::new(*s, *e));
 
-            let result = reserved_time_func.deref()(&route, &interval);
+            let result = reserved_time_func.deref()(&route, &interval).map(|(time, _)| time);
 
             assert_eq!(result, expected);
         });
@@ -248,4 +507,130 @@ mod objective {
 
         assert_eq!(result, expected);
     }
+
+    fn create_individual_with_routes(routes: Vec<(Vec<&str>, f64)>) -> InsertionContext {
+        let fleet = test_fleet();
+        let mut individual = create_individual(vec![0.]);
+
+        individual.solution.routes = routes
+            .into_iter()
+            .map(|(job_ids, finish)| {
+                let activities =
+                    job_ids.into_iter().map(|job_id| test_activity_with_job(test_single_with_id(job_id))).collect();
+                let mut route_ctx = create_route_context_with_activities(&fleet, "v1", activities);
+                let last_idx = route_ctx.route.tour.total() - 1;
+                route_ctx.route_mut().tour.get_mut(last_idx).unwrap().schedule.arrival = finish;
+
+                route_ctx
+            })
+            .collect();
+
+        individual
+    }
+
+    parameterized_test! {can_break_ties, (tie_breakers, routes_a, routes_b, expected), {
+        can_break_ties_impl(tie_breakers, routes_a, routes_b, expected);
+    }}
+
+    can_break_ties! {
+        case01_no_tie_breakers: (vec![], vec![(vec!["job1"], 10.)], vec![], Ordering::Equal),
+        case02_fewer_routes: (vec![TieBreaker::FewerRoutes], vec![(vec!["job1"], 10.)], vec![], Ordering::Greater),
+        case03_earlier_finish: (vec![TieBreaker::EarlierFinish], vec![(vec!["job1"], 5.)], vec![(vec!["job1"], 10.)], Ordering::Less),
+        case04_lexicographic: (vec![TieBreaker::Lexicographic], vec![(vec!["job2", "job1"], 10.)], vec![(vec!["job1", "job2"], 10.)], Ordering::Greater),
+        case05_lexicographic_same: (vec![TieBreaker::Lexicographic], vec![(vec!["job1", "job2"], 5.)], vec![(vec!["job1", "job2"], 10.)], Ordering::Equal),
+        case06_in_order: (vec![TieBreaker::FewerRoutes, TieBreaker::EarlierFinish], vec![(vec!["job1"], 5.)], vec![(vec!["job1"], 10.)], Ordering::Less),
+    }
+
+    fn can_break_ties_impl(
+        tie_breakers: Vec<TieBreaker>,
+        routes_a: Vec<(Vec<&str>, f64)>,
+        routes_b: Vec<(Vec<&str>, f64)>,
+        expected: Ordering,
+    ) {
+        let objective =
+            ProblemObjective::new(vec![vec![Arc::new(TestObjective { index: 0 })]]).with_tie_breakers(tie_breakers);
+
+        let result =
+            objective.tie_break(&create_individual_with_routes(routes_a), &create_individual_with_routes(routes_b));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn can_ignore_tie_breakers_in_total_order() {
+        let objective = ProblemObjective::new(vec![vec![Arc::new(TestObjective { index: 0 })]])
+            .with_tie_breakers(vec![TieBreaker::FewerRoutes]);
+        let a = create_individual_with_routes(vec![(vec!["job1"], 10.)]);
+        let b = create_individual_with_routes(vec![]);
+
+        assert_eq!(objective.tie_break(&a, &b), Ordering::Greater);
+        assert_eq!(objective.total_order(&a, &b), Ordering::Equal);
+    }
+
+    #[test]
+    fn can_get_tier_fitness() {
+        let objective = ProblemObjective::new(vec![
+            vec![Arc::new(TestObjective { index: 0 }), Arc::new(TestObjective { index: 1 })],
+            vec![Arc::new(TestObjective { index: 2 })],
+        ]);
+
+        let result = objective.get_tier_fitness(&create_individual(vec![1., 2., 3.]));
+
+        assert_eq!(result, vec![vec![1., 2.], vec![3.]]);
+    }
+}
+
+parameterized_test! {can_use_waiting_rate_depending_on_place, (location, has_job, expected), {
+    can_use_waiting_rate_depending_on_place_impl(location, has_job, expected);
+}}
+
+can_use_waiting_rate_depending_on_place! {
+    case01_customer: (5, true, 20.),
+    case02_job_at_depot_location: (DEFAULT_ACTOR_LOCATION, true, 10.),
+    case03_tour_end: (5, false, 10.),
+    case04_job_at_alternative_end_location: (7, true, 10.),
+}
+
+fn can_use_waiting_rate_depending_on_place_impl(location: Location, has_job: bool, expected: Cost) {
+    let costs = Costs { per_waiting_time: 2., per_depot_waiting_time: 1., per_service_time: 0., ..empty_costs() };
+    let actor = test_actor();
+    let mut vehicle = Vehicle { costs: costs.clone(), ..test_vehicle(0) };
+    vehicle.dimens.set_dimen(&VEHICLE_END_LOCATIONS_KEY, vec![DEFAULT_ACTOR_LOCATION, 7]);
+    let actor = Arc::new(Actor {
+        vehicle: Arc::new(vehicle),
+        driver: Arc::new(test_driver_with_costs(empty_costs())),
+        detail: actor.detail.clone(),
+    });
+    let route = Route { actor, tour: Default::default() };
+    let mut activity = test_activity_with_location_and_tw(location, TimeWindow::new(10., 20.));
+    if !has_job {
+        activity.job = None;
+    }
+
+    let cost = SimpleActivityCost::default().cost(&route, &activity, 0.);
+
+    assert_eq!(cost, expected);
+}
+
+parameterized_test! {can_estimate_arrival_with_reserved_time, (reserved_time, can_interrupt, expected), {
+    can_estimate_arrival_with_reserved_time_impl(TimeWindow::new(reserved_time.0, reserved_time.1), can_interrupt, expected);
+}}
+
+can_estimate_arrival_with_reserved_time! {
+    case01_interrupt_inside_service: ((42., 47.), true, 35.),
+    case02_no_interrupt_inside_service: ((42., 47.), false, 32.),
+    case03_no_interrupt_overlaps_service: ((45., 60.), false, 35.),
+    case04_no_interrupt_outside: ((60., 70.), false, 40.),
+}
+
+fn can_estimate_arrival_with_reserved_time_impl(reserved_time: TimeWindow, can_interrupt: bool, expected: Timestamp) {
+    let route = Route { actor: test_actor(), tour: Default::default() };
+    let reserved_time = ReservedTimeSpan { time: TimeSpan::Window(reserved_time), can_interrupt };
+    let reserved_times = vec![(route.actor.clone(), vec![reserved_time])].into_iter().collect();
+    let activity_cost = DynamicActivityCost::new(reserved_times, Arc::new(SimpleActivityCost::default())).unwrap();
+    let activity = test_activity_with_location_tw_and_duration(10, TimeWindow::new(0., 100.), 10.);
+
+    let result = activity_cost.estimate_arrival(&route, &activity, 50.);
+
+    assert_eq!(result, expected);
 }
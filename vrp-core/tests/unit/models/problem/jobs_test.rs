@@ -95,7 +95,14 @@ fn create_only_distance_transport_cost() -> Arc<dyn TransportCost + Sync + Send>
 }
 
 fn create_costs() -> Costs {
-    Costs { fixed: 10.0, per_distance: 1.0, per_driving_time: 1.0, per_waiting_time: 1.0, per_service_time: 1.0 }
+    Costs {
+        fixed: 10.0,
+        per_distance: 1.0,
+        per_driving_time: 1.0,
+        per_waiting_time: 1.0,
+        per_depot_waiting_time: 1.0,
+        per_service_time: 1.0,
+    }
 }
 
 #[test]
@@ -0,0 +1,104 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::helpers::models::domain::{create_problem_with_constraint_jobs_and_fleet, test_random};
+use crate::helpers::models::problem::*;
+
+fn create_activity(single: Arc<Single>, location: Location) -> Activity {
+    Activity {
+        place: Place { location, duration: 1., time: TimeWindow::new(0., 100.) },
+        schedule: Schedule::new(location as f64, location as f64 + 1.),
+        job: Some(single),
+        commute: None,
+    }
+}
+
+fn create_test_solution() -> (Arc<Problem>, Solution) {
+    let job1 = SingleBuilder::default().id("job1").location(Some(1)).build_as_job_ref();
+    let job2 = SingleBuilder::default().id("job2").location(Some(2)).build_as_job_ref();
+    let multi = MultiBuilder::default()
+        .id("multi1")
+        .job(SingleBuilder::default().id("pickup").location(Some(3)).build())
+        .job(SingleBuilder::default().id("delivery").location(Some(4)).build())
+        .build();
+    let problem = create_problem_with_constraint_jobs_and_fleet(
+        ConstraintPipeline::default(),
+        vec![job1.clone(), job2.clone(), multi.clone()],
+        test_fleet(),
+    );
+
+    let actor = get_test_actor_from_fleet(problem.fleet.as_ref(), "v1");
+    let mut tour = Tour::new(actor.as_ref());
+    tour.insert_last(create_activity(job1.to_single().clone(), 1));
+    tour.insert_last(create_activity(multi.to_multi().jobs[0].clone(), 3));
+    tour.insert_last(create_activity(multi.to_multi().jobs[1].clone(), 4));
+
+    let mut registry = Registry::new(problem.fleet.as_ref(), test_random());
+    registry.use_actor(&actor);
+
+    let solution = Solution {
+        registry,
+        routes: vec![Route { actor, tour }],
+        unassigned: vec![(job2, 1)],
+        extras: problem.extras.clone(),
+    };
+
+    (problem, solution)
+}
+
+#[test]
+fn can_serialize_and_deserialize_solution() {
+    let (problem, solution) = create_test_solution();
+
+    let json = serde_json::to_string(&solution).expect("cannot serialize solution");
+    let restored =
+        deserialize_solution(&mut serde_json::Deserializer::from_str(json.as_str()), &problem, test_random())
+            .expect("cannot deserialize solution");
+
+    assert_eq!(restored.routes.len(), 1);
+    assert_eq!(restored.registry.available().count(), 0);
+    let (original, restored_route) = (solution.routes.first().unwrap(), restored.routes.first().unwrap());
+    assert!(Arc::ptr_eq(&original.actor, &restored_route.actor));
+    assert_eq!(original.tour.total(), restored_route.tour.total());
+    original.tour.all_activities().zip(restored_route.tour.all_activities()).for_each(|(original, restored)| {
+        assert_eq!(original.place.location, restored.place.location);
+        assert_eq!(original.schedule.arrival, restored.schedule.arrival);
+        assert_eq!(original.schedule.departure, restored.schedule.departure);
+        match (original.job.as_ref(), restored.job.as_ref()) {
+            (Some(original), Some(restored)) => assert!(Arc::ptr_eq(original, restored)),
+            (None, None) => {}
+            _ => unreachable!("unexpected job mismatch"),
+        }
+    });
+    assert_eq!(restored.unassigned.len(), 1);
+    assert_eq!(get_job_id(&restored.unassigned[0].0), "job2");
+    assert_eq!(restored.unassigned[0].1, 1);
+}
+
+#[test]
+fn can_reference_jobs_by_id() {
+    let (_, solution) = create_test_solution();
+
+    let json = serde_json::to_value(&solution.routes[0].tour).expect("cannot serialize tour");
+
+    let jobs = json.as_array().unwrap().iter().map(|activity| activity["job"].clone()).collect::<Vec<_>>();
+    assert_eq!(
+        jobs,
+        vec![
+            serde_json::Value::Null,
+            serde_json::json!({ "id": "job1" }),
+            serde_json::json!({ "id": "multi1", "single": 0 }),
+            serde_json::json!({ "id": "multi1", "single": 1 }),
+            serde_json::Value::Null,
+        ]
+    );
+}
+
+#[test]
+fn can_return_error_for_unknown_job() {
+    let (problem, solution) = create_test_solution();
+    let json = serde_json::to_string(&solution).unwrap().replace("job1", "job3");
+
+    let result = deserialize_solution(&mut serde_json::Deserializer::from_str(json.as_str()), &problem, test_random());
+
+    assert!(result.err().unwrap().to_string().contains("cannot find job with id: 'job3'"));
+}
@@ -0,0 +1,90 @@
+use super::*;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use std::path::PathBuf;
+
+fn get_checkpoint_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("vrp_core_{}_{}.checkpoint", name, std::process::id()))
+}
+
+#[test]
+fn can_write_and_read_checkpoint() {
+    let path = get_checkpoint_path("roundtrip");
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let problem = Arc::new(problem);
+    let mut refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let environment = refinement_ctx.environment.clone();
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let original_cost = problem.objective.fitness(&insertion_ctx);
+    refinement_ctx.population.add(insertion_ctx);
+    refinement_ctx.statistics.generation = 5;
+
+    write_checkpoint(path.as_path(), &refinement_ctx, Some("heuristic_state".to_string())).unwrap();
+    let checkpoint = read_checkpoint(path.as_path(), problem.clone(), environment);
+    let _ = std::fs::remove_file(path);
+
+    let checkpoint = checkpoint.unwrap();
+    assert_eq!(checkpoint.generation, 5);
+    assert_eq!(checkpoint.heuristic, Some("heuristic_state".to_string()));
+    assert!(checkpoint.population.is_none());
+    assert_eq!(checkpoint.solutions.len(), 1);
+    let restored = &checkpoint.solutions[0];
+    assert_eq!(problem.objective.fitness(restored), original_cost);
+    assert_eq!(restored.solution.routes.len(), 2);
+    assert!(restored.solution.routes.iter().all(|route_ctx| route_ctx.route.tour.job_count() == 3));
+    assert!(restored.solution.unassigned.is_empty() && restored.solution.required.is_empty());
+}
+
+#[test]
+fn can_keep_ignored_jobs_and_random_state() {
+    let path = get_checkpoint_path("ignored");
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+    let problem = Arc::new(problem);
+    let mut refinement_ctx = create_default_refinement_ctx(problem.clone());
+    let environment =
+        Arc::new(Environment { random: Arc::new(DefaultRandom::new_with_seed(42)), ..Environment::default() });
+    refinement_ctx.environment = environment.clone();
+    let mut insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone());
+    let job = insertion_ctx.solution.routes[0].route.tour.jobs().next().unwrap();
+    insertion_ctx.solution.routes[0].route_mut().tour.remove(&job);
+    insertion_ctx.solution.ignored.push(job.clone());
+    insertion_ctx.restore();
+    refinement_ctx.population.add(insertion_ctx);
+    environment.random.uniform_int(0, 10);
+    let random_state = environment.random.save_state();
+
+    write_checkpoint(path.as_path(), &refinement_ctx, None).unwrap();
+    let checkpoint = read_checkpoint(path.as_path(), problem.clone(), environment);
+    let _ = std::fs::remove_file(path);
+
+    let checkpoint = checkpoint.unwrap();
+    assert_eq!(checkpoint.seed, Some(42));
+    assert_eq!(checkpoint.random, random_state);
+    assert_eq!(checkpoint.solutions.len(), 1);
+    let restored = &checkpoint.solutions[0];
+    assert!(restored.solution.ignored.len() == 1 && restored.solution.ignored[0] == job);
+    assert!(restored.solution.unassigned.is_empty() && restored.solution.required.is_empty());
+}
+
+parameterized_test! {can_reject_invalid_checkpoint, (name, content, expected), {
+    can_reject_invalid_checkpoint_impl(name, content, expected);
+}}
+
+can_reject_invalid_checkpoint! {
+    case01_version: ("version", "version 0\ngeneration 1", "unsupported checkpoint version"),
+    case02_entry: ("entry", "version 2\nunknown 1", "unknown checkpoint entry"),
+    case03_actor: ("actor", "version 2\nsolution 42,0:0/0/0/0/0/1", "cannot parse solution"),
+    case04_job: ("job", "version 2\nsolution 0,0:42/0/0/0/0/1", "cannot parse solution"),
+    case05_ignored: ("ignored", "version 2\nsolution 0,0:0/0/0/0/0/1#42", "cannot parse solution"),
+    case06_random: ("random", "version 2\nrandom abc", "cannot parse random state"),
+}
+
+fn can_reject_invalid_checkpoint_impl(name: &str, content: &str, expected: &str) {
+    let path = get_checkpoint_path(name);
+    let (problem, _) = generate_matrix_routes_with_defaults(3, 2, false);
+    std::fs::write(path.as_path(), content).unwrap();
+
+    let result = read_checkpoint(path.as_path(), Arc::new(problem), Arc::new(Environment::default()));
+    let _ = std::fs::remove_file(path);
+
+    assert!(result.err().unwrap().starts_with(expected));
+}
@@ -0,0 +1,88 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::helpers::utils::random::FakeRandom;
+use crate::solver::GreedyPopulation;
+use crate::utils::Environment;
+
+struct NoopRuin;
+
+impl Ruin for NoopRuin {
+    fn run(&self, _: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        insertion_ctx
+    }
+}
+
+struct NoopRecreate;
+
+impl Recreate for NoopRecreate {
+    fn run(&self, _: &RefinementContext, insertion_ctx: InsertionContext) -> InsertionContext {
+        insertion_ctx
+    }
+}
+
+fn create_segment_weights() -> SegmentWeights {
+    let mut weights = SegmentWeights::new(3);
+    weights.add_score(0, 33.);
+    weights.add_score(0, 0.);
+    weights.add_score(1, 9.);
+    weights.update(0.5, 0.01);
+
+    weights
+}
+
+#[test]
+fn can_update_segment_weights() {
+    let mut weights = create_segment_weights();
+
+    assert_eq!(weights.weights(), &[8.75, 5., 1.]);
+
+    weights.add_score(2, 0.);
+    weights.update(1., 0.01);
+
+    assert_eq!(weights.weights(), &[8.75, 5., 0.01]);
+}
+
+parameterized_test! {can_select_operator_using_weights, (value, expected), {
+    can_select_operator_using_weights_impl(value, expected);
+}}
+
+can_select_operator_using_weights! {
+    case_01: (0.5, 0),
+    case_02: (9., 1),
+    case_03: (14., 2),
+}
+
+fn can_select_operator_using_weights_impl(value: f64, expected: usize) {
+    let weights = create_segment_weights();
+
+    let result = weights.select(&FakeRandom::new(vec![], vec![value]));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_adapt_and_report_operator_weights() {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 3, false);
+    let problem = Arc::new(problem);
+    let population = Box::new(GreedyPopulation::new(problem.objective.clone(), 1, None));
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, environment.clone());
+    let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+    let mut heuristic = AdaptiveLargeNeighborhoodSearch::new(
+        vec![(Arc::new(NoopRuin), "noop".to_string())],
+        vec![(Arc::new(NoopRecreate), "noop".to_string())],
+        AlnsScores::default(),
+        1,
+        0.1,
+    );
+
+    let solutions = heuristic.search(&refinement_ctx, vec![&insertion_ctx]);
+
+    assert_eq!(solutions.len(), 1);
+    let weights = heuristic.get_operator_weights().expect("no weights");
+    assert_eq!(weights.len(), 2);
+    assert_eq!(weights[0].0, "ruin:noop");
+    assert_eq!(weights[1].0, "recreate:noop");
+    // NOTE population is empty, so the solution is considered as a new best one
+    weights.iter().for_each(|(_, weight)| assert!((weight - 4.2).abs() < 1E-9));
+}
@@ -0,0 +1,116 @@
+use super::*;
+use crate::helpers::models::domain::create_empty_solution_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, Schedule, TimeWindow, ValueDimension};
+use crate::models::solution::Place;
+
+fn create_single_with_priority(is_prioritized: bool) -> Arc<Single> {
+    let mut single = test_single();
+
+    if is_prioritized {
+        single.dimens.set_value("prioritized", true);
+    }
+
+    Arc::new(single)
+}
+
+fn create_activity(location: Location, departure: f64, is_prioritized: bool) -> Activity {
+    ActivityBuilder::default()
+        .place(Place { location, duration: 0., time: TimeWindow::new(0., 1000.) })
+        .schedule(Schedule::new(departure, departure))
+        .job(Some(create_single_with_priority(is_prioritized)))
+        .build()
+}
+
+fn get_priority_fn() -> PriorityFn {
+    Arc::new(|single| single.dimens.get_value::<bool>("prioritized").cloned().unwrap_or(false))
+}
+
+#[test]
+fn can_get_latest_eta() {
+    let fleet = test_fleet();
+    let routes = vec![
+        create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity(10, 10., true), create_activity(20, 20., false), create_activity(30, 30., true)],
+        ),
+        create_route_context_with_activities(
+            &fleet,
+            "v1",
+            vec![create_activity(10, 10., false), create_activity(50, 50., false)],
+        ),
+    ];
+
+    let latest = get_latest_eta(routes.as_slice(), &get_priority_fn());
+
+    assert_eq!(latest, 30.);
+}
+
+#[test]
+fn can_update_route_state_on_insertion() {
+    let fleet = test_fleet();
+    let (constraint, _) = LatestEta::new_minimized(get_priority_fn(), TestTransportCost::new_shared(), 1, 2);
+    let mut solution_ctx = SolutionContext {
+        routes: vec![create_route_context_with_activities(&fleet, "v1", vec![])],
+        ..create_empty_solution_context()
+    };
+    let mut insert_activity = |activity: Activity| {
+        let job = Job::Single(activity.job.clone().unwrap());
+        solution_ctx.routes[0].route_mut().tour.insert_last(activity);
+        constraint.accept_insertion(&mut solution_ctx, 0, &job);
+
+        solution_ctx.routes[0].state.get_route_state::<Option<Timestamp>>(1).cloned().flatten()
+    };
+
+    assert_eq!(insert_activity(create_activity(10, 10., true)), Some(10.));
+    assert_eq!(insert_activity(create_activity(20, 20., false)), Some(10.));
+    assert_eq!(insert_activity(create_activity(30, 30., true)), Some(30.));
+}
+
+parameterized_test! {can_estimate_activity, (latest, prev_departure, target_prioritized, expected), {
+    can_estimate_activity_impl(latest, prev_departure, target_prioritized, expected);
+}}
+
+can_estimate_activity! {
+    case01: (None, 0., false, 0.),
+    case02: (None, 0., true, 60.),
+    case03: (Some(50.), 0., false, 40.),
+    case04: (Some(50.), 0., true, 40.),
+    case05: (Some(50.), 60., false, 0.),
+    case06: (Some(50.), 60., true, 80.),
+}
+
+fn can_estimate_activity_impl(latest: Option<f64>, prev_departure: f64, target_prioritized: bool, expected: f64) {
+    let fleet = test_fleet();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    route_ctx.state_mut().put_route_state(1, latest);
+    let (constraint, _) = LatestEta::new_minimized(get_priority_fn(), TestTransportCost::new_shared(), 1, 2);
+    let constraint = match constraint.get_constraints().next() {
+        Some(ConstraintVariant::SoftActivity(constraint)) => constraint.clone(),
+        _ => unreachable!(),
+    };
+
+    let result = constraint.estimate_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: &create_activity(0, prev_departure, false),
+            target: &create_activity(30, 0., target_prioritized),
+            next: Some(&create_activity(20, prev_departure + 20., false)),
+        },
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_merge_jobs_with_same_priority() {
+    let (constraint, _) = LatestEta::new_minimized(get_priority_fn(), TestTransportCost::new_shared(), 1, 2);
+    let create_job = |is_prioritized| Job::Single(create_single_with_priority(is_prioritized));
+
+    assert!(constraint.merge(create_job(true), create_job(true)).is_ok());
+    assert!(constraint.merge(create_job(false), create_job(false)).is_ok());
+    assert_eq!(constraint.merge(create_job(true), create_job(false)).err(), Some(2));
+}
@@ -0,0 +1,102 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, Schedule, TimeWindow, ValueDimension};
+use crate::models::solution::Place;
+
+fn create_single_with_buffer(buffer: Option<Duration>) -> Arc<Single> {
+    let mut single = test_single();
+
+    if let Some(buffer) = buffer {
+        single.dimens.set_value("buffer", buffer);
+    }
+
+    Arc::new(single)
+}
+
+fn create_activity(location: Location, tw: (f64, f64), departure: f64, buffer: Option<Duration>) -> Activity {
+    ActivityBuilder::default()
+        .place(Place { location, duration: 0., time: TimeWindow::new(tw.0, tw.1) })
+        .schedule(Schedule::new(departure, departure))
+        .job(Some(create_single_with_buffer(buffer)))
+        .build()
+}
+
+fn get_buffer_fn() -> BufferFn {
+    Arc::new(|single| single.dimens.get_value::<f64>("buffer").cloned())
+}
+
+parameterized_test! {can_get_violations, (buffer, expected), {
+    can_get_violations_impl(buffer, expected);
+}}
+
+can_get_violations! {
+    case01: (None, 0),
+    case02: (Some(3.), 0),
+    case03: (Some(5.), 0),
+    case04: (Some(7.), 1),
+}
+
+fn can_get_violations_impl(buffer: Option<Duration>, expected: usize) {
+    let fleet = test_fleet();
+    let route_ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![create_activity(10, (0., 1000.), 10., buffer), create_activity(20, (0., 25.), 20., None)],
+    );
+
+    let violations = get_violations(&[route_ctx], &get_buffer_fn(), TestTransportCost::new_shared().as_ref());
+
+    assert_eq!(violations, expected);
+}
+
+parameterized_test! {can_estimate_activity, (prev_buffer, location, buffer, expected), {
+    can_estimate_activity_impl(prev_buffer, location, buffer, expected);
+}}
+
+can_estimate_activity! {
+    case01: (None, 10, None, 0.),
+    case02: (None, 10, Some(30.), 0.),
+    case03: (None, 10, Some(35.), 10.),
+    case04: (Some(30.), 30, None, 40.),
+    case05: (Some(40.), 30, None, 40.),
+    case06: (Some(30.), 30, Some(15.), 50.),
+}
+
+fn can_estimate_activity_impl(
+    prev_buffer: Option<Duration>,
+    location: Location,
+    buffer: Option<Duration>,
+    expected: f64,
+) {
+    let fleet = test_fleet();
+    let route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    let (constraint, _) = ScheduleBuffer::new_maximized(get_buffer_fn(), TestTransportCost::new_shared(), 1, 2);
+    let constraint = match constraint.get_constraints().next() {
+        Some(ConstraintVariant::SoftActivity(constraint)) => constraint.clone(),
+        _ => unreachable!(),
+    };
+
+    let result = constraint.estimate_activity(
+        &route_ctx,
+        &ActivityContext {
+            index: 0,
+            prev: &create_activity(0, (0., 1000.), 0., prev_buffer),
+            target: &create_activity(location, (0., 1000.), 0., buffer),
+            next: Some(&create_activity(20, (0., 50.), 0., None)),
+        },
+    );
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_merge_jobs_with_same_buffer() {
+    let (constraint, _) = ScheduleBuffer::new_maximized(get_buffer_fn(), TestTransportCost::new_shared(), 1, 2);
+    let create_job = |buffer| Job::Single(create_single_with_buffer(buffer));
+
+    assert!(constraint.merge(create_job(Some(1.)), create_job(Some(1.))).is_ok());
+    assert!(constraint.merge(create_job(Some(1.)), create_job(None)).is_ok());
+    assert_eq!(constraint.merge(create_job(None), create_job(Some(1.))).err(), Some(2));
+    assert_eq!(constraint.merge(create_job(Some(1.)), create_job(Some(2.))).err(), Some(2));
+}
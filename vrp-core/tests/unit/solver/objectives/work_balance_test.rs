@@ -0,0 +1,85 @@
+use super::*;
+use crate::construction::constraints::TOTAL_DISTANCE_KEY;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_module;
+use crate::helpers::models::domain::{create_empty_insertion_context, create_empty_solution_context};
+use crate::helpers::models::problem::{test_fleet, test_single_with_id};
+use crate::helpers::models::solution::*;
+use crate::models::problem::Job;
+
+fn create_route_ctx_with_locations(locations: Vec<usize>) -> RouteContext {
+    let mut route_ctx = create_route_context_with_activities(
+        &test_fleet(),
+        "v1",
+        locations.into_iter().map(test_activity_with_location).collect(),
+    );
+    route_ctx.state_mut().put_route_state(TOTAL_DISTANCE_KEY, 100.);
+
+    route_ctx
+}
+
+parameterized_test! {can_count_stops, (locations, expected), {
+    can_count_stops_impl(locations, expected);
+}}
+
+can_count_stops! {
+    case_01: (vec![], 0),
+    case_02: (vec![1, 2, 3], 3),
+    case_03: (vec![1, 1, 2], 2),
+    case_04: (vec![1, 2, 1], 3),
+    case_05: (vec![1, 1, 1], 1),
+}
+
+fn can_count_stops_impl(locations: Vec<usize>, expected: usize) {
+    let route_ctx = create_route_ctx_with_locations(locations);
+
+    assert_eq!(get_stop_count(&route_ctx), expected);
+}
+
+parameterized_test! {can_calculate_target_stops_fitness, (routes, target, tolerance, expected), {
+    can_calculate_target_stops_fitness_impl(routes, target, tolerance, expected);
+}}
+
+can_calculate_target_stops_fitness! {
+    case_01_exact: (vec![3, 3], 3, 0, 0.),
+    case_02_under_and_over: (vec![1, 5], 3, 0, 4.),
+    case_03_within_tolerance: (vec![2, 4], 3, 1, 0.),
+    case_04_beyond_tolerance: (vec![1, 6], 3, 1, 3.),
+}
+
+fn can_calculate_target_stops_fitness_impl(routes: Vec<usize>, target: usize, tolerance: usize, expected: f64) {
+    let (_, objective) = WorkBalance::new_target_stops(target, tolerance);
+    let mut insertion_ctx = create_empty_insertion_context();
+    insertion_ctx.solution.routes =
+        routes.into_iter().map(|stops| create_route_ctx_with_locations((1..=stops).collect())).collect();
+
+    let result = objective.fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_target_stops_job, (stops, target, tolerance, expected_delta), {
+    can_estimate_target_stops_job_impl(stops, target, tolerance, expected_delta);
+}}
+
+can_estimate_target_stops_job! {
+    case_01_empty_route: (0, 3, 0, 2.),
+    case_02_empty_route_with_tolerance: (0, 3, 2, 0.),
+    case_03_under_target: (1, 3, 0, -1.),
+    case_04_at_target: (3, 3, 0, 1.),
+    case_05_within_tolerance: (3, 3, 1, 0.),
+    case_06_over_target: (5, 3, 0, 1.),
+}
+
+fn can_estimate_target_stops_job_impl(stops: usize, target: usize, tolerance: usize, expected_delta: f64) {
+    let (constraint, _) = WorkBalance::new_target_stops(target, tolerance);
+    let constraint = create_constraint_pipeline_with_module(constraint);
+    let route_ctx = create_route_ctx_with_locations((1..=stops).collect());
+    let mut solution_ctx = create_empty_solution_context();
+    solution_ctx.routes.push(create_route_ctx_with_locations(vec![1]));
+    let max_cost = solution_ctx.get_max_cost();
+
+    let result = constraint.evaluate_soft_route(&solution_ctx, &route_ctx, &Job::Single(test_single_with_id("job")));
+
+    assert!(max_cost > 0.);
+    assert_eq!(result, expected_delta * max_cost);
+}
@@ -0,0 +1,52 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::*;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+fn create_insertion_ctx(locked_ids: &[&str]) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let mut insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default())),
+        locked_ids,
+    );
+    rearrange_jobs_in_routes(
+        &mut insertion_ctx,
+        &[vec!["c0", "c1", "c7", "c3", "c4"], vec!["c5", "c6", "c2", "c8", "c9"]],
+    );
+
+    insertion_ctx
+}
+
+#[test]
+fn can_polish_solution() {
+    let insertion_ctx = create_insertion_ctx(&[]);
+    let original_cost = insertion_ctx.solution.get_total_cost();
+
+    let insertion_ctx = ExhaustivePolishing::default().post_process(insertion_ctx);
+
+    assert!(insertion_ctx.solution.get_total_cost() < original_cost);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(),
+        10
+    );
+}
+
+parameterized_test! { can_keep_solution_unchanged, (locked_ids, max_time), {
+    can_keep_solution_unchanged_impl(locked_ids, max_time);
+}}
+
+can_keep_solution_unchanged! {
+    case_01_all_locked: (vec!["c0", "c1", "c2", "c3", "c4", "c5", "c6", "c7", "c8", "c9"], 10.),
+    case_02_no_time: (vec![], 0.),
+}
+
+fn can_keep_solution_unchanged_impl(locked_ids: Vec<&str>, max_time: f64) {
+    let insertion_ctx = create_insertion_ctx(locked_ids.as_slice());
+    let expected = get_customer_ids_from_routes(&insertion_ctx);
+
+    let insertion_ctx = ExhaustivePolishing::new(max_time).post_process(insertion_ctx);
+
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), expected);
+}
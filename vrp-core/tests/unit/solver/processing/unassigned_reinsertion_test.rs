@@ -0,0 +1,40 @@
+use super::*;
+use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
+use crate::helpers::construction::heuristics::create_insertion_context;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::TimeWindow;
+use crate::models::solution::Registry;
+
+parameterized_test! {can_insert_unassigned_jobs, (tw, max_time, expected), {
+    can_insert_unassigned_jobs_impl(tw, max_time, expected);
+}}
+
+can_insert_unassigned_jobs! {
+    case01: ((0., 100.), None, None),
+    case02: ((0., 100.), Some(0), None),
+    case03: ((2000., 3000.), None, Some(1)),
+}
+
+fn can_insert_unassigned_jobs_impl(tw: (f64, f64), max_time: Option<usize>, expected: Option<i32>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let constraint = create_constraint_pipeline_with_transport();
+    let mut route_ctx = create_route_context_with_activities(&fleet, "v1", vec![]);
+    constraint.accept_route_state(&mut route_ctx);
+    route_ctx.mark_stale(false);
+    let registry = Registry::new(&fleet, Environment::default().random);
+    let mut insertion_ctx = create_insertion_context(registry, constraint, vec![route_ctx.clone()]);
+    insertion_ctx.solution.registry.use_route(&route_ctx);
+    insertion_ctx.environment = Arc::new(Environment::new_with_time_quota(max_time));
+    let job = SingleBuilder::default().location(Some(10)).times(vec![TimeWindow::new(tw.0, tw.1)]).build_as_job_ref();
+    insertion_ctx.solution.unassigned.insert(job.clone(), 3);
+
+    let insertion_ctx = UnassignedReinsertion::default().post_process(insertion_ctx);
+
+    assert_eq!(insertion_ctx.solution.unassigned.get(&job).cloned(), expected);
+    assert_eq!(
+        insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(),
+        if expected.is_some() { 0 } else { 1 }
+    );
+    assert_eq!(insertion_ctx.environment.quota.is_some(), max_time.is_some());
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::construction::heuristics::{RouteContext, RouteState, SolutionContext};
+use crate::helpers::models::domain::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::models::common::IdDimension;
+use crate::models::solution::CommuteInfo;
+
+fn create_walk_in_commute(parking: Location, distance: Distance, duration: Duration) -> Commute {
+    let info = CommuteInfo { location: parking, distance, duration };
+
+    Commute { forward: info.clone(), backward: info }
+}
+
+fn create_test_jobs() -> Vec<Job> {
+    let mut walk_in = SingleBuilder::default()
+        .id("job1")
+        .location(Some(5))
+        .duration(2.)
+        .times(vec![TimeWindow::new(10., 20.)])
+        .build();
+    walk_in.dimens.set_walk_in(vec![(0, create_walk_in_commute(3, 3., 4.))]);
+
+    vec![Job::Single(Arc::new(walk_in)), SingleBuilder::default().id("job2").location(Some(10)).build_as_job_ref()]
+}
+
+fn create_problems(jobs: Vec<Job>) -> (Arc<Problem>, Arc<Problem>) {
+    let orig_problem = create_problem_with_constraint_jobs_and_fleet(ConstraintPipeline::default(), jobs, test_fleet());
+    let refinement_ctx = create_default_refinement_ctx(orig_problem.clone());
+
+    let new_refinement_ctx = WalkInCommute::default().pre_process(refinement_ctx);
+
+    (orig_problem, new_refinement_ctx.problem.clone())
+}
+
+fn get_job(problem: &Problem, id: &str) -> Job {
+    problem.jobs.all().find(|job| get_job_id(job) == id).unwrap()
+}
+
+#[test]
+fn can_move_walk_in_places_to_parking_on_pre_process() {
+    let (orig_problem, new_problem) = create_problems(create_test_jobs());
+
+    assert_eq!(new_problem.jobs.size(), 2);
+    assert!(get_job(&new_problem, "job2") == get_job(&orig_problem, "job2"));
+    let job = get_job(&new_problem, "job1");
+    let place = job.to_single().places.first().unwrap();
+    assert_eq!(place.location, Some(3));
+    assert_eq!(place.duration, 10.);
+    let time = place.times.first().unwrap().as_time_window().unwrap();
+    assert_eq!((time.start, time.end), (6., 16.));
+}
+
+#[test]
+fn can_skip_pre_process_without_walk_in_jobs() {
+    let jobs = vec![SingleBuilder::default().id("job2").location(Some(10)).build_as_job_ref()];
+    let (orig_problem, new_problem) = create_problems(jobs);
+
+    assert!(Arc::ptr_eq(&orig_problem, &new_problem));
+}
+
+#[test]
+fn can_restore_walk_in_places_on_post_process() {
+    let (orig_problem, new_problem) = create_problems(create_test_jobs());
+    let parking_job = get_job(&new_problem, "job1").to_single().clone();
+    let unassigned_job = get_job(&new_problem, "job2");
+    let insertion_ctx = InsertionContext {
+        problem: new_problem.clone(),
+        solution: SolutionContext {
+            routes: vec![RouteContext::new_with_state(
+                Arc::new(create_route_with_start_end_activities(
+                    new_problem.fleet.as_ref(),
+                    "v1",
+                    test_activity_with_schedule(Schedule::new(0., 0.)),
+                    test_activity_with_schedule(Schedule::new(0., 0.)),
+                    vec![Activity {
+                        place: ActivityPlace { location: 3, duration: 10., time: TimeWindow::new(6., 16.) },
+                        schedule: Schedule::new(3., 16.),
+                        job: Some(parking_job),
+                        commute: None,
+                    }],
+                )),
+                Arc::new(RouteState::default()),
+            )],
+            unassigned: vec![(unassigned_job, 1)].into_iter().collect(),
+            ..create_empty_solution_context()
+        },
+        ..create_empty_insertion_context()
+    };
+
+    let insertion_ctx = WalkInCommute::default().post_process(insertion_ctx);
+
+    assert!(Arc::ptr_eq(&insertion_ctx.problem, &orig_problem));
+    assert!(insertion_ctx.solution.unassigned.contains_key(&get_job(&orig_problem, "job2")));
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    assert!(route_ctx.route.tour.contains(&get_job(&orig_problem, "job1")));
+    let activity = route_ctx.route.tour.get(1).unwrap();
+    assert_eq!(activity.job.as_ref().unwrap().dimens.get_id().unwrap(), "job1");
+    assert_eq!(activity.place.location, 5);
+    assert_eq!(activity.place.duration, 2.);
+    assert_eq!((activity.place.time.start, activity.place.time.end), (10., 20.));
+    assert_eq!((activity.schedule.arrival, activity.schedule.departure), (3., 16.));
+    let commute = activity.commute.as_ref().unwrap();
+    assert_eq!((commute.forward.location, commute.forward.distance, commute.forward.duration), (3, 3., 4.));
+    assert_eq!((commute.backward.location, commute.backward.distance, commute.backward.duration), (3, 3., 4.));
+}
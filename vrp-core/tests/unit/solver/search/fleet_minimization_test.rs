@@ -0,0 +1,59 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::solver::search::{AdjustedStringRemoval, CompositeRuin};
+use crate::solver::GreedyPopulation;
+use crate::utils::Environment;
+
+fn create_test_data(rows: usize, cols: usize) -> (RefinementContext, InsertionContext) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, false);
+    let problem = Arc::new(problem);
+    let population = Box::new(GreedyPopulation::new(problem.objective.clone(), 1, None));
+
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, environment.clone());
+    let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+
+    (refinement_ctx, insertion_ctx)
+}
+
+fn create_operator() -> FleetMinimization {
+    FleetMinimization::new(Arc::new(CompositeRuin::new(vec![(Arc::new(AdjustedStringRemoval::default()), 1.)])), 20)
+}
+
+#[test]
+fn can_reduce_amount_of_routes() {
+    let (refinement_ctx, insertion_ctx) = create_test_data(2, 3);
+
+    let result = create_operator().search(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.solution.routes.len() < insertion_ctx.solution.routes.len());
+    assert!(result.solution.unassigned.is_empty());
+    assert!(result.solution.required.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(), 6);
+    let available = result.solution.registry.resources().available().count();
+    assert_eq!(available + result.solution.routes.len(), 3);
+}
+
+#[test]
+fn can_keep_solution_with_single_route() {
+    let (refinement_ctx, insertion_ctx) = create_test_data(3, 1);
+
+    let result = create_operator().search(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.solution.routes.len(), 1);
+    assert_eq!(result.solution.routes[0].route.tour.job_count(), 3);
+}
+
+#[test]
+fn can_select_jobs_by_absence() {
+    let (_, mut insertion_ctx) = create_test_data(2, 2);
+    let jobs = insertion_ctx.problem.jobs.all().collect::<Vec<_>>();
+    insertion_ctx.solution.required = jobs.clone();
+    let absences = vec![(jobs[2].clone(), 3), (jobs[0].clone(), 1)].into_iter().collect();
+
+    let selected = AbsenceJobSelector { absences }.select(&mut insertion_ctx).collect::<Vec<_>>();
+
+    assert_eq!(selected.len(), 4);
+    assert!(selected[0] == jobs[2]);
+    assert!(selected[1] == jobs[0]);
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::solver::{create_default_heuristic_operator, GreedyPopulation};
+use crate::utils::Environment;
+use std::sync::Arc;
+
+struct IdentityOperator;
+
+impl HeuristicOperator for IdentityOperator {
+    type Context = RefinementContext;
+    type Objective = ProblemObjective;
+    type Solution = InsertionContext;
+
+    fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        solution.deep_copy()
+    }
+}
+
+fn create_test_data() -> (RefinementContext, InsertionContext) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 3, false);
+    let problem = Arc::new(problem);
+    let population = Box::new(GreedyPopulation::new(problem.objective.clone(), 1, None));
+
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, environment.clone());
+    let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+
+    (refinement_ctx, insertion_ctx)
+}
+
+#[test]
+fn can_penalize_max_utility_arcs_in_local_optimum() {
+    let (refinement_ctx, insertion_ctx) = create_test_data();
+    let expected_arcs = get_max_utility_arcs(&insertion_ctx, &HashMap::default());
+    let mut heuristic = GuidedLocalSearch::new(Arc::new(IdentityOperator), 1, 0.3);
+
+    let solutions = heuristic.search(&refinement_ctx, vec![&insertion_ctx]);
+
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].solution.get_total_cost(), insertion_ctx.solution.get_total_cost());
+    assert!(!expected_arcs.is_empty());
+    expected_arcs.iter().for_each(|(from, to)| assert_eq!(heuristic.get_penalty(*from, *to), 1));
+    assert_eq!(heuristic.penalties.values().sum::<usize>(), expected_arcs.len());
+}
+
+#[test]
+fn can_reduce_utility_of_penalized_arcs() {
+    let (_, insertion_ctx) = create_test_data();
+    let arcs = get_max_utility_arcs(&insertion_ctx, &HashMap::default());
+    let penalties = arcs.iter().map(|arc| (*arc, 1)).collect::<HashMap<_, _>>();
+
+    let new_arcs = get_max_utility_arcs(&insertion_ctx, &penalties);
+
+    assert!(!new_arcs.is_empty());
+    assert!(new_arcs.iter().all(|arc| !arcs.contains(arc)));
+}
+
+#[test]
+fn can_keep_best_known_solution() {
+    let (refinement_ctx, insertion_ctx) = create_test_data();
+    let environment = insertion_ctx.environment.clone();
+    let inner_search = create_default_heuristic_operator(insertion_ctx.problem.clone(), environment);
+    let mut heuristic = GuidedLocalSearch::new(inner_search, 3, 0.3);
+
+    let solutions = heuristic.search(&refinement_ctx, vec![&insertion_ctx]);
+
+    assert_eq!(solutions.len(), 1);
+    assert!(solutions[0].solution.unassigned.is_empty());
+    assert!(solutions[0].solution.get_total_cost() <= insertion_ctx.solution.get_total_cost());
+}
@@ -0,0 +1,40 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::solver::*;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+parameterized_test! { can_use_exchange_two_opt, (jobs_order, locked_ids, disallowed_pairs, expected), {
+    can_use_exchange_two_opt_impl(jobs_order, locked_ids, disallowed_pairs, expected);
+}}
+
+can_use_exchange_two_opt! {
+    case_01_uncross: (vec!["c0", "c3", "c2", "c1", "c4"], vec![], vec![], Some(vec!["c0", "c1", "c2", "c3", "c4"])),
+    case_02_no_improvement: (vec!["c0", "c1", "c2", "c3", "c4"], vec![], vec![], None),
+    case_03_locked_job: (vec!["c0", "c3", "c2", "c1", "c4"], vec!["c2"], vec![], Some(vec!["c0", "c3", "c2", "c4", "c1"])),
+    case_04_hard_constraint: (vec!["c0", "c3", "c2", "c1", "c4"], vec![], vec![("c2", "c4")], None),
+}
+
+fn can_use_exchange_two_opt_impl(
+    jobs_order: Vec<&str>,
+    locked_ids: Vec<&str>,
+    disallowed_pairs: Vec<(&str, &str)>,
+    expected: Option<Vec<&str>>,
+) {
+    let (mut problem, solution) = generate_matrix_routes_with_defaults(5, 1, false);
+    add_leg_constraint(&mut problem, disallowed_pairs);
+    let mut insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default())),
+        locked_ids.as_slice(),
+    );
+    rearrange_jobs_in_routes(&mut insertion_ctx, &[jobs_order]);
+
+    let result = ExchangeTwoOpt::default()
+        .explore(&create_default_refinement_ctx(insertion_ctx.problem.clone()), &insertion_ctx)
+        .map(|insertion_ctx| get_customer_ids_from_routes(&insertion_ctx));
+
+    assert_eq!(
+        result,
+        expected.map(|expected| vec![expected.into_iter().map(|id| id.to_string()).collect::<Vec<_>>()])
+    );
+}
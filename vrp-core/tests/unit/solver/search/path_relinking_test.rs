@@ -0,0 +1,95 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_id;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::models::problem::Job;
+use crate::solver::ElitismPopulation;
+use crate::utils::Environment;
+
+fn create_test_data() -> (Arc<Environment>, InsertionContext) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(3, 2, false);
+
+    (environment.clone(), InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment))
+}
+
+fn create_guiding(insertion_ctx: &InsertionContext, job_ids: (&str, &str)) -> InsertionContext {
+    let mut guiding = insertion_ctx.deep_copy();
+    let find_job = |id: &str| insertion_ctx.problem.jobs.all().find(|job| get_customer_id(job) == id).unwrap();
+    let (first, second) = (find_job(job_ids.0), find_job(job_ids.1));
+
+    let get_activity = |job: &Job| {
+        insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .find_map(|route_ctx| route_ctx.route.tour.job_activities(job).next().map(|activity| activity.deep_copy()))
+            .unwrap()
+    };
+    let (first_activity, second_activity) = (get_activity(&first), get_activity(&second));
+
+    guiding.solution.routes.iter_mut().for_each(|route_ctx| {
+        let tour = &mut route_ctx.route_mut().tour;
+        let (removed, activity) = if tour.contains(&first) {
+            (&first, second_activity.deep_copy())
+        } else if tour.contains(&second) {
+            (&second, first_activity.deep_copy())
+        } else {
+            return;
+        };
+        let index = tour.index(removed).unwrap();
+        tour.remove(removed);
+        tour.insert_at(activity, index);
+    });
+    guiding.restore();
+
+    guiding
+}
+
+fn get_route_job_ids(insertion_ctx: &InsertionContext) -> Vec<Vec<String>> {
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| {
+            let mut ids = route_ctx.route.tour.jobs().map(|job| get_customer_id(&job)).collect::<Vec<_>>();
+            ids.sort();
+            ids
+        })
+        .collect()
+}
+
+#[test]
+fn can_get_moves_towards_guiding_solution() {
+    let (_, insertion_ctx) = create_test_data();
+    let guiding = create_guiding(&insertion_ctx, ("c0", "c3"));
+
+    let moves = get_moves(&insertion_ctx, &guiding);
+
+    assert_eq!(moves.len(), 2);
+    assert!(get_moves(&insertion_ctx, &insertion_ctx).is_empty());
+}
+
+#[test]
+fn can_relink_solutions() {
+    let (_, insertion_ctx) = create_test_data();
+    let guiding = create_guiding(&insertion_ctx, ("c0", "c3"));
+
+    let result = relink(&insertion_ctx, &guiding, 10).expect("should make at least one move");
+
+    assert_ne!(get_route_job_ids(&result), get_route_job_ids(&insertion_ctx));
+    assert!(result.solution.required.is_empty());
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(), 6);
+}
+
+#[test]
+fn can_keep_solution_without_guiding_solutions() {
+    let (environment, insertion_ctx) = create_test_data();
+    let problem = insertion_ctx.problem.clone();
+    let population = Box::new(ElitismPopulation::new(problem.objective.clone(), environment.random.clone(), 4, 4));
+    let refinement_ctx = RefinementContext::new(problem, population, environment);
+
+    let result = PathRelinking::default().search(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(get_route_job_ids(&result), get_route_job_ids(&insertion_ctx));
+}
@@ -0,0 +1,54 @@
+use super::*;
+use crate::helpers::solver::generate_matrix_routes_with_defaults;
+use crate::solver::search::ExchangeInterRouteBest;
+use crate::solver::GreedyPopulation;
+use crate::utils::Environment;
+
+fn create_test_data(rows: usize, cols: usize) -> (RefinementContext, InsertionContext) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(rows, cols, false);
+    let problem = Arc::new(problem);
+    let population = Box::new(GreedyPopulation::new(problem.objective.clone(), 1, None));
+
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, environment.clone());
+    let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+
+    (refinement_ctx, insertion_ctx)
+}
+
+fn create_operator(max_progress: f64) -> RouteMinimization {
+    RouteMinimization::new(Arc::new(ExchangeInterRouteBest::default()), 100, max_progress)
+}
+
+#[test]
+fn can_reduce_amount_of_routes() {
+    let (refinement_ctx, insertion_ctx) = create_test_data(2, 3);
+
+    let result = create_operator(1.).search(&refinement_ctx, &insertion_ctx);
+
+    assert!(result.solution.routes.len() < insertion_ctx.solution.routes.len());
+    assert!(result.solution.unassigned.is_empty());
+    assert!(result.solution.required.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>(), 6);
+    let available = result.solution.registry.resources().available().count();
+    assert_eq!(available + result.solution.routes.len(), 3);
+}
+
+#[test]
+fn can_keep_solution_with_single_route() {
+    let (refinement_ctx, insertion_ctx) = create_test_data(3, 1);
+
+    let result = create_operator(1.).search(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.solution.routes.len(), 1);
+    assert_eq!(result.solution.routes[0].route.tour.job_count(), 3);
+}
+
+#[test]
+fn can_skip_search_at_late_stage() {
+    let (refinement_ctx, insertion_ctx) = create_test_data(2, 3);
+
+    let result = create_operator(-1.).search(&refinement_ctx, &insertion_ctx);
+
+    assert_eq!(result.solution.routes.len(), insertion_ctx.solution.routes.len());
+}
@@ -0,0 +1,84 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_id;
+use crate::helpers::models::problem::test_single_with_id_and_location;
+use crate::helpers::solver::*;
+use crate::helpers::utils::random::FakeRandom;
+use crate::models::Problem;
+use std::sync::Arc;
+
+fn create_insertion_ctx(has_coordinates: bool, random: Arc<dyn Random + Send + Sync>) -> InsertionContext {
+    let (problem, solution) = generate_matrix_routes(
+        8,
+        1,
+        false,
+        |id, location| test_single_with_id_and_location(id, location),
+        |v| v,
+        |data| (data.clone(), data),
+    );
+
+    let mut extras = Extras::default();
+    if has_coordinates {
+        extras.set_location_coordinates(LocationCoordinates::new((0..8).map(|idx| Some((idx as f64, 0.))).collect()));
+    }
+    let problem = Arc::new(Problem { extras: Arc::new(extras), ..problem });
+    let environment = Arc::new(Environment { random, ..Environment::default() });
+
+    InsertionContext::new_from_solution(problem, (solution, None), environment)
+}
+
+parameterized_test! {can_ruin_jobs_in_zone, (has_coordinates, shape, expected), {
+    can_ruin_jobs_in_zone_impl(has_coordinates, shape, expected);
+}}
+
+can_ruin_jobs_in_zone! {
+    case_01_rectangle: (true, 0, vec!["c2", "c3", "c4"]),
+    case_02_circle: (true, 1, vec!["c2", "c3", "c4"]),
+    case_03_no_coordinates: (false, 0, vec![]),
+}
+
+fn can_ruin_jobs_in_zone_impl(has_coordinates: bool, shape: i32, expected: Vec<&str>) {
+    let insertion_ctx = create_insertion_ctx(has_coordinates, Arc::new(FakeRandom::new(vec![3, shape], vec![])));
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let ruin = ZoneRemoval::new(RuinLimits::new(1, 64, 1., 8), 0.3, 0.05);
+
+    let insertion_ctx = ruin.run(&refinement_ctx, insertion_ctx);
+
+    let mut removed = insertion_ctx.solution.required.iter().map(get_customer_id).collect::<Vec<_>>();
+    removed.sort();
+    assert_eq!(removed, expected);
+}
+
+#[test]
+fn can_respect_ruin_limits() {
+    let insertion_ctx = create_insertion_ctx(true, Arc::new(FakeRandom::new(vec![3, 0], vec![])));
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+    let ruin = ZoneRemoval::new(RuinLimits::new(1, 1, 1., 8), 1., 1.);
+
+    let insertion_ctx = ruin.run(&refinement_ctx, insertion_ctx);
+
+    assert_eq!(insertion_ctx.solution.required.iter().map(get_customer_id).collect::<Vec<_>>(), vec!["c3"]);
+}
+
+parameterized_test! {can_check_zone_contains_point, (zone, point, expected), {
+    assert_eq!(zone.contains(point), expected);
+}}
+
+can_check_zone_contains_point! {
+    case_01: (Zone::Rectangle { center: (0., 0.), half_size: (2., 1.) }, (1.5, -1.), true),
+    case_02: (Zone::Rectangle { center: (0., 0.), half_size: (2., 1.) }, (1.5, 1.5), false),
+    case_03: (Zone::Circle { center: (1., 1.), radius: 1. }, (1.5, 1.5), true),
+    case_04: (Zone::Circle { center: (1., 1.), radius: 1. }, (2., 2.), false),
+    case_05: (Zone::Polygon { vertices: vec![(0., 0.), (4., 0.), (4., 4.), (0., 4.)] }, (2., 2.), true),
+    case_06: (Zone::Polygon { vertices: vec![(0., 0.), (4., 0.), (0., 4.)] }, (3., 3.), false),
+    case_07: (Zone::Polygon { vertices: vec![(0., 0.), (4., 0.), (0., 4.)] }, (1., 1.), true),
+}
+
+#[test]
+fn can_create_polygon_zone_around_center() {
+    let random = FakeRandom::new(vec![2, 4], vec![0.1, 0.9, 0.5, 1., 1.2, 0.7, 0.3, 0.6]);
+
+    let zone = Zone::new_random((5., 5.), (2., 2.), &random);
+
+    assert!(matches!(&zone, Zone::Polygon { vertices } if vertices.len() == 4));
+    assert!(zone.contains((5., 5.)));
+}
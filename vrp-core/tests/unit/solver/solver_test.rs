@@ -0,0 +1,286 @@
+use super::*;
+use crate::construction::constraints::*;
+use crate::construction::heuristics::{RouteContext, SolutionContext};
+use crate::helpers::models::problem::SingleBuilder;
+use crate::models::common::TimeWindow;
+use crate::models::examples::create_example_problem;
+use std::slice::Iter;
+use std::sync::atomic::AtomicBool;
+
+fn create_solver(problem: Arc<Problem>) -> Solver {
+    let environment = Arc::new(Environment::new_with_time_quota(Some(10)));
+    let config = create_default_config_builder(problem.clone(), environment)
+        .with_max_generations(Some(10))
+        .build()
+        .expect("cannot build config");
+
+    Solver::new(problem, config)
+}
+
+#[test]
+fn can_insert_new_jobs_into_existing_solution() {
+    let problem = create_example_problem();
+    let (solution, cost, _) = create_solver(problem.clone()).solve().unwrap();
+    let job =
+        SingleBuilder::default().id("job2").location(Some(2)).times(vec![TimeWindow::new(0., 100.)]).build_as_job_ref();
+
+    let (new_solution, marginal_cost) = create_solver(problem).insert_jobs(&solution, vec![job.clone()]).unwrap();
+
+    assert_eq!(cost, 42.);
+    assert_eq!(marginal_cost, 42.);
+    assert!(new_solution.unassigned.is_empty());
+    assert_eq!(new_solution.routes.len(), 1);
+    assert_eq!(new_solution.routes[0].tour.job_count(), 2);
+    assert!(new_solution.routes[0].tour.contains(&job));
+    assert_eq!(solution.routes[0].tour.job_count(), 1);
+}
+
+#[test]
+fn can_reject_insertion_of_already_present_job() {
+    let problem = create_example_problem();
+    let (solution, _, _) = create_solver(problem.clone()).solve().unwrap();
+    let job = solution.routes[0].tour.jobs().next().unwrap();
+
+    let result = create_solver(problem).insert_jobs(&solution, vec![job]);
+
+    assert_eq!(result.err(), Some("cannot insert job which is already present in the solution".to_string()));
+}
+
+#[test]
+fn can_interrupt_solver_and_return_best_known_solution() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::new_with_time_quota(Some(300)));
+    let interrupt = Arc::new(AtomicBool::new(false));
+    let config = create_default_config_builder(problem.clone(), environment)
+        .with_max_time(Some(300))
+        .with_max_generations(Some(usize::MAX))
+        .with_interrupt(interrupt.clone())
+        .build()
+        .expect("cannot build config");
+
+    let handle = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        interrupt.store(true, std::sync::atomic::Ordering::Relaxed);
+    });
+    let (solution, cost, _) = Solver::new(problem, config).solve().unwrap();
+    handle.join().unwrap();
+
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+}
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut context = std::task::Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            std::task::Poll::Ready(result) => return result,
+            std::task::Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[test]
+fn can_solve_async_and_report_progress() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::new_with_time_quota(Some(10)));
+    let config = create_default_config_builder(problem.clone(), environment)
+        .with_max_generations(Some(10))
+        .build()
+        .expect("cannot build config");
+
+    let (future, receiver) = Solver::new(problem, config).solve_async();
+    let (solution, cost, _) = block_on(future).unwrap();
+    let progress = receiver.try_iter().collect::<Vec<_>>();
+
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+    assert!(!progress.is_empty());
+    assert!(progress.windows(2).all(|pair| pair[0].generation < pair[1].generation));
+    assert!(progress.iter().all(|progress| progress.cost == 42.));
+    assert!(progress.iter().all(|progress| progress.is_improvement == progress.solution.is_some()));
+}
+
+#[test]
+fn can_call_solution_callback_when_best_known_is_improved() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::new_with_time_quota(Some(10)));
+    let generations = Arc::new(Mutex::new(Vec::<(usize, usize)>::new()));
+    let config = create_default_config_builder(problem.clone(), environment)
+        .with_max_generations(Some(10))
+        .with_solution_callback(Box::new({
+            let generations = generations.clone();
+            move |insertion_ctx: &InsertionContext, statistics: &HeuristicStatistics| {
+                generations.lock().unwrap().push((statistics.generation, insertion_ctx.solution.routes.len()))
+            }
+        }))
+        .build()
+        .expect("cannot build config");
+
+    let (solution, cost, _) = Solver::new(problem, config).solve().unwrap();
+
+    let generations = generations.lock().unwrap();
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+    assert!(!generations.is_empty());
+    assert_eq!(generations[0].0, 0);
+    assert!(generations.iter().all(|(_, routes)| *routes == 1));
+    assert!(generations.windows(2).all(|pair| pair[0].0 < pair[1].0));
+}
+
+#[test]
+fn can_solve_problem_with_restarts_and_report_their_metrics() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::new_with_time_quota(Some(10)));
+    let telemetry = Telemetry::new(TelemetryMode::OnlyMetrics { track_population: 1000, snapshot_population: None });
+    let config = create_default_config_builder(problem.clone(), environment)
+        .with_telemetry(telemetry)
+        .with_max_generations(Some(10))
+        .with_restarts(3)
+        .build()
+        .expect("cannot build config");
+
+    let (solution, cost, metrics) = Solver::new(problem, config).solve().unwrap();
+
+    let restarts = metrics.expect("no metrics").restarts;
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(restarts.len(), 3);
+    assert!(restarts.iter().all(|restart| restart.generations > 0));
+}
+
+#[test]
+fn can_reject_restarts_with_custom_strategy() {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::new_with_time_quota(Some(10)));
+
+    let result = create_default_config_builder(problem, environment)
+        .with_strategy(Box::new(RunSimple::new(1)))
+        .with_restarts(2)
+        .build();
+
+    assert_eq!(result.err(), Some("restarts cannot be used with custom strategy".to_string()));
+}
+
+parameterized_test! {can_solve_problem_using_preset, preset, {
+    can_solve_problem_using_preset_impl(preset);
+}}
+
+can_solve_problem_using_preset! {
+    case01: Preset::FastFeasible,
+    case02: Preset::Balanced,
+    case03: Preset::HighQuality,
+}
+
+fn can_solve_problem_using_preset_impl(preset: Preset) {
+    let problem = create_example_problem();
+    let environment = Arc::new(Environment::new_with_time_quota(Some(10)));
+    let config = create_preset_config_builder(problem.clone(), environment, preset)
+        .with_max_generations(Some(10))
+        .build()
+        .expect("cannot build config");
+
+    let (solution, cost, _) = Solver::new(problem, config).solve().unwrap();
+
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+}
+
+struct CorruptedScheduleModule {
+    constraints: Vec<ConstraintVariant>,
+}
+
+impl ConstraintModule for CorruptedScheduleModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| {
+            if let Some(activity) = route_ctx.route_mut().tour.all_activities_mut().last() {
+                activity.schedule.departure = activity.schedule.arrival - 1.;
+            }
+        });
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        [].iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+#[test]
+fn can_stop_solver_with_error_on_inconsistent_state_in_strict_mode() {
+    let example = create_example_problem();
+    let mut constraint = ConstraintPipeline::default();
+    constraint.add_module(Arc::new(TransportConstraintModule::new(
+        example.transport.clone(),
+        example.activity.clone(),
+        Arc::new(|_| (None, None)),
+        1,
+        2,
+        3,
+    )));
+    constraint.add_module(Arc::new(CorruptedScheduleModule { constraints: vec![] }));
+    let problem = Arc::new(Problem {
+        fleet: example.fleet.clone(),
+        jobs: example.jobs.clone(),
+        locks: vec![],
+        constraint: Arc::new(constraint),
+        activity: example.activity.clone(),
+        transport: example.transport.clone(),
+        objective: example.objective.clone(),
+        extras: example.extras.clone(),
+    });
+    let environment = Arc::new(Environment {
+        random: Arc::new(DefaultRandom::new_with_seed(42)),
+        is_strict: true,
+        ..Environment::new_with_time_quota(Some(10))
+    });
+    let config = create_default_config_builder(problem.clone(), environment)
+        .with_max_generations(Some(10))
+        .build()
+        .expect("cannot build config");
+
+    let result = Solver::new(problem, config).solve();
+
+    let err = result.err().expect("should fail in strict mode");
+    assert!(err.starts_with("strict mode violation: inconsistent solution state: non-monotonic schedule"));
+    assert!(err.ends_with("seed: 42, generation: 0"));
+}
+
+#[test]
+fn can_save_and_restore_evolution_state() {
+    let path = std::env::temp_dir().join(format!("vrp_core_solver_{}.checkpoint", std::process::id()));
+    let problem = create_example_problem();
+
+    let (_, cost, _) = create_solver(problem.clone()).save_state(path.clone(), 2).solve().unwrap();
+    let content = std::fs::read_to_string(path.as_path()).unwrap();
+    let result = create_solver(problem.clone()).restore_state(path.as_path()).and_then(|solver| solver.solve());
+    let _ = std::fs::remove_file(path);
+
+    assert_eq!(cost, 42.);
+    assert!(content.starts_with("version 2\n"));
+    assert!(content.lines().any(|line| line.starts_with("heuristic ")));
+    assert!(content.lines().any(|line| line.starts_with("solution ")));
+    let (solution, cost, _) = result.unwrap();
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+}
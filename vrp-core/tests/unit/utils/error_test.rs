@@ -0,0 +1,16 @@
+use super::*;
+
+#[test]
+fn can_keep_message_as_display_text() {
+    let errors = vec![
+        VrpError::Validation("message".to_string()),
+        VrpError::Matrix("message".to_string()),
+        VrpError::Config("message".to_string()),
+        VrpError::Io("message".to_string()),
+    ];
+
+    errors.into_iter().for_each(|error| {
+        assert_eq!(error.to_string(), "message");
+        assert_eq!(String::from(error), "message");
+    });
+}
@@ -0,0 +1,51 @@
+#ifndef VRP_FFI_H
+#define VRP_FFI_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Result codes. */
+#define VRP_OK 0
+#define VRP_ERROR_INVALID_ARGUMENT 1
+#define VRP_ERROR_INVALID_PROBLEM 2
+#define VRP_ERROR_INVALID_CONFIG 3
+#define VRP_ERROR_SOLVER 4
+#define VRP_ERROR_INTERNAL 5
+
+/* A token which is used to cancel running search from another thread. */
+typedef struct VrpCancellationToken VrpCancellationToken;
+
+/* Creates a new cancellation token. Must be released with vrp_cancellation_token_free. */
+VrpCancellationToken *vrp_cancellation_token_new(void);
+
+/* Requests cancellation: the search is stopped and the best known solution is returned. */
+void vrp_cancel(const VrpCancellationToken *token);
+
+/* Releases cancellation token. */
+void vrp_cancellation_token_free(VrpCancellationToken *token);
+
+/* Releases a string returned by the library. */
+void vrp_string_free(char *value);
+
+/*
+ * Solves Vehicle Routing Problem passed in pragmatic format.
+ * `matrices` can be NULL when `matrices_len` is zero, `config` and `token` can be NULL.
+ * On return, `result` contains either solution or error message which must be released
+ * with vrp_string_free.
+ */
+int32_t vrp_solve(const char *problem,
+                  const char *const *matrices,
+                  size_t matrices_len,
+                  const char *config,
+                  const VrpCancellationToken *token,
+                  char **result);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* VRP_FFI_H */
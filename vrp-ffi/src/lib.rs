@@ -0,0 +1,190 @@
+//! A crate which provides a stable C interface to solve ***Vehicle Routing Problem*** defined in
+//! `pragmatic` format, so the solver can be embedded into applications written in other languages
+//! such as C++, C# or Java.
+//!
+//! All functions operate on null terminated UTF-8 json strings. Functions which produce a string
+//! return an integer result code (see `VRP_*` constants) and write either a result or an error
+//! message into output parameter. The output string is owned by the caller and must be released
+//! with `vrp_string_free`. Function declarations are available in `include/vrp_ffi.h` file.
+
+#![warn(missing_docs)]
+
+#[cfg(test)]
+#[path = "../tests/unit/lib_test.rs"]
+mod lib_test;
+
+use std::ffi::{CStr, CString};
+use std::io::BufReader;
+use std::os::raw::c_char;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use vrp_cli::extensions::solve::config::{read_config, Config};
+use vrp_cli::{get_pragmatic_solution_serialized, SolveError};
+use vrp_pragmatic::format::FormatError;
+
+/// A function call is completed successfully.
+pub const VRP_OK: i32 = 0;
+/// One of arguments is a null pointer or not a valid UTF-8 string.
+pub const VRP_ERROR_INVALID_ARGUMENT: i32 = 1;
+/// A problem or routing matrices cannot be read or they are not valid.
+pub const VRP_ERROR_INVALID_PROBLEM: i32 = 2;
+/// An algorithm configuration cannot be read or it is not valid.
+pub const VRP_ERROR_INVALID_CONFIG: i32 = 3;
+/// The solver has failed to find a solution.
+pub const VRP_ERROR_SOLVER: i32 = 4;
+/// An unexpected internal error (panic) has happened.
+pub const VRP_ERROR_INTERNAL: i32 = 5;
+
+/// A result of internal function call: an error is represented by result code and message.
+type FfiResult<T> = Result<T, (i32, String)>;
+
+/// A token which is used to cancel running search from another thread.
+pub struct VrpCancellationToken {
+    interrupt: Arc<AtomicBool>,
+}
+
+/// Creates a new cancellation token. The token must be released with `vrp_cancellation_token_free`.
+#[no_mangle]
+pub extern "C" fn vrp_cancellation_token_new() -> *mut VrpCancellationToken {
+    Box::into_raw(Box::new(VrpCancellationToken { interrupt: Arc::new(AtomicBool::new(false)) }))
+}
+
+/// Requests cancellation: the search is stopped and the best known solution is returned.
+/// Can be called from any thread.
+///
+/// # Safety
+/// `token` should be either null or a valid pointer returned by `vrp_cancellation_token_new`.
+#[no_mangle]
+pub unsafe extern "C" fn vrp_cancel(token: *const VrpCancellationToken) {
+    if let Some(token) = token.as_ref() {
+        token.interrupt.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Releases cancellation token.
+///
+/// # Safety
+/// `token` should be either null or a valid pointer returned by `vrp_cancellation_token_new`
+/// which is not used by running `vrp_solve` call.
+#[no_mangle]
+pub unsafe extern "C" fn vrp_cancellation_token_free(token: *mut VrpCancellationToken) {
+    if !token.is_null() {
+        drop(Box::from_raw(token));
+    }
+}
+
+/// Releases a string returned by the library.
+///
+/// # Safety
+/// `value` should be either null or a valid pointer to a string returned by the library.
+#[no_mangle]
+pub unsafe extern "C" fn vrp_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
+
+/// Solves Vehicle Routing Problem passed in `pragmatic` format.
+///
+/// * `problem`: a problem definition in json.
+/// * `matrices`: an array of routing matrices in json, can be null if `matrices_len` is zero.
+/// * `config`: an algorithm configuration in json, can be null to use defaults.
+/// * `token`: a cancellation token, can be null.
+/// * `result`: an output parameter which receives either solution in json or an error message.
+///
+/// Returns `VRP_OK` on success or one of `VRP_ERROR_*` codes.
+///
+/// # Safety
+/// All non-null pointers should point to valid null terminated strings, `matrices` should contain
+/// at least `matrices_len` elements, `result` should be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn vrp_solve(
+    problem: *const c_char,
+    matrices: *const *const c_char,
+    matrices_len: usize,
+    config: *const c_char,
+    token: *const VrpCancellationToken,
+    result: *mut *mut c_char,
+) -> i32 {
+    if result.is_null() {
+        return VRP_ERROR_INVALID_ARGUMENT;
+    }
+
+    let interrupt = token.as_ref().map(|token| token.interrupt.clone());
+
+    let (code, message) = match panic::catch_unwind(AssertUnwindSafe(|| {
+        let problem = read_string(problem)?;
+        let matrices = read_strings(matrices, matrices_len)?;
+        let config = if config.is_null() { None } else { Some(read_string(config)?) };
+
+        solve_pragmatic(problem, matrices, config, interrupt)
+    })) {
+        Ok(Ok(solution)) => (VRP_OK, solution),
+        Ok(Err((code, message))) => (code, message),
+        Err(err) => (VRP_ERROR_INTERNAL, get_panic_message(err)),
+    };
+
+    *result = to_raw_string(message);
+
+    code
+}
+
+/// Solves problem passed in `pragmatic` format and returns solution serialized in json.
+fn solve_pragmatic(
+    problem: String,
+    matrices: Vec<String>,
+    config: Option<String>,
+    interrupt: Option<Arc<AtomicBool>>,
+) -> FfiResult<String> {
+    let config = match config {
+        Some(config) => read_config(BufReader::new(config.as_bytes())).map_err(SolveError::Config),
+        None => Ok(Config::default()),
+    };
+
+    config.and_then(|config| get_pragmatic_solution_serialized(problem, matrices, config, interrupt, None)).map_err(
+        |err| match err {
+            SolveError::Problem(errors) => (VRP_ERROR_INVALID_PROBLEM, FormatError::format_many_to_json(&errors)),
+            SolveError::Config(err) => (VRP_ERROR_INVALID_CONFIG, err),
+            SolveError::Solver(err) => (VRP_ERROR_SOLVER, err),
+        },
+    )
+}
+
+unsafe fn read_string(pointer: *const c_char) -> FfiResult<String> {
+    if pointer.is_null() {
+        return Err((VRP_ERROR_INVALID_ARGUMENT, "null pointer is passed as a string".to_string()));
+    }
+
+    CStr::from_ptr(pointer)
+        .to_str()
+        .map(|value| value.to_string())
+        .map_err(|err| (VRP_ERROR_INVALID_ARGUMENT, format!("string is not valid UTF-8: {}", err)))
+}
+
+unsafe fn read_strings(pointers: *const *const c_char, len: usize) -> FfiResult<Vec<String>> {
+    if len == 0 {
+        return Ok(vec![]);
+    }
+
+    if pointers.is_null() {
+        return Err((VRP_ERROR_INVALID_ARGUMENT, "null pointer is passed as an array".to_string()));
+    }
+
+    slice::from_raw_parts(pointers, len).iter().map(|pointer| read_string(*pointer)).collect()
+}
+
+fn to_raw_string(value: String) -> *mut c_char {
+    // NOTE interior null bytes are not expected in json, but they should not break the caller
+    CString::new(value.replace('\0', "")).map_or(ptr::null_mut(), |value| value.into_raw())
+}
+
+fn get_panic_message(err: Box<dyn std::any::Any + Send>) -> String {
+    err.downcast_ref::<&str>()
+        .cloned()
+        .or_else(|| err.downcast_ref::<String>().map(|str| str.as_str()))
+        .map(|msg| format!("panic: '{}'", msg))
+        .unwrap_or_else(|| "panic with unknown type".to_string())
+}
@@ -0,0 +1,95 @@
+use super::*;
+use std::thread;
+use std::time::Duration;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const CONFIG: &str = r#"{"termination": {"maxGenerations": 10}}"#;
+
+fn read_problem() -> CString {
+    CString::new(std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap()).unwrap()
+}
+
+fn solve(problem: &CString, config: Option<&str>, token: *const VrpCancellationToken) -> (i32, String) {
+    let config = config.map(|config| CString::new(config).unwrap());
+    let mut result: *mut c_char = ptr::null_mut();
+
+    let code = unsafe {
+        vrp_solve(
+            problem.as_ptr(),
+            ptr::null(),
+            0,
+            config.as_ref().map_or(ptr::null(), |config| config.as_ptr()),
+            token,
+            &mut result,
+        )
+    };
+
+    assert!(!result.is_null());
+    let message = unsafe { CStr::from_ptr(result).to_str().unwrap().to_string() };
+    unsafe { vrp_string_free(result) };
+
+    (code, message)
+}
+
+#[test]
+fn can_solve_problem() {
+    let (code, solution) = solve(&read_problem(), Some(CONFIG), ptr::null());
+
+    assert_eq!(code, VRP_OK);
+    assert!(solution.contains("\"tours\""));
+}
+
+#[test]
+fn can_return_error_code_for_invalid_problem() {
+    let (code, message) = solve(&CString::new("{}").unwrap(), Some(CONFIG), ptr::null());
+
+    assert_eq!(code, VRP_ERROR_INVALID_PROBLEM);
+    assert!(message.contains("code"));
+}
+
+#[test]
+fn can_return_error_code_for_invalid_config() {
+    let (code, message) = solve(&read_problem(), Some(r#"{"termination": 1}"#), ptr::null());
+
+    assert_eq!(code, VRP_ERROR_INVALID_CONFIG);
+    assert!(!message.is_empty());
+}
+
+#[test]
+fn can_return_error_code_for_null_pointers() {
+    let mut result: *mut c_char = ptr::null_mut();
+
+    let code = unsafe { vrp_solve(ptr::null(), ptr::null(), 0, ptr::null(), ptr::null(), &mut result) };
+    assert_eq!(code, VRP_ERROR_INVALID_ARGUMENT);
+    unsafe { vrp_string_free(result) };
+
+    let problem = read_problem();
+    let code = unsafe { vrp_solve(problem.as_ptr(), ptr::null(), 1, ptr::null(), ptr::null(), &mut result) };
+    assert_eq!(code, VRP_ERROR_INVALID_ARGUMENT);
+    unsafe { vrp_string_free(result) };
+
+    let code = unsafe { vrp_solve(problem.as_ptr(), ptr::null(), 0, ptr::null(), ptr::null(), ptr::null_mut()) };
+    assert_eq!(code, VRP_ERROR_INVALID_ARGUMENT);
+}
+
+#[test]
+fn can_cancel_search() {
+    struct TokenPtr(*mut VrpCancellationToken);
+    unsafe impl Send for TokenPtr {}
+
+    let token = TokenPtr(vrp_cancellation_token_new());
+    let token_ptr = token.0;
+    let canceller = thread::spawn(move || {
+        let token = token;
+        thread::sleep(Duration::from_millis(500));
+        unsafe { vrp_cancel(token.0) };
+    });
+
+    let config = r#"{"termination": {"maxGenerations": 10000000, "maxTime": 300}}"#;
+    let (code, solution) = solve(&read_problem(), Some(config), token_ptr);
+
+    canceller.join().unwrap();
+    unsafe { vrp_cancellation_token_free(token_ptr) };
+    assert_eq!(code, VRP_OK);
+    assert!(solution.contains("\"tours\""));
+}
@@ -0,0 +1,8 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // NOTE use vendored protoc to avoid dependency on system wide installation
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+
+    tonic_build::compile_protos("proto/vrp.proto")?;
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use crate::proto;
+use vrp_pragmatic::format::solution::*;
+use vrp_pragmatic::format::Location;
+
+/// Converts solution in pragmatic format to its protobuf representation.
+pub(crate) fn to_proto_solution(solution: &Solution) -> proto::Solution {
+    proto::Solution {
+        statistic: Some(to_proto_statistic(&solution.statistic)),
+        tours: solution.tours.iter().map(to_proto_tour).collect(),
+        unassigned: solution
+            .unassigned
+            .iter()
+            .flat_map(|unassigned| unassigned.iter())
+            .map(|job| proto::UnassignedJob {
+                job_id: job.job_id.clone(),
+                reasons: job
+                    .reasons
+                    .iter()
+                    .map(|reason| proto::UnassignedJobReason {
+                        code: reason.code.clone(),
+                        description: reason.description.clone(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+fn to_proto_statistic(statistic: &Statistic) -> proto::Statistic {
+    let times = &statistic.times;
+
+    proto::Statistic {
+        cost: statistic.cost,
+        distance: statistic.distance,
+        duration: statistic.duration,
+        times: Some(proto::Timing {
+            driving: times.driving,
+            serving: times.serving,
+            waiting: times.waiting,
+            break_time: times.break_time,
+            commuting: times.commuting,
+            parking: times.parking,
+        }),
+    }
+}
+
+fn to_proto_tour(tour: &Tour) -> proto::Tour {
+    proto::Tour {
+        vehicle_id: tour.vehicle_id.clone(),
+        type_id: tour.type_id.clone(),
+        shift_index: tour.shift_index as u64,
+        stops: tour.stops.iter().map(to_proto_stop).collect(),
+        statistic: Some(to_proto_statistic(&tour.statistic)),
+    }
+}
+
+fn to_proto_stop(stop: &Stop) -> proto::Stop {
+    let (location, distance) = match stop {
+        Stop::Point(point) => (Some(to_proto_location(&point.location)), point.distance),
+        Stop::Transit(_) => (None, 0),
+    };
+    let schedule = stop.schedule();
+
+    proto::Stop {
+        location,
+        time: Some(proto::Schedule { arrival: schedule.arrival.clone(), departure: schedule.departure.clone() }),
+        distance,
+        load: stop.load().clone(),
+        activities: stop
+            .activities()
+            .iter()
+            .map(|activity| proto::Activity {
+                job_id: activity.job_id.clone(),
+                r#type: activity.activity_type.clone(),
+                location: activity.location.as_ref().map(to_proto_location),
+                time: activity
+                    .time
+                    .as_ref()
+                    .map(|time| proto::Interval { start: time.start.clone(), end: time.end.clone() }),
+                job_tag: activity.job_tag.clone(),
+            })
+            .collect(),
+    }
+}
+
+fn to_proto_location(location: &Location) -> proto::Location {
+    let kind = match location {
+        Location::Coordinate { lat, lng } => {
+            proto::location::Kind::Coordinate(proto::Coordinate { lat: *lat, lng: *lng })
+        }
+        Location::Reference { index } => proto::location::Kind::Index(*index as u64),
+    };
+
+    proto::Location { kind: Some(kind) }
+}
@@ -0,0 +1,232 @@
+use crate::convert::to_proto_solution;
+use crate::proto::{JobStatus, ProgressResponse, Solution as ProtoSolution};
+use hashbrown::HashMap;
+use std::io::BufReader;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::time::Instant;
+use vrp_cli::extensions::solve::config::*;
+use vrp_cli::get_errors_serialized;
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::prelude::Objective;
+use vrp_pragmatic::format::problem::PragmaticProblem;
+use vrp_pragmatic::format::solution::create_solution;
+
+/// Specifies solver job limits.
+#[derive(Clone, Debug)]
+pub struct JobLimits {
+    /// Max amount of jobs which can run concurrently.
+    pub max_jobs: usize,
+    /// Max running time of a single job in seconds. It is used as a default time quota.
+    pub max_time: usize,
+    /// Max amount of finished jobs which are kept to get their results. The oldest ones are evicted.
+    pub max_finished_jobs: usize,
+}
+
+impl Default for JobLimits {
+    fn default() -> Self {
+        Self { max_jobs: 4, max_time: 300, max_finished_jobs: 100 }
+    }
+}
+
+/// Specifies a job submission error.
+#[derive(Debug, PartialEq)]
+pub enum JobError {
+    /// Job definition is not valid.
+    InvalidArgument(String),
+    /// Max amount of running jobs is reached.
+    LimitExceeded(String),
+    /// Job with given id is not found.
+    NotFound(String),
+}
+
+/// A solver job definition.
+pub struct JobDefinition {
+    /// A problem definition in pragmatic json format.
+    pub problem: String,
+    /// Routing matrices in pragmatic json format.
+    pub matrices: Vec<String>,
+    /// An algorithm configuration in json format.
+    pub config: Option<String>,
+    /// A time quota in seconds.
+    pub max_time: Option<usize>,
+}
+
+struct JobState {
+    status: JobStatus,
+    generation: usize,
+    cost: Option<f64>,
+    solution: Option<ProtoSolution>,
+    error: Option<String>,
+    finished: Option<Instant>,
+}
+
+struct SolverJob {
+    interrupt: Arc<AtomicBool>,
+    state: Mutex<JobState>,
+}
+
+/// Manages solver jobs which run concurrently in background threads.
+pub struct JobManager {
+    limits: JobLimits,
+    jobs: RwLock<HashMap<String, Arc<SolverJob>>>,
+    counter: AtomicUsize,
+}
+
+impl JobManager {
+    /// Creates a new instance of `JobManager`.
+    pub fn new(limits: JobLimits) -> Self {
+        Self { limits, jobs: Default::default(), counter: AtomicUsize::new(0) }
+    }
+
+    /// Validates job definition and starts the search in background. Returns job id.
+    pub fn submit(&self, definition: JobDefinition) -> Result<String, JobError> {
+        let JobDefinition { problem, matrices, config, max_time } = definition;
+
+        let problem = if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+            .map_err(|errors| JobError::InvalidArgument(get_errors_serialized(&errors)))?;
+        let problem = Arc::new(problem);
+
+        let mut config = match config {
+            Some(config) => read_config(BufReader::new(config.as_bytes())).map_err(JobError::InvalidArgument)?,
+            None => Config::default(),
+        };
+
+        // NOTE time quota is always set to prevent jobs which run forever
+        let max_time = max_time.map_or(self.limits.max_time, |max_time| max_time.min(self.limits.max_time));
+        config.termination = Some(match config.termination {
+            Some(termination) => TerminationConfig {
+                max_time: Some(termination.max_time.map_or(max_time, |time| time.min(max_time))),
+                ..termination
+            },
+            None => TerminationConfig { max_time: Some(max_time), max_generations: None, variation: None },
+        });
+
+        let job = Arc::new(SolverJob {
+            interrupt: Arc::new(AtomicBool::new(false)),
+            state: Mutex::new(JobState {
+                status: JobStatus::Running,
+                generation: 0,
+                cost: None,
+                solution: None,
+                error: None,
+                finished: None,
+            }),
+        });
+
+        let builder = create_builder_from_config(problem.clone(), &config).map_err(JobError::InvalidArgument)?;
+        let mut solver_config =
+            builder.with_interrupt(job.interrupt.clone()).build().map_err(JobError::InvalidArgument)?;
+
+        let objective = problem.objective.clone();
+        let progress_job = job.clone();
+        solver_config.telemetry.add_generation_callback(Box::new(move |refinement_ctx, statistics, _| {
+            if let Some((insertion_ctx, _)) = refinement_ctx.population.ranked().next() {
+                let cost = objective.fitness(insertion_ctx);
+                let mut state = progress_job.state.lock().unwrap();
+                state.generation = statistics.generation;
+                state.cost = Some(cost);
+            }
+        }));
+
+        let job_id = {
+            let mut jobs = self.jobs.write().unwrap();
+
+            let running = jobs.values().filter(|job| job.state.lock().unwrap().status == JobStatus::Running).count();
+            if running >= self.limits.max_jobs {
+                return Err(JobError::LimitExceeded(format!("max amount of running jobs is reached: {}", running)));
+            }
+
+            evict_finished_jobs(&mut jobs, self.limits.max_finished_jobs);
+
+            let job_id = format!("job{}", self.counter.fetch_add(1, Ordering::Relaxed) + 1);
+            jobs.insert(job_id.clone(), job.clone());
+
+            job_id
+        };
+
+        thread::spawn(move || {
+            // NOTE a panic should not leave the job in running state forever
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                Solver::new(problem.clone(), solver_config)
+                    .solve()
+                    .map(|(solution, _, metrics)| create_solution(problem.as_ref(), &solution, metrics.as_ref()))
+            }))
+            .unwrap_or_else(|err| Err(get_panic_message(err)));
+
+            let mut state = job.state.lock().unwrap();
+            state.finished = Some(Instant::now());
+            match result {
+                Ok(solution) => {
+                    state.solution = Some(to_proto_solution(&solution));
+                    state.status =
+                        if job.interrupt.load(Ordering::Relaxed) { JobStatus::Cancelled } else { JobStatus::Completed };
+                }
+                Err(err) => {
+                    state.error = Some(err);
+                    state.status = JobStatus::Failed;
+                }
+            }
+        });
+
+        Ok(job_id)
+    }
+
+    /// Requests cancellation of the job. Returns true if the job is still running.
+    pub fn cancel(&self, job_id: &str) -> Result<bool, JobError> {
+        let job = self.get_job(job_id)?;
+        job.interrupt.store(true, Ordering::Relaxed);
+
+        let is_running = job.state.lock().unwrap().status == JobStatus::Running;
+
+        Ok(is_running)
+    }
+
+    /// Returns job progress.
+    pub fn progress(&self, job_id: &str) -> Result<ProgressResponse, JobError> {
+        let job = self.get_job(job_id)?;
+        let state = job.state.lock().unwrap();
+
+        Ok(ProgressResponse {
+            status: state.status as i32,
+            generation: state.generation as u64,
+            cost: state.cost,
+            solution: state.solution.clone(),
+            error: state.error.clone(),
+        })
+    }
+
+    fn get_job(&self, job_id: &str) -> Result<Arc<SolverJob>, JobError> {
+        self.jobs
+            .read()
+            .unwrap()
+            .get(job_id)
+            .cloned()
+            .ok_or_else(|| JobError::NotFound(format!("cannot find job with id: '{}'", job_id)))
+    }
+}
+
+/// Removes the oldest finished jobs, so only `max_finished_jobs` of them are kept.
+fn evict_finished_jobs(jobs: &mut HashMap<String, Arc<SolverJob>>, max_finished_jobs: usize) {
+    let mut finished = jobs
+        .iter()
+        .filter_map(|(job_id, job)| job.state.lock().unwrap().finished.map(|finished| (job_id.clone(), finished)))
+        .collect::<Vec<_>>();
+
+    if finished.len() > max_finished_jobs {
+        finished.sort_by_key(|(_, finished)| *finished);
+        finished.iter().take(finished.len() - max_finished_jobs).for_each(|(job_id, _)| {
+            jobs.remove(job_id);
+        });
+    }
+}
+
+fn get_panic_message(err: Box<dyn std::any::Any + Send>) -> String {
+    err.downcast_ref::<&str>()
+        .cloned()
+        .or_else(|| err.downcast_ref::<String>().map(|str| str.as_str()))
+        .map(|msg| format!("panic: '{}'", msg))
+        .unwrap_or_else(|| "panic with unknown type".to_string())
+}
@@ -0,0 +1,26 @@
+//! A crate which provides a gRPC server to solve ***Vehicle Routing Problem*** defined in
+//! `pragmatic` format.
+//!
+//! The service exposes `Solve`, `Cancel` and `GetProgress` RPCs defined in `proto/vrp.proto` file.
+//! Each `Solve` call submits a job which is run in background, so multiple problems can be solved
+//! concurrently. A number of running jobs and their running time are limited by the server settings.
+
+#![warn(missing_docs)]
+
+#[cfg(test)]
+#[path = "../tests/unit/service_test.rs"]
+mod service_test;
+
+/// Contains types generated from protobuf definitions.
+#[allow(missing_docs)]
+pub mod proto {
+    tonic::include_proto!("vrp");
+}
+
+mod convert;
+
+mod jobs;
+pub use self::jobs::*;
+
+mod service;
+pub use self::service::*;
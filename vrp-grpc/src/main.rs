@@ -0,0 +1,94 @@
+//! A gRPC server for *Vehicle Routing Problem* solver.
+//!
+
+use clap::{Arg, ArgMatches, Command};
+use std::net::SocketAddr;
+use std::process;
+use tonic::transport::Server;
+use vrp_grpc::proto::solver_server::SolverServer;
+use vrp_grpc::{JobLimits, SolverService};
+
+const ADDRESS_ARG_NAME: &str = "address";
+const MAX_JOBS_ARG_NAME: &str = "max-jobs";
+const MAX_TIME_ARG_NAME: &str = "max-time";
+const MAX_FINISHED_JOBS_ARG_NAME: &str = "max-finished-jobs";
+
+fn main() {
+    if let Err(err) = run_server(get_app().get_matches()) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn get_app() -> Command<'static> {
+    Command::new("Vehicle Routing Problem Solver gRPC server")
+        .version("1.16.0")
+        .author("Ilya Builuk <ilya.builuk@gmail.com>")
+        .about("A gRPC server for Vehicle Routing Problem solver")
+        .arg(
+            Arg::new(ADDRESS_ARG_NAME)
+                .help("Specifies address to listen")
+                .short('a')
+                .long(ADDRESS_ARG_NAME)
+                .default_value("127.0.0.1:50051")
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(MAX_JOBS_ARG_NAME)
+                .help("Specifies maximum number of jobs which can run concurrently")
+                .short('j')
+                .long(MAX_JOBS_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(MAX_TIME_ARG_NAME)
+                .help("Specifies maximum running time of a single job in seconds")
+                .short('t')
+                .long(MAX_TIME_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::new(MAX_FINISHED_JOBS_ARG_NAME)
+                .help("Specifies maximum number of finished jobs which are kept to get their results")
+                .short('f')
+                .long(MAX_FINISHED_JOBS_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+fn run_server(matches: ArgMatches) -> Result<(), String> {
+    let address = matches
+        .value_of(ADDRESS_ARG_NAME)
+        .unwrap_or_default()
+        .parse::<SocketAddr>()
+        .map_err(|err| format!("cannot parse address: '{}'", err))?;
+
+    let defaults = JobLimits::default();
+    let limits = JobLimits {
+        max_jobs: parse_usize_arg(&matches, MAX_JOBS_ARG_NAME)?.unwrap_or(defaults.max_jobs),
+        max_time: parse_usize_arg(&matches, MAX_TIME_ARG_NAME)?.unwrap_or(defaults.max_time),
+        max_finished_jobs: parse_usize_arg(&matches, MAX_FINISHED_JOBS_ARG_NAME)?.unwrap_or(defaults.max_finished_jobs),
+    };
+
+    let runtime = tokio::runtime::Runtime::new().map_err(|err| format!("cannot create runtime: '{}'", err))?;
+
+    runtime.block_on(async move {
+        println!("listening on {}", address);
+
+        Server::builder()
+            .add_service(SolverServer::new(SolverService::new(limits)))
+            .serve(address)
+            .await
+            .map_err(|err| format!("server error: '{}'", err))
+    })
+}
+
+fn parse_usize_arg(matches: &ArgMatches, arg_name: &str) -> Result<Option<usize>, String> {
+    matches
+        .value_of(arg_name)
+        .map(|value| value.parse::<usize>().map_err(|err| format!("cannot parse '{}' argument: '{}'", arg_name, err)))
+        .transpose()
+}
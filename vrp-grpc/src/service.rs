@@ -0,0 +1,57 @@
+use crate::proto::solver_server::Solver;
+use crate::proto::*;
+use crate::{JobDefinition, JobError, JobLimits, JobManager};
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+
+/// A gRPC solver service implementation.
+pub struct SolverService {
+    manager: Arc<JobManager>,
+}
+
+impl SolverService {
+    /// Creates a new instance of `SolverService`.
+    pub fn new(limits: JobLimits) -> Self {
+        Self { manager: Arc::new(JobManager::new(limits)) }
+    }
+}
+
+#[tonic::async_trait]
+impl Solver for SolverService {
+    async fn solve(&self, request: Request<SolveRequest>) -> Result<Response<SolveResponse>, Status> {
+        let request = request.into_inner();
+        let definition = JobDefinition {
+            problem: request.problem,
+            matrices: request.matrices,
+            config: request.config,
+            max_time: request.max_time.map(|max_time| max_time as usize),
+        };
+
+        // NOTE problem reading and validation can take a while, so it is not run on async worker
+        let manager = self.manager.clone();
+        let job_id = tokio::task::spawn_blocking(move || manager.submit(definition))
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .map_err(to_status)?;
+
+        Ok(Response::new(SolveResponse { job_id }))
+    }
+
+    async fn cancel(&self, request: Request<CancelRequest>) -> Result<Response<CancelResponse>, Status> {
+        let is_cancelled = self.manager.cancel(request.get_ref().job_id.as_str()).map_err(to_status)?;
+
+        Ok(Response::new(CancelResponse { is_cancelled }))
+    }
+
+    async fn get_progress(&self, request: Request<ProgressRequest>) -> Result<Response<ProgressResponse>, Status> {
+        self.manager.progress(request.get_ref().job_id.as_str()).map(Response::new).map_err(to_status)
+    }
+}
+
+fn to_status(error: JobError) -> Status {
+    match error {
+        JobError::InvalidArgument(message) => Status::invalid_argument(message),
+        JobError::LimitExceeded(message) => Status::resource_exhausted(message),
+        JobError::NotFound(message) => Status::not_found(message),
+    }
+}
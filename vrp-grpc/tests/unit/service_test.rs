@@ -0,0 +1,128 @@
+use crate::proto::solver_server::Solver;
+use crate::proto::*;
+use crate::{JobLimits, SolverService};
+use std::time::Duration;
+use tonic::{Code, Request};
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn create_solve_request(config: &str, max_time: Option<u64>) -> Request<SolveRequest> {
+    Request::new(SolveRequest {
+        problem: std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap(),
+        matrices: vec![],
+        config: Some(config.to_string()),
+        max_time,
+    })
+}
+
+async fn wait_for_finish(service: &SolverService, job_id: &str) -> ProgressResponse {
+    loop {
+        let progress = service
+            .get_progress(Request::new(ProgressRequest { job_id: job_id.to_string() }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        if progress.status != JobStatus::Running as i32 {
+            return progress;
+        }
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+}
+
+#[tokio::test]
+async fn can_solve_problem() {
+    let service = SolverService::new(JobLimits::default());
+
+    let job_id = service
+        .solve(create_solve_request(r#"{"termination": {"maxGenerations": 10}}"#, None))
+        .await
+        .unwrap()
+        .into_inner()
+        .job_id;
+    let progress = wait_for_finish(&service, job_id.as_str()).await;
+
+    assert_eq!(progress.status, JobStatus::Completed as i32);
+    assert!(progress.cost.is_some());
+    let solution = progress.solution.expect("no solution");
+    assert!(!solution.tours.is_empty());
+    assert!(solution.unassigned.is_empty());
+}
+
+#[tokio::test]
+async fn can_cancel_job() {
+    let service = SolverService::new(JobLimits::default());
+    let config = r#"{"termination": {"maxGenerations": 10000000}}"#;
+
+    let job_id = service.solve(create_solve_request(config, None)).await.unwrap().into_inner().job_id;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let is_cancelled =
+        service.cancel(Request::new(CancelRequest { job_id: job_id.clone() })).await.unwrap().into_inner().is_cancelled;
+    let progress = wait_for_finish(&service, job_id.as_str()).await;
+
+    assert!(is_cancelled);
+    assert_eq!(progress.status, JobStatus::Cancelled as i32);
+    assert!(progress.solution.is_some());
+}
+
+#[tokio::test]
+async fn can_limit_job_time_with_quota() {
+    let service = SolverService::new(JobLimits { max_jobs: 1, max_time: 1, ..JobLimits::default() });
+    let config = r#"{"termination": {"maxGenerations": 10000000, "maxTime": 300}}"#;
+
+    let job_id = service.solve(create_solve_request(config, Some(300))).await.unwrap().into_inner().job_id;
+    let progress = wait_for_finish(&service, job_id.as_str()).await;
+
+    assert_eq!(progress.status, JobStatus::Completed as i32);
+    assert!(progress.solution.is_some());
+}
+
+#[tokio::test]
+async fn can_limit_amount_of_running_jobs() {
+    let service = SolverService::new(JobLimits { max_jobs: 1, max_time: 300, ..JobLimits::default() });
+    let config = r#"{"termination": {"maxGenerations": 10000000}}"#;
+
+    let job_id = service.solve(create_solve_request(config, None)).await.unwrap().into_inner().job_id;
+    let result = service.solve(create_solve_request(config, None)).await;
+    service.cancel(Request::new(CancelRequest { job_id: job_id.clone() })).await.unwrap();
+    wait_for_finish(&service, job_id.as_str()).await;
+
+    assert_eq!(result.err().map(|status| status.code()), Some(Code::ResourceExhausted));
+}
+
+#[tokio::test]
+async fn can_return_error_for_invalid_requests() {
+    let service = SolverService::new(JobLimits::default());
+
+    let solve_result = service
+        .solve(Request::new(SolveRequest { problem: "{}".to_string(), matrices: vec![], config: None, max_time: None }))
+        .await;
+    let progress_result = service.get_progress(Request::new(ProgressRequest { job_id: "unknown".to_string() })).await;
+    let cancel_result = service.cancel(Request::new(CancelRequest { job_id: "unknown".to_string() })).await;
+
+    assert_eq!(solve_result.err().map(|status| status.code()), Some(Code::InvalidArgument));
+    assert_eq!(progress_result.err().map(|status| status.code()), Some(Code::NotFound));
+    assert_eq!(cancel_result.err().map(|status| status.code()), Some(Code::NotFound));
+}
+
+#[tokio::test]
+async fn can_evict_finished_jobs() {
+    let service = SolverService::new(JobLimits { max_finished_jobs: 1, ..JobLimits::default() });
+    let config = r#"{"termination": {"maxGenerations": 1}}"#;
+
+    let mut job_ids = vec![];
+    for _ in 0..3 {
+        let job_id = service.solve(create_solve_request(config, None)).await.unwrap().into_inner().job_id;
+        wait_for_finish(&service, job_id.as_str()).await;
+        job_ids.push(job_id);
+    }
+    let get_progress_code = |job_id: &String| {
+        let request = Request::new(ProgressRequest { job_id: job_id.clone() });
+        async { service.get_progress(request).await.err().map(|status| status.code()) }
+    };
+
+    assert_eq!(get_progress_code(&job_ids[0]).await, Some(Code::NotFound));
+    assert_eq!(get_progress_code(&job_ids[1]).await, None);
+    assert_eq!(get_progress_code(&job_ids[2]).await, None);
+}
@@ -3,13 +3,18 @@
 mod assignment_test;
 
 use super::*;
+use crate::format::dimens::{JOB_SYNC_VISIT_KEY, JOB_TRANSFER_VISIT_KEY, RELOAD_PER_UNIT_DURATION_KEY};
 use crate::format::solution::activity_matcher::*;
 use crate::format::{get_coord_index, get_job_index};
 use crate::utils::combine_error_results;
 use hashbrown::HashSet;
 use std::cmp::Ordering;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::Single;
+use vrp_core::models::solution::Place;
 use vrp_core::prelude::compare_floats;
+use vrp_core::solver::processing::WalkInDimension;
 
 /// Checks assignment of jobs and vehicles.
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<String>> {
@@ -19,6 +24,10 @@ pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<String>> {
         check_jobs_match(ctx),
         check_dispatch(ctx),
         check_groups(ctx),
+        check_skills(ctx),
+        check_sync(ctx),
+        check_transfer(ctx),
+        check_echelons(ctx),
     ])
 }
 
@@ -74,7 +83,11 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
                 let asgn =
                     used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
 
-                if asgn.tour_info != tour_info {
+                let is_split = ctx
+                    .get_job_by_id(&activity.job_id)
+                    .map_or(false, |job| job.sync.is_some() || job.transfer.is_some());
+
+                if asgn.tour_info != tour_info && !is_split {
                     return Err(format!("job served in multiple tours: '{}'", activity.job_id));
                 }
 
@@ -96,7 +109,8 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
         let expected_tasks = job.pickups.as_ref().map_or(0, |p| p.len())
             + job.deliveries.as_ref().map_or(0, |d| d.len())
             + job.services.as_ref().map_or(0, |s| s.len())
-            + job.replacements.as_ref().map_or(0, |r| r.len());
+            + job.replacements.as_ref().map_or(0, |r| r.len())
+            + job.transfer.as_ref().map_or(0, |_| 2);
         let assigned_tasks = asgn.pickups.len() + asgn.deliveries.len() + asgn.services.len() + asgn.replacements.len();
 
         if expected_tasks != assigned_tasks {
@@ -106,7 +120,11 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
             ));
         }
 
-        if !asgn.deliveries.is_empty() && asgn.pickups.iter().max() > asgn.deliveries.iter().min() {
+        // NOTE order of transfer job activities is checked separately
+        if job.transfer.is_none()
+            && !asgn.deliveries.is_empty()
+            && asgn.pickups.iter().max() > asgn.deliveries.iter().min()
+        {
             return Err(format!("found pickup after delivery for '{}'", id));
         }
 
@@ -180,7 +198,8 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                                                 true
                                             }
                                         },
-                                        Ok(Some(JobInfo(_, _, place, time))) => {
+                                        Ok(Some(JobInfo(_, single, mut place, time))) => {
+                                            place.duration *= get_service_time_factor(ctx, tour, activity);
                                             let not_equal = |left: f64, right: f64| compare_floats(left, right) != Ordering::Equal;
                                             let parking = ctx
                                                 .clustering
@@ -188,10 +207,29 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                                                 .map(|config| config.serving.get_parking())
                                                 .unwrap_or(0.);
                                             let commute_profile = ctx.clustering.as_ref().map(|config| config.profile.clone());
-                                            let domain_commute = ctx.get_commute_info(commute_profile, parking, stop, *idx);
-                                            let extra_time = get_extra_time(stop, activity, &place).unwrap_or(0.);
+                                            let walk_in_commute = get_walk_in_commute(single.as_ref(), &place);
+                                            let is_walk_in = walk_in_commute.is_some();
+                                            let domain_commute = if is_walk_in {
+                                                Ok(walk_in_commute)
+                                            } else {
+                                                ctx.get_commute_info(commute_profile, parking, stop, *idx)
+                                            };
+                                            let extra_time = get_extra_time(stop, activity, &place).unwrap_or(0.)
+                                                + get_reload_extra_time(single.as_ref(), stop);
 
                                             match (&ctx.clustering, &activity.commute, domain_commute) {
+                                                (_, Some(commute), Ok(Some(d_commute))) if is_walk_in => {
+                                                    let a_commute = commute.to_domain(&ctx.coord_index);
+                                                    let expected_departure = time.start.max(place.time.start) + place.duration + extra_time;
+
+                                                    not_equal(time.end, expected_departure)
+                                                        || a_commute.forward.location != d_commute.forward.location
+                                                        || a_commute.backward.location != d_commute.backward.location
+                                                        || not_equal(a_commute.forward.distance, d_commute.forward.distance)
+                                                        || not_equal(a_commute.forward.duration, d_commute.forward.duration)
+                                                        || not_equal(a_commute.backward.distance, d_commute.backward.distance)
+                                                        || not_equal(a_commute.backward.duration, d_commute.backward.duration)
+                                                }
                                                 (_, _, Err(_))
                                                 | (_, None, Ok(Some(_)))
                                                 | (_, Some(_), Ok(None))
@@ -259,6 +297,34 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
     Ok(())
 }
 
+/// Returns walk-in commute of the job place reached from parking location.
+fn get_walk_in_commute(single: &Single, place: &Place) -> Option<DomainCommute> {
+    single.dimens.get_walk_in().and_then(|commutes| {
+        commutes
+            .iter()
+            .find(|(idx, _)| single.places.get(*idx).and_then(|p| p.location).map_or(false, |l| l == place.location))
+            .map(|(_, commute)| commute.clone())
+    })
+}
+
+/// Returns service time factor of the vehicle which is applied to the activity of a regular job.
+fn get_service_time_factor(ctx: &CheckerContext, tour: &Tour, activity: &Activity) -> f64 {
+    match activity.activity_type.as_str() {
+        "pickup" | "delivery" | "replacement" | "service" => {
+            ctx.get_vehicle(&tour.vehicle_id).ok().and_then(|vehicle| vehicle.service_time_factor).unwrap_or(1.)
+        }
+        _ => 1.,
+    }
+}
+
+/// Returns extra reload duration which depends on vehicle load after reload.
+fn get_reload_extra_time(single: &Single, stop: &PointStop) -> f64 {
+    single
+        .dimens
+        .get_dimen(&RELOAD_PER_UNIT_DURATION_KEY)
+        .map_or(0., |per_unit| per_unit * stop.load.iter().sum::<i32>() as f64)
+}
+
 /// Checks whether dispatch is properly assigned.
 fn check_dispatch(ctx: &CheckerContext) -> Result<(), String> {
     let vehicles_with_dispatch = ctx
@@ -368,3 +434,222 @@ fn check_groups(ctx: &CheckerContext) -> Result<(), String> {
         Err(format!("job groups are not respected: '{}'", err_info))
     }
 }
+
+/// Checks that visits of synchronized jobs are served in order and within max gap.
+fn check_sync(ctx: &CheckerContext) -> Result<(), String> {
+    let job_index = get_job_index(&ctx.core_problem);
+    let coord_index = get_coord_index(&ctx.core_problem);
+
+    let visits = ctx.solution.tours.iter().try_fold(HashMap::<String, Vec<_>>::default(), |mut acc, tour| {
+        tour.stops
+            .iter()
+            .filter_map(|stop| match stop {
+                Stop::Point(stop) => Some(stop),
+                Stop::Transit(_) => None,
+            })
+            .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+            .filter(|(_, activity)| ctx.get_job_by_id(&activity.job_id).map_or(false, |job| job.sync.is_some()))
+            .try_for_each(|(stop, activity)| {
+                let JobInfo(_, single, place, time) =
+                    try_match_point_job(tour, stop, activity, job_index, coord_index)?
+                        .ok_or_else(|| format!("cannot match visit of sync job '{}'", activity.job_id))?;
+                let visit = single
+                    .dimens
+                    .get_dimen(&JOB_SYNC_VISIT_KEY)
+                    .ok_or_else(|| format!("cannot get visit of sync job '{}'", activity.job_id))?;
+
+                let time = TimeWindow::new(time.start.max(place.time.start), time.end);
+                acc.entry(visit.job_id.clone()).or_insert_with(Vec::default).push((visit.index, visit.max_gap, time));
+
+                Ok::<_, String>(())
+            })?;
+
+        Ok::<_, String>(acc)
+    })?;
+
+    visits.into_iter().try_for_each(|(job_id, mut visits)| {
+        visits.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+        visits.windows(2).try_for_each(|pair| match pair {
+            [(_, max_gap, prev), (_, _, next)] => {
+                if compare_floats(next.start, prev.end) == Ordering::Less {
+                    return Err(format!("visits of sync job '{}' are not served in order", job_id));
+                }
+
+                if max_gap.map_or(false, |gap| compare_floats(next.start - prev.end, gap) == Ordering::Greater) {
+                    return Err(format!("max gap between visits of sync job '{}' is exceeded", job_id));
+                }
+
+                Ok(())
+            }
+            _ => unreachable!(),
+        })
+    })
+}
+
+/// Checks that goods of transfer jobs are dropped at transfer place before they are picked up
+/// there and that each part of transfer job is served by one tour in proper order.
+fn check_transfer(ctx: &CheckerContext) -> Result<(), String> {
+    let job_index = get_job_index(&ctx.core_problem);
+    let coord_index = get_coord_index(&ctx.core_problem);
+
+    let activities = ctx.solution.tours.iter().try_fold(HashMap::<String, Vec<_>>::default(), |mut acc, tour| {
+        tour.stops
+            .iter()
+            .filter_map(|stop| match stop {
+                Stop::Point(stop) => Some(stop),
+                Stop::Transit(_) => None,
+            })
+            .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+            .enumerate()
+            .filter(|(_, (_, activity))| {
+                ctx.get_job_by_id(&activity.job_id).map_or(false, |job| job.transfer.is_some())
+            })
+            .try_for_each(|(activity_idx, (stop, activity))| {
+                let JobInfo(_, single, place, time) =
+                    try_match_point_job(tour, stop, activity, job_index, coord_index)?
+                        .ok_or_else(|| format!("cannot match activity of transfer job '{}'", activity.job_id))?;
+
+                // NOTE activity index: 0 - source pickup, 1 - drop, 2 - pickup, 3 - target delivery
+                let index = match (single.dimens.get_dimen(&JOB_TRANSFER_VISIT_KEY), activity.activity_type.as_str()) {
+                    (Some(visit), _) => visit.index + 1,
+                    (None, "pickup") => 0,
+                    (None, _) => 3,
+                };
+
+                let time = TimeWindow::new(time.start.max(place.time.start), time.end);
+                acc.entry(activity.job_id.clone()).or_insert_with(Vec::default).push((
+                    index,
+                    (tour.vehicle_id.clone(), tour.shift_index, activity_idx),
+                    time,
+                ));
+
+                Ok::<_, String>(())
+            })?;
+
+        Ok::<_, String>(acc)
+    })?;
+
+    activities.into_iter().try_for_each(|(job_id, mut activities)| {
+        activities.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+
+        let max_gap = ctx.get_job_by_id(&job_id).and_then(|job| job.transfer.as_ref()).and_then(|t| t.max_gap);
+
+        if activities.iter().map(|(index, _, _)| *index).ne(0..4) {
+            return Err(format!("not all activities of transfer job '{}' are served once", job_id));
+        }
+
+        let is_same_tour_in_order = |prev: usize, next: usize| {
+            let ((prev_vehicle, prev_shift, prev_idx), (next_vehicle, next_shift, next_idx)) =
+                (&activities[prev].1, &activities[next].1);
+
+            prev_vehicle == next_vehicle && prev_shift == next_shift && prev_idx < next_idx
+        };
+
+        if !is_same_tour_in_order(0, 1) || !is_same_tour_in_order(2, 3) {
+            return Err(format!("parts of transfer job '{}' are not served in order by one tour", job_id));
+        }
+
+        let (drop, pickup) = (&activities[1].2, &activities[2].2);
+
+        if compare_floats(pickup.start, drop.end) == Ordering::Less {
+            return Err(format!("goods of transfer job '{}' are picked up before they are dropped", job_id));
+        }
+
+        if max_gap.map_or(false, |gap| compare_floats(pickup.start - drop.end, gap) == Ordering::Greater) {
+            return Err(format!("max gap between drop and pickup of transfer job '{}' is exceeded", job_id));
+        }
+
+        Ok(())
+    })
+}
+
+/// Checks that vehicles of the first echelon only bring goods of transfer jobs to transfer places
+/// and vehicles of the second echelon serve all other job activities.
+fn check_echelons(ctx: &CheckerContext) -> Result<(), String> {
+    let job_index = get_job_index(&ctx.core_problem);
+    let coord_index = get_coord_index(&ctx.core_problem);
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let echelon = match ctx.get_vehicle(&tour.vehicle_id)?.echelon {
+            Some(echelon) => echelon,
+            None => return Ok(()),
+        };
+
+        tour.stops
+            .iter()
+            .filter_map(|stop| match stop {
+                Stop::Point(stop) => Some(stop),
+                Stop::Transit(_) => None,
+            })
+            .flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+            .filter_map(|(stop, activity)| ctx.get_job_by_id(&activity.job_id).map(|job| (stop, activity, job)))
+            .try_for_each(|(stop, activity, job)| {
+                let is_first_echelon = if job.transfer.is_some() {
+                    let JobInfo(_, single, _, _) =
+                        try_match_point_job(tour, stop, activity, job_index, coord_index)?
+                            .ok_or_else(|| format!("cannot match activity of transfer job '{}'", activity.job_id))?;
+
+                    match single.dimens.get_dimen(&JOB_TRANSFER_VISIT_KEY) {
+                        Some(visit) => visit.index == 0,
+                        None => activity.activity_type == "pickup",
+                    }
+                } else {
+                    false
+                };
+
+                if (echelon == 1) == is_first_echelon {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "job '{}' is served by vehicle '{}' of wrong echelon: '{}'",
+                        job.id, tour.vehicle_id, echelon
+                    ))
+                }
+            })
+    })
+}
+
+/// Checks that jobs are served by vehicles with required skills.
+fn check_skills(ctx: &CheckerContext) -> Result<(), String> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = ctx.get_vehicle(&tour.vehicle_id)?;
+        let vehicle_skills = vehicle.skills.iter().flat_map(|skills| skills.iter()).collect::<HashSet<_>>();
+
+        let has_all = |skills: &Option<Vec<String>>| {
+            skills.as_ref().map_or(true, |skills| skills.iter().all(|skill| vehicle_skills.contains(skill)))
+        };
+        let has_any = |skills: &Option<Vec<String>>| {
+            skills
+                .as_ref()
+                .map_or(true, |skills| skills.is_empty() || skills.iter().any(|skill| vehicle_skills.contains(skill)))
+        };
+        let has_none = |skills: &Option<Vec<String>>| {
+            skills.as_ref().map_or(true, |skills| skills.iter().all(|skill| !vehicle_skills.contains(skill)))
+        };
+
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .filter_map(|activity| ctx.get_job_by_id(&activity.job_id).map(|job| (job, activity)))
+            .flat_map(|(job, activity)| {
+                // NOTE visits of sync job are served by different vehicles, so check only served task
+                let is_served = move |task: &&JobTask| {
+                    job.sync.is_none()
+                        || task.places.iter().any(|place| place.tag.is_some() && place.tag == activity.job_tag)
+                };
+
+                job.skills
+                    .iter()
+                    .chain(get_job_tasks(job).filter(is_served).filter_map(|task| task.skills.as_ref()))
+                    .map(move |skills| (job, skills))
+            })
+            .try_for_each(|(job, skills)| {
+                if has_all(&skills.all_of) && has_any(&skills.one_of) && has_none(&skills.none_of) {
+                    Ok(())
+                } else {
+                    Err(format!("job skills are not respected: '{}' served by '{}'", job.id, tour.vehicle_id))
+                }
+            })
+    })
+}
@@ -7,7 +7,7 @@ use crate::utils::combine_error_results;
 
 /// Checks that breaks are properly assigned.
 pub fn check_breaks(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_break_assignment(context)])
+    combine_error_results(&[check_break_assignment(context), check_break_spacing(context)])
 }
 
 fn check_break_assignment(context: &CheckerContext) -> Result<(), String> {
@@ -20,9 +20,15 @@ fn check_break_assignment(context: &CheckerContext) -> Result<(), String> {
             .filter(|activity| activity.activity_type == "break")
             .count();
         let matched_break_count = tour.stops.iter().try_fold(0, |acc, stop| {
-            stop.activities()
-                .windows(stop.activities().len().min(2))
-                .flat_map(|leg| as_leg_info_with_break(context, tour, stop, leg))
+            let activities = stop.activities();
+            // NOTE break can be the first activity of the stop when it is not allowed to interrupt service
+            activities
+                .iter()
+                .enumerate()
+                .flat_map(|(idx, to)| {
+                    let from = idx.checked_sub(1).and_then(|idx| activities.get(idx));
+                    as_leg_info_with_break(context, tour, stop, from, to)
+                })
                 .try_fold(acc, |acc, (from_loc, from, to, vehicle_break)| {
                     // check time
                     let visit_time = get_time_window(stop, to);
@@ -42,7 +48,7 @@ fn check_break_assignment(context: &CheckerContext) -> Result<(), String> {
                         .map(|info| &info.location)
                         .cloned();
 
-                    let has_match = match vehicle_break {
+                    let has_match = match &vehicle_break {
                         // TODO check tag and duration
                         VehicleBreak::Optional { places, .. } => places.iter().any(|place| match &place.location {
                             Some(location) => actual_loc.as_ref().map_or(false, |actual_loc| actual_loc == location),
@@ -57,6 +63,24 @@ fn check_break_assignment(context: &CheckerContext) -> Result<(), String> {
                             actual_loc
                         ));
                     }
+
+                    // check interruption
+                    if let VehicleBreak::Required { can_interrupt: Some(false), .. } = vehicle_break {
+                        let is_interrupted = stop
+                            .activities()
+                            .iter()
+                            .filter(|activity| activity.activity_type != "break")
+                            .map(|activity| get_time_window(stop, activity))
+                            .any(|time| time.start < visit_time.end && visit_time.start < time.end);
+
+                        if is_interrupted {
+                            return Err(format!(
+                                "break at '{:?}' interrupts activity, but interruption is not allowed",
+                                visit_time
+                            ));
+                        }
+                    }
+
                     Ok(acc + 1)
                 })
         })?;
@@ -87,14 +111,16 @@ fn check_break_assignment(context: &CheckerContext) -> Result<(), String> {
                 let break_tw = get_break_time_window(tour, vehicle_break).expect("cannot get break time windows");
 
                 let should_assign = match vehicle_break {
-                    VehicleBreak::Optional { policy, .. } => {
+                    VehicleBreak::Optional { policy, min_shift_duration, .. } => {
                         let policy =
                             policy.as_ref().cloned().unwrap_or(VehicleOptionalBreakPolicy::SkipIfNoIntersection);
+                        let is_long_enough = min_shift_duration.map_or(true, |duration| tour_tw.duration() >= duration);
 
-                        match policy {
-                            VehicleOptionalBreakPolicy::SkipIfNoIntersection => break_tw.start < arrival,
-                            VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd => arrival > break_tw.end,
-                        }
+                        is_long_enough
+                            && match policy {
+                                VehicleOptionalBreakPolicy::SkipIfNoIntersection => break_tw.start < arrival,
+                                VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd => arrival > break_tw.end,
+                            }
                     }
                     VehicleBreak::Required { .. } => break_tw.intersects(&tour_tw),
                 };
@@ -119,27 +145,55 @@ fn check_break_assignment(context: &CheckerContext) -> Result<(), String> {
     })
 }
 
+fn check_break_spacing(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let breaks = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .filter_map(|(stop, activity)| match context.get_activity_type(tour, stop, activity) {
+                Ok(ActivityType::Break(VehicleBreak::Optional { min_spacing, .. })) => {
+                    Some((get_time_window(stop, activity), min_spacing.unwrap_or(0.)))
+                }
+                Ok(ActivityType::Break(VehicleBreak::Required { .. })) => Some((get_time_window(stop, activity), 0.)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        breaks.iter().enumerate().try_for_each(|(idx, (prev_time, prev_spacing))| {
+            breaks.iter().skip(idx + 1).try_for_each(|(next_time, next_spacing)| {
+                let spacing = prev_spacing.max(*next_spacing);
+                let is_too_close =
+                    next_time.start < prev_time.end + spacing && prev_time.start < next_time.end + spacing;
+
+                if spacing > 0. && is_too_close {
+                    Err(format!(
+                        "breaks at '{:?}' and '{:?}' violate min spacing '{}' for vehicle '{}', shift index '{}'",
+                        prev_time, next_time, spacing, tour.vehicle_id, tour.shift_index
+                    ))
+                } else {
+                    Ok(())
+                }
+            })
+        })
+    })
+}
+
 fn as_leg_info_with_break<'a>(
     context: &CheckerContext,
     tour: &Tour,
     stop: &'a Stop,
-    leg: &'a [Activity],
+    from: Option<&'a Activity>,
+    to: &'a Activity,
 ) -> Option<(Option<Location>, Option<&'a Activity>, &'a Activity, VehicleBreak)> {
-    let leg = match leg {
-        [from, to] => Some((Some(from), to)),
-        [to] => Some((None, to)),
-        _ => None,
-    };
-
-    if let Some((from, to)) = leg {
-        if let Ok(ActivityType::Break(vehicle_break)) = context.get_activity_type(tour, stop, to) {
-            let from_loc = leg.and_then(|(from, _)| from).and_then(|action| action.location.as_ref()).or(match stop {
-                Stop::Point(point) => Some(&point.location),
-                Stop::Transit(_) => None,
-            });
-            return Some((from_loc.cloned(), from, to, vehicle_break));
-        }
+    if let Ok(ActivityType::Break(vehicle_break)) = context.get_activity_type(tour, stop, to) {
+        let from_loc = from.and_then(|action| action.location.as_ref()).or(match stop {
+            Stop::Point(point) => Some(&point.location),
+            Stop::Transit(_) => None,
+        });
+        return Some((from_loc.cloned(), from, to, vehicle_break));
     }
+
     None
 }
 
@@ -159,7 +213,7 @@ fn get_break_time_window(tour: &Tour, vehicle_break: &VehicleBreak) -> Result<Ti
 
             Ok(TimeWindow::new(departure + *offset.first().unwrap(), departure + *offset.last().unwrap()))
         }
-        VehicleBreak::Required { time, duration } => {
+        VehicleBreak::Required { time, duration, .. } => {
             let start = match time {
                 VehicleRequiredBreakTime::OffsetTime(offset) => departure + *offset,
                 VehicleRequiredBreakTime::ExactTime(time) => parse_time(time),
@@ -8,15 +8,20 @@ use std::iter::once;
 use vrp_core::models::common::{Load, MultiDimLoad};
 
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
-/// * max vehicle's capacity is not violated
+/// * max vehicle's capacity is not violated, capacity can be changed by reload
 /// * load change is correct
+/// * load remaining at the end of the shift is carried to the next one when vehicle keeps it
+/// * amount of simultaneous reloads does not exceed capacity of their shared resource
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_vehicle_load_assignment(context)])
+    combine_error_results(&[check_vehicle_load_assignment(context), check_reload_resources(context)])
 }
 
 fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), String> {
     context.solution.tours.iter().try_for_each(|tour| {
-        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let capacity = MultiDimLoad::new(vehicle.capacity.clone());
+        let is_carry_load = vehicle.carry_load.unwrap_or(false);
+        let carried = get_carried_load(context, tour, is_carry_load);
 
         let legs = (0_usize..)
             .zip(tour.stops.windows(2))
@@ -49,9 +54,11 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), String>
             })
             .collect::<Vec<_>>();
 
-        intervals
-            .iter()
-            .try_fold::<_, _, Result<_, String>>(MultiDimLoad::default(), |acc, interval| {
+        (0_usize..)
+            .zip(intervals.iter())
+            .try_fold::<_, _, Result<_, String>>((carried, capacity), |(acc, capacity), (interval_idx, interval)| {
+                let capacity = get_reload_capacity(context, tour, interval)?.unwrap_or(capacity);
+
                 let (start_delivery, end_pickup) = interval
                     .iter()
                     .flat_map(|(_, (from, to))| once(from).chain(once(to)))
@@ -76,24 +83,16 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), String>
                         },
                     )?;
 
-                let end_capacity = interval.iter().try_fold(start_delivery, |acc, (idx, (from, to))| {
-                    let from_load = MultiDimLoad::new(from.load().clone());
-                    let to_load = MultiDimLoad::new(to.load().clone());
-
-                    if !capacity.can_fit(&from_load) || !capacity.can_fit(&to_load) {
-                        return Err(format!("load exceeds capacity in tour '{}'", tour.vehicle_id));
-                    }
-
-                    let change = to.activities().iter().try_fold::<_, _, Result<_, String>>(
+                let get_change = |stop: &Stop| {
+                    stop.activities().iter().try_fold::<_, _, Result<_, String>>(
                         MultiDimLoad::default(),
                         |acc, activity| {
-                            let activity_type = context.get_activity_type(tour, to, activity)?;
-                            let (demand_type, demand) =
-                                if activity.activity_type == "arrival" || activity.activity_type == "reload" {
-                                    (DemandType::StaticDelivery, end_pickup)
-                                } else {
-                                    get_demand(context, activity, &activity_type)?
-                                };
+                            let activity_type = context.get_activity_type(tour, stop, activity)?;
+                            let (demand_type, demand) = match activity.activity_type.as_str() {
+                                "arrival" if is_carry_load => (DemandType::None, MultiDimLoad::default()),
+                                "arrival" | "reload" => (DemandType::StaticDelivery, end_pickup),
+                                _ => get_demand(context, activity, &activity_type)?,
+                            };
 
                             Ok(match demand_type {
                                 DemandType::StaticDelivery | DemandType::DynamicDelivery => acc - demand,
@@ -101,7 +100,20 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), String>
                                 DemandType::None | DemandType::StaticPickupDelivery => acc,
                             })
                         },
-                    )?;
+                    )
+                };
+
+                let end_capacity = interval.iter().try_fold(start_delivery, |acc, (idx, (from, to))| {
+                    let from_load = MultiDimLoad::new(from.load().clone());
+                    let to_load = MultiDimLoad::new(to.load().clone());
+
+                    if !capacity.can_fit(&from_load) || !capacity.can_fit(&to_load) {
+                        return Err(format!("load exceeds capacity in tour '{}'", tour.vehicle_id));
+                    }
+
+                    // NOTE job activities at the tour start location are served at the first stop
+                    let acc = if *idx == 0 { acc + get_change(from)? } else { acc };
+                    let change = get_change(to)?;
 
                     let is_from_valid = from_load == acc;
                     let is_to_valid = to_load == from_load + change;
@@ -119,12 +131,32 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> Result<(), String>
                     }
                 })?;
 
-                Ok(end_capacity - end_pickup)
+                // NOTE carried load is unloaded at the first reload
+                let unloaded = if interval_idx == 0 { end_pickup + carried } else { end_pickup };
+
+                Ok((end_capacity - unloaded, capacity))
             })
             .map(|_| ())
     })
 }
 
+/// Returns load carried into the tour from the previous shift of the same vehicle.
+fn get_carried_load(context: &CheckerContext, tour: &Tour, is_carry_load: bool) -> MultiDimLoad {
+    if !is_carry_load {
+        return MultiDimLoad::default();
+    }
+
+    context
+        .solution
+        .tours
+        .iter()
+        .filter(|other| other.vehicle_id == tour.vehicle_id && other.shift_index < tour.shift_index)
+        .max_by_key(|other| other.shift_index)
+        .and_then(|other| other.stops.last())
+        .map(|stop| MultiDimLoad::new(stop.load().clone()))
+        .unwrap_or_default()
+}
+
 enum DemandType {
     None,
     StaticPickup,
@@ -164,10 +196,69 @@ fn get_demand(
     Ok((demand_type, demand))
 }
 
+fn check_reload_resources(context: &CheckerContext) -> Result<(), String> {
+    let mut resources = HashMap::<String, (usize, Vec<TimeWindow>)>::new();
+
+    context.solution.tours.iter().try_for_each(|tour| {
+        tour.stops.iter().try_for_each(|stop| {
+            stop.activities().iter().filter(|activity| activity.activity_type == "reload").try_for_each(|activity| {
+                if let ActivityType::Reload(reload) = context.get_activity_type(tour, stop, activity)? {
+                    if let Some(resource) = reload.resource {
+                        let load = stop.load().iter().sum::<i32>() as f64;
+                        let duration = reload.duration + reload.per_unit_duration.unwrap_or(0.) * load;
+                        let end = context.get_activity_time(stop, activity).end;
+
+                        resources
+                            .entry(resource.id)
+                            .or_insert_with(|| (resource.capacity, Vec::new()))
+                            .1
+                            .push(TimeWindow::new(end - duration, end));
+                    }
+                }
+
+                Ok::<_, String>(())
+            })
+        })
+    })?;
+
+    resources.into_iter().try_for_each(|(id, (capacity, times))| {
+        let usage = times
+            .iter()
+            .map(|time| times.iter().filter(|other| other.start <= time.start && time.start < other.end).count())
+            .max()
+            .unwrap_or(0);
+
+        if usage > capacity {
+            Err(format!("reload resource '{}' is used by '{}' vehicles, capacity is '{}'", id, usage, capacity))
+        } else {
+            Ok(())
+        }
+    })
+}
+
 fn is_reload_stop(context: &CheckerContext, stop: &Stop) -> bool {
     context.get_stop_activity_types(stop).first().map_or(false, |a| a == "reload")
 }
 
+/// Returns vehicle capacity defined by reload which starts given interval.
+fn get_reload_capacity(
+    context: &CheckerContext,
+    tour: &Tour,
+    interval: &[(usize, (&Stop, &Stop))],
+) -> Result<Option<MultiDimLoad>, String> {
+    let stop = match interval.first() {
+        Some((_, (from, _))) if is_reload_stop(context, from) => *from,
+        _ => return Ok(None),
+    };
+
+    stop.activities().iter().filter(|activity| activity.activity_type == "reload").try_fold(None, |acc, activity| {
+        Ok(match context.get_activity_type(tour, stop, activity)? {
+            ActivityType::Reload(reload) => reload.capacity.map(MultiDimLoad::new).or(acc),
+            _ => acc,
+        })
+    })
+}
+
 fn has_dispatch(tour: &Tour) -> bool {
     tour.stops
         .iter()
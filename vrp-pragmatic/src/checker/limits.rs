@@ -7,12 +7,18 @@ use crate::utils::combine_error_results;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context)])
+    combine_error_results(&[
+        check_shift_limits(context),
+        check_shift_time(context),
+        check_departure_loading(context),
+        check_driver_hours(context),
+    ])
 }
 
 /// Check that shift limits are not violated:
-/// * max shift time
+/// * max shift time including overtime
 /// * max distance
+/// * max cost
 fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
     context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
@@ -28,6 +34,7 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
             }
 
             if let Some(shift_time) = limits.shift_time {
+                let shift_time = shift_time + limits.overtime.as_ref().map_or(0., |overtime| overtime.max_duration);
                 if tour.statistic.duration as f64 > shift_time {
                     return Err(format!(
                         "shift time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
@@ -36,11 +43,25 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
                 }
             }
 
+            if let Some(max_cost) = limits.max_cost {
+                if tour.statistic.cost > max_cost {
+                    return Err(format!(
+                        "max cost limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_cost, tour.statistic.cost, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
+
             if let Some(tour_size_limit) = limits.tour_size {
                 let shift = context.get_vehicle_shift(tour)?;
 
                 let extra_activities = if shift.end.is_some() { 2 } else { 1 };
-                let tour_activities = tour.stops.iter().flat_map(|stop| stop.activities()).count();
+                let tour_activities = tour
+                    .stops
+                    .iter()
+                    .flat_map(|stop| stop.activities())
+                    .filter(|activity| activity.activity_type != "rest")
+                    .count();
                 let tour_activities = if tour_activities > extra_activities { tour_activities - extra_activities } else { 0 };
 
                 if tour_activities > tour_size_limit {
@@ -50,6 +71,28 @@ fn check_shift_limits(context: &CheckerContext) -> Result<(), String> {
                     ))
                 }
             }
+
+            if let Some(max_stops) = limits.max_stops {
+                let tour_stops = tour
+                    .stops
+                    .iter()
+                    .filter(|stop| {
+                        stop.activities()
+                            .iter()
+                            .any(|activity| !matches!(activity.activity_type.as_str(), "departure" | "arrival"))
+                    })
+                    .filter_map(|stop| stop.as_point())
+                    .map(|point| &point.location)
+                    .collect::<HashSet<_>>()
+                    .len();
+
+                if tour_stops > max_stops {
+                    return Err(format!(
+                        "max stops limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                        max_stops, tour_stops, tour.vehicle_id, tour.shift_index
+                    ));
+                }
+            }
         }
 
         Ok(())
@@ -86,3 +129,78 @@ fn check_shift_time(context: &CheckerContext) -> Result<(), String> {
         }
     })
 }
+
+/// Checks that vehicle departs not earlier than loading at shift start is finished.
+fn check_departure_loading(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
+        let shift = context.get_vehicle_shift(tour)?;
+        if shift.loading.is_none() {
+            return Ok(());
+        }
+
+        let start = tour.stops.first().ok_or("empty tour")?;
+        let duration = context.get_loading_duration(tour)?;
+
+        let loading_time = parse_time(&start.schedule().departure) - parse_time(&shift.start.earliest);
+
+        // NOTE times are rounded in solution, so some tolerance is allowed
+        if loading_time + 1. < duration {
+            Err(format!(
+                "departure loading violation, expected: not less than {}, got: {}, vehicle id '{}', shift index: {}",
+                duration, loading_time, tour.vehicle_id, tour.shift_index
+            ))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Checks that driving time between rests and total driving time between daily rests do not exceed
+/// driver hours limits. Vehicle stays at the stop for rest or daily rest duration or longer are
+/// considered as rests.
+fn check_driver_hours(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let driver_hours = match vehicle.limits.as_ref().and_then(|limits| limits.driver_hours.as_ref()) {
+            Some(driver_hours) => driver_hours,
+            None => return Ok(()),
+        };
+
+        let max_continuous = driver_hours.get_max_continuous_driving();
+        let rest_duration = driver_hours.get_break_duration();
+        let max_daily = driver_hours.get_max_daily_driving();
+        let daily_rest = driver_hours.get_daily_rest();
+
+        // NOTE times are rounded in solution, so some tolerance per leg is allowed
+        tour.stops.windows(2).try_fold((0., 0., 0_usize), |(continuous, total, legs), stops| {
+            let (prev, next) = match stops {
+                [prev, next] => (prev, next),
+                _ => unreachable!(),
+            };
+
+            let prev_schedule = prev.schedule();
+            let stay = parse_time(&prev_schedule.departure) - parse_time(&prev_schedule.arrival);
+            let (continuous, legs) = if stay >= rest_duration { (0., 0) } else { (continuous, legs) };
+            let total = if stay >= daily_rest { 0. } else { total };
+
+            let driving = parse_time(&next.schedule().arrival) - parse_time(&prev_schedule.departure);
+            let (continuous, total, legs) = (continuous + driving, total + driving, legs + 1);
+
+            if continuous > max_continuous + legs as f64 {
+                Err(format!(
+                    "max continuous driving violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                    max_continuous, continuous, tour.vehicle_id, tour.shift_index
+                ))
+            } else if total > max_daily + tour.stops.len() as f64 {
+                Err(format!(
+                    "max daily driving violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                    max_daily, total, tour.vehicle_id, tour.shift_index
+                ))
+            } else {
+                Ok((continuous, total, legs))
+            }
+        })?;
+
+        Ok(())
+    })
+}
@@ -8,12 +8,13 @@ mod checker_test;
 use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::format::{CoordIndex, Location};
-use crate::parse_time;
+use crate::{parse_time, parse_time_of_day};
 use hashbrown::{HashMap, HashSet};
 use std::sync::Arc;
 use vrp_core::construction::clustering::vicinity::ClusterConfig;
 use vrp_core::construction::clustering::vicinity::VisitPolicy;
 use vrp_core::models::common::{Duration, Profile, TimeWindow};
+use vrp_core::models::problem::get_matrix_dimension;
 use vrp_core::models::solution::{Commute as DomainCommute, CommuteInfo as DomainCommuteInfo};
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::solver::processing::VicinityDimension;
@@ -40,6 +41,7 @@ enum ActivityType {
     Job(Job),
     Depot(VehicleDispatch),
     Break(VehicleBreak),
+    Rest,
     Reload(VehicleReload),
 }
 
@@ -76,6 +78,7 @@ impl CheckerContext {
             .chain(check_assignment(self).err().into_iter())
             .chain(check_routing(self).err().into_iter())
             .chain(check_limits(self).err().into_iter())
+            .chain(check_objectives(self).err().into_iter())
             .flatten()
             .fold((HashSet::new(), Vec::default()), |(mut used, mut errors), error| {
                 if !used.contains(&error) {
@@ -114,6 +117,48 @@ impl CheckerContext {
         Ok(Profile { index, scale: profile.scale.unwrap_or(1.) })
     }
 
+    /// Gets leg override of vehicle profile for given locations.
+    fn get_leg_override(
+        &self,
+        vehicle_id: &str,
+        from: &Location,
+        to: &Location,
+    ) -> Result<Option<&LegOverride>, String> {
+        let matrix = &self.get_vehicle(vehicle_id)?.profile.matrix;
+
+        Ok(self
+            .problem
+            .fleet
+            .profiles
+            .iter()
+            .find(|profile| profile.name == *matrix)
+            .and_then(|profile| profile.leg_overrides.as_ref())
+            .and_then(|legs| legs.iter().find(|leg| leg.from == *from && leg.to == *to)))
+    }
+
+    /// Gets travel duration factor of vehicle profile for given departure time.
+    fn get_time_of_day_factor(&self, vehicle_id: &str, departure: i64) -> Result<f64, String> {
+        let matrix = &self.get_vehicle(vehicle_id)?.profile.matrix;
+        let time_of_day = departure.rem_euclid(86400) as f64;
+
+        self.problem
+            .fleet
+            .profiles
+            .iter()
+            .find(|profile| profile.name == *matrix)
+            .and_then(|profile| profile.time_of_day.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|factor| Ok((parse_time_of_day(&factor.start)?, parse_time_of_day(&factor.end)?, factor.factor)))
+            .collect::<Result<Vec<_>, String>>()
+            .map(|factors| {
+                factors
+                    .into_iter()
+                    .find(|(start, end, _)| *start <= time_of_day && time_of_day < *end)
+                    .map_or(1., |(_, _, factor)| factor)
+            })
+    }
+
     /// Gets activity operation time range in seconds since Unix epoch.
     fn get_activity_time(&self, stop: &Stop, activity: &Activity) -> TimeWindow {
         let schedule = stop.schedule();
@@ -159,6 +204,18 @@ impl CheckerContext {
             .ok_or_else(|| format!("cannot find shift for tour with vehicle if: '{}'", tour.vehicle_id))
     }
 
+    /// Gets duration of vehicle loading at shift start which precedes tour departure.
+    fn get_loading_duration(&self, tour: &Tour) -> Result<f64, String> {
+        let shift = self.get_vehicle_shift(tour)?;
+        let start = tour.stops.first().ok_or_else(|| "empty tour".to_string())?;
+        let amount = start.load().iter().sum::<i32>() as f64;
+
+        Ok(shift
+            .loading
+            .as_ref()
+            .map_or(0., |loading| loading.duration + loading.per_unit_duration.unwrap_or(0.) * amount))
+    }
+
     /// Returns stop's activity type names.
     fn get_stop_activity_types(&self, stop: &Stop) -> Vec<String> {
         stop.activities().iter().map(|a| a.activity_type.clone()).collect()
@@ -172,6 +229,7 @@ impl CheckerContext {
 
         match activity.activity_type.as_str() {
             "departure" | "arrival" => Ok(ActivityType::Terminal),
+            "rest" => Ok(ActivityType::Rest),
             "pickup" | "delivery" | "service" | "replacement" => {
                 self.job_map.get(activity.job_id.as_str()).map_or_else(
                     || Err(format!("cannot find job with id '{}'", activity.job_id)),
@@ -197,13 +255,21 @@ impl CheckerContext {
 
                                 TimeWindow::new(start, end).intersects(&time)
                             }
-                            VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime(b_time), duration } => {
+                            VehicleBreak::Required {
+                                time: VehicleRequiredBreakTime::ExactTime(b_time),
+                                duration,
+                                ..
+                            } => {
                                 let start = parse_time(b_time);
                                 let end = start + *duration;
 
                                 TimeWindow::new(start, end).intersects(&time)
                             }
-                            VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime(offset), duration } => {
+                            VehicleBreak::Required {
+                                time: VehicleRequiredBreakTime::OffsetTime(offset),
+                                duration,
+                                ..
+                            } => {
                                 let departure = parse_time(&tour.stops.first().unwrap().schedule().departure);
                                 let start = departure + *offset;
                                 let end = start + *duration;
@@ -218,10 +284,13 @@ impl CheckerContext {
             "reload" => shift
                 .reloads
                 .as_ref()
-                // TODO match reload's time windows
                 .and_then(|reload| {
                     reload.iter().find(|r| {
-                        location.as_ref().map_or(false, |location| r.location == *location) && r.tag == activity.job_tag
+                        location.as_ref().map_or(false, |location| r.location == *location)
+                            && r.tag == activity.job_tag
+                            && r.times
+                                .as_ref()
+                                .map_or(true, |times| times.iter().any(|tw| parse_time_window(tw).intersects(&time)))
                     })
                 })
                 .map(|r| ActivityType::Reload(r.clone()))
@@ -371,8 +440,11 @@ impl CheckerContext {
         let matrix =
             matrices.get(profile.index).ok_or_else(|| format!("cannot find matrix with index {}", profile.index))?;
 
-        let matrix_size = get_matrix_size(matrices.as_slice());
-        let matrix_idx = from_idx * matrix_size + to_idx;
+        let matrix_size = get_matrix_size(matrices.as_slice())?;
+        let matrix_idx = from_idx
+            .checked_mul(matrix_size)
+            .and_then(|idx| idx.checked_add(to_idx))
+            .ok_or_else(|| format!("matrix index overflow: from '{}' to '{}'", from_idx, to_idx))?;
 
         let distance = get_matrix_value(matrix_idx, &matrix.distances)?;
         let duration = get_matrix_value(matrix_idx, &matrix.travel_times)?;
@@ -416,8 +488,10 @@ fn get_time_window(stop: &Stop, activity: &Activity) -> TimeWindow {
     TimeWindow::new(parse_time(start), parse_time(end))
 }
 
-fn get_matrix_size(matrices: &[Matrix]) -> usize {
-    (matrices.first().unwrap().travel_times.len() as f64).sqrt().round() as usize
+fn get_matrix_size(matrices: &[Matrix]) -> Result<usize, String> {
+    let length = matrices.first().unwrap().travel_times.len();
+
+    get_matrix_dimension(length).ok_or_else(|| format!("routing matrix is not square: {} values", length))
 }
 
 fn get_matrix_value(idx: usize, matrix_values: &[i64]) -> Result<i64, String> {
@@ -473,3 +547,6 @@ use crate::checker::relations::check_relations;
 
 mod routing;
 use crate::checker::routing::check_routing;
+
+mod objectives;
+use crate::checker::objectives::check_objectives;
@@ -0,0 +1,235 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/objectives_test.rs"]
+mod objectives_test;
+
+use super::*;
+use crate::utils::combine_error_results;
+
+/// A tolerance used to compare floating point values which are not rounded.
+const PRECISE_TOLERANCE: f64 = 1E-3;
+
+/// Checks that objective values recomputed from solution match reported statistic.
+/// NOTE to ensure distance/duration correctness, routing check should be performed first.
+pub fn check_objectives(context: &CheckerContext) -> Result<(), Vec<String>> {
+    combine_error_results(&[check_tour_timing(context), check_tour_cost(context), check_solution_objectives(context)])
+}
+
+/// Checks that tour timing is consistent:
+/// * sum of timing statistic is equal to tour duration
+/// * commuting time matches activity commute information
+/// * break time matches duration of assigned breaks
+fn check_tour_timing(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().enumerate().try_for_each(|(tour_idx, tour)| {
+        let (statistic, tolerance) = get_tour_statistic(context, tour_idx);
+        let times = &statistic.times;
+
+        let total = times.driving + times.serving + times.waiting + times.break_time + times.commuting + times.parking;
+        // NOTE each of six timing values can be rounded
+        if (total - statistic.duration).abs() > 6. * tolerance {
+            return Err(format!(
+                "timing mismatch for tour statistic: {}, expected duration: '{}', got sum of times: '{}'",
+                tour.vehicle_id, statistic.duration, total
+            ));
+        }
+
+        let commuting = get_activities(tour)
+            .filter_map(|(_, activity)| activity.commute.as_ref())
+            .flat_map(|commute| commute.forward.iter().chain(commute.backward.iter()))
+            .map(|info| parse_time(&info.time.end) - parse_time(&info.time.start))
+            .sum::<f64>();
+
+        if (commuting - times.commuting).abs() > 2. * tolerance {
+            return Err(format!(
+                "commuting time mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+                tour.vehicle_id, commuting, times.commuting
+            ));
+        }
+
+        let break_time = get_activities(tour)
+            .filter(|(_, activity)| activity.activity_type == "break")
+            .map(|(stop, activity)| get_break_duration(context, tour, stop, activity))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .try_fold(0., |acc, duration| duration.map(|duration| acc + duration));
+
+        // NOTE driver hours rest is not a job, so its duration is taken from the solution as is
+        let rest_time = get_activities(tour)
+            .filter(|(_, activity)| activity.activity_type == "rest")
+            .map(|(stop, activity)| {
+                let schedule = stop.schedule();
+                activity.time.as_ref().map_or_else(
+                    || parse_time(&schedule.departure) - parse_time(&schedule.arrival),
+                    |time| parse_time(&time.end) - parse_time(&time.start),
+                )
+            })
+            .sum::<f64>();
+        let break_time = break_time.map(|break_time| break_time + rest_time);
+
+        match break_time {
+            Some(break_time) if (break_time - times.break_time).abs() > 2. * tolerance => Err(format!(
+                "break time mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+                tour.vehicle_id, break_time, times.break_time
+            )),
+            _ => Ok(()),
+        }
+    })
+}
+
+/// Checks that tour cost can be recomputed from vehicle costs and tour statistic.
+fn check_tour_cost(context: &CheckerContext) -> Result<(), String> {
+    context.solution.tours.iter().enumerate().try_for_each(|(tour_idx, tour)| {
+        let (statistic, tolerance) = get_tour_statistic(context, tour_idx);
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        let costs = &vehicle.costs;
+
+        let waiting_rates = [costs.waiting.unwrap_or(costs.time), costs.depot_waiting.unwrap_or(costs.time)];
+        let min_rate = waiting_rates.iter().cloned().fold(f64::MAX, f64::min);
+        let max_rate = waiting_rates.iter().cloned().fold(f64::MIN, f64::max);
+
+        // NOTE tour duration above shift time limit is charged at overtime rate
+        let (overtime, overtime_rate) = vehicle
+            .limits
+            .as_ref()
+            .and_then(|limits| limits.shift_time.zip(limits.overtime.as_ref()))
+            .map_or((0., 0.), |(shift_time, overtime)| ((statistic.duration - shift_time).max(0.), overtime.cost));
+
+        let waiting = statistic.times.waiting;
+        let base_cost = costs.fixed.unwrap_or(0.)
+            + statistic.distance * costs.distance
+            + (statistic.duration - waiting) * costs.time
+            + overtime * overtime_rate
+            + statistic.toll.unwrap_or(0.);
+
+        let (min_cost, max_cost) = (base_cost + waiting * min_rate, base_cost + waiting * max_rate);
+        let tolerance = tolerance * (costs.distance + costs.time + 2. * max_rate + overtime_rate)
+            + PRECISE_TOLERANCE * base_cost.max(1.);
+
+        if statistic.cost < min_cost - tolerance || statistic.cost > max_cost + tolerance {
+            Err(format!(
+                "cost mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+                tour.vehicle_id, min_cost, statistic.cost
+            ))
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Checks that values of configured objectives match solution statistic.
+fn check_solution_objectives(context: &CheckerContext) -> Result<(), String> {
+    let tours = &context.solution.tours;
+    let statistic = &context.solution.statistic;
+
+    let default_objectives = vec![Objective::MinimizeCost];
+    let objectives = context.problem.objectives.as_ref().map_or_else(
+        || default_objectives.iter().collect::<Vec<_>>(),
+        |objectives| objectives.iter().flatten().collect(),
+    );
+
+    objectives.into_iter().try_for_each(|objective| match objective {
+        Objective::MinimizeCost => {
+            let cost = tours.iter().map(|tour| tour.statistic.cost).sum::<f64>();
+            if (cost - statistic.cost).abs() > PRECISE_TOLERANCE * cost.max(1.) {
+                Err(format!("cost objective mismatch, expected: '{}', got: '{}'", cost, statistic.cost))
+            } else {
+                Ok(())
+            }
+        }
+        Objective::MinimizeDistance => {
+            let distance = tours.iter().map(|tour| tour.statistic.distance).sum::<i64>();
+            if distance != statistic.distance {
+                Err(format!("distance objective mismatch, expected: '{}', got: '{}'", distance, statistic.distance))
+            } else {
+                Ok(())
+            }
+        }
+        Objective::MinimizeDuration => {
+            let duration = tours.iter().map(|tour| tour.statistic.duration).sum::<i64>();
+            if duration != statistic.duration {
+                Err(format!("duration objective mismatch, expected: '{}', got: '{}'", duration, statistic.duration))
+            } else {
+                Ok(())
+            }
+        }
+        Objective::MinimizeTolls => {
+            let toll = tours.iter().filter_map(|tour| tour.statistic.toll).sum::<f64>();
+            let actual = statistic.toll.unwrap_or(0.);
+            if (toll - actual).abs() > PRECISE_TOLERANCE * toll.max(1.) {
+                Err(format!("toll objective mismatch, expected: '{}', got: '{}'", toll, actual))
+            } else {
+                Ok(())
+            }
+        }
+        _ => Ok(()),
+    })
+}
+
+/// Returns tour statistic with floating point values and tolerance of a single value: precise
+/// statistic is used when it is reported, otherwise values are rounded.
+fn get_tour_statistic(context: &CheckerContext, tour_idx: usize) -> (PreciseStatistic, f64) {
+    let precise = context
+        .solution
+        .extras
+        .as_ref()
+        .and_then(|extras| extras.tour_statistics.as_ref())
+        .and_then(|statistics| statistics.get(tour_idx));
+
+    if let Some(statistic) = precise {
+        return (statistic.clone(), PRECISE_TOLERANCE);
+    }
+
+    let statistic = &context.solution.tours[tour_idx].statistic;
+    let times = &statistic.times;
+
+    (
+        PreciseStatistic {
+            cost: statistic.cost,
+            distance: statistic.distance as f64,
+            duration: statistic.duration as f64,
+            times: PreciseTiming {
+                driving: times.driving as f64,
+                serving: times.serving as f64,
+                waiting: times.waiting as f64,
+                break_time: times.break_time as f64,
+                commuting: times.commuting as f64,
+                parking: times.parking as f64,
+            },
+            toll: statistic.toll,
+        },
+        1.,
+    )
+}
+
+fn get_activities(tour: &Tour) -> impl Iterator<Item = (&Stop, &Activity)> + '_ {
+    tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+}
+
+/// Gets duration of break as it is defined in vehicle shift. Returns `None` if duration cannot be
+/// determined unambiguously.
+fn get_break_duration(
+    context: &CheckerContext,
+    tour: &Tour,
+    stop: &Stop,
+    activity: &Activity,
+) -> Result<Option<f64>, String> {
+    let vehicle_break = match context.get_activity_type(tour, stop, activity)? {
+        ActivityType::Break(vehicle_break) => vehicle_break,
+        _ => return Err(format!("cannot get break for vehicle '{}'", tour.vehicle_id)),
+    };
+
+    Ok(match vehicle_break {
+        VehicleBreak::Required { duration, .. } => Some(duration),
+        VehicleBreak::Optional { places, .. } => {
+            let location = context.get_activity_location(stop, activity);
+            let place = places.iter().find(|place| place.location.is_some() && place.location == location);
+
+            match (place, places.first()) {
+                (Some(place), _) => Some(place.duration),
+                (None, Some(first)) if places.iter().all(|place| place.duration == first.duration) => {
+                    Some(first.duration)
+                }
+                _ => None,
+            }
+        }
+    })
+}
@@ -8,7 +8,22 @@ use hashbrown::HashSet;
 
 /// Checks relation rules.
 pub fn check_relations(context: &CheckerContext) -> Result<(), Vec<String>> {
-    combine_error_results(&[check_relations_assignment(context)])
+    combine_error_results(&[check_relations_assignment(context), check_readonly_routes(context)])
+}
+
+fn check_readonly_routes(context: &CheckerContext) -> Result<(), String> {
+    context.problem.plan.readonly_routes.iter().flatten().enumerate().try_for_each(|(idx, route)| {
+        let tour = get_tour_by_vehicle_id(&route.vehicle_id, route.shift_index, &context.solution)?;
+        let is_job_id = |id: &String| id != "departure" && id != "arrival";
+        let activity_ids = get_activity_ids(&tour).into_iter().filter(is_job_id).collect::<Vec<_>>();
+        let job_ids = route.jobs.iter().filter(|id| is_job_id(id)).cloned().collect::<Vec<_>>();
+
+        if activity_ids != job_ids {
+            Err(format!("readonly route {} is modified: expected {:?}, got {:?}", idx, job_ids, activity_ids))
+        } else {
+            Ok(())
+        }
+    })
 }
 
 fn check_relations_assignment(context: &CheckerContext) -> Result<(), String> {
@@ -22,10 +22,18 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
         let time_offset =
             parse_time(&tour.stops.first().ok_or_else(|| "empty tour".to_string())?.schedule().departure) as i64;
 
-        let get_matrix_data = |from: &PointStop, to: &PointStop| -> Result<(i64, i64), String> {
+        let get_matrix_data = |from: &PointStop, to: &PointStop, departure: i64| -> Result<(i64, i64), String> {
             let from_idx = context.get_location_index(&from.location)?;
             let to_idx = context.get_location_index(&to.location)?;
-            context.get_matrix_data(&profile, from_idx, to_idx)
+            let (distance, duration) = context.get_matrix_data(&profile, from_idx, to_idx)?;
+
+            match context.get_leg_override(&tour.vehicle_id, &from.location, &to.location)? {
+                Some(leg) => Ok((leg.distance.map_or(distance, |distance| distance as i64), leg.duration as i64)),
+                None => {
+                    let factor = context.get_time_of_day_factor(&tour.vehicle_id, departure)?;
+                    Ok((distance, (duration as f64 * factor).round() as i64))
+                }
+            }
         };
 
         //let stops = tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
@@ -39,7 +47,7 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
 
                 let (distance, duration, to_distance) = match (from, to) {
                     (Stop::Point(from), Stop::Point(to)) => {
-                        let (distance, duration) = get_matrix_data(from, to)?;
+                        let (distance, duration) = get_matrix_data(from, to, arrival_time)?;
                         (distance, duration, to.distance)
                     }
                     (_, Stop::Transit(transit)) => {
@@ -54,7 +62,7 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
                             .unwrap()
                             .as_point()
                             .expect("two consistent transit stops are not supported");
-                        let (distance, duration) = get_matrix_data(from, to)?;
+                        let (distance, duration) = get_matrix_data(from, to, arrival_time)?;
                         (distance, duration, to.distance)
                     }
                 };
@@ -76,7 +84,10 @@ fn check_routing_rules(context: &CheckerContext) -> Result<(), String> {
             },
         )?;
 
-        check_tour_statistic(departure_time, total_distance, time_offset, tour, skip_distance_check)
+        // NOTE tour duration includes loading time which precedes departure
+        let loading = context.get_loading_duration(tour)?.round() as i64;
+
+        check_tour_statistic(departure_time, total_distance, time_offset - loading, tour, skip_distance_check)
     })?;
 
     check_solution_statistic(&context.solution)
@@ -10,7 +10,7 @@ use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
 use vrp_core::models::common::{Schedule, TimeWindow, ValueDimension};
-use vrp_core::models::problem::{ActivityCost, Job, Single, TransportCost};
+use vrp_core::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
 use vrp_core::models::solution::Activity;
 
 /// Implements break functionality with variable location and time.
@@ -42,7 +42,11 @@ impl BreakModule {
             conditional: ConditionalJobModule::new(create_job_transition()),
             constraints: vec![
                 ConstraintVariant::HardRoute(Arc::new(BreakHardRouteConstraint { code })),
-                ConstraintVariant::HardActivity(Arc::new(BreakHardActivityConstraint { code })),
+                ConstraintVariant::HardActivity(Arc::new(BreakHardActivityConstraint {
+                    code,
+                    activity: activity.clone(),
+                    transport: transport.clone(),
+                })),
                 ConstraintVariant::SoftRoute(Arc::new(BreakSoftRouteConstraint {})),
             ],
             activity,
@@ -84,6 +88,8 @@ impl ConstraintModule for BreakModule {
 
 struct BreakHardActivityConstraint {
     code: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
 }
 
 /// Locks break jobs to specific vehicles.
@@ -116,11 +122,39 @@ impl BreakHardActivityConstraint {
 impl HardActivityConstraint for BreakHardActivityConstraint {
     fn evaluate_activity(
         &self,
-        _: &RouteContext,
+        route_ctx: &RouteContext,
         activity_ctx: &ActivityContext,
     ) -> Option<ActivityConstraintViolation> {
         match as_break_job(activity_ctx.target) {
             Some(_) if activity_ctx.prev.job.is_none() => Some(self.stop()),
+            Some(break_single) => {
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+                let departure = prev.schedule.departure;
+                let arrival = departure
+                    + self.transport.duration(
+                        &route_ctx.route,
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(departure),
+                    );
+                let start = arrival.max(target.place.time.start);
+                let break_tw = TimeWindow::new(start, start + self.activity.service_duration(&route_ctx.route, target));
+
+                // NOTE other breaks can be only shifted later by insertion, so their spacing is not decreased
+                let is_too_close = route_ctx
+                    .route
+                    .tour
+                    .all_activities()
+                    .filter_map(|activity| as_break_job(activity).map(|other| (activity, other)))
+                    .filter(|(_, other)| !Arc::ptr_eq(other, break_single))
+                    .any(|(activity, other)| is_too_close(break_single, &break_tw, other, &get_break_tw(activity)));
+
+                if is_too_close {
+                    Some(self.stop())
+                } else {
+                    None
+                }
+            }
             _ => None,
         }
     }
@@ -178,6 +212,7 @@ fn is_required_job(routes: &[RouteContext], route_index: Option<usize>, job: &Jo
 /// Removes breaks which conditions are violated after ruin:
 /// * break without location served separately when original job is removed, but break is kept.
 /// * break is defined by interval, but its time is violated. This might happen due to departure time rescheduling.
+/// * break is too close to previous one in the tour, e.g. when jobs between them are removed.
 fn remove_invalid_breaks(
     ctx: &mut SolutionContext,
     activity: &(dyn ActivityCost + Send + Sync),
@@ -206,9 +241,10 @@ fn remove_invalid_breaks(
                                 prev != current && break_single.places.first().and_then(|p| p.location).is_none();
                             let is_not_on_time = !is_on_proper_time(rc, break_single, &activity.schedule)
                                 || !can_be_scheduled(rc, break_single);
+                            let is_not_spaced = !is_properly_spaced(rc, activity, &breaks);
                             let is_ovrp_last = rc.route.tour.end().map_or(false, |end| std::ptr::eq(activity, end));
 
-                            if is_orphan || is_not_on_time || is_ovrp_last {
+                            if is_orphan || is_not_on_time || is_not_spaced || is_ovrp_last {
                                 // NOTE remove break with removed job location
                                 breaks.insert(Job::Single(activity.job.as_ref().unwrap().clone()));
                             }
@@ -246,7 +282,7 @@ fn remove_invalid_breaks(
 //region Helpers
 
 fn is_break_single(single: &Arc<Single>) -> bool {
-    single.dimens.get_value::<String>("type").map_or(false, |t| t == "break")
+    single.dimens.get_dimen(&JOB_TYPE_KEY).map_or(false, |t| t == "break")
 }
 
 fn as_break_job(activity: &Activity) -> Option<&Arc<Single>> {
@@ -262,12 +298,49 @@ fn can_be_scheduled(rc: &RouteContext, break_job: &Arc<Single>) -> bool {
     let departure = rc.route.tour.start().unwrap().schedule.departure;
     let arrival = rc.route.tour.end().map_or(0., |end| end.schedule.arrival);
     let tour_tw = TimeWindow::new(departure, arrival);
-    let policy = break_job.dimens.get_value::<BreakPolicy>("policy").unwrap_or(&BreakPolicy::SkipIfNoIntersection);
+    let policy = break_job.dimens.get_dimen(&BREAK_POLICY_KEY).unwrap_or(&BreakPolicy::SkipIfNoIntersection);
+    let is_long_enough = break_job
+        .dimens
+        .get_dimen(&BREAK_MIN_SHIFT_DURATION_KEY)
+        .map_or(true, |min_shift_duration| tour_tw.duration() >= *min_shift_duration);
+
+    is_long_enough
+        && get_break_time_windows(break_job, departure).any(|break_tw| match policy {
+            BreakPolicy::SkipIfNoIntersection => break_tw.intersects(&tour_tw),
+            BreakPolicy::SkipIfArrivalBeforeEnd => tour_tw.end > break_tw.end,
+        })
+}
 
-    get_break_time_windows(break_job, departure).any(|break_tw| match policy {
-        BreakPolicy::SkipIfNoIntersection => break_tw.intersects(&tour_tw),
-        BreakPolicy::SkipIfArrivalBeforeEnd => tour_tw.end > break_tw.end,
-    })
+/// Checks whether break activity keeps min spacing to all previous breaks in the tour, except removed ones.
+fn is_properly_spaced(rc: &RouteContext, break_activity: &Activity, removed: &HashSet<Job>) -> bool {
+    let break_single = match as_break_job(break_activity) {
+        Some(break_single) => break_single,
+        None => return true,
+    };
+    let break_tw = get_break_tw(break_activity);
+
+    rc.route
+        .tour
+        .all_activities()
+        .take_while(|activity| !std::ptr::eq(*activity, break_activity))
+        .filter_map(|activity| as_break_job(activity).map(|other| (activity, other)))
+        .filter(|(_, other)| !removed.contains(&Job::Single((*other).clone())))
+        .all(|(activity, other)| !is_too_close(break_single, &break_tw, other, &get_break_tw(activity)))
+}
+
+/// Checks whether time between two breaks is less than max of their min spacing.
+fn is_too_close(break_single: &Arc<Single>, break_tw: &TimeWindow, other: &Arc<Single>, other_tw: &TimeWindow) -> bool {
+    let get_spacing = |single: &Arc<Single>| single.dimens.get_dimen(&BREAK_MIN_SPACING_KEY).cloned().unwrap_or(0.);
+    let spacing = get_spacing(break_single).max(get_spacing(other));
+
+    spacing > 0. && break_tw.start < other_tw.end + spacing && other_tw.start < break_tw.end + spacing
+}
+
+/// Returns actual break time window: from start of the break till its end.
+fn get_break_tw(break_activity: &Activity) -> TimeWindow {
+    let start = break_activity.schedule.arrival.max(break_activity.place.time.start);
+
+    TimeWindow::new(start, start.max(break_activity.schedule.departure))
 }
 
 /// Checks whether break is scheduled on time as its time can be invalid due to departure time optimizations.
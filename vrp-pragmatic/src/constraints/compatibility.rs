@@ -2,6 +2,7 @@
 #[path = "../../tests/unit/constraints/compatibility_test.rs"]
 mod compatibility_test;
 
+use crate::format::dimens::*;
 use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
@@ -94,7 +95,7 @@ impl HardRouteConstraint for CompatibilityHardRouteConstraint {
 }
 
 fn get_job_compatibility(job: &Job) -> Option<&String> {
-    job.dimens().get_value::<String>("compat")
+    job.dimens().get_dimen(&JOB_COMPATIBILITY_KEY)
 }
 
 fn get_route_compatibility(route_ctx: &RouteContext) -> Option<String> {
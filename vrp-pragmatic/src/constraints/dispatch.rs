@@ -136,11 +136,11 @@ fn create_job_transition() -> Box<dyn JobContextTransition + Send + Sync> {
 }
 
 fn is_dispatch_job(job: &Job) -> bool {
-    job.as_single().and_then(|single| single.dimens.get_value::<String>("type")).map_or(false, |t| t == "dispatch")
+    job.as_single().and_then(|single| single.dimens.get_dimen(&JOB_TYPE_KEY)).map_or(false, |t| t == "dispatch")
 }
 
 fn is_dispatch_single(single: &Arc<Single>) -> bool {
-    single.dimens.get_value::<String>("type").map_or(false, |t| t == "dispatch")
+    single.dimens.get_dimen(&JOB_TYPE_KEY).map_or(false, |t| t == "dispatch")
 }
 
 fn is_dispatch_activity(activity: &Option<&Activity>) -> bool {
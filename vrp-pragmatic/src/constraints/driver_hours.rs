@@ -0,0 +1,388 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/driver_hours_test.rs"]
+mod driver_hours_test;
+
+use crate::constraints::return_removed_jobs;
+use crate::format::dimens::*;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Duration, Location, Schedule, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost, TravelTime};
+use vrp_core::models::solution::{Activity, Route};
+use vrp_core::prelude::compare_floats;
+
+/// Specifies driver hours rules: continuous driving is interrupted by a rest and total driving
+/// time is interrupted by a daily rest.
+#[derive(Clone, Debug)]
+pub struct DriverHours {
+    /// A max driving time before the rest has to be taken.
+    pub max_continuous_driving: Duration,
+    /// A rest duration which interrupts continuous driving.
+    pub rest_duration: Duration,
+    /// A max total driving time before the daily rest has to be taken.
+    pub max_daily_driving: Duration,
+    /// A daily rest duration which interrupts both continuous and total driving.
+    pub daily_rest: Duration,
+}
+
+/// Keeps driving time accumulated on arrival at activity.
+#[derive(Clone, Copy, Debug, Default)]
+struct DrivingState {
+    continuous: Duration,
+    total: Duration,
+}
+
+/// A result of driving from one activity to another one.
+struct DrivingStep {
+    /// A departure from previous activity which includes rest, if any.
+    departure: Timestamp,
+    arrival: Timestamp,
+    /// A departure from target activity when no rest is taken there.
+    service_end: Timestamp,
+    state: DrivingState,
+}
+
+/// A module which enforces driver hours rules: rest is inserted automatically at the activity
+/// before the leg which would exceed max continuous driving time, daily rest is inserted the same
+/// way before the leg which would exceed max daily driving time. Waiting time or break which is
+/// not shorter than rest or daily rest duration resets driving time as well.
+pub struct DriverHoursModule {
+    code: i32,
+    state_key: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl DriverHoursModule {
+    /// Creates a new instance of `DriverHoursModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        Self {
+            code,
+            state_key,
+            constraints: vec![
+                ConstraintVariant::HardActivity(Arc::new(DriverHoursHardActivityConstraint {
+                    code,
+                    state_key,
+                    activity: activity.clone(),
+                    transport: transport.clone(),
+                })),
+                ConstraintVariant::SoftActivity(Arc::new(DriverHoursSoftActivityConstraint {
+                    state_key,
+                    activity: activity.clone(),
+                    transport: transport.clone(),
+                })),
+            ],
+            keys: vec![state_key],
+            activity,
+            transport,
+        }
+    }
+
+    /// Updates schedules of route activities with rests and keeps driving state. Returns index of
+    /// the first activity which cannot be reached without violating driver hours rules, if any.
+    fn update_route(&self, route_ctx: &mut RouteContext) -> Option<usize> {
+        let hours = match get_driver_hours(&route_ctx.route) {
+            Some(hours) => hours.clone(),
+            None => return None,
+        };
+
+        let route = route_ctx.route.clone();
+        let start = route.tour.start().unwrap();
+        let init = (start.place.location, start.schedule.departure, DrivingState::default());
+
+        // NOTE stop at infeasible leg keeping original schedules for the rest of the tour
+        let steps = route
+            .tour
+            .all_activities()
+            .skip(1)
+            .scan(init, |(location, departure, state), activity| {
+                let step = drive_to(
+                    &hours,
+                    &route,
+                    self.activity.as_ref(),
+                    self.transport.as_ref(),
+                    (*location, *departure, *state),
+                    activity,
+                )?;
+
+                *location = activity.place.location;
+                *departure = step.service_end;
+                *state = step.state;
+
+                Some(step)
+            })
+            .collect::<Vec<_>>();
+
+        let (route_mut, state) = route_ctx.as_mut();
+        let activities = route_mut.tour.all_activities_mut().collect::<Vec<_>>();
+        let mut activities = activities.into_iter();
+        let mut prev = activities.next();
+
+        steps.iter().zip(activities).for_each(|(step, activity)| {
+            if let Some(prev) = prev.take() {
+                prev.schedule.departure = step.departure;
+            }
+
+            activity.schedule = Schedule::new(step.arrival, step.service_end);
+            state.put_activity_state(self.state_key, activity, step.state);
+
+            prev = Some(activity);
+        });
+
+        let route = route_ctx.route.as_ref();
+        if let Some((start, end)) = route.tour.start().zip(route.tour.end()) {
+            let total_duration = end.schedule.departure - start.schedule.departure;
+            route_ctx.state_mut().put_route_state(TOTAL_DURATION_KEY, total_duration);
+        }
+
+        if let Some(step) = steps.last() {
+            route_ctx.state_mut().put_route_state(self.state_key, step.state);
+        }
+
+        Some(steps.len() + 1).filter(|&index| index < route_ctx.route.tour.total())
+    }
+
+    /// Removes jobs which cannot be reached without violating driver hours rules, e.g. when the
+    /// previous job which split a long trip is removed from the tour.
+    fn remove_invalid_jobs(&self, solution_ctx: &mut SolutionContext) {
+        let locked = &solution_ctx.locked;
+        let mut removed = Vec::new();
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            while let Some(index) = self.update_route(route_ctx) {
+                let job = route_ctx.route.tour.get(index).and_then(|activity| activity.retrieve_job());
+                match job.filter(|job| !locked.contains(job)) {
+                    Some(job) => {
+                        route_ctx.route_mut().tour.remove(&job);
+                        update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+                        removed.push(job);
+                    }
+                    None => break,
+                }
+            }
+        });
+
+        if !removed.is_empty() {
+            return_removed_jobs(solution_ctx, removed, self.code);
+        }
+    }
+}
+
+impl ConstraintModule for DriverHoursModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.update_route(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.update_route(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        // NOTE schedules can be updated by other modules without considering rests
+        self.remove_invalid_jobs(ctx);
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct DriverHoursHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for DriverHoursHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let hours = get_driver_hours(route_ctx.route.as_ref())?;
+
+        if self.is_feasible(hours, route_ctx, activity_ctx).unwrap_or(false) {
+            None
+        } else {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        }
+    }
+}
+
+impl DriverHoursHardActivityConstraint {
+    /// Simulates driving through the rest of the tour after inserting target activity.
+    fn is_feasible(
+        &self,
+        hours: &DriverHours,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<bool> {
+        let route = route_ctx.route.as_ref();
+        let (activity, transport) = (self.activity.as_ref(), self.transport.as_ref());
+
+        let prev = activity_ctx.prev;
+        let (prev_end, prev_state) = if activity_ctx.index == 0 {
+            (prev.schedule.departure, DrivingState::default())
+        } else {
+            let state = route_ctx.state.get_activity_state::<DrivingState>(self.state_key, prev).cloned()?;
+            (activity.estimate_departure(route, prev, prev.schedule.arrival), state)
+        };
+
+        let target = activity_ctx.target;
+        let step = drive_to(hours, route, activity, transport, (prev.place.location, prev_end, prev_state), target)?;
+        let mut current = (target.place.location, step.service_end, step.state);
+
+        let next_activities =
+            activity_ctx.next.into_iter().chain(route.tour.all_activities().skip(activity_ctx.index + 2));
+
+        for next in next_activities {
+            let step = drive_to(hours, route, activity, transport, current, next)?;
+
+            // NOTE the rest of the tour is not affected when schedule and driving state are the same
+            let old_state = route_ctx.state.get_activity_state::<DrivingState>(self.state_key, next);
+            if let Some(old_state) = old_state {
+                let is_same = compare_floats(step.arrival, next.schedule.arrival) == Ordering::Equal
+                    && compare_floats(step.state.continuous, old_state.continuous) == Ordering::Equal
+                    && compare_floats(step.state.total, old_state.total) == Ordering::Equal;
+
+                if is_same {
+                    return Some(true);
+                }
+            }
+
+            current = (next.place.location, step.service_end, step.state);
+        }
+
+        Some(true)
+    }
+}
+
+/// Charges rest time which is added or removed by the insertion around target activity at service time rate,
+/// so rest is paid the same way as it is reported in the solution. Rest changes later in the tour are ignored.
+struct DriverHoursSoftActivityConstraint {
+    state_key: i32,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SoftActivityConstraint for DriverHoursSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        get_driver_hours(route_ctx.route.as_ref())
+            .and_then(|hours| self.estimate_rest(hours, route_ctx, activity_ctx))
+            .map_or(0., |rest| {
+                let actor = route_ctx.route.actor.as_ref();
+                rest * (actor.vehicle.costs.per_service_time + actor.driver.costs.per_service_time)
+            })
+    }
+}
+
+impl DriverHoursSoftActivityConstraint {
+    /// Returns a difference between rest time taken at previous and target activities after insertion
+    /// and rest time taken at previous activity before it.
+    fn estimate_rest(
+        &self,
+        hours: &DriverHours,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<Duration> {
+        let route = route_ctx.route.as_ref();
+        let (activity, transport) = (self.activity.as_ref(), self.transport.as_ref());
+
+        let prev = activity_ctx.prev;
+        let (prev_end, prev_state) = if activity_ctx.index == 0 {
+            (prev.schedule.departure, DrivingState::default())
+        } else {
+            let state = route_ctx.state.get_activity_state::<DrivingState>(self.state_key, prev).cloned()?;
+            (activity.estimate_departure(route, prev, prev.schedule.arrival), state)
+        };
+        let old_rest = (prev.schedule.departure - prev_end).max(0.);
+
+        let target = activity_ctx.target;
+        let step = drive_to(hours, route, activity, transport, (prev.place.location, prev_end, prev_state), target)?;
+        let new_rest = step.departure - prev_end
+            + activity_ctx.next.map_or(Some(0.), |next| {
+                drive_to(hours, route, activity, transport, (target.place.location, step.service_end, step.state), next)
+                    .map(|next_step| next_step.departure - step.service_end)
+            })?;
+
+        Some(new_rest - old_rest)
+    }
+}
+
+fn get_driver_hours(route: &Route) -> Option<&DriverHours> {
+    route.actor.vehicle.dimens.get_dimen(&VEHICLE_DRIVER_HOURS_KEY)
+}
+
+/// Simulates driving to the target activity taking rest before departure if continuous driving
+/// time would be exceeded or daily rest if total driving time would be exceeded. Returns `None`
+/// if driver hours rules or target time window are violated.
+fn drive_to(
+    hours: &DriverHours,
+    route: &Route,
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+    from: (Location, Timestamp, DrivingState),
+    target: &Activity,
+) -> Option<DrivingStep> {
+    let (location, service_end, state) = from;
+    let get_duration = |departure: Timestamp| {
+        transport.duration(route, location, target.place.location, TravelTime::Departure(departure))
+    };
+    let is_greater = |a: f64, b: f64| compare_floats(a, b) == Ordering::Greater;
+
+    let duration = get_duration(service_end);
+    if is_greater(duration, hours.max_continuous_driving) {
+        return None;
+    }
+
+    let (departure, duration, state) = if is_greater(state.total + duration, hours.max_daily_driving) {
+        let departure = service_end + hours.daily_rest;
+        (departure, get_duration(departure), DrivingState::default())
+    } else if is_greater(state.continuous + duration, hours.max_continuous_driving) {
+        let departure = service_end + hours.rest_duration;
+        (departure, get_duration(departure), DrivingState { continuous: 0., total: state.total })
+    } else {
+        (service_end, duration, state)
+    };
+
+    let (continuous, total) = (state.continuous + duration, state.total + duration);
+    let arrival = departure + duration;
+
+    if is_greater(total, hours.max_daily_driving) || is_greater(arrival, target.place.time.end) {
+        return None;
+    }
+
+    let waiting = target.place.time.start - arrival;
+    let is_break =
+        target.job.as_ref().and_then(|single| single.dimens.get_dimen(&JOB_TYPE_KEY)).map_or(false, |t| t == "break");
+    let stay = if is_break { waiting.max(activity.service_duration(route, target)) } else { waiting };
+
+    Some(DrivingStep {
+        departure,
+        arrival,
+        service_end: activity.estimate_departure(route, target, arrival),
+        state: DrivingState {
+            continuous: if is_greater(hours.rest_duration, stay) { continuous } else { 0. },
+            total: if is_greater(hours.daily_rest, stay) { total } else { 0. },
+        },
+    })
+}
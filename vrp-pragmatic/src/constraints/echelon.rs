@@ -0,0 +1,90 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/echelon_test.rs"]
+mod echelon_test;
+
+use crate::format::dimens::*;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::Job;
+
+/// An echelon module restricts jobs assignment in two-echelon routing: vehicles of the first echelon
+/// bring goods to transfer (satellite) places, vehicles of the second echelon serve the rest.
+pub struct EchelonModule {
+    code: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl EchelonModule {
+    /// Creates a new instance of `EchelonModule`.
+    pub fn new(code: i32) -> Self {
+        Self {
+            code,
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(EchelonHardRouteConstraint { code }))],
+            keys: vec![],
+        }
+    }
+}
+
+impl ConstraintModule for EchelonModule {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        if get_job_echelon(&source) == get_job_echelon(&candidate) {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct EchelonHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for EchelonHardRouteConstraint {
+    fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
+        let vehicle_echelon = *ctx.route.actor.vehicle.dimens.get_dimen(&VEHICLE_ECHELON_KEY)?;
+
+        // NOTE conditional jobs (e.g. break, reload) are defined per vehicle
+        let is_conditional = match job {
+            Job::Single(single) => single.dimens.get_dimen(&JOB_VEHICLE_ID_KEY).is_some(),
+            Job::Multi(_) => false,
+        };
+
+        if is_conditional || get_job_echelon(job) == vehicle_echelon {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+/// Returns job echelon: the first part of transfer job, which brings goods to the transfer place,
+/// belongs to the first echelon, all other jobs belong to the second one.
+fn get_job_echelon(job: &Job) -> usize {
+    let is_first_part = job.as_multi().map_or(false, |multi| {
+        multi.jobs.iter().any(|single| single.dimens.get_dimen(&JOB_TRANSFER_VISIT_KEY).map_or(false, |v| v.index == 0))
+    });
+
+    if is_first_part {
+        1
+    } else {
+        2
+    }
+}
@@ -2,6 +2,7 @@
 #[path = "../../tests/unit/constraints/group_test.rs"]
 mod group_test;
 
+use crate::format::dimens::*;
 use hashbrown::HashSet;
 use std::slice::Iter;
 use std::sync::Arc;
@@ -133,7 +134,7 @@ impl HardRouteConstraint for GroupHardRouteConstraint {
 }
 
 fn get_group(job: &Job) -> Option<&String> {
-    job.dimens().get_value::<String>("group")
+    job.dimens().get_dimen(&JOB_GROUP_KEY)
 }
 
 fn get_groups(route_ctx: &RouteContext) -> HashSet<String> {
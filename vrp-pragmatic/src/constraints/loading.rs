@@ -0,0 +1,226 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/loading_test.rs"]
+mod loading_test;
+
+use crate::format::dimens::*;
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Demand, DemandDimension, Duration, LoadOps, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost, TravelTime};
+use vrp_core::utils::compare_floats;
+
+/// Specifies vehicle loading at shift start: vehicle departs only when the goods are on board.
+#[derive(Clone, Debug)]
+pub struct DepartureLoading {
+    /// A fixed loading duration.
+    pub duration: Duration,
+    /// An extra loading duration per unit of load on board at departure.
+    pub per_unit_duration: Duration,
+}
+
+impl DepartureLoading {
+    /// Returns loading duration for given amount of load on board at departure.
+    pub fn get_duration(&self, amount: f64) -> Duration {
+        self.duration + self.per_unit_duration * amount
+    }
+}
+
+/// A function which returns total amount of given load.
+type LoadAmount<T> = Arc<dyn Fn(&T) -> f64 + Send + Sync>;
+
+/// A loading module which keeps duration of the tour start activity equal to the loading time
+/// of the vehicle and delays departure accordingly.
+pub struct LoadingModule<T: LoadOps> {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    amount: LoadAmount<T>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl<T: LoadOps> LoadingModule<T> {
+    /// Creates a new instance of `LoadingModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        amount: LoadAmount<T>,
+        code: i32,
+    ) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(LoadingHardActivityConstraint::<T> {
+                code,
+                amount: amount.clone(),
+                activity: activity.clone(),
+                transport: transport.clone(),
+            }))],
+            keys: vec![],
+            amount,
+            activity,
+            transport,
+        }
+    }
+
+    /// Updates loading duration using actual load on board at departure and reschedules the route if needed.
+    fn update_duration(&self, route_ctx: &mut RouteContext) {
+        let loading = match route_ctx.route.actor.vehicle.dimens.get_dimen(&VEHICLE_LOADING_KEY) {
+            Some(loading) => loading,
+            _ => return,
+        };
+
+        let start = match route_ctx.route.tour.start() {
+            Some(start) => start,
+            _ => return,
+        };
+
+        let load =
+            route_ctx.state.get_activity_state::<T>(CURRENT_CAPACITY_KEY, start).map_or(0., |load| (self.amount)(load));
+        let duration = loading.get_duration(load);
+
+        if compare_floats(duration, start.place.duration) == Ordering::Equal {
+            return;
+        }
+
+        let start = route_ctx.route_mut().tour.get_mut(0).expect("no tour start");
+        let loading_end = start.schedule.arrival + duration;
+
+        // NOTE departure is kept when it is delayed anyway, e.g. to minimize waiting time
+        let is_bound = compare_floats(start.schedule.departure, start.schedule.arrival + start.place.duration)
+            != Ordering::Greater;
+        start.schedule.departure = if is_bound { loading_end } else { start.schedule.departure.max(loading_end) };
+        start.place.duration = duration;
+
+        update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+    }
+}
+
+impl<T: LoadOps> ConstraintModule for LoadingModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        self.update_duration(route_ctx);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.update_duration(route_ctx);
+        });
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct LoadingHardActivityConstraint<T: LoadOps> {
+    code: i32,
+    amount: LoadAmount<T>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl<T: LoadOps> LoadingHardActivityConstraint<T> {
+    /// Returns a departure delay caused by loading of target's delivery or by loading itself when
+    /// the tour is not started yet.
+    fn get_departure_delay(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Option<Duration> {
+        let loading = route_ctx.route.actor.vehicle.dimens.get_dimen(&VEHICLE_LOADING_KEY)?;
+        let start = route_ctx.route.tour.start()?;
+
+        let is_first_trip = route_ctx
+            .state
+            .get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY)
+            .and_then(|intervals| intervals.first())
+            .map_or(true, |(_, end_idx)| activity_ctx.index <= *end_idx);
+
+        let demand: Option<&Demand<T>> = activity_ctx.target.job.as_ref().and_then(|job| job.dimens.get_demand());
+        let delivery = match demand {
+            Some(demand) if is_first_trip => (self.amount)(&demand.delivery.0),
+            _ => 0.,
+        };
+
+        let load =
+            route_ctx.state.get_activity_state::<T>(CURRENT_CAPACITY_KEY, start).map_or(0., |load| (self.amount)(load));
+        let loading_end = start.schedule.arrival + loading.get_duration(load + delivery);
+        let delay = loading_end - start.schedule.departure;
+
+        if compare_floats(delay, 0.) == Ordering::Greater {
+            Some(delay)
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether delayed departure shifts target or activities of the tour beyond their time windows.
+    fn has_time_violation(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext, delay: Duration) -> bool {
+        let route = &route_ctx.route;
+        let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+        let is_late = |arrival: Timestamp, latest: Timestamp| compare_floats(arrival, latest) == Ordering::Greater;
+
+        // NOTE delay is propagated through the tour at most, so it is enough to check first activity,
+        // inserted target and its next activity
+        let is_first_late = route.tour.get(1).filter(|first| first.job.is_some()).map_or(false, |first| {
+            let latest = route_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, first).cloned();
+
+            is_late(first.schedule.arrival + delay, latest.unwrap_or(first.place.time.end))
+        });
+
+        if is_first_late {
+            return true;
+        }
+
+        let departure = prev.schedule.departure + delay;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        if is_late(arrival, target.place.time.end) {
+            return true;
+        }
+
+        next.map_or(false, |next| {
+            let departure = self.activity.estimate_departure(route, target, arrival);
+            let arrival = departure
+                + self.transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+            let latest = route_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, next).cloned();
+
+            is_late(arrival, latest.unwrap_or(next.place.time.end))
+        })
+    }
+}
+
+impl<T: LoadOps> HardActivityConstraint for LoadingHardActivityConstraint<T> {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let delay = self.get_departure_delay(route_ctx, activity_ctx)?;
+
+        if self.has_time_violation(route_ctx, activity_ctx, delay) {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
@@ -1,9 +1,10 @@
 //! Contains implementation of extra constraints.
 
+use crate::format::dimens::*;
 use std::sync::Arc;
-use vrp_core::construction::heuristics::RouteContext;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
 use vrp_core::models::common::{Dimensions, IdDimension, ValueDimension};
-use vrp_core::models::problem::Single;
+use vrp_core::models::problem::{Job, Single};
 use vrp_core::models::solution::{Activity, Route};
 
 /// A key which tracks job group state.
@@ -21,6 +22,24 @@ pub const AREA_VALUE_KEY: i32 = 1004;
 /// A key which tracks area order state.
 pub const AREA_ORDER_KEY: i32 = 1005;
 
+/// A key which tracks schedule buffer violations state.
+pub const SCHEDULE_BUFFER_KEY: i32 = 1006;
+
+/// A key which tracks the latest completion time of prioritized jobs.
+pub const LATEST_ETA_KEY: i32 = 1007;
+
+/// A key which tracks schedules of synchronized job visits.
+pub const SYNC_KEY: i32 = 1008;
+
+/// A key which tracks driving time state.
+pub const DRIVER_HOURS_KEY: i32 = 1009;
+
+/// A key which tracks schedules of reloads using shared resources.
+pub const RELOAD_SCHEDULES_KEY: i32 = 1010;
+
+/// A key which tracks schedules of transfer (cross-docking) activities.
+pub const TRANSFER_KEY: i32 = 1011;
+
 fn as_single_job<F>(activity: &Activity, condition: F) -> Option<&Arc<Single>>
 where
     F: Fn(&Arc<Single>) -> bool,
@@ -29,11 +48,11 @@ where
 }
 
 fn get_shift_index(dimens: &Dimensions) -> usize {
-    *dimens.get_value::<usize>("shift_index").unwrap()
+    *dimens.get_dimen(&SHIFT_INDEX_KEY).unwrap()
 }
 
 fn get_vehicle_id_from_job(job: &Arc<Single>) -> Option<&String> {
-    job.dimens.get_value::<String>("vehicle_id")
+    job.dimens.get_dimen(&JOB_VEHICLE_ID_KEY)
 }
 
 fn is_correct_vehicle(route: &Route, target_id: &str, target_shift: usize) -> bool {
@@ -48,6 +67,27 @@ fn is_single_belongs_to_route(ctx: &RouteContext, single: &Arc<Single>) -> bool
     is_correct_vehicle(&ctx.route, vehicle_id, shift_index)
 }
 
+/// Returns jobs removed from solution's routes back: during insertion they can be inserted again,
+/// otherwise they are unassigned with given code. Routes left without jobs are removed.
+fn return_removed_jobs(solution_ctx: &mut SolutionContext, removed: Vec<Job>, code: i32) {
+    // NOTE tour can become empty after jobs are removed
+    let registry = &mut solution_ctx.registry;
+    solution_ctx.routes.retain(|route_ctx| {
+        if route_ctx.route.tour.has_jobs() {
+            true
+        } else {
+            registry.free_route(route_ctx);
+            false
+        }
+    });
+
+    if solution_ctx.required.is_empty() {
+        solution_ctx.unassigned.extend(removed.into_iter().map(|job| (job, code)));
+    } else {
+        solution_ctx.required.extend(removed);
+    }
+}
+
 mod areas;
 pub use self::areas::AreaModule;
 
@@ -60,11 +100,23 @@ pub use self::compatibility::CompatibilityModule;
 mod dispatch;
 pub use self::dispatch::DispatchModule;
 
+mod echelon;
+pub use self::echelon::EchelonModule;
+
+mod driver_hours;
+pub use self::driver_hours::{DriverHours, DriverHoursModule};
+
 mod groups;
 pub use self::groups::GroupModule;
 
+mod loading;
+pub use self::loading::{DepartureLoading, LoadingModule};
+
+mod overtime;
+pub use self::overtime::{Overtime, OvertimeModule};
+
 mod reloads;
-pub use self::reloads::ReloadMultiTrip;
+pub use self::reloads::{ReloadModule, ReloadMultiTrip, ReloadResource};
 
 mod reachable;
 pub use self::reachable::ReachableModule;
@@ -72,3 +124,6 @@ pub use self::reachable::ReachableModule;
 mod skills;
 pub use self::skills::JobSkills;
 pub use self::skills::SkillsModule;
+
+mod sync;
+pub use self::sync::{SyncModule, SyncVisit};
@@ -0,0 +1,143 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/overtime_test.rs"]
+mod overtime_test;
+
+use crate::format::dimens::*;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Cost, Duration, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, TransportCost, TravelTime};
+use vrp_core::models::solution::Route;
+
+/// Specifies soft shift time limit: tour duration above it is allowed, but penalized.
+#[derive(Clone, Debug)]
+pub struct Overtime {
+    /// A tour duration after which overtime starts.
+    pub shift_time: Duration,
+    /// A cost per time unit of overtime.
+    pub cost: Cost,
+}
+
+impl Overtime {
+    /// Returns overtime cost of given tour duration.
+    pub fn get_cost(&self, duration: Duration) -> Cost {
+        (duration - self.shift_time).max(0.) * self.cost
+    }
+}
+
+/// A module which penalizes tour duration above soft shift time limit. Max overtime is controlled
+/// by travel limits of `TransportConstraintModule`.
+pub struct OvertimeModule {
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl OvertimeModule {
+    /// Creates a new instance of `OvertimeModule`.
+    pub fn new(activity: Arc<dyn ActivityCost + Send + Sync>, transport: Arc<dyn TransportCost + Send + Sync>) -> Self {
+        Self {
+            constraints: vec![ConstraintVariant::SoftActivity(Arc::new(OvertimeSoftActivityConstraint {
+                activity,
+                transport,
+            }))],
+            keys: vec![OVERTIME_COST_KEY],
+        }
+    }
+
+    fn update_route(&self, route_ctx: &mut RouteContext) {
+        if let Some(overtime) = get_overtime(route_ctx.route.as_ref()) {
+            let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+            let cost = overtime.get_cost(duration);
+
+            route_ctx.state_mut().put_route_state(OVERTIME_COST_KEY, cost);
+        }
+    }
+}
+
+impl ConstraintModule for OvertimeModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.update_route(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, ctx: &mut RouteContext) {
+        self.update_route(ctx);
+    }
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        ctx.routes.iter_mut().for_each(|route_ctx| self.update_route(route_ctx));
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Estimates overtime cost change caused by activity insertion.
+struct OvertimeSoftActivityConstraint {
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SoftActivityConstraint for OvertimeSoftActivityConstraint {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let route = route_ctx.route.as_ref();
+
+        if let Some(overtime) = get_overtime(route) {
+            let change_duration = self.estimate_schedule_shift(route, activity_ctx);
+            let duration = route_ctx.state.get_route_state::<f64>(TOTAL_DURATION_KEY).cloned().unwrap_or(0.);
+
+            overtime.get_cost(duration + change_duration) - overtime.get_cost(duration)
+        } else {
+            0.
+        }
+    }
+}
+
+impl OvertimeSoftActivityConstraint {
+    /// Estimates tour duration change caused by activity insertion: travel, waiting and service time
+    /// of the target activity delay the next activity, but part of the delay is absorbed by waiting
+    /// for the time window start of the next activity.
+    fn estimate_schedule_shift(&self, route: &Route, activity_ctx: &ActivityContext) -> Duration {
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        let target_departure = self.activity.estimate_departure(route, target, arrival);
+
+        match activity_ctx.next {
+            Some(next) => {
+                let next_arrival = target_departure
+                    + self.transport.duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        TravelTime::Departure(target_departure),
+                    );
+                let get_start = |arrival: f64| arrival.max(next.place.time.start);
+
+                get_start(next_arrival) - get_start(next.schedule.arrival)
+            }
+            None => target_departure - departure,
+        }
+    }
+}
+
+fn get_overtime(route: &Route) -> Option<&Overtime> {
+    route.actor.vehicle.dimens.get_dimen(&VEHICLE_OVERTIME_KEY)
+}
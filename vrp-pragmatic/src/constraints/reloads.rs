@@ -3,13 +3,18 @@
 mod reload_test;
 
 use crate::constraints::*;
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::iter::once;
 use std::ops::Deref;
+use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
-use vrp_core::construction::heuristics::RouteContext;
-use vrp_core::models::common::{IdDimension, LoadOps, ValueDimension};
-use vrp_core::models::problem::{Job, Single};
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{Demand, DemandDimension, IdDimension, LoadOps, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
 use vrp_core::models::solution::{Activity, Route};
+use vrp_core::utils::compare_floats;
 
 /// A strategy to use multi trip with reload jobs.
 pub struct ReloadMultiTrip<T: LoadOps> {
@@ -28,7 +33,7 @@ impl<T: LoadOps> MultiTrip<T> for ReloadMultiTrip<T> {
     }
 
     fn is_reload_single(&self, single: &Single) -> bool {
-        single.dimens.get_value::<String>("type").map_or(false, |t| t == "reload")
+        single.dimens.get_dimen(&JOB_TYPE_KEY).map_or(false, |t| t == "reload")
     }
 
     fn is_assignable(&self, route: &Route, job: &Job) -> bool {
@@ -81,3 +86,358 @@ impl<T: LoadOps> MultiTrip<T> for ReloadMultiTrip<T> {
         )
     }
 }
+
+/// A resource shared by reload places, e.g. loading docks of the same depot.
+#[derive(Clone, Debug)]
+pub struct ReloadResource {
+    /// A resource id.
+    pub id: String,
+    /// Max amount of vehicles which can be reloaded at the same time.
+    pub capacity: usize,
+}
+
+/// Keeps service start with departure of assigned reloads for each shared resource.
+type ReloadSchedules = HashMap<String, Vec<(Timestamp, Timestamp)>>;
+
+/// A function which returns total amount of given load.
+type LoadAmount<T> = Arc<dyn Fn(&T) -> f64 + Send + Sync>;
+
+/// A reload module which adjusts reload duration by amount of load on board after reload and
+/// keeps amount of simultaneous reloads within capacity of their shared resources.
+pub struct ReloadModule<T: LoadOps> {
+    code: i32,
+    state_key: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    amount: LoadAmount<T>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl<T: LoadOps> ReloadModule<T> {
+    /// Creates a new instance of `ReloadModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        amount: LoadAmount<T>,
+        code: i32,
+        state_key: i32,
+    ) -> Self {
+        Self {
+            code,
+            state_key,
+            constraints: vec![ConstraintVariant::HardActivity(Arc::new(ReloadHardActivityConstraint::<T> {
+                code,
+                state_key,
+                amount: amount.clone(),
+                transport: transport.clone(),
+            }))],
+            keys: vec![state_key],
+            amount,
+            activity,
+            transport,
+        }
+    }
+
+    /// Updates reload durations using actual load on board and reschedules the route if needed.
+    fn update_durations(&self, route_ctx: &mut RouteContext) {
+        let durations = route_ctx
+            .route
+            .tour
+            .all_activities()
+            .enumerate()
+            .filter_map(|(idx, activity)| {
+                let reload = activity.job.as_ref()?;
+                let per_unit = reload.dimens.get_dimen(&RELOAD_PER_UNIT_DURATION_KEY)?;
+                let load = route_ctx
+                    .state
+                    .get_activity_state::<T>(CURRENT_CAPACITY_KEY, activity)
+                    .map_or(0., |load| (self.amount)(load));
+                let duration = reload.places.first()?.duration + per_unit * load;
+
+                if compare_floats(duration, activity.place.duration) != Ordering::Equal {
+                    Some((idx, duration))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if !durations.is_empty() {
+            let tour = &mut route_ctx.route_mut().tour;
+            durations.into_iter().for_each(|(idx, duration)| {
+                tour.get_mut(idx).expect("invalid reload index").place.duration = duration;
+            });
+
+            update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+        }
+    }
+
+    fn update_schedules(&self, solution_ctx: &mut SolutionContext) {
+        let schedules = Arc::new(get_reload_schedules(solution_ctx));
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state(self.state_key, schedules.clone());
+        });
+    }
+}
+
+impl<T: LoadOps> ConstraintModule for ReloadModule<T> {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        // NOTE any insertion can shift reloads in the tour
+        self.update_schedules(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        self.update_durations(route_ctx);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.update_durations(route_ctx);
+        });
+
+        remove_invalid_reloads(solution_ctx, self.code, self.activity.as_ref(), self.transport.as_ref());
+        self.update_schedules(solution_ctx);
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, i32> {
+        Ok(source)
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct ReloadHardActivityConstraint<T: LoadOps> {
+    code: i32,
+    state_key: i32,
+    amount: LoadAmount<T>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl<T: LoadOps> ReloadHardActivityConstraint<T> {
+    /// Checks whether reload can be started without exceeding capacity of its shared resource.
+    fn has_resource_violation(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> bool {
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let resource = match target.job.as_ref().and_then(|job| job.dimens.get_dimen(&RELOAD_RESOURCE_KEY)) {
+            Some(resource) => resource,
+            _ => return false,
+        };
+
+        let schedules = match route_ctx
+            .state
+            .get_route_state::<Arc<ReloadSchedules>>(self.state_key)
+            .and_then(|schedules| schedules.get(&resource.id))
+        {
+            Some(schedules) => schedules,
+            _ => return false,
+        };
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                &route_ctx.route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        let start = arrival.max(target.place.time.start);
+
+        get_max_usage(schedules, (start, start + target.place.duration)) >= resource.capacity
+    }
+
+    /// Checks whether extra reload duration caused by target's delivery shifts activities of the
+    /// trip beyond their time windows.
+    fn has_duration_violation(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> bool {
+        let demand: Option<&Demand<T>> = activity_ctx.target.job.as_ref().and_then(|job| job.dimens.get_demand());
+        let delivery = match demand {
+            Some(demand) if demand.delivery.0.is_not_empty() => &demand.delivery.0,
+            _ => return false,
+        };
+
+        let route = &route_ctx.route;
+        let interval =
+            route_ctx.state.get_route_state::<Vec<(usize, usize)>>(RELOAD_INTERVALS_KEY).and_then(|intervals| {
+                intervals
+                    .iter()
+                    .find(|(start_idx, end_idx)| activity_ctx.index >= *start_idx && activity_ctx.index <= *end_idx)
+            });
+        let start_idx = match interval {
+            Some((start_idx, _)) => *start_idx,
+            _ => return false,
+        };
+
+        let extra = match route
+            .tour
+            .get(start_idx)
+            .and_then(|reload| reload.job.as_ref())
+            .and_then(|reload| reload.dimens.get_dimen(&RELOAD_PER_UNIT_DURATION_KEY))
+        {
+            Some(per_unit) => per_unit * (self.amount)(delivery),
+            _ => return false,
+        };
+
+        let is_late = |arrival: Timestamp, latest: Timestamp| compare_floats(arrival, latest) == Ordering::Greater;
+
+        // NOTE target is inserted right after reload, so it is shifted too
+        if activity_ctx.index == start_idx {
+            let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+            let departure = prev.schedule.departure + extra;
+            let arrival = departure
+                + self.transport.duration(
+                    route,
+                    prev.place.location,
+                    target.place.location,
+                    TravelTime::Departure(departure),
+                );
+
+            if is_late(arrival, target.place.time.end) {
+                return true;
+            }
+        }
+
+        route.tour.get(start_idx + 1).filter(|first| first.job.is_some()).map_or(false, |first| {
+            let latest = route_ctx.state.get_activity_state::<f64>(LATEST_ARRIVAL_KEY, first).cloned();
+
+            is_late(first.schedule.arrival + extra, latest.unwrap_or(first.place.time.end))
+        })
+    }
+}
+
+impl<T: LoadOps> HardActivityConstraint for ReloadHardActivityConstraint<T> {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        if self.has_resource_violation(route_ctx, activity_ctx) || self.has_duration_violation(route_ctx, activity_ctx)
+        {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Removes reloads which exceed capacity of their shared resource, e.g. due to schedule change of
+/// other activities in the tour, together with jobs served within their trips.
+fn remove_invalid_reloads(
+    solution_ctx: &mut SolutionContext,
+    code: i32,
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+) {
+    let locked = &solution_ctx.locked;
+
+    let mut reloads = solution_ctx
+        .routes
+        .iter()
+        .enumerate()
+        .flat_map(|(route_idx, route_ctx)| {
+            route_ctx.route.tour.all_activities().filter_map(move |activity| {
+                let reload = activity.job.as_ref()?;
+                let resource = reload.dimens.get_dimen(&RELOAD_RESOURCE_KEY)?;
+                let schedule = (activity.schedule.arrival.max(activity.place.time.start), activity.schedule.departure);
+
+                Some((route_idx, Job::Single(reload.clone()), resource, schedule))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // NOTE locked reloads are never removed, so they take resource first
+    reloads.sort_by(|(_, a_job, _, (a_start, _)), (_, b_job, _, (b_start, _))| {
+        locked.contains(b_job).cmp(&locked.contains(a_job)).then_with(|| compare_floats(*a_start, *b_start))
+    });
+
+    let (_, invalid) = reloads.into_iter().fold(
+        (ReloadSchedules::default(), Vec::<(usize, Job)>::default()),
+        |(mut schedules, mut invalid), (route_idx, job, resource, schedule)| {
+            let accepted = schedules.entry(resource.id.clone()).or_insert_with(Vec::new);
+
+            if !locked.contains(&job) && get_max_usage(accepted, schedule) >= resource.capacity {
+                invalid.push((route_idx, job));
+            } else {
+                accepted.push(schedule);
+            }
+
+            (schedules, invalid)
+        },
+    );
+
+    if invalid.is_empty() {
+        return;
+    }
+
+    let mut removed_reloads = Vec::new();
+    let mut removed_jobs = Vec::new();
+
+    invalid.into_iter().for_each(|(route_idx, reload)| {
+        let route_ctx = solution_ctx.routes.get_mut(route_idx).unwrap();
+        let tour = &route_ctx.route.tour;
+
+        let jobs = match tour.index(&reload) {
+            Some(reload_idx) => tour
+                .all_activities()
+                .skip(reload_idx + 1)
+                .take_while(|activity| !is_reload_activity(activity))
+                .filter_map(|activity| activity.retrieve_job())
+                .filter(|job| !locked.contains(job) && !removed_jobs.contains(job))
+                .collect::<Vec<_>>(),
+            None => return,
+        };
+
+        jobs.iter().chain(once(&reload)).for_each(|job| {
+            route_ctx.route_mut().tour.remove(job);
+        });
+        update_route_schedule(route_ctx, activity, transport);
+
+        removed_reloads.push(reload);
+        removed_jobs.extend(jobs);
+    });
+
+    // NOTE reloads are kept in ignored till vehicle needs them again
+    solution_ctx.ignored.extend(removed_reloads);
+
+    return_removed_jobs(solution_ctx, removed_jobs, code);
+}
+
+/// Returns max amount of reloads served simultaneously within given time interval.
+fn get_max_usage(schedules: &[(Timestamp, Timestamp)], interval: (Timestamp, Timestamp)) -> usize {
+    let (start, end) = interval;
+    let overlapping = schedules.iter().filter(|(other_start, other_end)| *other_start < end && start < *other_end);
+
+    once(start)
+        .chain(overlapping.clone().map(|(other_start, _)| *other_start).filter(|other_start| *other_start > start))
+        .map(|time| {
+            overlapping.clone().filter(|(other_start, other_end)| *other_start <= time && time < *other_end).count()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn get_reload_schedules(solution_ctx: &SolutionContext) -> ReloadSchedules {
+    solution_ctx.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.all_activities()).fold(
+        ReloadSchedules::default(),
+        |mut schedules, activity| {
+            if let Some(resource) = activity.job.as_ref().and_then(|job| job.dimens.get_dimen(&RELOAD_RESOURCE_KEY)) {
+                schedules
+                    .entry(resource.id.clone())
+                    .or_insert_with(Vec::new)
+                    .push((activity.schedule.arrival.max(activity.place.time.start), activity.schedule.departure));
+            }
+
+            schedules
+        },
+    )
+}
+
+fn is_reload_activity(activity: &Activity) -> bool {
+    activity.job.as_ref().and_then(|job| job.dimens.get_dimen(&JOB_TYPE_KEY)).map_or(false, |t| t == "reload")
+}
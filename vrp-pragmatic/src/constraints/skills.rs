@@ -2,13 +2,14 @@
 #[path = "../../tests/unit/constraints/skills_test.rs"]
 mod skills_test;
 
+use crate::format::dimens::*;
 use hashbrown::HashSet;
 use std::slice::Iter;
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
 use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
 use vrp_core::models::common::ValueDimension;
-use vrp_core::models::problem::Job;
+use vrp_core::models::problem::{Job, Single};
 
 /// A job skills limitation for a vehicle.
 pub struct JobSkills {
@@ -45,27 +46,11 @@ impl ConstraintModule for SkillsModule {
     fn accept_solution_state(&self, _ctx: &mut SolutionContext) {}
 
     fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
-        let source_skills = get_skills(&source);
-        let candidate_skills = get_skills(&candidate);
-
-        let check_skill_sets = |source_set: Option<&HashSet<String>>, candidate_set: Option<&HashSet<String>>| match (
-            source_set,
-            candidate_set,
-        ) {
-            (Some(_), None) | (None, None) => true,
-            (None, Some(_)) => false,
-            (Some(source_skills), Some(candidate_skills)) => candidate_skills.is_subset(source_skills),
-        };
-
-        let has_comparable_skills = match (source_skills, candidate_skills) {
-            (Some(_), None) | (None, None) => true,
-            (None, Some(_)) => false,
-            (Some(source_skills), Some(candidate_skills)) => {
-                check_skill_sets(source_skills.all_of.as_ref(), candidate_skills.all_of.as_ref())
-                    && check_skill_sets(source_skills.one_of.as_ref(), candidate_skills.one_of.as_ref())
-                    && check_skill_sets(source_skills.none_of.as_ref(), candidate_skills.none_of.as_ref())
-            }
-        };
+        let has_comparable_skills = has_comparable_skills(get_skills(&source), get_skills(&candidate))
+            && has_comparable_skills(
+                get_task_skills(&source).first().cloned(),
+                get_task_skills(&candidate).first().cloned(),
+            );
 
         if has_comparable_skills {
             Ok(source)
@@ -89,14 +74,16 @@ struct SkillsHardRouteConstraint {
 
 impl HardRouteConstraint for SkillsHardRouteConstraint {
     fn evaluate_job(&self, _: &SolutionContext, ctx: &RouteContext, job: &Job) -> Option<RouteConstraintViolation> {
-        if let Some(job_skills) = get_skills(job) {
-            let vehicle_skills = ctx.route.actor.vehicle.dimens.get_value::<HashSet<String>>("skills");
-            let is_ok = check_all_of(job_skills, &vehicle_skills)
+        let vehicle_skills = ctx.route.actor.vehicle.dimens.get_dimen(&VEHICLE_SKILLS_KEY);
+
+        let is_ok = get_skills(job).iter().chain(get_task_skills(job).iter()).all(|job_skills| {
+            check_all_of(job_skills, &vehicle_skills)
                 && check_one_of(job_skills, &vehicle_skills)
-                && check_none_of(job_skills, &vehicle_skills);
-            if !is_ok {
-                return Some(RouteConstraintViolation { code: self.code });
-            }
+                && check_none_of(job_skills, &vehicle_skills)
+        });
+
+        if !is_ok {
+            return Some(RouteConstraintViolation { code: self.code });
         }
 
         None
@@ -128,6 +115,39 @@ fn check_none_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String
     }
 }
 
+fn has_comparable_skills(source: Option<&JobSkills>, candidate: Option<&JobSkills>) -> bool {
+    let check_skill_sets = |source_set: Option<&HashSet<String>>, candidate_set: Option<&HashSet<String>>| match (
+        source_set,
+        candidate_set,
+    ) {
+        (Some(_), None) | (None, None) => true,
+        (None, Some(_)) => false,
+        (Some(source_skills), Some(candidate_skills)) => candidate_skills.is_subset(source_skills),
+    };
+
+    match (source, candidate) {
+        (Some(_), None) | (None, None) => true,
+        (None, Some(_)) => false,
+        (Some(source_skills), Some(candidate_skills)) => {
+            check_skill_sets(source_skills.all_of.as_ref(), candidate_skills.all_of.as_ref())
+                && check_skill_sets(source_skills.one_of.as_ref(), candidate_skills.one_of.as_ref())
+                && check_skill_sets(source_skills.none_of.as_ref(), candidate_skills.none_of.as_ref())
+        }
+    }
+}
+
 fn get_skills(job: &Job) -> Option<&JobSkills> {
-    job.dimens().get_value::<JobSkills>("skills")
+    job.dimens().get_dimen(&JOB_SKILLS_KEY)
+}
+
+/// Returns skills defined on job tasks: each sub job of multi job can have its own skills.
+fn get_task_skills(job: &Job) -> Vec<&JobSkills> {
+    match job {
+        Job::Single(single) => get_single_task_skills(single.as_ref()).into_iter().collect(),
+        Job::Multi(multi) => multi.jobs.iter().filter_map(|single| get_single_task_skills(single.as_ref())).collect(),
+    }
+}
+
+fn get_single_task_skills(single: &Single) -> Option<&JobSkills> {
+    single.dimens.get_dimen(&JOB_TASK_SKILLS_KEY)
 }
@@ -0,0 +1,366 @@
+#[cfg(test)]
+#[path = "../../tests/unit/constraints/sync_test.rs"]
+mod sync_test;
+
+use crate::constraints::return_removed_jobs;
+use crate::extensions::get_service_time_factor;
+use hashbrown::{HashMap, HashSet};
+use std::cmp::Ordering;
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{ActivityContext, RouteContext, SolutionContext};
+use vrp_core::models::common::{DimenKey, Timestamp, ValueDimension};
+use vrp_core::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
+use vrp_core::prelude::compare_floats;
+
+/// A synchronized visit: one of the job visits which are served in specific order, potentially,
+/// by different vehicles. A transfer job is modeled with two such visits: the drop and the pickup
+/// of the goods at the transfer place.
+pub struct SyncVisit {
+    /// An id of the job which visit belongs to.
+    pub job_id: String,
+    /// A visit index in the order of visits.
+    pub index: usize,
+    /// A total amount of visits.
+    pub total: usize,
+    /// A max time between the end of the visit and the start of the next one.
+    pub max_gap: Option<f64>,
+}
+
+/// Keeps max gap and service start with departure of assigned visits for each synchronized job.
+type SyncSchedules = HashMap<String, (Option<f64>, Vec<Option<(Timestamp, Timestamp)>>)>;
+
+/// A sync module keeps visits of the synchronized job in the order of their definition and within
+/// max gap between each other. Visits are assigned all together or none of them. A visit is either
+/// a single job or a part of a multi job.
+pub struct SyncModule {
+    code: i32,
+    state_key: i32,
+    visit_key: DimenKey<SyncVisit>,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl SyncModule {
+    /// Creates a new instance of `SyncModule`.
+    pub fn new(
+        activity: Arc<dyn ActivityCost + Send + Sync>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+        code: i32,
+        state_key: i32,
+        visit_key: DimenKey<SyncVisit>,
+    ) -> Self {
+        Self {
+            code,
+            state_key,
+            visit_key,
+            constraints: vec![
+                ConstraintVariant::HardRoute(Arc::new(SyncHardRouteConstraint {
+                    code,
+                    state_key,
+                    visit_key,
+                    transport: transport.clone(),
+                })),
+                ConstraintVariant::HardActivity(Arc::new(SyncHardActivityConstraint {
+                    code,
+                    state_key,
+                    visit_key,
+                    activity: activity.clone(),
+                    transport: transport.clone(),
+                })),
+            ],
+            keys: vec![state_key],
+            activity,
+            transport,
+        }
+    }
+
+    /// Keeps schedules in solution state and in the state of each route, so they are available
+    /// for both route and activity constraints.
+    fn update_schedules(&self, solution_ctx: &mut SolutionContext, schedules: SyncSchedules) {
+        let schedules = Arc::new(schedules);
+
+        solution_ctx.state.insert(self.state_key, schedules.clone());
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.state_mut().put_route_state(self.state_key, schedules.clone());
+        });
+    }
+}
+
+impl ConstraintModule for SyncModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        // NOTE insertion can shift visits only in the changed tour, so the rest of schedules is kept
+        let mut schedules = get_cached_schedules(solution_ctx, self.state_key).cloned().unwrap_or_default();
+        if let Some(route_ctx) = solution_ctx.routes.get(route_index) {
+            add_route_schedules(&mut schedules, route_ctx, self.visit_key);
+        }
+
+        self.update_schedules(solution_ctx, schedules);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        remove_invalid_visits(solution_ctx, self.code, self.visit_key, self.activity.as_ref(), self.transport.as_ref());
+
+        let schedules = get_sync_schedules(solution_ctx, self.visit_key);
+        self.update_schedules(solution_ctx, schedules);
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, i32> {
+        if [&source, &candidate].iter().any(|job| get_job_visit(job, self.visit_key).is_some()) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+/// Checks visit insertion into a new tour which has no schedules state yet.
+struct SyncHardRouteConstraint {
+    code: i32,
+    state_key: i32,
+    visit_key: DimenKey<SyncVisit>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardRouteConstraint for SyncHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        let (single, visit) = get_job_visit(job, self.visit_key)?;
+
+        if route_ctx.state.get_route_state::<Arc<SyncSchedules>>(self.state_key).is_some() {
+            return None;
+        }
+
+        let (max_gap, schedules) = get_cached_schedules(solution_ctx, self.state_key)?.get(&visit.job_id)?;
+        let route = &route_ctx.route;
+        let start = route.tour.start()?;
+        let departure = start.schedule.departure;
+
+        // NOTE direct travel from tour start gives the exact visit time only when the visit is the
+        // first activity of the job, otherwise it is the earliest possible one: the visit cannot
+        // happen earlier, so only the conditions which cannot be fixed by a later visit are checked.
+        let is_first =
+            job.as_multi().map_or(true, |multi| multi.jobs.first().map_or(false, |first| Arc::ptr_eq(first, single)));
+
+        let factor = get_service_time_factor(route.actor.vehicle.as_ref(), single).unwrap_or(1.);
+        let is_valid = single.places.iter().any(|place| {
+            let location = place.location.unwrap_or(start.place.location);
+            let arrival = departure
+                + self.transport.duration(route, start.place.location, location, TravelTime::Departure(departure));
+
+            place.times.iter().map(|time| time.to_time_window(departure)).any(|time| {
+                let start = arrival.max(time.start);
+                let schedule = (start, start + place.duration * factor);
+
+                if is_first {
+                    let mut schedules = schedules.clone();
+                    schedules[visit.index] = Some(schedule);
+
+                    is_valid_schedules(&schedules, *max_gap)
+                } else {
+                    is_valid_earliest_schedule(schedules, visit.index, schedule, *max_gap)
+                }
+            })
+        });
+
+        if is_valid {
+            None
+        } else {
+            Some(RouteConstraintViolation { code: self.code })
+        }
+    }
+}
+
+struct SyncHardActivityConstraint {
+    code: i32,
+    state_key: i32,
+    visit_key: DimenKey<SyncVisit>,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+}
+
+impl HardActivityConstraint for SyncHardActivityConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ActivityConstraintViolation> {
+        let visit = activity_ctx.target.job.as_ref().and_then(|single| get_sync_visit(single, self.visit_key))?;
+        let (_, schedules) =
+            route_ctx.state.get_route_state::<Arc<SyncSchedules>>(self.state_key)?.get(&visit.job_id)?;
+
+        let route = &route_ctx.route;
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        let start = arrival.max(target.place.time.start);
+        let departure = self.activity.estimate_departure(route, target, arrival);
+
+        let mut schedules = schedules.clone();
+        schedules[visit.index] = Some((start, departure));
+
+        if !is_valid_schedules(&schedules, visit.max_gap) {
+            Some(ActivityConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Removes visits of synchronized jobs which are violated, e.g. due to schedule change of other
+/// activities in the tour, or assigned partially.
+fn remove_invalid_visits(
+    solution_ctx: &mut SolutionContext,
+    code: i32,
+    visit_key: DimenKey<SyncVisit>,
+    activity: &(dyn ActivityCost + Send + Sync),
+    transport: &(dyn TransportCost + Send + Sync),
+) {
+    let schedules = get_sync_schedules(solution_ctx, visit_key);
+
+    let partial_jobs = solution_ctx
+        .unassigned
+        .keys()
+        .filter(|job| !solution_ctx.required.contains(job))
+        .filter_map(|job| get_job_visit(job, visit_key))
+        .map(|(_, visit)| visit.job_id.clone())
+        .collect::<HashSet<_>>();
+
+    let invalid_jobs = schedules
+        .iter()
+        .filter(|(job_id, (max_gap, schedules))| {
+            partial_jobs.contains(*job_id) || !is_valid_schedules(schedules, *max_gap)
+        })
+        .map(|(job_id, _)| job_id.clone())
+        .collect::<HashSet<_>>();
+
+    if invalid_jobs.is_empty() {
+        return;
+    }
+
+    let locked = &solution_ctx.locked;
+    let mut removed = Vec::new();
+
+    solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+        let visits = route_ctx
+            .route
+            .tour
+            .jobs()
+            .filter(|job| !locked.contains(job))
+            .filter(|job| {
+                get_job_visit(job, visit_key).map_or(false, |(_, visit)| invalid_jobs.contains(&visit.job_id))
+            })
+            .collect::<Vec<_>>();
+
+        if !visits.is_empty() {
+            visits.iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(job);
+            });
+            update_route_schedule(route_ctx, activity, transport);
+            removed.extend(visits);
+        }
+    });
+
+    return_removed_jobs(solution_ctx, removed, code);
+}
+
+/// Checks that each assigned visit starts after all previous ones are finished and that
+/// the gap between two consecutive visits is not exceeded.
+fn is_valid_schedules(schedules: &[Option<(Timestamp, Timestamp)>], max_gap: Option<f64>) -> bool {
+    let assigned = schedules
+        .iter()
+        .enumerate()
+        .filter_map(|(index, schedule)| schedule.map(|schedule| (index, schedule)))
+        .collect::<Vec<_>>();
+
+    assigned.iter().enumerate().all(|(idx, (prev_index, (_, departure)))| {
+        assigned.iter().skip(idx + 1).all(|(next_index, (start, _))| {
+            let is_early = compare_floats(*start, *departure) == Ordering::Less;
+            let is_late = *next_index == *prev_index + 1
+                && max_gap.map_or(false, |gap| compare_floats(*start - *departure, gap) == Ordering::Greater);
+
+            !is_early && !is_late
+        })
+    })
+}
+
+/// Checks the earliest possible schedule of the visit: it should not start later than max gap after
+/// the previous visit departure and it should not end after the start of any next visit.
+fn is_valid_earliest_schedule(
+    schedules: &[Option<(Timestamp, Timestamp)>],
+    index: usize,
+    (start, departure): (Timestamp, Timestamp),
+    max_gap: Option<f64>,
+) -> bool {
+    let is_late = index
+        .checked_sub(1)
+        .and_then(|prev_index| schedules[prev_index])
+        .zip(max_gap)
+        .map_or(false, |((_, prev_departure), gap)| compare_floats(start - prev_departure, gap) == Ordering::Greater);
+
+    let is_early = schedules
+        .iter()
+        .skip(index + 1)
+        .flatten()
+        .any(|(next_start, _)| compare_floats(*next_start, departure) == Ordering::Less);
+
+    !is_late && !is_early
+}
+
+fn get_sync_visit(single: &Single, visit_key: DimenKey<SyncVisit>) -> Option<&SyncVisit> {
+    single.dimens.get_dimen(&visit_key)
+}
+
+fn get_job_visit(job: &Job, visit_key: DimenKey<SyncVisit>) -> Option<(&Arc<Single>, &SyncVisit)> {
+    match job {
+        Job::Single(single) => get_sync_visit(single, visit_key).map(|visit| (single, visit)),
+        Job::Multi(multi) => {
+            multi.jobs.iter().find_map(|single| get_sync_visit(single, visit_key).map(|visit| (single, visit)))
+        }
+    }
+}
+
+fn get_sync_schedules(solution_ctx: &SolutionContext, visit_key: DimenKey<SyncVisit>) -> SyncSchedules {
+    solution_ctx.routes.iter().fold(SyncSchedules::default(), |mut schedules, route_ctx| {
+        add_route_schedules(&mut schedules, route_ctx, visit_key);
+        schedules
+    })
+}
+
+fn get_cached_schedules(solution_ctx: &SolutionContext, state_key: i32) -> Option<&SyncSchedules> {
+    solution_ctx.state.get(&state_key).and_then(|schedules| schedules.downcast_ref::<SyncSchedules>())
+}
+
+fn add_route_schedules(schedules: &mut SyncSchedules, route_ctx: &RouteContext, visit_key: DimenKey<SyncVisit>) {
+    route_ctx.route.tour.all_activities().for_each(|activity| {
+        if let Some(visit) = activity.job.as_ref().and_then(|single| get_sync_visit(single, visit_key)) {
+            let (_, visits) =
+                schedules.entry(visit.job_id.clone()).or_insert_with(|| (visit.max_gap, vec![None; visit.total]));
+            visits[visit.index] =
+                Some((activity.schedule.arrival.max(activity.place.time.start), activity.schedule.departure));
+        }
+    });
+}
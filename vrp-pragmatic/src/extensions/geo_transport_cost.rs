@@ -0,0 +1,122 @@
+#[cfg(test)]
+#[path = "../../tests/unit/extensions/geo_transport_cost_test.rs"]
+mod geo_transport_cost_test;
+
+use crate::format::problem::Problem as ApiProblem;
+use crate::format::Location as ApiLocation;
+use crate::get_unique_locations;
+use crate::utils::get_haversine_distance;
+use vrp_core::models::common::{Distance, Duration, Location, Profile};
+use vrp_core::models::problem::{TransportCost, TravelTime};
+use vrp_core::models::solution::Route;
+
+/// A default approximation speed in meters per second.
+const DEFAULT_SPEED: f64 = 10.;
+
+/// A default approximation detour factor.
+const DEFAULT_DETOUR_FACTOR: f64 = 1.;
+
+/// Specifies geo approximation settings of a routing profile.
+#[derive(Clone, Debug)]
+pub struct GeoProfile {
+    /// An average speed in meters per second.
+    pub speed: f64,
+    /// A factor applied to straight line distance to estimate road distance.
+    pub detour_factor: f64,
+}
+
+impl Default for GeoProfile {
+    fn default() -> Self {
+        Self { speed: DEFAULT_SPEED, detour_factor: DEFAULT_DETOUR_FACTOR }
+    }
+}
+
+/// A transport cost which calculates distances between geo coordinates on demand using haversine formula
+/// and durations using profile's average speed. It does not require any routing matrix, so it can be used
+/// to get quick estimations without routing engine.
+pub struct GeoTransportCost {
+    coordinates: Vec<(f64, f64)>,
+    profiles: Vec<GeoProfile>,
+}
+
+impl GeoTransportCost {
+    /// Creates a new instance of `GeoTransportCost`. Location index is a position in the `locations`
+    /// collection and profile index is a position in the `profiles` collection.
+    pub fn new(locations: Vec<ApiLocation>, profiles: Vec<GeoProfile>) -> Result<Self, String> {
+        if profiles.is_empty() {
+            return Err("at least one profile should be specified".to_string());
+        }
+
+        if let Some(profile) = profiles.iter().find(|profile| profile.speed <= 0.) {
+            return Err(format!("speed should be positive, got: '{}'", profile.speed));
+        }
+
+        if let Some(profile) = profiles.iter().find(|profile| profile.detour_factor < 1.) {
+            return Err(format!("detour factor should not be less than one, got: '{}'", profile.detour_factor));
+        }
+
+        let coordinates = locations
+            .into_iter()
+            .map(|location| match location {
+                ApiLocation::Coordinate { lat, lng } => Ok((lat, lng)),
+                ApiLocation::Reference { .. } => Err("geo approximation requires coordinates".to_string()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { coordinates, profiles })
+    }
+
+    /// Creates a new instance of `GeoTransportCost` using problem locations and approximation settings
+    /// specified on its routing profiles.
+    pub fn from_problem(problem: &ApiProblem) -> Result<Self, String> {
+        let profiles = get_geo_profiles(problem);
+
+        Self::new(get_unique_locations(problem), profiles)
+    }
+
+    fn get_distance(&self, profile: &Profile, from: Location, to: Location) -> f64 {
+        let get_location = |index: Location| {
+            let (lat, lng) = *self.coordinates.get(index).expect("unknown location index");
+            ApiLocation::Coordinate { lat, lng }
+        };
+
+        get_haversine_distance(&get_location(from), &get_location(to)) * self.get_profile(profile).detour_factor
+    }
+
+    fn get_profile(&self, profile: &Profile) -> &GeoProfile {
+        self.profiles.get(profile.index).expect("unknown profile index")
+    }
+}
+
+impl TransportCost for GeoTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        let duration = (self.get_distance(profile, from, to) / self.get_profile(profile).speed).round();
+
+        duration * profile.scale
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.get_distance(profile, from, to).round()
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        self.duration_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+}
+
+/// Gets geo approximation settings of problem's routing profiles ordered by profile index.
+pub(crate) fn get_geo_profiles(problem: &ApiProblem) -> Vec<GeoProfile> {
+    problem
+        .fleet
+        .profiles
+        .iter()
+        .map(|profile| GeoProfile {
+            speed: profile.speed.unwrap_or(DEFAULT_SPEED),
+            detour_factor: profile.detour_factor.unwrap_or(DEFAULT_DETOUR_FACTOR),
+        })
+        .collect()
+}
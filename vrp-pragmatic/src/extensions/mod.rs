@@ -1,6 +1,10 @@
 //! Contains some algorithm extensions.
 
+mod geo_transport_cost;
+pub use self::geo_transport_cost::*;
+
 mod only_vehicle_activity_cost;
+pub(crate) use self::only_vehicle_activity_cost::get_service_time_factor;
 pub use self::only_vehicle_activity_cost::OnlyVehicleActivityCost;
 
 mod route_modifier;
@@ -1,12 +1,25 @@
+#[cfg(test)]
+#[path = "../../tests/unit/extensions/only_vehicle_activity_cost_test.rs"]
+mod only_vehicle_activity_cost_test;
+
 use crate::core::models::solution::Route;
-use vrp_core::models::common::{Cost, Timestamp};
-use vrp_core::models::problem::{ActivityCost, SimpleActivityCost};
+use crate::format::dimens::*;
+use vrp_core::models::common::{Cost, Duration, Timestamp, ValueDimension};
+use vrp_core::models::problem::{is_depot_activity, ActivityCost, Single, Vehicle};
 use vrp_core::models::solution::Activity;
 
-/// Uses costs only for a vehicle ignoring costs of a driver.
+/// Uses costs only for a vehicle ignoring costs of a driver. Optionally, scales service durations
+/// of job activities using vehicle's service time factor.
 #[derive(Default)]
 pub struct OnlyVehicleActivityCost {
-    inner: SimpleActivityCost,
+    has_service_time_factors: bool,
+}
+
+impl OnlyVehicleActivityCost {
+    /// Creates a new instance of `OnlyVehicleActivityCost`.
+    pub fn new(has_service_time_factors: bool) -> Self {
+        Self { has_service_time_factors }
+    }
 }
 
 impl ActivityCost for OnlyVehicleActivityCost {
@@ -14,16 +27,38 @@ impl ActivityCost for OnlyVehicleActivityCost {
         let actor = route.actor.as_ref();
 
         let waiting = if activity.place.time.start > arrival { activity.place.time.start - arrival } else { 0.0 };
-        let service = activity.place.duration;
+        let service = self.service_duration(route, activity);
+
+        let waiting_rate = actor.vehicle.costs.waiting_rate(is_depot_activity(actor, activity));
+
+        waiting * waiting_rate + service * actor.vehicle.costs.per_service_time
+    }
+
+    fn service_duration(&self, route: &Route, activity: &Activity) -> Duration {
+        let factor = if self.has_service_time_factors {
+            activity.job.as_ref().and_then(|single| get_service_time_factor(route.actor.vehicle.as_ref(), single))
+        } else {
+            None
+        };
 
-        waiting * actor.vehicle.costs.per_waiting_time + service * actor.vehicle.costs.per_service_time
+        factor.map_or(activity.place.duration, |factor| activity.place.duration * factor)
     }
 
     fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
-        self.inner.estimate_departure(route, activity, arrival)
+        arrival.max(activity.place.time.start) + self.service_duration(route, activity)
     }
 
     fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
-        self.inner.estimate_arrival(route, activity, departure)
+        activity.place.time.end.min(departure - self.service_duration(route, activity))
+    }
+}
+
+/// Returns service time factor of the vehicle if it is applicable to given job.
+/// Conditional jobs (e.g. break, reload) are not affected.
+pub(crate) fn get_service_time_factor(vehicle: &Vehicle, single: &Single) -> Option<f64> {
+    if single.dimens.get_dimen(&JOB_VEHICLE_ID_KEY).is_some() {
+        return None;
     }
+
+    vehicle.dimens.get_dimen(&VEHICLE_SERVICE_TIME_FACTOR_KEY).cloned()
 }
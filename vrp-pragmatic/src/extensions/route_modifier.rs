@@ -1,3 +1,4 @@
+use crate::format::dimens::*;
 use crate::format::{JobIndex, UNASSIGNABLE_ROUTE_KEY};
 use std::sync::Arc;
 use vrp_core::construction::constraints::ConstraintPipeline;
@@ -15,7 +16,7 @@ pub fn get_route_modifier(
         let actor = &route_ctx.route.actor;
         let vehicle = &actor.vehicle;
 
-        let shift_index = vehicle.dimens.get_value::<usize>("shift_index").expect("cannot find shift index");
+        let shift_index = vehicle.dimens.get_dimen(&SHIFT_INDEX_KEY).expect("cannot find shift index");
         let vehicle_id = vehicle.dimens.get_id().expect("cannot get vehicle id");
 
         let candidates = (1..)
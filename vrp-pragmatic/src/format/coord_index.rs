@@ -27,7 +27,15 @@ impl CoordIndex {
                 .flat_map(|tasks| tasks.iter().flat_map(|task| task.places.iter()))
                 .for_each(|place| {
                     index.add(&place.location);
+
+                    if let Some(parking) = &place.parking {
+                        index.add(&parking.location);
+                    }
                 });
+
+            if let Some(transfer) = &job.transfer {
+                index.add(&transfer.location);
+            }
         });
 
         // process fleet
@@ -37,6 +45,7 @@ impl CoordIndex {
 
                 if let Some(end) = &shift.end {
                     index.add(&end.location);
+                    end.alternatives.iter().flatten().for_each(|location| index.add(location));
                 }
 
                 if let Some(dispatch) = &shift.dispatch {
@@ -99,6 +108,11 @@ impl CoordIndex {
         self.reverse_index.keys().max().cloned()
     }
 
+    /// Returns dimension of routing matrix required to address all locations.
+    pub fn matrix_size(&self) -> usize {
+        self.max_index().map_or(0, |index| index.saturating_add(1))
+    }
+
     /// Returns types of locations in form (has_coordinates, has_indices).
     pub fn get_used_types(&self) -> (bool, bool) {
         self.direct_index.iter().fold((false, false), |(has_coordinates, has_indices), (location, _)| match location {
@@ -0,0 +1,72 @@
+//! Specifies typed keys of values which are stored in dimensions of core models when pragmatic
+//! problem is read and which are used later by constraints and solution writer.
+
+use crate::constraints::{BreakPolicy, DepartureLoading, DriverHours, JobSkills, Overtime, ReloadResource, SyncVisit};
+use hashbrown::{HashMap, HashSet};
+use vrp_core::models::common::DimenKey;
+
+/// A key of vehicle type id.
+pub const VEHICLE_TYPE_ID_KEY: DimenKey<String> = DimenKey::new("type_id");
+/// A key of vehicle type group id which is set when multiple vehicle types share the same settings.
+pub const VEHICLE_TYPE_GROUP_ID_KEY: DimenKey<String> = DimenKey::new("type_group_id");
+/// A key of vehicle shift index. It is also set on conditional jobs bound to specific vehicle shift.
+pub const SHIFT_INDEX_KEY: DimenKey<usize> = DimenKey::new("shift_index");
+/// A key of vehicle areas: area job ids mapped to area order and value.
+pub const VEHICLE_AREAS_KEY: DimenKey<HashMap<String, (usize, f64)>> = DimenKey::new("areas");
+/// A key of vehicle tour size limit.
+pub const VEHICLE_TOUR_SIZE_KEY: DimenKey<usize> = DimenKey::new("tour_size");
+/// A key of vehicle max cost limit.
+pub const VEHICLE_MAX_COST_KEY: DimenKey<f64> = DimenKey::new("max_cost");
+/// A key of vehicle max stops limit.
+pub const VEHICLE_MAX_STOPS_KEY: DimenKey<usize> = DimenKey::new("max_stops");
+/// A key of vehicle carry load flag.
+pub const VEHICLE_CARRY_LOAD_KEY: DimenKey<bool> = DimenKey::new("carry_load");
+/// A key of vehicle echelon in two-echelon routing.
+pub const VEHICLE_ECHELON_KEY: DimenKey<usize> = DimenKey::new("echelon");
+/// A key of vehicle service time factor which multiplies service durations of jobs.
+pub const VEHICLE_SERVICE_TIME_FACTOR_KEY: DimenKey<f64> = DimenKey::new("service_time_factor");
+/// A key of vehicle skills.
+pub const VEHICLE_SKILLS_KEY: DimenKey<HashSet<String>> = DimenKey::new("skills");
+/// A key of vehicle driver hours rules.
+pub const VEHICLE_DRIVER_HOURS_KEY: DimenKey<DriverHours> = DimenKey::new("driver_hours");
+/// A key of vehicle overtime settings.
+pub const VEHICLE_OVERTIME_KEY: DimenKey<Overtime> = DimenKey::new("overtime");
+/// A key of vehicle loading settings at shift start.
+pub const VEHICLE_LOADING_KEY: DimenKey<DepartureLoading> = DimenKey::new("loading");
+
+/// A key of vehicle id which conditional job (e.g. break, dispatch, reload) belongs to.
+pub const JOB_VEHICLE_ID_KEY: DimenKey<String> = DimenKey::new("vehicle_id");
+/// A key of job activity type, e.g. pickup, delivery, break.
+pub const JOB_TYPE_KEY: DimenKey<String> = DimenKey::new("type");
+/// A key of job skills.
+pub const JOB_SKILLS_KEY: DimenKey<JobSkills> = DimenKey::new("skills");
+/// A key of job task skills.
+pub const JOB_TASK_SKILLS_KEY: DimenKey<JobSkills> = DimenKey::new("task_skills");
+/// A key of job tags: place index mapped to tag.
+pub const JOB_TAGS_KEY: DimenKey<Vec<(usize, String)>> = DimenKey::new("tags");
+/// A key of job order.
+pub const JOB_ORDER_KEY: DimenKey<i32> = DimenKey::new("order");
+/// A key of job value.
+pub const JOB_VALUE_KEY: DimenKey<f64> = DimenKey::new("value");
+/// A key of job group.
+pub const JOB_GROUP_KEY: DimenKey<String> = DimenKey::new("group");
+/// A key of job compatibility.
+pub const JOB_COMPATIBILITY_KEY: DimenKey<String> = DimenKey::new("compat");
+/// A key of job schedule buffer.
+pub const JOB_BUFFER_KEY: DimenKey<f64> = DimenKey::new("buffer");
+/// A key of job prioritized flag.
+pub const JOB_PRIORITIZED_KEY: DimenKey<bool> = DimenKey::new("prioritized");
+/// A key of synchronized job visit.
+pub const JOB_SYNC_VISIT_KEY: DimenKey<SyncVisit> = DimenKey::new("sync_visit");
+/// A key of job transfer visit.
+pub const JOB_TRANSFER_VISIT_KEY: DimenKey<SyncVisit> = DimenKey::new("transfer_visit");
+/// A key of break job policy.
+pub const BREAK_POLICY_KEY: DimenKey<BreakPolicy> = DimenKey::new("policy");
+/// A key of break job min tour duration.
+pub const BREAK_MIN_SHIFT_DURATION_KEY: DimenKey<f64> = DimenKey::new("min_shift_duration");
+/// A key of break job min spacing to other breaks.
+pub const BREAK_MIN_SPACING_KEY: DimenKey<f64> = DimenKey::new("min_spacing");
+/// A key of reload job extra duration per unit of load on board after reload.
+pub const RELOAD_PER_UNIT_DURATION_KEY: DimenKey<f64> = DimenKey::new("per_unit_duration");
+/// A key of reload job shared resource.
+pub const RELOAD_RESOURCE_KEY: DimenKey<ReloadResource> = DimenKey::new("resource");
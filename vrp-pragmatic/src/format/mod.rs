@@ -15,6 +15,7 @@ use vrp_core::models::Problem as CoreProblem;
 mod coord_index;
 pub use self::coord_index::CoordIndex;
 
+pub mod dimens;
 pub mod problem;
 pub mod solution;
 
@@ -65,6 +66,23 @@ impl fmt::Display for Location {
     }
 }
 
+/// Specifies units of measure for durations and distances. By default, durations are in seconds
+/// and distances are in meters, but they can represent abstract units, e.g. picking seconds and
+/// meters inside a warehouse. Vehicle costs and limits are interpreted in the same units.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct PlanningUnits {
+    /// A unit of durations: travel times, service durations, time windows and shift time limit.
+    pub duration: String,
+    /// A unit of distances: travel distances and distance limit.
+    pub distance: String,
+}
+
+impl Default for PlanningUnits {
+    fn default() -> Self {
+        Self { duration: "second".to_string(), distance: "meter".to_string() }
+    }
+}
+
 /// A format error.
 #[derive(Clone, Debug, Serialize)]
 pub struct FormatError {
@@ -133,12 +151,30 @@ const TOUR_SIZE_CONSTRAINT_CODE: i32 = 11;
 const TOUR_ORDER_CONSTRAINT_CODE: i32 = 12;
 const GROUP_CONSTRAINT_CODE: i32 = 13;
 const COMPATIBILITY_CONSTRAINT_CODE: i32 = 14;
+const TOUR_COST_CONSTRAINT_CODE: i32 = 15;
+const TOUR_STOPS_CONSTRAINT_CODE: i32 = 16;
+const SYNC_CONSTRAINT_CODE: i32 = 17;
+const DRIVER_HOURS_CONSTRAINT_CODE: i32 = 18;
+const RELOAD_CONSTRAINT_CODE: i32 = 19;
+const TRANSFER_CONSTRAINT_CODE: i32 = 20;
+const ECHELON_CONSTRAINT_CODE: i32 = 21;
+const SCHEDULE_BUFFER_CONSTRAINT_CODE: i32 = 22;
+const LATEST_ETA_CONSTRAINT_CODE: i32 = 23;
 
 pub(crate) const UNASSIGNABLE_ROUTE_KEY: i32 = 100;
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
 
+/// A job id of driver rest activity. Rests are inserted automatically, so the id is namespaced
+/// to not clash with ids of user defined jobs.
+pub const DRIVER_REST_JOB_ID: &str = "vrp:rest";
+
+/// Checks whether given job id is reserved for vehicle activities, e.g. departure or break.
+pub fn is_reserved_job_id(job_id: &str) -> bool {
+    matches!(job_id, "departure" | "arrival" | "break" | "reload" | "dispatch" | DRIVER_REST_JOB_ID)
+}
+
 /// Gets job index from core problem definition.
 pub fn get_job_index(problem: &CoreProblem) -> &JobIndex {
     problem
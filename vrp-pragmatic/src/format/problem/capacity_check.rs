@@ -0,0 +1,294 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/capacity_check_test.rs"]
+mod capacity_check_test;
+
+use crate::format::problem::{Job, JobSkills, JobTask, Problem, VehicleType};
+use hashbrown::{HashMap, HashSet};
+use serde::Serialize;
+
+/// Suggests how many extra vehicles of given type are needed to cover a capacity shortfall.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleSuggestion {
+    /// A vehicle type id.
+    pub type_id: String,
+    /// Amount of extra vehicles of this type.
+    pub vehicles: usize,
+}
+
+/// Compares total static demand of jobs with total capacity of vehicles which can serve them.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapacityCheck {
+    /// Skills required by checked jobs. Not set for the overall check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<JobSkills>,
+    /// Skills required by checked job tasks in addition to job skills.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_skills: Option<JobSkills>,
+    /// An area id of checked jobs. Not set for the overall check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
+    /// Amount of checked jobs.
+    pub jobs: usize,
+    /// Total demand of static delivery jobs per dimension.
+    pub delivery: Vec<i32>,
+    /// Total demand of static pickup jobs per dimension.
+    pub pickup: Vec<i32>,
+    /// Total demand of pickup and delivery jobs per dimension. Such demand occupies capacity only
+    /// temporary, so it is not compared with total capacity.
+    pub transfer: Vec<i32>,
+    /// Total capacity of vehicle shifts which can serve checked jobs per dimension. Capacity is
+    /// multiplied by amount of reloads as each reload allows to reuse it.
+    pub capacity: Vec<i32>,
+    /// Demand which exceeds capacity per dimension, zeros when capacity is sufficient.
+    pub shortfall: Vec<i32>,
+    /// Ids of jobs which have a task with demand exceeding capacity of any single vehicle.
+    pub oversized: Vec<String>,
+    /// Extra vehicles per vehicle type, each of them alone covers the shortfall.
+    pub suggestions: Vec<VehicleSuggestion>,
+}
+
+impl CapacityCheck {
+    /// Returns true if demand exceeds capacity in any dimension or some job cannot fit into any vehicle.
+    pub fn has_shortfall(&self) -> bool {
+        self.shortfall.iter().any(|&value| value > 0) || !self.oversized.is_empty()
+    }
+}
+
+/// Contains results of capacity feasibility pre-check.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapacityReport {
+    /// A check of all jobs against the whole fleet.
+    pub overall: CapacityCheck,
+    /// Checks of jobs grouped by their job and task skills requirement against vehicles which have
+    /// these skills.
+    pub skills: Vec<CapacityCheck>,
+    /// Checks of jobs grouped by areas against vehicles which have these areas in their limits.
+    /// As areas define vehicle preferences, a shortfall means that not all jobs of the area can
+    /// be served by preferred vehicles.
+    pub areas: Vec<CapacityCheck>,
+}
+
+/// A job task with its activity type and a flag whether it belongs to pickup and delivery job.
+type TaskDemand<'a> = (&'a Job, &'a JobTask, &'static str, bool);
+
+/// Checks whether total demand of jobs can fit into total fleet capacity, overall, per required
+/// skills and per area, and reports the shortfall per dimension. This is a cheap necessary condition
+/// evaluated before solving: when it fails, many jobs end up unassigned regardless of search time.
+/// Demand of pickup and delivery jobs reuses capacity within a tour, so it is reported separately
+/// and checked only against capacity of a single vehicle.
+pub fn check_capacity_feasibility(problem: &Problem) -> CapacityReport {
+    let vehicles = problem.fleet.vehicles.iter().collect::<Vec<_>>();
+    let tasks = problem.plan.jobs.iter().flat_map(get_task_demands).collect::<Vec<_>>();
+
+    let mut groups: Vec<((Option<JobSkills>, Option<JobSkills>), Vec<TaskDemand>)> = vec![];
+    let mut group_indices = HashMap::<String, usize>::new();
+    tasks.iter().filter(|(job, task, _, _)| job.skills.is_some() || task.skills.is_some()).for_each(|task_demand| {
+        let (job, task, _, _) = task_demand;
+        let skills = (job.skills.as_ref().map(normalize_skills), task.skills.as_ref().map(normalize_skills));
+
+        let key = format!("{:?}", skills);
+        let idx = *group_indices.entry(key).or_insert_with(|| {
+            groups.push((skills, vec![]));
+            groups.len() - 1
+        });
+        groups[idx].1.push(*task_demand);
+    });
+
+    let areas = problem
+        .plan
+        .areas
+        .iter()
+        .flatten()
+        .map(|area| {
+            let job_ids = area.jobs.iter().collect::<HashSet<_>>();
+            let tasks = tasks.iter().filter(|(job, _, _, _)| job_ids.contains(&job.id)).cloned().collect::<Vec<_>>();
+            let vehicles = vehicles.iter().filter(|vehicle| has_area(vehicle, &area.id)).cloned().collect::<Vec<_>>();
+
+            CapacityCheck {
+                area: Some(area.id.clone()),
+                ..create_capacity_check(tasks.as_slice(), vehicles.as_slice())
+            }
+        })
+        .collect();
+
+    CapacityReport {
+        overall: create_capacity_check(tasks.as_slice(), vehicles.as_slice()),
+        skills: groups
+            .into_iter()
+            .map(|((skills, task_skills), tasks)| {
+                let vehicles = vehicles
+                    .iter()
+                    .filter(|vehicle| skills.iter().chain(task_skills.iter()).all(|skills| has_skills(vehicle, skills)))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                CapacityCheck { skills, task_skills, ..create_capacity_check(tasks.as_slice(), vehicles.as_slice()) }
+            })
+            .collect(),
+        areas,
+    }
+}
+
+fn get_task_demands(job: &Job) -> impl Iterator<Item = TaskDemand<'_>> + '_ {
+    let has_pickups = job.pickups.as_ref().map_or(false, |tasks| !tasks.is_empty());
+    let has_deliveries = job.deliveries.as_ref().map_or(false, |tasks| !tasks.is_empty());
+    let is_transfer = has_pickups && has_deliveries;
+
+    let get_tasks = move |tasks: &'static str| {
+        match tasks {
+            "pickup" => &job.pickups,
+            "delivery" => &job.deliveries,
+            "replacement" => &job.replacements,
+            _ => &job.services,
+        }
+        .iter()
+        .flatten()
+        .map(move |task| (job, task, tasks, is_transfer))
+    };
+
+    get_tasks("pickup").chain(get_tasks("delivery")).chain(get_tasks("replacement")).chain(get_tasks("service"))
+}
+
+fn create_capacity_check(tasks: &[TaskDemand], vehicles: &[&VehicleType]) -> CapacityCheck {
+    let (mut delivery, mut pickup, mut transfer) = (vec![], vec![], vec![]);
+    tasks
+        .iter()
+        .filter_map(|(_, task, activity_type, is_transfer)| {
+            task.demand.as_ref().map(|demand| (demand, *activity_type, *is_transfer))
+        })
+        .for_each(|(demand, activity_type, is_transfer)| match (activity_type, is_transfer) {
+            // NOTE pickup and delivery job occupies capacity only temporary, count its demand once
+            ("pickup", true) => add_to(&mut transfer, demand),
+            ("pickup", false) => add_to(&mut pickup, demand),
+            ("delivery", false) => add_to(&mut delivery, demand),
+            ("replacement", _) => {
+                add_to(&mut delivery, demand);
+                add_to(&mut pickup, demand);
+            }
+            _ => {}
+        });
+
+    let mut capacity = vec![];
+    vehicles.iter().for_each(|vehicle| {
+        let amount = vehicle.vehicle_ids.len() as i32;
+        let type_capacity =
+            get_vehicle_capacity(vehicle).iter().map(|value| value.saturating_mul(amount)).collect::<Vec<_>>();
+        add_to(&mut capacity, type_capacity.as_slice())
+    });
+
+    let dimensions = delivery.len().max(pickup.len()).max(transfer.len()).max(capacity.len());
+    let get_value = |values: &Vec<i32>, idx: usize| values.get(idx).cloned().unwrap_or(0);
+    let shortfall = (0..dimensions)
+        .map(|idx| (get_value(&delivery, idx).max(get_value(&pickup, idx)) - get_value(&capacity, idx)).max(0))
+        .collect::<Vec<_>>();
+
+    let mut oversized = tasks
+        .iter()
+        .filter(|(_, task, _, _)| {
+            task.demand.as_ref().map_or(false, |demand| {
+                demand.iter().any(|&value| value > 0)
+                    && !vehicles.iter().any(|vehicle| {
+                        demand.iter().enumerate().all(|(idx, &value)| value <= get_value(&vehicle.capacity, idx))
+                    })
+            })
+        })
+        .map(|(job, _, _, _)| job.id.clone())
+        .collect::<Vec<_>>();
+    oversized.dedup();
+
+    let suggestions = if shortfall.iter().any(|&value| value > 0) {
+        vehicles
+            .iter()
+            .filter_map(|vehicle| {
+                let vehicle_capacity = get_vehicle_capacity(vehicle);
+
+                shortfall
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &value)| value > 0)
+                    .try_fold(0_usize, |acc, (idx, &value)| {
+                        let per_vehicle = get_value(&vehicle_capacity, idx);
+                        if per_vehicle > 0 {
+                            Some(acc.max(((value + per_vehicle - 1) / per_vehicle) as usize))
+                        } else {
+                            None
+                        }
+                    })
+                    .map(|vehicles| VehicleSuggestion { type_id: vehicle.type_id.clone(), vehicles })
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+
+    let resize = |mut values: Vec<i32>| {
+        values.resize(dimensions, 0);
+        values
+    };
+
+    CapacityCheck {
+        skills: None,
+        task_skills: None,
+        area: None,
+        jobs: tasks.iter().map(|(job, _, _, _)| job.id.as_str()).collect::<HashSet<_>>().len(),
+        delivery: resize(delivery),
+        pickup: resize(pickup),
+        transfer: resize(transfer),
+        capacity: resize(capacity),
+        shortfall,
+        oversized,
+        suggestions,
+    }
+}
+
+/// Gets total capacity of a single vehicle of given type taking into account its shifts and reloads.
+fn get_vehicle_capacity(vehicle: &VehicleType) -> Vec<i32> {
+    let trips = vehicle.shifts.iter().map(|shift| 1 + shift.reloads.as_ref().map_or(0, |reloads| reloads.len()));
+    let trips = trips.sum::<usize>() as i32;
+
+    vehicle.capacity.iter().map(|value| value.saturating_mul(trips)).collect()
+}
+
+fn add_to(total: &mut Vec<i32>, values: &[i32]) {
+    if total.len() < values.len() {
+        total.resize(values.len(), 0);
+    }
+
+    total.iter_mut().zip(values.iter()).for_each(|(total, value)| *total = total.saturating_add(*value));
+}
+
+fn has_skills(vehicle: &VehicleType, skills: &JobSkills) -> bool {
+    let vehicle_skills = vehicle.skills.iter().flatten().collect::<HashSet<_>>();
+
+    skills.all_of.as_ref().map_or(true, |all_of| all_of.iter().all(|skill| vehicle_skills.contains(skill)))
+        && skills.one_of.as_ref().map_or(true, |one_of| one_of.iter().any(|skill| vehicle_skills.contains(skill)))
+        && skills.none_of.as_ref().map_or(true, |none_of| none_of.iter().all(|skill| !vehicle_skills.contains(skill)))
+}
+
+fn has_area(vehicle: &VehicleType, area_id: &str) -> bool {
+    vehicle
+        .limits
+        .as_ref()
+        .and_then(|limits| limits.areas.as_ref())
+        .map_or(false, |areas| areas.iter().flatten().any(|limit| limit.area_id == area_id))
+}
+
+fn normalize_skills(skills: &JobSkills) -> JobSkills {
+    let normalize = |skills: &Option<Vec<String>>| {
+        skills.as_ref().filter(|skills| !skills.is_empty()).map(|skills| {
+            let mut skills = skills.clone();
+            skills.sort();
+            skills.dedup();
+            skills
+        })
+    };
+
+    JobSkills {
+        all_of: normalize(&skills.all_of),
+        one_of: normalize(&skills.one_of),
+        none_of: normalize(&skills.none_of),
+    }
+}
@@ -12,11 +12,11 @@ use vrp_core::models::problem::Job;
 use vrp_core::prelude::*;
 
 /// Creates cluster config if it is defined on the api problem.
-pub(crate) fn create_cluster_config(api_problem: &ApiProblem) -> Result<Option<ClusterConfig>, String> {
+pub(crate) fn create_cluster_config(api_problem: &ApiProblem) -> Result<Option<ClusterConfig>, VrpError> {
     if let Some(clustering) = api_problem.plan.clustering.as_ref() {
         match clustering {
             Clustering::Vicinity { profile, threshold, visiting, serving, filtering } => Ok(Some(ClusterConfig {
-                profile: get_profile(api_problem, profile)?,
+                profile: get_profile(api_problem, profile).map_err(VrpError::Config)?,
                 threshold: ThresholdPolicy {
                     moving_duration: threshold.distance,
                     moving_distance: threshold.duration,
@@ -0,0 +1,126 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/executed_stops_test.rs"]
+mod executed_stops_test;
+
+use crate::format::is_reserved_job_id;
+use crate::format::problem::*;
+use crate::format::solution::{Solution, Tour};
+use crate::parse_time_safe;
+use hashbrown::{HashMap, HashSet};
+
+/// Specifies how already executed stops of the tour are detected.
+#[derive(Clone, Debug)]
+pub enum ExecutedStops {
+    /// Stops with arrival time before or equal to the given cutoff time (RFC3339) are executed.
+    Time(String),
+    /// First N stops of each tour are executed, departure stop included.
+    Count(usize),
+    /// All stops up to the last one with an activity of any given job id are executed.
+    Jobs(Vec<String>),
+}
+
+/// Creates a new problem where executed stops of each tour are locked to the same vehicle shift
+/// using strict relation which starts from departure. The rest of the plan can be re-optimized
+/// while locked prefixes are kept untouched: no jobs are inserted before or between them.
+/// Executed jobs are removed from existing relations. Returns error when a job with multiple
+/// activities is executed partially as it cannot be locked.
+pub fn lock_executed_stops(
+    problem: &Problem,
+    solution: &Solution,
+    executed: &ExecutedStops,
+) -> Result<Problem, String> {
+    let cutoff = match executed {
+        ExecutedStops::Time(time) => Some(parse_time_safe(time)?),
+        _ => None,
+    };
+
+    let locked = solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let count = get_executed_count(tour, executed, cutoff)?;
+            let jobs = tour
+                .stops
+                .iter()
+                .take(count)
+                .flat_map(|stop| stop.activities().iter())
+                .map(|activity| activity.job_id.clone())
+                .collect::<Vec<_>>();
+
+            Ok((tour, jobs))
+        })
+        .collect::<Result<Vec<_>, String>>()?
+        .into_iter()
+        .filter(|(_, jobs)| jobs.iter().any(|job_id| job_id != "departure"))
+        .collect::<Vec<_>>();
+
+    check_partial_jobs(problem, &locked)?;
+
+    let executed_ids = locked.iter().flat_map(|(_, jobs)| jobs.iter()).cloned().collect::<HashSet<_>>();
+
+    let mut problem = problem.clone();
+    let relations = problem
+        .plan
+        .relations
+        .take()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|mut relation| {
+            relation.jobs.retain(|job_id| is_reserved_job_id(job_id) || !executed_ids.contains(job_id));
+            // NOTE relation without regular jobs is not needed anymore
+            if relation.jobs.iter().any(|job_id| !is_reserved_job_id(job_id)) {
+                Some(relation)
+            } else {
+                None
+            }
+        })
+        .chain(locked.into_iter().map(|(tour, jobs)| Relation {
+            type_field: RelationType::Strict,
+            jobs,
+            vehicle_id: tour.vehicle_id.clone(),
+            shift_index: Some(tour.shift_index),
+        }))
+        .collect::<Vec<_>>();
+
+    problem.plan.relations = if relations.is_empty() { None } else { Some(relations) };
+
+    Ok(problem)
+}
+
+fn get_executed_count(tour: &Tour, executed: &ExecutedStops, cutoff: Option<f64>) -> Result<usize, String> {
+    Ok(match (executed, cutoff) {
+        (ExecutedStops::Time(_), Some(cutoff)) => tour.stops.iter().try_fold(0, |count, stop| {
+            parse_time_safe(&stop.schedule().arrival).map(|arrival| if arrival <= cutoff { count + 1 } else { count })
+        })?,
+        (ExecutedStops::Count(count), _) => *count,
+        (ExecutedStops::Jobs(job_ids), _) => tour
+            .stops
+            .iter()
+            .rposition(|stop| stop.activities().iter().any(|activity| job_ids.contains(&activity.job_id)))
+            .map_or(0, |idx| idx + 1),
+        _ => unreachable!(),
+    })
+}
+
+fn check_partial_jobs(problem: &Problem, locked: &[(&Tour, Vec<String>)]) -> Result<(), String> {
+    let task_counts =
+        problem.plan.jobs.iter().map(|job| (job.id.as_str(), get_job_tasks(job).count())).collect::<HashMap<_, _>>();
+
+    let executed_counts = locked.iter().flat_map(|(_, jobs)| jobs.iter()).fold(HashMap::new(), |mut acc, job_id| {
+        *acc.entry(job_id.as_str()).or_insert(0_usize) += 1;
+        acc
+    });
+
+    let mut partial = executed_counts
+        .into_iter()
+        .filter(|(job_id, count)| task_counts.get(job_id).map_or(false, |total| total != count))
+        .map(|(job_id, _)| job_id.to_string())
+        .collect::<Vec<_>>();
+
+    if partial.is_empty() {
+        Ok(())
+    } else {
+        partial.sort();
+        Err(format!("cannot lock partially executed jobs: {}", partial.join(", ")))
+    }
+}
@@ -2,16 +2,20 @@
 #[path = "../../../tests/unit/format/problem/fleet_reader_test.rs"]
 mod fleet_reader_test;
 
+use crate::constraints::{DepartureLoading, DriverHours, Overtime};
 use crate::extensions::create_typed_actor_groups;
 use crate::format::coord_index::CoordIndex;
+use crate::format::dimens::*;
 use crate::format::problem::reader::{ApiProblem, ProblemProperties};
-use crate::format::problem::Matrix;
-use crate::parse_time;
+use crate::format::problem::{Matrix, VehicleType};
+use crate::{parse_time, parse_time_of_day};
 use hashbrown::{HashMap, HashSet};
+use std::iter::once;
 use std::sync::Arc;
-use vrp_core::construction::constraints::TravelLimitFunc;
+use vrp_core::construction::constraints::{TravelLimitFunc, VEHICLE_DEPARTURE_WINDOW_KEY, VEHICLE_END_LOCATIONS_KEY};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
+use vrp_core::utils::VrpError;
 
 pub(crate) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String, usize> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
@@ -25,23 +29,24 @@ pub(crate) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String,
 pub(crate) fn create_transport_costs(
     api_problem: &ApiProblem,
     matrices: &[Matrix],
-) -> Result<Arc<dyn TransportCost + Sync + Send>, String> {
+    matrix_storage: MatrixStorage,
+) -> Result<Arc<dyn TransportCost + Sync + Send>, VrpError> {
     if !matrices.iter().all(|m| m.profile.is_some()) && !matrices.iter().all(|m| m.profile.is_none()) {
-        return Err("all matrices should have profile set or none of them".to_string());
+        return Err(VrpError::Matrix("all matrices should have profile set or none of them".to_string()));
     }
 
     if matrices.iter().any(|m| m.profile.is_none()) && matrices.iter().any(|m| m.timestamp.is_some()) {
-        return Err("when timestamp is set, all matrices should have profile set".to_string());
+        return Err(VrpError::Matrix("when timestamp is set, all matrices should have profile set".to_string()));
     }
 
     let matrix_profiles = get_profile_index_map(api_problem);
     if matrix_profiles.len() > matrices.len() {
-        return Err(format!(
+        return Err(VrpError::Matrix(format!(
             "not enough routing matrices specified for fleet profiles defined: \
              {} must be less or equal to {}",
             matrix_profiles.len(),
             matrices.len()
-        ));
+        )));
     }
 
     let matrix_data = matrices
@@ -80,10 +85,71 @@ pub(crate) fn create_transport_costs(
 
     let matrix_indices = matrix_data.iter().map(|data| data.index).collect::<HashSet<_>>().len();
     if matrix_profiles.len() != matrix_indices {
-        return Err("amount of fleet profiles does not match matrix profiles".to_string());
+        return Err(VrpError::Matrix("amount of fleet profiles does not match matrix profiles".to_string()));
     }
 
-    create_matrix_transport_cost(matrix_data)
+    create_matrix_transport_cost_with_storage(matrix_data, matrix_storage)
+}
+
+pub(crate) fn read_toll_index(api_problem: &ApiProblem, matrices: &[Matrix]) -> TollIndex {
+    let matrix_profiles = get_profile_index_map(api_problem);
+
+    // NOTE tolls are time independent, so the first toll matrix of the profile is used
+    matrices.iter().enumerate().fold(TollIndex::default(), |mut acc, (idx, matrix)| {
+        if let Some(tolls) = &matrix.tolls {
+            let profile = matrix.profile.as_ref().and_then(|p| matrix_profiles.get(p)).cloned().unwrap_or(idx);
+            acc.entry(profile).or_insert_with(|| tolls.clone());
+        }
+
+        acc
+    })
+}
+
+pub(crate) fn read_time_of_day_index(api_problem: &ApiProblem) -> Result<TimeOfDayIndex, String> {
+    let profile_indices = get_profile_index_map(api_problem);
+
+    api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.time_of_day.as_ref().map(|factors| (profile, factors)))
+        .map(|(profile, factors)| {
+            let multipliers = factors
+                .iter()
+                .map(|factor| {
+                    Ok(TimeOfDayMultiplier {
+                        start: parse_time_of_day(&factor.start)?,
+                        end: parse_time_of_day(&factor.end)?,
+                        factor: factor.factor,
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+
+            Ok((*profile_indices.get(&profile.name).unwrap(), multipliers))
+        })
+        .collect()
+}
+
+pub(crate) fn read_leg_override_index(api_problem: &ApiProblem, coord_index: &CoordIndex) -> LegOverrideIndex {
+    let profile_indices = get_profile_index_map(api_problem);
+
+    api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.leg_overrides.as_ref().map(|legs| (profile, legs)))
+        .flat_map(|(profile, legs)| {
+            let profile_index = *profile_indices.get(&profile.name).unwrap();
+
+            // NOTE skip legs with locations which are not used in the problem
+            legs.iter().filter_map(move |leg| {
+                let from = coord_index.get_by_loc(&leg.from)?;
+                let to = coord_index.get_by_loc(&leg.to)?;
+
+                Some(((profile_index, from, to), (leg.duration, leg.distance)))
+            })
+        })
+        .collect()
 }
 
 pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> Fleet {
@@ -94,6 +160,7 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         .iter()
         .flat_map(|areas| areas.iter().map(|area| (&area.id, area)))
         .collect::<HashMap<_, _>>();
+    let type_groups = get_vehicle_type_groups(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
@@ -101,7 +168,8 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
             fixed: vehicle.costs.fixed.unwrap_or(0.),
             per_distance: vehicle.costs.distance,
             per_driving_time: vehicle.costs.time,
-            per_waiting_time: vehicle.costs.time,
+            per_waiting_time: vehicle.costs.waiting.unwrap_or(vehicle.costs.time),
+            per_depot_waiting_time: vehicle.costs.depot_waiting.unwrap_or(vehicle.costs.time),
             per_service_time: vehicle.costs.time,
         };
 
@@ -109,6 +177,20 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         let profile = Profile::new(index, vehicle.profile.scale);
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let max_cost = vehicle.limits.as_ref().and_then(|l| l.max_cost);
+        let max_stops = vehicle.limits.as_ref().and_then(|l| l.max_stops);
+        let driver_hours = vehicle.limits.as_ref().and_then(|l| l.driver_hours.as_ref()).map(|hours| DriverHours {
+            max_continuous_driving: hours.get_max_continuous_driving(),
+            rest_duration: hours.get_break_duration(),
+            max_daily_driving: hours.get_max_daily_driving(),
+            daily_rest: hours.get_daily_rest(),
+        });
+        let overtime = vehicle
+            .limits
+            .as_ref()
+            .and_then(|l| l.shift_time.zip(l.overtime.as_ref()))
+            .map(|(shift_time, overtime)| Overtime { shift_time, cost: overtime.cost });
+        let carry_load = vehicle.carry_load.unwrap_or(false);
         let mut area_jobs = vehicle.limits.as_ref().and_then(|l| l.areas.as_ref()).map({
             let area_index = &area_index;
             move |areas| {
@@ -145,6 +227,18 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                 (location, time)
             });
 
+            let loading = shift.loading.as_ref().map(|loading| DepartureLoading {
+                duration: loading.duration,
+                per_unit_duration: loading.per_unit_duration.unwrap_or(0.),
+            });
+
+            let end_locations = shift.end.as_ref().and_then(|end| end.alternatives.as_ref()).map(|alternatives| {
+                once(&shift.end.as_ref().unwrap().location)
+                    .chain(alternatives.iter())
+                    .map(|location| coord_index.get_by_loc(location).unwrap())
+                    .collect::<Vec<_>>()
+            });
+
             let details = vec![VehicleDetail {
                 start: Some(VehiclePlace {
                     location: start.0,
@@ -158,22 +252,65 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
 
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
                 let mut dimens: Dimensions = Default::default();
-                dimens.set_value("type_id", vehicle.type_id.clone());
-                dimens.set_value("shift_index", shift_index);
+                dimens.set_dimen(&VEHICLE_TYPE_ID_KEY, vehicle.type_id.clone());
+                if let Some(type_group_id) = type_groups.get(&vehicle.type_id) {
+                    dimens.set_dimen(&VEHICLE_TYPE_GROUP_ID_KEY, type_group_id.clone());
+                }
+                dimens.set_dimen(&SHIFT_INDEX_KEY, shift_index);
                 dimens.set_id(vehicle_id);
 
                 if let Some(area_jobs) = area_jobs.take() {
-                    dimens.set_value("areas", area_jobs);
+                    dimens.set_dimen(&VEHICLE_AREAS_KEY, area_jobs);
                 }
 
                 if let Some(tour_size) = tour_size {
-                    dimens.set_value("tour_size", tour_size);
+                    dimens.set_dimen(&VEHICLE_TOUR_SIZE_KEY, tour_size);
+                }
+
+                if let Some(max_cost) = max_cost {
+                    dimens.set_dimen(&VEHICLE_MAX_COST_KEY, max_cost);
+                }
+
+                if let Some(max_stops) = max_stops {
+                    dimens.set_dimen(&VEHICLE_MAX_STOPS_KEY, max_stops);
+                }
+
+                if let Some(driver_hours) = driver_hours.clone() {
+                    dimens.set_dimen(&VEHICLE_DRIVER_HOURS_KEY, driver_hours);
+                }
+
+                if let Some(end_locations) = end_locations.clone() {
+                    dimens.set_dimen(&VEHICLE_END_LOCATIONS_KEY, end_locations);
+                }
+
+                if let Some(overtime) = overtime.clone() {
+                    dimens.set_dimen(&VEHICLE_OVERTIME_KEY, overtime);
+                }
+
+                if carry_load {
+                    dimens.set_dimen(&VEHICLE_CARRY_LOAD_KEY, carry_load);
+                }
+
+                if shift.start.optimize_departure.unwrap_or(false) {
+                    dimens.set_dimen(&VEHICLE_DEPARTURE_WINDOW_KEY, true);
+                }
+
+                if let Some(loading) = loading.clone() {
+                    dimens.set_dimen(&VEHICLE_LOADING_KEY, loading);
+                }
+
+                if let Some(echelon) = vehicle.echelon {
+                    dimens.set_dimen(&VEHICLE_ECHELON_KEY, echelon);
+                }
+
+                if let Some(service_time_factor) = vehicle.service_time_factor {
+                    dimens.set_dimen(&VEHICLE_SERVICE_TIME_FACTOR_KEY, service_time_factor);
                 }
 
                 if props.has_multi_dimen_capacity {
                     dimens.set_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
                 } else {
-                    dimens.set_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
+                    dimens.set_capacity(SingleDimLoad::new(vehicle.capacity.first().copied().unwrap_or_default()));
                 }
                 add_vehicle_skills(&mut dimens, &vehicle.skills);
 
@@ -193,6 +330,7 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
             per_distance: 0.0,
             per_driving_time: 0.0,
             per_waiting_time: 0.0,
+            per_depot_waiting_time: 0.0,
             per_service_time: 0.0,
         },
         dimens: Default::default(),
@@ -207,7 +345,11 @@ pub fn read_travel_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
         HashMap::new(),
         |mut acc, vehicle| {
             let limits = vehicle.limits.as_ref().unwrap().clone();
-            acc.insert(vehicle.type_id.clone(), (limits.max_distance, limits.shift_time));
+            // NOTE overtime extends shift time limit, its cost is applied by overtime module
+            let shift_time = limits
+                .shift_time
+                .map(|shift_time| shift_time + limits.overtime.as_ref().map_or(0., |overtime| overtime.max_duration));
+            acc.insert(vehicle.type_id.clone(), (limits.max_distance, shift_time));
             acc
         },
     );
@@ -216,7 +358,7 @@ pub fn read_travel_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
         None
     } else {
         Some(Arc::new(move |actor: &Actor| {
-            if let Some(limits) = limits.get(actor.vehicle.dimens.get_value::<String>("type_id").unwrap()) {
+            if let Some(limits) = limits.get(actor.vehicle.dimens.get_dimen(&VEHICLE_TYPE_ID_KEY).unwrap()) {
                 (limits.0, limits.1)
             } else {
                 (None, None)
@@ -225,8 +367,34 @@ pub fn read_travel_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
     }
 }
 
+/// Maps ids of vehicle types which have identical properties to the id of the first of them, so
+/// they are treated as one vehicle type with multiple vehicles. Vehicle types without duplicates
+/// are not included.
+fn get_vehicle_type_groups(api_problem: &ApiProblem) -> HashMap<String, String> {
+    let mut signatures: HashMap<String, Vec<&String>> = HashMap::default();
+    api_problem.fleet.vehicles.iter().for_each(|vehicle| {
+        let signature = serde_json::to_string(&VehicleType {
+            type_id: String::default(),
+            vehicle_ids: Vec::default(),
+            ..vehicle.clone()
+        })
+        .expect("cannot serialize vehicle type");
+
+        signatures.entry(signature).or_insert_with(Vec::new).push(&vehicle.type_id);
+    });
+
+    signatures
+        .into_iter()
+        .filter(|(_, type_ids)| type_ids.len() > 1)
+        .flat_map(|(_, type_ids)| {
+            let group_id = type_ids[0].clone();
+            type_ids.into_iter().map(move |type_id| (type_id.clone(), group_id.clone()))
+        })
+        .collect()
+}
+
 fn add_vehicle_skills(dimens: &mut Dimensions, skills: &Option<Vec<String>>) {
     if let Some(skills) = skills {
-        dimens.set_value("skills", skills.iter().cloned().collect::<HashSet<_>>());
+        dimens.set_dimen(&VEHICLE_SKILLS_KEY, skills.iter().cloned().collect::<HashSet<_>>());
     }
 }
@@ -1,4 +1,5 @@
 use crate::format::coord_index::CoordIndex;
+use crate::format::dimens::*;
 use crate::format::problem::reader::{parse_time_window, ApiProblem, ProblemProperties};
 use crate::format::problem::*;
 use crate::format::{JobIndex, Location};
@@ -6,10 +7,12 @@ use crate::utils::VariableJobPermutation;
 use std::sync::Arc;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Actor, Fleet, Job, Jobs, Multi, Place, Single, TransportCost};
-use vrp_core::models::{Lock, LockDetail, LockOrder, LockPosition};
+use vrp_core::models::solution::{Commute, CommuteInfo};
+use vrp_core::models::{AssignmentHint as CoreAssignmentHint, Lock, LockDetail, LockOrder, LockPosition};
 use vrp_core::prelude::*;
+use vrp_core::solver::processing::WalkInDimension;
 
-use crate::constraints::{BreakPolicy, JobSkills as ConstraintJobSkills};
+use crate::constraints::{BreakPolicy, JobSkills as ConstraintJobSkills, ReloadResource, SyncVisit};
 use crate::format::problem::JobSkills as FormatJobSkills;
 use crate::parse_time;
 use hashbrown::HashMap;
@@ -31,7 +34,7 @@ pub(crate) fn read_jobs_with_extra_locks(
     random: &Arc<dyn Random + Send + Sync>,
 ) -> (Jobs, Vec<Arc<Lock>>) {
     let (mut jobs, mut locks) = read_required_jobs(api_problem, props, coord_index, job_index, random);
-    let (conditional_jobs, conditional_locks) = read_conditional_jobs(api_problem, coord_index, job_index);
+    let (conditional_jobs, conditional_locks) = read_conditional_jobs(api_problem, props, coord_index, job_index);
 
     jobs.extend(conditional_jobs);
     locks.extend(conditional_locks);
@@ -40,8 +43,19 @@ pub(crate) fn read_jobs_with_extra_locks(
 }
 
 pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
+    let readonly_locks = api_problem.plan.readonly_routes.iter().flatten().map(|route| {
+        let shift_index = route.shift_index.unwrap_or(0);
+        let condition = create_condition(route.vehicle_id.clone(), shift_index);
+        // NOTE departure and arrival are part of any route, fixed position below keeps them in place
+        let job_ids = route.jobs.iter().filter(|job| job.as_str() != "departure" && job.as_str() != "arrival");
+        let jobs = get_lock_jobs(route.vehicle_id.as_str(), shift_index, job_ids, job_index);
+
+        // NOTE fixed position prevents insertion of any other job into the route
+        Arc::new(Lock::new(condition, vec![LockDetail::new(LockOrder::Strict, LockPosition::Fixed, jobs)], false))
+    });
+
     if api_problem.plan.relations.as_ref().map_or(true, |r| r.is_empty()) {
-        return vec![];
+        return readonly_locks.collect();
     }
 
     let relations = api_problem.plan.relations.as_ref().unwrap().iter().fold(HashMap::new(), |mut acc, r| {
@@ -51,7 +65,7 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
         acc
     });
 
-    relations.into_iter().fold(vec![], |mut acc, ((vehicle_id, shift_index), rels)| {
+    let relation_locks = relations.into_iter().fold(vec![], |mut acc, ((vehicle_id, shift_index), rels)| {
         let condition = create_condition(vehicle_id.clone(), shift_index);
         let details = rels.iter().fold(vec![], |mut acc, rel| {
             let order = match rel.type_field {
@@ -67,29 +81,8 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
                 _ => LockPosition::Any,
             };
 
-            let (_, jobs) = rel
-                .jobs
-                .iter()
-                .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
-                .fold((HashMap::<String, _>::default(), vec![]), |(mut indexer, mut jobs), job| {
-                    let job_id = match job.as_str() {
-                        "break" | "dispatch" | "reload" => {
-                            let entry = indexer.entry(job.clone()).or_insert(1_usize);
-                            let job_index = *entry;
-                            *entry += 1;
-                            format!("{}_{}_{}_{}", vehicle_id, job, shift_index, job_index)
-                        }
-                        _ => job.clone(),
-                    };
-                    let job = job_index
-                        .get(&job_id)
-                        .cloned()
-                        .unwrap_or_else(|| panic!("cannot find job with id: '{};", job_id));
-
-                    jobs.push(job);
-
-                    (indexer, jobs)
-                });
+            let job_ids = rel.jobs.iter().filter(|job| job.as_str() != "departure" && job.as_str() != "arrival");
+            let jobs = get_lock_jobs(vehicle_id.as_str(), shift_index, job_ids, job_index);
 
             acc.push(LockDetail::new(order, position, jobs));
 
@@ -99,7 +92,52 @@ pub fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Loc
         acc.push(Arc::new(Lock::new(condition, details, false)));
 
         acc
-    })
+    });
+
+    relation_locks.into_iter().chain(readonly_locks).collect()
+}
+
+/// Gets jobs from their ids resolving reserved ones (break, dispatch, reload) using vehicle id and shift index.
+fn get_lock_jobs<'a>(
+    vehicle_id: &str,
+    shift_index: usize,
+    job_ids: impl Iterator<Item = &'a String>,
+    job_index: &JobIndex,
+) -> Vec<Job> {
+    let (_, jobs) = job_ids.fold((HashMap::<String, _>::default(), vec![]), |(mut indexer, mut jobs), job| {
+        let job_id = match job.as_str() {
+            "break" | "dispatch" | "reload" => {
+                let entry = indexer.entry(job.clone()).or_insert(1_usize);
+                let job_index = *entry;
+                *entry += 1;
+                format!("{}_{}_{}_{}", vehicle_id, job, shift_index, job_index)
+            }
+            _ => job.clone(),
+        };
+        let job = job_index.get(&job_id).cloned().unwrap_or_else(|| panic!("cannot find job with id: '{};", job_id));
+
+        jobs.push(job);
+
+        (indexer, jobs)
+    });
+
+    jobs
+}
+
+/// Reads assignment hints which are used to seed initial solutions.
+pub fn read_assignment_hints(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<CoreAssignmentHint> {
+    api_problem
+        .plan
+        .hints
+        .iter()
+        .flat_map(|hints| hints.iter())
+        .map(|hint| {
+            let condition = create_condition(hint.vehicle_id.clone(), hint.shift_index.unwrap_or(0));
+            let jobs = hint.jobs.iter().filter_map(|job_id| job_index.get(job_id).cloned()).collect();
+
+            CoreAssignmentHint::new(condition, jobs)
+        })
+        .collect()
 }
 
 fn read_required_jobs(
@@ -117,21 +155,31 @@ fn read_required_jobs(
         let capacity = task.demand.clone().map_or_else(empty, MultiDimLoad::new);
         let demand = if is_static_demand { (capacity, empty()) } else { (empty(), capacity) };
 
+        // NOTE service has no demand, so it is skipped by capacity constraint entirely
         let demand = match activity_type {
-            "pickup" => Demand { pickup: demand, delivery: absent },
-            "delivery" => Demand { pickup: absent, delivery: demand },
-            "replacement" => Demand { pickup: demand, delivery: demand },
-            "service" => Demand { pickup: absent, delivery: absent },
+            "pickup" => Some(Demand { pickup: demand, delivery: absent }),
+            "delivery" => Some(Demand { pickup: absent, delivery: demand }),
+            "replacement" => Some(Demand { pickup: demand, delivery: demand }),
+            "service" => None,
             _ => panic!("Invalid activity type."),
         };
 
+        let not_before = task.not_before.as_ref().map(|time| parse_time(time));
         let places = task
             .places
             .iter()
-            .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
+            .map(|p| {
+                let times = apply_not_before(parse_times(&p.times), not_before);
+                (Some(p.location.clone()), p.duration, times, p.tag.clone())
+            })
             .collect();
 
-        get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
+        let mut single =
+            get_single_with_extras(places, demand, &task.order, activity_type, has_multi_dimens, coord_index);
+        add_job_skills(&mut single.dimens, &JOB_TASK_SKILLS_KEY, &task.skills);
+        add_walk_in(&mut single.dimens, task, coord_index);
+
+        single
     };
 
     api_problem.plan.jobs.iter().for_each(|job| {
@@ -160,6 +208,38 @@ fn read_required_jobs(
 
         assert!(!singles.is_empty());
 
+        if let Some(sync) = &job.sync {
+            let (visits, problem_job) = get_sync_jobs(job, sync, singles);
+
+            job_index.insert(job.id.clone(), problem_job);
+            jobs.extend(visits);
+
+            return;
+        }
+
+        if let Some(transfer) = &job.transfer {
+            let task = JobTask {
+                places: vec![JobPlace {
+                    location: transfer.location.clone(),
+                    duration: transfer.duration,
+                    times: transfer.times.clone(),
+                    tag: transfer.tag.clone(),
+                    parking: None,
+                }],
+                demand: job.pickups.iter().flat_map(|tasks| tasks.iter()).find_map(|task| task.demand.clone()),
+                order: None,
+                skills: None,
+                not_before: None,
+            };
+            let visits = (get_single_from_task(&task, "delivery", false), get_single_from_task(&task, "pickup", false));
+            let (parts, problem_job) = get_transfer_jobs(job, transfer, singles, visits);
+
+            job_index.insert(job.id.clone(), problem_job);
+            jobs.extend(parts);
+
+            return;
+        }
+
         let problem_job = if singles.len() > 1 {
             let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
             get_multi_job(job, singles, deliveries_start_index, random)
@@ -176,6 +256,7 @@ fn read_required_jobs(
 
 fn read_conditional_jobs(
     api_problem: &ApiProblem,
+    props: &ProblemProperties,
     coord_index: &CoordIndex,
     job_index: &mut JobIndex,
 ) -> (Vec<Job>, Vec<Arc<Lock>>) {
@@ -192,7 +273,7 @@ fn read_conditional_jobs(
             }
 
             if let Some(reloads) = &shift.reloads {
-                read_reloads(coord_index, job_index, &mut jobs, vehicle, shift_index, reloads);
+                read_reloads(props, coord_index, job_index, &mut jobs, vehicle, shift_index, reloads);
             }
         }
     });
@@ -210,10 +291,12 @@ fn read_optional_breaks(
 ) {
     (1..)
         .zip(breaks.iter().filter_map(|vehicle_break| match vehicle_break {
-            VehicleBreak::Optional { time, places, policy } => Some((time, places, policy)),
+            VehicleBreak::Optional { time, places, policy, min_shift_duration, min_spacing } => {
+                Some((time, places, policy, min_shift_duration, min_spacing))
+            }
             VehicleBreak::Required { .. } => None,
         }))
-        .flat_map(|(break_idx, (break_time, break_places, policy))| {
+        .flat_map(|(break_idx, (break_time, break_places, policy, min_shift_duration, min_spacing))| {
             vehicle
                 .vehicle_ids
                 .iter()
@@ -246,7 +329,15 @@ fn read_optional_breaks(
                             VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd => BreakPolicy::SkipIfArrivalBeforeEnd,
                         };
 
-                        job.dimens.set_value("policy", policy);
+                        job.dimens.set_dimen(&BREAK_POLICY_KEY, policy);
+                    }
+
+                    if let Some(min_shift_duration) = min_shift_duration {
+                        job.dimens.set_dimen(&BREAK_MIN_SHIFT_DURATION_KEY, *min_shift_duration);
+                    }
+
+                    if let Some(min_spacing) = min_spacing {
+                        job.dimens.set_dimen(&BREAK_MIN_SPACING_KEY, *min_spacing);
                     }
 
                     (job_id, job)
@@ -300,6 +391,7 @@ fn read_dispatch(
 }
 
 fn read_reloads(
+    props: &ProblemProperties,
     coord_index: &CoordIndex,
     job_index: &mut JobIndex,
     jobs: &mut Vec<Job>,
@@ -317,7 +409,7 @@ fn read_reloads(
                     let job_id = format!("{}_reload_{}_{}", vehicle_id, shift_index, place_idx);
                     let times = parse_times(&place.times);
 
-                    let job = get_conditional_job(
+                    let mut job = get_conditional_job(
                         coord_index,
                         vehicle_id.clone(),
                         &job_id,
@@ -326,6 +418,25 @@ fn read_reloads(
                         vec![(Some(place.location.clone()), place.duration, times, place.tag.clone())],
                     );
 
+                    if let Some(capacity) = &place.capacity {
+                        if props.has_multi_dimen_capacity {
+                            job.dimens.set_capacity(MultiDimLoad::new(capacity.clone()));
+                        } else {
+                            job.dimens.set_capacity(SingleDimLoad::new(capacity.first().copied().unwrap_or_default()));
+                        }
+                    }
+
+                    if let Some(per_unit_duration) = place.per_unit_duration {
+                        job.dimens.set_dimen(&RELOAD_PER_UNIT_DURATION_KEY, per_unit_duration);
+                    }
+
+                    if let Some(resource) = &place.resource {
+                        job.dimens.set_dimen(
+                            &RELOAD_RESOURCE_KEY,
+                            ReloadResource { id: resource.id.clone(), capacity: resource.capacity },
+                        );
+                    }
+
                     (job_id, job)
                 })
                 .collect::<Vec<_>>()
@@ -343,9 +454,9 @@ fn get_conditional_job(
 ) -> Single {
     let mut single = get_single(places, coord_index);
     single.dimens.set_id(job_id);
-    single.dimens.set_value("type", job_type.to_string());
-    single.dimens.set_value("shift_index", shift_index);
-    single.dimens.set_value("vehicle_id", vehicle_id);
+    single.dimens.set_dimen(&JOB_TYPE_KEY, job_type.to_string());
+    single.dimens.set_dimen(&SHIFT_INDEX_KEY, shift_index);
+    single.dimens.set_dimen(&JOB_VEHICLE_ID_KEY, vehicle_id);
 
     single
 }
@@ -382,7 +493,7 @@ fn get_single(places: Vec<PlaceData>, coord_index: &CoordIndex) -> Single {
 
 fn get_single_with_extras(
     places: Vec<PlaceData>,
-    demand: Demand<MultiDimLoad>,
+    demand: Option<Demand<MultiDimLoad>>,
     order: &Option<i32>,
     activity_type: &str,
     has_multi_dimens: bool,
@@ -391,15 +502,22 @@ fn get_single_with_extras(
     let mut single = get_single(places, coord_index);
     let dimens = &mut single.dimens;
 
-    if has_multi_dimens {
-        dimens.set_demand(demand);
-    } else {
-        dimens.set_demand(Demand {
-            pickup: (SingleDimLoad::new(demand.pickup.0.load[0]), SingleDimLoad::new(demand.pickup.1.load[0])),
-            delivery: (SingleDimLoad::new(demand.delivery.0.load[0]), SingleDimLoad::new(demand.delivery.1.load[0])),
-        });
+    match demand {
+        Some(demand) if has_multi_dimens => {
+            dimens.set_demand(demand);
+        }
+        Some(demand) => {
+            dimens.set_demand(Demand {
+                pickup: (SingleDimLoad::new(demand.pickup.0.load[0]), SingleDimLoad::new(demand.pickup.1.load[0])),
+                delivery: (
+                    SingleDimLoad::new(demand.delivery.0.load[0]),
+                    SingleDimLoad::new(demand.delivery.1.load[0]),
+                ),
+            });
+        }
+        None => {}
     }
-    dimens.set_value("type", activity_type.to_string());
+    dimens.set_dimen(&JOB_TYPE_KEY, activity_type.to_string());
     add_order(dimens, order);
 
     single
@@ -412,7 +530,9 @@ fn get_single_job(job: &ApiJob, single: Single) -> Job {
     add_value(&mut single.dimens, &job.value);
     add_group(&mut single.dimens, &job.group);
     add_compatibility(&mut single.dimens, &job.compatibility);
-    add_job_skills(&mut single.dimens, &job.skills);
+    add_job_skills(&mut single.dimens, &JOB_SKILLS_KEY, &job.skills);
+    add_buffer(&mut single.dimens, &job.buffer);
+    add_priority(&mut single.dimens, &job.is_prioritized);
 
     Job::Single(Arc::new(single))
 }
@@ -428,9 +548,16 @@ fn get_multi_job(
     add_value(&mut dimens, &job.value);
     add_group(&mut dimens, &job.group);
     add_compatibility(&mut dimens, &job.compatibility);
-    add_job_skills(&mut dimens, &job.skills);
+    add_job_skills(&mut dimens, &JOB_SKILLS_KEY, &job.skills);
 
-    let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
+    let singles = singles
+        .into_iter()
+        .map(|mut single| {
+            add_buffer(&mut single.dimens, &job.buffer);
+            add_priority(&mut single.dimens, &job.is_prioritized);
+            Arc::new(single)
+        })
+        .collect::<Vec<_>>();
 
     let multi = if singles.len() == 2 && deliveries_start_index == 1 {
         Multi::new(singles, dimens)
@@ -451,47 +578,164 @@ fn get_multi_job(
     Job::Multi(Multi::bind(multi))
 }
 
+/// Returns visits of synchronized job as separate jobs and a multi job which is not a part of the
+/// problem, but keeps all visits under original job id.
+fn get_sync_jobs(job: &ApiJob, sync: &JobSync, singles: Vec<Single>) -> (Vec<Job>, Job) {
+    let total = singles.len();
+    let value = job.value.map(|value| value / total as f64);
+
+    let visits = singles
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut single)| {
+            single.dimens.set_id(&job.id);
+            single.dimens.set_dimen(
+                &JOB_SYNC_VISIT_KEY,
+                SyncVisit { job_id: job.id.clone(), index, total, max_gap: sync.max_gap },
+            );
+
+            add_value(&mut single.dimens, &value);
+            add_compatibility(&mut single.dimens, &job.compatibility);
+            add_job_skills(&mut single.dimens, &JOB_SKILLS_KEY, &job.skills);
+            add_buffer(&mut single.dimens, &job.buffer);
+            add_priority(&mut single.dimens, &job.is_prioritized);
+
+            Arc::new(single)
+        })
+        .collect::<Vec<_>>();
+
+    let mut dimens: Dimensions = Default::default();
+    dimens.set_id(&job.id);
+    let problem_job = Job::Multi(Arc::new(Multi::new(visits.clone(), dimens)));
+
+    (visits.into_iter().map(Job::Single).collect(), problem_job)
+}
+
+/// Returns two parts of the transfer job as separate jobs: the first one brings goods to the
+/// transfer place, the second one takes them from there. A multi job which is not a part of
+/// the problem keeps all activities under original job id.
+fn get_transfer_jobs(
+    job: &ApiJob,
+    transfer: &JobTransfer,
+    singles: Vec<Single>,
+    visits: (Single, Single),
+) -> (Vec<Job>, Job) {
+    let value = job.value.map(|value| value / 2.);
+    let (drop, pickup) = visits;
+
+    let mut singles = singles.into_iter();
+    let (source, target) = (singles.next().expect("no pickup"), singles.next().expect("no delivery"));
+
+    let singles = vec![source, drop, pickup, target]
+        .into_iter()
+        .enumerate()
+        .map(|(idx, mut single)| {
+            if idx == 1 || idx == 2 {
+                single.dimens.set_dimen(
+                    &JOB_TRANSFER_VISIT_KEY,
+                    SyncVisit { job_id: job.id.clone(), index: idx - 1, total: 2, max_gap: transfer.max_gap },
+                );
+            }
+
+            add_buffer(&mut single.dimens, &job.buffer);
+            add_priority(&mut single.dimens, &job.is_prioritized);
+
+            Arc::new(single)
+        })
+        .collect::<Vec<_>>();
+
+    let parts = singles
+        .chunks(2)
+        .map(|singles| {
+            let mut dimens: Dimensions = Default::default();
+            dimens.set_id(&job.id);
+            add_value(&mut dimens, &value);
+            add_compatibility(&mut dimens, &job.compatibility);
+            add_job_skills(&mut dimens, &JOB_SKILLS_KEY, &job.skills);
+
+            Job::Multi(Multi::bind(Multi::new(singles.to_vec(), dimens)))
+        })
+        .collect::<Vec<_>>();
+
+    let mut dimens: Dimensions = Default::default();
+    dimens.set_id(&job.id);
+    let problem_job = Job::Multi(Arc::new(Multi::new(singles, dimens)));
+
+    (parts, problem_job)
+}
+
 fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor) -> bool + Sync + Send> {
     Arc::new(move |actor: &Actor| {
         *actor.vehicle.dimens.get_id().unwrap() == vehicle_id
-            && *actor.vehicle.dimens.get_value::<usize>("shift_index").unwrap() == shift_index
+            && *actor.vehicle.dimens.get_dimen(&SHIFT_INDEX_KEY).unwrap() == shift_index
     })
 }
 
 fn add_tags(dimens: &mut Dimensions, tags: Vec<(usize, String)>) {
     if !tags.is_empty() {
-        dimens.set_value("tags", tags);
+        dimens.set_dimen(&JOB_TAGS_KEY, tags);
+    }
+}
+
+fn add_walk_in(dimens: &mut Dimensions, task: &JobTask, coord_index: &CoordIndex) {
+    let commutes = task
+        .places
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, place)| place.parking.as_ref().map(|parking| (idx, parking)))
+        .map(|(idx, parking)| {
+            let location = coord_index.get_by_loc(&parking.location).expect("cannot find parking location");
+            let info = CommuteInfo { location, distance: parking.distance, duration: parking.duration };
+
+            (idx, Commute { forward: info.clone(), backward: info })
+        })
+        .collect::<Vec<_>>();
+
+    if !commutes.is_empty() {
+        dimens.set_walk_in(commutes);
     }
 }
 
 fn add_order(dimens: &mut Dimensions, order: &Option<i32>) {
     if let Some(order) = order {
-        dimens.set_value("order", *order);
+        dimens.set_dimen(&JOB_ORDER_KEY, *order);
     }
 }
 
 fn add_value(dimens: &mut Dimensions, value: &Option<f64>) {
     if let Some(value) = *value {
-        dimens.set_value("value", value);
+        dimens.set_dimen(&JOB_VALUE_KEY, value);
     }
 }
 
 fn add_group(dimens: &mut Dimensions, group: &Option<String>) {
     if let Some(group) = group {
-        dimens.set_value("group", group.clone());
+        dimens.set_dimen(&JOB_GROUP_KEY, group.clone());
     }
 }
 
 fn add_compatibility(dimens: &mut Dimensions, compatibility: &Option<String>) {
     if let Some(compatibility) = compatibility {
-        dimens.set_value("compat", compatibility.clone());
+        dimens.set_dimen(&JOB_COMPATIBILITY_KEY, compatibility.clone());
     }
 }
 
-fn add_job_skills(dimens: &mut Dimensions, skills: &Option<FormatJobSkills>) {
+fn add_buffer(dimens: &mut Dimensions, buffer: &Option<f64>) {
+    if let Some(buffer) = buffer {
+        dimens.set_dimen(&JOB_BUFFER_KEY, *buffer);
+    }
+}
+
+fn add_priority(dimens: &mut Dimensions, is_prioritized: &Option<bool>) {
+    if let Some(true) = is_prioritized {
+        dimens.set_dimen(&JOB_PRIORITIZED_KEY, true);
+    }
+}
+
+fn add_job_skills(dimens: &mut Dimensions, key: &DimenKey<ConstraintJobSkills>, skills: &Option<FormatJobSkills>) {
     if let Some(skills) = skills {
-        dimens.set_value(
-            "skills",
+        dimens.set_dimen(
+            key,
             ConstraintJobSkills {
                 all_of: skills.all_of.as_ref().map(|all_of| all_of.iter().cloned().collect()),
                 one_of: skills.one_of.as_ref().map(|any_of| any_of.iter().cloned().collect()),
@@ -510,3 +754,19 @@ fn parse_times(times: &Option<Vec<Vec<String>>>) -> Vec<TimeSpan> {
         tws.iter().map(|tw| TimeSpan::Window(parse_time_window(tw))).collect()
     })
 }
+
+/// Restricts time windows to start not earlier than given time: windows which end before it are removed.
+/// NOTE: `notBefore` has no dedicated constraint, so time window constraint and checker rules apply.
+fn apply_not_before(times: Vec<TimeSpan>, not_before: Option<Timestamp>) -> Vec<TimeSpan> {
+    match not_before {
+        Some(not_before) => times
+            .into_iter()
+            .filter_map(|span| match span {
+                TimeSpan::Window(tw) if tw.end < not_before => None,
+                TimeSpan::Window(tw) => Some(TimeSpan::Window(TimeWindow::new(tw.start.max(not_before), tw.end))),
+                span => Some(span),
+            })
+            .collect(),
+        None => times,
+    }
+}
@@ -0,0 +1,176 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/matrix_consistency_test.rs"]
+mod matrix_consistency_test;
+
+use crate::format::problem::Matrix;
+use serde::Serialize;
+use vrp_core::models::problem::get_matrix_dimension;
+
+/// Specifies a kind of routing matrix inconsistency.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MatrixIssueKind {
+    /// A value in one direction differs from the value in the opposite direction more than allowed.
+    Asymmetry,
+    /// A direct value is bigger than a value of the shortest detour via another location.
+    TriangleInequality,
+    /// A travel duration is zero while a distance is not.
+    ZeroDuration,
+}
+
+/// Specifies a routing matrix metric.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MatrixMetric {
+    /// Travel durations.
+    Duration,
+    /// Travel distances.
+    Distance,
+}
+
+/// Describes a single inconsistent cell of a routing matrix.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatrixIssue {
+    /// A profile of the matrix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// A kind of inconsistency.
+    pub kind: MatrixIssueKind,
+    /// A metric which has inconsistent value.
+    pub metric: MatrixMetric,
+    /// A location index of the cell row.
+    pub from: usize,
+    /// A location index of the cell column.
+    pub to: usize,
+    /// An intermediate location index of the shortest detour, set only for triangle inequality.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via: Option<usize>,
+    /// A value of the cell.
+    pub value: i64,
+    /// A value the cell is compared with: the opposite direction value for asymmetry, the shortest
+    /// detour value for triangle inequality, and the distance for zero duration.
+    pub reference: i64,
+}
+
+/// Specifies tolerances used to check routing matrix consistency.
+#[derive(Clone, Debug)]
+pub struct MatrixConsistencySettings {
+    /// A max allowed relative difference between values in opposite directions.
+    pub asymmetry_tolerance: f64,
+    /// A max allowed relative excess of the direct value over the shortest detour value.
+    pub triangle_tolerance: f64,
+}
+
+impl Default for MatrixConsistencySettings {
+    fn default() -> Self {
+        Self { asymmetry_tolerance: 0.5, triangle_tolerance: 0.1 }
+    }
+}
+
+/// Checks routing matrix consistency and returns per-cell diagnostics. Such data does not prevent
+/// the solver from running, but quietly degrades solution quality. Cells marked as unreachable
+/// by error codes are ignored.
+pub fn check_matrix_consistency(
+    matrix: &Matrix,
+    settings: &MatrixConsistencySettings,
+) -> Result<Vec<MatrixIssue>, String> {
+    let size = get_matrix_size(matrix)?;
+    let is_reachable = |from: usize, to: usize| {
+        matrix.error_codes.as_ref().map_or(true, |error_codes| error_codes[from * size + to] == 0)
+    };
+
+    let create_issue = |kind, metric, from, to, via, value, reference| MatrixIssue {
+        profile: matrix.profile.clone(),
+        kind,
+        metric,
+        from,
+        to,
+        via,
+        value,
+        reference,
+    };
+
+    let mut issues = vec![];
+
+    for (metric, values) in
+        [(MatrixMetric::Duration, &matrix.travel_times), (MatrixMetric::Distance, &matrix.distances)]
+    {
+        let get_value = |from: usize, to: usize| values[from * size + to];
+
+        for from in 0..size {
+            for to in (from + 1)..size {
+                if !is_reachable(from, to) || !is_reachable(to, from) {
+                    continue;
+                }
+
+                let (forward, backward) = (get_value(from, to), get_value(to, from));
+                if forward.max(backward) as f64 > forward.min(backward) as f64 * (1. + settings.asymmetry_tolerance) {
+                    issues.push(create_issue(MatrixIssueKind::Asymmetry, metric, from, to, None, forward, backward));
+                }
+            }
+        }
+
+        for from in 0..size {
+            for to in (0..size).filter(|&to| to != from && is_reachable(from, to)) {
+                let detour = (0..size)
+                    .filter(|&via| via != from && via != to && is_reachable(from, via) && is_reachable(via, to))
+                    .map(|via| (via, get_value(from, via) + get_value(via, to)))
+                    .min_by_key(|(_, value)| *value);
+
+                if let Some((via, detour)) = detour {
+                    let direct = get_value(from, to);
+                    if direct as f64 > detour as f64 * (1. + settings.triangle_tolerance) {
+                        issues.push(create_issue(
+                            MatrixIssueKind::TriangleInequality,
+                            metric,
+                            from,
+                            to,
+                            Some(via),
+                            direct,
+                            detour,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    (0..size)
+        .flat_map(|from| (0..size).map(move |to| (from, to)))
+        .filter(|&(from, to)| from != to && is_reachable(from, to))
+        .filter(|&(from, to)| matrix.travel_times[from * size + to] == 0 && matrix.distances[from * size + to] > 0)
+        .for_each(|(from, to)| {
+            issues.push(create_issue(
+                MatrixIssueKind::ZeroDuration,
+                MatrixMetric::Duration,
+                from,
+                to,
+                None,
+                0,
+                matrix.distances[from * size + to],
+            ))
+        });
+
+    Ok(issues)
+}
+
+fn get_matrix_size(matrix: &Matrix) -> Result<usize, String> {
+    let length = matrix.travel_times.len();
+    let size =
+        get_matrix_dimension(length).ok_or_else(|| format!("routing matrix is not square: {} values", length))?;
+
+    if matrix.distances.len() != length {
+        return Err(format!(
+            "amount of distances does not match amount of durations: {} != {}",
+            matrix.distances.len(),
+            length
+        ));
+    }
+
+    if matrix.error_codes.as_ref().map_or(false, |error_codes| error_codes.len() != length) {
+        return Err("amount of error codes does not match amount of durations".to_string());
+    }
+
+    Ok(size)
+}
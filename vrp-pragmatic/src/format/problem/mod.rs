@@ -7,6 +7,22 @@ pub use self::model::*;
 mod reader;
 pub use self::reader::create_approx_matrices;
 pub use self::reader::PragmaticProblem;
+pub use self::reader::{CustomObjectives, PragmaticProblemBuilder};
+
+mod territory;
+pub use self::territory::*;
+
+mod executed_stops;
+pub use self::executed_stops::*;
+
+mod matrix_consistency;
+pub use self::matrix_consistency::*;
+
+mod order_batching;
+pub use self::order_batching::*;
+
+mod capacity_check;
+pub use self::capacity_check::*;
 
 pub(crate) fn get_job_tasks(job: &Job) -> impl Iterator<Item = &JobTask> {
     job.pickups.iter().chain(job.deliveries.iter()).chain(job.services.iter()).chain(job.replacements.iter()).flatten()
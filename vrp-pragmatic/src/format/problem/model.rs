@@ -4,7 +4,7 @@ mod model_test;
 
 extern crate serde_json;
 
-use crate::format::{FormatError, Location};
+use crate::format::{FormatError, Location, PlanningUnits};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
 
@@ -38,6 +38,34 @@ pub struct Relation {
     pub shift_index: Option<usize>,
 }
 
+/// Assignment hint is the way to specify preferred vehicle and order for some jobs. Unlike relation,
+/// it is used only to seed initial solutions, so jobs can be moved to other places during the search.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssignmentHint {
+    /// List of job ids in preferred order.
+    pub jobs: Vec<String>,
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+}
+
+/// Readonly route is the way to keep a pre-supplied route of specific vehicle untouched: its jobs
+/// are visited in exactly the same order and no other jobs can be inserted into it.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadonlyRoute {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+    /// List of job ids in visit order including reserved: `break`, `dispatch` and `reload`.
+    pub jobs: Vec<String>,
+}
+
 /// An area is the way to control job execution order.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct Area {
@@ -76,6 +104,20 @@ pub struct JobPlace {
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// A parking place where vehicle stops, the job place is reached from it by walking.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parking: Option<JobParking>,
+}
+
+/// Specifies a parking place of the job place.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobParking {
+    /// A parking location.
+    pub location: Location,
+    /// Walking duration from parking location to the job place, the same is used to walk back.
+    pub duration: f64,
+    /// Walking distance from parking location to the job place, the same is used to walk back.
+    pub distance: f64,
 }
 
 /// Specifies a job task.
@@ -89,6 +131,43 @@ pub struct JobTask {
     /// An order, bigger value - later assignment in the route.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
+    /// A task skills limitations: applied in addition to job skills.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<JobSkills>,
+    /// An earliest time (in RFC3339 format) when the task can be started, e.g. when goods become
+    /// available. It is applied to place time windows, so it is not a separate constraint.
+    #[serde(rename = "notBefore", skip_serializing_if = "Option::is_none")]
+    pub not_before: Option<String>,
+}
+
+/// Specifies that service tasks of the job are separate visits which can be done by different
+/// vehicles, e.g. an electrician and then an inspector.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSync {
+    /// A max time (in seconds) between the end of one visit and the start of the next one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_gap: Option<f64>,
+}
+
+/// Specifies a transfer (cross-docking) place where goods of the job are dropped by one vehicle
+/// and picked up later by the same or another one.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobTransfer {
+    /// A transfer location.
+    pub location: Location,
+    /// A handling duration (in seconds) applied to both drop and pickup at transfer location.
+    pub duration: f64,
+    /// A list of transfer place time windows with time specified in RFC3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+    /// A max time (in seconds) between the end of the drop and the start of the pickup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_gap: Option<f64>,
+    /// A tag which will be propagated back within transfer activities in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
 }
 
 /// A customer job model. Actual tasks of the job specified by list of pickups and deliveries
@@ -131,6 +210,26 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// An uncertainty buffer (in seconds): it is not counted as serving time, but a schedule slack
+    /// of this duration is preferred after each job activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buffer: Option<f64>,
+
+    /// Specifies whether job is prioritized: prioritized jobs are served as early as possible
+    /// when `minimize-latest-eta` objective is used.
+    #[serde(rename = "isPrioritized", skip_serializing_if = "Option::is_none")]
+    pub is_prioritized: Option<bool>,
+
+    /// Specifies that service tasks are synchronized visits served in the order of definition,
+    /// potentially, by different vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync: Option<JobSync>,
+
+    /// Specifies a transfer place: goods picked up by one vehicle are dropped there and delivered
+    /// later, potentially, by another vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer: Option<JobTransfer>,
 }
 
 // region Clustering
@@ -223,6 +322,7 @@ pub struct VicinityFilteringPolicy {
 
 /// A plan specifies work which has to be done.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Plan {
     /// List of jobs.
     pub jobs: Vec<Job>,
@@ -238,6 +338,14 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+
+    /// List of assignment hints used to seed initial solutions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hints: Option<Vec<AssignmentHint>>,
+
+    /// List of routes which are kept in the solution as they are.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readonly_routes: Option<Vec<ReadonlyRoute>>,
 }
 
 // endregion
@@ -246,6 +354,7 @@ pub struct Plan {
 
 /// Specifies vehicle costs.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VehicleCosts {
     /// Fixed is cost of vehicle usage per tour.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -256,6 +365,14 @@ pub struct VehicleCosts {
 
     /// Cost per time unit.
     pub time: f64,
+
+    /// Cost per waiting time unit at customer's place. If omitted, time cost is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waiting: Option<f64>,
+
+    /// Cost per waiting time unit at depot: vehicle shift start or end location. If omitted, time cost is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depot_waiting: Option<f64>,
 }
 
 /// Specifies vehicle shift start.
@@ -270,6 +387,11 @@ pub struct ShiftStart {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest: Option<String>,
 
+    /// If set to true, the best departure time between earliest and latest is searched during
+    /// insertion. Otherwise, departure time is changed only by post processing or shift time limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimize_departure: Option<bool>,
+
     /// Shift start location.
     pub location: Location,
 }
@@ -287,6 +409,11 @@ pub struct ShiftEnd {
 
     /// Shift end location.
     pub location: Location,
+
+    /// Alternative shift end locations. When specified, the cheapest of them and `location` is
+    /// chosen as the end of the tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternatives: Option<Vec<Location>>,
 }
 
 /// Specifies vehicle shift.
@@ -313,6 +440,10 @@ pub struct VehicleShift {
     /// unloaded during single tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reloads: Option<Vec<VehicleReload>>,
+
+    /// Vehicle loading at shift start which delays departure till the goods are on board.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loading: Option<VehicleLoading>,
 }
 
 /// Specifies a dispatch place where vehicle can load cargo and start the tour.
@@ -356,6 +487,39 @@ pub struct VehicleReload {
     /// A tag which will be propagated back within corresponding activity in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+
+    /// Vehicle capacity after reload. If omitted, capacity of previous trip is kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Vec<i32>>,
+
+    /// An extra reload duration per unit of load on board after reload.
+    #[serde(rename = "perUnitDuration", skip_serializing_if = "Option::is_none")]
+    pub per_unit_duration: Option<f64>,
+
+    /// A shared resource which limits amount of simultaneous reloads.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<VehicleReloadResource>,
+}
+
+/// Specifies vehicle loading at shift start location before departure.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleLoading {
+    /// A fixed loading duration.
+    pub duration: f64,
+
+    /// An extra loading duration per unit of load on board at departure.
+    #[serde(rename = "perUnitDuration", skip_serializing_if = "Option::is_none")]
+    pub per_unit_duration: Option<f64>,
+}
+
+/// Specifies a resource shared by reload places, e.g. loading docks of the same depot.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleReloadResource {
+    /// A unique resource id.
+    pub id: String,
+
+    /// Max amount of vehicles which can be reloaded at the same time.
+    pub capacity: usize,
 }
 
 /// Vehicle limits.
@@ -377,10 +541,85 @@ pub struct VehicleLimits {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
 
+    /// Max cost per shift/tour.
+    /// No cost restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_cost: Option<f64>,
+
+    /// Max amount of distinct stop locations with job activities per shift/tour.
+    /// No stops restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_stops: Option<usize>,
+
     /// Specifies a list of area ids where vehicle can serve jobs.
     /// No area restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub areas: Option<Vec<Vec<AreaLimit>>>,
+
+    /// Specifies driver hours rules, e.g. regulatory driving time limits.
+    /// No driving time restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub driver_hours: Option<VehicleDriverHours>,
+
+    /// Allows tour to exceed shift time limit at extra cost.
+    /// No overtime is allowed when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overtime: Option<VehicleOvertime>,
+}
+
+/// Specifies soft end of shift time limit: tour duration can exceed it at extra cost.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleOvertime {
+    /// Max time which tour can exceed shift time limit.
+    pub max_duration: f64,
+
+    /// A cost per time unit of overtime.
+    pub cost: f64,
+}
+
+/// Specifies driver hours rules. When property is omitted, a value from EU regulation is used.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleDriverHours {
+    /// Max continuous driving time before a break has to be taken. Default is 4.5 hours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_continuous_driving: Option<f64>,
+
+    /// A break duration which interrupts continuous driving. Default is 45 minutes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub break_duration: Option<f64>,
+
+    /// Max total driving time before a daily rest has to be taken. Default is 9 hours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_daily_driving: Option<f64>,
+
+    /// A daily rest duration which interrupts total driving, also a min time between consecutive
+    /// shifts of the vehicle. Default is 11 hours.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daily_rest: Option<f64>,
+}
+
+impl VehicleDriverHours {
+    /// Returns max continuous driving time.
+    pub fn get_max_continuous_driving(&self) -> f64 {
+        self.max_continuous_driving.unwrap_or(4.5 * 3600.)
+    }
+
+    /// Returns break duration.
+    pub fn get_break_duration(&self) -> f64 {
+        self.break_duration.unwrap_or(45. * 60.)
+    }
+
+    /// Returns max total driving time before a daily rest.
+    pub fn get_max_daily_driving(&self) -> f64 {
+        self.max_daily_driving.unwrap_or(9. * 3600.)
+    }
+
+    /// Returns daily rest duration.
+    pub fn get_daily_rest(&self) -> f64 {
+        self.daily_rest.unwrap_or(11. * 3600.)
+    }
 }
 
 /// An area limit.
@@ -449,6 +688,15 @@ pub enum VehicleBreak {
         places: Vec<VehicleOptionalBreakPlace>,
         /// Specifies vehicle break policy.
         policy: Option<VehicleOptionalBreakPolicy>,
+        /// Specifies min tour duration: break is skipped when actual tour is shorter.
+        #[serde(rename(deserialize = "minShiftDuration", serialize = "minShiftDuration"))]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        min_shift_duration: Option<f64>,
+        /// Specifies min time between the end of any other break in the tour and the start of this
+        /// break (and vice versa).
+        #[serde(rename(deserialize = "minSpacing", serialize = "minSpacing"))]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        min_spacing: Option<f64>,
     },
     /// A break which has to be assigned. It is less flexible than optional break, but has strong
     /// assignment guarantee.
@@ -457,6 +705,11 @@ pub enum VehicleBreak {
         time: VehicleRequiredBreakTime,
         /// Break duration.
         duration: f64,
+        /// Specifies whether break can interrupt an ongoing service activity. If not, the break has to
+        /// be taken between activities and service starts after the break. Default is true.
+        #[serde(rename(deserialize = "canInterrupt", serialize = "canInterrupt"))]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        can_interrupt: Option<bool>,
     },
 }
 
@@ -489,6 +742,22 @@ pub struct VehicleType {
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Specifies whether load remaining at the end of the shift is kept in the vehicle and carried
+    /// to its next shift instead of being unloaded. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carry_load: Option<bool>,
+
+    /// Specifies echelon of the vehicle in two-echelon routing: vehicles of the first echelon (1)
+    /// serve only first parts of transfer jobs which bring goods to transfer (satellite) places,
+    /// vehicles of the second echelon (2) serve all other jobs. Vehicle without echelon serves any job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub echelon: Option<usize>,
+
+    /// Specifies a factor which multiplies service durations of jobs served by the vehicle, e.g.
+    /// to model experienced or trainee drivers. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_time_factor: Option<f64>,
 }
 
 /// Specifies a vehicle profile.
@@ -505,6 +774,7 @@ pub struct VehicleProfile {
 
 /// Specifies routing matrix profile.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MatrixProfile {
     /// Profile name.
     pub name: String,
@@ -513,6 +783,47 @@ pub struct MatrixProfile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
+
+    /// Approximation detour factor which is applied to straight line distance to take into account that
+    /// actual road distance is longer. Used only when routing matrix is not specified. Default value is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detour_factor: Option<f64>,
+
+    /// Travel duration multipliers applied depending on time of day (UTC). Allows to model traffic
+    /// peaks without specifying time dependent routing matrices.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_of_day: Option<Vec<TimeOfDayFactor>>,
+
+    /// Fixed travel data for specific legs (e.g. ferry or known shortcut) which overrides routing
+    /// matrix values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leg_overrides: Option<Vec<LegOverride>>,
+}
+
+/// Specifies fixed travel data between two locations. Override is directional: specify reverse leg
+/// separately when needed.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct LegOverride {
+    /// Leg start location.
+    pub from: Location,
+    /// Leg end location.
+    pub to: Location,
+    /// Travel duration in seconds.
+    pub duration: f64,
+    /// Travel distance in meters. If omitted, routing matrix value is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+}
+
+/// Specifies a travel duration multiplier within a time of day interval.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct TimeOfDayFactor {
+    /// Interval start (inclusive) in `HH:MM` or `HH:MM:SS` format.
+    pub start: String,
+    /// Interval end (exclusive) in `HH:MM` or `HH:MM:SS` format, `24:00` is allowed.
+    pub end: String,
+    /// A factor applied to travel duration when travel starts within the interval.
+    pub factor: f64,
 }
 
 /// Specifies fleet.
@@ -544,6 +855,10 @@ pub enum Objective {
     #[serde(rename(deserialize = "minimize-duration", serialize = "minimize-duration"))]
     MinimizeDuration,
 
+    /// An objective to minimize total tolls.
+    #[serde(rename(deserialize = "minimize-tolls", serialize = "minimize-tolls"))]
+    MinimizeTolls,
+
     /// An objective to minimize total tour amount.
     #[serde(rename(deserialize = "minimize-tours", serialize = "minimize-tours"))]
     MinimizeTours,
@@ -610,6 +925,16 @@ pub enum Objective {
         options: Option<BalanceOptions>,
     },
 
+    /// An objective to keep amount of stops in each tour close to the target value.
+    #[serde(rename(deserialize = "target-stops", serialize = "target-stops"))]
+    TargetStops {
+        /// A desired amount of stops per tour.
+        target: usize,
+        /// An allowed deviation from the target which is not penalized. Default is zero.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tolerance: Option<usize>,
+    },
+
     /// An objective to control order of job activities in the tour.
     #[serde(rename(deserialize = "tour-order", serialize = "tour-order"))]
     TourOrder {
@@ -631,6 +956,21 @@ pub enum Objective {
         #[serde(rename = "isValuePreferred")]
         is_value_preferred: Option<bool>,
     },
+
+    /// An objective to maximize amount of job activities with respected uncertainty buffer.
+    #[serde(rename(deserialize = "maximize-respected-buffers", serialize = "maximize-respected-buffers"))]
+    MaximizeRespectedBuffers,
+
+    /// An objective to minimize the latest completion time of prioritized jobs.
+    #[serde(rename(deserialize = "minimize-latest-eta", serialize = "minimize-latest-eta"))]
+    MinimizeLatestEta,
+
+    /// A custom objective registered by name using `PragmaticProblemBuilder`.
+    #[serde(rename(deserialize = "custom", serialize = "custom"))]
+    Custom {
+        /// A name used to register objective.
+        name: String,
+    },
 }
 
 /// Specifies balance objective options. At the moment, it uses coefficient of variation as
@@ -659,6 +999,10 @@ pub struct Problem {
     /// Specifies objective function hierarchy.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub objectives: Option<Vec<Vec<Objective>>>,
+
+    /// Specifies units of durations and distances. Default are seconds and meters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<PlanningUnits>,
 }
 
 /// A routing matrix.
@@ -681,6 +1025,14 @@ pub struct Matrix {
     /// Error codes to mark unreachable locations.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_codes: Option<Vec<i64>>,
+
+    /// Units of travel times and distances. If specified, they have to match problem units.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<PlanningUnits>,
+
+    /// Tolls (monetary costs) which are added to the cost of the leg as is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tolls: Option<Vec<f64>>,
 }
 
 // endregion
@@ -2,30 +2,59 @@
 #[path = "../../../tests/unit/format/problem/objective_reader_test.rs"]
 mod objective_reader_test;
 
-use crate::constraints::{AreaModule, TOTAL_VALUE_KEY, TOUR_ORDER_KEY};
+use crate::constraints::{AreaModule, LATEST_ETA_KEY, SCHEDULE_BUFFER_KEY, TOTAL_VALUE_KEY, TOUR_ORDER_KEY};
 use crate::core::models::common::IdDimension;
-use crate::format::problem::reader::{ApiProblem, ProblemProperties};
+use crate::format::dimens::*;
+use crate::format::problem::reader::{ApiProblem, CustomObjectives, ProblemProperties};
 use crate::format::problem::BalanceOptions;
 use crate::format::problem::Objective::TourOrder as FormatTourOrder;
 use crate::format::problem::Objective::*;
-use crate::format::{AREA_CONSTRAINT_CODE, TOUR_ORDER_CONSTRAINT_CODE};
-use hashbrown::HashMap;
+use crate::format::{
+    AREA_CONSTRAINT_CODE, LATEST_ETA_CONSTRAINT_CODE, SCHEDULE_BUFFER_CONSTRAINT_CODE, TOUR_ORDER_CONSTRAINT_CODE,
+};
 use std::sync::Arc;
 use vrp_core::construction::clustering::vicinity::ClusterDimension;
 use vrp_core::construction::constraints::{ConstraintPipeline, FleetUsageConstraintModule};
 use vrp_core::models::common::ValueDimension;
 use vrp_core::models::common::{MultiDimLoad, SingleDimLoad};
 use vrp_core::models::problem::Job;
-use vrp_core::models::problem::{ProblemObjective, Single, TargetConstraint, TargetObjective};
+use vrp_core::models::problem::{
+    ProblemObjective, Single, TargetConstraint, TargetObjective, TollTransportCost, TransportCost,
+};
 use vrp_core::solver::objectives::TourOrder as CoreTourOrder;
 use vrp_core::solver::objectives::*;
+use vrp_core::utils::VrpError;
 
 pub fn create_objective(
     api_problem: &ApiProblem,
     constraint: &mut ConstraintPipeline,
+    transport: &Arc<dyn TransportCost + Send + Sync>,
+    toll_cost: Option<&Arc<TollTransportCost>>,
     props: &ProblemProperties,
-) -> Arc<ProblemObjective> {
-    Arc::new(match &api_problem.objectives {
+    custom_objectives: &CustomObjectives,
+) -> Result<Arc<ProblemObjective>, VrpError> {
+    let unknown_names = api_problem
+        .objectives
+        .iter()
+        .flatten()
+        .flatten()
+        .filter_map(|objective| match objective {
+            Custom { name } if !custom_objectives.contains_key(name) => Some(name.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if !unknown_names.is_empty() {
+        return Err(VrpError::Config(format!("custom objectives are not registered: '{}'", unknown_names.join(", "))));
+    }
+
+    let has_toll_objective =
+        api_problem.objectives.iter().flatten().flatten().any(|objective| matches!(objective, MinimizeTolls));
+    if has_toll_objective && toll_cost.is_none() {
+        return Err(VrpError::Config("minimize-tolls objective requires matrices with tolls".to_string()));
+    }
+
+    Ok(Arc::new(match &api_problem.objectives {
         Some(objectives) => ProblemObjective::new(
             objectives
                 .iter()
@@ -35,6 +64,11 @@ pub fn create_objective(
                         MinimizeCost => core_objectives.push(TotalCost::minimize()),
                         MinimizeDistance => core_objectives.push(TotalDistance::minimize()),
                         MinimizeDuration => core_objectives.push(TotalDuration::minimize()),
+                        MinimizeTolls => {
+                            if let Some(toll_cost) = toll_cost {
+                                core_objectives.push(TotalToll::minimize(toll_cost.clone()))
+                            }
+                        }
                         MinimizeTours => {
                             constraint.add_module(Arc::new(FleetUsageConstraintModule::new_minimized()));
                             core_objectives.push(Arc::new(TotalRoutes::new_minimized()))
@@ -81,6 +115,11 @@ pub fn create_objective(
                             constraint.add_module(module);
                             core_objectives.push(objective);
                         }
+                        TargetStops { target, tolerance } => {
+                            let (module, objective) = WorkBalance::new_target_stops(*target, tolerance.unwrap_or(0));
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
                         FormatTourOrder { is_constrained } => {
                             let (module, objective) = get_order(*is_constrained);
                             constraint.add_module(module);
@@ -94,6 +133,23 @@ pub fn create_objective(
                             constraint.add_module(module);
                             objectives.into_iter().for_each(|objective| core_objectives.push(objective));
                         }
+                        MaximizeRespectedBuffers => {
+                            let (module, objective) = get_buffer(transport.clone());
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        MinimizeLatestEta => {
+                            let (module, objective) = get_latest_eta(transport.clone());
+                            constraint.add_module(module);
+                            core_objectives.push(objective);
+                        }
+                        Custom { name } => {
+                            let (objective, module) = custom_objectives.get(name).expect("unknown custom objective");
+                            if let Some(module) = module {
+                                constraint.add_module(module.clone());
+                            }
+                            core_objectives.push(objective.clone());
+                        }
                     });
                     core_objectives
                 })
@@ -121,7 +177,7 @@ pub fn create_objective(
 
             ProblemObjective::new(objectives)
         }
-    })
+    }))
 }
 
 fn unwrap_options(options: &Option<BalanceOptions>) -> Option<f64> {
@@ -141,11 +197,11 @@ fn get_value(
         Arc::new(move |solution| {
             solution.unassigned.iter().map(|(job, _)| get_unassigned_job_estimate(job, break_value, 0.)).sum()
         }),
-        ValueFn::Left(Arc::new(|job| job.dimens().get_value::<f64>("value").cloned().unwrap_or(0.))),
+        ValueFn::Left(Arc::new(|job| job.dimens().get_dimen(&JOB_VALUE_KEY).cloned().unwrap_or(0.))),
         Arc::new(|job, value| match job {
             Job::Single(single) => {
                 let mut dimens = single.dimens.clone();
-                dimens.set_value("value", value);
+                dimens.set_dimen(&JOB_VALUE_KEY, value);
 
                 Job::Single(Arc::new(Single { places: single.places.clone(), dimens }))
             }
@@ -157,7 +213,7 @@ fn get_value(
 }
 
 fn get_order(is_constrained: bool) -> (TargetConstraint, TargetObjective) {
-    let order_fn = OrderFn::Left(Arc::new(|single| single.dimens.get_value::<i32>("order").map(|order| *order as f64)));
+    let order_fn = OrderFn::Left(Arc::new(|single| single.dimens.get_dimen(&JOB_ORDER_KEY).map(|order| *order as f64)));
 
     if is_constrained {
         CoreTourOrder::new_constrained(order_fn, TOUR_ORDER_KEY, TOUR_ORDER_CONSTRAINT_CODE)
@@ -166,6 +222,19 @@ fn get_order(is_constrained: bool) -> (TargetConstraint, TargetObjective) {
     }
 }
 
+fn get_buffer(transport: Arc<dyn TransportCost + Send + Sync>) -> (TargetConstraint, TargetObjective) {
+    let buffer_fn: BufferFn = Arc::new(|single| single.dimens.get_dimen(&JOB_BUFFER_KEY).cloned());
+
+    ScheduleBuffer::new_maximized(buffer_fn, transport, SCHEDULE_BUFFER_KEY, SCHEDULE_BUFFER_CONSTRAINT_CODE)
+}
+
+fn get_latest_eta(transport: Arc<dyn TransportCost + Send + Sync>) -> (TargetConstraint, TargetObjective) {
+    let priority_fn: PriorityFn =
+        Arc::new(|single| single.dimens.get_dimen(&JOB_PRIORITIZED_KEY).cloned().unwrap_or(false));
+
+    LatestEta::new_minimized(priority_fn, transport, LATEST_ETA_KEY, LATEST_ETA_CONSTRAINT_CODE)
+}
+
 fn get_area(
     max_value: f64,
     break_value: Option<f64>,
@@ -178,7 +247,7 @@ fn get_area(
         actor
             .vehicle
             .dimens
-            .get_value::<HashMap<String, (usize, f64)>>("areas")
+            .get_dimen(&VEHICLE_AREAS_KEY)
             .and_then(|index| single.dimens.get_id().and_then(|id| index.get(id)))
             .map(|(order, _)| *order as f64)
     });
@@ -186,7 +255,7 @@ fn get_area(
         actor
             .vehicle
             .dimens
-            .get_value::<HashMap<String, (usize, f64)>>("areas")
+            .get_dimen(&VEHICLE_AREAS_KEY)
             .and_then(|index| job.dimens().get_id().and_then(|id| index.get(id)))
             .map(|(_, value)| *value)
             .unwrap_or(0.)
@@ -237,7 +306,7 @@ fn get_unassigned_job_estimate(job: &Job, break_value: f64, default_value: f64)
     if let Some(clusters) = job.dimens().get_cluster() {
         clusters.len() as f64 * default_value
     } else {
-        job.dimens().get_value::<String>("type").map_or(default_value, |job_type| {
+        job.dimens().get_dimen(&JOB_TYPE_KEY).map_or(default_value, |job_type| {
             if job_type == "break" {
                 break_value
             } else {
@@ -0,0 +1,240 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/order_batching_test.rs"]
+mod order_batching_test;
+
+use crate::format::problem::*;
+use crate::format::{Location, PlanningUnits};
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, Read};
+
+/// Specifies an order batching problem for warehouse picking: pickers with carts walk through
+/// the warehouse and pick order lines, all lines of the same order are put into the same cart.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBatching {
+    /// A warehouse layout.
+    pub layout: WarehouseLayout,
+    /// Pickers with carts.
+    pub pickers: Vec<Picker>,
+    /// Orders to be picked.
+    pub orders: Vec<Order>,
+    /// A walking speed of pickers in layout distance units per duration unit.
+    pub walking_speed: f64,
+    /// Specifies units of durations and distances used by layout, orders and pickers.
+    /// Default are seconds and meters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<PlanningUnits>,
+}
+
+/// Specifies a warehouse layout with parallel aisles of the same length which are connected by
+/// front and back cross aisles.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarehouseLayout {
+    /// A distance between centers of two neighbour aisles.
+    pub aisle_width: f64,
+    /// An aisle length.
+    pub aisle_length: f64,
+    /// A location of the depot where pickers take and return their carts.
+    pub depot: WarehouseLocation,
+}
+
+/// Specifies a location in the warehouse.
+#[derive(Clone, Deserialize, Debug, Serialize, PartialEq)]
+pub struct WarehouseLocation {
+    /// An aisle index, starting from zero.
+    pub aisle: usize,
+    /// A position along the aisle measured from the front cross aisle.
+    pub position: f64,
+}
+
+/// Specifies a picker type with a cart.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Picker {
+    /// A picker type id.
+    pub id: String,
+    /// An amount of pickers of this type.
+    pub amount: usize,
+    /// A cart capacity.
+    pub capacity: i32,
+    /// A shift start time in RFC3339 format.
+    pub shift_start: String,
+    /// A shift end time in RFC3339 format.
+    pub shift_end: String,
+}
+
+/// Specifies an order which consists of one or more order lines.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct Order {
+    /// An order id.
+    pub id: String,
+    /// Order lines.
+    pub lines: Vec<OrderLine>,
+}
+
+/// Specifies an order line to be picked.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct OrderLine {
+    /// A picking location.
+    pub location: WarehouseLocation,
+    /// A quantity which occupies cart capacity.
+    pub quantity: i32,
+    /// A picking duration.
+    pub duration: f64,
+}
+
+/// Reads order batching problem from json input.
+pub fn deserialize_order_batching<R: Read>(reader: BufReader<R>) -> Result<OrderBatching, String> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize order batching problem: '{}'", err))
+}
+
+/// Creates a pragmatic problem and routing matrix from order batching problem. Each order becomes
+/// a multi job with pickup per order line, so it is picked completely by one picker or unassigned,
+/// pickers become vehicles with cart capacity which start and end at the depot. Travel distances
+/// are walking distances between aisles and vehicle costs depend only on distance, so the cost
+/// objective minimizes total walking distance.
+pub fn create_order_batching_problem(batching: &OrderBatching) -> Result<(Problem, Matrix), String> {
+    let layout = &batching.layout;
+
+    if layout.aisle_width < 0. || layout.aisle_length <= 0. || batching.walking_speed <= 0. {
+        return Err("order batching layout and walking speed should be positive".to_string());
+    }
+
+    let mut locations = vec![layout.depot.clone()];
+    let mut get_location_index = |location: &WarehouseLocation| {
+        let index = locations.iter().position(|l| l == location).unwrap_or_else(|| {
+            locations.push(location.clone());
+            locations.len() - 1
+        });
+
+        Location::Reference { index }
+    };
+
+    let jobs = batching
+        .orders
+        .iter()
+        .map(|order| Job {
+            id: order.id.clone(),
+            pickups: Some(
+                order
+                    .lines
+                    .iter()
+                    .enumerate()
+                    .map(|(line_idx, line)| JobTask {
+                        places: vec![JobPlace {
+                            location: get_location_index(&line.location),
+                            duration: line.duration,
+                            times: None,
+                            tag: Some(format!("line{}", line_idx + 1)),
+                            parking: None,
+                        }],
+                        demand: Some(vec![line.quantity]),
+                        order: None,
+                        skills: None,
+                        not_before: None,
+                    })
+                    .collect(),
+            ),
+            deliveries: None,
+            replacements: None,
+            services: None,
+            skills: None,
+            value: None,
+            group: None,
+            compatibility: None,
+            buffer: None,
+            is_prioritized: None,
+            sync: None,
+            transfer: None,
+        })
+        .collect();
+
+    let depot = Location::Reference { index: 0 };
+    let profile = "walking".to_string();
+
+    let vehicles = batching
+        .pickers
+        .iter()
+        .map(|picker| VehicleType {
+            type_id: picker.id.clone(),
+            vehicle_ids: (1..=picker.amount).map(|seq| format!("{}_{}", picker.id, seq)).collect(),
+            profile: VehicleProfile { matrix: profile.clone(), scale: None },
+            costs: VehicleCosts { fixed: None, distance: 1., time: 0., waiting: None, depot_waiting: None },
+            shifts: vec![VehicleShift {
+                start: ShiftStart {
+                    earliest: picker.shift_start.clone(),
+                    latest: None,
+                    optimize_departure: None,
+                    location: depot.clone(),
+                },
+                end: Some(ShiftEnd {
+                    earliest: None,
+                    latest: picker.shift_end.clone(),
+                    location: depot.clone(),
+                    alternatives: None,
+                }),
+                dispatch: None,
+                breaks: None,
+                reloads: None,
+                loading: None,
+            }],
+            capacity: vec![picker.capacity],
+            skills: None,
+            limits: None,
+            carry_load: None,
+            echelon: None,
+            service_time_factor: None,
+        })
+        .collect();
+
+    let distances = locations
+        .iter()
+        .flat_map(|from| locations.iter().map(move |to| get_walking_distance(layout, from, to)))
+        .collect::<Vec<_>>();
+
+    let problem = Problem {
+        plan: Plan { jobs, relations: None, areas: None, clustering: None, hints: None, readonly_routes: None },
+        fleet: Fleet {
+            vehicles,
+            profiles: vec![MatrixProfile {
+                name: profile.clone(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: None,
+                leg_overrides: None,
+            }],
+        },
+        objectives: Some(vec![vec![Objective::MinimizeUnassignedJobs { breaks: None }], vec![Objective::MinimizeCost]]),
+        units: batching.units.clone(),
+    };
+
+    let matrix = Matrix {
+        profile: Some(profile),
+        timestamp: None,
+        travel_times: distances.iter().map(|distance| (distance / batching.walking_speed).round() as i64).collect(),
+        distances: distances.iter().map(|distance| distance.round() as i64).collect(),
+        error_codes: None,
+        units: batching.units.clone(),
+        tolls: None,
+    };
+
+    Ok((problem, matrix))
+}
+
+/// Returns walking distance between two warehouse locations. Within the same aisle, picker walks
+/// straight, otherwise picker leaves the aisle via front or back cross aisle whichever is shorter.
+pub fn get_walking_distance(layout: &WarehouseLayout, from: &WarehouseLocation, to: &WarehouseLocation) -> f64 {
+    let from_position = from.position.clamp(0., layout.aisle_length);
+    let to_position = to.position.clamp(0., layout.aisle_length);
+
+    if from.aisle == to.aisle {
+        return (from_position - to_position).abs();
+    }
+
+    let across = (from.aisle as f64 - to.aisle as f64).abs() * layout.aisle_width;
+    let front = from_position + to_position;
+    let back = 2. * layout.aisle_length - from_position - to_position;
+
+    across + front.min(back)
+}
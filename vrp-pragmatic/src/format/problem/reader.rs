@@ -15,19 +15,22 @@ mod objective_reader;
 mod clustering_reader;
 
 use self::clustering_reader::create_cluster_config;
-use self::fleet_reader::{create_transport_costs, read_fleet, read_travel_limits};
-use self::job_reader::{read_jobs_with_extra_locks, read_locks};
+use self::fleet_reader::{
+    create_transport_costs, read_fleet, read_leg_override_index, read_time_of_day_index, read_toll_index,
+    read_travel_limits,
+};
+use self::job_reader::{read_assignment_hints, read_jobs_with_extra_locks, read_locks};
 use self::objective_reader::create_objective;
 use crate::constraints::*;
-use crate::extensions::{get_route_modifier, OnlyVehicleActivityCost};
+use crate::extensions::{get_route_modifier, GeoTransportCost, OnlyVehicleActivityCost};
 use crate::format::coord_index::CoordIndex;
+use crate::format::dimens::*;
 use crate::format::problem::*;
+use crate::format::Location as ApiLocation;
 use crate::format::*;
-use crate::utils::get_approx_transportation;
 use crate::validation::ValidationContext;
 use crate::{get_unique_locations, parse_time};
-use hashbrown::HashSet;
-use std::cmp::Ordering::Equal;
+use hashbrown::HashMap;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::construction::constraints::*;
@@ -35,8 +38,9 @@ use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 use vrp_core::models::{Extras, Lock, Problem};
 use vrp_core::prelude::*;
-use vrp_core::rosomaxa::utils::CollectGroupBy;
+use vrp_core::rosomaxa::utils::{parallel_collect, CollectGroupBy};
 use vrp_core::solver::processing::VicinityDimension;
+use vrp_core::solver::search::{CoordinatesDimension, LocationCoordinates};
 
 pub type ApiProblem = crate::format::problem::Problem;
 pub type CoreFleet = vrp_core::models::problem::Fleet;
@@ -56,7 +60,7 @@ impl<R: Read> PragmaticProblem for (BufReader<R>, Vec<BufReader<R>>) {
             matrices.push(deserialize_matrix(matrix)?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, &Default::default())
     }
 }
 
@@ -64,7 +68,7 @@ impl<R: Read> PragmaticProblem for BufReader<R> {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(self)?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, &Default::default())
     }
 }
 
@@ -77,7 +81,7 @@ impl PragmaticProblem for (String, Vec<String>) {
             matrices.push(deserialize_matrix(BufReader::new(matrix.as_bytes()))?);
         }
 
-        map_to_problem_with_matrices(problem, matrices)
+        map_to_problem_with_matrices(problem, matrices, &Default::default())
     }
 }
 
@@ -85,19 +89,19 @@ impl PragmaticProblem for String {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
         let problem = deserialize_problem(BufReader::new(self.as_bytes()))?;
 
-        map_to_problem_with_approx(problem)
+        map_to_problem_with_approx(problem, &Default::default())
     }
 }
 
 impl PragmaticProblem for (ApiProblem, Vec<Matrix>) {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
-        map_to_problem_with_matrices(self.0, self.1)
+        map_to_problem_with_matrices(self.0, self.1, &Default::default())
     }
 }
 
 impl PragmaticProblem for ApiProblem {
     fn read_pragmatic(self) -> Result<Problem, Vec<FormatError>> {
-        map_to_problem_with_approx(self)
+        map_to_problem_with_approx(self, &Default::default())
     }
 }
 
@@ -111,6 +115,54 @@ impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
     }
 }
 
+/// Specifies custom objectives with their optional constraint modules keyed by name.
+pub type CustomObjectives = HashMap<String, (TargetObjective, Option<TargetConstraint>)>;
+
+/// Provides the way to read problem in pragmatic format with extra user-defined extensions.
+#[derive(Default)]
+pub struct PragmaticProblemBuilder {
+    custom_objectives: CustomObjectives,
+    use_geo_transport: bool,
+    matrix_storage: MatrixStorage,
+}
+
+impl PragmaticProblemBuilder {
+    /// Registers a custom objective with its optional constraint module under given name. Such objective
+    /// can be referenced in problem definition using `custom` objective type with the same name.
+    pub fn with_custom_objective(
+        mut self,
+        name: &str,
+        objective: TargetObjective,
+        constraint: Option<TargetConstraint>,
+    ) -> Self {
+        self.custom_objectives.insert(name.to_string(), (objective, constraint));
+        self
+    }
+
+    /// Uses geo transport cost which calculates distances between geo coordinates on demand instead of
+    /// approximated routing matrices. It is applied only when no routing matrices are specified.
+    pub fn with_geo_transport(mut self) -> Self {
+        self.use_geo_transport = true;
+        self
+    }
+
+    /// Specifies how routing matrix values are kept in memory. Compact storages reduce memory usage
+    /// on problems with many locations at the cost of precision.
+    pub fn with_matrix_storage(mut self, matrix_storage: MatrixStorage) -> Self {
+        self.matrix_storage = matrix_storage;
+        self
+    }
+
+    /// Builds a core problem from problem in pragmatic format and optional routing matrices.
+    pub fn build(self, problem: ApiProblem, matrices: Option<Vec<Matrix>>) -> Result<Problem, Vec<FormatError>> {
+        if let Some(matrices) = matrices {
+            map_to_problem_with_matrices(problem, matrices, &self)
+        } else {
+            map_to_problem_with_approx(problem, &self)
+        }
+    }
+}
+
 pub struct ProblemProperties {
     has_multi_dimen_capacity: bool,
     has_breaks: bool,
@@ -118,89 +170,160 @@ pub struct ProblemProperties {
     has_unreachable_locations: bool,
     has_dispatch: bool,
     has_reloads: bool,
+    has_extended_reloads: bool,
+    has_loading: bool,
     has_order: bool,
     has_group: bool,
+    has_sync: bool,
+    has_transfer: bool,
+    has_echelons: bool,
     has_compatibility: bool,
     has_tour_size_limits: bool,
+    has_tour_cost_limits: bool,
+    has_tour_stops_limits: bool,
+    has_driver_hours: bool,
+    has_overtime: bool,
+    has_carried_load: bool,
     max_job_value: Option<f64>,
     max_area_value: Option<f64>,
 }
 
 /// Creates a matrices using approximation.
 pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
-    const DEFAULT_SPEED: f64 = 10.;
-    // get each speed value once
-    let speeds = problem
-        .fleet
-        .profiles
-        .iter()
-        .map(|profile| profile.speed.unwrap_or(DEFAULT_SPEED))
-        .map(|speed| speed.to_bits())
-        .collect::<HashSet<u64>>();
-    let speeds = speeds.into_iter().map(f64::from_bits).collect::<Vec<_>>();
+    let transport = GeoTransportCost::from_problem(problem).expect("cannot create approximation");
 
-    let locations = get_unique_locations(problem);
-    let approx_data = get_approx_transportation(&locations, speeds.as_slice());
+    create_approx_matrices_from_transport(problem, &transport)
+}
 
-    problem
-        .fleet
-        .profiles
-        .iter()
-        .map(move |profile| {
-            let speed = profile.speed.unwrap_or(DEFAULT_SPEED);
-            let idx =
-                speeds.iter().position(|s| compare_floats(*s, speed) == Equal).expect("Cannot find profile speed");
-
-            Matrix {
-                profile: Some(profile.name.clone()),
-                timestamp: None,
-                travel_times: approx_data[idx].0.clone(),
-                distances: approx_data[idx].1.clone(),
-                error_codes: None,
-            }
-        })
-        .collect()
+fn create_approx_matrices_from_transport(problem: &ApiProblem, transport: &GeoTransportCost) -> Vec<Matrix> {
+    let size = get_unique_locations(problem).len();
+    let profiles = problem.fleet.profiles.iter().enumerate().collect::<Vec<_>>();
+
+    parallel_collect(&profiles, |(idx, profile)| {
+        let profile_idx = Profile::new(*idx, None);
+        let (travel_times, distances) = (0..size)
+            .flat_map(|from| (0..size).map(move |to| (from, to)))
+            .map(|(from, to)| {
+                (
+                    transport.duration_approx(&profile_idx, from, to) as i64,
+                    transport.distance_approx(&profile_idx, from, to) as i64,
+                )
+            })
+            .unzip();
+
+        Matrix {
+            profile: Some(profile.name.clone()),
+            timestamp: None,
+            travel_times,
+            distances,
+            error_codes: None,
+            units: None,
+            tolls: None,
+        }
+    })
 }
 
-fn map_to_problem_with_approx(problem: ApiProblem) -> Result<Problem, Vec<FormatError>> {
+fn map_to_problem_with_approx(
+    problem: ApiProblem,
+    builder: &PragmaticProblemBuilder,
+) -> Result<Problem, Vec<FormatError>> {
     let coord_index = CoordIndex::new(&problem);
-    let matrices = if coord_index.get_used_types().1 { vec![] } else { create_approx_matrices(&problem) };
-    map_to_problem(problem, matrices, coord_index)
+    // NOTE if approximation cannot be created or addressed in memory, validation reports the reason
+    let geo_transport =
+        if coord_index.get_used_types().1 { None } else { GeoTransportCost::from_problem(&problem).ok() };
+    let is_addressable =
+        estimate_matrix_memory(coord_index.matrix_size(), problem.fleet.profiles.len(), MatrixStorage::default())
+            .is_some();
+
+    match geo_transport {
+        Some(transport) if builder.use_geo_transport => {
+            map_to_problem(problem, vec![], coord_index, Some(Arc::new(transport)), builder)
+        }
+        Some(transport) if is_addressable => {
+            let matrices = create_approx_matrices_from_transport(&problem, &transport);
+            map_to_problem(problem, matrices, coord_index, None, builder)
+        }
+        _ => map_to_problem(problem, vec![], coord_index, None, builder),
+    }
 }
 
-fn map_to_problem_with_matrices(problem: ApiProblem, matrices: Vec<Matrix>) -> Result<Problem, Vec<FormatError>> {
+fn map_to_problem_with_matrices(
+    problem: ApiProblem,
+    matrices: Vec<Matrix>,
+    builder: &PragmaticProblemBuilder,
+) -> Result<Problem, Vec<FormatError>> {
     let coord_index = CoordIndex::new(&problem);
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, coord_index, None, builder)
 }
 
 fn map_to_problem(
     api_problem: ApiProblem,
     matrices: Vec<Matrix>,
     coord_index: CoordIndex,
+    transport: Option<Arc<dyn TransportCost + Send + Sync>>,
+    builder: &PragmaticProblemBuilder,
 ) -> Result<Problem, Vec<FormatError>> {
     ValidationContext::new(&api_problem, Some(&matrices), &coord_index).validate()?;
 
     let problem_props = get_problem_properties(&api_problem, &matrices);
+    let matrix_memory = Some(matrices.len())
+        .filter(|&amount| amount > 0)
+        .and_then(|amount| estimate_matrix_memory(coord_index.matrix_size(), amount, builder.matrix_storage));
 
     let coord_index = Arc::new(coord_index);
     let fleet = read_fleet(&api_problem, &problem_props, &coord_index);
     let reserved_times_index = read_reserved_times_index(&api_problem, &fleet);
 
-    let transport = create_transport_costs(&api_problem, &matrices).map_err(|err| {
-        vec![FormatError::new(
-            "E0002".to_string(),
-            "cannot create transport costs".to_string(),
-            format!("check matrix routing data: '{}'", err),
-        )]
-    })?;
-    let activity: Arc<dyn ActivityCost + Send + Sync> = Arc::new(OnlyVehicleActivityCost::default());
+    let transport = transport
+        .map_or_else(|| create_transport_costs(&api_problem, &matrices, builder.matrix_storage), Ok)
+        .map_err(|err| {
+            vec![FormatError::new(
+                "E0002".to_string(),
+                "cannot create transport costs".to_string(),
+                format!("check matrix routing data: '{}'", err),
+            )]
+        })?;
+    let transport = read_time_of_day_index(&api_problem)
+        .and_then(|time_of_day_index| {
+            if time_of_day_index.is_empty() {
+                Ok(transport)
+            } else {
+                TimeOfDayTransportCost::new(time_of_day_index, transport)
+                    .map::<Arc<dyn TransportCost + Send + Sync>, _>(|transport| Arc::new(transport))
+            }
+        })
+        .map_err(|err| {
+            vec![FormatError::new(
+                "E0002".to_string(),
+                "cannot create transport costs".to_string(),
+                format!("check time of day settings in fleet profiles: '{}'", err),
+            )]
+        })?;
+    let leg_override_index = read_leg_override_index(&api_problem, &coord_index);
+    let transport = if leg_override_index.is_empty() {
+        transport
+    } else {
+        LegOverrideTransportCost::new(leg_override_index, transport)
+            .map::<Arc<dyn TransportCost + Send + Sync>, _>(|transport| Arc::new(transport))
+            .map_err(|err| {
+                vec![FormatError::new(
+                    "E0002".to_string(),
+                    "cannot create transport costs".to_string(),
+                    format!("check leg overrides in fleet profiles: '{}'", err),
+                )]
+            })?
+    };
+    let has_service_time_factors =
+        api_problem.fleet.vehicles.iter().any(|vehicle| vehicle.service_time_factor.is_some());
+    let activity: Arc<dyn ActivityCost + Send + Sync> =
+        Arc::new(OnlyVehicleActivityCost::new(has_service_time_factors));
 
     let (transport, activity) = if reserved_times_index.is_empty() {
         (transport, activity)
     } else {
         DynamicTransportCost::new(reserved_times_index.clone(), transport)
             .and_then(|transport| {
-                DynamicActivityCost::new(reserved_times_index.clone()).map(|activity| (transport, activity))
+                DynamicActivityCost::new(reserved_times_index.clone(), activity).map(|activity| (transport, activity))
             })
             .map_err(|err| {
                 vec![FormatError::new(
@@ -213,6 +336,19 @@ fn map_to_problem(
                 |(transport, activity)| (Arc::new(transport), Arc::new(activity)),
             )?
     };
+    let toll_index = read_toll_index(&api_problem, &matrices);
+    let toll_cost = if toll_index.is_empty() {
+        None
+    } else {
+        Some(Arc::new(TollTransportCost::new(toll_index, transport.clone()).map_err(|err| {
+            vec![FormatError::new(
+                "E0002".to_string(),
+                "cannot create transport costs".to_string(),
+                format!("check matrix tolls: '{}'", err),
+            )]
+        })?))
+    };
+    let transport = toll_cost.clone().map_or(transport, |toll_cost| toll_cost as Arc<dyn TransportCost + Send + Sync>);
 
     // TODO pass random from outside as there might be need to have it initialized with seed
     //      at the moment, this random instance is used only by multi job permutation generator
@@ -232,7 +368,21 @@ fn map_to_problem(
     let mut constraint =
         create_constraint_pipeline(&jobs, &fleet, transport.clone(), activity.clone(), &problem_props, &locks, limits);
 
-    let objective = create_objective(&api_problem, &mut constraint, &problem_props);
+    let objective = create_objective(
+        &api_problem,
+        &mut constraint,
+        &transport,
+        toll_cost.as_ref(),
+        &problem_props,
+        &builder.custom_objectives,
+    )
+    .map_err(|err| {
+        vec![FormatError::new(
+            "E0005".to_string(),
+            "cannot create objective".to_string(),
+            format!("check objectives definition: '{}'", err),
+        )]
+    })?;
     let constraint = Arc::new(constraint);
     let extras = Arc::new(
         create_extras(
@@ -243,6 +393,8 @@ fn map_to_problem(
             job_index,
             coord_index,
             reserved_times_index,
+            toll_cost,
+            matrix_memory,
         )
         .map_err(|err| {
             // TODO make sure that error matches actual reason
@@ -274,34 +426,42 @@ fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> Res
         .flat_map(|vehicle| {
             vehicle.shifts.iter().enumerate().flat_map(move |(shift_idx, shift)| {
                 shift.breaks.iter().flat_map(|br| br.iter()).filter_map(move |br| match br {
-                    VehicleBreak::Required { time, duration } => {
-                        Some((vehicle.type_id.clone(), shift_idx, time.clone(), *duration))
-                    }
+                    VehicleBreak::Required { time, duration, can_interrupt } => Some((
+                        vehicle.type_id.clone(),
+                        shift_idx,
+                        time.clone(),
+                        *duration,
+                        can_interrupt.unwrap_or(true),
+                    )),
                     VehicleBreak::Optional { .. } => None,
                 })
             })
         })
-        .collect_group_by_key(|(type_id, shift_idx, _, _)| (type_id.clone(), *shift_idx));
+        .collect_group_by_key(|(type_id, shift_idx, _, _, _)| (type_id.clone(), *shift_idx));
 
     fleet
         .actors
         .iter()
         .filter_map(|actor| {
-            let type_id = actor.vehicle.dimens.get_value::<String>("type_id").unwrap().clone();
-            let shift_idx = *actor.vehicle.dimens.get_value::<usize>("shift_index").unwrap();
+            let type_id = actor.vehicle.dimens.get_dimen(&VEHICLE_TYPE_ID_KEY).unwrap().clone();
+            let shift_idx = *actor.vehicle.dimens.get_dimen(&SHIFT_INDEX_KEY).unwrap();
 
             let times = breaks_map
                 .get(&(type_id, shift_idx))
                 .iter()
                 .flat_map(|data| data.iter())
-                .map(|(_, _, time, duration)| match time {
-                    VehicleRequiredBreakTime::ExactTime(time) => {
-                        let time = parse_time(time);
-                        TimeSpan::Window(TimeWindow::new(time, time + duration))
-                    }
-                    VehicleRequiredBreakTime::OffsetTime(offset) => {
-                        TimeSpan::Offset(TimeOffset::new(*offset, *offset + duration))
-                    }
+                .map(|(_, _, time, duration, can_interrupt)| {
+                    let time = match time {
+                        VehicleRequiredBreakTime::ExactTime(time) => {
+                            let time = parse_time(time);
+                            TimeSpan::Window(TimeWindow::new(time, time + duration))
+                        }
+                        VehicleRequiredBreakTime::OffsetTime(offset) => {
+                            TimeSpan::Offset(TimeOffset::new(*offset, *offset + duration))
+                        }
+                    };
+
+                    ReservedTimeSpan { time, can_interrupt: *can_interrupt }
                 })
                 .collect::<Vec<_>>();
 
@@ -341,6 +501,16 @@ fn create_constraint_pipeline(
 
     add_capacity_module(&mut constraint, props, activity.clone(), transport.clone());
 
+    // NOTE should be added after capacity module as it depends on load states
+    if props.has_extended_reloads {
+        add_reload_module(&mut constraint, props, activity.clone(), transport.clone());
+    }
+
+    // NOTE uses initial load state of the tour, so should be added after capacity module too
+    if props.has_loading {
+        add_loading_module(&mut constraint, props, activity.clone(), transport.clone());
+    }
+
     if props.has_breaks {
         constraint.add_module(Arc::new(BreakModule::new(activity.clone(), transport.clone(), BREAK_CONSTRAINT_CODE)));
     }
@@ -353,6 +523,30 @@ fn create_constraint_pipeline(
         constraint.add_module(Arc::new(GroupModule::new(jobs.size(), GROUP_CONSTRAINT_CODE, GROUP_KEY)));
     }
 
+    if props.has_sync {
+        constraint.add_module(Arc::new(SyncModule::new(
+            activity.clone(),
+            transport.clone(),
+            SYNC_CONSTRAINT_CODE,
+            SYNC_KEY,
+            JOB_SYNC_VISIT_KEY,
+        )));
+    }
+
+    if props.has_transfer {
+        constraint.add_module(Arc::new(SyncModule::new(
+            activity.clone(),
+            transport.clone(),
+            TRANSFER_CONSTRAINT_CODE,
+            TRANSFER_KEY,
+            JOB_TRANSFER_VISIT_KEY,
+        )));
+    }
+
+    if props.has_echelons {
+        constraint.add_module(Arc::new(EchelonModule::new(ECHELON_CONSTRAINT_CODE)));
+    }
+
     if props.has_skills {
         constraint.add_module(Arc::new(SkillsModule::new(SKILL_CONSTRAINT_CODE)));
     }
@@ -361,6 +555,21 @@ fn create_constraint_pipeline(
         constraint.add_module(Arc::new(DispatchModule::new(DISPATCH_CONSTRAINT_CODE)));
     }
 
+    // NOTE should be added after modules which update route schedules
+    if props.has_driver_hours {
+        constraint.add_module(Arc::new(DriverHoursModule::new(
+            activity.clone(),
+            transport.clone(),
+            DRIVER_HOURS_CONSTRAINT_CODE,
+            DRIVER_HOURS_KEY,
+        )));
+    }
+
+    // NOTE should be added after modules which update route duration
+    if props.has_overtime {
+        constraint.add_module(Arc::new(OvertimeModule::new(activity.clone(), transport.clone())));
+    }
+
     if !locks.is_empty() {
         constraint.add_module(Arc::new(StrictLockingModule::new(fleet, locks, LOCKING_CONSTRAINT_CODE)));
     }
@@ -369,6 +578,14 @@ fn create_constraint_pipeline(
         add_tour_size_module(&mut constraint)
     }
 
+    if props.has_tour_cost_limits {
+        add_tour_cost_module(&mut constraint, transport)
+    }
+
+    if props.has_tour_stops_limits {
+        add_tour_stops_module(&mut constraint)
+    }
+
     constraint
 }
 
@@ -378,37 +595,130 @@ fn add_capacity_module(
     activity: Arc<dyn ActivityCost + Send + Sync>,
     transport: Arc<dyn TransportCost + Send + Sync>,
 ) {
-    constraint.add_module(if props.has_reloads {
-        let threshold = 0.9;
-        if props.has_multi_dimen_capacity {
-            Arc::new(CapacityConstraintModule::<MultiDimLoad>::new_with_multi_trip(
-                activity,
-                transport,
-                CAPACITY_CONSTRAINT_CODE,
-                Arc::new(ReloadMultiTrip::new(Box::new(move |capacity| *capacity * threshold))),
-            ))
-        } else {
-            Arc::new(CapacityConstraintModule::<SingleDimLoad>::new_with_multi_trip(
-                activity,
-                transport,
-                CAPACITY_CONSTRAINT_CODE,
-                Arc::new(ReloadMultiTrip::new(Box::new(move |capacity| *capacity * threshold))),
-            ))
-        }
-    } else if props.has_multi_dimen_capacity {
-        Arc::new(CapacityConstraintModule::<MultiDimLoad>::new(activity, transport, CAPACITY_CONSTRAINT_CODE))
+    let threshold = 0.9;
+    let carried_load: CarriedLoadResolver = if props.has_carried_load {
+        Arc::new(|actor| {
+            let dimens = &actor.vehicle.dimens;
+            if dimens.get_dimen(&VEHICLE_CARRY_LOAD_KEY).cloned().unwrap_or(false) {
+                dimens.get_id().cloned().zip(dimens.get_dimen(&SHIFT_INDEX_KEY).cloned())
+            } else {
+                None
+            }
+        })
+    } else {
+        Arc::new(|_| None)
+    };
+
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Arc::new(CapacityConstraintModule::<MultiDimLoad>::new_with_carried_load(
+            activity,
+            transport,
+            CAPACITY_CONSTRAINT_CODE,
+            if props.has_reloads {
+                Arc::new(ReloadMultiTrip::new(Box::new(move |capacity| *capacity * threshold)))
+            } else {
+                Arc::new(NoMultiTrip::default())
+            },
+            carried_load,
+        ))
+    } else {
+        Arc::new(CapacityConstraintModule::<SingleDimLoad>::new_with_carried_load(
+            activity,
+            transport,
+            CAPACITY_CONSTRAINT_CODE,
+            if props.has_reloads {
+                Arc::new(ReloadMultiTrip::new(Box::new(move |capacity| *capacity * threshold)))
+            } else {
+                Arc::new(NoMultiTrip::default())
+            },
+            carried_load,
+        ))
+    });
+}
+
+fn add_reload_module(
+    constraint: &mut ConstraintPipeline,
+    props: &ProblemProperties,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+) {
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Arc::new(ReloadModule::<MultiDimLoad>::new(
+            activity,
+            transport,
+            Arc::new(|load| load.load.iter().take(load.size).sum::<i32>() as f64),
+            RELOAD_CONSTRAINT_CODE,
+            RELOAD_SCHEDULES_KEY,
+        ))
     } else {
-        Arc::new(CapacityConstraintModule::<SingleDimLoad>::new(activity, transport, CAPACITY_CONSTRAINT_CODE))
+        Arc::new(ReloadModule::<SingleDimLoad>::new(
+            activity,
+            transport,
+            Arc::new(|load| load.value as f64),
+            RELOAD_CONSTRAINT_CODE,
+            RELOAD_SCHEDULES_KEY,
+        ))
+    });
+}
+
+fn add_loading_module(
+    constraint: &mut ConstraintPipeline,
+    props: &ProblemProperties,
+    activity: Arc<dyn ActivityCost + Send + Sync>,
+    transport: Arc<dyn TransportCost + Send + Sync>,
+) {
+    constraint.add_module(if props.has_multi_dimen_capacity {
+        Arc::new(LoadingModule::<MultiDimLoad>::new(
+            activity,
+            transport,
+            Arc::new(|load| load.load.iter().take(load.size).sum::<i32>() as f64),
+            TIME_CONSTRAINT_CODE,
+        ))
+    } else {
+        Arc::new(LoadingModule::<SingleDimLoad>::new(
+            activity,
+            transport,
+            Arc::new(|load| load.value as f64),
+            TIME_CONSTRAINT_CODE,
+        ))
     });
 }
 
 fn add_tour_size_module(constraint: &mut ConstraintPipeline) {
     constraint.add_module(Arc::new(TourSizeModule::new(
-        Arc::new(|actor| actor.vehicle.dimens.get_value::<usize>("tour_size").cloned()),
+        Arc::new(|actor| actor.vehicle.dimens.get_dimen(&VEHICLE_TOUR_SIZE_KEY).cloned()),
         TOUR_SIZE_CONSTRAINT_CODE,
     )));
 }
 
+fn add_tour_cost_module(constraint: &mut ConstraintPipeline, transport: Arc<dyn TransportCost + Send + Sync>) {
+    constraint.add_module(Arc::new(TourCostModule::new(
+        Arc::new(|actor| actor.vehicle.dimens.get_dimen(&VEHICLE_MAX_COST_KEY).cloned()),
+        transport,
+        TOUR_COST_CONSTRAINT_CODE,
+    )));
+}
+
+fn add_tour_stops_module(constraint: &mut ConstraintPipeline) {
+    constraint.add_module(Arc::new(TourStopsModule::new(
+        Arc::new(|actor| actor.vehicle.dimens.get_dimen(&VEHICLE_MAX_STOPS_KEY).cloned()),
+        TOUR_STOPS_CONSTRAINT_CODE,
+    )));
+}
+
+fn get_location_coordinates(coord_index: &CoordIndex) -> LocationCoordinates {
+    // NOTE geo coordinates are used as planar ones: this is good enough approximation to define zones
+    let coordinates = (0..=coord_index.max_index().unwrap_or(0))
+        .map(|idx| match coord_index.get_by_idx(idx) {
+            Some(ApiLocation::Coordinate { lat, lng }) => Some((lng, lat)),
+            _ => None,
+        })
+        .collect();
+
+    LocationCoordinates::new(coordinates)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn create_extras(
     api_problem: &ApiProblem,
     constraint: Arc<ConstraintPipeline>,
@@ -417,16 +727,36 @@ fn create_extras(
     job_index: JobIndex,
     coord_index: Arc<CoordIndex>,
     reserved_times_index: ReservedTimesIndex,
-) -> Result<Extras, String> {
+    toll_cost: Option<Arc<TollTransportCost>>,
+    matrix_memory: Option<usize>,
+) -> Result<Extras, VrpError> {
     let mut extras = Extras::default();
     extras.insert(
         "capacity_type".to_string(),
         Arc::new((if props.has_multi_dimen_capacity { "multi" } else { "single" }).to_string()),
     );
+    extras.set_location_coordinates(get_location_coordinates(coord_index.as_ref()));
     extras.insert("coord_index".to_owned(), coord_index);
     extras.insert("job_index".to_owned(), Arc::new(job_index.clone()));
     extras.insert("reserved_times_index".to_owned(), Arc::new(reserved_times_index));
 
+    if let Some(units) = api_problem.units.clone() {
+        extras.insert("planning_units".to_owned(), Arc::new(units));
+    }
+
+    if let Some(toll_cost) = toll_cost {
+        extras.insert("toll_cost".to_owned(), toll_cost);
+    }
+
+    if let Some(matrix_memory) = matrix_memory {
+        extras.set_matrix_memory(matrix_memory);
+    }
+
+    let hints = read_assignment_hints(api_problem, &job_index);
+    if !hints.is_empty() {
+        extras.insert("assignment_hints".to_owned(), Arc::new(hints));
+    }
+
     if props.has_dispatch {
         extras.insert("route_modifier".to_owned(), Arc::new(get_route_modifier(constraint, random, job_index)));
     }
@@ -460,7 +790,11 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .flat_map(|t| &t.shifts)
         .any(|shift| shift.breaks.as_ref().map_or(false, |b| !b.is_empty()));
 
-    let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
+    let has_skills = api_problem
+        .plan
+        .jobs
+        .iter()
+        .any(|job| job.skills.is_some() || get_job_tasks(job).any(|task| task.skills.is_some()));
     let max_job_value = api_problem
         .plan
         .jobs
@@ -490,6 +824,14 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .vehicles
         .iter()
         .any(|t| t.shifts.iter().any(|s| s.reloads.as_ref().map_or(false, |reloads| !reloads.is_empty())));
+    let has_extended_reloads = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|t| t.shifts.iter())
+        .flat_map(|s| s.reloads.iter().flat_map(|reloads| reloads.iter()))
+        .any(|reload| reload.per_unit_duration.is_some() || reload.resource.is_some());
+    let has_loading = api_problem.fleet.vehicles.iter().any(|t| t.shifts.iter().any(|shift| shift.loading.is_some()));
 
     let has_order = api_problem
         .plan
@@ -500,9 +842,24 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         .any(|order| order > 0);
 
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
+    let has_sync = api_problem.plan.jobs.iter().any(|job| job.sync.is_some());
+    let has_transfer = api_problem.plan.jobs.iter().any(|job| job.transfer.is_some());
+    let has_echelons = api_problem.fleet.vehicles.iter().any(|v| v.echelon.is_some());
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
+    let has_tour_cost_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.max_cost.is_some()));
+    let has_carried_load = api_problem.fleet.vehicles.iter().any(|v| v.carry_load.unwrap_or(false));
+    let has_tour_stops_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.max_stops.is_some()));
+    let has_driver_hours =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.driver_hours.is_some()));
+    let has_overtime = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .any(|v| v.limits.as_ref().map_or(false, |l| l.shift_time.is_some() && l.overtime.is_some()));
 
     ProblemProperties {
         has_multi_dimen_capacity,
@@ -511,10 +868,20 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_unreachable_locations,
         has_dispatch,
         has_reloads,
+        has_extended_reloads,
+        has_loading,
         has_order,
         has_group,
+        has_sync,
+        has_transfer,
+        has_echelons,
         has_compatibility,
         has_tour_size_limits,
+        has_tour_cost_limits,
+        has_tour_stops_limits,
+        has_driver_hours,
+        has_overtime,
+        has_carried_load,
         max_job_value,
         max_area_value,
     }
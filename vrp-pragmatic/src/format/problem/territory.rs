@@ -0,0 +1,164 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/territory_test.rs"]
+mod territory_test;
+
+use crate::format::problem::*;
+use crate::format::solution::{Solution, Stop};
+use crate::format::{is_reserved_job_id, Location};
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+
+/// A function which returns area id for given location.
+pub type AreaResolver = dyn Fn(&Location) -> Option<String>;
+
+/// Keeps affinity scores learned from historical solutions: how often each vehicle served each area.
+#[derive(Clone, Debug, Default)]
+pub struct TerritoryAffinity {
+    /// Affinity scores in `[0, 1]` range for each area of each vehicle, keyed by vehicle id.
+    pub vehicles: HashMap<String, HashMap<String, f64>>,
+}
+
+/// Learns vehicle territory affinity from historical solutions. An affinity score is the share
+/// of vehicle's job activities which were performed in the given area.
+pub fn learn_territory_affinity(solutions: &[Solution], area_resolver: &AreaResolver) -> TerritoryAffinity {
+    let counts = solutions.iter().flat_map(|solution| solution.tours.iter()).fold(
+        HashMap::<String, HashMap<String, usize>>::new(),
+        |mut acc, tour| {
+            let areas = acc.entry(tour.vehicle_id.clone()).or_default();
+
+            tour.stops
+                .iter()
+                .flat_map(|stop| {
+                    let stop_location = match stop {
+                        Stop::Point(point) => Some(&point.location),
+                        Stop::Transit(_) => None,
+                    };
+
+                    stop.activities()
+                        .iter()
+                        .filter(|activity| !is_reserved_job_id(&activity.job_id))
+                        .filter_map(move |activity| activity.location.as_ref().or(stop_location))
+                })
+                .filter_map(area_resolver)
+                .for_each(|area_id| *areas.entry(area_id).or_insert(0) += 1);
+
+            acc
+        },
+    );
+
+    let vehicles = counts
+        .into_iter()
+        .filter_map(|(vehicle_id, areas)| {
+            let total = areas.values().sum::<usize>();
+            if total == 0 {
+                return None;
+            }
+
+            let scores = areas.into_iter().map(|(area_id, count)| (area_id, count as f64 / total as f64)).collect();
+
+            Some((vehicle_id, scores))
+        })
+        .collect();
+
+    TerritoryAffinity { vehicles }
+}
+
+/// Creates a new problem with areas and vehicle area limits derived from the territory affinity.
+/// Jobs are assigned to areas using area resolver on location of their first place. Affinity of
+/// vehicle type is an average of its vehicles affinities scaled to `[0, max_job_value]` range.
+/// Existing areas are replaced and unconstrained `area-order` objective is added, if missing,
+/// so vehicles are only encouraged to serve jobs within their usual areas.
+pub fn apply_territory_affinity(
+    problem: &Problem,
+    affinity: &TerritoryAffinity,
+    area_resolver: &AreaResolver,
+    max_job_value: f64,
+) -> Problem {
+    let mut problem = problem.clone();
+
+    let mut areas = problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| {
+            get_job_tasks(job)
+                .flat_map(|task| task.places.first())
+                .next()
+                .and_then(|place| area_resolver(&place.location))
+                .map(|area_id| (area_id, job.id.clone()))
+        })
+        .fold(HashMap::<String, Vec<String>>::new(), |mut acc, (area_id, job_id)| {
+            acc.entry(area_id).or_default().push(job_id);
+            acc
+        })
+        .into_iter()
+        .map(|(id, jobs)| Area { id, jobs })
+        .collect::<Vec<_>>();
+    areas.sort_by(|a, b| a.id.cmp(&b.id));
+
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        let mut limits = areas
+            .iter()
+            .filter_map(|area| {
+                let score = vehicle
+                    .vehicle_ids
+                    .iter()
+                    .filter_map(|vehicle_id| affinity.vehicles.get(vehicle_id))
+                    .filter_map(|scores| scores.get(&area.id))
+                    .sum::<f64>()
+                    / vehicle.vehicle_ids.len().max(1) as f64;
+
+                if score > 0. {
+                    Some(AreaLimit { area_id: area.id.clone(), job_value: (score * max_job_value).max(1.).round() })
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        limits.sort_by(|a, b| b.job_value.partial_cmp(&a.job_value).unwrap_or(Ordering::Equal));
+
+        let area_limits = if limits.is_empty() { None } else { Some(vec![limits]) };
+        match vehicle.limits.as_mut() {
+            Some(vehicle_limits) => vehicle_limits.areas = area_limits,
+            None if area_limits.is_some() => {
+                vehicle.limits = Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    max_cost: None,
+                    max_stops: None,
+                    areas: area_limits,
+                    driver_hours: None,
+                    overtime: None,
+                })
+            }
+            None => {}
+        }
+    });
+
+    problem.plan.areas = if areas.is_empty() { None } else { Some(areas) };
+
+    if problem.plan.areas.is_some() {
+        let mut objectives = problem.objectives.take().unwrap_or_else(|| {
+            vec![
+                vec![Objective::MinimizeUnassignedJobs { breaks: None }],
+                vec![Objective::MinimizeTours],
+                vec![Objective::MinimizeCost],
+            ]
+        });
+
+        let has_area_objective =
+            objectives.iter().flatten().any(|objective| matches!(objective, Objective::AreaOrder { .. }));
+
+        if !has_area_objective {
+            let area_objective =
+                Objective::AreaOrder { breaks: None, is_constrained: false, is_value_preferred: Some(true) };
+            let idx = objectives.len().max(1) - 1;
+            objectives.insert(idx, vec![area_objective]);
+        }
+
+        problem.objectives = Some(objectives);
+    }
+
+    problem
+}
@@ -1,3 +1,4 @@
+use crate::format::dimens::*;
 use crate::format::problem::VehicleBreak;
 use crate::format::problem::{Problem as FormatProblem, VehicleRequiredBreakTime};
 use crate::format::solution::{Activity as FormatActivity, Schedule as FormatSchedule, Tour as FormatTour};
@@ -9,7 +10,7 @@ use std::cmp::Ordering;
 use std::iter::once;
 use std::sync::Arc;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Job, Single};
+use vrp_core::models::problem::{Job, Multi, Single};
 use vrp_core::models::solution::{Activity, Place};
 use vrp_core::utils::compare_floats;
 
@@ -37,17 +38,27 @@ pub(crate) fn try_match_point_job(
     };
 
     match activity.activity_type.as_str() {
-        "departure" | "arrival" => Ok(None),
+        "departure" | "arrival" | "rest" => Ok(None),
         "pickup" | "delivery" | "replacement" | "service" => {
             let job =
                 job_index.get(&activity.job_id).ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
+            let is_transfer = job.as_multi().map_or(false, |multi| {
+                multi.jobs.iter().any(|single| single.dimens.get_dimen(&JOB_TRANSFER_VISIT_KEY).is_some())
+            });
             let singles: Box<dyn Iterator<Item = &Arc<_>>> = match job {
                 Job::Single(single) => Box::new(once(single)),
+                // NOTE activities of transfer job are distinguished by activity type and location
+                Job::Multi(multi) if is_transfer => Box::new(
+                    multi
+                        .jobs
+                        .iter()
+                        .filter(|single| single.dimens.get_dimen(&JOB_TYPE_KEY) == Some(&activity.activity_type)),
+                ),
                 Job::Multi(multi) => {
                     let tags = multi
                         .jobs
                         .iter()
-                        .filter_map(|single| single.dimens.get_value::<Vec<(usize, String)>>("tags"))
+                        .filter_map(|single| single.dimens.get_dimen(&JOB_TAGS_KEY))
                         .flat_map(|tags| tags.iter().map(|(_, tag)| tag))
                         .collect::<HashSet<_>>();
                     if tags.len() < multi.jobs.len() {
@@ -65,7 +76,16 @@ pub(crate) fn try_match_point_job(
                 .next()
                 .ok_or_else(|| format!("cannot match job '{}'", activity.job_id))?;
 
-            Ok(Some(JobInfo(job.clone(), single.clone(), place, ctx.time)))
+            // NOTE visits of synchronized job and parts of transfer job are separate jobs in the problem
+            let job = if single.dimens.get_dimen(&JOB_SYNC_VISIT_KEY).is_some() {
+                Job::Single(single.clone())
+            } else if is_transfer {
+                Multi::roots(single).map(Job::Multi).ok_or_else(|| format!("cannot match job '{}'", activity.job_id))?
+            } else {
+                job.clone()
+            };
+
+            Ok(Some(JobInfo(job, single.clone(), place, ctx.time)))
         }
         "break" | "dispatch" | "reload" => Ok(Some(
             (1..)
@@ -111,10 +131,10 @@ pub(crate) fn try_match_break_activity(
         .flat_map(|shift| shift.breaks.iter())
         .flat_map(|brs| brs.iter())
         .filter_map(|br| match br {
-            VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime(time), duration } => {
+            VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime(time), duration, .. } => {
                 Some((parse_time(time), *duration))
             }
-            VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime(offset), duration } => {
+            VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime(offset), duration, .. } => {
                 Some((route_start_time + *offset, *duration))
             }
             VehicleBreak::Optional { .. } => None,
@@ -183,26 +203,24 @@ fn match_place<'a>(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &'
 
 pub(crate) fn get_job_tag(single: &Single, place: (Location, (TimeWindow, Timestamp))) -> Option<&String> {
     let (location, (time_window, start_time)) = place;
-    single.dimens.get_value::<Vec<(usize, String)>>("tags").map(|tags| (tags, &single.places)).and_then(
-        |(tags, places)| {
-            tags.iter()
-                .find(|(place_idx, _)| {
-                    let place = places.get(*place_idx).expect("invalid tag place index");
-
-                    let is_correct_location = place.location.map_or(true, |l| location == l);
-                    let is_correct_time = place
-                        .times
-                        .iter()
-                        .map(|time| time.to_time_window(start_time))
-                        .any(|time| time.intersects(&time_window));
+    single.dimens.get_dimen(&JOB_TAGS_KEY).map(|tags| (tags, &single.places)).and_then(|(tags, places)| {
+        tags.iter()
+            .find(|(place_idx, _)| {
+                let place = places.get(*place_idx).expect("invalid tag place index");
 
-                    // TODO check duration too?
+                let is_correct_location = place.location.map_or(true, |l| location == l);
+                let is_correct_time = place
+                    .times
+                    .iter()
+                    .map(|time| time.to_time_window(start_time))
+                    .any(|time| time.intersects(&time_window));
 
-                    is_correct_location && is_correct_time
-                })
-                .map(|(_, tag)| tag)
-        },
-    )
+                // TODO check duration too?
+
+                is_correct_location && is_correct_time
+            })
+            .map(|(_, tag)| tag)
+    })
 }
 
 pub(crate) fn get_extra_time(stop: &PointStop, activity: &FormatActivity, place: &Place) -> Option<f64> {
@@ -1,9 +1,9 @@
-use crate::format::solution::{Statistic, Timing};
+use crate::format::solution::{PreciseStatistic, PreciseTiming, Statistic, Timing};
 use std::ops::Add;
 
 impl Default for Statistic {
     fn default() -> Self {
-        Statistic { cost: 0.0, distance: 0, duration: 0, times: Timing::default() }
+        Statistic { cost: 0.0, distance: 0, duration: 0, times: Timing::default(), toll: None }
     }
 }
 
@@ -23,6 +23,35 @@ impl Add for Statistic {
                 commuting: self.times.commuting + rhs.times.commuting,
                 parking: self.times.parking + rhs.times.parking,
             },
+            toll: add_tolls(self.toll, rhs.toll),
         }
     }
 }
+
+impl Add for PreciseStatistic {
+    type Output = PreciseStatistic;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        PreciseStatistic {
+            cost: self.cost + rhs.cost,
+            distance: self.distance + rhs.distance,
+            duration: self.duration + rhs.duration,
+            times: PreciseTiming {
+                driving: self.times.driving + rhs.times.driving,
+                serving: self.times.serving + rhs.times.serving,
+                waiting: self.times.waiting + rhs.times.waiting,
+                break_time: self.times.break_time + rhs.times.break_time,
+                commuting: self.times.commuting + rhs.times.commuting,
+                parking: self.times.parking + rhs.times.parking,
+            },
+            toll: add_tolls(self.toll, rhs.toll),
+        }
+    }
+}
+
+fn add_tolls(a: Option<f64>, b: Option<f64>) -> Option<f64> {
+    match (a, b) {
+        (None, None) => None,
+        (a, b) => Some(a.unwrap_or(0.) + b.unwrap_or(0.)),
+    }
+}
@@ -0,0 +1,280 @@
+//! Provides the way to compare planned solution with its actual execution and use the difference
+//! to adjust problem definition for the next planning cycle.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/feedback_test.rs"]
+mod feedback_test;
+
+use crate::format::problem::{JobTask, Problem};
+use crate::format::solution::{Schedule, Solution, Tour};
+use crate::parse_time_safe;
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, Read};
+
+/// Specifies actual execution of a job activity.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ActualActivity {
+    /// Job id.
+    pub job_id: String,
+    /// Activity type.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub activity_type: String,
+    /// Actual arrival and departure time. When omitted, the activity is considered as skipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<Schedule>,
+}
+
+/// Specifies actual execution of a tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ActualTour {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    #[serde(default)]
+    pub shift_index: usize,
+    /// Activities in order of their actual execution.
+    pub activities: Vec<ActualActivity>,
+}
+
+/// Specifies actual execution data collected for a planned solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct ExecutionReport {
+    /// Actually executed tours.
+    pub tours: Vec<ActualTour>,
+}
+
+/// Specifies deviation of a single activity from its plan.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityDeviation {
+    /// Job id.
+    pub job_id: String,
+    /// Activity type.
+    #[serde(rename(serialize = "type", deserialize = "type"))]
+    pub activity_type: String,
+    /// Difference between actual and planned arrival (positive means delay).
+    pub arrival_delay: f64,
+    /// Ratio between actual and planned time spent at activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_ratio: Option<f64>,
+}
+
+/// Specifies deviation of a tour from its plan.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourDeviation {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle type id.
+    pub type_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Ratio between actual and planned travel time between performed activities.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub travel_ratio: Option<f64>,
+    /// Deviations of performed activities.
+    pub activities: Vec<ActivityDeviation>,
+    /// Ids of jobs which activities were planned, but skipped.
+    pub skipped: Vec<String>,
+}
+
+/// Specifies deviation of the whole solution from its plan.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionDeviation {
+    /// Average arrival delay over all performed activities.
+    pub mean_arrival_delay: f64,
+    /// Maximum arrival delay over all performed activities.
+    pub max_arrival_delay: f64,
+    /// Total amount of skipped activities.
+    pub skipped: usize,
+    /// Tour deviations.
+    pub tours: Vec<TourDeviation>,
+}
+
+/// Deserializes execution report in json format from `BufReader`.
+pub fn deserialize_execution_report<R: Read>(reader: BufReader<R>) -> Result<ExecutionReport, String> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize execution report: {}", err))
+}
+
+/// Compares planned solution with its actual execution and returns deviation metrics.
+pub fn get_execution_deviation(solution: &Solution, report: &ExecutionReport) -> Result<ExecutionDeviation, String> {
+    let tours = solution
+        .tours
+        .iter()
+        .filter_map(|tour| {
+            report
+                .tours
+                .iter()
+                .find(|actual| actual.vehicle_id == tour.vehicle_id && actual.shift_index == tour.shift_index)
+                .map(|actual| get_tour_deviation(tour, actual))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let delays = tours.iter().flat_map(|tour| tour.activities.iter().map(|a| a.arrival_delay)).collect::<Vec<_>>();
+    let mean_arrival_delay = if delays.is_empty() { 0. } else { delays.iter().sum::<f64>() / delays.len() as f64 };
+    let max_arrival_delay = delays.iter().cloned().fold(0., f64::max);
+    let skipped = tours.iter().map(|tour| tour.skipped.len()).sum();
+
+    Ok(ExecutionDeviation { mean_arrival_delay, max_arrival_delay, skipped, tours })
+}
+
+/// Creates a new problem from the original one with service durations and vehicle profile scales
+/// adjusted using observed deviation. The `weight` parameter in `[0, 1]` range controls how much
+/// observed values affect the original ones: zero keeps the problem as is, one replaces planned
+/// values with observed ones.
+pub fn create_adjusted_problem(problem: &Problem, deviation: &ExecutionDeviation, weight: f64) -> Problem {
+    let weight = weight.clamp(0., 1.);
+    let blend = |ratio: f64| 1. - weight + weight * ratio;
+
+    let service_ratios = deviation
+        .tours
+        .iter()
+        .flat_map(|tour| tour.activities.iter())
+        .filter_map(|activity| {
+            activity.service_ratio.map(|ratio| ((activity.job_id.clone(), activity.activity_type.clone()), ratio))
+        })
+        .fold(HashMap::<_, Vec<f64>>::new(), |mut acc, (key, ratio)| {
+            acc.entry(key).or_default().push(ratio);
+            acc
+        });
+
+    let travel_ratios = deviation
+        .tours
+        .iter()
+        .filter_map(|tour| tour.travel_ratio.map(|ratio| (tour.type_id.clone(), ratio)))
+        .fold(HashMap::<_, Vec<f64>>::new(), |mut acc, (type_id, ratio)| {
+            acc.entry(type_id).or_default().push(ratio);
+            acc
+        });
+
+    let get_mean = |values: &Vec<f64>| values.iter().sum::<f64>() / values.len() as f64;
+
+    let mut problem = problem.clone();
+
+    problem.plan.jobs.iter_mut().for_each(|job| {
+        let job_id = job.id.clone();
+        let adjust_tasks = |tasks: Option<&mut Vec<JobTask>>, activity_type: &str| {
+            if let Some(ratio) = service_ratios.get(&(job_id.clone(), activity_type.to_string())).map(get_mean) {
+                tasks.into_iter().flat_map(|tasks| tasks.iter_mut()).flat_map(|task| task.places.iter_mut()).for_each(
+                    |place| {
+                        place.duration = (place.duration * blend(ratio)).round();
+                    },
+                );
+            }
+        };
+
+        adjust_tasks(job.pickups.as_mut(), "pickup");
+        adjust_tasks(job.deliveries.as_mut(), "delivery");
+        adjust_tasks(job.replacements.as_mut(), "replacement");
+        adjust_tasks(job.services.as_mut(), "service");
+    });
+
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+        if let Some(ratio) = travel_ratios.get(&vehicle.type_id).map(get_mean) {
+            vehicle.profile.scale = Some(vehicle.profile.scale.unwrap_or(1.) * blend(ratio));
+        }
+    });
+
+    problem
+}
+
+struct TimedActivity {
+    job_id: String,
+    activity_type: String,
+    arrival: f64,
+    departure: f64,
+}
+
+fn get_tour_deviation(tour: &Tour, actual: &ActualTour) -> Result<TourDeviation, String> {
+    let planned = get_planned_activities(tour)?;
+
+    let performed = actual
+        .activities
+        .iter()
+        .filter_map(|activity| {
+            activity.time.as_ref().map(|time| {
+                Ok(TimedActivity {
+                    job_id: activity.job_id.clone(),
+                    activity_type: activity.activity_type.clone(),
+                    arrival: parse_time_safe(&time.arrival)?,
+                    departure: parse_time_safe(&time.departure)?,
+                })
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let find_planned = |activity: &TimedActivity| {
+        planned.iter().find(|p| p.job_id == activity.job_id && p.activity_type == activity.activity_type)
+    };
+
+    let activities = performed
+        .iter()
+        .filter_map(|activity| {
+            find_planned(activity).map(|plan| {
+                let planned_service = plan.departure - plan.arrival;
+                let actual_service = activity.departure - activity.arrival;
+
+                ActivityDeviation {
+                    job_id: activity.job_id.clone(),
+                    activity_type: activity.activity_type.clone(),
+                    arrival_delay: activity.arrival - plan.arrival,
+                    service_ratio: if planned_service > 0. { Some(actual_service / planned_service) } else { None },
+                }
+            })
+        })
+        .collect();
+
+    let (planned_travel, actual_travel) =
+        performed.windows(2).fold((0., 0.), |(planned_travel, actual_travel), pair| match pair {
+            [prev, next] => match (find_planned(prev), find_planned(next)) {
+                (Some(plan_prev), Some(plan_next)) => (
+                    planned_travel + (plan_next.arrival - plan_prev.departure).max(0.),
+                    actual_travel + (next.arrival - prev.departure).max(0.),
+                ),
+                _ => (planned_travel, actual_travel),
+            },
+            _ => unreachable!(),
+        });
+
+    let skipped = planned
+        .iter()
+        .filter(|plan| !matches!(plan.activity_type.as_str(), "departure" | "arrival" | "break" | "rest" | "reload"))
+        .filter(|plan| !performed.iter().any(|a| a.job_id == plan.job_id && a.activity_type == plan.activity_type))
+        .map(|plan| plan.job_id.clone())
+        .collect();
+
+    Ok(TourDeviation {
+        vehicle_id: tour.vehicle_id.clone(),
+        type_id: tour.type_id.clone(),
+        shift_index: tour.shift_index,
+        travel_ratio: if planned_travel > 0. { Some(actual_travel / planned_travel) } else { None },
+        activities,
+        skipped,
+    })
+}
+
+fn get_planned_activities(tour: &Tour) -> Result<Vec<TimedActivity>, String> {
+    tour.stops
+        .iter()
+        .flat_map(|stop| {
+            let schedule = stop.schedule();
+            stop.activities().iter().map(move |activity| {
+                let (arrival, departure) = match &activity.time {
+                    Some(interval) => (parse_time_safe(&interval.start)?, parse_time_safe(&interval.end)?),
+                    None => (parse_time_safe(&schedule.arrival)?, parse_time_safe(&schedule.departure)?),
+                };
+
+                Ok(TimedActivity {
+                    job_id: activity.job_id.clone(),
+                    activity_type: activity.activity_type.clone(),
+                    arrival,
+                    departure,
+                })
+            })
+        })
+        .collect()
+}
@@ -5,6 +5,7 @@ mod geo_serializer_test;
 use super::Solution;
 use crate::format::solution::{Activity, PointStop, Tour, UnassignedJob};
 use crate::format::{get_coord_index, get_job_index, CoordIndex, Location};
+use crate::utils::serialize_pretty_with_parallel_array;
 use serde::Serialize;
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -32,6 +33,13 @@ struct FeatureCollection {
     pub features: Vec<Feature>,
 }
 
+/// Mirrors `FeatureCollection` layout without features which are serialized separately.
+#[derive(Serialize)]
+#[serde(tag = "type", rename = "FeatureCollection")]
+struct FeatureCollectionLayout {
+    pub features: Vec<()>,
+}
+
 impl Eq for Geometry {}
 
 impl PartialEq for Geometry {
@@ -79,7 +87,12 @@ pub fn serialize_solution_as_geojson<W: Write>(
 ) -> Result<(), Error> {
     let geo_json = create_geojson_solution(problem, solution)?;
 
-    serde_json::to_writer_pretty(writer, &geo_json).map_err(Error::from)
+    serialize_pretty_with_parallel_array(
+        writer,
+        &FeatureCollectionLayout { features: vec![] },
+        "features",
+        geo_json.features.as_slice(),
+    )
 }
 
 /// Serializes named location list with their color index.
@@ -2,6 +2,7 @@
 #[path = "../../../tests/unit/format/solution/initial_reader_test.rs"]
 mod initial_reader_test;
 
+use crate::format::dimens::*;
 use crate::format::solution::activity_matcher::{try_match_point_job, JobInfo};
 use crate::format::solution::Activity as FormatActivity;
 use crate::format::solution::Stop as FormatStop;
@@ -13,7 +14,7 @@ use hashbrown::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Job, Single};
+use vrp_core::models::problem::{Actor, Job, Multi, Single};
 use vrp_core::models::solution::Tour as CoreTour;
 use vrp_core::models::solution::{Activity, Place, Registry, Route};
 use vrp_core::prelude::*;
@@ -69,8 +70,10 @@ pub fn read_init_solution<R: Read>(
                 .map(|reason| map_reason_code(&reason.code))
                 .ok_or_else(|| format!("cannot get reason for: {:?}", unassigned_job))?;
 
-            added_jobs.insert(job.clone());
-            acc.push((job, code));
+            get_problem_jobs(job).into_iter().for_each(|job| {
+                added_jobs.insert(job.clone());
+                acc.push((job, code));
+            });
 
             Ok(acc)
         },
@@ -110,12 +113,32 @@ fn try_insert_activity(
     Ok(())
 }
 
+/// Returns problem jobs: visits of synchronized job and parts of transfer job are separate jobs.
+fn get_problem_jobs(job: Job) -> Vec<Job> {
+    match &job {
+        Job::Multi(multi) if multi.jobs.iter().any(|single| single.dimens.get_dimen(&JOB_SYNC_VISIT_KEY).is_some()) => {
+            multi.jobs.iter().cloned().map(Job::Single).collect()
+        }
+        Job::Multi(multi)
+            if multi.jobs.iter().any(|single| single.dimens.get_dimen(&JOB_TRANSFER_VISIT_KEY).is_some()) =>
+        {
+            multi.jobs.iter().filter_map(|single| Multi::roots(single)).map(Job::Multi).fold(vec![], |mut acc, job| {
+                if !acc.contains(&job) {
+                    acc.push(job);
+                }
+                acc
+            })
+        }
+        _ => vec![job],
+    }
+}
+
 fn get_actor_key(actor: &Actor) -> ActorKey {
     let dimens = &actor.vehicle.dimens;
 
     let vehicle_id = dimens.get_id().cloned().expect("cannot get vehicle id!");
-    let type_id = dimens.get_value::<String>("type_id").cloned().expect("cannot get type id!");
-    let shift_index = dimens.get_value::<usize>("shift_index").cloned().expect("cannot get shift index!");
+    let type_id = dimens.get_dimen(&VEHICLE_TYPE_ID_KEY).cloned().expect("cannot get type id!");
+    let shift_index = dimens.get_dimen(&SHIFT_INDEX_KEY).cloned().expect("cannot get shift index!");
 
     (vehicle_id, type_id, shift_index)
 }
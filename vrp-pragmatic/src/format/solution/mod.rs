@@ -8,14 +8,25 @@ pub(crate) mod activity_matcher;
 mod geo_serializer;
 pub use self::geo_serializer::*;
 
+mod track_serializer;
+pub use self::track_serializer::*;
+
 mod initial_reader;
 pub use self::initial_reader::read_init_solution;
 
 mod extensions;
 
+mod feedback;
+pub use self::feedback::*;
+
+mod unassigned_analyzer;
+pub use self::unassigned_analyzer::analyze_unassigned;
+
 mod writer;
 pub use self::writer::create_solution;
+pub use self::writer::create_solution_with_settings;
 pub use self::writer::PragmaticSolution;
+pub use self::writer::{RoundingMode, SolutionWriterSettings};
 
 use super::*;
 
@@ -43,6 +54,19 @@ fn map_code_reason(code: i32) -> (&'static str, &'static str) {
         COMPATIBILITY_CONSTRAINT_CODE => {
             ("COMPATIBILITY_CONSTRAINT", "cannot be assigned due to compatibility constraint")
         }
+        TOUR_COST_CONSTRAINT_CODE => {
+            ("MAX_COST_CONSTRAINT", "cannot be assigned due to max cost constraint of vehicle")
+        }
+        TOUR_STOPS_CONSTRAINT_CODE => {
+            ("MAX_STOPS_CONSTRAINT", "cannot be assigned due to max stops constraint of vehicle")
+        }
+        SYNC_CONSTRAINT_CODE => ("SYNC_CONSTRAINT", "cannot be assigned due to sync constraint"),
+        DRIVER_HOURS_CONSTRAINT_CODE => {
+            ("DRIVER_HOURS_CONSTRAINT", "cannot be assigned due to driver hours constraint of vehicle")
+        }
+        RELOAD_CONSTRAINT_CODE => ("RELOAD_CONSTRAINT", "cannot be assigned due to reload constraint"),
+        TRANSFER_CONSTRAINT_CODE => ("TRANSFER_CONSTRAINT", "cannot be assigned due to transfer constraint"),
+        ECHELON_CONSTRAINT_CODE => ("ECHELON_CONSTRAINT", "cannot be assigned due to vehicle echelon constraint"),
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -63,6 +87,13 @@ fn map_reason_code(reason: &str) -> i32 {
         "TOUR_ORDER_CONSTRAINT" => TOUR_ORDER_CONSTRAINT_CODE,
         "GROUP_CONSTRAINT" => GROUP_CONSTRAINT_CODE,
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
+        "MAX_COST_CONSTRAINT" => TOUR_COST_CONSTRAINT_CODE,
+        "MAX_STOPS_CONSTRAINT" => TOUR_STOPS_CONSTRAINT_CODE,
+        "SYNC_CONSTRAINT" => SYNC_CONSTRAINT_CODE,
+        "DRIVER_HOURS_CONSTRAINT" => DRIVER_HOURS_CONSTRAINT_CODE,
+        "RELOAD_CONSTRAINT" => RELOAD_CONSTRAINT_CODE,
+        "TRANSFER_CONSTRAINT" => TRANSFER_CONSTRAINT_CODE,
+        "ECHELON_CONSTRAINT" => ECHELON_CONSTRAINT_CODE,
         _ => -1,
     }
 }
@@ -1,4 +1,5 @@
-use crate::format::{CoordIndex, Location};
+use crate::format::{CoordIndex, Location, PlanningUnits};
+use crate::utils::serialize_pretty_with_parallel_array;
 use crate::{format_time, parse_time};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
@@ -37,6 +38,53 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Total toll. Reported only when toll matrices are specified.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub toll: Option<f64>,
+}
+
+/// Timing statistic with floating point values as they are calculated by the solver.
+#[derive(Clone, Default, Deserialize, Serialize, PartialEq, Debug)]
+pub struct PreciseTiming {
+    /// Driving time.
+    pub driving: f64,
+    /// Serving time.
+    pub serving: f64,
+    /// Waiting time.
+    pub waiting: f64,
+    /// Break time.
+    #[serde(rename(serialize = "break", deserialize = "break"))]
+    pub break_time: f64,
+    /// Commuting time.
+    pub commuting: f64,
+    /// Parking time.
+    pub parking: f64,
+}
+
+/// Represents statistic with floating point values as they are calculated by the solver.
+#[derive(Clone, Default, Deserialize, Serialize, PartialEq, Debug)]
+pub struct PreciseStatistic {
+    /// Total cost.
+    pub cost: f64,
+    /// Total distance.
+    pub distance: f64,
+    /// Total duration.
+    pub duration: f64,
+    /// Timing statistic.
+    pub times: PreciseTiming,
+    /// Total toll.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub toll: Option<f64>,
+}
+
+/// Represents tour compactness: how close jobs of the tour are to each other.
+#[derive(Clone, Default, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourCompactness {
+    /// Average distance between each job and its nearest job of the same tour.
+    pub average_distance: f64,
+    /// Max distance between a job and its nearest job of the same tour.
+    pub max_distance: f64,
 }
 
 /// Represents a schedule.
@@ -48,6 +96,15 @@ pub struct Schedule {
     pub departure: String,
 }
 
+/// Represents tour departure time flexibility.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct DepartureTime {
+    /// Optimized departure time specified in RFC3339 format.
+    pub optimized: String,
+    /// Latest departure time which keeps tour feasible specified in RFC3339 format.
+    pub latest: String,
+}
+
 /// Represents time interval.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 pub struct Interval {
@@ -179,10 +236,25 @@ pub struct PointStop {
     /// Parking time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parking: Option<Interval>,
+    /// Combined service of activities merged into the stop. Present only when stop has more than
+    /// one job activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<StopService>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
 }
 
+/// Represents combined service of several activities performed at the same stop.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct StopService {
+    /// Service start time of the earliest activity in RFC3339 format.
+    pub start: String,
+    /// Service end time of the latest activity in RFC3339 format.
+    pub end: String,
+    /// Total service duration of the stop activities.
+    pub duration: i64,
+}
+
 /// A tour is list of stops with their activities performed by specific vehicle.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -198,6 +270,9 @@ pub struct Tour {
     pub stops: Vec<Stop>,
     /// Tour statistic.
     pub statistic: Statistic,
+    /// Departure time flexibility. Reported only when vehicle shift has departure time window.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub departure: Option<DepartureTime>,
 }
 
 /// Unassigned job reason.
@@ -207,6 +282,22 @@ pub struct UnassignedJobReason {
     pub code: String,
     /// Description.
     pub description: String,
+    /// Vehicle specific details. Reported only by unassigned jobs analysis.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<UnassignedJobDetail>,
+}
+
+/// Vehicle specific details of unassigned job reason.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UnassignedJobDetail {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// A suggested relaxation of the problem which might make job assignable to the vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
 }
 
 /// Unassigned job.
@@ -245,6 +336,60 @@ pub struct Metrics {
     pub speed: f64,
     /// Evolution progress.
     pub evolution: Vec<Generation>,
+    /// Best individual fitness per objective tier: kept only when it is changed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tiers: Vec<TierFitness>,
+    /// Periodic snapshots of the whole population fitness: taken only when enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub snapshots: Vec<PopulationSnapshot>,
+    /// Weights of heuristic operators learned by adaptive heuristic: kept only when they are changed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub operator_weights: Vec<OperatorWeights>,
+}
+
+/// Represents fitness of the best individual per objective tier at given generation.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TierFitness {
+    /// Generation sequence number.
+    pub number: usize,
+    /// Time since evolution started.
+    pub timestamp: f64,
+    /// Fitness values of objectives grouped by tiers.
+    pub fitness: Vec<Vec<f64>>,
+}
+
+/// Represents fitness of all individuals kept by population at given generation.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PopulationSnapshot {
+    /// Generation sequence number.
+    pub number: usize,
+    /// Time since evolution started.
+    pub timestamp: f64,
+    /// Fitness values of all individuals.
+    pub fitness: Vec<Vec<f64>>,
+}
+
+/// Represents weights of heuristic operators at given generation.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OperatorWeights {
+    /// Generation sequence number.
+    pub number: usize,
+    /// Time since evolution started.
+    pub timestamp: f64,
+    /// Weights of operators.
+    pub weights: Vec<OperatorWeight>,
+}
+
+/// Represents weight of a single heuristic operator.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct OperatorWeight {
+    /// Operator name.
+    pub name: String,
+    /// Operator weight.
+    pub weight: f64,
 }
 
 /// Represents information about generation.
@@ -285,10 +430,20 @@ pub struct Population {
 
 /// Contains extra information.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Extras {
     /// A telemetry metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
+    /// Total statistic without rounding. Reported only when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statistic: Option<PreciseStatistic>,
+    /// Tour statistics without rounding in the same order as tours. Reported only when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tour_statistics: Option<Vec<PreciseStatistic>>,
+    /// Tour compactness metrics in the same order as tours. Reported only when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tour_compactness: Option<Vec<TourCompactness>>,
 }
 
 /// A VRP solution.
@@ -312,11 +467,40 @@ pub struct Solution {
     /// An extra information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extras: Option<Extras>,
+
+    /// Units of durations and distances echoed from the problem when specified there.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub units: Option<PlanningUnits>,
 }
 
-/// Serializes solution into json format.
+/// Serializes solution into json format. Tours are serialized in parallel.
 pub fn serialize_solution<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
-    serde_json::to_writer_pretty(writer, solution).map_err(Error::from)
+    let layout = SolutionLayout {
+        statistic: &solution.statistic,
+        tours: vec![],
+        unassigned: solution.unassigned.as_ref(),
+        violations: solution.violations.as_ref(),
+        extras: solution.extras.as_ref(),
+        units: solution.units.as_ref(),
+    };
+
+    serialize_pretty_with_parallel_array(writer, &layout, "tours", solution.tours.as_slice())
+}
+
+/// Mirrors `Solution` layout without tours which are serialized separately.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SolutionLayout<'a> {
+    statistic: &'a Statistic,
+    tours: Vec<()>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unassigned: Option<&'a Vec<UnassignedJob>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    violations: Option<&'a Vec<Violation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    extras: Option<&'a Extras>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    units: Option<&'a PlanningUnits>,
 }
 
 /// Deserializes solution from json format.
@@ -0,0 +1,144 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/track_serializer_test.rs"]
+mod track_serializer_test;
+
+use super::Solution;
+use crate::format::solution::{PointStop, Tour};
+use crate::format::{get_coord_index, Location};
+use std::io::{BufWriter, Error, ErrorKind, Write};
+use vrp_core::models::Problem;
+
+/// Serializes solution into GPX format: each tour is written as a separate track with stops
+/// as track points. Stop arrival time is used as point time, departure time and job ids are
+/// kept in point description.
+pub fn serialize_solution_as_gpx<W: Write>(
+    writer: BufWriter<W>,
+    problem: &Problem,
+    solution: &Solution,
+) -> Result<(), Error> {
+    let mut writer = writer;
+    let tracks = get_tracks(problem, solution)?;
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(writer, r#"<gpx version="1.1" creator="vrp-pragmatic" xmlns="http://www.topografix.com/GPX/1/1">"#)?;
+
+    for (tour, stops) in tracks {
+        writeln!(writer, "  <trk>")?;
+        writeln!(writer, "    <name>{}</name>", escape(get_tour_name(tour).as_str()))?;
+        writeln!(writer, "    <trkseg>")?;
+
+        for (stop, (lng, lat)) in stops {
+            writeln!(writer, r#"      <trkpt lat="{}" lon="{}">"#, lat, lng)?;
+            writeln!(writer, "        <time>{}</time>", escape(stop.time.arrival.as_str()))?;
+            writeln!(writer, "        <name>{}</name>", escape(get_job_ids(stop).as_str()))?;
+            writeln!(writer, "        <desc>{}</desc>", escape(get_stop_description(stop).as_str()))?;
+            writeln!(writer, "      </trkpt>")?;
+        }
+
+        writeln!(writer, "    </trkseg>")?;
+        writeln!(writer, "  </trk>")?;
+    }
+
+    writeln!(writer, "</gpx>")
+}
+
+/// Serializes solution into KML format: each tour is written as a separate folder with route
+/// line and stop placemarks. Stop arrival and departure times are written as placemark time span.
+pub fn serialize_solution_as_kml<W: Write>(
+    writer: BufWriter<W>,
+    problem: &Problem,
+    solution: &Solution,
+) -> Result<(), Error> {
+    let mut writer = writer;
+    let tracks = get_tracks(problem, solution)?;
+
+    writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(writer, r#"<kml xmlns="http://www.opengis.net/kml/2.2">"#)?;
+    writeln!(writer, "  <Document>")?;
+
+    for (tour, stops) in tracks {
+        let tour_name = escape(get_tour_name(tour).as_str());
+        let coordinates = stops.iter().map(|(_, (lng, lat))| format!("{},{}", lng, lat)).collect::<Vec<_>>().join(" ");
+
+        writeln!(writer, "    <Folder>")?;
+        writeln!(writer, "      <name>{}</name>", tour_name)?;
+        writeln!(writer, "      <Placemark>")?;
+        writeln!(writer, "        <name>{}</name>", tour_name)?;
+        writeln!(writer, "        <LineString><coordinates>{}</coordinates></LineString>", coordinates)?;
+        writeln!(writer, "      </Placemark>")?;
+
+        for (stop, (lng, lat)) in stops {
+            writeln!(writer, "      <Placemark>")?;
+            writeln!(writer, "        <name>{}</name>", escape(get_job_ids(stop).as_str()))?;
+            writeln!(
+                writer,
+                "        <TimeSpan><begin>{}</begin><end>{}</end></TimeSpan>",
+                escape(stop.time.arrival.as_str()),
+                escape(stop.time.departure.as_str())
+            )?;
+            writeln!(writer, "        <description>{}</description>", escape(get_stop_description(stop).as_str()))?;
+            writeln!(writer, "        <Point><coordinates>{},{}</coordinates></Point>", lng, lat)?;
+            writeln!(writer, "      </Placemark>")?;
+        }
+
+        writeln!(writer, "    </Folder>")?;
+    }
+
+    writeln!(writer, "  </Document>")?;
+    writeln!(writer, "</kml>")
+}
+
+type Track<'a> = (&'a Tour, Vec<(&'a PointStop, (f64, f64))>);
+
+/// Returns point stops of each tour with their coordinates in (lng, lat) form.
+fn get_tracks<'a>(problem: &Problem, solution: &'a Solution) -> Result<Vec<Track<'a>>, Error> {
+    let (_, has_indices) = get_coord_index(problem).get_used_types();
+    if has_indices {
+        return Err(Error::new(ErrorKind::InvalidData, "gpx or kml cannot be used with location indices"));
+    }
+
+    solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let stops = tour
+                .stops
+                .iter()
+                .filter_map(|stop| stop.as_point())
+                .map(|stop| match &stop.location {
+                    Location::Coordinate { lat, lng } => Ok((stop, (*lng, *lat))),
+                    Location::Reference { .. } => {
+                        Err(Error::new(ErrorKind::InvalidData, "stop location should have coordinates"))
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok((tour, stops))
+        })
+        .collect()
+}
+
+fn get_tour_name(tour: &Tour) -> String {
+    format!("{} (shift {})", tour.vehicle_id, tour.shift_index)
+}
+
+fn get_job_ids(stop: &PointStop) -> String {
+    stop.activities.iter().map(|activity| activity.job_id.as_str()).collect::<Vec<_>>().join(",")
+}
+
+fn get_stop_description(stop: &PointStop) -> String {
+    format!(
+        "arrival: {}, departure: {}, activities: {}",
+        stop.time.arrival,
+        stop.time.departure,
+        stop.activities
+            .iter()
+            .map(|activity| format!("{}:{}", activity.job_id, activity.activity_type))
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/unassigned_analyzer_test.rs"]
+mod unassigned_analyzer_test;
+
+use super::*;
+use crate::format::dimens::*;
+use crate::format::solution::writer::{get_capacity, has_multi_dimensional_capacity};
+use std::cmp::Ordering;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::{get_failed_actors, InsertionContext};
+use vrp_core::models::common::*;
+use vrp_core::models::problem::{Actor, Job as CoreJob};
+use vrp_core::models::{Problem as CoreProblem, Solution as CoreSolution};
+use vrp_core::utils::{compare_floats, parallel_collect, Environment};
+
+/// Analyzes unassigned jobs of the solution: each job is evaluated against every vehicle shift and
+/// a failure reason is reported per vehicle shift together with a suggested relaxation, if it is
+/// possible to estimate it (e.g. time window extension or capacity increase).
+pub fn analyze_unassigned(
+    problem: Arc<CoreProblem>,
+    solution: CoreSolution,
+    environment: Arc<Environment>,
+) -> Vec<UnassignedJob> {
+    let mut insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment);
+
+    // NOTE unassigned jobs with positive code are not evaluated in unmodified routes
+    let jobs = insertion_ctx
+        .solution
+        .unassigned
+        .drain()
+        .map(|(job, _)| job)
+        .filter(|job| job.dimens().get_dimen(&JOB_VEHICLE_ID_KEY).is_none())
+        .collect::<Vec<_>>();
+
+    let mut unassigned = parallel_collect(&jobs, |job| {
+        let mut reasons = get_failed_actors(&insertion_ctx, job)
+            .into_iter()
+            .filter_map(|(actor, code)| {
+                let dimens = &actor.vehicle.dimens;
+                let vehicle_id = dimens.get_id().cloned()?;
+                let shift_index = dimens.get_dimen(&SHIFT_INDEX_KEY).cloned()?;
+                let (code_name, description) = map_code_reason(code);
+
+                Some(UnassignedJobReason {
+                    code: code_name.to_string(),
+                    description: description.to_string(),
+                    details: Some(UnassignedJobDetail {
+                        vehicle_id,
+                        shift_index,
+                        suggestion: get_suggestion(problem.as_ref(), actor.as_ref(), job, code),
+                    }),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        reasons.sort_by(|a, b| match (&a.details, &b.details) {
+            (Some(a), Some(b)) => a.vehicle_id.cmp(&b.vehicle_id).then(a.shift_index.cmp(&b.shift_index)),
+            _ => Ordering::Equal,
+        });
+
+        UnassignedJob { job_id: job.dimens().get_id().expect("job id expected").clone(), reasons }
+    });
+
+    unassigned.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    unassigned
+}
+
+fn get_suggestion(problem: &CoreProblem, actor: &Actor, job: &CoreJob, code: i32) -> Option<String> {
+    match code {
+        CAPACITY_CONSTRAINT_CODE => get_capacity_suggestion(problem, actor, job),
+        TIME_CONSTRAINT_CODE => get_time_suggestion(problem, actor, job),
+        _ => None,
+    }
+}
+
+/// Suggests capacity increase when job demand does not fit into empty vehicle.
+fn get_capacity_suggestion(problem: &CoreProblem, actor: &Actor, job: &CoreJob) -> Option<String> {
+    let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
+
+    let capacity = if is_multi_dimen {
+        actor.vehicle.dimens.get_capacity().cloned()
+    } else {
+        actor.vehicle.dimens.get_capacity().map(|capacity: &SingleDimLoad| MultiDimLoad::new(vec![capacity.value]))
+    }?;
+
+    // NOTE dynamic delivery is the same load as dynamic pickup
+    let singles = match job {
+        CoreJob::Single(single) => vec![single.clone()],
+        CoreJob::Multi(multi) => multi.jobs.clone(),
+    };
+
+    let required = singles
+        .iter()
+        .filter_map(|single| get_capacity(&single.dimens, is_multi_dimen))
+        .fold(MultiDimLoad::default(), |acc, demand| acc + demand.pickup.0 + demand.delivery.0 + demand.pickup.1);
+
+    let capacity = capacity.as_vec();
+    let increase = required
+        .as_vec()
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| (value - capacity.get(idx).cloned().unwrap_or(0)).max(0))
+        .collect::<Vec<_>>();
+
+    if increase.iter().any(|value| *value > 0) {
+        Some(format!("increase vehicle capacity by {:?}", increase))
+    } else {
+        None
+    }
+}
+
+/// Suggests time window or shift end extension when job cannot be reached in time from vehicle start.
+fn get_time_suggestion(problem: &CoreProblem, actor: &Actor, job: &CoreJob) -> Option<String> {
+    let single = job.as_single()?;
+    let start = actor.detail.start.as_ref()?;
+    let start_time = start.time.earliest.unwrap_or(0.);
+    let profile = &actor.vehicle.profile;
+    let transport = problem.transport.as_ref();
+
+    single
+        .places
+        .iter()
+        .filter_map(|place| place.location.map(|location| (location, place)))
+        .filter_map(|(location, place)| {
+            let arrival = start_time + transport.duration_approx(profile, start.location, location);
+            let time_windows = place.times.iter().map(|time| time.to_time_window(start_time)).collect::<Vec<_>>();
+
+            let tw_end = time_windows.iter().map(|tw| tw.end).fold(f64::MIN, f64::max);
+            if !time_windows.is_empty() && compare_floats(arrival, tw_end) == Ordering::Greater {
+                return Some((arrival - tw_end, "extend job time window end"));
+            }
+
+            let service_start = time_windows
+                .iter()
+                .filter(|tw| compare_floats(arrival, tw.end) != Ordering::Greater)
+                .map(|tw| arrival.max(tw.start))
+                .fold(f64::MAX, f64::min)
+                .min(if time_windows.is_empty() { arrival } else { f64::MAX });
+
+            let end = actor.detail.end.as_ref()?;
+            let shift_end = end.time.latest?;
+            let return_time =
+                service_start + place.duration + transport.duration_approx(profile, location, end.location);
+
+            if compare_floats(return_time, shift_end) == Ordering::Greater {
+                Some((return_time - shift_end, "extend vehicle shift end"))
+            } else {
+                None
+            }
+        })
+        .min_by(|(a, _), (b, _)| compare_floats(*a, *b))
+        .map(|(extension, message)| format!("{} by {}s", message, extension.ceil()))
+}
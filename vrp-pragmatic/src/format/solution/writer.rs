@@ -3,122 +3,251 @@
 mod writer_test;
 
 use crate::format::coord_index::CoordIndex;
+use crate::format::dimens::*;
 use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
 use crate::format::*;
 use crate::{format_time, parse_time};
+use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 use std::io::{BufWriter, Write};
-use vrp_core::construction::constraints::route_intervals;
+use vrp_core::construction::constraints::{get_latest_departure_time, is_new_stop, route_intervals};
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Multi, TravelTime};
+use vrp_core::models::problem::{is_depot_activity, Job, Multi, TollTransportCost, TravelTime, Vehicle};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::models::{Problem, Solution};
 use vrp_core::prelude::compare_floats;
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
 use vrp_core::solver::processing::VicinityDimension;
+use vrp_core::utils::VrpError;
 
 type ApiActivity = crate::format::solution::model::Activity;
 type ApiSolution = crate::format::solution::model::Solution;
 type ApiSchedule = crate::format::solution::model::Schedule;
 type ApiMetrics = crate::format::solution::model::Metrics;
 type ApiGeneration = crate::format::solution::model::Generation;
+type ApiTierFitness = crate::format::solution::model::TierFitness;
+type ApiPopulationSnapshot = crate::format::solution::model::PopulationSnapshot;
+type ApiOperatorWeights = crate::format::solution::model::OperatorWeights;
+type ApiOperatorWeight = crate::format::solution::model::OperatorWeight;
 type AppPopulation = crate::format::solution::model::Population;
 type ApiIndividual = crate::format::solution::model::Individual;
 type DomainSchedule = vrp_core::models::common::Schedule;
 type DomainLocation = vrp_core::models::common::Location;
 type DomainExtras = vrp_core::models::Extras;
 
+/// Specifies how distances and durations are converted to integer values of solution statistic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Discards fractional part.
+    Truncate,
+    /// Rounds to the nearest integer, half way cases are rounded away from zero.
+    Round,
+    /// Rounds up to the nearest integer.
+    Ceil,
+    /// Rounds down to the nearest integer.
+    Floor,
+}
+
+impl RoundingMode {
+    /// Converts floating point value to integer using rounding mode.
+    pub fn apply(&self, value: f64) -> i64 {
+        let value = match self {
+            RoundingMode::Truncate => value.trunc(),
+            RoundingMode::Round => value.round(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::Floor => value.floor(),
+        };
+
+        value as i64
+    }
+}
+
+impl Default for RoundingMode {
+    fn default() -> Self {
+        RoundingMode::Truncate
+    }
+}
+
+/// Specifies settings used to write solution in pragmatic format.
+#[derive(Clone, Debug, Default)]
+pub struct SolutionWriterSettings {
+    /// A rounding mode used for distances and durations.
+    pub rounding: RoundingMode,
+    /// If set, statistic without rounding is reported within solution extras.
+    pub precise_statistic: bool,
+    /// If set, compactness of each tour is reported within solution extras.
+    pub compactness: bool,
+}
+
 /// A trait to serialize solution in pragmatic format.
 pub trait PragmaticSolution<W: Write> {
     /// Serializes solution in pragmatic json format.
-    fn write_pragmatic_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+    fn write_pragmatic_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), VrpError> {
+        self.write_pragmatic_json_with_settings(problem, &SolutionWriterSettings::default(), writer)
+    }
+
+    /// Serializes solution in pragmatic json format using given writer settings.
+    fn write_pragmatic_json_with_settings(
+        &self,
+        problem: &Problem,
+        settings: &SolutionWriterSettings,
+        writer: BufWriter<W>,
+    ) -> Result<(), VrpError>;
 
     /// Serializes solution in pragmatic geo json format.
-    fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+    fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), VrpError>;
+
+    /// Serializes solution in GPX format with one track per tour.
+    fn write_gpx(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), VrpError>;
+
+    /// Serializes solution in KML format with one folder per tour.
+    fn write_kml(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), VrpError>;
 }
 
 impl<W: Write> PragmaticSolution<W> for (&Solution, f64) {
-    fn write_pragmatic_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
-        write_pragmatic_json(problem, self.0, None, writer)
+    fn write_pragmatic_json_with_settings(
+        &self,
+        problem: &Problem,
+        settings: &SolutionWriterSettings,
+        writer: BufWriter<W>,
+    ) -> Result<(), VrpError> {
+        write_pragmatic_json(problem, self.0, None, settings, writer)
     }
 
-    fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+    fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), VrpError> {
         write_geo_json(problem, self.0, writer)
     }
+
+    fn write_gpx(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), VrpError> {
+        write_gpx(problem, self.0, writer)
+    }
+
+    fn write_kml(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), VrpError> {
+        write_kml(problem, self.0, writer)
+    }
 }
 
 impl<W: Write> PragmaticSolution<W> for (&Solution, f64, &TelemetryMetrics) {
-    fn write_pragmatic_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
-        write_pragmatic_json(problem, self.0, Some(self.2), writer)
+    fn write_pragmatic_json_with_settings(
+        &self,
+        problem: &Problem,
+        settings: &SolutionWriterSettings,
+        writer: BufWriter<W>,
+    ) -> Result<(), VrpError> {
+        write_pragmatic_json(problem, self.0, Some(self.2), settings, writer)
     }
 
-    fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+    fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), VrpError> {
         write_geo_json(problem, self.0, writer)
     }
+
+    fn write_gpx(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), VrpError> {
+        write_gpx(problem, self.0, writer)
+    }
+
+    fn write_kml(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), VrpError> {
+        write_kml(problem, self.0, writer)
+    }
 }
 
 fn write_pragmatic_json<W: Write>(
     problem: &Problem,
     solution: &Solution,
     metrics: Option<&TelemetryMetrics>,
+    settings: &SolutionWriterSettings,
     writer: BufWriter<W>,
-) -> Result<(), String> {
-    let solution = create_solution(problem, solution, metrics);
-    serialize_solution(writer, &solution).map_err(|err| err.to_string())?;
+) -> Result<(), VrpError> {
+    let solution = create_solution_with_settings(problem, solution, metrics, settings);
+    serialize_solution(writer, &solution).map_err(|err| VrpError::Io(err.to_string()))?;
     Ok(())
 }
 
-fn write_geo_json<W: Write>(problem: &Problem, solution: &Solution, writer: BufWriter<W>) -> Result<(), String> {
+fn write_geo_json<W: Write>(problem: &Problem, solution: &Solution, writer: BufWriter<W>) -> Result<(), VrpError> {
     let solution = create_solution(problem, solution, None);
-    serialize_solution_as_geojson(writer, problem, &solution).map_err(|err| err.to_string())?;
+    serialize_solution_as_geojson(writer, problem, &solution).map_err(|err| VrpError::Io(err.to_string()))?;
     Ok(())
 }
 
+fn write_gpx<W: Write>(problem: &Problem, solution: &Solution, writer: BufWriter<W>) -> Result<(), VrpError> {
+    let solution = create_solution(problem, solution, None);
+    serialize_solution_as_gpx(writer, problem, &solution).map_err(|err| VrpError::Io(err.to_string()))
+}
+
+fn write_kml<W: Write>(problem: &Problem, solution: &Solution, writer: BufWriter<W>) -> Result<(), VrpError> {
+    let solution = create_solution(problem, solution, None);
+    serialize_solution_as_kml(writer, problem, &solution).map_err(|err| VrpError::Io(err.to_string()))
+}
+
 struct Leg {
     pub last_detail: Option<(DomainLocation, Timestamp)>,
     pub load: Option<MultiDimLoad>,
-    pub statistic: Statistic,
+    pub statistic: PreciseStatistic,
 }
 
 impl Leg {
-    fn new(last_detail: Option<(DomainLocation, Timestamp)>, load: Option<MultiDimLoad>, statistic: Statistic) -> Self {
+    fn new(
+        last_detail: Option<(DomainLocation, Timestamp)>,
+        load: Option<MultiDimLoad>,
+        statistic: PreciseStatistic,
+    ) -> Self {
         Self { last_detail, load, statistic }
     }
 
     fn empty() -> Self {
-        Self { last_detail: None, load: None, statistic: Statistic::default() }
+        Self { last_detail: None, load: None, statistic: PreciseStatistic::default() }
     }
 }
 
 /// Creates solution.
 pub fn create_solution(problem: &Problem, solution: &Solution, metrics: Option<&TelemetryMetrics>) -> ApiSolution {
+    create_solution_with_settings(problem, solution, metrics, &SolutionWriterSettings::default())
+}
+
+/// Creates solution using given writer settings.
+pub fn create_solution_with_settings(
+    problem: &Problem,
+    solution: &Solution,
+    metrics: Option<&TelemetryMetrics>,
+    settings: &SolutionWriterSettings,
+) -> ApiSolution {
     let coord_index = get_coord_index(problem);
     let reserved_times_index = get_reserved_times_index(problem);
+    let carried_loads = get_carried_loads(problem, solution);
 
-    let tours = solution
+    let (tours, tour_statistics): (Vec<_>, Vec<_>) = solution
         .routes
         .iter()
-        .map(|r| create_tour(problem, r, coord_index, reserved_times_index))
-        .collect::<Vec<Tour>>();
+        .zip(carried_loads.into_iter())
+        .map(|(r, carried)| create_tour(problem, r, carried, coord_index, reserved_times_index, settings.rounding))
+        .unzip();
 
-    let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
+    let statistic = tours.iter().fold(Statistic::default(), |acc, tour: &Tour| acc + tour.statistic.clone());
 
     let unassigned = create_unassigned(solution);
     let violations = create_violations(solution);
 
-    let extras = create_extras(solution, metrics);
+    let tour_statistics = if settings.precise_statistic { Some(tour_statistics) } else { None };
+    let tour_compactness = if settings.compactness {
+        Some(solution.routes.iter().map(|route| create_tour_compactness(problem, route)).collect())
+    } else {
+        None
+    };
+    let extras = create_extras(solution, metrics, tour_statistics, tour_compactness);
+    let units = get_planning_units(problem.extras.as_ref());
 
-    ApiSolution { statistic, tours, unassigned, violations, extras }
+    ApiSolution { statistic, tours, unassigned, violations, extras, units }
 }
 
 fn create_tour(
     problem: &Problem,
     route: &Route,
+    carried: MultiDimLoad,
     coord_index: &CoordIndex,
     reserved_times_index: &ReservedTimesIndex,
-) -> Tour {
+    rounding: RoundingMode,
+) -> (Tour, PreciseStatistic) {
     // TODO reduce complexity
 
     let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
@@ -127,228 +256,313 @@ fn create_tour(
     let actor = route.actor.as_ref();
     let vehicle = actor.vehicle.as_ref();
     let transport = problem.transport.as_ref();
+    let toll_cost = get_toll_cost(problem.extras.as_ref());
+    let is_carry_load = is_carry_load(vehicle);
+    let has_driver_hours = vehicle.dimens.get_dimen(&VEHICLE_DRIVER_HOURS_KEY).is_some();
+    let reserved_times = get_reserved_times(route, reserved_times_index);
 
     let mut tour = Tour {
         vehicle_id: vehicle.dimens.get_id().unwrap().clone(),
-        type_id: vehicle.dimens.get_value::<String>("type_id").unwrap().to_string(),
-        shift_index: *vehicle.dimens.get_value::<usize>("shift_index").unwrap(),
+        type_id: vehicle.dimens.get_dimen(&VEHICLE_TYPE_ID_KEY).unwrap().to_string(),
+        shift_index: *vehicle.dimens.get_dimen(&SHIFT_INDEX_KEY).unwrap(),
         stops: vec![],
         statistic: Statistic::default(),
+        departure: create_departure_time(route),
     };
 
     let intervals = route_intervals(route, Box::new(|a| get_activity_type(a).map_or(false, |t| t == "reload")));
 
-    let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
-        let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
-            (leg.load.unwrap_or_default(), MultiDimLoad::default()),
-            |acc, activity| {
-                let (delivery, pickup) = activity
-                    .job
-                    .as_ref()
-                    .and_then(|job| get_capacity(&job.dimens, is_multi_dimen).map(|d| (d.delivery.0, d.pickup.0)))
-                    .unwrap_or((MultiDimLoad::default(), MultiDimLoad::default()));
-                (acc.0 + delivery, acc.1 + pickup)
-            },
-        );
+    let mut leg = intervals.into_iter().fold(
+        Leg::new(None, Some(carried), PreciseStatistic::default()),
+        |leg, (start_idx, end_idx)| {
+            let is_first_interval = start_idx == 0;
+            let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
+                (leg.load.unwrap_or_default(), MultiDimLoad::default()),
+                |acc, activity| {
+                    let (delivery, pickup) = activity
+                        .job
+                        .as_ref()
+                        .and_then(|job| get_capacity(&job.dimens, is_multi_dimen).map(|d| (d.delivery.0, d.pickup.0)))
+                        .unwrap_or((MultiDimLoad::default(), MultiDimLoad::default()));
+                    (acc.0 + delivery, acc.1 + pickup)
+                },
+            );
+
+            let (start_idx, start) = if start_idx == 0 {
+                let start = route.tour.start().unwrap();
+                let (has_dispatch, is_same_location) = route.tour.get(1).map_or((false, false), |activity| {
+                    let has_dispatch = activity
+                        .retrieve_job()
+                        .and_then(|job| job.dimens().get_dimen(&JOB_TYPE_KEY).cloned())
+                        .map_or(false, |job_type| job_type == "dispatch");
+
+                    let is_same_location = start.place.location == activity.place.location;
+
+                    (has_dispatch, is_same_location)
+                });
 
-        let (start_idx, start) = if start_idx == 0 {
-            let start = route.tour.start().unwrap();
-            let (has_dispatch, is_same_location) = route.tour.get(1).map_or((false, false), |activity| {
-                let has_dispatch = activity
-                    .retrieve_job()
-                    .and_then(|job| job.dimens().get_value::<String>("type").cloned())
-                    .map_or(false, |job_type| job_type == "dispatch");
+                tour.stops.push(Stop::Point(PointStop {
+                    location: coord_index.get_by_idx(start.place.location).unwrap(),
+                    time: format_schedule(&start.schedule),
+                    load: if has_dispatch { vec![0] } else { start_delivery.as_vec() },
+                    distance: 0,
+                    activities: vec![ApiActivity {
+                        job_id: "departure".to_string(),
+                        activity_type: "departure".to_string(),
+                        location: None,
+                        time: if is_same_location {
+                            Some(Interval {
+                                start: format_time(start.schedule.arrival),
+                                end: format_time(start.schedule.departure),
+                            })
+                        } else {
+                            None
+                        },
+                        job_tag: None,
+                        commute: None,
+                    }],
+                    parking: None,
+                    service: None,
+                }));
+                (start_idx + 1, start)
+            } else {
+                (start_idx, route.tour.get(start_idx - 1).unwrap())
+            };
+
+            let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
+                Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
+                |leg, act| {
+                    let activity_type = get_activity_type(act).cloned();
+                    let (prev_location, prev_departure) = leg.last_detail.unwrap();
+                    let prev_load = if activity_type.is_some() || is_carry_load {
+                        leg.load.unwrap()
+                    } else {
+                        // NOTE arrival must have zero load unless load is carried to the next shift
+                        let dimen_size = leg.load.unwrap().size;
+                        MultiDimLoad::new(vec![0; dimen_size])
+                    };
 
-                let is_same_location = start.place.location == activity.place.location;
+                    let activity_type = activity_type.unwrap_or_else(|| "arrival".to_string());
+                    let is_break = activity_type == "break";
 
-                (has_dispatch, is_same_location)
-            });
+                    let job_tag = act.job.as_ref().and_then(|single| {
+                        get_job_tag(single, (act.place.location, (act.place.time.clone(), start.schedule.departure)))
+                            .cloned()
+                    });
+                    let job_id = match activity_type.as_str() {
+                        "pickup" | "delivery" | "replacement" | "service" => {
+                            let single = act.job.as_ref().unwrap();
+                            let id = single.dimens.get_id().cloned();
+                            id.unwrap_or_else(|| Multi::roots(single).unwrap().dimens.get_id().unwrap().clone())
+                        }
+                        _ => activity_type.clone(),
+                    };
 
-            tour.stops.push(Stop::Point(PointStop {
-                location: coord_index.get_by_idx(start.place.location).unwrap(),
-                time: format_schedule(&start.schedule),
-                load: if has_dispatch { vec![0] } else { start_delivery.as_vec() },
-                distance: 0,
-                activities: vec![ApiActivity {
-                    job_id: "departure".to_string(),
-                    activity_type: "departure".to_string(),
-                    location: None,
-                    time: if is_same_location {
-                        Some(Interval {
-                            start: format_time(start.schedule.arrival),
-                            end: format_time(start.schedule.departure),
-                        })
+                    let commute = act.commute.clone().unwrap_or_default();
+                    let commuting = commute.duration();
+
+                    // NOTE: vehicle stops at the place or at the location where commute to the place starts
+                    let stop_location =
+                        if commute.is_zero_distance() { act.place.location } else { commute.forward.location };
+                    let is_new_stop = is_new_stop(prev_location, act);
+
+                    let (driving, transport_cost) = if commute.is_zero_distance() {
+                        // NOTE: use original cost traits to adapt time-based costs (except waiting/commuting)
+                        let prev_departure = TravelTime::Departure(prev_departure);
+                        let duration = transport.duration(route, prev_location, act.place.location, prev_departure);
+                        let transport_cost = transport.cost(route, prev_location, act.place.location, prev_departure);
+                        (duration, transport_cost)
+                    } else if is_new_stop {
+                        // NOTE: drive to the commute location first, e.g. parking place of walk-in job
+                        let prev_departure = TravelTime::Departure(prev_departure);
+                        let duration = transport.duration(route, prev_location, stop_location, prev_departure);
+                        let transport_cost = transport.cost(route, prev_location, stop_location, prev_departure);
+                        (duration, transport_cost + commuting * vehicle.costs.per_service_time)
                     } else {
-                        None
-                    },
-                    job_tag: None,
-                    commute: None,
-                }],
-                parking: None,
-            }));
-            (start_idx + 1, start)
-        } else {
-            (start_idx, route.tour.get(start_idx - 1).unwrap())
-        };
+                        // NOTE: no need to drive in case of non-zero commute, this goes to commuting time
+                        (0., commuting * vehicle.costs.per_service_time)
+                    };
 
-        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
-            Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
-            |leg, act| {
-                let activity_type = get_activity_type(act).cloned();
-                let (prev_location, prev_departure) = leg.last_detail.unwrap();
-                let prev_load = if activity_type.is_some() {
-                    leg.load.unwrap()
-                } else {
-                    // NOTE arrival must have zero load
-                    let dimen_size = leg.load.unwrap().size;
-                    MultiDimLoad::new(vec![0; dimen_size])
-                };
+                    // NOTE two clusters at the same stop location
+                    let parking = match (
+                        prev_location == act.place.location,
+                        act.commute.is_some(),
+                        commute.is_zero_distance(),
+                    ) {
+                        (false, true, true) => parking,
+                        _ => 0.,
+                    };
 
-                let activity_type = activity_type.unwrap_or_else(|| "arrival".to_string());
-                let is_break = activity_type == "break";
+                    // NOTE: parking time is a part of service time, so vehicle waits for time window start before parking
+                    let parking_start = if parking > 0. {
+                        act.schedule.arrival.max(act.place.time.start)
+                    } else {
+                        act.schedule.arrival
+                    };
+                    let activity_arrival = parking_start + parking + commute.forward.duration;
+                    let service_start = activity_arrival.max(act.place.time.start);
+                    let serving = problem.activity.service_duration(route, act) - parking;
 
-                let job_tag = act.job.as_ref().and_then(|single| {
-                    get_job_tag(single, (act.place.location, (act.place.time.clone(), start.schedule.departure)))
-                        .cloned()
-                });
-                let job_id = match activity_type.as_str() {
-                    "pickup" | "delivery" | "replacement" | "service" => {
-                        let single = act.job.as_ref().unwrap();
-                        let id = single.dimens.get_id().cloned();
-                        id.unwrap_or_else(|| Multi::roots(single).unwrap().dimens.get_id().unwrap().clone())
-                    }
-                    _ => activity_type.clone(),
-                };
+                    // NOTE: service is not interrupted by such reserved time, it starts when reserved time is over
+                    let service_time = TimeWindow::new(activity_arrival, service_start + serving);
+                    let reserved_time = reserved_times
+                        .iter()
+                        .filter(|(time, can_interrupt)| {
+                            !*can_interrupt
+                                && compare_floats(service_time.start, time.end) == Ordering::Less
+                                && compare_floats(time.start, service_time.end) == Ordering::Less
+                        })
+                        .map(|(time, _)| time)
+                        .next();
+                    let (activity_start, service_start, reserved) =
+                        reserved_time.map_or((activity_arrival, service_start, 0.), |time| {
+                            let reserved = time.end - time.start.max(activity_arrival);
+                            (activity_arrival.max(time.end), service_start.max(time.end), reserved)
+                        });
+
+                    let waiting = service_start - activity_arrival - reserved + parking_start - act.schedule.arrival;
+                    let service_end = service_start + serving;
+                    let activity_departure = service_end;
+
+                    // TODO: add better support of time based activity costs
+                    let serving_cost = problem.activity.cost(route, act, service_start);
+                    let waiting_rate = vehicle.costs.waiting_rate(is_depot_activity(route.actor.as_ref(), act));
+                    let total_cost = serving_cost + transport_cost + waiting * waiting_rate;
+
+                    let toll = toll_cost.map(|toll_cost| {
+                        leg.statistic.toll.unwrap_or(0.) + toll_cost.leg_toll(&vehicle.profile, prev_location, act)
+                    });
 
-                let commute = act.commute.clone().unwrap_or_default();
-                let commuting = commute.duration();
+                    let distance = if !commute.is_zero_distance() && is_new_stop {
+                        let travel_time = TravelTime::Departure(prev_departure);
+                        leg.statistic.distance + transport.distance(route, prev_location, stop_location, travel_time)
+                    } else {
+                        let location_distance = transport.distance(
+                            route,
+                            prev_location,
+                            act.place.location,
+                            TravelTime::Departure(prev_departure),
+                        );
+                        leg.statistic.distance + location_distance - commute.forward.distance
+                    };
 
-                let (driving, transport_cost) = if commute.is_zero_distance() {
-                    // NOTE: use original cost traits to adapt time-based costs (except waiting/commuting)
-                    let prev_departure = TravelTime::Departure(prev_departure);
-                    let duration = transport.duration(route, prev_location, act.place.location, prev_departure);
-                    let transport_cost = transport.cost(route, prev_location, act.place.location, prev_departure);
-                    (duration, transport_cost)
-                } else {
-                    // NOTE: no need to drive in case of non-zero commute, this goes to commuting time
-                    (0., commuting * vehicle.costs.per_service_time)
-                };
+                    if is_new_stop {
+                        tour.stops.push(Stop::Point(PointStop {
+                            location: coord_index.get_by_idx(stop_location).unwrap(),
+                            time: format_schedule(&act.schedule),
+                            load: prev_load.as_vec(),
+                            distance: rounding.apply(distance),
+                            parking: if parking > 0. {
+                                Some(Interval {
+                                    start: format_time(parking_start),
+                                    end: format_time(parking_start + parking),
+                                })
+                            } else {
+                                None
+                            },
+                            service: None,
+                            activities: vec![],
+                        }));
+                    }
 
-                // NOTE two clusters at the same stop location
-                let parking =
-                    match (prev_location == act.place.location, act.commute.is_some(), commute.is_zero_distance()) {
-                        (false, true, true) => parking,
-                        _ => 0.,
-                    };
+                    let load = calculate_load(prev_load, act, is_multi_dimen);
 
-                let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
-                let service_start = activity_arrival.max(act.place.time.start);
-                let waiting = service_start - activity_arrival;
-                let serving = act.place.duration - parking;
-                let service_end = service_start + serving;
-                let activity_departure = service_end;
-
-                // TODO: add better support of time based activity costs
-                let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
-
-                let location_distance =
-                    transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
-                        as i64;
-                let distance = leg.statistic.distance + location_distance - commute.forward.distance as i64;
-
-                let is_new_stop = match (act.commute.as_ref(), prev_location == act.place.location) {
-                    (Some(commute), false) if commute.is_zero_distance() => true,
-                    (Some(_), _) => false,
-                    (None, is_same_location) => !is_same_location,
-                };
+                    let last = tour.stops.len() - 1;
+                    let mut last = match tour.stops.get_mut(last).unwrap() {
+                        Stop::Point(point) => point,
+                        Stop::Transit(_) => unreachable!(),
+                    };
 
-                if is_new_stop {
-                    tour.stops.push(Stop::Point(PointStop {
-                        location: coord_index.get_by_idx(act.place.location).unwrap(),
-                        time: format_schedule(&act.schedule),
-                        load: prev_load.as_vec(),
-                        distance,
-                        parking: if parking > 0. {
-                            Some(Interval {
-                                start: format_time(act.schedule.arrival),
-                                end: format_time(act.schedule.arrival + parking),
-                            })
-                        } else {
+                    last.time.departure = format_time(act.schedule.departure);
+                    last.load = load.as_vec();
+                    last.activities.push(ApiActivity {
+                        job_id,
+                        activity_type: activity_type.clone(),
+                        location: if !is_new_stop && activity_type == "dispatch" {
                             None
+                        } else {
+                            Some(coord_index.get_by_idx(act.place.location).unwrap())
                         },
-                        activities: vec![],
-                    }));
-                }
+                        time: Some(Interval {
+                            start: format_time(activity_start),
+                            end: format_time(activity_departure),
+                        }),
+                        job_tag,
+                        commute: act.commute.as_ref().map(|commute| {
+                            Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)
+                        }),
+                    });
 
-                let load = calculate_load(prev_load, act, is_multi_dimen);
+                    // NOTE rest is taken after activity when driving time exceeds driver hours limits
+                    let rest_start = problem.activity.estimate_departure(route, act, act.schedule.arrival);
+                    let rest = if has_driver_hours { (act.schedule.departure - rest_start).max(0.) } else { 0. };
+                    if compare_floats(rest, 0.) == Ordering::Greater {
+                        last.activities.push(ApiActivity {
+                            job_id: DRIVER_REST_JOB_ID.to_string(),
+                            activity_type: "rest".to_string(),
+                            location: Some(coord_index.get_by_idx(act.place.location).unwrap()),
+                            time: Some(Interval {
+                                start: format_time(rest_start),
+                                end: format_time(act.schedule.departure),
+                            }),
+                            job_tag: None,
+                            commute: None,
+                        });
+                    }
 
-                let last = tour.stops.len() - 1;
-                let mut last = match tour.stops.get_mut(last).unwrap() {
-                    Stop::Point(point) => point,
-                    Stop::Transit(_) => unreachable!(),
-                };
+                    // NOTE detect when vehicle returns after activity to stop point
+                    let end_location = commute.departure_location(act.place.location);
+
+                    Leg {
+                        last_detail: Some((end_location, act.schedule.departure)),
+                        statistic: PreciseStatistic {
+                            cost: leg.statistic.cost + total_cost + rest * vehicle.costs.per_service_time,
+                            distance,
+                            duration: leg.statistic.duration + act.schedule.departure - prev_departure,
+                            times: PreciseTiming {
+                                driving: leg.statistic.times.driving + driving,
+                                serving: leg.statistic.times.serving + (if is_break { 0. } else { serving }),
+                                waiting: leg.statistic.times.waiting + waiting,
+                                break_time: leg.statistic.times.break_time
+                                    + (if is_break { serving } else { 0. })
+                                    + rest,
+                                commuting: leg.statistic.times.commuting + commuting,
+                                parking: leg.statistic.times.parking + parking,
+                            },
+                            toll,
+                        },
+                        load: Some(load),
+                    }
+                },
+            );
 
-                last.time.departure = format_time(act.schedule.departure);
-                last.load = load.as_vec();
-                last.activities.push(ApiActivity {
-                    job_id,
-                    activity_type: activity_type.clone(),
-                    location: if !is_new_stop && activity_type == "dispatch" {
-                        None
-                    } else {
-                        Some(coord_index.get_by_idx(act.place.location).unwrap())
-                    },
-                    time: Some(Interval { start: format_time(activity_arrival), end: format_time(activity_departure) }),
-                    job_tag,
-                    commute: act
-                        .commute
-                        .as_ref()
-                        .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
-                });
+            // NOTE carried load is unloaded at the first reload
+            let unloaded = if is_first_interval { end_pickup + carried } else { end_pickup };
+            leg.load = Some(leg.load.unwrap() - unloaded);
 
-                // NOTE detect when vehicle returns after activity to stop point
-                let end_location = if commute.backward.is_zero_distance() {
-                    act.place.location
-                } else {
-                    tour.stops
-                        .last()
-                        .and_then(|stop| stop.as_point())
-                        .and_then(|stop| coord_index.get_by_loc(&stop.location))
-                        .expect("expect to have at least one stop")
-                };
+            leg
+        },
+    );
 
-                Leg {
-                    last_detail: Some((end_location, act.schedule.departure)),
-                    statistic: Statistic {
-                        cost: leg.statistic.cost + total_cost,
-                        distance,
-                        duration: leg.statistic.duration + act.schedule.departure as i64 - prev_departure as i64,
-                        times: Timing {
-                            driving: leg.statistic.times.driving + driving as i64,
-                            serving: leg.statistic.times.serving + (if is_break { 0 } else { serving as i64 }),
-                            waiting: leg.statistic.times.waiting + waiting as i64,
-                            break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
-                            commuting: leg.statistic.times.commuting + commuting as i64,
-                            parking: leg.statistic.times.parking + parking as i64,
-                        },
-                    },
-                    load: Some(load),
-                }
-            },
-        );
+    leg.statistic.cost += vehicle.costs.fixed;
 
-        leg.load = Some(leg.load.unwrap() - end_pickup);
+    // NOTE loading at departure is counted as serving time
+    if let Some(start) = route.tour.start() {
+        leg.statistic.cost += problem.activity.cost(route, start, start.schedule.arrival);
+        leg.statistic.duration += start.place.duration;
+        leg.statistic.times.serving += start.place.duration;
+    }
 
-        leg
-    });
+    insert_reserved_times(route, &mut tour, &mut leg.statistic, reserved_times);
+    insert_stop_services(&mut tour, rounding);
 
-    leg.statistic.cost += vehicle.costs.fixed;
-    tour.statistic = leg.statistic;
+    if let Some(overtime) = vehicle.dimens.get_dimen(&VEHICLE_OVERTIME_KEY) {
+        leg.statistic.cost += overtime.get_cost(leg.statistic.duration);
+    }
 
-    insert_reserved_times(route, &mut tour, reserved_times_index);
+    tour.statistic = round_statistic(&leg.statistic, rounding);
 
-    // NOTE remove redundant info
+    // NOTE remove redundant info, activity with commute has location and time different from the stop
     tour.stops
         .iter_mut()
         .filter(|stop| stop.activities().len() == 1)
@@ -356,18 +570,101 @@ fn create_tour(
             Stop::Point(point) => point.activities.iter_mut(),
             Stop::Transit(transit) => transit.activities.iter_mut(),
         })
+        .filter(|activity| {
+            activity.commute.as_ref().map_or(true, |commute| commute.forward.is_none() && commute.backward.is_none())
+        })
         .for_each(|activity| {
             activity.location = None;
             activity.time = None;
         });
 
     tour.vehicle_id = vehicle.dimens.get_id().unwrap().clone();
-    tour.type_id = vehicle.dimens.get_value::<String>("type_id").unwrap().clone();
+    tour.type_id = vehicle.dimens.get_dimen(&VEHICLE_TYPE_ID_KEY).unwrap().clone();
 
-    tour
+    (tour, leg.statistic)
 }
 
-fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &ReservedTimesIndex) {
+/// Aggregates service intervals of job activities merged into one stop: total duration is a length
+/// of intervals union. Break, reload and dispatch activities as well as jobs visited from parking
+/// (vicinity clustering) are not counted.
+fn insert_stop_services(tour: &mut Tour, rounding: RoundingMode) {
+    tour.stops
+        .iter_mut()
+        .filter_map(|stop| match stop {
+            Stop::Point(point) => Some(point),
+            Stop::Transit(_) => None,
+        })
+        .for_each(|point| {
+            let mut intervals = point
+                .activities
+                .iter()
+                .filter(|activity| {
+                    activity.commute.is_none()
+                        && !matches!(
+                            activity.activity_type.as_str(),
+                            "departure" | "arrival" | "break" | "reload" | "dispatch"
+                        )
+                })
+                .filter_map(|activity| activity.time.as_ref())
+                .map(|time| (parse_time(&time.start), parse_time(&time.end)))
+                .collect::<Vec<_>>();
+
+            if intervals.len() > 1 {
+                intervals.sort_by(|(a, _), (b, _)| compare_floats(*a, *b));
+
+                let start = intervals.first().map(|(start, _)| *start).unwrap();
+                let end = intervals.iter().map(|(_, end)| *end).fold(f64::MIN, f64::max);
+
+                // NOTE activity intervals can overlap, so overlapping time is counted once
+                let (duration, _) = intervals.iter().fold((0., f64::MIN), |(duration, covered), &(start, end)| {
+                    (duration + (end - start.max(covered)).max(0.), covered.max(end))
+                });
+
+                point.service = Some(StopService {
+                    start: format_time(start),
+                    end: format_time(end),
+                    duration: rounding.apply(duration),
+                });
+            }
+        });
+}
+
+fn round_statistic(statistic: &PreciseStatistic, rounding: RoundingMode) -> Statistic {
+    Statistic {
+        cost: statistic.cost,
+        distance: rounding.apply(statistic.distance),
+        duration: rounding.apply(statistic.duration),
+        times: Timing {
+            driving: rounding.apply(statistic.times.driving),
+            serving: rounding.apply(statistic.times.serving),
+            waiting: rounding.apply(statistic.times.waiting),
+            break_time: rounding.apply(statistic.times.break_time),
+            commuting: rounding.apply(statistic.times.commuting),
+            parking: rounding.apply(statistic.times.parking),
+        },
+        toll: statistic.toll,
+    }
+}
+
+fn create_departure_time(route: &Route) -> Option<DepartureTime> {
+    let (earliest, latest) =
+        route.actor.detail.start.as_ref().and_then(|start| start.time.earliest.zip(start.time.latest))?;
+
+    // NOTE departure can be advanced only when shift has departure time window
+    if compare_floats(earliest, latest) != Ordering::Less {
+        return None;
+    }
+
+    let start = route.tour.start()?;
+
+    get_latest_departure_time(route).map(|latest| DepartureTime {
+        optimized: format_time(start.schedule.departure),
+        latest: format_time(latest.max(start.schedule.departure)),
+    })
+}
+
+/// Returns reserved times of the route's actor within its shift time with interruption flag.
+fn get_reserved_times(route: &Route, reserved_times_index: &ReservedTimesIndex) -> Vec<(TimeWindow, bool)> {
     let shift_time = route
         .tour
         .start()
@@ -379,57 +676,69 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
         .get(&route.actor)
         .iter()
         .flat_map(|times| times.iter())
-        .map(|time| match time {
-            TimeSpan::Offset(offset) => TimeWindow::new(offset.start + shift_time.start, offset.end + shift_time.start),
-            TimeSpan::Window(tw) => tw.clone(),
+        .map(|span| {
+            let time = match &span.time {
+                TimeSpan::Offset(offset) => {
+                    TimeWindow::new(offset.start + shift_time.start, offset.end + shift_time.start)
+                }
+                TimeSpan::Window(tw) => tw.clone(),
+            };
+
+            (time, span.can_interrupt)
         })
-        .filter(|time| shift_time.intersects(time))
-        .for_each(|reserved_time| {
-            // NOTE scan and insert new stop if necessary
-            if let Some((leg_idx, load)) = tour
-                .stops
-                .windows(2)
-                .enumerate()
-                .filter_map(|(leg_idx, stops)| {
-                    if let &[prev, next] = &stops {
-                        let travel_tw = TimeWindow::new(
-                            parse_time(&prev.schedule().departure),
-                            parse_time(&next.schedule().arrival),
-                        );
+        .filter(|(time, _)| shift_time.intersects(time))
+        .collect()
+}
 
-                        if compare_floats(travel_tw.start, reserved_time.end) == Ordering::Less
-                            && compare_floats(reserved_time.start, travel_tw.end) == Ordering::Less
-                        {
-                            return Some((leg_idx, prev.load().clone()));
-                        }
+fn insert_reserved_times(
+    route: &Route,
+    tour: &mut Tour,
+    statistic: &mut PreciseStatistic,
+    reserved_times: Vec<(TimeWindow, bool)>,
+) {
+    reserved_times.into_iter().for_each(|(reserved_time, can_interrupt)| {
+        // NOTE scan and insert new stop if necessary
+        if let Some((leg_idx, load)) = tour
+            .stops
+            .windows(2)
+            .enumerate()
+            .filter_map(|(leg_idx, stops)| {
+                if let &[prev, next] = &stops {
+                    let travel_tw =
+                        TimeWindow::new(parse_time(&prev.schedule().departure), parse_time(&next.schedule().arrival));
+
+                    if compare_floats(travel_tw.start, reserved_time.end) == Ordering::Less
+                        && compare_floats(reserved_time.start, travel_tw.end) == Ordering::Less
+                    {
+                        return Some((leg_idx, prev.load().clone()));
                     }
+                }
 
-                    None
-                })
-                .next()
-            {
-                tour.stops.insert(
-                    leg_idx + 1,
-                    Stop::Transit(TransitStop {
-                        time: ApiSchedule {
-                            arrival: format_time(reserved_time.start),
-                            departure: format_time(reserved_time.end),
-                        },
-                        load,
-                        activities: vec![],
-                    }),
-                )
-            }
-
-            let break_time = reserved_time.duration() as i64;
-
-            // NOTE insert activity
-            tour.stops.iter_mut().for_each(|stop| {
-                let stop_tw =
-                    TimeWindow::new(parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure));
-                if stop_tw.intersects(&reserved_time) {
-                    let idx = stop
-                        .activities()
+                None
+            })
+            .next()
+        {
+            tour.stops.insert(
+                leg_idx + 1,
+                Stop::Transit(TransitStop {
+                    time: ApiSchedule {
+                        arrival: format_time(reserved_time.start),
+                        departure: format_time(reserved_time.end),
+                    },
+                    load,
+                    activities: vec![],
+                }),
+            )
+        }
+
+        let break_time = reserved_time.duration();
+
+        // NOTE insert activity
+        tour.stops.iter_mut().for_each(|stop| {
+            let stop_tw = TimeWindow::new(parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure));
+            if stop_tw.intersects(&reserved_time) {
+                let idx = if can_interrupt {
+                    stop.activities()
                         .iter()
                         .enumerate()
                         .filter_map(|(activity_idx, activity)| {
@@ -444,60 +753,122 @@ fn insert_reserved_times(route: &Route, tour: &mut Tour, reserved_times_index: &
                             }
                         })
                         .next()
-                        .unwrap_or(0);
+                        .unwrap_or(0)
+                } else {
+                    // NOTE break is taken between activities: insert it before the first one started after
+                    stop.activities()
+                        .iter()
+                        .filter(|activity| {
+                            let start = activity.time.as_ref().map_or(stop_tw.start, |time| parse_time(&time.start));
+                            compare_floats(start, reserved_time.start) == Ordering::Less
+                        })
+                        .count()
+                };
 
-                    // TODO costs may not match?
-                    let activities = match stop {
-                        Stop::Point(point) => {
-                            tour.statistic.cost += break_time as f64 * route.actor.vehicle.costs.per_service_time;
-                            &mut point.activities
-                        }
-                        Stop::Transit(transit) => {
-                            tour.statistic.times.driving -= break_time;
-                            &mut transit.activities
-                        }
-                    };
+                // TODO costs may not match?
+                let activities = match stop {
+                    Stop::Point(point) => {
+                        statistic.cost += break_time * route.actor.vehicle.costs.per_service_time;
+                        &mut point.activities
+                    }
+                    Stop::Transit(transit) => {
+                        statistic.times.driving -= break_time;
+                        &mut transit.activities
+                    }
+                };
 
-                    activities.insert(
-                        idx,
-                        ApiActivity {
-                            job_id: "break".to_string(),
-                            activity_type: "break".to_string(),
-                            location: None,
-                            time: Some(Interval {
-                                start: format_time(reserved_time.start),
-                                end: format_time(reserved_time.end),
-                            }),
-                            job_tag: None,
-                            commute: None,
-                        },
-                    );
+                activities.insert(
+                    idx,
+                    ApiActivity {
+                        job_id: "break".to_string(),
+                        activity_type: "break".to_string(),
+                        location: None,
+                        time: Some(Interval {
+                            start: format_time(reserved_time.start),
+                            end: format_time(reserved_time.end),
+                        }),
+                        job_tag: None,
+                        commute: None,
+                    },
+                );
 
-                    let activity_count = activities.len() - 1;
+                let activity_count = if can_interrupt { activities.len() - 1 } else { 0 };
 
-                    activities.iter_mut().take(activity_count).for_each(|activity| {
-                        if let Some(time) = &mut activity.time {
-                            let start = parse_time(&time.start);
-                            let end = parse_time(&time.end);
-                            let overlap = TimeWindow::new(start, end).overlapping(&reserved_time);
+                activities.iter_mut().take(activity_count).for_each(|activity| {
+                    if let Some(time) = &mut activity.time {
+                        let start = parse_time(&time.start);
+                        let end = parse_time(&time.end);
+                        let overlap = TimeWindow::new(start, end).overlapping(&reserved_time);
 
-                            if let Some(overlap) = overlap {
-                                let extra_time = reserved_time.end - overlap.end + overlap.duration();
-                                time.end = format_time(end + extra_time);
-                            }
+                        if let Some(overlap) = overlap {
+                            let extra_time = reserved_time.end - overlap.end + overlap.duration();
+                            time.end = format_time(end + extra_time);
                         }
-                    });
-                }
-            });
-
-            tour.statistic.times.break_time += break_time;
+                    }
+                });
+            }
         });
+
+        statistic.times.break_time += break_time;
+    });
 }
 
 fn format_schedule(schedule: &DomainSchedule) -> ApiSchedule {
     ApiSchedule { arrival: format_time(schedule.arrival), departure: format_time(schedule.departure) }
 }
 
+fn is_carry_load(vehicle: &Vehicle) -> bool {
+    vehicle.dimens.get_dimen(&VEHICLE_CARRY_LOAD_KEY).cloned().unwrap_or(false)
+}
+
+/// Returns load carried into each route from the previous shift of the same vehicle.
+fn get_carried_loads(problem: &Problem, solution: &Solution) -> Vec<MultiDimLoad> {
+    let is_multi_dimen = has_multi_dimensional_capacity(problem.extras.as_ref());
+    let mut carried_loads = vec![MultiDimLoad::default(); solution.routes.len()];
+
+    solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route)| is_carry_load(route.actor.vehicle.as_ref()))
+        .fold(HashMap::<&String, Vec<(usize, usize)>>::new(), |mut acc, (idx, route)| {
+            let dimens = &route.actor.vehicle.dimens;
+            let shift_index = *dimens.get_dimen(&SHIFT_INDEX_KEY).unwrap();
+            acc.entry(dimens.get_id().unwrap()).or_insert_with(Vec::new).push((shift_index, idx));
+            acc
+        })
+        .into_iter()
+        .for_each(|(_, mut sequence)| {
+            sequence.sort();
+            sequence.into_iter().fold(MultiDimLoad::default(), |carried, (_, idx)| {
+                carried_loads[idx] = carried;
+
+                let route = &solution.routes[idx];
+                let intervals =
+                    route_intervals(route, Box::new(|a| get_activity_type(a).map_or(false, |t| t == "reload")));
+                let (start_idx, end_idx) = intervals.last().cloned().unwrap_or((0, 0));
+                let end_pickup = route.tour.activities_slice(start_idx, end_idx).iter().fold(
+                    MultiDimLoad::default(),
+                    |acc, activity| {
+                        acc + activity
+                            .job
+                            .as_ref()
+                            .and_then(|job| get_capacity(&job.dimens, is_multi_dimen))
+                            .map_or(MultiDimLoad::default(), |demand| demand.pickup.0)
+                    },
+                );
+
+                if intervals.len() > 1 {
+                    end_pickup
+                } else {
+                    carried + end_pickup
+                }
+            });
+        });
+
+    carried_loads
+}
+
 fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -> MultiDimLoad {
     let job = act.job.as_ref();
     let demand = job.and_then(|job| get_capacity(&job.dimens, is_multi_dimen)).unwrap_or_default();
@@ -505,15 +876,33 @@ fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -
 }
 
 fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
+    let mut split_jobs = HashSet::new();
     let unassigned = solution
         .unassigned
         .iter()
-        .filter(|(job, _)| job.dimens().get_value::<String>("vehicle_id").is_none())
+        .filter(|(job, _)| job.dimens().get_dimen(&JOB_VEHICLE_ID_KEY).is_none())
+        // NOTE visits of synchronized job and parts of transfer job are reported once
+        .filter(|(job, _)| {
+            let sync_job_id = job
+                .as_single()
+                .and_then(|single| single.dimens.get_dimen(&JOB_SYNC_VISIT_KEY))
+                .map(|visit| &visit.job_id);
+            let transfer_job_id = job
+                .as_multi()
+                .and_then(|multi| multi.jobs.iter().find_map(|single| single.dimens.get_dimen(&JOB_TRANSFER_VISIT_KEY)))
+                .map(|visit| &visit.job_id);
+
+            sync_job_id.or(transfer_job_id).map_or(true, |job_id| split_jobs.insert(job_id.clone()))
+        })
         .map(|(job, code)| {
             let (code, reason) = map_code_reason(*code);
             UnassignedJob {
                 job_id: job.dimens().get_id().expect("job id expected").clone(),
-                reasons: vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string() }],
+                reasons: vec![UnassignedJobReason {
+                    code: code.to_string(),
+                    description: reason.to_string(),
+                    details: None,
+                }],
             }
         })
         .collect::<Vec<_>>();
@@ -530,10 +919,10 @@ fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
     let violations = solution
         .unassigned
         .iter()
-        .filter(|(job, _)| job.dimens().get_value::<String>("type").map_or(false, |t| t == "break"))
+        .filter(|(job, _)| job.dimens().get_dimen(&JOB_TYPE_KEY).map_or(false, |t| t == "break"))
         .map(|(job, _)| Violation::Break {
-            vehicle_id: job.dimens().get_value::<String>("vehicle_id").expect("vehicle id").clone(),
-            shift_index: *job.dimens().get_value::<usize>("shift_index").expect("shift index"),
+            vehicle_id: job.dimens().get_dimen(&JOB_VEHICLE_ID_KEY).expect("vehicle id").clone(),
+            shift_index: *job.dimens().get_dimen(&SHIFT_INDEX_KEY).expect("shift index"),
         })
         .collect::<Vec<_>>();
 
@@ -545,10 +934,10 @@ fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
 }
 
 fn get_activity_type(activity: &Activity) -> Option<&String> {
-    activity.job.as_ref().and_then(|single| single.dimens.get_value::<String>("type"))
+    activity.job.as_ref().and_then(|single| single.dimens.get_dimen(&JOB_TYPE_KEY))
 }
 
-fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<MultiDimLoad>> {
+pub(crate) fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<MultiDimLoad>> {
     if is_multi_dimen {
         dimens.get_demand().cloned()
     } else {
@@ -566,7 +955,7 @@ fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<Mult
     }
 }
 
-fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
+pub(crate) fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
     let capacity_type = extras
         .get("capacity_type")
         .and_then(|s| s.downcast_ref::<String>())
@@ -578,13 +967,92 @@ fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
     }
 }
 
+fn get_planning_units(extras: &DomainExtras) -> Option<PlanningUnits> {
+    extras.get("planning_units").and_then(|units| units.downcast_ref::<PlanningUnits>()).cloned()
+}
+
+fn get_toll_cost(extras: &DomainExtras) -> Option<&TollTransportCost> {
+    extras.get("toll_cost").and_then(|toll_cost| toll_cost.downcast_ref::<TollTransportCost>())
+}
+
 fn get_parking_time(extras: &DomainExtras) -> f64 {
     extras.get_cluster_config().map_or(0., |config| config.serving.get_parking())
 }
 
-fn create_extras(_solution: &Solution, metrics: Option<&TelemetryMetrics>) -> Option<Extras> {
-    metrics.map(|metrics| Extras {
-        metrics: Some(ApiMetrics {
+/// Creates tour compactness using routing distances between each job of the tour and its nearest
+/// job of the same tour. Nearest jobs are taken from jobs neighbourhood, so pairs of all jobs are
+/// not compared.
+fn create_tour_compactness(problem: &Problem, route: &Route) -> TourCompactness {
+    let (jobs, job_locations) = route
+        .tour
+        .all_activities()
+        .filter(|activity| {
+            get_activity_type(activity).map_or(false, |activity_type| {
+                matches!(activity_type.as_str(), "pickup" | "delivery" | "replacement" | "service")
+            })
+        })
+        .filter_map(|activity| activity.retrieve_job().map(|job| (job, activity.place.location)))
+        .fold(
+            (Vec::<Job>::new(), HashMap::<Job, Vec<DomainLocation>>::new()),
+            |(mut jobs, mut locations), (job, location)| {
+                locations
+                    .entry(job.clone())
+                    .or_insert_with(|| {
+                        jobs.push(job);
+                        vec![]
+                    })
+                    .push(location);
+
+                (jobs, locations)
+            },
+        );
+
+    let profile = &route.actor.vehicle.profile;
+    let departure = route.tour.start().map_or(0., |start| start.schedule.departure);
+    let distance = |from: DomainLocation, to: DomainLocation| {
+        problem.transport.distance(route, from, to, TravelTime::Departure(departure))
+    };
+
+    // NOTE routing distances can be asymmetric, so both directions are averaged for each pair
+    let get_distance = |from: &[DomainLocation], to: &[DomainLocation]| {
+        from.iter()
+            .flat_map(|&from| to.iter().map(move |&to| (from, to)))
+            .map(|(from, to)| (distance(from, to) + distance(to, from)) / 2.)
+            .min_by(|a, b| compare_floats(*a, *b))
+            .unwrap_or(0.)
+    };
+
+    let (total, max, count) = jobs
+        .iter()
+        .filter_map(|job| {
+            let locations = job_locations.get(job)?;
+            problem
+                .jobs
+                .neighbors(profile, job, departure)
+                .find_map(|(neighbor, _)| job_locations.get(neighbor))
+                .map(|neighbor_locations| get_distance(locations, neighbor_locations))
+        })
+        .fold((0., 0_f64, 0), |(total, max, count), distance| (total + distance, max.max(distance), count + 1));
+
+    TourCompactness { average_distance: if count > 0 { total / count as f64 } else { 0. }, max_distance: max }
+}
+
+fn create_extras(
+    _solution: &Solution,
+    metrics: Option<&TelemetryMetrics>,
+    tour_statistics: Option<Vec<PreciseStatistic>>,
+    tour_compactness: Option<Vec<TourCompactness>>,
+) -> Option<Extras> {
+    if metrics.is_none() && tour_statistics.is_none() && tour_compactness.is_none() {
+        return None;
+    }
+
+    let statistic = tour_statistics
+        .as_ref()
+        .map(|statistics| statistics.iter().fold(PreciseStatistic::default(), |acc, s| acc + s.clone()));
+
+    Some(Extras {
+        metrics: metrics.map(|metrics| ApiMetrics {
             duration: metrics.duration,
             generations: metrics.generations,
             speed: metrics.speed,
@@ -607,6 +1075,32 @@ fn create_extras(_solution: &Solution, metrics: Option<&TelemetryMetrics>) -> Op
                     },
                 })
                 .collect(),
+            tiers: metrics
+                .tiers
+                .iter()
+                .map(|t| ApiTierFitness { number: t.number, timestamp: t.timestamp, fitness: t.fitness.clone() })
+                .collect(),
+            snapshots: metrics
+                .snapshots
+                .iter()
+                .map(|s| ApiPopulationSnapshot { number: s.number, timestamp: s.timestamp, fitness: s.fitness.clone() })
+                .collect(),
+            operator_weights: metrics
+                .operator_weights
+                .iter()
+                .map(|w| ApiOperatorWeights {
+                    number: w.number,
+                    timestamp: w.timestamp,
+                    weights: w
+                        .weights
+                        .iter()
+                        .map(|(name, weight)| ApiOperatorWeight { name: name.clone(), weight: *weight })
+                        .collect(),
+                })
+                .collect(),
         }),
+        statistic,
+        tour_statistics,
+        tour_compactness,
     })
 }
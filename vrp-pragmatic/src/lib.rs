@@ -53,8 +53,27 @@ fn parse_time(time: &str) -> f64 {
     parse_time_safe(time).unwrap()
 }
 
-fn parse_time_safe(time: &str) -> Result<f64, String> {
+/// Parses time in RFC3339 format as unix timestamp in seconds.
+pub fn parse_time_safe(time: &str) -> Result<f64, String> {
     OffsetDateTime::parse(time, &Rfc3339)
         .map(|time| time.unix_timestamp() as f64)
         .map_err(|err| format!("cannot parse date: {}", err))
 }
+
+/// Parses time of day in `HH:MM` or `HH:MM:SS` format as seconds since midnight.
+fn parse_time_of_day(time: &str) -> Result<f64, String> {
+    let parts = time.split(':').map(|part| part.parse::<u32>().ok()).collect::<Option<Vec<_>>>();
+
+    let seconds = match parts.as_deref() {
+        Some([hours, minutes]) if *minutes < 60 => Some(hours * 3600 + minutes * 60),
+        Some([hours, minutes, seconds]) if *minutes < 60 && *seconds < 60 => {
+            Some(hours * 3600 + minutes * 60 + seconds)
+        }
+        _ => None,
+    };
+
+    match seconds {
+        Some(seconds) if seconds <= 86400 => Ok(seconds as f64),
+        _ => Err(format!("cannot parse time of day: '{}'", time)),
+    }
+}
@@ -8,3 +8,6 @@ pub use self::collections::*;
 
 mod permutations;
 pub use self::permutations::VariableJobPermutation;
+
+mod serialization;
+pub(crate) use self::serialization::serialize_pretty_with_parallel_array;
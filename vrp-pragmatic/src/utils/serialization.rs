@@ -0,0 +1,68 @@
+#[cfg(test)]
+#[path = "../../tests/unit/utils/serialization_test.rs"]
+mod serialization_test;
+
+use serde::Serialize;
+use std::io::{BufWriter, Error, ErrorKind, Write};
+use vrp_core::rosomaxa::utils::parallel_collect;
+
+/// Indentation of array items which belong to a top level field in pretty json output.
+const ITEM_INDENT: &[u8] = b"    ";
+
+/// Serializes `layout` into pretty json format where the top level `field` (expected to be serialized
+/// as an empty array by layout) is replaced with `items`. Items are serialized in parallel into
+/// separate buffers which are concatenated in original order, so the output is the same as if the
+/// whole value was serialized at once.
+pub(crate) fn serialize_pretty_with_parallel_array<W, L, T>(
+    mut writer: BufWriter<W>,
+    layout: &L,
+    field: &str,
+    items: &[T],
+) -> Result<(), Error>
+where
+    W: Write,
+    L: Serialize,
+    T: Serialize + Send + Sync,
+{
+    let layout = serde_json::to_vec_pretty(layout).map_err(Error::from)?;
+
+    let placeholder = format!("\n  \"{}\": []", field);
+    let position = layout
+        .windows(placeholder.len())
+        .position(|window| window == placeholder.as_bytes())
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("cannot find '{}' field in layout", field)))?;
+    let (head, tail) = (&layout[..position], &layout[position + placeholder.len()..]);
+
+    writer.write_all(head)?;
+
+    if items.is_empty() {
+        writer.write_all(placeholder.as_bytes())?;
+    } else {
+        let buffers = parallel_collect(items, serialize_item).into_iter().collect::<Result<Vec<_>, _>>()?;
+
+        writer.write_all(format!("\n  \"{}\": [", field).as_bytes())?;
+        buffers.iter().enumerate().try_for_each(|(idx, buffer)| {
+            writer.write_all(if idx == 0 { b"\n" } else { b",\n" })?;
+            writer.write_all(ITEM_INDENT)?;
+            writer.write_all(buffer)
+        })?;
+        writer.write_all(b"\n  ]")?;
+    }
+
+    writer.write_all(tail)?;
+    writer.flush()
+}
+
+/// Serializes item into pretty json with indentation of a top level array item.
+fn serialize_item<T: Serialize>(item: &T) -> Result<Vec<u8>, Error> {
+    let buffer = serde_json::to_vec_pretty(item).map_err(Error::from)?;
+
+    // NOTE json strings cannot contain raw new line symbols, so it is safe to indent after each of them
+    Ok(buffer.into_iter().fold(Vec::new(), |mut acc, byte| {
+        acc.push(byte);
+        if byte == b'\n' {
+            acc.extend_from_slice(ITEM_INDENT);
+        }
+        acc
+    }))
+}
@@ -3,7 +3,9 @@
 mod jobs_test;
 
 use super::*;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
+use hashbrown::HashSet;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -179,6 +181,223 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that job has no negative uncertainty buffer.
+fn check_e1108_negative_buffer(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.buffer.map_or(false, |buffer| buffer.is_sign_negative()))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "job has negative buffer".to_string(),
+            format!("fix negative buffer in jobs with ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job parking is properly defined.
+fn check_e1109_invalid_parking(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_clustering = ctx.problem.plan.clustering.is_some();
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            let tasks = ctx.tasks(job);
+            let parkings = tasks
+                .iter()
+                .flat_map(|task| task.places.iter().filter_map(|place| place.parking.as_ref()))
+                .collect::<Vec<_>>();
+
+            let has_invalid_values =
+                parkings.iter().any(|parking| parking.duration.is_sign_negative() || parking.distance <= 0.);
+
+            !parkings.is_empty() && (has_clustering || tasks.len() > 1 || has_invalid_values)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "job has invalid parking".to_string(),
+            format!(
+                "use parking only in jobs with one task, with positive distance, non negative duration and \
+                 without clustering, check jobs with ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that synchronized jobs are properly defined.
+fn check_e1110_invalid_sync(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let referenced_ids = get_referenced_job_ids(ctx);
+
+    let ids = ctx
+        .jobs()
+        .filter_map(|job| job.sync.as_ref().map(|sync| (job, sync)))
+        .filter(|(job, sync)| {
+            let has_other_tasks = job.pickups.is_some() || job.deliveries.is_some() || job.replacements.is_some();
+            let has_few_visits = job.services.as_ref().map_or(true, |services| services.len() < 2);
+            let has_invalid_gap = sync.max_gap.map_or(false, |gap| gap.is_sign_negative());
+
+            has_other_tasks
+                || has_few_visits
+                || has_invalid_gap
+                || job.group.is_some()
+                || referenced_ids.contains(&job.id)
+        })
+        .map(|(job, _)| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "job has invalid sync".to_string(),
+            format!(
+                "use sync only in jobs with two or more service tasks, non negative max gap, without group \
+                 and not referenced in relations or hints, check jobs with ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job tasks have proper not before time.
+fn check_e1111_invalid_not_before(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_invalid_task = |task: &JobTask| {
+        task.not_before.as_ref().map_or(false, |not_before| match parse_time_safe(not_before) {
+            Ok(not_before) => !task.places.iter().any(|place| {
+                place.times.as_ref().map_or(true, |times| {
+                    get_time_windows(times).iter().flatten().any(|time_window| time_window.end >= not_before)
+                })
+            }),
+            Err(_) => true,
+        })
+    };
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.pickups
+                .iter()
+                .chain(job.deliveries.iter())
+                .chain(job.replacements.iter())
+                .chain(job.services.iter())
+                .flat_map(|tasks| tasks.iter())
+                .any(is_invalid_task)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid not before time in job task".to_string(),
+            format!(
+                "use RFC3339 format and make sure that not before time is earlier than the end of at least one \
+                 place time window, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that transfer jobs are properly defined.
+fn check_e1112_invalid_transfer(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let referenced_ids = get_referenced_job_ids(ctx);
+
+    let ids = ctx
+        .jobs()
+        .filter_map(|job| job.transfer.as_ref().map(|transfer| (job, transfer)))
+        .filter(|(job, transfer)| {
+            let tasks = match (job.pickups.as_deref(), job.deliveries.as_deref()) {
+                (Some([pickup]), Some([delivery])) => Some((pickup, delivery)),
+                _ => None,
+            };
+            let has_invalid_tasks = tasks.map_or(true, |(pickup, delivery)| {
+                pickup.demand != delivery.demand || pickup.skills.is_some() || delivery.skills.is_some()
+            });
+            let has_other_tasks = job.replacements.is_some() || job.services.is_some();
+            let has_invalid_values = transfer.duration.is_sign_negative()
+                || transfer.max_gap.map_or(false, |gap| gap.is_sign_negative())
+                || transfer.times.as_ref().map_or(false, |tws| !check_raw_time_windows(tws, false));
+
+            has_invalid_tasks
+                || has_other_tasks
+                || has_invalid_values
+                || job.sync.is_some()
+                || job.group.is_some()
+                || referenced_ids.contains(&job.id)
+        })
+        .map(|(job, _)| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "job has invalid transfer".to_string(),
+            format!(
+                "use transfer only in jobs with one pickup and one delivery of the same demand and without task \
+                 skills, non negative duration and max gap, valid time windows, without sync or group and not \
+                 referenced in relations or hints, check jobs with ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that jobs with parking are not referenced in relations, readonly routes or hints.
+fn check_e1113_parking_with_relations(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let referenced_ids = get_referenced_job_ids(ctx);
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            ctx.tasks(job).iter().any(|task| task.places.iter().any(|place| place.parking.is_some()))
+                && referenced_ids.contains(&job.id)
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "job with parking is referenced in relations".to_string(),
+            format!(
+                "remove parking from jobs or remove them from relations, readonly routes and hints, check jobs \
+                 with ids: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn get_referenced_job_ids<'a>(ctx: &'a ValidationContext) -> HashSet<&'a String> {
+    let plan = &ctx.problem.plan;
+
+    plan.relations
+        .iter()
+        .flat_map(|relations| relations.iter().flat_map(|relation| relation.jobs.iter()))
+        .chain(plan.readonly_routes.iter().flat_map(|routes| routes.iter().flat_map(|route| route.jobs.iter())))
+        .chain(plan.hints.iter().flat_map(|hints| hints.iter().flat_map(|hint| hint.jobs.iter())))
+        .collect()
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     combine_error_results(&[
@@ -190,5 +409,11 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_negative_buffer(ctx),
+        check_e1109_invalid_parking(ctx),
+        check_e1110_invalid_sync(ctx),
+        check_e1111_invalid_not_before(ctx),
+        check_e1112_invalid_transfer(ctx),
+        check_e1113_parking_with_relations(ctx),
     ])
 }
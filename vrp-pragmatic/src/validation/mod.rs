@@ -1,7 +1,7 @@
 //! This module provides functionality to validate problem definition for logical correctness.
 
 use crate::format::problem::*;
-use crate::format::{CoordIndex, FormatError};
+use crate::format::{is_reserved_job_id, CoordIndex, FormatError, DRIVER_REST_JOB_ID};
 
 /// A validation context which keeps essential information.
 pub struct ValidationContext<'a> {
@@ -85,7 +85,3 @@ impl<'a> ValidationContext<'a> {
             .collect()
     }
 }
-
-fn is_reserved_job_id(job_id: &str) -> bool {
-    job_id == "departure" || job_id == "arrival" || job_id == "break" || job_id == "reload" || job_id == "dispatch"
-}
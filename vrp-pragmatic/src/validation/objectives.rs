@@ -28,6 +28,7 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 MinimizeCost => acc.entry("minimize-cost"),
                 MinimizeDistance => acc.entry("minimize-distance"),
                 MinimizeDuration => acc.entry("minimize-duration"),
+                MinimizeTolls => acc.entry("minimize-tolls"),
                 MinimizeTours => acc.entry("minimize-tours"),
                 MaximizeTours => acc.entry("maximize-tours"),
                 MaximizeValue { .. } => acc.entry("maximize-value"),
@@ -36,8 +37,12 @@ fn check_e1601_duplicate_objectives(objectives: &[&Objective]) -> Result<(), For
                 BalanceActivities { .. } => acc.entry("balance-activities"),
                 BalanceDistance { .. } => acc.entry("balance-distance"),
                 BalanceDuration { .. } => acc.entry("balance-duration"),
+                TargetStops { .. } => acc.entry("target-stops"),
                 TourOrder { .. } => acc.entry("tour-order"),
                 AreaOrder { .. } => acc.entry("area-order"),
+                MaximizeRespectedBuffers => acc.entry("maximize-respected-buffers"),
+                MinimizeLatestEta => acc.entry("minimize-latest-eta"),
+                Custom { name } => acc.entry(name.as_str()),
             }
             .and_modify(|count| *count += 1)
             .or_insert(1_usize);
@@ -205,6 +210,21 @@ fn check_e1608_areas_but_no_objective(ctx: &ValidationContext, objectives: &[&Ob
     }
 }
 
+/// Checks that target stops objective has a positive target.
+fn check_e1609_invalid_target_stops(objectives: &[&Objective]) -> Result<(), FormatError> {
+    let has_invalid_target = objectives.iter().any(|objective| matches!(objective, TargetStops { target: 0, .. }));
+
+    if has_invalid_target {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "invalid target stops objective".to_string(),
+            "specify 'target' of 'target-stops' objective greater than zero".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().flatten().collect())
 }
@@ -221,6 +241,7 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), Vec<FormatErro
             check_e1606_jobs_with_order_but_no_objective(ctx, &objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
             check_e1608_areas_but_no_objective(ctx, &objectives),
+            check_e1609_invalid_target_stops(&objectives),
         ])
     } else {
         Ok(())
@@ -182,6 +182,7 @@ fn check_e1206_relation_has_no_missing_shift_properties(
                 "dispatch" => vehicle_shift.dispatch.is_none(),
                 "reload" => vehicle_shift.reloads.is_none(),
                 "arrival" => vehicle_shift.end.is_none(),
+                DRIVER_REST_JOB_ID => true,
                 _ => false,
             })
         })
@@ -254,25 +255,99 @@ fn check_e1207_no_incomplete_relation(ctx: &ValidationContext, relations: &[Rela
     }
 }
 
-/// Validates relations in the plan.
+/// Checks that assignment hints have only known job and vehicle ids.
+fn check_e1208_hint_existence(
+    ctx: &ValidationContext,
+    hints: &[AssignmentHint],
+    vehicle_map: &HashMap<String, &VehicleType>,
+) -> Result<(), FormatError> {
+    let ids = hints
+        .iter()
+        .flat_map(|hint| hint.jobs.iter().filter(|&job_id| !ctx.job_index.contains_key(job_id)))
+        .chain(hints.iter().map(|hint| &hint.vehicle_id).filter(|&vehicle_id| !vehicle_map.contains_key(vehicle_id)))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1208".to_string(),
+            "assignment hint has job or vehicle id which does not present in the problem".to_string(),
+            format!("remove from hints or add jobs to the plan or vehicles to the fleet, ids: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that readonly route does not share vehicle shift with relations or other readonly routes.
+fn check_e1209_readonly_route_has_unique_vehicle_shift(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let relations = ctx.problem.plan.relations.iter().flatten();
+    let readonly_routes = ctx.problem.plan.readonly_routes.iter().flatten();
+
+    let shift_usage = relations
+        .map(|relation| (relation.vehicle_id.clone(), relation.shift_index.unwrap_or(0)))
+        .chain(readonly_routes.clone().map(|route| (route.vehicle_id.clone(), route.shift_index.unwrap_or(0))))
+        .collect_group_by_key(|key| key.clone());
+
+    let mut vehicle_ids = readonly_routes
+        .filter(|route| {
+            shift_usage.get(&(route.vehicle_id.clone(), route.shift_index.unwrap_or(0))).map_or(false, |u| u.len() > 1)
+        })
+        .map(|route| route.vehicle_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    vehicle_ids.sort();
+
+    if vehicle_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1209".to_string(),
+            "readonly route vehicle shift is used by another readonly route or relation".to_string(),
+            format!(
+                "specify only one readonly route per vehicle shift without relations, vehicle ids: '{}'",
+                vehicle_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Validates relations and readonly routes in the plan. Readonly route is validated as strict relation.
 pub fn validate_relations(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let vehicle_map = ctx
         .vehicles()
         .flat_map(|v_type| v_type.vehicle_ids.iter().map(move |id| (id.clone(), v_type)))
         .collect::<HashMap<_, _>>();
 
-    if let Some(relations) = ctx.problem.plan.relations.as_ref() {
-        combine_error_results(&[
-            check_e1200_job_existence(ctx, relations),
-            check_e1201_vehicle_existence(relations, &vehicle_map),
-            check_e1202_empty_job_list(relations),
-            check_e1203_no_multiple_places_times(ctx, relations),
-            check_e1204_job_assigned_to_multiple_vehicles(relations),
-            check_e1205_relation_has_correct_shift_index(relations, &vehicle_map),
-            check_e1206_relation_has_no_missing_shift_properties(relations, &vehicle_map),
-            check_e1207_no_incomplete_relation(ctx, relations),
-        ])
-    } else {
-        Ok(())
-    }
+    let hints = ctx.problem.plan.hints.as_ref();
+    combine_error_results(&[hints.map_or(Ok(()), |hints| check_e1208_hint_existence(ctx, hints, &vehicle_map))])?;
+
+    let relations = ctx
+        .problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .cloned()
+        .chain(ctx.problem.plan.readonly_routes.iter().flatten().map(|route| Relation {
+            type_field: RelationType::Strict,
+            jobs: route.jobs.clone(),
+            vehicle_id: route.vehicle_id.clone(),
+            shift_index: route.shift_index,
+        }))
+        .collect::<Vec<_>>();
+
+    combine_error_results(&[
+        check_e1200_job_existence(ctx, &relations),
+        check_e1201_vehicle_existence(&relations, &vehicle_map),
+        check_e1202_empty_job_list(&relations),
+        check_e1203_no_multiple_places_times(ctx, &relations),
+        check_e1204_job_assigned_to_multiple_vehicles(&relations),
+        check_e1205_relation_has_correct_shift_index(&relations, &vehicle_map),
+        check_e1206_relation_has_no_missing_shift_properties(&relations, &vehicle_map),
+        check_e1207_no_incomplete_relation(ctx, &relations),
+        check_e1209_readonly_route_has_unique_vehicle_shift(ctx),
+    ])
 }
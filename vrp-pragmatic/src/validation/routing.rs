@@ -3,8 +3,11 @@
 mod routing_test;
 
 use super::*;
+use crate::format::PlanningUnits;
+use crate::parse_time_of_day;
 use crate::utils::combine_error_results;
 use hashbrown::HashSet;
+use vrp_core::models::problem::{estimate_matrix_memory, get_matrix_dimension, MatrixStorage};
 
 /// Checks that no duplicated profile names specified.
 fn check_e1500_duplicated_profiles(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -63,21 +66,16 @@ fn check_e1503_no_matrix_when_indices_used(
     }
 }
 
-/// Checks that coord index has a proper maximum index for
+/// Checks that coord index has a proper maximum index for matrix dimension.
 fn check_e1504_index_size_mismatch(ctx: &ValidationContext) -> Result<(), FormatError> {
-    let (max_index, matrix_size, is_correct_index): _ = ctx
-        .coord_index
-        .max_index()
-        .into_iter()
-        .zip(
-            ctx.matrices
-                .and_then(|matrices| matrices.first())
-                .map(|matrix| (matrix.distances.len() as f64).sqrt().round() as usize),
-        )
-        .next()
-        .map_or((0_usize, 0_usize, true), |(max_index, matrix_size)| {
-            (max_index, matrix_size, max_index + 1 == matrix_size)
-        });
+    let matrix_length = ctx.matrices.and_then(|matrices| matrices.first()).map(|matrix| matrix.distances.len());
+    let (matrix_size, is_correct_index) = match (ctx.coord_index.max_index(), matrix_length) {
+        (Some(max_index), Some(length)) => {
+            let matrix_size = get_matrix_dimension(length);
+            (matrix_size, matrix_size.map_or(false, |size| max_index.checked_add(1) == Some(size)))
+        }
+        _ => (None, true),
+    };
 
     if !is_correct_index {
         Err(FormatError::new(
@@ -85,7 +83,8 @@ fn check_e1504_index_size_mismatch(ctx: &ValidationContext) -> Result<(), Format
             "amount of locations does not match matrix dimension".to_string(),
             format!(
                 "check matrix size: max location index '{}' + 1 should be equal to matrix size ('{}')",
-                max_index, matrix_size
+                ctx.coord_index.max_index().unwrap_or_default(),
+                matrix_size.map_or_else(|| "not square".to_string(), |size| size.to_string())
             ),
         ))
     } else {
@@ -121,6 +120,188 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that approximation settings of profiles are valid.
+fn check_e1506_approximation_settings(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_profiles = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile.speed.map_or(false, |speed| speed <= 0.)
+                || profile.detour_factor.map_or(false, |detour_factor| detour_factor < 1.)
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if invalid_profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "invalid approximation settings in profile".to_string(),
+            format!(
+                "ensure that speed is positive and detour factor is not less than one in profiles: '{}'",
+                invalid_profiles.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that time of day settings of profiles are valid.
+fn check_e1507_time_of_day_settings(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_profiles = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile.time_of_day.as_ref().map_or(false, |factors| {
+                let intervals = factors
+                    .iter()
+                    .map(|factor| {
+                        Ok((parse_time_of_day(&factor.start)?, parse_time_of_day(&factor.end)?, factor.factor))
+                    })
+                    .collect::<Result<Vec<_>, String>>();
+
+                intervals.map_or(true, |mut intervals| {
+                    intervals.sort_by(|(a, _, _), (b, _, _)| a.total_cmp(b));
+
+                    intervals.iter().any(|(start, end, factor)| start >= end || *factor <= 0.)
+                        || intervals.windows(2).any(|pair| pair[0].1 > pair[1].0)
+                })
+            })
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if invalid_profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "invalid time of day settings in profile".to_string(),
+            format!(
+                "ensure that time of day intervals are valid, do not overlap and have positive factor in profiles: '{}'",
+                invalid_profiles.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that leg overrides of profiles are valid.
+fn check_e1508_leg_overrides(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_profiles = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| {
+            profile.leg_overrides.as_ref().map_or(false, |legs| {
+                legs.iter().any(|leg| leg.duration < 0. || leg.distance.map_or(false, |distance| distance < 0.))
+            })
+        })
+        .map(|profile| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if invalid_profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1508".to_string(),
+            "invalid leg overrides in profile".to_string(),
+            format!(
+                "ensure that duration and distance of leg overrides are not negative in profiles: '{}'",
+                invalid_profiles.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that routing matrices use the same units as the problem.
+fn check_e1509_matrix_units(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let units = ctx.problem.units.clone().unwrap_or_default();
+    let mismatch = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter())
+        .filter_map(|matrix| matrix.units.as_ref())
+        .find(|matrix_units| **matrix_units != units);
+
+    if let Some(matrix_units) = mismatch {
+        Err(FormatError::new(
+            "E1509".to_string(),
+            "routing matrix units do not match problem units".to_string(),
+            format!(
+                "ensure that routing matrix units ('{}', '{}') are the same as problem units ('{}', '{}')",
+                matrix_units.duration, matrix_units.distance, units.duration, units.distance
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that routing matrix is specified when non default units are used.
+fn check_e1510_no_matrix_when_custom_units_used(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_custom_units = ctx.problem.units.as_ref().map_or(false, |units| *units != PlanningUnits::default());
+
+    if has_custom_units && ctx.matrices.map_or(true, |matrices| matrices.is_empty()) {
+        Err(FormatError::new(
+            "E1510".to_string(),
+            "custom units require routing matrix to be specified".to_string(),
+            "specify routing matrix as approximation is based on seconds and meters".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that routing matrices for all locations can be kept in memory.
+fn check_e1511_matrix_size_overflow(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let size = ctx.coord_index.matrix_size();
+    let profiles = ctx.problem.fleet.profiles.len().max(1);
+
+    if estimate_matrix_memory(size, profiles, MatrixStorage::default()).is_none() {
+        Err(FormatError::new(
+            "E1511".to_string(),
+            "too many locations for routing matrix".to_string(),
+            format!(
+                "reduce amount of unique locations or max location index: matrix dimension '{}' for '{}' \
+                 profile(-s) cannot be addressed on this platform",
+                size, profiles
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that toll matrices have the same size as routing matrices.
+fn check_e1512_toll_matrix_size_mismatch(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_matrices = ctx
+        .matrices
+        .iter()
+        .flat_map(|matrices| matrices.iter())
+        .enumerate()
+        .filter(|(_, matrix)| matrix.tolls.as_ref().map_or(false, |tolls| tolls.len() != matrix.distances.len()))
+        .map(|(idx, matrix)| matrix.profile.clone().unwrap_or_else(|| idx.to_string()))
+        .collect::<Vec<_>>();
+
+    if invalid_matrices.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1512".to_string(),
+            "toll matrix size does not match routing matrix size".to_string(),
+            format!(
+                "ensure that tolls have the same amount of values as distances and durations in matrices: '{}'",
+                invalid_matrices.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>> {
     let location_types = ctx.coord_index.get_used_types();
@@ -132,5 +313,12 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), Vec<FormatError>>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_approximation_settings(ctx),
+        check_e1507_time_of_day_settings(ctx),
+        check_e1508_leg_overrides(ctx),
+        check_e1509_matrix_units(ctx),
+        check_e1510_no_matrix_when_custom_units_used(ctx),
+        check_e1511_matrix_size_overflow(ctx),
+        check_e1512_toll_matrix_size_mismatch(ctx),
     ])
 }
@@ -86,7 +86,11 @@ fn check_e1303_vehicle_breaks_time_is_correct(ctx: &ValidationContext) -> Result
                             VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeWindow(tw), .. } => {
                                 Some(get_time_window_from_vec(tw))
                             }
-                            VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime(time), duration } => {
+                            VehicleBreak::Required {
+                                time: VehicleRequiredBreakTime::ExactTime(time),
+                                duration,
+                                ..
+                            } => {
                                 Some(parse_time_safe(time).ok().map(|start| TimeWindow::new(start, start + *duration)))
                             }
                             _ => None,
@@ -298,6 +302,252 @@ fn check_e1308_vehicle_required_break_rescheduling(ctx: &ValidationContext) -> R
     }
 }
 
+/// Checks that reload capacity has the same dimensions as vehicle capacity.
+fn check_e1309_vehicle_reload_capacity_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|vehicle, shift, _| {
+            shift.reloads.as_ref().map_or(true, |reloads| {
+                reloads
+                    .iter()
+                    .filter_map(|reload| reload.capacity.as_ref())
+                    .all(|capacity| capacity.len() == vehicle.capacity.len())
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid reload capacity".to_string(),
+            format!(
+                "ensure that reload capacity has the same dimensions as vehicle capacity, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that driver hours are positive and daily rest is kept between consecutive shifts.
+fn check_e1310_vehicle_driver_hours_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().and_then(|limits| limits.driver_hours.as_ref()).map_or(false, |hours| {
+                let max_continuous = hours.get_max_continuous_driving();
+                let max_daily = hours.get_max_daily_driving();
+                let daily_rest = hours.get_daily_rest();
+
+                let has_invalid_values = [max_continuous, hours.get_break_duration(), max_daily]
+                    .iter()
+                    .any(|value| compare_floats(*value, 0.) != Ordering::Greater)
+                    || compare_floats(daily_rest, 0.) == Ordering::Less
+                    || compare_floats(max_continuous, max_daily) == Ordering::Greater;
+
+                let mut shift_times = vehicle.shifts.iter().filter_map(get_shift_time_window).collect::<Vec<_>>();
+                shift_times.sort_by(|a, b| compare_floats(a.start, b.start));
+
+                let has_short_rest = shift_times
+                    .windows(2)
+                    .any(|pair| compare_floats(pair[1].start - pair[0].end, daily_rest) == Ordering::Less);
+
+                has_invalid_values || has_short_rest
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid driver hours".to_string(),
+            format!(
+                "ensure that driver hours values are positive, max continuous driving is not greater than max daily \
+                 driving and consecutive shifts are separated by daily rest, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that overtime values are not negative and shift time limit is set.
+fn check_e1311_vehicle_overtime_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.limits.as_ref().map_or(false, |limits| {
+                limits.overtime.as_ref().map_or(false, |overtime| {
+                    limits.shift_time.is_none()
+                        || compare_floats(overtime.max_duration, 0.) == Ordering::Less
+                        || compare_floats(overtime.cost, 0.) == Ordering::Less
+                })
+            })
+        })
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid overtime".to_string(),
+            format!(
+                "ensure that overtime values are not negative and shift time limit is set, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that reload per unit duration is not negative and reload resources are defined consistently.
+fn check_e1312_vehicle_reload_resource_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let reloads = || {
+        ctx.vehicles().flat_map(|vehicle| {
+            vehicle
+                .shifts
+                .iter()
+                .flat_map(|shift| shift.reloads.iter().flat_map(|reloads| reloads.iter()))
+                .map(move |reload| (vehicle, reload))
+        })
+    };
+
+    let capacities = reloads()
+        .filter_map(|(_, reload)| reload.resource.as_ref())
+        .map(|resource| (resource.id.as_str(), resource.capacity))
+        .collect::<HashSet<_>>();
+
+    let type_ids = reloads()
+        .filter(|(_, reload)| {
+            let has_invalid_duration =
+                reload.per_unit_duration.map_or(false, |duration| compare_floats(duration, 0.) == Ordering::Less);
+            let has_invalid_resource = reload.resource.as_ref().map_or(false, |resource| {
+                resource.capacity == 0
+                    || capacities.iter().any(|(id, capacity)| *id == resource.id && *capacity != resource.capacity)
+            });
+
+            has_invalid_duration || has_invalid_resource
+        })
+        .map(|(vehicle, _)| vehicle.type_id.to_string())
+        .collect::<HashSet<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        let mut type_ids = type_ids.into_iter().collect::<Vec<_>>();
+        type_ids.sort();
+
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid reload resource".to_string(),
+            format!(
+                "ensure that reload per unit duration is not negative, resource capacity is positive and the same \
+                 for all reloads sharing resource, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that departure loading durations are not negative.
+fn check_e1313_vehicle_loading_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift.loading.as_ref().map_or(true, |loading| {
+                compare_floats(loading.duration, 0.) != Ordering::Less
+                    && loading.per_unit_duration.map_or(true, |duration| compare_floats(duration, 0.) != Ordering::Less)
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle loading".to_string(),
+            format!(
+                "ensure that loading duration and per unit duration are not negative, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle echelon is either first or second.
+fn check_e1314_vehicle_echelon_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.echelon.map_or(false, |echelon| echelon != 1 && echelon != 2))
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid vehicle echelon".to_string(),
+            format!("ensure that vehicle echelon is either 1 or 2, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle service time factor is positive.
+fn check_e1315_vehicle_service_time_factor_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.service_time_factor.map_or(false, |factor| factor <= 0.))
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1315".to_string(),
+            "invalid vehicle service time factor".to_string(),
+            format!("ensure that vehicle service time factor is positive, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle shift end alternatives are correct.
+fn check_e1316_vehicle_end_alternatives_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift.end.as_ref().and_then(|end| end.alternatives.as_ref().map(|alternatives| (end, alternatives))).map_or(
+                true,
+                |(end, alternatives)| {
+                    let unique = alternatives.iter().collect::<HashSet<_>>();
+
+                    !alternatives.is_empty() && unique.len() == alternatives.len() && !unique.contains(&end.location)
+                },
+            )
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1316".to_string(),
+            "invalid vehicle end alternatives".to_string(),
+            format!(
+                "ensure that vehicle shift end alternatives are not empty, have no duplicates and do not include \
+                 end location, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 fn get_invalid_type_ids(
     ctx: &ValidationContext,
     check_shift: Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>,
@@ -347,5 +597,13 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1306_vehicle_dispatch_is_correct(ctx),
         check_e1307_vehicle_has_no_zero_costs(ctx),
         check_e1308_vehicle_required_break_rescheduling(ctx),
+        check_e1309_vehicle_reload_capacity_is_correct(ctx),
+        check_e1310_vehicle_driver_hours_is_correct(ctx),
+        check_e1311_vehicle_overtime_is_correct(ctx),
+        check_e1312_vehicle_reload_resource_is_correct(ctx),
+        check_e1313_vehicle_loading_is_correct(ctx),
+        check_e1314_vehicle_echelon_is_correct(ctx),
+        check_e1315_vehicle_service_time_factor_is_correct(ctx),
+        check_e1316_vehicle_end_alternatives_are_correct(ctx),
     ])
 }
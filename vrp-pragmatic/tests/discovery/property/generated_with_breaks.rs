@@ -35,7 +35,9 @@ mod optional {
             VehicleBreak::Optional {
                 time,
                 places,
-                policy
+                policy,
+                min_shift_duration: None,
+                min_spacing: None,
             }
         }
     }
@@ -81,7 +83,7 @@ mod optional {
             generate_vehicles(get_vehicle_type_with_optional_breaks(), 1..4),
             default_matrix_profiles())
         ) -> Problem {
-            Problem { plan, fleet, objectives: None }
+            Problem { plan, fleet, objectives: None, units: None }
         }
     }
 
@@ -122,7 +124,7 @@ mod required {
          time in time_proto,
          duration in duration_proto,
         ) -> VehicleBreak {
-            VehicleBreak::Required { time, duration }
+            VehicleBreak::Required { time, duration, can_interrupt: None }
         }
     }
 
@@ -167,7 +169,7 @@ mod required {
             generate_vehicles(get_vehicle_type_with_required_breaks(), 1..4),
             default_matrix_profiles())
         ) -> Problem {
-            Problem { plan, fleet, objectives: None }
+            Problem { plan, fleet, objectives: None, units: None }
         }
     }
 }
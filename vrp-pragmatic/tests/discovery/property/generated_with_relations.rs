@@ -17,7 +17,12 @@ fn vehicle_type_prototype() -> impl Strategy<Value = VehicleType> {
             generate_shift(
                 generate_location(&DEFAULT_BOUNDING_BOX).prop_flat_map(|location| {
                     Just((
-                        ShiftStart { earliest: default_time_plus_offset(9), latest: None, location: location.clone() },
+                        ShiftStart {
+                            earliest: default_time_plus_offset(9),
+                            latest: None,
+                            optimize_departure: None,
+                            location: location.clone(),
+                        },
                         None,
                     ))
                 }),
@@ -72,6 +77,7 @@ prop_compose! {
             },
             fleet,
             objectives: None,
+            units: None,
         }
     }
 }
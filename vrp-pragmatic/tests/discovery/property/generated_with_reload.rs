@@ -45,6 +45,7 @@ prop_compose! {
             plan,
             fleet,
             objectives: None,
+            units: None,
         }
     }
 }
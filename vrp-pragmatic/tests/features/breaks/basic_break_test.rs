@@ -22,6 +22,8 @@ fn can_assign_break_between_jobs() {
                             tag: Some("break_tag".to_string()),
                         }],
                         policy: None,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -43,6 +45,7 @@ fn can_assign_break_between_jobs() {
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -96,7 +99,9 @@ fn can_assign_break_between_jobs() {
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -18,6 +18,7 @@ fn can_assign_break_using_second_place() {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![30., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(10.), format_time(30.)]),
@@ -34,6 +35,8 @@ fn can_assign_break_using_second_place() {
                             },
                         ],
                         policy: None,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -55,6 +58,7 @@ fn can_assign_break_using_second_place() {
                 distance: 30,
                 duration: 34,
                 times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -108,7 +112,9 @@ fn can_assign_break_using_second_place() {
                     distance: 30,
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
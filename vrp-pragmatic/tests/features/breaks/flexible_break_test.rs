@@ -0,0 +1,85 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_optional_break(time: Vec<f64>, min_shift_duration: Option<f64>, min_spacing: Option<f64>) -> VehicleBreak {
+    VehicleBreak::Optional {
+        time: VehicleOptionalBreakTime::TimeOffset(time),
+        places: vec![VehicleOptionalBreakPlace { duration: 2., location: None, tag: None }],
+        policy: None,
+        min_shift_duration,
+        min_spacing,
+    }
+}
+
+fn create_problem(jobs: Vec<Job>, breaks: Vec<VehicleBreak>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { breaks: Some(breaks), ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_break_times(solution: &Solution) -> Vec<(f64, f64)> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| {
+            stop.activities().iter().filter(|activity| activity.activity_type == "break").map(move |activity| {
+                let schedule = stop.schedule();
+                activity.time.as_ref().map_or_else(
+                    || (parse_time(&schedule.arrival), parse_time(&schedule.departure)),
+                    |time| (parse_time(&time.start), parse_time(&time.end)),
+                )
+            })
+        })
+        .collect()
+}
+
+parameterized_test! {can_skip_break_when_shift_is_short, (min_shift_duration, expected_breaks), {
+    can_skip_break_when_shift_is_short_impl(min_shift_duration, expected_breaks);
+}}
+
+can_skip_break_when_shift_is_short! {
+    case01_short_shift: (Some(100.), 0),
+    case02_long_shift: (Some(5.), 1),
+    case03_no_threshold: (None, 1),
+}
+
+fn can_skip_break_when_shift_is_short_impl(min_shift_duration: Option<f64>, expected_breaks: usize) {
+    let problem = create_problem(
+        vec![create_delivery_job("job1", vec![5., 0.])],
+        vec![create_optional_break(vec![0., 100.], min_shift_duration, None)],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(solution.violations.is_none());
+    assert_eq!(get_break_times(&solution).len(), expected_breaks);
+}
+
+#[test]
+fn can_keep_min_spacing_between_breaks() {
+    let problem = create_problem(
+        (1..=8).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64 * 5., 0.])).collect(),
+        vec![create_optional_break(vec![5., 60.], None, Some(10.)), create_optional_break(vec![5., 60.], None, None)],
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let break_times = get_break_times(&solution);
+    assert!(solution.violations.is_none());
+    assert_eq!(break_times.len(), 2);
+    assert!(break_times[1].0 - break_times[0].1 >= 10.);
+}
@@ -22,6 +22,8 @@ fn can_assign_interval_break_between_jobs() {
                         time: VehicleOptionalBreakTime::TimeOffset(vec![5., 10.]),
                         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
                         policy: None,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -44,6 +46,7 @@ fn can_assign_interval_break_between_jobs() {
                 distance: 30,
                 duration: 34,
                 times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -67,6 +70,7 @@ fn can_assign_interval_break_between_jobs() {
                         distance: 5,
                         load: vec![1],
                         parking: None,
+                        service: None,
                         activities: vec![
                             Activity {
                                 job_id: "job1".to_string(),
@@ -114,7 +118,9 @@ fn can_assign_interval_break_between_jobs() {
                     distance: 30,
                     duration: 34,
                     times: Timing { driving: 30, serving: 2, break_time: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -139,25 +145,33 @@ fn can_assign_interval_break_with_reload() {
                     start: ShiftStart {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
+                        optimize_departure: None,
                         location: vec![0., 0.].to_loc(),
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![30., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeOffset(vec![8., 12.]),
                         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
                         policy: None,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     reloads: Some(vec![VehicleReload {
                         times: Some(vec![vec![format_time(0.), format_time(1000.)]]),
                         location: vec![0., 0.].to_loc(),
                         duration: 3.0,
                         tag: None,
+                        capacity: None,
+                        per_unit_duration: None,
+                        resource: None,
                     }]),
+                    loading: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -179,6 +193,7 @@ fn can_assign_interval_break_with_reload() {
                 distance: 60,
                 duration: 69,
                 times: Timing { driving: 60, serving: 7, break_time: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -202,6 +217,7 @@ fn can_assign_interval_break_with_reload() {
                         distance: 10,
                         load: vec![1],
                         parking: None,
+                        service: None,
                         activities: vec![
                             Activity {
                                 job_id: "job1".to_string(),
@@ -273,7 +289,9 @@ fn can_assign_interval_break_with_reload() {
                     distance: 60,
                     duration: 69,
                     times: Timing { driving: 60, serving: 7, break_time: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -297,6 +315,8 @@ fn can_consider_departure_rescheduling() {
                         time: VehicleOptionalBreakTime::TimeOffset(vec![10., 12.]),
                         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
                         policy: None,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -16,6 +16,7 @@ fn can_use_two_breaks() {
                     start: ShiftStart {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
+                        optimize_departure: None,
                         location: vec![0., 0.].to_loc(),
                     },
                     breaks: Some(vec![
@@ -27,11 +28,15 @@ fn can_use_two_breaks() {
                                 tag: None,
                             }],
                             policy: None,
+                            min_shift_duration: None,
+                            min_spacing: None,
                         },
                         VehicleBreak::Optional {
                             time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(100.), format_time(120.)]),
                             places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
                             policy: None,
+                            min_shift_duration: None,
+                            min_spacing: None,
                         },
                     ]),
                     ..create_default_vehicle_shift()
@@ -54,6 +59,7 @@ fn can_use_two_breaks() {
                 distance: 198,
                 duration: 204,
                 times: Timing { driving: 198, serving: 2, break_time: 4, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -93,6 +99,7 @@ fn can_use_two_breaks() {
                         distance: 99,
                         parking: None,
                         load: vec![0],
+                        service: None,
                         activities: vec![
                             Activity {
                                 job_id: "job2".to_string(),
@@ -132,7 +139,9 @@ fn can_use_two_breaks() {
                     distance: 198,
                     duration: 204,
                     times: Timing { driving: 198, serving: 2, break_time: 4, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -26,12 +26,14 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                         start: ShiftStart {
                             earliest: format_time(0.),
                             latest: None,
+                            optimize_departure: None,
                             location: vec![100., 0.].to_loc(),
                         },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(1000.).to_string(),
                             location: vec![100., 0.].to_loc(),
+                            alternatives: None,
                         }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
@@ -42,8 +44,11 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                                 tag: None,
                             }],
                             policy,
+                            min_shift_duration: None,
+                            min_spacing: None,
                         }]),
                         reloads: None,
+                        loading: None,
                     }],
                     ..create_default_vehicle_type()
                 },
@@ -65,6 +70,7 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                 distance: 20,
                 duration: 22,
                 times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_without_break_1".to_string(),
@@ -109,7 +115,9 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                     distance: 20,
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -140,6 +148,8 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                             tag: None,
                         }],
                         policy,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -161,6 +171,7 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                 distance: 2,
                 duration: 12,
                 times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -197,7 +208,9 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                     distance: 2,
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             violations: Some(vec![Violation::Break { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
             ..create_empty_solution()
@@ -232,11 +245,15 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                                 tag: None,
                             }],
                             policy: policy.clone(),
+                            min_shift_duration: None,
+                            min_spacing: None,
                         },
                         VehicleBreak::Optional {
                             time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(100.), format_time(120.)]),
                             places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
                             policy,
+                            min_shift_duration: None,
+                            min_spacing: None,
                         },
                     ]),
                     ..create_default_vehicle_shift()
@@ -259,6 +276,7 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -311,7 +329,9 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -348,6 +368,8 @@ fn can_skip_break_depending_on_policy_impl(
                         time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(time.0), format_time(time.1)]),
                         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
                         policy,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -41,6 +41,8 @@ fn get_solution(
                         time: break_time,
                         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: break_location, tag: None }],
                         policy: None,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -80,6 +82,7 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -132,7 +135,9 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -160,6 +165,7 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -212,7 +218,9 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 2, break_time: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
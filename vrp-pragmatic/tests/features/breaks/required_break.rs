@@ -4,7 +4,12 @@ use crate::format_time;
 use crate::helpers::*;
 
 fn create_shift_start() -> ShiftStart {
-    ShiftStart { earliest: format_time(0.), latest: Some(format_time(0.)), location: vec![0., 0.].to_loc() }
+    ShiftStart {
+        earliest: format_time(0.),
+        latest: Some(format_time(0.)),
+        optimize_departure: None,
+        location: vec![0., 0.].to_loc(),
+    }
 }
 
 #[test]
@@ -22,6 +27,7 @@ fn can_assign_break_during_travel() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::ExactTime(format_time(7.)),
                         duration: 2.,
+                        can_interrupt: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -43,6 +49,7 @@ fn can_assign_break_during_travel() {
                 distance: 20,
                 duration: 24,
                 times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -102,7 +109,9 @@ fn can_assign_break_during_travel() {
                     distance: 20,
                     duration: 24,
                     times: Timing { driving: 20, serving: 2, break_time: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -121,6 +130,7 @@ fn can_assign_break_during_activity() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::ExactTime(format_time(7.)),
                         duration: 2.,
+                        can_interrupt: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -142,6 +152,7 @@ fn can_assign_break_during_activity() {
                 distance: 10,
                 duration: 15,
                 times: Timing { driving: 10, serving: 3, break_time: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -165,6 +176,7 @@ fn can_assign_break_during_activity() {
                         distance: 5,
                         parking: None,
                         load: vec![0],
+                        service: None,
                         activities: vec![
                             Activity {
                                 job_id: "job1".to_string(),
@@ -204,7 +216,116 @@ fn can_assign_break_during_activity() {
                     distance: 10,
                     duration: 15,
                     times: Timing { driving: 10, serving: 3, break_time: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_assign_break_before_activity_when_interruption_is_not_allowed() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_duration("job1", vec![5., 0.], 3.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: create_default_vehicle_costs(),
+                shifts: vec![VehicleShift {
+                    start: create_shift_start(),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::ExactTime(format_time(7.)),
+                        duration: 2.,
+                        can_interrupt: Some(false),
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 37.,
+                distance: 10,
+                duration: 17,
+                times: Timing { driving: 10, serving: 3, waiting: 2, break_time: 2, ..Timing::default() },
+                toll: None,
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0,
+                    ),
+                    Stop::Point(PointStop {
+                        location: vec![5., 0.].to_loc(),
+                        time: Schedule {
+                            arrival: "1970-01-01T00:00:05Z".to_string(),
+                            departure: "1970-01-01T00:00:12Z".to_string(),
+                        },
+                        distance: 5,
+                        parking: None,
+                        load: vec![0],
+                        service: None,
+                        activities: vec![
+                            Activity {
+                                job_id: "break".to_string(),
+                                activity_type: "break".to_string(),
+                                location: None,
+                                time: Some(Interval {
+                                    start: "1970-01-01T00:00:07Z".to_string(),
+                                    end: "1970-01-01T00:00:09Z".to_string(),
+                                }),
+                                job_tag: None,
+                                commute: None
+                            },
+                            Activity {
+                                job_id: "job1".to_string(),
+                                activity_type: "delivery".to_string(),
+                                location: Some(vec![5., 0.].to_loc()),
+                                time: Some(Interval {
+                                    start: "1970-01-01T00:00:09Z".to_string(),
+                                    end: "1970-01-01T00:00:12Z".to_string(),
+                                }),
+                                job_tag: None,
+                                commute: None
+                            }
+                        ],
+                    }),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:17Z", "1970-01-01T00:00:17Z"),
+                        10
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 37.,
+                    distance: 10,
+                    duration: 17,
+                    times: Timing { driving: 10, serving: 3, waiting: 2, break_time: 2, ..Timing::default() },
+                    toll: None,
+                },
+                departure: None,
             }],
             ..create_empty_solution()
         }
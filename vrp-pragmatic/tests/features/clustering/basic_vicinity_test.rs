@@ -105,6 +105,7 @@ fn can_cluster_simple_jobs_impl(
                     ),
                 ],
                 statistic,
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -237,6 +238,7 @@ fn can_handle_two_clusters_impl(
                 .chain(stops.into_iter().map(StopData::into))
                 .collect(),
                 statistic,
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -68,6 +68,7 @@ fn can_mix_pickup_delivery_jobs() {
                     ),
                 ],
                 statistic,
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -155,6 +156,7 @@ fn can_vary_cluster_size_based_on_capacity_impl(
                 .collect(),
 
                 statistic,
+                departure: None,
             }],
             unassigned: unassigned.map(|job_ids| job_ids
                 .iter()
@@ -162,7 +164,8 @@ fn can_vary_cluster_size_based_on_capacity_impl(
                     job_id: job_id.to_string(),
                     reasons: vec![UnassignedJobReason {
                         code: "CAPACITY_CONSTRAINT".to_string(),
-                        description: "does not fit into any vehicle due to capacity".to_string()
+                        description: "does not fit into any vehicle due to capacity".to_string(),
+                        details: None,
                     }]
                 })
                 .collect()),
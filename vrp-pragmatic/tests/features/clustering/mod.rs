@@ -82,6 +82,7 @@ impl From<StopData> for Stop {
                 None
             },
             load: vec![stop.load],
+            service: None,
             activities: stop.activities.into_iter().map(ActivityData::into).collect(),
         })
     }
@@ -99,6 +100,7 @@ fn create_statistic(data: (f64, i64, i64, (i64, i64, i64, i64))) -> Statistic {
             parking: data.3 .3,
             ..Timing::default()
         },
+        toll: None,
     }
 }
 
@@ -122,7 +124,13 @@ fn create_test_problem(jobs_data: &[(f64, &str)], capacity: i32, clustering: Clu
                 shifts: vec![create_default_open_vehicle_shift()],
                 ..create_vehicle_with_capacity("my_vehicle", vec![capacity])
             }],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: None,
+                leg_overrides: None,
+            }],
         },
         ..create_empty_problem()
     }
@@ -51,6 +51,7 @@ fn can_use_scale_on_profile() {
                     stop2.into(),
                 ],
                 statistic,
+                departure: None,
             }],
             ..create_empty_solution()
         }
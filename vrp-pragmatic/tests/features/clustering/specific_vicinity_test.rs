@@ -29,18 +29,26 @@ fn can_handle_parking_with_no_clusters_and_job_time_windows() {
                     start: ShiftStart {
                         earliest: "1970-01-01T09:00:00Z".to_string(),
                         latest: None,
+                        optimize_departure: None,
                         location: Location::Coordinate { lat: 52.497, lng: 13.547 },
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "1970-01-01T18:00:00Z".to_string(),
                         location: Location::Coordinate { lat: 52.497, lng: 13.547 },
+                        alternatives: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle("vehicle1")
             }],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: None,
+                leg_overrides: None,
+            }],
         },
         ..create_empty_problem()
     };
@@ -103,20 +111,29 @@ fn can_handle_waiting_time_with_parking_impl(
                     start: ShiftStart {
                         earliest: "1970-01-01T09:00:00Z".to_string(),
                         latest: None,
+                        optimize_departure: None,
                         location: vehicle_location.clone(),
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "1970-01-01T18:00:00Z".to_string(),
                         location: vehicle_location,
+                        alternatives: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle("vehicle1")
             }],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: None,
+                leg_overrides: None,
+            }],
         },
         objectives: None,
+        units: None,
     };
 
     let matrices = create_approx_matrices(&problem);
@@ -74,7 +74,8 @@ fn can_unassign_job_due_to_compatibility() {
         reasons,
         vec![UnassignedJobReason {
             code: "COMPATIBILITY_CONSTRAINT".to_string(),
-            description: "cannot be assigned due to compatibility constraint".to_string()
+            description: "cannot be assigned due to compatibility constraint".to_string(),
+            details: None,
         }]
     );
 }
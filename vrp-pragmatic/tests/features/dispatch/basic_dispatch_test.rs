@@ -39,6 +39,7 @@ fn can_assign_single_dispatch() {
                 distance: 14,
                 duration: 18,
                 times: Timing { driving: 14, serving: 4, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -91,7 +92,9 @@ fn can_assign_single_dispatch() {
                     distance: 14,
                     duration: 18,
                     times: Timing { driving: 14, serving: 4, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -46,6 +46,7 @@ fn can_use_init_solution_with_dispatch() {
             distance: 4,
             duration: 10,
             times: Timing { driving: 4, serving: 6, ..Timing::default() },
+            toll: None,
         },
         tours: vec![
             Tour {
@@ -62,6 +63,7 @@ fn can_use_init_solution_with_dispatch() {
                         distance: 0,
                         parking: None,
                         load: vec![1],
+                        service: None,
                         activities: vec![
                             Activity {
                                 job_id: "departure".to_string(),
@@ -109,7 +111,9 @@ fn can_use_init_solution_with_dispatch() {
                     distance: 2,
                     duration: 5,
                     times: Timing { driving: 2, serving: 3, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             },
             Tour {
                 vehicle_id: "v2".to_string(),
@@ -125,6 +129,7 @@ fn can_use_init_solution_with_dispatch() {
                         distance: 0,
                         parking: None,
                         load: vec![1],
+                        service: None,
                         activities: vec![
                             Activity {
                                 job_id: "departure".to_string(),
@@ -172,7 +177,9 @@ fn can_use_init_solution_with_dispatch() {
                     distance: 2,
                     duration: 5,
                     times: Timing { driving: 2, serving: 3, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             },
         ],
         ..create_empty_solution()
@@ -17,11 +17,17 @@ fn can_use_multiple_times_from_vehicle_and_job() {
             vehicles: vec![VehicleType {
                 shifts: vec![
                     VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            optimize_departure: None,
+                            location: vec![0., 0.].to_loc(),
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(99.).to_string(),
                             location: vec![0., 0.].to_loc(),
+                            alternatives: None,
                         }),
                         ..create_default_vehicle_shift()
                     },
@@ -29,12 +35,14 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         start: ShiftStart {
                             earliest: format_time(100.),
                             latest: None,
+                            optimize_departure: None,
                             location: vec![0., 0.].to_loc(),
                         },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(200.).to_string(),
                             location: vec![0., 0.].to_loc(),
+                            alternatives: None,
                         }),
                         ..create_default_vehicle_shift()
                     },
@@ -58,6 +66,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 distance: 40,
                 duration: 42,
                 times: Timing { driving: 40, serving: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![
                 Tour {
@@ -95,7 +104,9 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         distance: 20,
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, ..Timing::default() },
+                        toll: None,
                     },
+                    departure: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_1".to_string(),
@@ -132,7 +143,9 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         distance: 20,
                         duration: 21,
                         times: Timing { driving: 20, serving: 1, ..Timing::default() },
+                        toll: None,
                     },
+                    departure: None,
                 },
             ],
             ..create_empty_solution()
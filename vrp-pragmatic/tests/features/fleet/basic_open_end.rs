@@ -27,6 +27,7 @@ fn can_use_vehicle_with_open_end() {
                 distance: 1,
                 duration: 2,
                 times: Timing { driving: 1, serving: 1, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -55,7 +56,9 @@ fn can_use_vehicle_with_open_end() {
                     distance: 1,
                     duration: 2,
                     times: Timing { driving: 1, serving: 1, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -0,0 +1,89 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_job_with_demand(id: &str, is_pickup: bool, demand: i32, times: (i32, i32)) -> Job {
+    let job = create_delivery_job_with_times(id, vec![10., 0.], vec![times], 1.);
+    let tasks = job
+        .deliveries
+        .clone()
+        .map(|tasks| tasks.into_iter().map(|task| JobTask { demand: Some(vec![demand]), ..task }).collect());
+
+    if is_pickup {
+        Job { pickups: tasks, deliveries: None, ..job }
+    } else {
+        Job { deliveries: tasks, ..job }
+    }
+}
+
+fn create_shift(start: f64, end: f64) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart {
+            earliest: format_time(start),
+            latest: None,
+            optimize_departure: None,
+            location: vec![0., 0.].to_loc(),
+        },
+        end: Some(ShiftEnd {
+            earliest: None,
+            latest: format_time(end),
+            location: vec![0., 0.].to_loc(),
+            alternatives: None,
+        }),
+        ..create_default_vehicle_shift()
+    }
+}
+
+fn create_test_problem(pickup: i32, delivery: i32) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_job_with_demand("job1", true, pickup, (0, 100)),
+                create_job_with_demand("job2", false, delivery, (200, 300)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_shift(0., 100.), create_shift(200., 300.)],
+                capacity: vec![10],
+                carry_load: Some(true),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_loads(tour: &Tour) -> Vec<i32> {
+    tour.stops.iter().map(|stop| stop.load()[0]).collect()
+}
+
+#[test]
+fn can_carry_load_to_next_shift() {
+    let problem = create_test_problem(4, 5);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let first = solution.tours.iter().find(|tour| tour.shift_index == 0).unwrap();
+    let second = solution.tours.iter().find(|tour| tour.shift_index == 1).unwrap();
+    assert_eq!(get_loads(first), vec![0, 4, 4]);
+    assert_eq!(get_loads(second), vec![9, 4, 4]);
+}
+
+#[test]
+fn can_skip_job_when_carried_load_exceeds_capacity() {
+    let problem = create_test_problem(6, 6);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let unassigned = solution.unassigned.expect("should have unassigned job");
+    assert_eq!(unassigned.len(), 1);
+}
@@ -1,5 +1,7 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod carry_load;
 mod multi_dimens;
+mod multiple_end_locations;
 mod profile_variation;
 mod unreachable_jobs;
@@ -34,6 +34,7 @@ fn can_use_two_dimensions() {
                 distance: 2,
                 duration: 4,
                 times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -70,7 +71,9 @@ fn can_use_two_dimensions() {
                     distance: 2,
                     duration: 4,
                     times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -106,13 +109,15 @@ fn can_unassign_due_to_dimension_mismatch() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, ..Timing::default() },
+                toll: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: "CAPACITY_CONSTRAINT".to_string(),
-                    description: "does not fit into any vehicle due to capacity".to_string()
+                    description: "does not fit into any vehicle due to capacity".to_string(),
+                    details: None,
                 }]
             }]),
             ..create_empty_solution()
@@ -0,0 +1,80 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[test]
+fn can_choose_the_cheapest_end_location() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![9., 0.])], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd {
+                        alternatives: Some(vec![vec![10., 0.].to_loc(), vec![20., 0.].to_loc()]),
+                        ..create_default_vehicle_shift().end.unwrap()
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 31.,
+                distance: 10,
+                duration: 11,
+                times: Timing { driving: 10, serving: 1, ..Timing::default() },
+                toll: None,
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (9., 0.),
+                        0,
+                        ("1970-01-01T00:00:09Z", "1970-01-01T00:00:10Z"),
+                        9
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (10., 0.),
+                        0,
+                        ("1970-01-01T00:00:11Z", "1970-01-01T00:00:11Z"),
+                        10
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 31.,
+                    distance: 10,
+                    duration: 11,
+                    times: Timing { driving: 10, serving: 1, ..Timing::default() },
+                    toll: None,
+                },
+                departure: None,
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
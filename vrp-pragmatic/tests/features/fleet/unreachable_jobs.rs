@@ -15,6 +15,8 @@ fn can_use_vehicle_with_open_end() {
         travel_times: vec![0, 1, 1, 0],
         distances: vec![0, 1, 1, 0],
         error_codes: Some(vec![0, 1, 1, 1]),
+        units: None,
+        tolls: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -27,13 +29,15 @@ fn can_use_vehicle_with_open_end() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, ..Timing::default() },
+                toll: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: "REACHABLE_CONSTRAINT".to_string(),
-                    description: "location unreachable".to_string()
+                    description: "location unreachable".to_string(),
+                    details: None,
                 }]
             }]),
             ..create_empty_solution()
@@ -16,6 +16,7 @@ fn create_test_problem() -> Problem {
                     start: ShiftStart {
                         earliest: format_time(0.),
                         latest: None,
+                        optimize_departure: None,
                         location: Location::Reference { index: 2 },
                     },
                     ..create_default_open_vehicle_shift()
@@ -35,6 +36,8 @@ fn create_test_matrix() -> Matrix {
         travel_times: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         distances: vec![0, 3, 3, 1, 0, 3, 3, 2, 0],
         error_codes: None,
+        units: None,
+        tolls: None,
     }
 }
 
@@ -52,7 +55,8 @@ fn can_use_location_index() {
                 cost: 18.,
                 distance: 3,
                 duration: 5,
-                times: Timing { driving: 3, serving: 2, ..Timing::default() }
+                times: Timing { driving: 3, serving: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -100,12 +104,15 @@ fn can_use_location_index() {
                     cost: 18.,
                     distance: 3,
                     duration: 5,
-                    times: Timing { driving: 3, serving: 2, ..Timing::default() }
-                }
+                    times: Timing { driving: 3, serving: 2, ..Timing::default() },
+                    toll: None,
+                },
+                departure: None,
             }],
             unassigned: None,
             violations: None,
-            extras: None
+            extras: None,
+            units: None,
         }
     );
 }
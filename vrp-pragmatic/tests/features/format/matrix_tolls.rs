@@ -0,0 +1,82 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem(objectives: Option<Vec<Vec<Objective>>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", 1)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: Location::Reference { index: 2 },
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        objectives,
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_matrix() -> Matrix {
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![0, 1, 2, 2, 0, 2, 1, 2, 0],
+        distances: vec![0, 1, 2, 2, 0, 2, 1, 2, 0],
+        error_codes: None,
+        units: None,
+        tolls: Some(vec![0., 0., 0., 0., 0., 0., 3., 0., 0.]),
+    }
+}
+
+fn get_job_ids(solution: &Solution) -> Vec<String> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+#[test]
+fn can_add_tolls_to_cost() {
+    let problem = create_test_problem(None);
+    let matrix = create_test_matrix();
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_job_ids(&solution), vec!["job1", "job2"]);
+    assert_eq!(solution.statistic.toll, Some(3.));
+    assert_eq!(solution.tours[0].statistic.toll, Some(3.));
+    assert_eq!(solution.statistic.cost, 10. + 2. + 4. + 3.);
+}
+
+#[test]
+fn can_minimize_tolls() {
+    let problem = create_test_problem(Some(vec![
+        vec![Objective::MinimizeUnassignedJobs { breaks: None }],
+        vec![Objective::MinimizeTolls],
+        vec![Objective::MinimizeCost],
+    ]));
+    let matrix = create_test_matrix();
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_job_ids(&solution), vec!["job2", "job1"]);
+    assert_eq!(solution.statistic.toll, Some(0.));
+    assert_eq!(solution.statistic.cost, 10. + 4. + 6.);
+}
@@ -1 +1,4 @@
 mod location_index;
+mod matrix_tolls;
+mod planning_units;
+mod walk_in_commute;
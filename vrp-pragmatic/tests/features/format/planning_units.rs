@@ -0,0 +1,42 @@
+use crate::format::problem::*;
+use crate::format::{Location, PlanningUnits};
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_picking_units() -> PlanningUnits {
+    PlanningUnits { duration: "picking-second".to_string(), distance: "shelf".to_string() }
+}
+
+#[test]
+fn can_echo_planning_units_in_solution() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", 1)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: Location::Reference { index: 2 },
+                    },
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        units: Some(create_picking_units()),
+        ..create_empty_problem()
+    };
+    let matrix = Matrix { units: Some(create_picking_units()), ..create_matrix(vec![0, 3, 3, 1, 0, 3, 3, 2, 0]) };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.units, Some(create_picking_units()));
+    assert_eq!(solution.statistic.distance, 3);
+    assert_eq!(solution.statistic.duration, 5);
+}
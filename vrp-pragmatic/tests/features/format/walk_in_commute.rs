@@ -0,0 +1,104 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_visit_job_from_parking_place() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        parking: Some(JobParking { location: vec![3., 0.].to_loc(), duration: 4., distance: 3. }),
+                        ..create_job_place(vec![5., 0.], None)
+                    }],
+                    demand: Some(vec![1]),
+                    order: None,
+                    skills: None,
+                    not_before: None,
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let stops = &solution.tours[0].stops;
+    assert_eq!(stops.len(), 3);
+    let parking_stop = stops[1].as_point().unwrap();
+    assert_eq!(parking_stop.location, vec![3., 0.].to_loc());
+    assert_eq!(parking_stop.time.arrival, format_time(3.));
+    assert_eq!(parking_stop.time.departure, format_time(12.));
+    let activity = parking_stop.activities.first().unwrap();
+    assert_eq!(activity.location, Some(vec![5., 0.].to_loc()));
+    assert_eq!(activity.time, Some(Interval { start: format_time(7.), end: format_time(8.) }));
+    assert_eq!(
+        activity.commute,
+        Some(Commute {
+            forward: Some(CommuteInfo {
+                location: vec![3., 0.].to_loc(),
+                distance: 3.,
+                time: Interval { start: format_time(3.), end: format_time(7.) },
+            }),
+            backward: Some(CommuteInfo {
+                location: vec![3., 0.].to_loc(),
+                distance: 3.,
+                time: Interval { start: format_time(8.), end: format_time(12.) },
+            }),
+        })
+    );
+    assert_eq!(solution.statistic.distance, 6);
+    assert_eq!(solution.statistic.times.commuting, 8);
+}
+
+#[test]
+fn can_reject_relation_with_job_visited_from_parking_place() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    deliveries: Some(vec![JobTask {
+                        places: vec![JobPlace {
+                            parking: Some(JobParking { location: vec![3., 0.].to_loc(), duration: 4., distance: 3. }),
+                            ..create_job_place(vec![5., 0.], None)
+                        }],
+                        demand: Some(vec![1]),
+                        order: None,
+                        skills: None,
+                        not_before: None,
+                    }]),
+                    ..create_job("job1")
+                },
+                create_delivery_job("job2", vec![1., 0.]),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Strict,
+                jobs: to_strings(vec!["departure", "job2", "job1"]),
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let result = (problem, vec![matrix]).read_pragmatic();
+
+    let codes = result.err().map(|errors| errors.into_iter().map(|error| error.code).collect::<Vec<_>>());
+    assert_eq!(codes, Some(vec!["E1113".to_string()]));
+}
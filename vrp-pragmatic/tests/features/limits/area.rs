@@ -31,10 +31,14 @@ fn can_use_constrained_areas() {
                     max_distance: None,
                     shift_time: None,
                     tour_size: None,
+                    max_cost: None,
+                    max_stops: None,
                     areas: Some(vec![
                         vec![AreaLimit { area_id: "area1".to_string(), job_value: 10. }],
                         vec![AreaLimit { area_id: "area2".to_string(), job_value: 1. }],
                     ]),
+                    driver_hours: None,
+                    overtime: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -88,10 +92,14 @@ fn can_use_unconstrained_areas_impl(area1_job_value: f64, expected_job_ids: Vec<
                     max_distance: None,
                     shift_time: None,
                     tour_size: None,
+                    max_cost: None,
+                    max_stops: None,
                     areas: Some(vec![
                         vec![AreaLimit { area_id: "area1".to_string(), job_value: area1_job_value }],
                         vec![AreaLimit { area_id: "area2".to_string(), job_value: 1. }],
                     ]),
+                    driver_hours: None,
+                    overtime: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -0,0 +1,75 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_driver_hours(max_continuous_driving: f64, max_daily_driving: f64, daily_rest: f64) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![10., 0.]), create_delivery_job("job2", vec![20., 0.])],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    max_cost: None,
+                    max_stops: None,
+                    areas: None,
+                    driver_hours: Some(VehicleDriverHours {
+                        max_continuous_driving: Some(max_continuous_driving),
+                        break_duration: Some(5.),
+                        max_daily_driving: Some(max_daily_driving),
+                        daily_rest: Some(daily_rest),
+                    }),
+                    overtime: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_insert_rest_when_continuous_driving_is_exceeded() {
+    let problem = create_problem_with_driver_hours(15., 100., 100.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activities = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.activity_type.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(activities, vec!["departure", "delivery", "rest", "delivery"]);
+    assert_eq!(solution.tours[0].stops[1].schedule().departure, format_time(16.));
+    assert_eq!(solution.tours[0].stops[2].schedule().arrival, format_time(26.));
+}
+
+#[test]
+fn can_insert_daily_rest_when_daily_driving_is_exceeded() {
+    let problem = create_problem_with_driver_hours(15., 15., 100.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activities = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.activity_type.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(activities, vec!["departure", "delivery", "rest", "delivery"]);
+    assert_eq!(solution.tours[0].stops[1].schedule().departure, format_time(111.));
+    assert_eq!(solution.tours[0].stops[2].schedule().arrival, format_time(121.));
+}
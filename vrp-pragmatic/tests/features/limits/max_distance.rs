@@ -8,7 +8,16 @@ fn can_limit_by_max_distance() {
         plan: Plan { jobs: vec![create_delivery_job("job1", vec![100., 0.])], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: Some(99.), shift_time: None, tour_size: None, areas: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(99.),
+                    shift_time: None,
+                    tour_size: None,
+                    max_cost: None,
+                    max_stops: None,
+                    areas: None,
+                    driver_hours: None,
+                    overtime: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_matrix_profiles(),
@@ -21,6 +30,8 @@ fn can_limit_by_max_distance() {
         travel_times: vec![1, 1, 1, 1],
         distances: vec![1, 100, 100, 1],
         error_codes: Option::None,
+        units: None,
+        tolls: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -33,13 +44,15 @@ fn can_limit_by_max_distance() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, ..Timing::default() },
+                toll: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: "MAX_DISTANCE_CONSTRAINT".to_string(),
-                    description: "cannot be assigned due to max distance constraint of vehicle".to_string()
+                    description: "cannot be assigned due to max distance constraint of vehicle".to_string(),
+                    details: None,
                 }]
             }]),
             ..create_empty_solution()
@@ -0,0 +1,43 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_limit_by_max_stops() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![1., 0.]),
+                create_delivery_job("job3", vec![10., 0.]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    max_cost: None,
+                    max_stops: Some(1),
+                    areas: None,
+                    driver_hours: None,
+                    overtime: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops.len(), 3);
+    let unassigned = solution.unassigned.expect("no unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job3");
+    assert_eq!(unassigned[0].reasons[0].code, "MAX_STOPS_CONSTRAINT");
+}
@@ -1,4 +1,8 @@
 mod area;
+mod driver_hours;
+mod max_cost;
 mod max_distance;
+mod max_stops;
+mod overtime;
 mod shift_time;
 mod tour_size;
@@ -0,0 +1,73 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_type_with_overtime(shift_time: f64, max_duration: f64) -> VehicleType {
+    VehicleType {
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: Some(shift_time),
+            tour_size: None,
+            max_cost: None,
+            max_stops: None,
+            areas: None,
+            driver_hours: None,
+            overtime: Some(VehicleOvertime { max_duration, cost: 3. }),
+        }),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_test_problem(max_duration: f64) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_duration("job1", vec![5., 0.], 10.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_overtime(15., max_duration)],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_exceed_shift_time_with_overtime_cost() {
+    let problem = create_test_problem(10.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.tours[0].statistic,
+        Statistic {
+            cost: 55.,
+            distance: 10,
+            duration: 20,
+            times: Timing { driving: 10, serving: 10, ..Timing::default() },
+            toll: None,
+        }
+    );
+    assert_eq!(solution.statistic.cost, 55.);
+}
+
+#[test]
+fn can_limit_overtime_by_max_duration() {
+    let problem = create_test_problem(4.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "SHIFT_TIME_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to shift time constraint of vehicle".to_string(),
+                details: None,
+            }]
+        }])
+    );
+}
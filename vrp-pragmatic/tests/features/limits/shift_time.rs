@@ -4,7 +4,16 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_shift_time_limit(shift_time: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, shift_time: Some(shift_time), tour_size: None, areas: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: Some(shift_time),
+            tour_size: None,
+            max_cost: None,
+            max_stops: None,
+            areas: None,
+            driver_hours: None,
+            overtime: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -25,6 +34,8 @@ fn can_limit_one_job_by_shift_time() {
         travel_times: vec![1, 100, 100, 1],
         distances: vec![1, 1, 1, 1],
         error_codes: Option::None,
+        units: None,
+        tolls: None,
     };
 
     let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
@@ -37,13 +48,15 @@ fn can_limit_one_job_by_shift_time() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, ..Timing::default() },
+                toll: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: "SHIFT_TIME_CONSTRAINT".to_string(),
-                    description: "cannot be assigned due to shift time constraint of vehicle".to_string()
+                    description: "cannot be assigned due to shift time constraint of vehicle".to_string(),
+                    details: None,
                 }]
             }]),
             ..create_empty_solution()
@@ -82,6 +95,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                 distance: 6,
                 duration: 36,
                 times: Timing { driving: 6, serving: 30, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -134,21 +148,25 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
                     distance: 6,
                     duration: 36,
                     times: Timing { driving: 6, serving: 30, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             unassigned: Some(vec![
                 UnassignedJob {
                     job_id: "job4".to_string(),
                     reasons: vec![UnassignedJobReason {
                         code: "SHIFT_TIME_CONSTRAINT".to_string(),
-                        description: "cannot be assigned due to shift time constraint of vehicle".to_string()
+                        description: "cannot be assigned due to shift time constraint of vehicle".to_string(),
+                        details: None,
                     }]
                 },
                 UnassignedJob {
                     job_id: "job5".to_string(),
                     reasons: vec![UnassignedJobReason {
                         code: "SHIFT_TIME_CONSTRAINT".to_string(),
-                        description: "cannot be assigned due to shift time constraint of vehicle".to_string()
+                        description: "cannot be assigned due to shift time constraint of vehicle".to_string(),
+                        details: None,
                     }]
                 }
             ]),
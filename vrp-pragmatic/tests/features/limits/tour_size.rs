@@ -16,7 +16,16 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                limits: Some(VehicleLimits { max_distance: None, shift_time: None, areas: None, tour_size: Some(2) }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    areas: None,
+                    driver_hours: None,
+                    overtime: None,
+                    tour_size: Some(2),
+                    max_cost: None,
+                    max_stops: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_matrix_profiles(),
@@ -35,6 +44,7 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                 distance: 2,
                 duration: 4,
                 times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -71,13 +81,16 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                     distance: 2,
                     duration: 4,
                     times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job3".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: "TOUR_SIZE_CONSTRAINT".to_string(),
-                    description: "cannot be assigned due to tour size constraint of vehicle".to_string()
+                    description: "cannot be assigned due to tour size constraint of vehicle".to_string(),
+                    details: None,
                 }]
             }]),
             ..create_empty_solution()
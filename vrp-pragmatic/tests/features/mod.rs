@@ -15,5 +15,7 @@ mod priorities;
 mod relations;
 mod reload;
 mod skills;
+mod sync;
 mod timing;
+mod transfer;
 mod work_balance;
@@ -34,6 +34,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 distance: 16,
                 duration: 20,
                 times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -97,7 +98,9 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                     distance: 16,
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -133,6 +136,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                 distance: 12,
                 duration: 15,
                 times: Timing { driving: 12, serving: 3, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -188,7 +192,9 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                     distance: 12,
                     duration: 15,
                     times: Timing { driving: 12, serving: 3, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -21,6 +21,7 @@ fn can_assign_replacement_job() {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![4., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
@@ -42,6 +43,7 @@ fn can_assign_replacement_job() {
                 distance: 4,
                 duration: 7,
                 times: Timing { driving: 4, serving: 3, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -94,7 +96,9 @@ fn can_assign_replacement_job() {
                     distance: 4,
                     duration: 7,
                     times: Timing { driving: 4, serving: 3, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
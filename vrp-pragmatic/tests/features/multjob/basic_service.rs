@@ -21,6 +21,7 @@ fn can_assign_service_job() {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![4., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
@@ -42,6 +43,7 @@ fn can_assign_service_job() {
                 distance: 4,
                 duration: 7,
                 times: Timing { driving: 4, serving: 3, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -94,9 +96,58 @@ fn can_assign_service_job() {
                     distance: 4,
                     duration: 7,
                     times: Timing { driving: 4, serving: 3, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
     );
 }
+
+#[test]
+fn can_assign_service_jobs_without_reloads() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_service_job("job1", vec![1., 0.]),
+                create_service_job("job2", vec![2., 0.]),
+                create_service_job("job3", vec![3., 0.]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        times: None,
+                        location: vec![0., 0.].to_loc(),
+                        duration: 2.0,
+                        tag: None,
+                        capacity: None,
+                        per_unit_duration: None,
+                        resource: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activity_types = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| activity.activity_type.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(activity_types, vec!["departure", "service", "service", "service", "arrival"]);
+}
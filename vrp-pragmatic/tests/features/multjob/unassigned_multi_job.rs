@@ -31,13 +31,15 @@ fn can_unassign_multi_job_due_to_capacity() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, ..Timing::default() },
+                toll: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "multi".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: "CAPACITY_CONSTRAINT".to_string(),
-                    description: "does not fit into any vehicle due to capacity".to_string()
+                    description: "does not fit into any vehicle due to capacity".to_string(),
+                    details: None,
                 }]
             }]),
             ..create_empty_solution()
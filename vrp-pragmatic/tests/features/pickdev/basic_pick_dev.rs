@@ -27,6 +27,7 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                 distance: 4,
                 duration: 6,
                 times: Timing { driving: 4, serving: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -73,7 +74,9 @@ fn can_use_one_pickup_delivery_job_with_one_vehicle() {
                     distance: 4,
                     duration: 6,
                     times: Timing { driving: 4, serving: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
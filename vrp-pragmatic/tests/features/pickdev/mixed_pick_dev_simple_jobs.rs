@@ -31,6 +31,7 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                 distance: 8,
                 duration: 12,
                 times: Timing { driving: 8, serving: 4, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -93,7 +94,9 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
                     distance: 8,
                     duration: 12,
                     times: Timing { driving: 8, serving: 4, ..Timing::default() },
-                }
+                    toll: None,
+                },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -39,6 +39,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 distance: 50,
                 duration: 54,
                 times: Timing { driving: 50, serving: 4, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -103,7 +104,9 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                     distance: 50,
                     duration: 54,
                     times: Timing { driving: 50, serving: 4, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
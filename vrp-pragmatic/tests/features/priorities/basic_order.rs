@@ -14,7 +14,16 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), shift_time: None, tour_size: None, areas: None })
+    Some(VehicleLimits {
+        max_distance: Some(15.),
+        shift_time: None,
+        tour_size: None,
+        max_cost: None,
+        max_stops: None,
+        areas: None,
+        driver_hours: None,
+        overtime: None,
+    })
 }
 
 #[test]
@@ -36,6 +45,7 @@ fn can_follow_orders() {
                 distance: 20,
                 duration: 23,
                 times: Timing { driving: 20, serving: 3, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -88,7 +98,9 @@ fn can_follow_orders() {
                     distance: 20,
                     duration: 23,
                     times: Timing { driving: 20, serving: 3, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -141,7 +153,8 @@ fn can_follow_order_when_prioritized_property_set() {
             job_id: "job3".to_string(),
             reasons: vec![UnassignedJobReason {
                 code: "MAX_DISTANCE_CONSTRAINT".to_string(),
-                description: "cannot be assigned due to max distance constraint of vehicle".to_string()
+                description: "cannot be assigned due to max distance constraint of vehicle".to_string(),
+                details: None,
             }]
         }])
     );
@@ -46,7 +46,8 @@ fn can_prefer_jobs_with_more_value_impl(objectives: Option<Vec<Vec<Objective>>>)
             job_id: "job1".to_string(),
             reasons: vec![UnassignedJobReason {
                 code: "CAPACITY_CONSTRAINT".to_string(),
-                description: "does not fit into any vehicle due to capacity".to_string()
+                description: "does not fit into any vehicle due to capacity".to_string(),
+                details: None,
             }]
         }
     );
@@ -1,2 +1,3 @@
+mod basic_latest_eta;
 mod basic_order;
 mod basic_value;
@@ -34,3 +34,39 @@ fn can_skip_constraints_check() {
         Ok(_) => unreachable!("unexpected load or missing checker rule"),
     }
 }
+
+#[test]
+fn can_seed_jobs_respecting_time_windows() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 5)], 1.),
+                create_delivery_job_with_times("job2", vec![10., 0.], vec![(0, 100)], 1.),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Any,
+                jobs: to_strings(vec!["job2", "job1"]),
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.tours[0]
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .map(|a| a.job_id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["departure", "job1", "job2", "arrival"]
+    );
+}
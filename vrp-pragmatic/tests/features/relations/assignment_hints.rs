@@ -0,0 +1,87 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::common::IdDimension;
+use vrp_core::utils::Environment;
+
+fn create_test_problem(capacity: i32, hint_jobs: Vec<&str>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            hints: Some(vec![AssignmentHint {
+                jobs: to_strings(hint_jobs),
+                vehicle_id: "v2".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                capacity: vec![capacity],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_seeded_routes(problem: Problem) -> (Vec<(String, Vec<String>)>, usize) {
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+
+    let insertion_ctx = InsertionContext::new_with_hints(problem, Arc::new(Environment::default()));
+
+    let routes = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| {
+            let vehicle_id = route_ctx.route.actor.vehicle.dimens.get_id().unwrap().clone();
+            let job_ids = route_ctx
+                .route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.retrieve_job())
+                .map(|job| job.dimens().get_id().unwrap().clone())
+                .collect::<Vec<_>>();
+
+            (vehicle_id, job_ids)
+        })
+        .collect();
+
+    (routes, insertion_ctx.solution.unassigned.len())
+}
+
+#[test]
+fn can_seed_initial_solution_with_hints() {
+    let (routes, unassigned) = get_seeded_routes(create_test_problem(10, vec!["job3", "job1"]));
+
+    assert_eq!(routes, vec![("v2".to_string(), to_strings(vec!["job3", "job1"]))]);
+    assert_eq!(unassigned, 1);
+}
+
+#[test]
+fn can_skip_hinted_jobs_which_cannot_be_inserted() {
+    let (routes, unassigned) = get_seeded_routes(create_test_problem(1, vec!["job2", "job3"]));
+
+    assert_eq!(routes, vec![("v2".to_string(), to_strings(vec!["job2"]))]);
+    assert_eq!(unassigned, 2);
+}
+
+#[test]
+fn can_solve_problem_with_hints() {
+    let problem = create_test_problem(2, vec!["job3", "job1", "job2"]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 10);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.iter().map(|tour| tour.stops.len() - 2).sum::<usize>(), 3);
+}
@@ -0,0 +1,42 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn get_job_ids(tour: &Tour) -> Vec<String> {
+    tour.stops.iter().flat_map(|stop| stop.activities().iter()).map(|activity| activity.job_id.clone()).collect()
+}
+
+#[test]
+fn can_keep_executed_stops_when_new_job_is_added() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![5], ..create_default_vehicle_type() }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem.clone(), Some(vec![matrix]));
+    assert_eq!(solution.tours.len(), 1);
+    let executed = get_job_ids(&solution.tours[0]).into_iter().take(3).collect::<Vec<_>>();
+
+    let mut problem = lock_executed_stops(&problem, &solution, &ExecutedStops::Count(3)).unwrap();
+    problem.plan.jobs.push(create_delivery_job("job5", vec![0., 0.]));
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_ids = get_job_ids(&solution.tours[0]);
+    assert_eq!(job_ids.len(), 7);
+    assert_eq!(job_ids.into_iter().take(3).collect::<Vec<_>>(), executed);
+}
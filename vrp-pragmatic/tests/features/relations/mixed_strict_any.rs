@@ -46,6 +46,7 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 distance: 18,
                 duration: 25,
                 times: Timing { driving: 18, serving: 7, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -130,7 +131,9 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                     distance: 18,
                     duration: 25,
                     times: Timing { driving: 18, serving: 7, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -46,6 +46,7 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 distance: 22,
                 duration: 29,
                 times: Timing { driving: 22, serving: 7, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -130,7 +131,9 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                     distance: 22,
                     duration: 29,
                     times: Timing { driving: 22, serving: 7, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -202,6 +205,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 distance: 26,
                 duration: 34,
                 times: Timing { driving: 26, serving: 8, ..Timing::default() },
+                toll: None,
             },
             tours: vec![
                 Tour {
@@ -255,7 +259,9 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         distance: 13,
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, ..Timing::default() },
+                        toll: None,
                     },
+                    departure: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -308,7 +314,9 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                         distance: 13,
                         duration: 17,
                         times: Timing { driving: 13, serving: 4, ..Timing::default() },
+                        toll: None,
                     },
+                    departure: None,
                 }
             ],
             ..create_empty_solution()
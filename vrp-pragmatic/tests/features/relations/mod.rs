@@ -1,7 +1,10 @@
 mod any_basic;
 mod any_with_new_jobs;
+mod assignment_hints;
+mod executed_stops_lock;
 mod mixed_strict_any;
 mod mixed_strict_sequence;
+mod readonly_route;
 mod sequence_with_new_jobs;
 mod strict_with_new_jobs;
 mod strict_with_old_jobs;
@@ -0,0 +1,81 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_keep_readonly_route_with_new_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+                create_delivery_job("job5", vec![5., 0.]),
+            ],
+            readonly_routes: Some(vec![ReadonlyRoute {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                jobs: to_strings(vec!["job3", "job1"]),
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![5],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let get_tour = |vehicle_id: &str| solution.tours.iter().find(|tour| tour.vehicle_id == vehicle_id).unwrap();
+    assert_eq!(
+        get_ids_from_tour(get_tour("my_vehicle_1")),
+        vec![vec!["departure"], vec!["job3"], vec!["job1"], vec!["arrival"]]
+    );
+    let mut other_ids = get_ids_from_tour(get_tour("my_vehicle_2")).into_iter().flatten().collect::<Vec<_>>();
+    other_ids.sort();
+    assert_eq!(other_ids, vec!["arrival", "departure", "job2", "job4", "job5"]);
+}
+
+#[test]
+fn can_keep_readonly_route_with_departure_and_arrival_ids() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            readonly_routes: Some(vec![ReadonlyRoute {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                jobs: to_strings(vec!["departure", "job3", "job1", "arrival"]),
+            }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![5],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = solution.tours.iter().find(|tour| tour.vehicle_id == "my_vehicle_1").unwrap();
+    assert_eq!(get_ids_from_tour(tour), vec![vec!["departure"], vec!["job3"], vec!["job1"], vec!["arrival"]]);
+}
@@ -43,6 +43,7 @@ fn can_use_sequence_relation_with_strict_time_windows() {
                 distance: 100,
                 duration: 160,
                 times: Timing { driving: 100, serving: 50, waiting: 10, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -111,7 +112,9 @@ fn can_use_sequence_relation_with_strict_time_windows() {
                     distance: 100,
                     duration: 160,
                     times: Timing { driving: 100, serving: 50, waiting: 10, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -56,6 +56,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                 distance: 42,
                 duration: 52,
                 times: Timing { driving: 42, serving: 10, ..Timing::default() },
+                toll: None,
             },
             tours: vec![
                 Tour {
@@ -125,7 +126,9 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         distance: 22,
                         duration: 27,
                         times: Timing { driving: 22, serving: 5, ..Timing::default() },
+                        toll: None,
                     },
+                    departure: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -194,7 +197,9 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         distance: 20,
                         duration: 25,
                         times: Timing { driving: 20, serving: 5, ..Timing::default() },
+                        toll: None,
                     },
+                    departure: None,
                 }
             ],
             ..create_empty_solution()
@@ -54,6 +54,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 distance: 34,
                 duration: 42,
                 times: Timing { driving: 34, serving: 8, ..Timing::default() },
+                toll: None,
             },
             tours: vec![
                 Tour {
@@ -115,7 +116,9 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         distance: 20,
                         duration: 24,
                         times: Timing { driving: 20, serving: 4, ..Timing::default() },
+                        toll: None,
                     },
+                    departure: None,
                 },
                 Tour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -176,7 +179,9 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                         distance: 14,
                         duration: 18,
                         times: Timing { driving: 14, serving: 4, ..Timing::default() },
+                        toll: None,
                     },
+                    departure: None,
                 }
             ],
             ..create_empty_solution()
@@ -28,11 +28,17 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     dispatch: None,
                     breaks: None,
@@ -41,7 +47,11 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                         location: vec![0., 0.].to_loc(),
                         duration: 2.0,
                         tag: None,
+                        capacity: None,
+                        per_unit_duration: None,
+                        resource: None,
                     }]),
+                    loading: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -62,6 +72,7 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                 distance: 16,
                 duration: 20,
                 times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -125,7 +136,9 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                     distance: 16,
                     duration: 20,
                     times: Timing { driving: 16, serving: 4, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -22,8 +22,9 @@ can_use_vehicle_with_two_tours_and_two_jobs! {
                     job_id: "job3".to_string(),
                     reasons: vec![UnassignedJobReason {
                         code: "CAPACITY_CONSTRAINT".to_string(),
-                        description: "does not fit into any vehicle due to capacity".to_string()
-                    }]
+                        description: "does not fit into any vehicle due to capacity".to_string(),
+details: None,
+}]
                 }
              ])),
 }
@@ -34,11 +35,17 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     dispatch: None,
                     breaks: None,
@@ -47,7 +54,11 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         location: vec![0., 0.].to_loc(),
                         duration: 2.0,
                         tag: None,
+                        capacity: None,
+                        per_unit_duration: None,
+                        resource: None,
                     }]),
+                    loading: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -68,6 +79,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -120,7 +132,9 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             unassigned,
             ..create_empty_solution()
@@ -0,0 +1,79 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_change_vehicle_capacity_at_reload, (vehicle_capacity, reload_capacity, expected_unassigned, has_reload), {
+    can_change_vehicle_capacity_at_reload_impl(vehicle_capacity, reload_capacity, expected_unassigned, has_reload);
+}}
+
+can_change_vehicle_capacity_at_reload! {
+    case01: (vec![1], None, 1, true),
+    case02: (vec![1], Some(vec![2]), 0, true),
+    case03: (vec![], Some(vec![]), 3, false),
+}
+
+fn can_change_vehicle_capacity_at_reload_impl(
+    vehicle_capacity: Vec<i32>,
+    reload_capacity: Option<Vec<i32>>,
+    expected_unassigned: usize,
+    has_reload: bool,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.).to_string(),
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                    }),
+                    dispatch: None,
+                    breaks: None,
+                    reloads: Some(vec![VehicleReload {
+                        times: None,
+                        location: vec![0., 0.].to_loc(),
+                        duration: 2.0,
+                        tag: None,
+                        capacity: reload_capacity,
+                        per_unit_duration: None,
+                        resource: None,
+                    }]),
+                    loading: None,
+                }],
+                capacity: vehicle_capacity,
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), expected_unassigned);
+    assert_eq!(
+        solution
+            .tours
+            .iter()
+            .flat_map(|tour| tour.stops.iter())
+            .flat_map(|stop| stop.activities().iter())
+            .any(|activity| activity.activity_type == "reload"),
+        has_reload
+    );
+}
@@ -18,11 +18,17 @@ fn can_use_reloads_with_different_locations() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.),
                         location: vec![32., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     dispatch: None,
                     breaks: None,
@@ -32,14 +38,21 @@ fn can_use_reloads_with_different_locations() {
                             location: vec![12., 0.].to_loc(),
                             duration: 2.0,
                             tag: Some("close".to_string()),
+                            capacity: None,
+                            per_unit_duration: None,
+                            resource: None,
                         },
                         VehicleReload {
                             times: None,
                             location: vec![33., 0.].to_loc(),
                             duration: 2.0,
                             tag: Some("far".to_string()),
+                            capacity: None,
+                            per_unit_duration: None,
+                            resource: None,
                         },
                     ]),
+                    loading: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -0,0 +1,212 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_reload(reload: VehicleReload) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 2)], 1.),
+                create_delivery_job("job2", vec![2., 0.]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.).to_string(),
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                    }),
+                    dispatch: None,
+                    breaks: None,
+                    reloads: Some(vec![reload]),
+                    loading: None,
+                }],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_reload_duration_per_unit_of_load() {
+    let problem = create_problem_with_reload(VehicleReload {
+        times: None,
+        location: vec![0., 0.].to_loc(),
+        duration: 2.0,
+        tag: None,
+        capacity: None,
+        per_unit_duration: Some(3.),
+        resource: None,
+    });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 29.,
+                distance: 6,
+                duration: 13,
+                times: Timing { driving: 6, serving: 7, ..Timing::default() },
+                toll: None,
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                        1
+                    ),
+                    create_stop_with_activity(
+                        "reload",
+                        "reload",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:03Z", "1970-01-01T00:00:08Z"),
+                        2
+                    ),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (2., 0.),
+                        0,
+                        ("1970-01-01T00:00:10Z", "1970-01-01T00:00:11Z"),
+                        4
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:13Z", "1970-01-01T00:00:13Z"),
+                        6
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 29.,
+                    distance: 6,
+                    duration: 13,
+                    times: Timing { driving: 6, serving: 7, ..Timing::default() },
+                    toll: None,
+                },
+                departure: None,
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_wait_for_reload_opening_hours() {
+    let problem = create_problem_with_reload(VehicleReload {
+        times: Some(vec![vec![format_time(10.), format_time(100.)]]),
+        location: vec![0., 0.].to_loc(),
+        duration: 2.0,
+        tag: None,
+        capacity: None,
+        per_unit_duration: None,
+        resource: None,
+    });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 33.,
+                distance: 6,
+                duration: 17,
+                times: Timing { driving: 6, serving: 4, waiting: 7, ..Timing::default() },
+                toll: None,
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (1., 0.),
+                        0,
+                        ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                        1
+                    ),
+                    create_stop_with_activity(
+                        "reload",
+                        "reload",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:03Z", "1970-01-01T00:00:12Z"),
+                        2
+                    ),
+                    create_stop_with_activity(
+                        "job2",
+                        "delivery",
+                        (2., 0.),
+                        0,
+                        ("1970-01-01T00:00:14Z", "1970-01-01T00:00:15Z"),
+                        4
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:17Z", "1970-01-01T00:00:17Z"),
+                        6
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 33.,
+                    distance: 6,
+                    duration: 17,
+                    times: Timing { driving: 6, serving: 4, waiting: 7, ..Timing::default() },
+                    toll: None,
+                },
+                departure: None,
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
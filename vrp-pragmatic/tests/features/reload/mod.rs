@@ -1,7 +1,10 @@
 mod avoid_reload;
 mod basic_reload;
+mod capacity_reload;
 mod diff_reload_places;
+mod duration_reload;
 mod multi_dim_reload;
 mod multi_job_reload;
 mod multi_vehicle_reload;
 mod picks_devs_reload;
+mod resource_reload;
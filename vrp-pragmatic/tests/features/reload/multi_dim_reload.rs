@@ -16,11 +16,17 @@ fn can_use_multi_dim_capacity() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     dispatch: None,
                     breaks: None,
@@ -29,7 +35,11 @@ fn can_use_multi_dim_capacity() {
                         location: vec![0., 0.].to_loc(),
                         duration: 2.0,
                         tag: None,
+                        capacity: None,
+                        per_unit_duration: None,
+                        resource: None,
                     }]),
+                    loading: None,
                 }],
                 capacity: vec![1, 1],
                 ..create_default_vehicle_type()
@@ -50,6 +60,7 @@ fn can_use_multi_dim_capacity() {
                 distance: 6,
                 duration: 10,
                 times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -102,7 +113,9 @@ fn can_use_multi_dim_capacity() {
                     distance: 6,
                     duration: 10,
                     times: Timing { driving: 6, serving: 4, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
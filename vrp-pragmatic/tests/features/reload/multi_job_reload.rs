@@ -23,11 +23,17 @@ fn can_serve_multi_job_and_delivery_with_reload() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![10., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     dispatch: None,
                     breaks: None,
@@ -36,7 +42,11 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         location: vec![0., 0.].to_loc(),
                         duration: 2.0,
                         tag: None,
+                        capacity: None,
+                        per_unit_duration: None,
+                        resource: None,
                     }]),
+                    loading: None,
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -57,6 +67,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 distance: 14,
                 duration: 22,
                 times: Timing { driving: 14, serving: 8, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -144,7 +155,9 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                     distance: 14,
                     duration: 22,
                     times: Timing { driving: 14, serving: 8, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -168,7 +181,13 @@ fn can_properly_handle_load_without_capacity_violation() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003 },
+                costs: VehicleCosts {
+                    fixed: Some(20.0),
+                    distance: 0.002,
+                    time: 0.003,
+                    waiting: None,
+                    depot_waiting: None,
+                },
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![
                         VehicleReload {
@@ -176,12 +195,18 @@ fn can_properly_handle_load_without_capacity_violation() {
                             location: Location::Coordinate { lat: 0.0, lng: 0.0 },
                             duration: 2620.0,
                             tag: None,
+                            capacity: None,
+                            per_unit_duration: None,
+                            resource: None,
                         },
                         VehicleReload {
                             times: None,
                             location: Location::Coordinate { lat: 0.0, lng: 0.0 },
                             duration: 2874.0,
                             tag: None,
+                            capacity: None,
+                            per_unit_duration: None,
+                            resource: None,
                         },
                     ]),
                     ..create_default_vehicle_shift()
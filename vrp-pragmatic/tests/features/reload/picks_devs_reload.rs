@@ -19,11 +19,17 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(100.).to_string(),
                         location: vec![6., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     dispatch: None,
                     breaks: None,
@@ -32,7 +38,11 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         location: vec![3., 0.].to_loc(),
                         duration: 2.0,
                         tag: None,
+                        capacity: None,
+                        per_unit_duration: None,
+                        resource: None,
                     }]),
+                    loading: None,
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -114,7 +124,9 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 distance: 6,
                 duration: 12,
                 times: Timing { driving: 6, serving: 6, ..Timing::default() },
+                toll: None,
             },
+            departure: None,
         }]
     );
     assert_eq!(
@@ -124,6 +136,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
             distance: 6,
             duration: 12,
             times: Timing { driving: 6, serving: 6, ..Timing::default() },
+            toll: None,
         }
     );
     assert!(solution.violations.is_none());
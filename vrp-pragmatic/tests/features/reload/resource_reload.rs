@@ -0,0 +1,68 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+#[test]
+fn can_limit_simultaneous_reloads_with_shared_resource() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_delivery_job("job4", vec![4., 0.]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(20.).to_string(),
+                        location: vec![0., 0.].to_loc(),
+                        alternatives: None,
+                    }),
+                    dispatch: None,
+                    breaks: None,
+                    reloads: Some(vec![VehicleReload {
+                        times: None,
+                        location: vec![0., 0.].to_loc(),
+                        duration: 4.0,
+                        tag: None,
+                        capacity: None,
+                        per_unit_duration: None,
+                        resource: Some(VehicleReloadResource { id: "depot".to_string(), capacity: 1 }),
+                    }]),
+                    loading: None,
+                }],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let reloads = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter(|stop| stop.activities().iter().any(|activity| activity.activity_type == "reload"))
+        .map(|stop| (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)))
+        .collect::<Vec<_>>();
+    assert_eq!(reloads.len(), 2);
+    assert!(reloads[0].1 <= reloads[1].0 || reloads[1].1 <= reloads[0].0);
+}
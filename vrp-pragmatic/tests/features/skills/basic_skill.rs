@@ -40,6 +40,7 @@ fn can_wait_for_job_start() {
                 distance: 18,
                 duration: 19,
                 times: Timing { driving: 18, serving: 1, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "vehicle_with_skill_1".to_string(),
@@ -76,7 +77,9 @@ fn can_wait_for_job_start() {
                     distance: 18,
                     duration: 19,
                     times: Timing { driving: 18, serving: 1, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
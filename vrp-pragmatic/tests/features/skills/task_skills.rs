@@ -0,0 +1,80 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_multi_job_with_delivery_skills(id: &str, skills: JobSkills) -> Job {
+    let job = create_multi_job(id, vec![((1., 0.), 1., vec![1])], vec![((2., 0.), 1., vec![1])]);
+
+    Job {
+        deliveries: job
+            .deliveries
+            .map(|tasks| tasks.into_iter().map(|task| JobTask { skills: Some(skills.clone()), ..task }).collect()),
+        ..job
+    }
+}
+
+fn create_vehicle_type_with_skill(type_id: &str, skills: Option<Vec<String>>) -> VehicleType {
+    VehicleType {
+        type_id: type_id.to_string(),
+        vehicle_ids: vec![format!("{}_1", type_id)],
+        shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+        skills,
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_serve_multi_job_with_task_skills() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_multi_job_with_delivery_skills("job1", all_of_skills(vec!["unique_skill".to_string()]))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("vehicle_without_skill"),
+                create_vehicle_type_with_skill("vehicle_with_skill", Some(vec!["unique_skill".to_string()])),
+            ],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, "vehicle_with_skill_1");
+}
+
+#[test]
+fn can_have_unassigned_multi_job_due_to_task_skills() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_multi_job_with_delivery_skills("job1", all_of_skills(vec!["unique_skill".to_string()]))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle_without_skill")],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "SKILL_CONSTRAINT".to_string(),
+                description: "cannot serve required skill".to_string(),
+                details: None,
+            }]
+        }])
+    );
+}
@@ -31,13 +31,15 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
                 distance: 0,
                 duration: 0,
                 times: Timing { driving: 0, serving: 0, ..Timing::default() },
+                toll: None,
             },
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: "SKILL_CONSTRAINT".to_string(),
-                    description: "cannot serve required skill".to_string()
+                    description: "cannot serve required skill".to_string(),
+                    details: None,
                 }]
             }]),
             ..create_empty_solution()
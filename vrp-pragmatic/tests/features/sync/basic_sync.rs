@@ -0,0 +1,78 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_with_skill(id: &str, skill: &str) -> VehicleType {
+    VehicleType {
+        type_id: id.to_string(),
+        vehicle_ids: vec![format!("{}_1", id)],
+        skills: Some(vec![skill.to_string()]),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_problem(
+    max_gap: Option<f64>,
+    first_visit_times: Vec<(i32, i32)>,
+    second_visit_times: Vec<(i32, i32)>,
+) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_sync_job(
+                "job1",
+                vec![
+                    (vec![5., 0.], 10., first_visit_times, "electrician"),
+                    (vec![5., 0.], 5., second_visit_times, "inspector"),
+                ],
+                max_gap,
+            )],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_skill("electrician", "electrician"),
+                create_vehicle_with_skill("inspector", "inspector"),
+            ],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_visit_tags(solution: &Solution, vehicle_id: &str) -> Vec<String> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.job_id == "job1")
+        .filter_map(|activity| activity.job_tag.clone())
+        .collect()
+}
+
+#[test]
+fn can_serve_visits_by_different_vehicles_in_order() {
+    let problem = create_problem(Some(10.), vec![], vec![(15, 100)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_visit_tags(&solution, "electrician_1"), vec!["v1".to_string()]);
+    assert_eq!(get_visit_tags(&solution, "inspector_1"), vec!["v2".to_string()]);
+}
+
+#[test]
+fn can_unassign_all_visits_when_max_gap_is_exceeded() {
+    let problem = create_problem(Some(3.), vec![(0, 5)], vec![(25, 100)]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned.iter().flat_map(|jobs| jobs.iter()).map(|job| job.job_id.as_str()).collect::<Vec<_>>(),
+        vec!["job1"]
+    );
+}
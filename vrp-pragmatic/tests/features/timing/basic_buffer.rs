@@ -0,0 +1,35 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_keep_buffer_after_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { buffer: Some(5.), ..create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 100)], 0.) },
+                create_delivery_job_with_times("job2", vec![3., 0.], vec![(0, 5)], 0.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle("my_vehicle")
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        objectives: Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![MaximizeRespectedBuffers],
+            vec![MinimizeCost],
+        ]),
+        units: None,
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job2"], vec!["job1"]]);
+}
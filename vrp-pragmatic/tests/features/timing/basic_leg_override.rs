@@ -0,0 +1,34 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_use_leg_override_instead_of_matrix() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![10., 0.])], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: None,
+                leg_overrides: Some(vec![LegOverride {
+                    from: vec![0., 0.].to_loc(),
+                    to: vec![10., 0.].to_loc(),
+                    duration: 3.,
+                    distance: Some(2.),
+                }]),
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let arrivals = solution.tours[0].stops.iter().map(|stop| stop.schedule().arrival.clone()).collect::<Vec<_>>();
+    assert_eq!(arrivals, vec![format_time(0.), format_time(3.), format_time(14.)]);
+    assert_eq!(solution.statistic.distance, 12);
+}
@@ -0,0 +1,150 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_loading(job: Job, latest: Option<f64>, loading: VehicleLoading) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: latest.map(format_time),
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
+                    loading: Some(loading),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_delay_departure_by_loading_time() {
+    let problem = create_problem_with_loading(
+        create_delivery_job("job1", vec![5., 0.]),
+        None,
+        VehicleLoading { duration: 5., per_unit_duration: Some(2.) },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 38.,
+                distance: 10,
+                duration: 18,
+                times: Timing { driving: 10, serving: 8, ..Timing::default() },
+                toll: None,
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:07Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (5., 0.),
+                        0,
+                        ("1970-01-01T00:00:12Z", "1970-01-01T00:00:13Z"),
+                        5
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:18Z", "1970-01-01T00:00:18Z"),
+                        10
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 38.,
+                    distance: 10,
+                    duration: 18,
+                    times: Timing { driving: 10, serving: 8, ..Timing::default() },
+                    toll: None,
+                },
+                departure: None,
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_load_vehicle_within_departure_time_window() {
+    let problem = create_problem_with_loading(
+        create_delivery_job_with_times("job1", vec![5., 0.], vec![(20, 30)], 1.),
+        Some(100.),
+        VehicleLoading { duration: 5., per_unit_duration: Some(2.) },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let tour = &solution.tours[0];
+    assert_eq!(
+        tour.stops[0].schedule().clone(),
+        Schedule { arrival: "1970-01-01T00:00:00Z".to_string(), departure: "1970-01-01T00:00:15Z".to_string() }
+    );
+    assert_eq!(
+        tour.stops[1].schedule().clone(),
+        Schedule { arrival: "1970-01-01T00:00:20Z".to_string(), departure: "1970-01-01T00:00:21Z".to_string() }
+    );
+    assert_eq!(
+        tour.statistic,
+        Statistic {
+            cost: 38.,
+            distance: 10,
+            duration: 18,
+            times: Timing { driving: 10, serving: 8, ..Timing::default() },
+            toll: None,
+        }
+    );
+}
+
+#[test]
+fn can_unassign_job_when_loading_delays_arrival() {
+    let problem = create_problem_with_loading(
+        create_delivery_job_with_times("job1", vec![5., 0.], vec![(0, 10)], 1.),
+        None,
+        VehicleLoading { duration: 3., per_unit_duration: Some(3.) },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "TIME_WINDOW_CONSTRAINT".to_string(),
+                description: "cannot be visited within time window".to_string(),
+                details: None,
+            }]
+        }])
+    );
+}
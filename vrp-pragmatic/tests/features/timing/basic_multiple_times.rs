@@ -33,6 +33,7 @@ fn can_use_multiple_times() {
                 distance: 100,
                 duration: 130,
                 times: Timing { driving: 100, serving: 0, waiting: 30, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -101,7 +102,9 @@ fn can_use_multiple_times() {
                     distance: 100,
                     duration: 130,
                     times: Timing { driving: 100, serving: 0, waiting: 30, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
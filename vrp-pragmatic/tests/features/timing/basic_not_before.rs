@@ -0,0 +1,131 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_start_job_not_before_given_time_with_advanced_departure() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_not_before("job1", vec![10., 0.], 20.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 51.,
+                distance: 20,
+                duration: 21,
+                times: Timing { driving: 20, serving: 1, ..Timing::default() },
+                toll: None,
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (0., 0.),
+                        1,
+                        ("1970-01-01T00:00:00Z", "1970-01-01T00:00:10Z"),
+                        0
+                    ),
+                    create_stop_with_activity(
+                        "job1",
+                        "delivery",
+                        (10., 0.),
+                        0,
+                        ("1970-01-01T00:00:20Z", "1970-01-01T00:00:21Z"),
+                        10
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:31Z", "1970-01-01T00:00:31Z"),
+                        20
+                    ),
+                ],
+                statistic: Statistic {
+                    cost: 51.,
+                    distance: 20,
+                    duration: 21,
+                    times: Timing { driving: 20, serving: 1, ..Timing::default() },
+                    toll: None,
+                },
+                departure: None,
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
+
+#[test]
+fn can_skip_time_windows_ending_before_not_before_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    not_before: Some(format_time(20.)),
+                    ..create_delivery_job_with_times("job1", vec![10., 0.], vec![(0, 15), (30, 40)], 1.)
+                        .deliveries
+                        .unwrap()
+                        .remove(0)
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        solution.tours[0].stops[1].schedule().clone(),
+        Schedule { arrival: "1970-01-01T00:00:30Z".to_string(), departure: "1970-01-01T00:00:31Z".to_string() }
+    );
+}
+
+#[test]
+fn can_report_time_window_reason_when_not_before_is_after_shift_end() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_not_before("job1", vec![10., 0.], 2000.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "TIME_WINDOW_CONSTRAINT".to_string(),
+                description: "cannot be visited within time window".to_string(),
+                details: None,
+            }]
+        }])
+    );
+}
@@ -0,0 +1,33 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_scale_travel_duration_by_time_of_day() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![10., 0.])], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: Some(vec![TimeOfDayFactor {
+                    start: "00:00".to_string(),
+                    end: "00:00:15".to_string(),
+                    factor: 2.,
+                }]),
+                leg_overrides: None,
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let arrivals = solution.tours[0].stops.iter().map(|stop| stop.schedule().arrival.clone()).collect::<Vec<_>>();
+    assert_eq!(arrivals, vec![format_time(0.), format_time(20.), format_time(31.)]);
+    assert_eq!(solution.statistic.distance, 20);
+}
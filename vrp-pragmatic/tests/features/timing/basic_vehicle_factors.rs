@@ -0,0 +1,49 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_factors(scale: Option<f64>, service_time_factor: Option<f64>) -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_duration("job1", vec![10., 0.], 2.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: VehicleProfile { scale, ..create_default_vehicle_profile() },
+                service_time_factor,
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_scale_durations_by_vehicle_factors, (scale, service_time_factor, expected), {
+    can_scale_durations_by_vehicle_factors_impl(scale, service_time_factor, expected);
+}}
+
+can_scale_durations_by_vehicle_factors! {
+    case01_no_factors: (None, None, (vec![0., 10., 22.], 20, 2)),
+    case02_faster: (Some(0.5), None, (vec![0., 5., 12.], 10, 2)),
+    case03_slower: (Some(2.), None, (vec![0., 20., 42.], 40, 2)),
+    case04_trainee: (None, Some(3.), (vec![0., 10., 26.], 20, 6)),
+    case05_both: (Some(0.5), Some(0.5), (vec![0., 5., 11.], 10, 1)),
+}
+
+fn can_scale_durations_by_vehicle_factors_impl(
+    scale: Option<f64>,
+    service_time_factor: Option<f64>,
+    expected: (Vec<f64>, i64, i64),
+) {
+    let (arrivals, driving, serving) = expected;
+    let problem = create_problem_with_factors(scale, service_time_factor);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let actual = solution.tours[0].stops.iter().map(|stop| stop.schedule().arrival.clone()).collect::<Vec<_>>();
+    assert_eq!(actual, arrivals.into_iter().map(format_time).collect::<Vec<_>>());
+    assert_eq!(solution.statistic.distance, 20);
+    assert_eq!(solution.statistic.times.driving, driving);
+    assert_eq!(solution.statistic.times.serving, serving);
+}
@@ -1,6 +1,7 @@
 use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::format::Location;
+use crate::format_time;
 use crate::helpers::*;
 
 #[test]
@@ -31,6 +32,7 @@ fn can_wait_for_job_start() {
                 distance: 4,
                 duration: 12,
                 times: Timing { driving: 4, serving: 0, waiting: 8, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -75,7 +77,9 @@ fn can_wait_for_job_start() {
                     distance: 4,
                     duration: 12,
                     times: Timing { driving: 4, serving: 0, waiting: 8, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -107,6 +111,7 @@ fn can_skip_initial_waiting() {
                 distance: 2,
                 duration: 12,
                 times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -143,7 +148,9 @@ fn can_skip_initial_waiting() {
                     distance: 2,
                     duration: 12,
                     times: Timing { driving: 2, serving: 10, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -163,6 +170,7 @@ fn can_consider_latest_departure_time() {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: Some("1970-01-01T00:00:05Z".to_string()),
+                        optimize_departure: None,
                         location: Location::Coordinate { lat: 0.0, lng: 0.0 },
                     },
                     ..create_default_vehicle_shift()
@@ -185,6 +193,7 @@ fn can_consider_latest_departure_time() {
                 distance: 2,
                 duration: 16,
                 times: Timing { driving: 2, serving: 10, waiting: 4, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -221,9 +230,96 @@ fn can_consider_latest_departure_time() {
                     distance: 2,
                     duration: 16,
                     times: Timing { driving: 2, serving: 10, waiting: 4, ..Timing::default() },
+                    toll: None,
                 },
+                departure: Some(DepartureTime {
+                    optimized: "1970-01-01T00:00:05Z".to_string(),
+                    latest: "1970-01-01T00:00:05Z".to_string(),
+                }),
             }],
             ..create_empty_solution()
         }
     );
 }
+
+#[test]
+fn can_report_latest_departure_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], vec![(10, 20)], 10.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(100.)),
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle("my_vehicle")
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution.tours[0].departure,
+        Some(DepartureTime { optimized: format_time(9.), latest: format_time(19.) })
+    );
+}
+
+parameterized_test! {can_optimize_departure_within_window_only_when_enabled, (optimize_departure, expected_vehicle_id), {
+    can_optimize_departure_within_window_only_when_enabled_impl(optimize_departure, expected_vehicle_id);
+}}
+
+can_optimize_departure_within_window_only_when_enabled! {
+    case01_enabled: (Some(true), "vehicle_a_1"),
+    case02_default: (None, "vehicle_b_1"),
+}
+
+fn can_optimize_departure_within_window_only_when_enabled_impl(
+    optimize_departure: Option<bool>,
+    expected_vehicle_id: &str,
+) {
+    let create_vehicle =
+        |type_id: &str, earliest: f64, latest: Option<f64>, optimize_departure: Option<bool>| VehicleType {
+            shifts: vec![VehicleShift {
+                start: ShiftStart {
+                    earliest: format_time(earliest),
+                    latest: latest.map(format_time),
+                    optimize_departure,
+                    location: vec![0., 0.].to_loc(),
+                },
+                ..create_default_vehicle_shift()
+            }],
+            ..create_default_vehicle(type_id)
+        };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], vec![(50, 60)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle("vehicle_a", 0., Some(100.), optimize_departure),
+                create_vehicle("vehicle_b", 40., None, None),
+            ],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, expected_vehicle_id);
+}
@@ -1,4 +1,10 @@
+mod basic_buffer;
+mod basic_leg_override;
+mod basic_loading;
 mod basic_multiple_times;
+mod basic_not_before;
+mod basic_time_of_day;
+mod basic_vehicle_factors;
 mod basic_waiting_time;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
@@ -33,6 +33,7 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                 distance: 80,
                 duration: 80,
                 times: Timing { driving: 80, serving: 0, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -93,13 +94,16 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                     distance: 80,
                     duration: 80,
                     times: Timing { driving: 80, serving: 0, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job5".to_string(),
                 reasons: vec![UnassignedJobReason {
                     code: "TIME_WINDOW_CONSTRAINT".to_string(),
-                    description: "cannot be visited within time window".to_string()
+                    description: "cannot be visited within time window".to_string(),
+                    details: None,
                 }]
             }]),
             ..create_empty_solution()
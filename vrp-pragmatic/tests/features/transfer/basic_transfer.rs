@@ -0,0 +1,82 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_at(id: &str, location: f64, earliest: f64) -> VehicleType {
+    let mut shift = create_default_vehicle_shift_with_locations((location, 0.), (location, 0.));
+    shift.start.earliest = format_time(earliest);
+
+    VehicleType {
+        shifts: vec![shift],
+        limits: Some(VehicleLimits {
+            max_distance: Some(22.),
+            shift_time: None,
+            tour_size: None,
+            max_cost: None,
+            max_stops: None,
+            areas: None,
+            driver_hours: None,
+            overtime: None,
+        }),
+        ..create_default_vehicle(id)
+    }
+}
+
+fn create_problem(max_gap: Option<f64>, east_earliest: f64) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_transfer_job("job1", vec![2., 0.], vec![18., 0.], vec![10., 0.], max_gap)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_at("west", 0., 0.), create_vehicle_at("east", 20., east_earliest)],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_activities(solution: &Solution, vehicle_id: &str) -> Vec<(String, Option<String>)> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.job_id == "job1")
+        .map(|activity| (activity.activity_type.clone(), activity.job_tag.clone()))
+        .collect()
+}
+
+#[test]
+fn can_transfer_goods_between_vehicles() {
+    let problem = create_problem(None, 5.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_job_activities(&solution, "west_1"),
+        vec![("pickup".to_string(), Some("p1".to_string())), ("delivery".to_string(), Some("t".to_string()))]
+    );
+    assert_eq!(
+        get_job_activities(&solution, "east_1"),
+        vec![("pickup".to_string(), Some("t".to_string())), ("delivery".to_string(), Some("d1".to_string()))]
+    );
+}
+
+#[test]
+fn can_unassign_transfer_job_when_max_gap_is_exceeded() {
+    let problem = create_problem(Some(10.), 100.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned.iter().flat_map(|jobs| jobs.iter()).map(|job| job.job_id.as_str()).collect::<Vec<_>>(),
+        vec!["job1"]
+    );
+}
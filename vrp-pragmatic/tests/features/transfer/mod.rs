@@ -0,0 +1,2 @@
+mod basic_transfer;
+mod two_echelon;
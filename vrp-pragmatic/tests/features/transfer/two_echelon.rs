@@ -0,0 +1,80 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_at(id: &str, location: f64, echelon: usize) -> VehicleType {
+    VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations((location, 0.), (location, 0.))],
+        echelon: Some(echelon),
+        ..create_default_vehicle(id)
+    }
+}
+
+fn get_activities(solution: &Solution, vehicle_id: &str) -> Vec<(String, String, Option<String>)> {
+    solution
+        .tours
+        .iter()
+        .filter(|tour| tour.vehicle_id == vehicle_id)
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.job_id.starts_with("job"))
+        .map(|activity| (activity.job_id.clone(), activity.activity_type.clone(), activity.job_tag.clone()))
+        .collect()
+}
+
+#[test]
+fn can_serve_jobs_by_vehicles_of_two_echelons() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_transfer_job("job1", vec![0., 0.], vec![14., 0.], vec![10., 0.], None),
+                create_delivery_job("job2", vec![1., 0.]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_at("truck", 0., 1), create_vehicle_at("van", 10., 2)],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_activities(&solution, "truck_1"),
+        vec![
+            ("job1".to_string(), "pickup".to_string(), Some("p1".to_string())),
+            ("job1".to_string(), "delivery".to_string(), Some("t".to_string()))
+        ]
+    );
+    assert_eq!(get_activities(&solution, "van_1").len(), 3);
+    assert!(get_activities(&solution, "van_1").contains(&("job2".to_string(), "delivery".to_string(), None)));
+}
+
+#[test]
+fn can_unassign_job_when_vehicle_of_required_echelon_is_missing() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_vehicle_at("truck", 0., 1)], profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours.is_empty());
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "ECHELON_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to vehicle echelon constraint".to_string(),
+                details: None,
+            }]
+        }])
+    );
+}
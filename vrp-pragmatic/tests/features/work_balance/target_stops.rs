@@ -0,0 +1,50 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::format::solution::Tour;
+use crate::helpers::*;
+
+fn get_job_stops_count(tour: &Tour) -> usize {
+    tour.stops
+        .iter()
+        .filter(|stop| stop.activities().iter().any(|activity| activity.activity_type == "delivery"))
+        .count()
+}
+
+parameterized_test! {can_keep_target_stops, (tolerance, expected_tours), {
+    can_keep_target_stops_impl(tolerance, expected_tours);
+}}
+
+can_keep_target_stops! {
+    case01: (None, vec![3, 3]),
+    case02: (Some(3), vec![6]),
+}
+
+fn can_keep_target_stops_impl(tolerance: Option<usize>, expected_tours: Vec<usize>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=6).map(|idx| create_delivery_job(&format!("job{}", idx), vec![idx as f64, 0.])).collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![10],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        objectives: Some(vec![
+            vec![MinimizeUnassignedJobs { breaks: None }],
+            vec![TargetStops { target: 3, tolerance }],
+            vec![MinimizeCost],
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let mut tours = solution.tours.iter().map(get_job_stops_count).collect::<Vec<_>>();
+    tours.sort();
+    assert_eq!(tours, expected_tours);
+}
@@ -83,16 +83,21 @@ pub fn default_job_prototype() -> impl Strategy<Value = Job> {
 
 pub fn default_costs_prototype() -> impl Strategy<Value = VehicleCosts> {
     from_costs(vec![
-        VehicleCosts { fixed: Some(20.), distance: 0.0020, time: 0.003 },
-        VehicleCosts { fixed: Some(30.), distance: 0.0015, time: 0.005 },
+        VehicleCosts { fixed: Some(20.), distance: 0.0020, time: 0.003, waiting: None, depot_waiting: None },
+        VehicleCosts { fixed: Some(30.), distance: 0.0015, time: 0.005, waiting: None, depot_waiting: None },
     ])
 }
 
 pub fn default_shift_places_prototype() -> impl Strategy<Value = (ShiftStart, Option<ShiftEnd>)> {
     generate_location(&DEFAULT_BOUNDING_BOX).prop_flat_map(|location| {
         Just((
-            ShiftStart { earliest: default_time_plus_offset(9), latest: None, location: location.clone() },
-            Some(ShiftEnd { earliest: None, latest: default_time_plus_offset(18), location }),
+            ShiftStart {
+                earliest: default_time_plus_offset(9),
+                latest: None,
+                optimize_departure: None,
+                location: location.clone(),
+            },
+            Some(ShiftEnd { earliest: None, latest: default_time_plus_offset(18), location, alternatives: None }),
         ))
     })
 }
@@ -102,6 +107,8 @@ pub fn default_breaks_prototype() -> impl Strategy<Value = Option<Vec<VehicleBre
         time: VehicleOptionalBreakTime::TimeWindow(vec![default_time_plus_offset(12), default_time_plus_offset(14)]),
         places: vec![VehicleOptionalBreakPlace { duration: 3600., location: None, tag: None }],
         policy: None,
+        min_shift_duration: None,
+        min_spacing: None,
     }]))
 }
 
@@ -74,7 +74,7 @@ prop_compose! {
                         tag: Some("p1".to_owned()),
                         ..pickup
                     }
-                ], demand: demand.clone(), order }
+                ], demand: demand.clone(), order, skills: None, not_before: None }
             ]),
             deliveries: Some(vec![
              JobTask { places: vec![
@@ -82,14 +82,18 @@ prop_compose! {
                         tag: Some("d1".to_owned()),
                         ..delivery
                     }
-                ], demand: demand.clone(), order: None }
+                ], demand: demand.clone(), order: None, skills: None, not_before: None }
             ]),
             replacements: None,
             services: None,
             skills,
             value,
             group,
-            compatibility
+            compatibility,
+            buffer: None,
+            is_prioritized: None,
+            sync: None,
+            transfer: None,
         }
     }
 }
@@ -135,6 +139,10 @@ prop_compose! {
             value,
             group,
             compatibility,
+            buffer: None,
+            is_prioritized: None,
+            sync: None,
+            transfer: None,
         }
     }
 }
@@ -150,7 +158,7 @@ prop_compose! {
      demand in demand_proto,
      order in order_proto,
     ) -> JobTask {
-       JobTask { places: vec![place], demand, order }
+       JobTask { places: vec![place], demand, order, skills: None, not_before: None }
     }
 }
 
@@ -167,7 +175,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, tag, parking: None }
     }
 }
 
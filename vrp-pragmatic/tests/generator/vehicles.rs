@@ -33,6 +33,9 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            carry_load: None,
+            echelon: None,
+            service_time_factor: None,
         }
     }
 }
@@ -54,7 +57,10 @@ prop_compose! {
           times,
           location,
           duration,
-          tag
+          tag,
+          capacity: None,
+          per_unit_duration: None,
+          resource: None,
         }
     }
 }
@@ -85,7 +91,8 @@ prop_compose! {
           end: places.1,
           dispatch,
           breaks,
-          reloads
+          reloads,
+          loading: None
         }
     }
 }
@@ -1,4 +1,5 @@
 use crate::extensions::create_typed_actor_groups;
+use crate::format::dimens::*;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::construction::heuristics::{RegistryContext, SolutionContext};
@@ -8,8 +9,14 @@ use vrp_core::models::problem::*;
 use vrp_core::models::solution::*;
 use vrp_core::utils::{DefaultRandom, Environment};
 
-const DEFAULT_VEHICLE_COSTS: Costs =
-    Costs { fixed: 100.0, per_distance: 1.0, per_driving_time: 1.0, per_waiting_time: 1.0, per_service_time: 1.0 };
+const DEFAULT_VEHICLE_COSTS: Costs = Costs {
+    fixed: 100.0,
+    per_distance: 1.0,
+    per_driving_time: 1.0,
+    per_waiting_time: 1.0,
+    per_depot_waiting_time: 1.0,
+    per_service_time: 1.0,
+};
 pub const DEFAULT_JOB_LOCATION: Location = 0;
 pub const DEFAULT_JOB_DURATION: Duration = 0.0;
 pub const DEFAULT_JOB_TIME_SPAN: TimeSpan = TimeSpan::Window(TimeWindow { start: 0., end: 1000. });
@@ -23,8 +30,8 @@ pub fn test_driver() -> Driver {
 pub fn test_vehicle(id: &str) -> Vehicle {
     let mut dimens = Dimensions::new();
     dimens.set_id(id);
-    dimens.set_value("type_id", id.to_owned());
-    dimens.set_value("shift_index", 0_usize);
+    dimens.set_dimen(&VEHICLE_TYPE_ID_KEY, id.to_owned());
+    dimens.set_dimen(&SHIFT_INDEX_KEY, 0_usize);
 
     Vehicle {
         profile: Profile::default(),
@@ -76,7 +83,7 @@ pub fn create_activity_with_job_at_location(job: Arc<Single>, location: Location
 pub fn create_single(id: &str) -> Arc<Single> {
     let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
     single.dimens.set_id(id);
-    single.dimens.set_value("type", "delivery".to_string());
+    single.dimens.set_dimen(&JOB_TYPE_KEY, "delivery".to_string());
 
     Arc::new(single)
 }
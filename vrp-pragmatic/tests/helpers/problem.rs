@@ -11,11 +11,17 @@ use vrp_core::models::common::Profile as CoreProfile;
 use vrp_core::models::problem::{ActivityCost, SimpleActivityCost, TransportCost};
 
 pub fn create_job_place(location: Vec<f64>, tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., tag, parking: None }
 }
 
 pub fn create_task(location: Vec<f64>, tag: Option<String>) -> JobTask {
-    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1]), order: None }
+    JobTask {
+        places: vec![create_job_place(location, tag)],
+        demand: Some(vec![1]),
+        order: None,
+        skills: None,
+        not_before: None,
+    }
 }
 
 pub fn create_job(id: &str) -> Job {
@@ -29,6 +35,10 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        buffer: None,
+        is_prioritized: None,
+        sync: None,
+        transfer: None,
     }
 }
 
@@ -42,6 +52,8 @@ pub fn create_delivery_job_with_order(id: &str, location: Vec<f64>, order: i32)
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: Some(order),
+            skills: None,
+            not_before: None,
         }]),
         ..create_job(id)
     }
@@ -53,6 +65,8 @@ pub fn create_delivery_job_with_group(id: &str, location: Vec<f64>, group: &str)
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: None,
+            skills: None,
+            not_before: None,
         }]),
         group: Some(group.to_string()),
         ..create_job(id)
@@ -65,6 +79,8 @@ pub fn create_delivery_job_with_compatibility(id: &str, location: Vec<f64>, comp
             places: vec![create_job_place(location, None)],
             demand: Some(vec![1]),
             order: None,
+            skills: None,
+            not_before: None,
         }]),
         compatibility: Some(compatibility.to_string()),
         ..create_job(id)
@@ -85,6 +101,8 @@ pub fn create_delivery_job_with_duration(id: &str, location: Vec<f64>, duration:
             places: vec![JobPlace { duration, ..create_job_place(location, None) }],
             demand: Some(vec![1]),
             order: None,
+            skills: None,
+            not_before: None,
         }]),
         ..create_job(id)
     }
@@ -96,11 +114,20 @@ pub fn create_delivery_job_with_times(id: &str, location: Vec<f64>, times: Vec<(
             places: vec![JobPlace { duration, times: convert_times(&times), ..create_job_place(location, None) }],
             demand: Some(vec![1]),
             order: None,
+            skills: None,
+            not_before: None,
         }]),
         ..create_job(id)
     }
 }
 
+pub fn create_delivery_job_with_not_before(id: &str, location: Vec<f64>, not_before: f64) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask { not_before: Some(format_time(not_before)), ..create_task(location, None) }]),
+        ..create_job(id)
+    }
+}
+
 pub fn create_delivery_job_with_value(id: &str, location: Vec<f64>, value: f64) -> Job {
     Job { deliveries: Some(vec![create_task(location.clone(), None)]), value: Some(value), ..create_job(id) }
 }
@@ -144,6 +171,8 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand.clone()),
             order: None,
+            skills: None,
+            not_before: None,
         }]),
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace {
@@ -153,6 +182,8 @@ pub fn create_pickup_delivery_job_with_params(
             }],
             demand: Some(demand.clone()),
             order: None,
+            skills: None,
+            not_before: None,
         }]),
 
         ..create_job(id)
@@ -162,9 +193,17 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                tag: None,
+                parking: None,
+            }],
             demand: Some(vec![1]),
             order: None,
+            skills: None,
+            not_before: None,
         }]),
         ..create_job(id)
     }
@@ -186,6 +225,8 @@ pub fn create_multi_job(
                 }],
                 demand: Some(demand),
                 order: None,
+                skills: None,
+                not_before: None,
             })
             .collect::<Vec<_>>();
 
@@ -199,36 +240,92 @@ pub fn create_multi_job(
     Job { pickups: create_tasks(pickups, "p"), deliveries: create_tasks(deliveries, "d"), ..create_job(id) }
 }
 
+pub fn create_sync_job(id: &str, visits: Vec<(Vec<f64>, f64, Vec<(i32, i32)>, &str)>, max_gap: Option<f64>) -> Job {
+    Job {
+        services: Some(
+            visits
+                .into_iter()
+                .enumerate()
+                .map(|(idx, (location, duration, times, skill))| JobTask {
+                    places: vec![JobPlace {
+                        duration,
+                        times: convert_times(&times),
+                        ..create_job_place(location, Some(format!("v{}", idx + 1)))
+                    }],
+                    demand: None,
+                    order: None,
+                    skills: Some(all_of_skills(vec![skill.to_string()])),
+                    not_before: None,
+                })
+                .collect(),
+        ),
+        sync: Some(JobSync { max_gap }),
+        ..create_job(id)
+    }
+}
+
+pub fn create_transfer_job(
+    id: &str,
+    pickup_location: Vec<f64>,
+    delivery_location: Vec<f64>,
+    transfer_location: Vec<f64>,
+    max_gap: Option<f64>,
+) -> Job {
+    Job {
+        transfer: Some(JobTransfer {
+            location: transfer_location.to_loc(),
+            duration: 1.,
+            times: None,
+            max_gap,
+            tag: Some("t".to_string()),
+        }),
+        ..create_pickup_delivery_job(id, pickup_location, delivery_location)
+    }
+}
+
 pub fn create_default_vehicle_shift() -> VehicleShift {
     create_default_vehicle_shift_with_locations((0., 0.), (0., 0.))
 }
 
 pub fn create_default_open_vehicle_shift() -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+        start: ShiftStart {
+            earliest: format_time(0.),
+            latest: None,
+            optimize_departure: None,
+            location: vec![0., 0.].to_loc(),
+        },
         end: None,
         dispatch: None,
         breaks: None,
         reloads: None,
+        loading: None,
     }
 }
 
 pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64, f64)) -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![start.0, start.1].to_loc() },
+        start: ShiftStart {
+            earliest: format_time(0.),
+            latest: None,
+            optimize_departure: None,
+            location: vec![start.0, start.1].to_loc(),
+        },
         end: Some(ShiftEnd {
             earliest: None,
             latest: format_time(1000.).to_string(),
             location: vec![end.0, end.1].to_loc(),
+            alternatives: None,
         }),
         dispatch: None,
         breaks: None,
         reloads: None,
+        loading: None,
     }
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., waiting: None, depot_waiting: None }
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
@@ -257,11 +354,20 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        carry_load: None,
+        echelon: None,
+        service_time_factor: None,
     }
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
-    vec![MatrixProfile { name: "car".to_string(), speed: None }]
+    vec![MatrixProfile {
+        name: "car".to_string(),
+        speed: None,
+        detour_factor: None,
+        time_of_day: None,
+        leg_overrides: None,
+    }]
 }
 
 pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
@@ -269,11 +375,16 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Vec<Objective>>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, areas: None, clustering: None }
+    Plan { jobs: vec![], relations: None, areas: None, clustering: None, hints: None, readonly_routes: None }
 }
 
 pub fn create_empty_problem() -> Problem {
-    Problem { plan: create_empty_plan(), fleet: Fleet { vehicles: vec![], profiles: vec![] }, objectives: None }
+    Problem {
+        plan: create_empty_plan(),
+        fleet: Fleet { vehicles: vec![], profiles: vec![] },
+        objectives: None,
+        units: None,
+    }
 }
 
 pub fn get_costs() -> (Arc<dyn TransportCost + Send + Sync>, Arc<dyn ActivityCost + Send + Sync>) {
@@ -311,6 +422,8 @@ pub fn create_matrix(data: Vec<i64>) -> Matrix {
         travel_times: data.clone(),
         distances: data.clone(),
         error_codes: None,
+        units: None,
+        tolls: None,
     }
 }
 
@@ -56,6 +56,7 @@ fn create_stop_with_activity_impl(
         time: Schedule { arrival: time.0.to_string(), departure: time.1.to_string() },
         load,
         distance,
+        service: None,
         activities: vec![Activity {
             job_id: id.to_string(),
             activity_type: activity_type.to_string(),
@@ -104,11 +105,19 @@ pub fn create_empty_tour() -> Tour {
         shift_index: 0,
         stops: vec![],
         statistic: Default::default(),
+        departure: None,
     }
 }
 
 pub fn create_empty_solution() -> Solution {
-    Solution { statistic: Default::default(), tours: vec![], unassigned: None, violations: None, extras: None }
+    Solution {
+        statistic: Default::default(),
+        tours: vec![],
+        unassigned: None,
+        violations: None,
+        extras: None,
+        units: None,
+    }
 }
 
 pub fn get_ids_from_tour(tour: &Tour) -> Vec<Vec<String>> {
@@ -22,9 +22,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T13:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parking: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            skills: None,
+                            not_before: None,
                         }]),
                         ..create_job("job1")
                     },
@@ -38,9 +41,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T11:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parking: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            skills: None,
+                            not_before: None,
                         }]),
                         ..create_job("job2")
                     },
@@ -54,9 +60,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                parking: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            skills: None,
+                            not_before: None,
                         }]),
                         ..create_job("job3")
                     },
@@ -70,9 +79,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T16:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parking: None,
                             }],
                             demand: Some(vec![2]),
                             order: None,
+                            skills: None,
+                            not_before: None,
                         }]),
                         ..create_job("job4")
                     },
@@ -86,9 +98,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                parking: None,
                             }],
                             demand: Some(vec![3]),
                             order: None,
+                            skills: None,
+                            not_before: None,
                         }]),
                         ..create_job("job5")
                     },
@@ -102,9 +117,12 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T18:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parking: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
+                            skills: None,
+                            not_before: None,
                         }]),
                         ..create_job("job6")
                     },
@@ -116,17 +134,25 @@ fn can_handle_properly_invalid_break_removal() {
                     type_id: "vehicle1".to_string(),
                     vehicle_ids: vec!["vehicle1_1".to_string()],
                     profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-                    costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003 },
+                    costs: VehicleCosts {
+                        fixed: Some(20.),
+                        distance: 0.002,
+                        time: 0.003,
+                        waiting: None,
+                        depot_waiting: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: "2020-07-04T09:00:00Z".to_string(),
                             latest: None,
+                            optimize_departure: None,
                             location: Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 },
                         },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: "2020-07-04T18:00:00Z".to_string(),
                             location: Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 },
+                            alternatives: None,
                         }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
@@ -136,14 +162,26 @@ fn can_handle_properly_invalid_break_removal() {
                             ]),
                             places: vec![VehicleOptionalBreakPlace { duration: 3600.0, location: None, tag: None }],
                             policy: None,
+                            min_shift_duration: None,
+                            min_spacing: None,
                         }]),
                         reloads: None,
+                        loading: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    carry_load: None,
+                    echelon: None,
+                    service_time_factor: None,
+                }],
+                profiles: vec![MatrixProfile {
+                    name: "car".to_string(),
+                    speed: None,
+                    detour_factor: None,
+                    time_of_day: None,
+                    leg_overrides: None,
                 }],
-                profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
             },
             ..create_empty_problem()
         };
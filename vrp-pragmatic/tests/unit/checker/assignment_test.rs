@@ -35,6 +35,7 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 shift_index,
                 stops: vec![],
                 statistic: Statistic::default(),
+                departure: None,
             })
             .collect(),
         ..create_empty_solution()
@@ -132,9 +133,12 @@ fn check_jobs_impl(
                     duration: 0.0,
                     times: None,
                     tag: Some(format!("{}{}", tgt, idx)),
+                    parking: None,
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 order: None,
+                skills: None,
+                not_before: None,
             })
             .collect()
     };
@@ -168,6 +172,7 @@ fn check_jobs_impl(
                 shift_index,
                 stops: stops.into_iter().map(create_stop).collect(),
                 statistic: Statistic::default(),
+                departure: None,
             })
             .collect(),
         unassigned: Some(
@@ -198,6 +203,7 @@ fn can_detect_time_window_violation() {
             distance: 2,
             duration: 3,
             times: Timing { driving: 2, serving: 1, ..Timing::default() },
+            toll: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -234,7 +240,9 @@ fn can_detect_time_window_violation() {
                 distance: 2,
                 duration: 3,
                 times: Timing { driving: 2, serving: 1, ..Timing::default() },
+                toll: None,
             },
+            departure: None,
         }],
         ..create_empty_solution()
     };
@@ -262,6 +270,7 @@ fn can_detect_job_duration_violation() {
             distance: 2,
             duration: 6,
             times: Timing { driving: 2, serving: 2, waiting: 2, ..Timing::default() },
+            toll: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -298,7 +307,9 @@ fn can_detect_job_duration_violation() {
                 distance: 2,
                 duration: 6,
                 times: Timing { driving: 2, serving: 2, waiting: 2, ..Timing::default() },
+                toll: None,
             },
+            departure: None,
         }],
         ..create_empty_solution()
     };
@@ -431,3 +442,73 @@ fn can_detect_group_violations() {
 
     assert_eq!(result, Err("job groups are not respected: 'group1'".to_owned()));
 }
+
+parameterized_test! {can_detect_skills_violations, (vehicle_skills, job_skills, expected), {
+    can_detect_skills_violations_impl(vehicle_skills, job_skills, expected);
+}}
+
+can_detect_skills_violations! {
+    case01: (Some(vec!["s1"]), (Some(vec!["s1"]), None, None), Ok(())),
+    case02: (None, (Some(vec!["s1"]), None, None), Err(())),
+    case03: (Some(vec!["s2"]), (None, Some(vec!["s1", "s2"]), None), Ok(())),
+    case04: (Some(vec!["s3"]), (None, Some(vec!["s1", "s2"]), None), Err(())),
+    case05: (Some(vec!["s1"]), (None, None, Some(vec!["s1"])), Err(())),
+    case06: (None, (None, None, Some(vec!["s1"])), Ok(())),
+}
+
+fn can_detect_skills_violations_impl(
+    vehicle_skills: Option<Vec<&str>>,
+    job_skills: (Option<Vec<&str>>, Option<Vec<&str>>, Option<Vec<&str>>),
+    expected: Result<(), ()>,
+) {
+    let to_strings =
+        |skills: Option<Vec<&str>>| skills.map(|skills| skills.into_iter().map(|s| s.to_string()).collect());
+    let (all_of, one_of, none_of) = job_skills;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills(
+                "job1",
+                vec![1., 0.],
+                JobSkills { all_of: to_strings(all_of), one_of: to_strings(one_of), none_of: to_strings(none_of) },
+            )],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { skills: to_strings(vehicle_skills), ..create_default_vehicle_type() }],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    1,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    0,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+            ],
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    };
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_skills(&ctx).map_err(|_| ());
+
+    assert_eq!(result, expected);
+}
@@ -78,19 +78,28 @@ fn can_check_breaks_impl(
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: break_times,
                         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
                         policy: None,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     reloads: None,
+                    loading: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -125,6 +134,7 @@ fn can_check_breaks_impl(
             distance: 4,
             duration: 8,
             times: Timing { driving: 4, serving: 2, break_time: 2, ..Timing::default() },
+            toll: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -155,6 +165,7 @@ fn can_check_breaks_impl(
                     },
                     distance: 2,
                     parking: None,
+                    service: None,
                     load: vec![0],
                     activities,
                 }),
@@ -172,7 +183,9 @@ fn can_check_breaks_impl(
                 distance: 4,
                 duration: 8,
                 times: Timing { driving: 4, serving: 2, break_time: 2, ..Timing::default() },
+                toll: None,
             },
+            departure: None,
         }],
         violations,
         ..create_empty_solution()
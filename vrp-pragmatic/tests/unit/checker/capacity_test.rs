@@ -36,11 +36,17 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(1000.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     dispatch: None,
                     breaks: None,
@@ -49,7 +55,11 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                         location: vec![0., 0.].to_loc(),
                         duration: 2.0,
                         tag: None,
+                        capacity: None,
+                        per_unit_duration: None,
+                        resource: None,
                     }]),
+                    loading: None,
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -64,6 +74,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
             distance: 1,
             duration: 2,
             times: Timing { driving: 1, serving: 1, ..Timing::default() },
+            toll: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -87,6 +98,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                     distance: 1,
                     parking: None,
                     load: vec![*stop_loads.get(1).unwrap()],
+                    service: None,
                     activities: vec![
                         Activity {
                             job_id: "job1".to_string(),
@@ -115,6 +127,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                     distance: 1,
                     parking: None,
                     load: vec![*stop_loads.get(2).unwrap()],
+                    service: None,
                     activities: vec![Activity {
                         job_id: "reload".to_string(),
                         activity_type: "reload".to_string(),
@@ -133,6 +146,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                     distance: 3,
                     parking: None,
                     load: vec![*stop_loads.get(3).unwrap()],
+                    service: None,
                     activities: vec![
                         Activity {
                             job_id: "job2".to_string(),
@@ -189,7 +203,9 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Str
                 distance: 1,
                 duration: 2,
                 times: Timing { driving: 1, serving: 1, ..Timing::default() },
+                toll: None,
             },
+            departure: None,
         }],
         ..create_empty_solution()
     };
@@ -220,6 +236,7 @@ fn can_check_load_when_departure_has_other_activity() {
             distance: 2,
             duration: 4,
             times: Timing { driving: 2, serving: 2, ..Timing::default() },
+            toll: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -235,6 +252,7 @@ fn can_check_load_when_departure_has_other_activity() {
                     distance: 0,
                     parking: None,
                     load: vec![1],
+                    service: None,
                     activities: vec![
                         Activity {
                             job_id: "departure".to_string(),
@@ -277,7 +295,9 @@ fn can_check_load_when_departure_has_other_activity() {
                 distance: 2,
                 duration: 4,
                 times: Timing { driving: 2, serving: 2, ..Timing::default() },
+                toll: None,
             },
+            departure: None,
         }],
         ..create_empty_solution()
     };
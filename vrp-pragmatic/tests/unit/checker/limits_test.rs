@@ -61,7 +61,16 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), String>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, shift_time, tour_size: None, areas: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        shift_time,
+        tour_size: None,
+        max_cost: None,
+        max_stops: None,
+        areas: None,
+        driver_hours: None,
+        overtime: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -71,59 +80,122 @@ pub fn can_check_shift_and_distance_limit_impl(
     assert_eq!(result, expected);
 }
 
+parameterized_test! {can_check_max_cost_limit, (actual, expected), {
+    can_check_max_cost_limit_impl(actual, expected);
+}}
+
+can_check_max_cost_limit! {
+    case_01: (11., false),
+    case_02: (10., true),
+    case_03: (9., true),
+}
+
+fn can_check_max_cost_limit_impl(actual: f64, expected: bool) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        tour_size: None,
+        max_cost: Some(10.),
+        max_stops: None,
+        areas: None,
+        driver_hours: None,
+        overtime: None,
+    }));
+    let solution = create_test_solution(Statistic { cost: actual, ..Statistic::default() }, vec![]);
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx);
+
+    assert_eq!(result.is_ok(), expected);
+}
+
+parameterized_test! {can_check_shift_time_limit_with_overtime, (actual, expected), {
+    can_check_shift_time_limit_with_overtime_impl(actual, expected);
+}}
+
+can_check_shift_time_limit_with_overtime! {
+    case_01: (16, false),
+    case_02: (15, true),
+    case_03: (11, true),
+}
+
+fn can_check_shift_time_limit_with_overtime_impl(actual: i64, expected: bool) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: Some(10.),
+        tour_size: None,
+        max_cost: None,
+        max_stops: None,
+        areas: None,
+        driver_hours: None,
+        overtime: Some(VehicleOvertime { max_duration: 5., cost: 1. }),
+    }));
+    let solution = create_test_solution(Statistic { duration: actual, ..Statistic::default() }, vec![]);
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx);
+
+    assert_eq!(result.is_ok(), expected);
+}
+
+fn create_test_stops() -> Vec<Stop> {
+    vec![
+        create_stop_with_activity(
+            "departure",
+            "departure",
+            (0., 0.),
+            3,
+            (format_time(0.).as_str(), format_time(0.).as_str()),
+            0,
+        ),
+        create_stop_with_activity(
+            "job1",
+            "delivery",
+            (1., 0.),
+            2,
+            (format_time(1.).as_str(), format_time(1.).as_str()),
+            1,
+        ),
+        create_stop_with_activity(
+            "job2",
+            "delivery",
+            (2., 0.),
+            1,
+            (format_time(2.).as_str(), format_time(2.).as_str()),
+            2,
+        ),
+        create_stop_with_activity(
+            "job3",
+            "delivery",
+            (3., 0.),
+            0,
+            (format_time(3.).as_str(), format_time(3.).as_str()),
+            3,
+        ),
+        create_stop_with_activity(
+            "arrival",
+            "arrival",
+            (0., 0.),
+            0,
+            (format_time(6.).as_str(), format_time(6.).as_str()),
+            6,
+        ),
+    ]
+}
+
 #[test]
 pub fn can_check_tour_size_limit() {
     let problem = create_test_problem(Some(VehicleLimits {
         max_distance: None,
         shift_time: None,
         tour_size: Some(2),
+        max_cost: None,
+        max_stops: None,
         areas: None,
+        driver_hours: None,
+        overtime: None,
     }));
-    let solution = create_test_solution(
-        Statistic::default(),
-        vec![
-            create_stop_with_activity(
-                "departure",
-                "departure",
-                (0., 0.),
-                3,
-                (format_time(0.).as_str(), format_time(0.).as_str()),
-                0,
-            ),
-            create_stop_with_activity(
-                "job1",
-                "delivery",
-                (1., 0.),
-                2,
-                (format_time(1.).as_str(), format_time(1.).as_str()),
-                1,
-            ),
-            create_stop_with_activity(
-                "job2",
-                "delivery",
-                (2., 0.),
-                1,
-                (format_time(2.).as_str(), format_time(2.).as_str()),
-                2,
-            ),
-            create_stop_with_activity(
-                "job3",
-                "delivery",
-                (3., 0.),
-                0,
-                (format_time(3.).as_str(), format_time(3.).as_str()),
-                3,
-            ),
-            create_stop_with_activity(
-                "arrival",
-                "arrival",
-                (0., 0.),
-                0,
-                (format_time(6.).as_str(), format_time(6.).as_str()),
-                6,
-            ),
-        ],
-    );
+    let solution = create_test_solution(Statistic::default(), create_test_stops());
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
 
     let result = check_shift_limits(&ctx);
@@ -135,6 +207,59 @@ pub fn can_check_tour_size_limit() {
     );
 }
 
+#[test]
+pub fn can_check_max_stops_limit() {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        tour_size: None,
+        max_cost: None,
+        max_stops: Some(2),
+        areas: None,
+        driver_hours: None,
+        overtime: None,
+    }));
+    let solution = create_test_solution(Statistic::default(), create_test_stops());
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx);
+
+    assert_eq!(
+        result,
+        Err("max stops limit violation, expected: not more than 2, got: 3, vehicle id 'some_real_vehicle', shift index: 0"
+            .to_string())
+    );
+}
+
+#[test]
+pub fn can_count_same_location_once_in_max_stops_limit() {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        shift_time: None,
+        tour_size: None,
+        max_cost: None,
+        max_stops: Some(2),
+        areas: None,
+        driver_hours: None,
+        overtime: None,
+    }));
+    let mut stops = create_test_stops();
+    stops[3] = create_stop_with_activity(
+        "job3",
+        "delivery",
+        (1., 0.),
+        0,
+        (format_time(3.).as_str(), format_time(3.).as_str()),
+        3,
+    );
+    let solution = create_test_solution(Statistic::default(), stops);
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_shift_limits(&ctx);
+
+    assert_eq!(result, Ok(()));
+}
+
 #[test]
 fn can_check_shift_time() {
     let problem = Problem {
@@ -145,11 +270,17 @@ fn can_check_shift_time() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        optimize_departure: None,
+                        location: vec![0., 0.].to_loc(),
+                    },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(5.).to_string(),
                         location: vec![0., 0.].to_loc(),
+                        alternatives: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
@@ -165,6 +296,7 @@ fn can_check_shift_time() {
             distance: 2,
             duration: 5,
             times: Timing { driving: 2, serving: 1, waiting: 2, ..Timing::default() },
+            toll: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -201,7 +333,9 @@ fn can_check_shift_time() {
                 distance: 2,
                 duration: 5,
                 times: Timing { driving: 2, serving: 1, waiting: 2, ..Timing::default() },
+                toll: None,
             },
+            departure: None,
         }],
         ..create_empty_solution()
     };
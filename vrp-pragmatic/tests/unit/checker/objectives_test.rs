@@ -0,0 +1,157 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+fn create_test_problem(objectives: Option<Vec<Vec<Objective>>>) -> Problem {
+    Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_matrix_profiles(),
+        },
+        objectives,
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_statistic(cost: f64, duration: i64, times: (i64, i64, i64)) -> Statistic {
+    let (driving, serving, waiting) = times;
+    Statistic {
+        cost,
+        distance: 10,
+        duration,
+        times: Timing { driving, serving, waiting, break_time: 0, commuting: 0, parking: 0 },
+        toll: None,
+    }
+}
+
+fn create_test_solution(statistic: Statistic, tour_statistic: Statistic) -> Solution {
+    Solution {
+        statistic,
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            statistic: tour_statistic,
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    }
+}
+
+fn create_test_context(objectives: Option<Vec<Vec<Objective>>>, solution: Solution) -> CheckerContext {
+    CheckerContext::new(create_example_problem(), create_test_problem(objectives), None, solution).unwrap()
+}
+
+parameterized_test! {can_check_tour_timing, (duration, times, expected), {
+    can_check_tour_timing_impl(duration, times, expected);
+}}
+
+can_check_tour_timing! {
+    case_01: (20, (10, 5, 5), true),
+    case_02: (20, (10, 5, 4), true),
+    case_03: (20, (10, 0, 0), false),
+    case_04: (20, (10, 15, 5), false),
+}
+
+fn can_check_tour_timing_impl(duration: i64, times: (i64, i64, i64), expected: bool) {
+    let statistic = create_test_statistic(40., duration, times);
+    let ctx = create_test_context(None, create_test_solution(statistic.clone(), statistic));
+
+    let result = check_tour_timing(&ctx);
+
+    assert_eq!(result.is_ok(), expected, "{:?}", result);
+}
+
+parameterized_test! {can_check_tour_cost, (cost, waiting_cost, expected), {
+    can_check_tour_cost_impl(cost, waiting_cost, expected);
+}}
+
+can_check_tour_cost! {
+    case_01: (40., None, true),
+    case_02: (41., None, true),
+    case_03: (50., None, false),
+    case_04: (30., None, false),
+    case_05: (30., Some(3.), false),
+    case_06: (50., Some(3.), true),
+}
+
+fn can_check_tour_cost_impl(cost: f64, waiting_cost: Option<f64>, expected: bool) {
+    let statistic = create_test_statistic(cost, 20, (10, 5, 5));
+    let mut ctx = create_test_context(None, create_test_solution(statistic.clone(), statistic));
+    ctx.problem.fleet.vehicles[0].costs.waiting = waiting_cost;
+
+    let result = check_tour_cost(&ctx);
+
+    assert_eq!(result.is_ok(), expected, "{:?}", result);
+}
+
+#[test]
+fn can_check_commuting_time() {
+    let commute_info = |start: &str, end: &str| CommuteInfo {
+        location: vec![1., 0.].to_loc(),
+        distance: 10.,
+        time: Interval { start: start.to_string(), end: end.to_string() },
+    };
+    let mut solution = create_test_solution(Statistic::default(), Statistic::default());
+    solution.tours[0].stops = vec![Stop::Point(PointStop {
+        location: vec![0., 0.].to_loc(),
+        time: Schedule { arrival: format_time(0.), departure: format_time(10.) },
+        distance: 0,
+        load: vec![0],
+        parking: None,
+        service: None,
+        activities: vec![Activity {
+            job_id: "job1".to_string(),
+            activity_type: "delivery".to_string(),
+            location: Some(vec![1., 0.].to_loc()),
+            time: None,
+            job_tag: None,
+            commute: Some(Commute {
+                forward: Some(commute_info(&format_time(0.), &format_time(3.))),
+                backward: Some(commute_info(&format_time(7.), &format_time(10.))),
+            }),
+        }],
+    })];
+    solution.tours[0].statistic.duration = 6;
+    solution.tours[0].statistic.times.commuting = 2;
+    let ctx = create_test_context(None, solution);
+
+    let result = check_tour_timing(&ctx);
+
+    assert_eq!(
+        result,
+        Err("commuting time mismatch for tour statistic: my_vehicle_1, expected: '6', got: '2'".to_string())
+    );
+}
+
+parameterized_test! {can_check_solution_objectives, (objectives, statistic, expected), {
+    can_check_solution_objectives_impl(objectives, statistic, expected);
+}}
+
+can_check_solution_objectives! {
+    case_01: (None, (40., 10, 20), Ok(())),
+    case_02: (None, (45., 10, 20), Err("cost objective mismatch, expected: '40', got: '45'")),
+    case_03: (Some(vec![vec![Objective::MinimizeDistance]]), (45., 10, 20), Ok(())),
+    case_04: (Some(vec![vec![Objective::MinimizeDistance]]), (40., 11, 20),
+              Err("distance objective mismatch, expected: '10', got: '11'")),
+    case_05: (Some(vec![vec![Objective::MinimizeDuration]]), (40., 10, 21),
+              Err("duration objective mismatch, expected: '20', got: '21'")),
+}
+
+fn can_check_solution_objectives_impl(
+    objectives: Option<Vec<Vec<Objective>>>,
+    statistic: (f64, i64, i64),
+    expected: Result<(), &str>,
+) {
+    let tour_statistic = create_test_statistic(40., 20, (10, 5, 5));
+    let statistic =
+        Statistic { cost: statistic.0, distance: statistic.1, duration: statistic.2, ..Statistic::default() };
+    let ctx = create_test_context(objectives, create_test_solution(statistic, tour_statistic));
+
+    let result = check_solution_objectives(&ctx);
+
+    assert_eq!(result, expected.map_err(|err| err.to_string()));
+}
@@ -87,28 +87,43 @@ mod single {
                     profile: create_default_vehicle_profile(),
                     costs: create_default_vehicle_costs(),
                     shifts: vec![VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: vec![0., 0.].to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            optimize_departure: None,
+                            location: vec![0., 0.].to_loc(),
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(1000.).to_string(),
                             location: vec![0., 0.].to_loc(),
+                            alternatives: None,
                         }),
                         dispatch: None,
                         breaks: Some(vec![VehicleBreak::Optional {
                             time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(0.), format_time(1000.)]),
                             places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
                             policy: None,
+                            min_shift_duration: None,
+                            min_spacing: None,
                         }]),
                         reloads: Some(vec![VehicleReload {
                             times: None,
                             location: vec![0., 0.].to_loc(),
                             duration: 2.0,
                             tag: None,
+                            capacity: None,
+                            per_unit_duration: None,
+                            resource: None,
                         }]),
+                        loading: None,
                     }],
                     capacity: vec![5],
                     skills: None,
                     limits: None,
+                    carry_load: None,
+                    echelon: None,
+                    service_time_factor: None,
                 }],
                 profiles: create_default_matrix_profiles(),
             },
@@ -120,6 +135,7 @@ mod single {
                 distance: 16,
                 duration: 25,
                 times: Timing { driving: 16, serving: 9, break_time: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![
                 VehicleTour {
@@ -152,6 +168,7 @@ mod single {
                             distance: 2,
                             parking: None,
                             load: vec![0],
+                            service: None,
                             activities: vec![
                                 Activity {
                                     job_id: "job2".to_string(),
@@ -217,7 +234,9 @@ mod single {
                         distance: 16,
                         duration: 25,
                         times: Timing { driving: 16, serving: 9, break_time: 2, ..Timing::default() },
+                        toll: None,
                     },
+                    departure: None,
                 },
                 VehicleTour {
                     vehicle_id: "my_vehicle_2".to_string(),
@@ -225,6 +244,7 @@ mod single {
                     shift_index: 0,
                     stops: vec![],
                     statistic: Default::default(),
+                    departure: None,
                 },
             ],
             ..create_empty_solution()
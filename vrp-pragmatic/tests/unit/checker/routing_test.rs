@@ -15,7 +15,13 @@ fn create_test_problem() -> Problem {
 }
 
 fn create_test_statistic() -> Statistic {
-    Statistic { cost: 10., distance: 4, duration: 6, times: Timing { driving: 4, serving: 2, ..Timing::default() } }
+    Statistic {
+        cost: 10.,
+        distance: 4,
+        duration: 6,
+        times: Timing { driving: 4, serving: 2, ..Timing::default() },
+        toll: None,
+    }
 }
 
 fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> Solution {
@@ -41,6 +47,7 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                     distance: first.1,
                     parking: None,
                     load: vec![1],
+                    service: None,
                     activities: vec![Activity {
                         job_id: "job1".to_string(),
                         activity_type: "delivery".to_string(),
@@ -56,6 +63,7 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                     distance: second.1,
                     parking: None,
                     load: vec![0],
+                    service: None,
                     activities: vec![Activity {
                         job_id: "job2".to_string(),
                         activity_type: "delivery".to_string(),
@@ -75,6 +83,7 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
                 ),
             ],
             statistic,
+            departure: None,
         }],
         ..create_empty_solution()
     }
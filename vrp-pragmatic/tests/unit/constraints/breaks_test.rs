@@ -1,4 +1,5 @@
 use crate::constraints::BreakModule;
+use crate::format::dimens::*;
 use crate::helpers::*;
 use std::sync::Arc;
 use vrp_core::construction::constraints::ConstraintModule;
@@ -18,9 +19,9 @@ fn create_single(id: &str) -> Arc<Single> {
 fn create_break(vehicled_id: &str, location: Option<Location>) -> Arc<Single> {
     let mut single = create_single_with_location(location);
     single.dimens.set_id("break");
-    single.dimens.set_value("type", "break".to_string());
-    single.dimens.set_value("vehicle_id", vehicled_id.to_string());
-    single.dimens.set_value("shift_index", 0_usize);
+    single.dimens.set_dimen(&JOB_TYPE_KEY, "break".to_string());
+    single.dimens.set_dimen(&JOB_VEHICLE_ID_KEY, vehicled_id.to_string());
+    single.dimens.set_dimen(&SHIFT_INDEX_KEY, 0_usize);
 
     Arc::new(single)
 }
@@ -0,0 +1,76 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::TimeWindow;
+use vrp_core::models::problem::Fleet;
+
+parameterized_test! {can_drive_to_activity, (state, time, expected), {
+    can_drive_to_activity_impl(state, time, expected);
+}}
+
+can_drive_to_activity! {
+    case01_no_rest: ((0., 0.), (0., 1000.), Some((0., 42., 42., 42.))),
+    case02_rest_before_departure: ((60., 60.), (0., 1000.), Some((30., 72., 42., 102.))),
+    case03_rest_by_waiting: ((0., 0.), (100., 1000.), Some((0., 42., 0., 42.))),
+    case04_short_waiting: ((0., 0.), (60., 1000.), Some((0., 42., 42., 42.))),
+    case05_daily_rest_before_departure: ((60., 180.), (0., 1000.), Some((500., 542., 42., 42.))),
+    case06_daily_rest_by_waiting: ((0., 100.), (600., 1000.), Some((0., 42., 0., 0.))),
+    case07_daily_rest_violates_time: ((60., 180.), (0., 500.), None),
+}
+
+fn can_drive_to_activity_impl(
+    state: (f64, f64),
+    time: (f64, f64),
+    expected: Option<(Timestamp, Timestamp, Duration, Duration)>,
+) {
+    let hours =
+        DriverHours { max_continuous_driving: 100., rest_duration: 30., max_daily_driving: 200., daily_rest: 500. };
+    let (transport, activity) = get_costs();
+    let route = create_route_with_activities(&test_fleet(), "v1", vec![]);
+    let mut target = create_activity_with_job_at_location(create_single("job1"), 1);
+    target.place.time = TimeWindow::new(time.0, time.1);
+    let state = DrivingState { continuous: state.0, total: state.1 };
+
+    let result = drive_to(&hours, &route, activity.as_ref(), transport.as_ref(), (0, 0., state), &target)
+        .map(|step| (step.departure, step.arrival, step.state.continuous, step.state.total));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_rest_cost, (max_continuous_driving, expected), {
+    can_estimate_rest_cost_impl(max_continuous_driving, expected);
+}}
+
+can_estimate_rest_cost! {
+    case01_no_rest: (100., 0.),
+    case02_rest_at_target: (60., 60.),
+}
+
+fn can_estimate_rest_cost_impl(max_continuous_driving: Duration, expected: f64) {
+    let hours = DriverHours { max_continuous_driving, rest_duration: 30., max_daily_driving: 200., daily_rest: 500. };
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_dimen(&VEHICLE_DRIVER_HOURS_KEY, hours);
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let (transport, activity) = get_costs();
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        Arc::new(RouteState::default()),
+    );
+    let target = create_activity_with_job_at_location(create_single("job1"), 1);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let result = DriverHoursSoftActivityConstraint { state_key: 0, activity, transport }
+        .estimate_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
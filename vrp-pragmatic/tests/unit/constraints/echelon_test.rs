@@ -0,0 +1,95 @@
+use super::*;
+use crate::constraints::SyncVisit;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::constraints::{ConstraintPipeline, RouteConstraintViolation};
+use vrp_core::construction::heuristics::{RouteContext, RouteState};
+use vrp_core::models::common::Dimensions;
+use vrp_core::models::problem::{Fleet, Multi, Vehicle};
+
+fn create_job(transfer_index: Option<usize>) -> Job {
+    match transfer_index {
+        Some(index) => {
+            let mut visit = create_single_with_location(None);
+            visit.dimens.set_dimen(
+                &JOB_TRANSFER_VISIT_KEY,
+                SyncVisit { job_id: "job1".to_string(), index, total: 2, max_gap: None },
+            );
+            let singles = vec![Arc::new(create_single_with_location(None)), Arc::new(visit)];
+
+            Job::Multi(Multi::bind(Multi::new(singles, Dimensions::default())))
+        }
+        None => Job::Single(Arc::new(create_single_with_location(None))),
+    }
+}
+
+fn create_vehicle_with_echelon(echelon: Option<usize>) -> Vehicle {
+    let mut vehicle = test_vehicle("v1");
+
+    if let Some(echelon) = echelon {
+        vehicle.dimens.set_dimen(&VEHICLE_ECHELON_KEY, echelon);
+    }
+
+    vehicle
+}
+
+fn failure() -> Option<RouteConstraintViolation> {
+    Some(RouteConstraintViolation { code: 1 })
+}
+
+parameterized_test! {can_check_echelon, (transfer_index, echelon, expected), {
+    can_check_echelon_impl(transfer_index, echelon, expected);
+}}
+
+can_check_echelon! {
+    case01_regular_any: (None, None, None),
+    case02_regular_first: (None, Some(1), failure()),
+    case03_regular_second: (None, Some(2), None),
+    case04_drop_any: (Some(0), None, None),
+    case05_drop_first: (Some(0), Some(1), None),
+    case06_drop_second: (Some(0), Some(2), failure()),
+    case07_pickup_any: (Some(1), None, None),
+    case08_pickup_first: (Some(1), Some(1), failure()),
+    case09_pickup_second: (Some(1), Some(2), None),
+}
+
+fn can_check_echelon_impl(
+    transfer_index: Option<usize>,
+    echelon: Option<usize>,
+    expected: Option<RouteConstraintViolation>,
+) {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(create_vehicle_with_echelon(echelon))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        Arc::new(RouteState::default()),
+    );
+
+    let actual = ConstraintPipeline::default().add_module(Arc::new(EchelonModule::new(1))).evaluate_hard_route(
+        &create_solution_context_for_fleet(&fleet),
+        &route_ctx,
+        &create_job(transfer_index),
+    );
+
+    assert_eq!(actual, expected)
+}
+
+parameterized_test! {can_merge_jobs_of_same_echelon, (source, candidate, expected), {
+    can_merge_jobs_of_same_echelon_impl(source, candidate, expected);
+}}
+
+can_merge_jobs_of_same_echelon! {
+    case01: (None, None, Ok(())),
+    case02: (None, Some(1), Ok(())),
+    case03: (None, Some(0), Err(1)),
+    case04: (Some(0), Some(0), Ok(())),
+}
+
+fn can_merge_jobs_of_same_echelon_impl(source: Option<usize>, candidate: Option<usize>, expected: Result<(), i32>) {
+    let result = EchelonModule::new(1).merge(create_job(source), create_job(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
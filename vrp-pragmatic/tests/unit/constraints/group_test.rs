@@ -26,7 +26,7 @@ fn create_test_fleet() -> Fleet {
 fn create_test_single(group: Option<&str>) -> Arc<Single> {
     let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
     if let Some(group) = group {
-        single.dimens.set_value("group", group.to_string())
+        single.dimens.set_dimen(&JOB_GROUP_KEY, group.to_string())
     }
 
     Arc::new(single)
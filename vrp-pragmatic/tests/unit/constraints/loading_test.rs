@@ -0,0 +1,18 @@
+use super::*;
+
+parameterized_test! {can_get_loading_duration, (duration, per_unit_duration, amount, expected), {
+    can_get_loading_duration_impl(duration, per_unit_duration, amount, expected);
+}}
+
+can_get_loading_duration! {
+    case01_fixed_only: (10., 0., 5., 10.),
+    case02_empty_vehicle: (10., 2., 0., 10.),
+    case03_per_unit: (10., 2., 5., 20.),
+    case04_per_unit_only: (0., 2., 3., 6.),
+}
+
+fn can_get_loading_duration_impl(duration: Duration, per_unit_duration: Duration, amount: f64, expected: Duration) {
+    let loading = DepartureLoading { duration, per_unit_duration };
+
+    assert_eq!(loading.get_duration(amount), expected);
+}
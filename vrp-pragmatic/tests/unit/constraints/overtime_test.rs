@@ -0,0 +1,61 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use vrp_core::construction::heuristics::RouteState;
+use vrp_core::models::common::{TimeWindow, Timestamp};
+use vrp_core::models::problem::Fleet;
+
+parameterized_test! {can_get_overtime_cost, (duration, expected), {
+    can_get_overtime_cost_impl(duration, expected);
+}}
+
+can_get_overtime_cost! {
+    case01_no_overtime: (80., 0.),
+    case02_exact_shift_time: (100., 0.),
+    case03_overtime: (130., 60.),
+}
+
+fn can_get_overtime_cost_impl(duration: Duration, expected: Cost) {
+    let overtime = Overtime { shift_time: 100., cost: 2. };
+
+    assert_eq!(overtime.get_cost(duration), expected);
+}
+
+parameterized_test! {can_estimate_overtime_cost_with_schedule_shift, (start, duration, expected), {
+    can_estimate_overtime_cost_with_schedule_shift_impl(start, duration, expected);
+}}
+
+can_estimate_overtime_cost_with_schedule_shift! {
+    case01_travel_only: (0., 0., 68.),
+    case02_with_service: (0., 10., 88.),
+    case03_with_waiting: (100., 10., 204.),
+}
+
+fn can_estimate_overtime_cost_with_schedule_shift_impl(start: Timestamp, duration: Duration, expected: Cost) {
+    let mut vehicle = test_vehicle("v1");
+    vehicle.dimens.set_dimen(&VEHICLE_OVERTIME_KEY, Overtime { shift_time: 50., cost: 2. });
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let (transport, activity) = get_costs();
+    let mut route = create_route_with_activities(&fleet, "v1", vec![]);
+    route.tour.get_mut(1).unwrap().schedule.arrival = 42.;
+    let mut state = RouteState::default();
+    state.put_route_state(TOTAL_DURATION_KEY, 42.);
+    let route_ctx = RouteContext::new_with_state(Arc::new(route), Arc::new(state));
+    let mut target = create_activity_with_job_at_location(create_single("job1"), 1);
+    target.place.time = TimeWindow::new(start, 1000.);
+    target.place.duration = duration;
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route.tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route.tour.get(1),
+    };
+
+    let result = OvertimeSoftActivityConstraint { activity, transport }.estimate_activity(&route_ctx, &activity_ctx);
+
+    assert_eq!(result, expected);
+}
@@ -6,7 +6,7 @@ use vrp_core::models::common::SingleDimLoad;
 fn can_handle_reload_jobs_with_merge() {
     let create_reload = || {
         let mut single = create_single_with_location(None);
-        single.dimens.set_value("type", "reload".to_string());
+        single.dimens.set_dimen(&JOB_TYPE_KEY, "reload".to_string());
 
         Job::Single(Arc::new(single))
     };
@@ -19,3 +19,21 @@ fn can_handle_reload_jobs_with_merge() {
     assert_eq!(constraint.merge(create_job(), create_reload()).map(|_| ()), Err(2));
     assert_eq!(constraint.merge(create_reload(), create_reload()).map(|_| ()), Err(2));
 }
+
+parameterized_test! {can_get_max_usage, (schedules, interval, expected), {
+    can_get_max_usage_impl(schedules, interval, expected);
+}}
+
+can_get_max_usage! {
+    case01: (vec![], (0., 10.), 0),
+    case02: (vec![(0., 5.)], (5., 10.), 0),
+    case03: (vec![(0., 5.)], (4., 10.), 1),
+    case04: (vec![(0., 5.), (5., 10.)], (0., 10.), 1),
+    case05: (vec![(0., 5.), (3., 10.)], (0., 10.), 2),
+    case06: (vec![(0., 2.), (3., 10.), (4., 6.)], (1., 5.), 2),
+    case07: (vec![(6., 8.), (0., 2.)], (1., 7.), 1),
+}
+
+fn can_get_max_usage_impl(schedules: Vec<(f64, f64)>, interval: (f64, f64), expected: usize) {
+    assert_eq!(get_max_usage(schedules.as_slice(), interval), expected);
+}
@@ -1,5 +1,6 @@
 use crate::constraints::{JobSkills, SkillsModule};
 use crate::extensions::create_typed_actor_groups;
+use crate::format::dimens::*;
 use crate::helpers::*;
 use hashbrown::HashSet;
 use std::iter::FromIterator;
@@ -8,12 +9,12 @@ use vrp_core::construction::constraints::ConstraintModule;
 use vrp_core::construction::constraints::{ConstraintPipeline, RouteConstraintViolation};
 use vrp_core::construction::heuristics::{RouteContext, RouteState};
 use vrp_core::models::common::ValueDimension;
-use vrp_core::models::problem::{Fleet, Job, Vehicle};
+use vrp_core::models::problem::{Fleet, Job, Multi, Single, Vehicle};
 
 fn create_job_with_skills(all_of: Option<Vec<&str>>, one_of: Option<Vec<&str>>, none_of: Option<Vec<&str>>) -> Job {
     let mut single = create_single_with_location(None);
-    single.dimens.set_value(
-        "skills",
+    single.dimens.set_dimen(
+        &JOB_SKILLS_KEY,
         JobSkills {
             all_of: all_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
             one_of: one_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
@@ -24,11 +25,25 @@ fn create_job_with_skills(all_of: Option<Vec<&str>>, one_of: Option<Vec<&str>>,
     Job::Single(Arc::new(single))
 }
 
+fn create_single_with_task_skills(all_of: Option<Vec<&str>>) -> Arc<Single> {
+    let mut single = create_single_with_location(None);
+    if let Some(all_of) = all_of {
+        single.dimens.set_dimen(
+            &JOB_TASK_SKILLS_KEY,
+            JobSkills { all_of: Some(all_of.iter().map(|s| s.to_string()).collect()), one_of: None, none_of: None },
+        );
+    }
+
+    Arc::new(single)
+}
+
 fn create_vehicle_with_skills(skills: Option<Vec<&str>>) -> Vehicle {
     let mut vehicle = test_vehicle("v1");
 
     if let Some(skills) = skills {
-        vehicle.dimens.set_value("skills", HashSet::<String>::from_iter(skills.iter().map(|s| s.to_string())));
+        vehicle
+            .dimens
+            .set_dimen(&VEHICLE_SKILLS_KEY, HashSet::<String>::from_iter(skills.iter().map(|s| s.to_string())));
     }
 
     vehicle
@@ -129,3 +144,45 @@ fn can_merge_skills_impl(source: Job, candidate: Job, expected: Result<(), i32>)
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_check_task_skills_of_multi_job, (pickup_skills, delivery_skills, vehicle_skills, expected), {
+    can_check_task_skills_of_multi_job_impl(pickup_skills, delivery_skills, vehicle_skills, expected);
+}}
+
+can_check_task_skills_of_multi_job! {
+    case01: (None, None, None, None),
+    case02: (Some(vec!["s1"]), None, Some(vec!["s1"]), None),
+    case03: (Some(vec!["s1"]), None, None, failure()),
+    case04: (None, Some(vec!["s2"]), Some(vec!["s1"]), failure()),
+    case05: (Some(vec!["s1"]), Some(vec!["s2"]), Some(vec!["s1"]), failure()),
+    case06: (Some(vec!["s1"]), Some(vec!["s2"]), Some(vec!["s1", "s2"]), None),
+}
+
+fn can_check_task_skills_of_multi_job_impl(
+    pickup_skills: Option<Vec<&str>>,
+    delivery_skills: Option<Vec<&str>>,
+    vehicle_skills: Option<Vec<&str>>,
+    expected: Option<RouteConstraintViolation>,
+) {
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(create_vehicle_with_skills(vehicle_skills))],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route_ctx = RouteContext::new_with_state(
+        Arc::new(create_route_with_activities(&fleet, "v1", vec![])),
+        Arc::new(RouteState::default()),
+    );
+    let job = Job::Multi(Multi::bind(Multi::new(
+        vec![create_single_with_task_skills(pickup_skills), create_single_with_task_skills(delivery_skills)],
+        Default::default(),
+    )));
+
+    let actual = ConstraintPipeline::default().add_module(Arc::new(SkillsModule::new(0))).evaluate_hard_route(
+        &create_solution_context_for_fleet(&fleet),
+        &route_ctx,
+        &job,
+    );
+
+    assert_eq!(actual, expected)
+}
@@ -0,0 +1,19 @@
+use super::*;
+
+parameterized_test! {can_check_schedules, (schedules, max_gap, expected), {
+    can_check_schedules_impl(schedules, max_gap, expected);
+}}
+
+can_check_schedules! {
+    case01_in_order: (vec![Some((0., 10.)), Some((10., 15.))], None, true),
+    case02_wrong_order: (vec![Some((0., 10.)), Some((5., 15.))], None, false),
+    case03_within_gap: (vec![Some((0., 10.)), Some((15., 20.))], Some(5.), true),
+    case04_exceeded_gap: (vec![Some((0., 10.)), Some((16., 20.))], Some(5.), false),
+    case05_partial: (vec![Some((0., 10.)), None, Some((30., 40.))], Some(5.), true),
+    case06_partial_wrong_order: (vec![Some((20., 30.)), None, Some((10., 15.))], None, false),
+    case07_no_visits: (vec![None, None], Some(5.), true),
+}
+
+fn can_check_schedules_impl(schedules: Vec<Option<(f64, f64)>>, max_gap: Option<f64>, expected: bool) {
+    assert_eq!(is_valid_schedules(schedules.as_slice(), max_gap), expected);
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+fn get_test_locations() -> Vec<ApiLocation> {
+    vec![
+        ApiLocation::Coordinate { lat: 52.52599, lng: 13.45413 },
+        ApiLocation::Coordinate { lat: 52.5225, lng: 13.4095 },
+        ApiLocation::Coordinate { lat: 52.5165, lng: 13.3808 },
+    ]
+}
+
+#[test]
+fn can_calculate_approximated_costs() {
+    let profile = Profile::default();
+    let speed = 10.;
+    let transport = GeoTransportCost::new(get_test_locations(), vec![GeoProfile::default()]).unwrap();
+
+    vec![(0, 1, 3048.), (1, 2, 2056.), (2, 0, 5078.), (1, 1, 0.)].into_iter().for_each(|(from, to, expected)| {
+        let distance = transport.distance_approx(&profile, from, to);
+        let duration = transport.duration_approx(&profile, from, to);
+
+        assert_eq!(distance, expected);
+        assert_eq!(duration, (distance / speed).round());
+    });
+}
+
+parameterized_test! {can_use_profile_settings, (profile, expected), {
+    can_use_profile_settings_impl(profile, expected);
+}}
+
+can_use_profile_settings! {
+    case01_default: (Profile::new(0, None), (5078., 508.)),
+    case02_speed: (Profile::new(1, None), (5078., 1016.)),
+    case03_detour: (Profile::new(2, None), (7617., 762.)),
+    case04_scale: (Profile::new(2, Some(2.)), (7617., 1524.)),
+}
+
+fn can_use_profile_settings_impl(profile: Profile, expected: (f64, f64)) {
+    let profiles = vec![
+        GeoProfile::default(),
+        GeoProfile { speed: 5., detour_factor: 1. },
+        GeoProfile { speed: 10., detour_factor: 1.5 },
+    ];
+    let transport = GeoTransportCost::new(get_test_locations(), profiles).unwrap();
+
+    let distance = transport.distance_approx(&profile, 0, 2);
+    let duration = transport.duration_approx(&profile, 0, 2);
+
+    assert_eq!((distance, duration), expected);
+}
+
+parameterized_test! {can_validate_settings, (locations, profiles, expected), {
+    can_validate_settings_impl(locations, profiles, expected);
+}}
+
+can_validate_settings! {
+    case01_no_profiles: (get_test_locations(), vec![], "at least one profile should be specified"),
+    case02_zero_speed: (get_test_locations(), vec![GeoProfile { speed: 0., detour_factor: 1. }],
+                        "speed should be positive, got: '0'"),
+    case03_small_detour: (get_test_locations(), vec![GeoProfile { speed: 1., detour_factor: 0.5 }],
+                          "detour factor should not be less than one, got: '0.5'"),
+    case04_reference: (vec![ApiLocation::Reference { index: 0 }], vec![GeoProfile::default()],
+                       "geo approximation requires coordinates"),
+}
+
+fn can_validate_settings_impl(locations: Vec<ApiLocation>, profiles: Vec<GeoProfile>, expected: &str) {
+    let result = GeoTransportCost::new(locations, profiles);
+
+    assert_eq!(result.err(), Some(expected.to_string()));
+}
@@ -0,0 +1,50 @@
+use super::*;
+use crate::extensions::create_typed_actor_groups;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::models::problem::Fleet;
+
+fn create_activity(is_conditional: bool) -> Activity {
+    let mut single = create_single_with_location(Some(DEFAULT_JOB_LOCATION));
+    single.dimens.set_dimen(&JOB_TYPE_KEY, if is_conditional { "break" } else { "delivery" }.to_string());
+    if is_conditional {
+        single.dimens.set_dimen(&JOB_VEHICLE_ID_KEY, "v1".to_string());
+    }
+
+    let mut activity = create_activity_with_job_at_location(Arc::new(single), DEFAULT_JOB_LOCATION);
+    activity.place.duration = 10.;
+
+    activity
+}
+
+parameterized_test! {can_scale_service_time, (factor, is_conditional, expected), {
+    can_scale_service_time_impl(factor, is_conditional, expected);
+}}
+
+can_scale_service_time! {
+    case01_no_factor: (None, false, (110., 10.)),
+    case02_slower: (Some(1.5), false, (115., 15.)),
+    case03_faster: (Some(0.5), false, (105., 5.)),
+    case04_conditional: (Some(1.5), true, (110., 10.)),
+}
+
+fn can_scale_service_time_impl(factor: Option<f64>, is_conditional: bool, expected: (Timestamp, Cost)) {
+    let mut vehicle = test_vehicle("v1");
+    if let Some(factor) = factor {
+        vehicle.dimens.set_dimen(&VEHICLE_SERVICE_TIME_FACTOR_KEY, factor);
+    }
+    let fleet = Fleet::new(
+        vec![Arc::new(test_driver())],
+        vec![Arc::new(vehicle)],
+        Box::new(|actors| create_typed_actor_groups(actors)),
+    );
+    let route = create_route_with_activities(&fleet, "v1", vec![]);
+    let activity = create_activity(is_conditional);
+    let activity_cost = OnlyVehicleActivityCost::new(true);
+
+    let departure = activity_cost.estimate_departure(&route, &activity, 100.);
+    let cost = activity_cost.cost(&route, &activity, 100.);
+
+    assert_eq!((departure, cost), expected);
+    assert_eq!(activity_cost.estimate_arrival(&route, &activity, departure), 100.);
+}
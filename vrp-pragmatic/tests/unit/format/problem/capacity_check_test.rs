@@ -0,0 +1,200 @@
+use super::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(jobs: Vec<Job>, vehicles: Vec<VehicleType>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet { vehicles, profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    }
+}
+
+fn create_skills(all_of: Vec<&str>) -> JobSkills {
+    JobSkills { all_of: Some(all_of.iter().map(|skill| skill.to_string()).collect()), one_of: None, none_of: None }
+}
+
+#[test]
+fn can_accept_sufficient_capacity() {
+    let problem = create_problem(
+        vec![
+            create_delivery_job_with_demand("job1", vec![1., 0.], vec![4]),
+            create_pickup_job_with_demand("job2", vec![2., 0.], vec![6]),
+            create_pickup_delivery_job("job3", vec![3., 0.], vec![4., 0.]),
+        ],
+        vec![create_vehicle_with_capacity("vehicle", vec![6])],
+    );
+
+    let report = check_capacity_feasibility(&problem);
+
+    assert!(!report.overall.has_shortfall());
+    assert_eq!(report.overall.jobs, 3);
+    assert_eq!(report.overall.delivery, vec![4]);
+    assert_eq!(report.overall.pickup, vec![6]);
+    assert_eq!(report.overall.transfer, vec![1]);
+    assert_eq!(report.overall.capacity, vec![6]);
+    assert!(report.overall.oversized.is_empty());
+    assert!(report.overall.suggestions.is_empty());
+    assert!(report.skills.is_empty());
+    assert!(report.areas.is_empty());
+}
+
+#[test]
+fn can_detect_overall_shortfall_and_suggest_extra_vehicles() {
+    let problem = create_problem(
+        vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![32, 1])],
+        vec![create_vehicle_with_capacity("small", vec![5, 10]), create_vehicle_with_capacity("large", vec![20, 0])],
+    );
+
+    let report = check_capacity_feasibility(&problem);
+
+    assert!(report.overall.has_shortfall());
+    assert_eq!(report.overall.capacity, vec![25, 10]);
+    assert_eq!(report.overall.shortfall, vec![7, 0]);
+    assert_eq!(
+        report.overall.suggestions,
+        vec![
+            VehicleSuggestion { type_id: "small".to_string(), vehicles: 2 },
+            VehicleSuggestion { type_id: "large".to_string(), vehicles: 1 },
+        ]
+    );
+}
+
+#[test]
+fn can_take_into_account_shifts_and_reloads() {
+    let mut vehicle = create_vehicle_with_capacity("vehicle", vec![5]);
+    vehicle.vehicle_ids = vec!["vehicle_1".to_string(), "vehicle_2".to_string()];
+    vehicle.shifts[0].reloads = Some(vec![VehicleReload {
+        location: vec![0., 0.].to_loc(),
+        duration: 1.,
+        times: None,
+        tag: None,
+        capacity: None,
+        per_unit_duration: None,
+        resource: None,
+    }]);
+    let problem = create_problem(vec![create_delivery_job_with_demand("job1", vec![1., 0.], vec![21])], vec![vehicle]);
+
+    let report = check_capacity_feasibility(&problem);
+
+    assert_eq!(report.overall.capacity, vec![20]);
+    assert_eq!(report.overall.shortfall, vec![1]);
+    assert_eq!(report.overall.suggestions, vec![VehicleSuggestion { type_id: "vehicle".to_string(), vehicles: 1 }]);
+}
+
+#[test]
+fn can_detect_shortfall_per_required_skills() {
+    let problem = create_problem(
+        vec![
+            create_delivery_job_with_skills("job1", vec![1., 0.], create_skills(vec!["fridge"])),
+            create_delivery_job_with_skills("job2", vec![2., 0.], create_skills(vec!["fridge"])),
+            create_delivery_job("job3", vec![3., 0.]),
+        ],
+        vec![
+            VehicleType { skills: Some(vec!["fridge".to_string()]), ..create_vehicle_with_capacity("fridge", vec![1]) },
+            create_vehicle_with_capacity("regular", vec![10]),
+        ],
+    );
+
+    let report = check_capacity_feasibility(&problem);
+
+    assert!(!report.overall.has_shortfall());
+    assert_eq!(report.skills.len(), 1);
+    assert_eq!(report.skills[0].jobs, 2);
+    assert_eq!(report.skills[0].capacity, vec![1]);
+    assert_eq!(report.skills[0].shortfall, vec![1]);
+    assert_eq!(report.skills[0].suggestions, vec![VehicleSuggestion { type_id: "fridge".to_string(), vehicles: 1 }]);
+}
+
+#[test]
+fn can_detect_shortfall_per_task_skills() {
+    let mut job = create_pickup_delivery_job("job1", vec![1., 0.], vec![2., 0.]);
+    job.deliveries.as_mut().unwrap()[0].skills = Some(create_skills(vec!["fridge"]));
+    let problem = create_problem(
+        vec![job, create_delivery_job_with_skills("job2", vec![3., 0.], create_skills(vec!["fridge"]))],
+        vec![
+            VehicleType { skills: Some(vec!["fridge".to_string()]), ..create_vehicle_with_capacity("fridge", vec![0]) },
+            create_vehicle_with_capacity("regular", vec![10]),
+        ],
+    );
+
+    let report = check_capacity_feasibility(&problem);
+
+    assert!(report.overall.oversized.is_empty());
+    assert_eq!(report.skills.len(), 2);
+    assert!(report.skills[0].skills.is_none());
+    assert_eq!(
+        report.skills[0].task_skills.as_ref().and_then(|skills| skills.all_of.clone()),
+        Some(vec!["fridge".to_string()])
+    );
+    assert_eq!(report.skills[0].jobs, 1);
+    assert_eq!(report.skills[0].oversized, vec!["job1".to_string()]);
+    assert!(report.skills[0].has_shortfall());
+    assert!(report.skills[1].task_skills.is_none());
+    assert_eq!(report.skills[1].shortfall, vec![1]);
+}
+
+#[test]
+fn can_detect_oversized_pickup_delivery_job() {
+    let problem = create_problem(
+        vec![
+            create_pickup_delivery_job_with_params(
+                "job1",
+                vec![5],
+                (vec![1., 0.], 1., vec![]),
+                (vec![2., 0.], 1., vec![]),
+            ),
+            create_pickup_delivery_job("job2", vec![3., 0.], vec![4., 0.]),
+        ],
+        vec![create_vehicle_with_capacity("vehicle", vec![2]), create_vehicle_with_capacity("vehicle", vec![4])],
+    );
+
+    let report = check_capacity_feasibility(&problem);
+
+    assert_eq!(report.overall.transfer, vec![6]);
+    assert_eq!(report.overall.shortfall, vec![0]);
+    assert_eq!(report.overall.oversized, vec!["job1".to_string()]);
+    assert!(report.overall.has_shortfall());
+}
+
+#[test]
+fn can_detect_shortfall_per_area() {
+    let area_vehicle = VehicleType {
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            shift_time: None,
+            tour_size: None,
+            max_cost: None,
+            max_stops: None,
+            areas: Some(vec![vec![AreaLimit { area_id: "area1".to_string(), job_value: 1. }]]),
+            driver_hours: None,
+            overtime: None,
+        }),
+        ..create_vehicle_with_capacity("area", vec![1])
+    };
+    let mut problem = create_problem(
+        vec![
+            create_delivery_job("job1", vec![1., 0.]),
+            create_delivery_job("job2", vec![2., 0.]),
+            create_delivery_job("job3", vec![3., 0.]),
+        ],
+        vec![area_vehicle, create_vehicle_with_capacity("regular", vec![10])],
+    );
+    problem.plan.areas = Some(vec![
+        Area { id: "area1".to_string(), jobs: vec!["job1".to_string(), "job2".to_string()] },
+        Area { id: "area2".to_string(), jobs: vec!["job3".to_string()] },
+    ]);
+
+    let report = check_capacity_feasibility(&problem);
+
+    assert!(!report.overall.has_shortfall());
+    assert_eq!(report.areas.len(), 2);
+    assert_eq!(report.areas[0].area, Some("area1".to_string()));
+    assert_eq!(report.areas[0].jobs, 2);
+    assert_eq!(report.areas[0].capacity, vec![1]);
+    assert_eq!(report.areas[0].shortfall, vec![1]);
+    assert_eq!(report.areas[0].suggestions, vec![VehicleSuggestion { type_id: "area".to_string(), vehicles: 1 }]);
+    assert_eq!(report.areas[1].area, Some("area2".to_string()));
+    assert_eq!(report.areas[1].shortfall, vec![1]);
+    assert!(report.areas[1].suggestions.is_empty());
+}
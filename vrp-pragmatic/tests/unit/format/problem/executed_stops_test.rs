@@ -0,0 +1,131 @@
+use super::*;
+use crate::format::solution::Tour;
+use crate::helpers::*;
+
+fn create_tour(vehicle_id: &str, stops: Vec<(&str, &str, &str)>) -> Tour {
+    let stops = stops
+        .into_iter()
+        .map(|(job_id, activity_type, time)| {
+            create_stop_with_activity(job_id, activity_type, (0., 0.), 0, (time, time), 0)
+        })
+        .collect();
+
+    Tour { vehicle_id: vehicle_id.to_string(), type_id: "my_vehicle".to_string(), stops, ..create_empty_tour() }
+}
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![2., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+                create_pickup_delivery_job("job4", vec![4., 0.], vec![5., 0.]),
+            ],
+            relations: Some(vec![Relation {
+                type_field: RelationType::Any,
+                jobs: vec!["job1".to_string(), "job3".to_string()],
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_solution() -> Solution {
+    Solution {
+        tours: vec![
+            create_tour(
+                "my_vehicle_1",
+                vec![
+                    ("departure", "departure", "1970-01-01T00:00:00Z"),
+                    ("job1", "delivery", "1970-01-01T00:00:10Z"),
+                    ("job2", "delivery", "1970-01-01T00:00:20Z"),
+                    ("job3", "delivery", "1970-01-01T00:00:30Z"),
+                    ("arrival", "arrival", "1970-01-01T00:00:40Z"),
+                ],
+            ),
+            create_tour(
+                "my_vehicle_2",
+                vec![
+                    ("departure", "departure", "1970-01-01T00:00:00Z"),
+                    ("job4", "pickup", "1970-01-01T00:00:15Z"),
+                    ("job4", "delivery", "1970-01-01T00:00:25Z"),
+                    ("arrival", "arrival", "1970-01-01T00:00:35Z"),
+                ],
+            ),
+        ],
+        ..create_empty_solution()
+    }
+}
+
+fn get_relations(problem: &Problem) -> Vec<(String, String, Vec<&str>)> {
+    problem
+        .plan
+        .relations
+        .iter()
+        .flat_map(|relations| relations.iter())
+        .map(|relation| {
+            (
+                format!("{:?}", relation.type_field),
+                relation.vehicle_id.clone(),
+                relation.jobs.iter().map(|job| job.as_str()).collect(),
+            )
+        })
+        .collect()
+}
+
+parameterized_test! {can_lock_executed_stops, (executed, expected), {
+    can_lock_executed_stops_impl(executed, expected);
+}}
+
+can_lock_executed_stops! {
+    case01_time: (ExecutedStops::Time("1970-01-01T00:00:10Z".to_string()), Ok(vec![
+        ("Any", "my_vehicle_1", vec!["job3"]),
+        ("Strict", "my_vehicle_1", vec!["departure", "job1"]),
+    ])),
+    case02_time_all: (ExecutedStops::Time("1970-01-01T00:00:40Z".to_string()), Ok(vec![
+        ("Strict", "my_vehicle_1", vec!["departure", "job1", "job2", "job3", "arrival"]),
+        ("Strict", "my_vehicle_2", vec!["departure", "job4", "job4", "arrival"]),
+    ])),
+    case03_time_partial: (ExecutedStops::Time("1970-01-01T00:00:20Z".to_string()), Err(
+        "cannot lock partially executed jobs: job4".to_string()
+    )),
+    case04_count: (ExecutedStops::Count(3), Ok(vec![
+        ("Any", "my_vehicle_1", vec!["job3"]),
+        ("Strict", "my_vehicle_1", vec!["departure", "job1", "job2"]),
+        ("Strict", "my_vehicle_2", vec!["departure", "job4", "job4"]),
+    ])),
+    case05_count_departure_only: (ExecutedStops::Count(1), Ok(vec![
+        ("Any", "my_vehicle_1", vec!["job1", "job3"]),
+    ])),
+    case06_jobs: (ExecutedStops::Jobs(vec!["job2".to_string()]), Ok(vec![
+        ("Any", "my_vehicle_1", vec!["job3"]),
+        ("Strict", "my_vehicle_1", vec!["departure", "job1", "job2"]),
+    ])),
+    case07_jobs_all: (ExecutedStops::Jobs(vec!["job3".to_string()]), Ok(vec![
+        ("Strict", "my_vehicle_1", vec!["departure", "job1", "job2", "job3"]),
+    ])),
+    case08_bad_time: (ExecutedStops::Time("not a time".to_string()), Err(
+        "cannot parse date".to_string()
+    )),
+}
+
+fn can_lock_executed_stops_impl(executed: ExecutedStops, expected: Result<Vec<(&str, &str, Vec<&str>)>, String>) {
+    let result = lock_executed_stops(&create_test_problem(), &create_test_solution(), &executed);
+
+    match (result, expected) {
+        (Ok(problem), Ok(expected)) => {
+            let expected = expected
+                .into_iter()
+                .map(|(relation_type, vehicle_id, jobs)| (relation_type.to_string(), vehicle_id.to_string(), jobs))
+                .collect::<Vec<_>>();
+            assert_eq!(get_relations(&problem), expected);
+            assert_eq!(problem.plan.jobs.len(), 4);
+        }
+        (Err(err), Err(expected)) => assert!(err.starts_with(&expected), "'{}' vs '{}'", err, expected),
+        (result, expected) => panic!("unexpected result: {:?}, expected: {:?}", result.map(|_| ()), expected),
+    }
+}
@@ -1,12 +1,14 @@
 use super::create_transport_costs;
+use crate::format::dimens::*;
 use crate::format::problem::*;
 use crate::format_time;
 use crate::helpers::*;
 use std::sync::Arc;
-use vrp_core::models::common::{Distance, Profile as CoreProfile, TimeWindow, Timestamp};
+use vrp_core::models::common::{Distance, Profile as CoreProfile, TimeWindow, Timestamp, ValueDimension};
 use vrp_core::models::problem::TravelTime;
-use vrp_core::models::problem::{Actor, ActorDetail, Vehicle};
+use vrp_core::models::problem::{Actor, ActorDetail, MatrixStorage, Vehicle};
 use vrp_core::models::solution::Route;
+use vrp_core::utils::VrpError;
 
 fn matrix(profile: Option<&str>, timestamp: Option<f64>, fill_value: i64, size: usize) -> Matrix {
     Matrix {
@@ -15,6 +17,8 @@ fn matrix(profile: Option<&str>, timestamp: Option<f64>, fill_value: i64, size:
         travel_times: vec![fill_value; size],
         distances: vec![fill_value; size],
         error_codes: None,
+        units: None,
+        tolls: None,
     }
 }
 
@@ -25,6 +29,8 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
         travel_times: vec![1; 4],
         distances: vec![2; 3],
         error_codes: None,
+        units: None,
+        tolls: None,
     }
 }
 
@@ -32,7 +38,16 @@ fn create_problem(profiles: &[&str]) -> Problem {
     Problem {
         fleet: Fleet {
             vehicles: vec![],
-            profiles: profiles.iter().map(|p| MatrixProfile { name: p.to_string(), speed: None }).collect(),
+            profiles: profiles
+                .iter()
+                .map(|p| MatrixProfile {
+                    name: p.to_string(),
+                    speed: None,
+                    detour_factor: None,
+                    time_of_day: None,
+                    leg_overrides: None,
+                })
+                .collect(),
         },
         ..create_empty_problem()
     }
@@ -98,9 +113,9 @@ can_create_transport_costs_negative_cases! {
 fn can_create_transport_costs_negative_cases_impl(profiles: &[&str], matrices: &[Matrix], res_err: &str) {
     let problem = create_problem(profiles);
 
-    let result = create_transport_costs(&problem, matrices);
+    let result = create_transport_costs(&problem, matrices, MatrixStorage::default());
 
-    assert_eq!(result.err(), Some(res_err.to_string()));
+    assert_eq!(result.err(), Some(VrpError::Matrix(res_err.to_string())));
 }
 
 parameterized_test! {can_create_transport_costs_positive_cases, (profiles, matrices, probes), {
@@ -155,7 +170,7 @@ fn can_create_transport_costs_positive_cases_impl(
 ) {
     let problem = create_problem(profiles);
 
-    let transport = create_transport_costs(&problem, matrices).unwrap();
+    let transport = create_transport_costs(&problem, matrices, MatrixStorage::default()).unwrap();
 
     probes.iter().for_each(|&(profile_idx, timestamp, distance)| {
         let route = Route {
@@ -171,3 +186,35 @@ fn can_create_transport_costs_positive_cases_impl(
         assert_eq!(result, distance);
     });
 }
+
+#[test]
+fn can_group_identical_vehicle_types() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("type1"),
+                create_default_vehicle("type2"),
+                create_default_vehicle("type3"),
+                create_vehicle_with_capacity("type4", vec![5]),
+            ],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+
+    let matrix = create_matrix_from_problem(&problem);
+
+    let core_problem = (problem, vec![matrix]).read_pragmatic().unwrap();
+
+    assert_eq!(core_problem.fleet.actors.len(), 4);
+    assert_eq!(core_problem.fleet.groups.len(), 2);
+    let mut type_ids = core_problem
+        .fleet
+        .actors
+        .iter()
+        .map(|actor| actor.vehicle.dimens.get_dimen(&VEHICLE_TYPE_ID_KEY).unwrap().clone())
+        .collect::<Vec<_>>();
+    type_ids.sort();
+    assert_eq!(type_ids, vec!["type1", "type2", "type3", "type4"]);
+}
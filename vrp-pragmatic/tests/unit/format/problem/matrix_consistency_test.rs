@@ -0,0 +1,79 @@
+use super::*;
+
+fn create_matrix(travel_times: Vec<i64>, distances: Vec<i64>, error_codes: Option<Vec<i64>>) -> Matrix {
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times,
+        distances,
+        error_codes,
+        units: None,
+        tolls: None,
+    }
+}
+
+fn get_issues(matrix: &Matrix) -> Vec<(MatrixIssueKind, MatrixMetric, usize, usize, Option<usize>)> {
+    check_matrix_consistency(matrix, &MatrixConsistencySettings::default())
+        .expect("cannot check matrix")
+        .into_iter()
+        .map(|issue| (issue.kind, issue.metric, issue.from, issue.to, issue.via))
+        .collect()
+}
+
+#[test]
+fn can_accept_consistent_matrix() {
+    let matrix =
+        create_matrix(vec![0, 10, 20, 10, 0, 10, 20, 10, 0], vec![0, 100, 200, 100, 0, 100, 200, 100, 0], None);
+
+    assert!(get_issues(&matrix).is_empty());
+}
+
+#[test]
+fn can_detect_asymmetry() {
+    let matrix = create_matrix(vec![0, 10, 40, 0], vec![0, 100, 110, 0], None);
+
+    assert_eq!(get_issues(&matrix), vec![(MatrixIssueKind::Asymmetry, MatrixMetric::Duration, 0, 1, None)]);
+}
+
+#[test]
+fn can_detect_triangle_inequality_violation() {
+    let matrix =
+        create_matrix(vec![0, 10, 50, 10, 0, 10, 50, 10, 0], vec![0, 100, 200, 100, 0, 100, 200, 100, 0], None);
+
+    assert_eq!(
+        get_issues(&matrix),
+        vec![
+            (MatrixIssueKind::TriangleInequality, MatrixMetric::Duration, 0, 2, Some(1)),
+            (MatrixIssueKind::TriangleInequality, MatrixMetric::Duration, 2, 0, Some(1)),
+        ]
+    );
+}
+
+#[test]
+fn can_detect_zero_duration_with_nonzero_distance() {
+    let matrix = create_matrix(vec![0, 0, 0, 0], vec![0, 100, 100, 0], None);
+
+    assert_eq!(
+        get_issues(&matrix),
+        vec![
+            (MatrixIssueKind::ZeroDuration, MatrixMetric::Duration, 0, 1, None),
+            (MatrixIssueKind::ZeroDuration, MatrixMetric::Duration, 1, 0, None),
+        ]
+    );
+}
+
+#[test]
+fn can_skip_unreachable_cells() {
+    let matrix = create_matrix(vec![0, 0, 40, 0], vec![0, 100, 10, 0], Some(vec![0, 1, 0, 0]));
+
+    assert!(get_issues(&matrix).is_empty());
+}
+
+#[test]
+fn can_return_error_for_invalid_matrix_size() {
+    let matrix = create_matrix(vec![0, 10, 10], vec![0, 100, 100], None);
+
+    let result = check_matrix_consistency(&matrix, &MatrixConsistencySettings::default());
+
+    assert_eq!(result, Err("routing matrix is not square: 3 values".to_string()));
+}
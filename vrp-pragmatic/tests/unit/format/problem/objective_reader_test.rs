@@ -1,12 +1,16 @@
 use crate::constraints::{TOTAL_VALUE_KEY, TOUR_ORDER_KEY};
 use crate::format::problem::reader::objective_reader::create_objective;
-use crate::format::problem::reader::ProblemProperties;
+use crate::format::problem::reader::{CustomObjectives, ProblemProperties};
+use crate::format::problem::Objective::{Custom, MinimizeCost};
+use crate::format::problem::Problem;
 use crate::helpers::create_empty_insertion_context;
-use crate::helpers::create_empty_problem;
+use crate::helpers::{create_empty_problem, get_costs};
 use std::sync::Arc;
 use vrp_core::construction::constraints::ConstraintPipeline;
 use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::rosomaxa::prelude::MultiObjective;
+use vrp_core::solver::objectives::TotalDistance;
+use vrp_core::utils::VrpError;
 
 fn create_problem_props() -> ProblemProperties {
     ProblemProperties {
@@ -16,10 +20,20 @@ fn create_problem_props() -> ProblemProperties {
         has_unreachable_locations: false,
         has_dispatch: false,
         has_reloads: false,
+        has_extended_reloads: false,
+        has_loading: false,
         has_order: false,
         has_group: false,
+        has_sync: false,
+        has_transfer: false,
+        has_echelons: false,
         has_compatibility: false,
         has_tour_size_limits: false,
+        has_tour_cost_limits: false,
+        has_tour_stops_limits: false,
+        has_driver_hours: false,
+        has_overtime: false,
+        has_carried_load: false,
         max_job_value: None,
         max_area_value: None,
     }
@@ -38,7 +52,8 @@ fn can_define_proper_place_for_value_objective_by_default() {
     let mut constraint = ConstraintPipeline::default();
     let props = ProblemProperties { max_job_value: Some(1.), ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost =
+        create_objective(&problem, &mut constraint, &get_costs().0, None, &props, &Default::default()).unwrap();
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[0].fitness(&create_solution_with_state_value(TOTAL_VALUE_KEY, 1234.)), 1234.);
@@ -50,7 +65,8 @@ fn can_define_proper_place_for_order_objective_by_default() {
     let mut constraint = ConstraintPipeline::default();
     let props = ProblemProperties { has_order: true, ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost =
+        create_objective(&problem, &mut constraint, &get_costs().0, None, &props, &Default::default()).unwrap();
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[1].fitness(&create_solution_with_state_value(TOUR_ORDER_KEY, 1234_usize)), 1234.);
@@ -66,9 +82,44 @@ fn can_define_proper_places_for_mixed_priority_and_order_objectives_by_default()
 
     let props = ProblemProperties { max_job_value: Some(1.), has_order: true, ..create_problem_props() };
 
-    let objective_cost = create_objective(&problem, &mut constraint, &props);
+    let objective_cost =
+        create_objective(&problem, &mut constraint, &get_costs().0, None, &props, &Default::default()).unwrap();
     let objectives = objective_cost.objectives().collect::<Vec<_>>();
 
     assert_eq!(objectives[0].fitness(&insertion_ctx), 123.);
     assert_eq!(objectives[2].fitness(&insertion_ctx), 321.);
 }
+
+#[test]
+fn can_use_registered_custom_objective() {
+    let problem = Problem {
+        objectives: Some(vec![vec![Custom { name: "my-objective".to_string() }], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let mut constraint = ConstraintPipeline::default();
+    let mut custom_objectives = CustomObjectives::default();
+    custom_objectives.insert("my-objective".to_string(), (TotalDistance::minimize(), None));
+
+    let objective_cost =
+        create_objective(&problem, &mut constraint, &get_costs().0, None, &create_problem_props(), &custom_objectives)
+            .unwrap();
+
+    assert_eq!(objective_cost.objectives().count(), 2);
+}
+
+#[test]
+fn can_return_error_for_unknown_custom_objective() {
+    let problem = Problem {
+        objectives: Some(vec![vec![Custom { name: "my-objective".to_string() }], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let mut constraint = ConstraintPipeline::default();
+
+    let result =
+        create_objective(&problem, &mut constraint, &get_costs().0, None, &create_problem_props(), &Default::default());
+
+    assert_eq!(
+        result.err(),
+        Some(VrpError::Config("custom objectives are not registered: 'my-objective'".to_string()))
+    );
+}
@@ -0,0 +1,103 @@
+use super::*;
+use crate::format::solution::Solution;
+use crate::format_time;
+use crate::helpers::*;
+use std::fs::File;
+
+fn create_layout() -> WarehouseLayout {
+    WarehouseLayout { aisle_width: 3., aisle_length: 20., depot: WarehouseLocation { aisle: 0, position: 0. } }
+}
+
+fn create_line(aisle: usize, position: f64, quantity: i32) -> OrderLine {
+    OrderLine { location: WarehouseLocation { aisle, position }, quantity, duration: 10. }
+}
+
+fn create_batching(orders: Vec<(&str, Vec<OrderLine>)>) -> OrderBatching {
+    OrderBatching {
+        layout: create_layout(),
+        pickers: vec![Picker {
+            id: "picker".to_string(),
+            amount: 1,
+            capacity: 10,
+            shift_start: format_time(0.),
+            shift_end: format_time(1000.),
+        }],
+        orders: orders.into_iter().map(|(id, lines)| Order { id: id.to_string(), lines }).collect(),
+        walking_speed: 2.,
+        units: None,
+    }
+}
+
+parameterized_test! {can_get_walking_distance, (from, to, expected), {
+    can_get_walking_distance_impl(from, to, expected);
+}}
+
+can_get_walking_distance! {
+    case01_same_aisle: ((0, 5.), (0, 15.), 10.),
+    case02_front_cross_aisle: ((0, 5.), (2, 3.), 14.),
+    case03_back_cross_aisle: ((0, 15.), (2, 17.), 14.),
+    case04_clamp_position: ((1, -5.), (1, 25.), 20.),
+}
+
+fn can_get_walking_distance_impl(from: (usize, f64), to: (usize, f64), expected: f64) {
+    let from = WarehouseLocation { aisle: from.0, position: from.1 };
+    let to = WarehouseLocation { aisle: to.0, position: to.1 };
+
+    assert_eq!(get_walking_distance(&create_layout(), &from, &to), expected);
+    assert_eq!(get_walking_distance(&create_layout(), &to, &from), expected);
+}
+
+#[test]
+fn can_create_problem_with_shared_locations() {
+    let batching = create_batching(vec![
+        ("order1", vec![create_line(0, 5., 2), create_line(1, 12., 1)]),
+        ("order2", vec![create_line(0, 5., 3)]),
+    ]);
+
+    let (problem, matrix) = create_order_batching_problem(&batching).unwrap();
+
+    assert_eq!(problem.plan.jobs.len(), 2);
+    let locations = problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| job.pickups.iter().flatten())
+        .map(|task| (task.places[0].location.clone(), task.demand.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        locations,
+        vec![
+            (Location::Reference { index: 1 }, Some(vec![2])),
+            (Location::Reference { index: 2 }, Some(vec![1])),
+            (Location::Reference { index: 1 }, Some(vec![3])),
+        ]
+    );
+    assert_eq!(problem.fleet.vehicles[0].vehicle_ids, vec!["picker_1".to_string()]);
+    assert_eq!(problem.fleet.vehicles[0].capacity, vec![10]);
+    assert_eq!(matrix.distances, vec![0, 5, 15, 5, 0, 20, 15, 20, 0]);
+    assert_eq!(matrix.travel_times, vec![0, 3, 8, 3, 0, 10, 8, 10, 0]);
+}
+
+#[test]
+fn can_detect_invalid_walking_speed() {
+    let mut batching = create_batching(vec![("order1", vec![create_line(0, 5., 2)])]);
+    batching.walking_speed = 0.;
+
+    assert!(create_order_batching_problem(&batching).is_err());
+}
+
+#[test]
+fn can_solve_example_problem_keeping_orders_in_the_same_cart() {
+    let file = File::open("../examples/data/pragmatic/basics/order-batching.input.json").expect("cannot read file");
+    let batching = deserialize_order_batching(BufReader::new(file)).unwrap();
+    let (problem, matrix) = create_order_batching_problem(&batching).unwrap();
+
+    let solution: Solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    solution.tours.iter().for_each(|tour| {
+        let max_load = tour.stops.iter().map(|stop| stop.load()[0]).max().unwrap();
+        assert!(max_load <= 15);
+    });
+}
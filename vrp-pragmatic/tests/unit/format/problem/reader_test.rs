@@ -1,12 +1,14 @@
 use super::create_approx_matrices;
 use crate::constraints::JobSkills as ConstraintJobSkills;
 use crate::format::problem::*;
+use crate::format::CoordIndex;
 use crate::helpers::*;
 use hashbrown::HashSet;
 use std::iter::FromIterator;
 use std::sync::Arc;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Jobs, Multi, Place, Single};
+use vrp_core::models::problem::{Jobs, MatrixMemoryDimension, MatrixStorage, Multi, Place, Single};
+use vrp_core::solver::search::CoordinatesDimension;
 
 fn get_job(index: usize, jobs: &Jobs) -> vrp_core::models::problem::Job {
     jobs.all().collect::<Vec<_>>().get(index).unwrap().clone()
@@ -78,9 +80,12 @@ fn can_read_complex_problem() {
                             location: vec![52.48325, 13.4436].to_loc(),
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
+                            parking: None,
                         }],
                         demand: Some(vec![0, 1]),
                         order: None,
+                        skills: None,
+                        not_before: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique".to_string()])),
                     ..create_job("delivery_job")
@@ -95,9 +100,12 @@ fn can_read_complex_problem() {
                             location: vec![52.48300, 13.4420].to_loc(),
                             duration: 110.0,
                             tag: None,
+                            parking: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
+                        skills: None,
+                        not_before: None,
                     }]),
                     deliveries: Some(vec![JobTask {
                         places: vec![JobPlace {
@@ -108,9 +116,12 @@ fn can_read_complex_problem() {
                             location: vec![52.48325, 13.4436].to_loc(),
                             duration: 120.0,
                             tag: None,
+                            parking: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
+                        skills: None,
+                        not_before: None,
                     }]),
                     ..create_job("pickup_delivery_job")
                 },
@@ -124,9 +135,12 @@ fn can_read_complex_problem() {
                             location: vec![52.48321, 13.4438].to_loc(),
                             duration: 90.0,
                             tag: None,
+                            parking: None,
                         }],
                         demand: Some(vec![3]),
                         order: None,
+                        skills: None,
+                        not_before: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique2".to_string()])),
                     ..create_job("pickup_job")
@@ -139,17 +153,19 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: create_default_vehicle_profile(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2. },
+                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2., waiting: None, depot_waiting: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: None,
+                        optimize_departure: None,
                         location: vec![52.4862, 13.45148].to_loc(),
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "1970-01-01T00:01:40Z".to_string(),
                         location: vec![52.4862, 13.45148].to_loc(),
+                        alternatives: None,
                     }),
                     dispatch: None,
                     breaks: Some(vec![VehicleBreak::Optional {
@@ -163,8 +179,11 @@ fn can_read_complex_problem() {
                             tag: None,
                         }],
                         policy: None,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     reloads: None,
+                    loading: None,
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
@@ -172,12 +191,20 @@ fn can_read_complex_problem() {
                     max_distance: Some(123.1),
                     shift_time: Some(100.),
                     tour_size: Some(3),
+                    max_cost: None,
+                    max_stops: None,
                     areas: None,
+                    driver_hours: None,
+                    overtime: None,
                 }),
+                carry_load: None,
+                echelon: None,
+                service_time_factor: None,
             }],
             profiles: create_default_matrix_profiles(),
         },
         objectives: None,
+        units: None,
     };
     let matrix = Matrix {
         profile: Some("car".to_owned()),
@@ -185,6 +212,8 @@ fn can_read_complex_problem() {
         travel_times: vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
         distances: vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2],
         error_codes: Option::None,
+        units: None,
+        tolls: None,
     };
 
     let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
@@ -298,10 +327,34 @@ fn can_create_approximation_matrices() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                MatrixProfile { name: "car1".to_string(), speed: Some(8.) },
-                MatrixProfile { name: "car2".to_string(), speed: Some(10.) },
-                MatrixProfile { name: "car3".to_string(), speed: Some(5.) },
-                MatrixProfile { name: "car4".to_string(), speed: None },
+                MatrixProfile {
+                    name: "car1".to_string(),
+                    speed: Some(8.),
+                    detour_factor: None,
+                    time_of_day: None,
+                    leg_overrides: None,
+                },
+                MatrixProfile {
+                    name: "car2".to_string(),
+                    speed: Some(10.),
+                    detour_factor: None,
+                    time_of_day: None,
+                    leg_overrides: None,
+                },
+                MatrixProfile {
+                    name: "car3".to_string(),
+                    speed: Some(5.),
+                    detour_factor: None,
+                    time_of_day: None,
+                    leg_overrides: None,
+                },
+                MatrixProfile {
+                    name: "car4".to_string(),
+                    speed: None,
+                    detour_factor: None,
+                    time_of_day: None,
+                    leg_overrides: None,
+                },
             ],
         },
         ..create_empty_problem()
@@ -320,3 +373,130 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+#[test]
+fn can_read_problem_with_custom_objective_using_builder() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        objectives: Some(vec![
+            vec![Objective::Custom { name: "my-objective".to_string() }],
+            vec![Objective::MinimizeCost],
+        ]),
+        units: None,
+    };
+
+    let result = PragmaticProblemBuilder::default()
+        .with_custom_objective("my-objective", vrp_core::solver::objectives::TotalDistance::minimize(), None)
+        .build(problem.clone(), None);
+    assert!(result.is_ok());
+
+    let result = problem.read_pragmatic();
+    assert_eq!(result.err().map(|errors| errors.first().unwrap().code.clone()), Some("E0005".to_string()));
+}
+
+#[test]
+fn can_read_service_job_without_demand() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_service_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let service = get_single_job(0, problem.jobs.as_ref());
+    assert_eq!(service.dimens.get_id().unwrap(), "job1");
+    assert!(service.dimens.get_demand().map(|_: &Demand<SingleDimLoad>| ()).is_none());
+    let delivery = get_single_job(1, problem.jobs.as_ref());
+    assert!(delivery.dimens.get_demand().map(|_: &Demand<SingleDimLoad>| ()).is_some());
+}
+
+#[test]
+fn can_read_location_coordinates() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![52., 13.])], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let coord_index = CoordIndex::new(&problem);
+    let job_location = coord_index.get_by_loc(&vec![52., 13.].to_loc()).unwrap();
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let coordinates = problem.extras.get_location_coordinates().expect("no coordinates");
+    assert_eq!(coordinates.get(job_location), Some((13., 52.)));
+    assert_eq!(coordinates.get(100), None);
+}
+
+#[test]
+fn can_read_problem_with_geo_transport_using_builder() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![52.52599, 13.45413]),
+                create_delivery_job("job2", vec![52.5165, 13.3808]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: Some(5.),
+                detour_factor: Some(1.5),
+                time_of_day: None,
+                leg_overrides: None,
+            }],
+        },
+        ..create_empty_problem()
+    };
+
+    let with_matrices = problem.clone().read_pragmatic().unwrap();
+    let with_geo = PragmaticProblemBuilder::default().with_geo_transport().build(problem, None).unwrap();
+
+    let profile = Profile::default();
+    for (from, to) in &[(0, 1), (1, 0), (0, 2), (1, 2)] {
+        let distance = with_geo.transport.distance_approx(&profile, *from, *to);
+        let duration = with_geo.transport.duration_approx(&profile, *from, *to);
+
+        assert_eq!(distance, with_matrices.transport.distance_approx(&profile, *from, *to));
+        assert_eq!(duration, with_matrices.transport.duration_approx(&profile, *from, *to));
+    }
+    assert_eq!(with_geo.transport.distance_approx(&profile, 0, 1), 7617.);
+    assert_eq!(with_geo.transport.duration_approx(&profile, 0, 1), 1523.);
+}
+
+#[test]
+fn can_read_problem_with_matrix_storage_using_builder() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], ..create_empty_plan() },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_matrix_profiles() },
+        ..create_empty_problem()
+    };
+    let matrix = Matrix {
+        profile: Some("car".to_string()),
+        timestamp: None,
+        travel_times: vec![0, 123457, 3, 0],
+        distances: vec![0, 4, 5, 0],
+        error_codes: Some(vec![0, 0, 1, 0]),
+        units: None,
+        tolls: None,
+    };
+
+    let problem = PragmaticProblemBuilder::default()
+        .with_matrix_storage(MatrixStorage::Quantized { scale: 0.01 })
+        .build(problem, Some(vec![matrix]))
+        .unwrap();
+
+    let profile = Profile::default();
+    assert_eq!(problem.transport.duration_approx(&profile, 0, 1), 123500.);
+    assert_eq!(problem.transport.distance_approx(&profile, 0, 1), 0.);
+    assert_eq!(problem.transport.duration_approx(&profile, 1, 0), -1.);
+    assert_eq!(problem.extras.get_matrix_memory(), Some(32));
+}
@@ -0,0 +1,96 @@
+use super::*;
+use crate::format::solution::Tour;
+use crate::helpers::*;
+
+fn resolve_area(location: &Location) -> Option<String> {
+    let (lat, _) = location.to_lat_lng();
+    Some(if lat < 2. { "north".to_string() } else { "south".to_string() })
+}
+
+fn create_tour(vehicle_id: &str, jobs: Vec<(&str, f64)>) -> Tour {
+    let stops = jobs
+        .into_iter()
+        .map(|(job_id, lat)| {
+            create_stop_with_activity(
+                job_id,
+                "delivery",
+                (lat, 0.),
+                0,
+                ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                0,
+            )
+        })
+        .collect();
+
+    Tour { vehicle_id: vehicle_id.to_string(), type_id: "my_vehicle".to_string(), stops, ..create_empty_tour() }
+}
+
+fn create_history() -> Vec<Solution> {
+    vec![
+        Solution {
+            tours: vec![
+                create_tour("my_vehicle_1", vec![("job1", 1.), ("job2", 1.), ("job3", 3.)]),
+                create_tour("my_vehicle_2", vec![("job4", 3.)]),
+            ],
+            ..create_empty_solution()
+        },
+        Solution { tours: vec![create_tour("my_vehicle_1", vec![("job5", 1.)])], ..create_empty_solution() },
+    ]
+}
+
+#[test]
+fn can_learn_territory_affinity() {
+    let affinity = learn_territory_affinity(&create_history(), &resolve_area);
+
+    let vehicle1 = affinity.vehicles.get("my_vehicle_1").unwrap();
+    assert_eq!(vehicle1.get("north"), Some(&0.75));
+    assert_eq!(vehicle1.get("south"), Some(&0.25));
+    let vehicle2 = affinity.vehicles.get("my_vehicle_2").unwrap();
+    assert_eq!(vehicle2.get("north"), None);
+    assert_eq!(vehicle2.get("south"), Some(&1.));
+}
+
+#[test]
+fn can_apply_territory_affinity() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![3., 0.])],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("vehicle1"), create_default_vehicle("vehicle2")],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let affinity = TerritoryAffinity {
+        vehicles: vec![
+            ("vehicle1_1".to_string(), vec![("north".to_string(), 0.75), ("south".to_string(), 0.25)]),
+            ("vehicle2_1".to_string(), vec![("south".to_string(), 1.)]),
+        ]
+        .into_iter()
+        .map(|(vehicle_id, scores)| (vehicle_id, scores.into_iter().collect()))
+        .collect(),
+    };
+
+    let problem = apply_territory_affinity(&problem, &affinity, &resolve_area, 100.);
+
+    let areas = problem.plan.areas.as_ref().unwrap();
+    assert_eq!(
+        areas.iter().map(|area| (area.id.as_str(), area.jobs.clone())).collect::<Vec<_>>(),
+        vec![("north", vec!["job1".to_string()]), ("south", vec!["job2".to_string()])]
+    );
+    let get_limits = |idx: usize| {
+        problem.fleet.vehicles[idx].limits.as_ref().unwrap().areas.as_ref().unwrap()[0]
+            .iter()
+            .map(|limit| (limit.area_id.clone(), limit.job_value))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(get_limits(0), vec![("north".to_string(), 75.), ("south".to_string(), 25.)]);
+    assert_eq!(get_limits(1), vec![("south".to_string(), 100.)]);
+    let objectives = problem.objectives.as_ref().unwrap();
+    assert_eq!(objectives.len(), 4);
+    assert!(matches!(objectives[2][0], Objective::AreaOrder { is_constrained: false, .. }));
+
+    assert!(problem.read_pragmatic().is_ok());
+}
@@ -0,0 +1,127 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_planned_solution() -> Solution {
+    Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    2,
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:10Z", "1970-01-01T00:00:20Z"),
+                    10,
+                ),
+                create_stop_with_activity(
+                    "job2",
+                    "delivery",
+                    (2., 0.),
+                    0,
+                    ("1970-01-01T00:00:30Z", "1970-01-01T00:00:40Z"),
+                    20,
+                ),
+                create_stop_with_activity(
+                    "arrival",
+                    "arrival",
+                    (0., 0.),
+                    0,
+                    ("1970-01-01T00:01:00Z", "1970-01-01T00:01:00Z"),
+                    40,
+                ),
+            ],
+            statistic: Default::default(),
+            departure: None,
+        }],
+        ..create_empty_solution()
+    }
+}
+
+fn create_actual_activity(job_id: &str, activity_type: &str, time: Option<(f64, f64)>) -> ActualActivity {
+    ActualActivity {
+        job_id: job_id.to_string(),
+        activity_type: activity_type.to_string(),
+        time: time
+            .map(|(arrival, departure)| Schedule { arrival: format_time(arrival), departure: format_time(departure) }),
+    }
+}
+
+fn create_execution_report() -> ExecutionReport {
+    ExecutionReport {
+        tours: vec![ActualTour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            activities: vec![
+                create_actual_activity("departure", "departure", Some((0., 0.))),
+                create_actual_activity("job1", "delivery", Some((20., 35.))),
+                create_actual_activity("job2", "delivery", None),
+            ],
+        }],
+    }
+}
+
+#[test]
+fn can_calculate_execution_deviation() {
+    let deviation = get_execution_deviation(&create_planned_solution(), &create_execution_report()).unwrap();
+
+    assert_eq!(deviation.skipped, 1);
+    assert_eq!(deviation.max_arrival_delay, 10.);
+    assert_eq!(deviation.mean_arrival_delay, 5.);
+    assert_eq!(deviation.tours.len(), 1);
+
+    let tour = deviation.tours.first().unwrap();
+    assert_eq!(tour.skipped, vec!["job2".to_string()]);
+    assert_eq!(tour.travel_ratio, Some(2.));
+    let job1 = tour.activities.iter().find(|a| a.job_id == "job1").unwrap();
+    assert_eq!(job1.arrival_delay, 10.);
+    assert_eq!(job1.service_ratio, Some(1.5));
+}
+
+parameterized_test! {can_create_adjusted_problem, (weight, expected_duration, expected_scale), {
+    can_create_adjusted_problem_impl(weight, expected_duration, expected_scale);
+}}
+
+can_create_adjusted_problem! {
+    case01_keep_original: (0., 10., 1.),
+    case02_use_observed: (1., 15., 2.),
+    case03_use_average: (0.5, 13., 1.5),
+}
+
+fn can_create_adjusted_problem_impl(weight: f64, expected_duration: f64, expected_scale: f64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_duration("job1", vec![1., 0.], 10.),
+                create_delivery_job_with_duration("job2", vec![2., 0.], 10.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let deviation = get_execution_deviation(&create_planned_solution(), &create_execution_report()).unwrap();
+
+    let adjusted = create_adjusted_problem(&problem, &deviation, weight);
+
+    let get_duration = |idx: usize| adjusted.plan.jobs[idx].deliveries.as_ref().unwrap()[0].places[0].duration;
+    assert_eq!(get_duration(0), expected_duration);
+    assert_eq!(get_duration(1), 10.);
+    assert_eq!(adjusted.fleet.vehicles.first().unwrap().profile.scale, Some(expected_scale));
+}
@@ -69,6 +69,7 @@ fn can_create_geo_json_for_cluster_geometry() {
         distance: 0,
         load: vec![],
         parking: None,
+        service: None,
         activities: vec![
             Activity {
                 job_id: "job1".to_string(),
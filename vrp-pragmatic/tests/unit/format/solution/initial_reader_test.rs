@@ -38,6 +38,8 @@ fn create_default_breaks() -> Option<Vec<VehicleBreak>> {
         time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(5.), format_time(10.)]),
         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
         policy: None,
+        min_shift_duration: None,
+        min_spacing: None,
     }])
 }
 
@@ -50,6 +52,7 @@ fn create_unassigned_jobs(job_ids: &[&str]) -> Option<Vec<UnassignedJob>> {
                 reasons: vec![UnassignedJobReason {
                     code: "NO_REASON_FOUND".to_string(),
                     description: "unknown".to_string(),
+                    details: None,
                 }],
             })
             .collect(),
@@ -85,6 +88,7 @@ fn can_read_basic_init_solution() {
             distance: 8,
             duration: 14,
             times: Timing { driving: 8, serving: 4, break_time: 2, ..Timing::default() },
+            toll: None,
         },
         tours: vec![Tour {
             vehicle_id: "my_vehicle_1".to_string(),
@@ -125,6 +129,7 @@ fn can_read_basic_init_solution() {
                     distance: 3,
                     parking: None,
                     load: vec![2],
+                    service: None,
                     activities: vec![
                         Activity {
                             job_id: "job2".to_string(),
@@ -173,7 +178,9 @@ fn can_read_basic_init_solution() {
                 distance: 8,
                 duration: 14,
                 times: Timing { driving: 8, serving: 4, break_time: 2, ..Timing::default() },
+                toll: None,
             },
+            departure: None,
         }],
         unassigned: create_unassigned_jobs(&["job3"]),
         ..create_empty_solution()
@@ -227,6 +234,7 @@ fn can_handle_commute_error_in_init_solution() {
                     distance: 1,
                     parking: None,
                     load: vec![0],
+                    service: None,
                     activities: vec![Activity {
                         job_id: "job1".to_string(),
                         activity_type: "delivery".to_string(),
@@ -0,0 +1,62 @@
+use super::*;
+use crate::format::problem::Problem as FormatProblem;
+use crate::format::problem::*;
+use crate::helpers::*;
+use vrp_core::models::Problem as CoreProblem;
+
+fn create_test_problem_and_solution() -> (CoreProblem, Solution) {
+    let problem = FormatProblem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = (problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap();
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    (core_problem, solution)
+}
+
+fn write_to_string(write: impl FnOnce(BufWriter<&mut Vec<u8>>) -> Result<(), Error>) -> String {
+    let mut buffer = Vec::new();
+    write(BufWriter::new(&mut buffer)).unwrap();
+
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn can_serialize_solution_as_gpx() {
+    let (problem, solution) = create_test_problem_and_solution();
+
+    let gpx = write_to_string(|writer| serialize_solution_as_gpx(writer, &problem, &solution));
+
+    assert!(gpx.starts_with("<?xml"));
+    assert_eq!(gpx.matches("<trk>").count(), 1);
+    assert_eq!(gpx.matches("<trkpt ").count(), 4);
+    assert!(gpx.contains("<trkpt lat=\"1\" lon=\"0\">"));
+    assert!(gpx.contains("<time>1970-01-01T00:00:00Z</time>"));
+    assert!(gpx.contains("<name>my_vehicle_1 (shift 0)</name>"));
+}
+
+#[test]
+fn can_serialize_solution_as_kml() {
+    let (problem, solution) = create_test_problem_and_solution();
+
+    let kml = write_to_string(|writer| serialize_solution_as_kml(writer, &problem, &solution));
+
+    assert_eq!(kml.matches("<Folder>").count(), 1);
+    assert_eq!(kml.matches("<Placemark>").count(), 5);
+    assert_eq!(kml.matches("<LineString>").count(), 1);
+    assert!(kml.contains("<TimeSpan><begin>1970-01-01T00:00:00Z</begin>"));
+}
+
+#[test]
+fn can_escape_special_characters() {
+    assert_eq!(escape("a<b>&\"c'"), "a&lt;b&gt;&amp;&quot;c&apos;");
+}
@@ -0,0 +1,89 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", vec![1., 0.], vec![2]),
+                create_delivery_job_with_times("job2", vec![20., 0.], vec![(0, 10)], 1.),
+                create_delivery_job_with_duration("job3", vec![8., 0.], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_capacity("small", vec![1]),
+                VehicleType {
+                    shifts: vec![VehicleShift {
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(10.),
+                            location: vec![0., 0.].to_loc(),
+                            alternatives: None,
+                        }),
+                        ..create_default_vehicle_shift()
+                    }],
+                    ..create_default_vehicle("slow")
+                },
+            ],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn create_reason(code: &str, vehicle_id: &str, suggestion: Option<&str>) -> (String, String, Option<String>) {
+    (code.to_string(), vehicle_id.to_string(), suggestion.map(|suggestion| suggestion.to_string()))
+}
+
+#[test]
+fn can_analyze_unassigned_jobs_per_vehicle() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read core problem"));
+    let core_solution = to_core_solution(&create_empty_solution(), core_problem.clone(), create_random())
+        .expect("cannot create core solution");
+
+    let unassigned = analyze_unassigned(core_problem, core_solution, Arc::new(Environment::default()));
+
+    let unassigned = unassigned
+        .into_iter()
+        .map(|job| {
+            let reasons = job
+                .reasons
+                .into_iter()
+                .map(|reason| {
+                    let details = reason.details.expect("details are expected");
+                    assert_eq!(details.shift_index, 0);
+                    (reason.code, details.vehicle_id, details.suggestion)
+                })
+                .collect::<Vec<_>>();
+
+            (job.job_id, reasons)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        unassigned,
+        vec![
+            (
+                "job1".to_string(),
+                vec![create_reason("CAPACITY_CONSTRAINT", "small_1", Some("increase vehicle capacity by [1]"))]
+            ),
+            (
+                "job2".to_string(),
+                vec![
+                    create_reason("TIME_WINDOW_CONSTRAINT", "slow_1", Some("extend job time window end by 10s")),
+                    create_reason("TIME_WINDOW_CONSTRAINT", "small_1", Some("extend job time window end by 10s")),
+                ]
+            ),
+            (
+                "job3".to_string(),
+                vec![create_reason("TIME_WINDOW_CONSTRAINT", "slow_1", Some("extend vehicle shift end by 7s"))]
+            ),
+        ]
+    );
+}
@@ -4,9 +4,13 @@ use crate::format::solution::*;
 use crate::helpers::*;
 use std::cmp::Ordering;
 use std::sync::Arc;
-use vrp_core::models::common::{TimeSpan, TimeWindow};
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::common::{MultiDimLoad, TimeSpan, TimeWindow};
 use vrp_core::models::examples::create_example_problem;
-use vrp_core::utils::{as_mut, compare_floats};
+use vrp_core::models::problem::ReservedTimeSpan;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{create_elitism_population, RefinementContext};
+use vrp_core::utils::{as_mut, compare_floats, Environment};
 
 type DomainProblem = vrp_core::models::Problem;
 type DomainActivity = vrp_core::models::solution::Activity;
@@ -55,6 +59,7 @@ fn can_create_solution() {
                 distance: 20,
                 duration: 22,
                 times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                toll: None,
             },
             tours: vec![Tour {
                 vehicle_id: "my_vehicle_1".to_string(),
@@ -99,7 +104,9 @@ fn can_create_solution() {
                     distance: 20,
                     duration: 22,
                     times: Timing { driving: 20, serving: 2, ..Timing::default() },
+                    toll: None,
                 },
+                departure: None,
             }],
             ..create_empty_solution()
         }
@@ -130,11 +137,21 @@ fn can_merge_activities_with_same_location_in_one_stop() {
             distance: 10,
             duration: 12,
             times: Timing { driving: 10, serving: 2, ..Timing::default() },
+            toll: None,
         }
     );
     assert_eq!(solution.tours.len(), 1);
     assert_eq!(solution.tours.first().unwrap().stops.len(), 3);
     assert_eq!(solution.tours.first().unwrap().stops.get(1).unwrap().activities().len(), 2);
+    assert_eq!(
+        solution.tours.first().unwrap().stops.get(1).unwrap().as_point().unwrap().service,
+        Some(StopService {
+            start: "1970-01-01T00:00:05Z".to_string(),
+            end: "1970-01-01T00:00:07Z".to_string(),
+            duration: 2,
+        })
+    );
+    assert!(solution.tours.first().unwrap().stops.get(2).unwrap().as_point().unwrap().service.is_none());
 }
 
 parameterized_test! {can_merge_activities_with_commute_in_one_stop, (jobs_data, expected), {
@@ -168,14 +185,19 @@ fn can_merge_activities_with_commute_in_one_stop_impl(
     expected: Vec<(usize, Vec<(Option<usize>, Option<(f64, f64)>)>)>,
 ) {
     let (problem, mut coord_index) = create_test_problem_and_coord_index();
+    // NOTE commute starts and ends at the location of the last activity without commute (cluster center)
+    let mut center = 0;
     let activities = jobs_data
         .into_iter()
         .map(|(index, commute)| {
             coord_index.add(&Location::Reference { index });
             let arrival = index as f64;
+            if commute.map_or(true, |(f, b)| f == 0. && b == 0.) {
+                center = index;
+            }
             let commute = commute.map(|(f, b)| DomainCommute {
-                forward: DomainCommuteInfo { location: 0, distance: f, duration: f },
-                backward: DomainCommuteInfo { location: 0, distance: b, duration: b },
+                forward: DomainCommuteInfo { location: center, distance: f, duration: f },
+                backward: DomainCommuteInfo { location: center, distance: b, duration: b },
             });
             let departure = arrival + commute.as_ref().map(|c| c.forward.duration + c.backward.duration).unwrap_or(0.);
             DomainActivity {
@@ -187,7 +209,14 @@ fn can_merge_activities_with_commute_in_one_stop_impl(
         .collect();
     let route = create_route_with_activities(&problem.fleet, "v1", activities);
 
-    let tour = create_tour(&problem, &route, &coord_index, &Default::default());
+    let (tour, _) = create_tour(
+        &problem,
+        &route,
+        MultiDimLoad::default(),
+        &coord_index,
+        &Default::default(),
+        RoundingMode::default(),
+    );
 
     assert_eq!(expected.len(), tour.stops.len() - 2);
     expected.iter().zip(tour.stops.iter().skip(1)).for_each(|((expected_stop_idx, expected_acts), actual_stop)| {
@@ -229,10 +258,131 @@ fn can_merge_required_break_on_stop_arrival_time_properly() {
     let mut route = create_route_with_activities(&problem.fleet, "v1", activities);
     route.tour.all_activities_mut().last().unwrap().schedule.arrival = 6.;
     let reserved_times_index =
-        vec![(route.actor.clone(), vec![TimeSpan::Window(TimeWindow::new(4., 5.))])].into_iter().collect();
+        vec![(route.actor.clone(), vec![ReservedTimeSpan::new(TimeSpan::Window(TimeWindow::new(4., 5.)))])]
+            .into_iter()
+            .collect();
 
-    let tour = create_tour(&problem, &route, &coord_index, &reserved_times_index);
+    let (tour, _) = create_tour(
+        &problem,
+        &route,
+        MultiDimLoad::default(),
+        &coord_index,
+        &reserved_times_index,
+        RoundingMode::default(),
+    );
 
     assert_eq!(tour.stops.len(), 3);
     assert_eq!(get_ids_from_tour(&tour).into_iter().flatten().filter(|id| id == "break").count(), 1);
 }
+
+parameterized_test! {can_apply_rounding_mode_to_tour_statistic, (rounding, expected), {
+    can_apply_rounding_mode_to_tour_statistic_impl(rounding, expected);
+}}
+
+can_apply_rounding_mode_to_tour_statistic! {
+    case01_truncate: (RoundingMode::Truncate, 7),
+    case02_round: (RoundingMode::Round, 8),
+    case03_ceil: (RoundingMode::Ceil, 8),
+    case04_floor: (RoundingMode::Floor, 7),
+}
+
+fn can_apply_rounding_mode_to_tour_statistic_impl(rounding: RoundingMode, expected: i64) {
+    let (problem, mut coord_index) = create_test_problem_and_coord_index();
+    coord_index.add(&Location::Reference { index: 1 });
+    let activities = vec![DomainActivity {
+        schedule: DomainSchedule { arrival: 4.6, departure: 5.6 },
+        ..create_activity_with_job_at_location(create_single("job1"), 1)
+    }];
+    let mut route = create_route_with_activities(&problem.fleet, "v1", activities);
+    let end = route.tour.all_activities_mut().last().unwrap();
+    end.schedule.arrival = 7.7;
+    end.schedule.departure = 7.7;
+
+    let (tour, statistic) =
+        create_tour(&problem, &route, MultiDimLoad::default(), &coord_index, &Default::default(), rounding);
+
+    assert_eq!(tour.statistic.duration, expected);
+    assert!((statistic.duration - 7.7).abs() < 1E-6);
+}
+
+#[test]
+fn can_report_precise_statistic_in_extras() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", vec![5., 0.])], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let environment = Arc::new(Environment::default());
+    let core_solution = RecreateWithCheapest::new(environment.random.clone())
+        .run(
+            &RefinementContext::new(
+                core_problem.clone(),
+                create_elitism_population(core_problem.objective.clone(), environment.clone()),
+                environment.clone(),
+            ),
+            InsertionContext::new(core_problem.clone(), environment),
+        )
+        .solution
+        .to_solution(core_problem.extras.clone());
+    let settings = SolutionWriterSettings { precise_statistic: true, ..SolutionWriterSettings::default() };
+
+    let default = create_solution(&core_problem, &core_solution, None);
+    let precise = create_solution_with_settings(&core_problem, &core_solution, None, &settings);
+
+    assert!(default.extras.is_none());
+    let extras = precise.extras.expect("no extras");
+    assert!(extras.metrics.is_none());
+    assert_eq!(extras.tour_statistics.map(|statistics| statistics.len()), Some(1));
+    let statistic = extras.statistic.expect("no precise statistic");
+    assert_eq!(statistic.distance, 10.);
+    assert_eq!(statistic.duration, 11.);
+    assert_eq!(precise.statistic, default.statistic);
+}
+
+#[test]
+fn can_report_tour_compactness_in_extras() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_delivery_job("job2", vec![3., 0.]),
+                create_delivery_job("job3", vec![6., 0.]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_matrix_profiles(),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let environment = Arc::new(Environment::default());
+    let core_solution = RecreateWithCheapest::new(environment.random.clone())
+        .run(
+            &RefinementContext::new(
+                core_problem.clone(),
+                create_elitism_population(core_problem.objective.clone(), environment.clone()),
+                environment.clone(),
+            ),
+            InsertionContext::new(core_problem.clone(), environment),
+        )
+        .solution
+        .to_solution(core_problem.extras.clone());
+    let settings = SolutionWriterSettings { compactness: true, ..SolutionWriterSettings::default() };
+
+    let solution = create_solution_with_settings(&core_problem, &core_solution, None, &settings);
+
+    let extras = solution.extras.expect("no extras");
+    assert!(extras.tour_statistics.is_none());
+    let compactness = extras.tour_compactness.expect("no tour compactness");
+    assert_eq!(compactness.len(), 1);
+    assert!((compactness[0].average_distance - 7. / 3.).abs() < 1E-6);
+    assert_eq!(compactness[0].max_distance, 3.);
+}
@@ -0,0 +1,77 @@
+use super::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+#[derive(Serialize)]
+struct TestLayout<'a> {
+    name: &'a str,
+    items: Vec<()>,
+    tail: Option<Vec<i32>>,
+}
+
+#[derive(Serialize)]
+struct TestValue<'a> {
+    name: &'a str,
+    items: &'a [Vec<i32>],
+    tail: Option<Vec<i32>>,
+}
+
+fn serialize_to_string<L: Serialize, T: Serialize + Send + Sync>(layout: &L, field: &str, items: &[T]) -> String {
+    let mut buffer = Vec::new();
+    serialize_pretty_with_parallel_array(BufWriter::new(&mut buffer), layout, field, items).expect("cannot serialize");
+
+    String::from_utf8(buffer).expect("invalid utf8")
+}
+
+parameterized_test! {can_serialize_array_items_in_parallel, items, {
+    can_serialize_array_items_in_parallel_impl(items);
+}}
+
+can_serialize_array_items_in_parallel! {
+    case_01_empty: vec![],
+    case_02_single: vec![vec![1]],
+    case_03_many: vec![vec![1, 2], vec![], vec![3]],
+}
+
+fn can_serialize_array_items_in_parallel_impl(items: Vec<Vec<i32>>) {
+    let tail = Some(vec![4, 5]);
+    let layout = TestLayout { name: "test", items: vec![], tail: tail.clone() };
+    let value = TestValue { name: "test", items: items.as_slice(), tail };
+
+    let result = serialize_to_string(&layout, "items", items.as_slice());
+
+    assert_eq!(result, serde_json::to_string_pretty(&value).unwrap());
+}
+
+#[test]
+fn can_return_error_when_field_is_not_in_layout() {
+    let layout = TestLayout { name: "test", items: vec![], tail: None };
+
+    let result = serialize_pretty_with_parallel_array(BufWriter::new(Vec::new()), &layout, "unknown", &[1, 2]);
+
+    assert_eq!(result.map_err(|err| err.kind()), Err(ErrorKind::InvalidData));
+}
+
+#[test]
+fn can_serialize_solution_same_as_sequential() {
+    let solution = Solution {
+        tours: vec![
+            Tour { vehicle_id: "v1".to_string(), ..create_empty_tour() },
+            Tour { vehicle_id: "v2".to_string(), ..create_empty_tour() },
+        ],
+        unassigned: Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "NO_REASON_FOUND".to_string(),
+                description: "unknown".to_string(),
+                details: None,
+            }],
+        }]),
+        ..create_empty_solution()
+    };
+    let mut buffer = Vec::new();
+
+    serialize_solution(BufWriter::new(&mut buffer), &solution).expect("cannot serialize solution");
+
+    assert_eq!(String::from_utf8(buffer).unwrap(), serde_json::to_string_pretty(&solution).unwrap());
+}
@@ -17,6 +17,8 @@ can_detect_reserved_ids! {
     case04: ("break", Some("break")),
     case05: ("reload", Some("reload")),
     case06: ("dispatch", Some("dispatch")),
+    case07: ("rest", None),
+    case08: ("vrp:rest", Some("vrp:rest")),
 }
 
 fn can_detect_reserved_ids_impl(job_id: String, expected: Option<&str>) {
@@ -74,3 +76,196 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+#[test]
+fn can_detect_negative_buffer() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { buffer: Some(-10.), ..create_delivery_job("job1", vec![1., 0.]) }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1108_negative_buffer(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1108", "job1", result);
+}
+
+fn with_parking(job: Job, duration: f64, distance: f64) -> Job {
+    let update_tasks = |tasks: Option<Vec<JobTask>>| {
+        tasks.map(|tasks| {
+            tasks
+                .into_iter()
+                .map(|task| JobTask {
+                    places: task
+                        .places
+                        .into_iter()
+                        .map(|place| JobPlace {
+                            parking: Some(JobParking { location: vec![0., 0.].to_loc(), duration, distance }),
+                            ..place
+                        })
+                        .collect(),
+                    ..task
+                })
+                .collect()
+        })
+    };
+
+    Job { pickups: update_tasks(job.pickups), deliveries: update_tasks(job.deliveries), ..job }
+}
+
+parameterized_test! {can_detect_invalid_parking, (job, expected), {
+    can_detect_invalid_parking_impl(job, expected);
+}}
+
+can_detect_invalid_parking! {
+    case01_valid: (with_parking(create_delivery_job("job1", vec![1., 0.]), 10., 10.), None),
+    case02_negative_duration: (with_parking(create_delivery_job("job1", vec![1., 0.]), -1., 10.), Some("E1109")),
+    case03_zero_distance: (with_parking(create_delivery_job("job1", vec![1., 0.]), 10., 0.), Some("E1109")),
+    case04_multi_job: (with_parking(create_pickup_delivery_job("job1", vec![1., 0.], vec![2., 0.]), 10., 10.), Some("E1109")),
+    case05_no_parking: (create_pickup_delivery_job("job1", vec![1., 0.], vec![2., 0.]), None),
+}
+
+fn can_detect_invalid_parking_impl(job: Job, expected: Option<&str>) {
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result = check_e1109_invalid_parking(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+        .err()
+        .map(|err| err.code);
+
+    assert_eq!(result, expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_parking_with_relations, (job, relation_job_id, expected), {
+    can_detect_parking_with_relations_impl(job, relation_job_id, expected);
+}}
+
+can_detect_parking_with_relations! {
+    case01_no_relation: (with_parking(create_delivery_job("job1", vec![1., 0.]), 10., 10.), None, None),
+    case02_in_relation: (with_parking(create_delivery_job("job1", vec![1., 0.]), 10., 10.), Some("job1"), Some("E1113")),
+    case03_no_parking: (create_delivery_job("job1", vec![1., 0.]), Some("job1"), None),
+}
+
+fn can_detect_parking_with_relations_impl(job: Job, relation_job_id: Option<&str>, expected: Option<&str>) {
+    let relations = relation_job_id.map(|job_id| {
+        vec![Relation {
+            type_field: RelationType::Any,
+            jobs: vec![job_id.to_string()],
+            vehicle_id: "vehicle_1".to_string(),
+            shift_index: None,
+        }]
+    });
+    let problem =
+        Problem { plan: Plan { jobs: vec![job], relations, ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1113_parking_with_relations(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+            .err()
+            .map(|err| err.code);
+
+    assert_eq!(result, expected.map(|code| code.to_string()));
+}
+
+fn with_sync(job: Job, max_gap: Option<f64>) -> Job {
+    Job { sync: Some(JobSync { max_gap }), ..job }
+}
+
+parameterized_test! {can_detect_invalid_sync, (job, relation_job_id, expected), {
+    can_detect_invalid_sync_impl(job, relation_job_id, expected);
+}}
+
+can_detect_invalid_sync! {
+    case01_valid: (create_sync_job("job1", vec![(vec![1., 0.], 1., vec![], "a"), (vec![1., 0.], 1., vec![], "b")], Some(10.)), None, None),
+    case02_single_visit: (create_sync_job("job1", vec![(vec![1., 0.], 1., vec![], "a")], None), None, Some("E1110")),
+    case03_not_service: (with_sync(create_pickup_delivery_job("job1", vec![1., 0.], vec![2., 0.]), None), None, Some("E1110")),
+    case04_negative_gap: (create_sync_job("job1", vec![(vec![1., 0.], 1., vec![], "a"), (vec![1., 0.], 1., vec![], "b")], Some(-1.)), None, Some("E1110")),
+    case05_in_relation: (create_sync_job("job1", vec![(vec![1., 0.], 1., vec![], "a"), (vec![1., 0.], 1., vec![], "b")], None), Some("job1"), Some("E1110")),
+    case06_no_sync: (create_delivery_job("job1", vec![1., 0.]), Some("job1"), None),
+}
+
+fn can_detect_invalid_sync_impl(job: Job, relation_job_id: Option<&str>, expected: Option<&str>) {
+    let relations = relation_job_id.map(|job_id| {
+        vec![Relation {
+            type_field: RelationType::Any,
+            jobs: vec![job_id.to_string()],
+            vehicle_id: "vehicle_1".to_string(),
+            shift_index: None,
+        }]
+    });
+    let problem =
+        Problem { plan: Plan { jobs: vec![job], relations, ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result = check_e1110_invalid_sync(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+        .err()
+        .map(|err| err.code);
+
+    assert_eq!(result, expected.map(|code| code.to_string()));
+}
+
+fn with_not_before(job: Job, not_before: &str) -> Job {
+    let deliveries = job.deliveries.map(|tasks| {
+        tasks.into_iter().map(|task| JobTask { not_before: Some(not_before.to_string()), ..task }).collect()
+    });
+
+    Job { deliveries, ..job }
+}
+
+parameterized_test! {can_detect_invalid_not_before, (job, expected), {
+    can_detect_invalid_not_before_impl(job, expected);
+}}
+
+can_detect_invalid_not_before! {
+    case01_no_times: (with_not_before(create_delivery_job("job1", vec![1., 0.]), "1970-01-01T00:00:10Z"), None),
+    case02_within_time: (with_not_before(create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 20)], 1.), "1970-01-01T00:00:10Z"), None),
+    case03_after_time: (with_not_before(create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 5)], 1.), "1970-01-01T00:00:10Z"), Some("E1111")),
+    case04_invalid_format: (with_not_before(create_delivery_job("job1", vec![1., 0.]), "10:00"), Some("E1111")),
+    case05_no_not_before: (create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 5)], 1.), None),
+}
+
+fn can_detect_invalid_not_before_impl(job: Job, expected: Option<&str>) {
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result = check_e1111_invalid_not_before(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+        .err()
+        .map(|err| err.code);
+
+    assert_eq!(result, expected.map(|code| code.to_string()));
+}
+
+fn with_transfer(job: Job, modify: fn(JobTransfer) -> JobTransfer) -> Job {
+    Job { transfer: job.transfer.map(modify), ..job }
+}
+
+parameterized_test! {can_detect_invalid_transfer, (job, relation_job_id, expected), {
+    can_detect_invalid_transfer_impl(job, relation_job_id, expected);
+}}
+
+can_detect_invalid_transfer! {
+    case01_valid: (create_transfer_job("job1", vec![1., 0.], vec![3., 0.], vec![2., 0.], None), None, None),
+    case02_no_delivery: (Job { deliveries: None, ..create_transfer_job("job1", vec![1., 0.], vec![3., 0.], vec![2., 0.], None) }, None, Some("E1112")),
+    case03_negative_gap: (create_transfer_job("job1", vec![1., 0.], vec![3., 0.], vec![2., 0.], Some(-1.)), None, Some("E1112")),
+    case04_negative_duration: (with_transfer(create_transfer_job("job1", vec![1., 0.], vec![3., 0.], vec![2., 0.], None), |transfer| JobTransfer { duration: -1., ..transfer }), None, Some("E1112")),
+    case05_in_relation: (create_transfer_job("job1", vec![1., 0.], vec![3., 0.], vec![2., 0.], None), Some("job1"), Some("E1112")),
+    case06_with_sync: (Job { sync: Some(JobSync { max_gap: None }), ..create_transfer_job("job1", vec![1., 0.], vec![3., 0.], vec![2., 0.], None) }, None, Some("E1112")),
+    case07_no_transfer: (create_pickup_delivery_job("job1", vec![1., 0.], vec![3., 0.]), Some("job1"), None),
+}
+
+fn can_detect_invalid_transfer_impl(job: Job, relation_job_id: Option<&str>, expected: Option<&str>) {
+    let relations = relation_job_id.map(|job_id| {
+        vec![Relation {
+            type_field: RelationType::Any,
+            jobs: vec![job_id.to_string()],
+            vehicle_id: "vehicle_1".to_string(),
+            shift_index: None,
+        }]
+    });
+    let problem =
+        Problem { plan: Plan { jobs: vec![job], relations, ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result = check_e1112_invalid_transfer(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)))
+        .err()
+        .map(|err| err.code);
+
+    assert_eq!(result, expected.map(|code| code.to_string()));
+}
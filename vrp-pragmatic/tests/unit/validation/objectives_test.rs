@@ -251,3 +251,26 @@ fn can_detect_missing_area_objective() {
 
     assert_eq!(result.err().unwrap().code, "E1608".to_string());
 }
+
+parameterized_test! {can_detect_invalid_target_stops, (target, expected), {
+    can_detect_invalid_target_stops_impl(target, expected);
+}}
+
+can_detect_invalid_target_stops! {
+    case01: (0, Some("E1609".to_string())),
+    case02: (1, None),
+}
+
+fn can_detect_invalid_target_stops_impl(target: usize, expected: Option<String>) {
+    let problem = Problem {
+        objectives: Some(vec![vec![TargetStops { target, tolerance: None }], vec![MinimizeCost]]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1609_invalid_target_stops(&objectives);
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
@@ -33,6 +33,7 @@ can_detect_relation_errors! {
     case08: (vec!["departure", "job2", "break"], "vehicle_1", None, Some(("E1206", "break"))),
     case09: (vec!["departure", "job2", "dispatch"], "vehicle_1", None, Some(("E1206", "dispatch"))),
     case10: (vec!["departure", "job2", "reload"], "vehicle_1", None, Some(("E1206", "reload"))),
+    case11: (vec!["departure", "job2", "vrp:rest"], "vehicle_1", None, Some(("E1206", "vehicle_1"))),
 }
 
 fn can_detect_relation_errors_impl(
@@ -198,3 +199,89 @@ fn can_detect_incomplete_multi_job_in_relation_impl(
 
     assert_eq!(result.map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_hint_errors, (job_ids, vehicle_id, expected), {
+    can_detect_hint_errors_impl(to_strings(job_ids), vehicle_id.to_string(), expected);
+}}
+
+can_detect_hint_errors! {
+    case01: (vec!["job2"], "vehicle_1", None),
+    case02: (vec!["job1", "job2"], "vehicle_1", Some("job1")),
+    case03: (vec!["job2"], "vehicle_2", Some("vehicle_2")),
+}
+
+fn can_detect_hint_errors_impl(job_ids: Vec<String>, vehicle_id: String, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job2", vec![1., 0.])],
+            hints: Some(vec![AssignmentHint { jobs: job_ids, vehicle_id, shift_index: None }]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    if let Some(id) = expected {
+        let error = result.expect("expect to have error");
+        assert_eq!(error.code, "E1208");
+        assert!(error.action.contains(id));
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_readonly_route_errors, (routes, relation_jobs, expected), {
+    can_detect_readonly_route_errors_impl(routes, relation_jobs, expected);
+}}
+
+can_detect_readonly_route_errors! {
+    case01: (vec![vec!["job1", "job2"]], None, None),
+    case02: (vec![vec!["job1", "job3"]], None, Some(("E1200", "job3"))),
+    case03: (vec![vec!["job1"]], Some(vec!["job2"]), Some(("E1209", "vehicle_1"))),
+    case04: (vec![vec!["job1"], vec!["job2"]], None, Some(("E1209", "vehicle_1"))),
+    case05: (vec![Vec::<&str>::default()], None, Some(("E1202", "jobs list"))),
+}
+
+fn can_detect_readonly_route_errors_impl(
+    routes: Vec<Vec<&str>>,
+    relation_jobs: Option<Vec<&str>>,
+    expected: Option<(&str, &str)>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: relation_jobs.map(|jobs| {
+                vec![Relation {
+                    type_field: RelationType::Any,
+                    jobs: to_strings(jobs),
+                    vehicle_id: "vehicle_1".to_string(),
+                    shift_index: None,
+                }]
+            }),
+            readonly_routes: Some(
+                routes
+                    .into_iter()
+                    .map(|jobs| ReadonlyRoute {
+                        vehicle_id: "vehicle_1".to_string(),
+                        shift_index: None,
+                        jobs: to_strings(jobs),
+                    })
+                    .collect(),
+            ),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle("vehicle")], profiles: vec![] },
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    if let Some((code, action)) = expected {
+        assert_eq!(result.clone().map(|err| err.code), Some(code.to_string()));
+        assert!(result.map_or("".to_string(), |err| err.action).contains(action));
+    } else {
+        assert!(result.is_none());
+    }
+}
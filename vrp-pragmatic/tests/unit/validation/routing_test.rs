@@ -1,4 +1,6 @@
 use super::*;
+use crate::format::Location;
+use crate::format_time;
 use crate::helpers::*;
 
 #[test]
@@ -7,8 +9,20 @@ fn can_detect_duplicates() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
+                MatrixProfile {
+                    name: "my_vehicle".to_string(),
+                    speed: None,
+                    detour_factor: None,
+                    time_of_day: None,
+                    leg_overrides: None,
+                },
+                MatrixProfile {
+                    name: "my_vehicle".to_string(),
+                    speed: None,
+                    detour_factor: None,
+                    time_of_day: None,
+                    leg_overrides: None,
+                },
             ],
         },
         ..create_empty_problem()
@@ -83,6 +97,8 @@ fn can_detect_index_mismatch() {
         travel_times: vec![1; 4],
         distances: vec![1; 4],
         error_codes: None,
+        units: None,
+        tolls: None,
     }];
     let coord_index = CoordIndex::new(&problem);
     let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
@@ -100,7 +116,13 @@ fn can_detect_missing_profile() {
                 VehicleType { profile: create_vehicle_profile_with_name("car"), ..create_default_vehicle_type() },
                 VehicleType { profile: create_vehicle_profile_with_name("truck"), ..create_default_vehicle_type() },
             ],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: None,
+                leg_overrides: None,
+            }],
         },
         ..create_empty_problem()
     };
@@ -111,3 +133,285 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_approximation_settings, (speed, detour_factor, expected), {
+    can_detect_invalid_approximation_settings_impl(speed, detour_factor, expected);
+}}
+
+can_detect_invalid_approximation_settings! {
+    case01: (None, None, None),
+    case02: (Some(5.), Some(1.2), None),
+    case03: (Some(0.), None, Some("E1506")),
+    case04: (None, Some(0.9), Some("E1506")),
+}
+
+fn can_detect_invalid_approximation_settings_impl(
+    speed: Option<f64>,
+    detour_factor: Option<f64>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed,
+                detour_factor,
+                time_of_day: None,
+                leg_overrides: None,
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1506_approximation_settings(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_time_of_day_settings, (intervals, expected), {
+    can_detect_invalid_time_of_day_settings_impl(intervals, expected);
+}}
+
+can_detect_invalid_time_of_day_settings! {
+    case01: (vec![("07:00", "09:00", 1.3), ("16:00", "24:00", 1.2)], None),
+    case02: (vec![("07:00", "09:00:30", 1.3)], None),
+    case03: (vec![("07:00", "09:00", 0.)], Some("E1507")),
+    case04: (vec![("09:00", "07:00", 1.3)], Some("E1507")),
+    case05: (vec![("07:00", "09:00", 1.3), ("08:00", "10:00", 1.2)], Some("E1507")),
+    case06: (vec![("7am", "09:00", 1.3)], Some("E1507")),
+    case07: (vec![("07:00", "24:01", 1.3)], Some("E1507")),
+}
+
+fn can_detect_invalid_time_of_day_settings_impl(intervals: Vec<(&str, &str, f64)>, expected: Option<&str>) {
+    let time_of_day = intervals
+        .into_iter()
+        .map(|(start, end, factor)| TimeOfDayFactor { start: start.to_string(), end: end.to_string(), factor })
+        .collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: Some(time_of_day),
+                leg_overrides: None,
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1507_time_of_day_settings(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_leg_overrides, (duration, distance, expected), {
+    can_detect_invalid_leg_overrides_impl(duration, distance, expected);
+}}
+
+can_detect_invalid_leg_overrides! {
+    case01: (10., None, None),
+    case02: (10., Some(100.), None),
+    case03: (-1., None, Some("E1508")),
+    case04: (10., Some(-1.), Some("E1508")),
+}
+
+fn can_detect_invalid_leg_overrides_impl(duration: f64, distance: Option<f64>, expected: Option<&str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                detour_factor: None,
+                time_of_day: None,
+                leg_overrides: Some(vec![LegOverride {
+                    from: vec![0., 0.].to_loc(),
+                    to: vec![1., 0.].to_loc(),
+                    duration,
+                    distance,
+                }]),
+            }],
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1508_leg_overrides(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+fn create_units(duration: &str, distance: &str) -> Option<PlanningUnits> {
+    Some(PlanningUnits { duration: duration.to_string(), distance: distance.to_string() })
+}
+
+parameterized_test! {can_detect_inconsistent_units, (problem_units, matrix_units, expected), {
+    can_detect_inconsistent_units_impl(problem_units, matrix_units, expected);
+}}
+
+can_detect_inconsistent_units! {
+    case01: (None, None, None),
+    case02: (None, create_units("second", "meter"), None),
+    case03: (create_units("tick", "step"), create_units("tick", "step"), None),
+    case04: (create_units("tick", "step"), None, None),
+    case05: (None, create_units("tick", "step"), Some("E1509")),
+    case06: (create_units("tick", "step"), create_units("tick", "meter"), Some("E1509")),
+}
+
+fn can_detect_inconsistent_units_impl(
+    problem_units: Option<PlanningUnits>,
+    matrix_units: Option<PlanningUnits>,
+    expected: Option<&str>,
+) {
+    let problem = Problem { units: problem_units, ..create_empty_problem() };
+    let matrices = vec![Matrix { units: matrix_units, ..create_matrix(vec![0]) }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1509_matrix_units(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_missing_matrix_with_custom_units, (units, has_matrix, expected), {
+    can_detect_missing_matrix_with_custom_units_impl(units, has_matrix, expected);
+}}
+
+can_detect_missing_matrix_with_custom_units! {
+    case01: (None, false, None),
+    case02: (create_units("second", "meter"), false, None),
+    case03: (create_units("tick", "step"), true, None),
+    case04: (create_units("tick", "step"), false, Some("E1510")),
+}
+
+fn can_detect_missing_matrix_with_custom_units_impl(
+    units: Option<PlanningUnits>,
+    has_matrix: bool,
+    expected: Option<&str>,
+) {
+    let problem = Problem { units, ..create_empty_problem() };
+    let matrices = if has_matrix { vec![create_matrix(vec![0])] } else { vec![] };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1510_no_matrix_when_custom_units_used(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+#[test]
+fn can_detect_non_square_matrix() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", 1)],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: None,
+        travel_times: vec![1; 3],
+        distances: vec![1; 3],
+        error_codes: None,
+        units: None,
+        tolls: None,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1504_index_size_mismatch(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1504".to_string()));
+}
+
+parameterized_test! {can_detect_matrix_size_overflow, (max_index, expected), {
+    can_detect_matrix_size_overflow_impl(max_index, expected);
+}}
+
+can_detect_matrix_size_overflow! {
+    case01_small: (1, None),
+    case02_big: (300_000, None),
+    case03_overflow: (usize::MAX / 2, Some("E1511".to_string())),
+}
+
+fn can_detect_matrix_size_overflow_impl(max_index: usize, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_index("job1", 0), create_delivery_job_with_index("job2", max_index)],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1511_matrix_size_overflow(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_toll_matrix_size_mismatch, (tolls, expected), {
+    can_detect_toll_matrix_size_mismatch_impl(tolls, expected);
+}}
+
+can_detect_toll_matrix_size_mismatch! {
+    case01_no_tolls: (None, None),
+    case02_same_size: (Some(vec![0.; 4]), None),
+    case03_smaller: (Some(vec![0.; 1]), Some("E1512")),
+    case04_bigger: (Some(vec![0.; 9]), Some("E1512")),
+}
+
+fn can_detect_toll_matrix_size_mismatch_impl(tolls: Option<Vec<f64>>, expected: Option<&str>) {
+    let problem = create_empty_problem();
+    let matrices = vec![Matrix { tolls, ..create_matrix(vec![0; 4]) }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1512_toll_matrix_size_mismatch(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+#[test]
+fn can_detect_index_mismatch_with_end_alternatives() {
+    let mut shift = create_default_vehicle_shift();
+    shift.start.location = Location::Reference { index: 0 };
+    shift.end = Some(ShiftEnd {
+        earliest: None,
+        latest: format_time(1000.),
+        location: Location::Reference { index: 0 },
+        alternatives: Some(vec![Location::Reference { index: 2 }]),
+    });
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_index("job1", 1)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix {
+        profile: Some("car".to_owned()),
+        timestamp: None,
+        travel_times: vec![1; 4],
+        distances: vec![1; 4],
+        error_codes: None,
+        units: None,
+        tolls: None,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1504_index_size_mismatch(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), Some("E1504".to_string()));
+}
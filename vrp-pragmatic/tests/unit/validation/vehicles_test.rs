@@ -13,6 +13,8 @@ fn can_detect_invalid_break_time() {
                         time: VehicleOptionalBreakTime::TimeWindow(vec![]),
                         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
                         policy: None,
+                        min_shift_duration: None,
+                        min_spacing: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -67,12 +69,16 @@ fn can_detect_invalid_area_impl(
                     max_distance: None,
                     shift_time: None,
                     tour_size: None,
+                    max_cost: None,
+                    max_stops: None,
                     areas: area_ids.map(|area_ids| {
                         vec![area_ids
                             .iter()
                             .map(|area_id| AreaLimit { area_id: area_id.to_string(), job_value: 1. })
                             .collect()]
                     }),
+                    driver_hours: None,
+                    overtime: None,
                 }),
                 ..create_default_vehicle_type()
             }],
@@ -147,7 +153,7 @@ fn can_detect_zero_costs_impl(costs: (f64, f64), expected: Option<String>) {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance, time },
+                costs: VehicleCosts { fixed: None, distance, time, waiting: None, depot_waiting: None },
                 ..create_default_vehicle_type()
             }],
             profiles: vec![],
@@ -179,11 +185,13 @@ fn can_handle_rescheduling_with_required_break_impl(latest: Option<f64>, expecte
                     start: ShiftStart {
                         earliest: format_time(0.),
                         latest: latest.map(|latest| format_time(latest)),
+                        optimize_departure: None,
                         location: vec![0., 0.].to_loc(),
                     },
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime(10.),
                         duration: 2.,
+                        can_interrupt: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -202,3 +210,328 @@ fn can_handle_rescheduling_with_required_break_impl(latest: Option<f64>, expecte
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_reload_capacity, (capacity, expected), {
+    can_detect_invalid_reload_capacity_impl(capacity, expected);
+}}
+
+can_detect_invalid_reload_capacity! {
+    case01: (None, None),
+    case02: (Some(vec![5]), None),
+    case03: (Some(vec![5, 2]), Some("E1309".to_string())),
+}
+
+fn can_detect_invalid_reload_capacity_impl(capacity: Option<Vec<i32>>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        location: vec![0., 0.].to_loc(),
+                        duration: 2.,
+                        times: None,
+                        tag: None,
+                        capacity,
+                        per_unit_duration: None,
+                        resource: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![10],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_reload_capacity_is_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_driver_hours, (hours, shifts, expected), {
+    can_detect_invalid_driver_hours_impl(hours, shifts, expected);
+}}
+
+can_detect_invalid_driver_hours! {
+    case01: ((None, None, None, None), vec![(0., 100.)], None),
+    case02: ((Some(10.), Some(5.), Some(20.), Some(10.)), vec![(0., 100.), (110., 200.)], None),
+    case03: ((Some(10.), Some(5.), Some(20.), Some(10.)), vec![(0., 100.), (105., 200.)], Some("E1310".to_string())),
+    case04: ((Some(0.), None, None, None), vec![(0., 100.)], Some("E1310".to_string())),
+    case05: ((Some(30.), Some(5.), Some(20.), None), vec![(0., 100.)], Some("E1310".to_string())),
+}
+
+fn can_detect_invalid_driver_hours_impl(
+    hours: (Option<f64>, Option<f64>, Option<f64>, Option<f64>),
+    shifts: Vec<(f64, f64)>,
+    expected: Option<String>,
+) {
+    let (max_continuous_driving, break_duration, max_daily_driving, daily_rest) = hours;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: shifts
+                    .into_iter()
+                    .map(|(start, end)| VehicleShift {
+                        start: ShiftStart {
+                            earliest: format_time(start),
+                            latest: None,
+                            optimize_departure: None,
+                            location: vec![0., 0.].to_loc(),
+                        },
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(end),
+                            location: vec![0., 0.].to_loc(),
+                            alternatives: None,
+                        }),
+                        ..create_default_vehicle_shift()
+                    })
+                    .collect(),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time: None,
+                    tour_size: None,
+                    max_cost: None,
+                    max_stops: None,
+                    areas: None,
+                    driver_hours: Some(VehicleDriverHours {
+                        max_continuous_driving,
+                        break_duration,
+                        max_daily_driving,
+                        daily_rest,
+                    }),
+                    overtime: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_driver_hours_is_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_overtime, (shift_time, overtime, expected), {
+    can_detect_invalid_overtime_impl(shift_time, overtime, expected);
+}}
+
+can_detect_invalid_overtime! {
+    case01: (Some(100.), (10., 1.), None),
+    case02: (None, (10., 1.), Some("E1311".to_string())),
+    case03: (Some(100.), (-10., 1.), Some("E1311".to_string())),
+    case04: (Some(100.), (10., -1.), Some("E1311".to_string())),
+}
+
+fn can_detect_invalid_overtime_impl(shift_time: Option<f64>, overtime: (f64, f64), expected: Option<String>) {
+    let (max_duration, cost) = overtime;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    shift_time,
+                    tour_size: None,
+                    max_cost: None,
+                    max_stops: None,
+                    areas: None,
+                    driver_hours: None,
+                    overtime: Some(VehicleOvertime { max_duration, cost }),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1311_vehicle_overtime_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_reload_resource, (per_unit_duration, resources, expected), {
+    can_detect_invalid_reload_resource_impl(per_unit_duration, resources, expected);
+}}
+
+can_detect_invalid_reload_resource! {
+    case01: (None, vec![], None),
+    case02: (Some(1.), vec![("depot", 2), ("depot", 2)], None),
+    case03: (Some(-1.), vec![], Some("E1312".to_string())),
+    case04: (None, vec![("depot", 0)], Some("E1312".to_string())),
+    case05: (None, vec![("depot", 1), ("depot", 2)], Some("E1312".to_string())),
+    case06: (None, vec![("depot1", 1), ("depot2", 2)], None),
+}
+
+fn can_detect_invalid_reload_resource_impl(
+    per_unit_duration: Option<f64>,
+    resources: Vec<(&str, usize)>,
+    expected: Option<String>,
+) {
+    let create_reload = |resource: Option<VehicleReloadResource>| VehicleReload {
+        location: vec![0., 0.].to_loc(),
+        duration: 2.,
+        times: None,
+        tag: None,
+        capacity: None,
+        per_unit_duration,
+        resource,
+    };
+    let reloads = if resources.is_empty() {
+        vec![create_reload(None)]
+    } else {
+        resources
+            .into_iter()
+            .map(|(id, capacity)| create_reload(Some(VehicleReloadResource { id: id.to_string(), capacity })))
+            .collect()
+    };
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { reloads: Some(reloads), ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1312_vehicle_reload_resource_is_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_loading, (duration, per_unit_duration, expected), {
+    can_detect_invalid_loading_impl(duration, per_unit_duration, expected);
+}}
+
+can_detect_invalid_loading! {
+    case01: (10., None, None),
+    case02: (0., Some(1.), None),
+    case03: (-1., None, Some("E1313".to_string())),
+    case04: (10., Some(-1.), Some("E1313".to_string())),
+}
+
+fn can_detect_invalid_loading_impl(duration: f64, per_unit_duration: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    loading: Some(VehicleLoading { duration, per_unit_duration }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1313_vehicle_loading_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_echelon, (echelon, expected), {
+    can_detect_invalid_echelon_impl(echelon, expected);
+}}
+
+can_detect_invalid_echelon! {
+    case01: (None, None),
+    case02: (Some(1), None),
+    case03: (Some(2), None),
+    case04: (Some(0), Some("E1314".to_string())),
+    case05: (Some(3), Some("E1314".to_string())),
+}
+
+fn can_detect_invalid_echelon_impl(echelon: Option<usize>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet { vehicles: vec![VehicleType { echelon, ..create_default_vehicle_type() }], profiles: vec![] },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1314_vehicle_echelon_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_service_time_factor, (service_time_factor, expected), {
+    can_detect_invalid_service_time_factor_impl(service_time_factor, expected);
+}}
+
+can_detect_invalid_service_time_factor! {
+    case01: (None, None),
+    case02: (Some(1.5), None),
+    case03: (Some(0.), Some("E1315".to_string())),
+    case04: (Some(-1.), Some("E1315".to_string())),
+}
+
+fn can_detect_invalid_service_time_factor_impl(service_time_factor: Option<f64>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { service_time_factor, ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1315_vehicle_service_time_factor_is_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_end_alternatives, (alternatives, expected), {
+    can_detect_invalid_end_alternatives_impl(alternatives, expected);
+}}
+
+can_detect_invalid_end_alternatives! {
+    case01_no_alternatives: (None, None),
+    case02_valid: (Some(vec![(1., 0.), (2., 0.)]), None),
+    case03_empty: (Some(vec![]), Some("E1316".to_string())),
+    case04_duplicates: (Some(vec![(1., 0.), (1., 0.)]), Some("E1316".to_string())),
+    case05_end_location: (Some(vec![(1., 0.), (0., 0.)]), Some("E1316".to_string())),
+}
+
+fn can_detect_invalid_end_alternatives_impl(alternatives: Option<Vec<(f64, f64)>>, expected: Option<String>) {
+    let mut shift = create_default_vehicle_shift_with_locations((0., 0.), (0., 0.));
+    shift.end.as_mut().unwrap().alternatives =
+        alternatives.map(|alternatives| alternatives.into_iter().map(|(lat, lng)| vec![lat, lng].to_loc()).collect());
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { shifts: vec![shift], ..create_default_vehicle_type() }],
+            profiles: vec![],
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1316_vehicle_end_alternatives_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
@@ -0,0 +1,94 @@
+//! A crate which provides python bindings to solve ***Vehicle Routing Problem*** defined in
+//! `pragmatic` format.
+//!
+//! Python module exposes `solve` function which accepts problem, routing matrices and algorithm
+//! configuration serialized in json, and returns solution serialized in json. Optionally, progress
+//! callback and cancellation token can be passed to track and stop the search.
+
+#![warn(missing_docs)]
+
+#[cfg(test)]
+#[path = "../tests/unit/lib_test.rs"]
+mod lib_test;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::io::BufReader;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use vrp_cli::extensions::solve::config::read_config;
+use vrp_cli::{get_errors_serialized, get_pragmatic_solution_serialized, ProgressCallback, SolveError};
+
+/// Solves problem passed in `pragmatic` format and returns solution serialized in json.
+/// When `interrupt` flag is set, the search is stopped and the best known solution is returned.
+pub fn solve_pragmatic(
+    problem: String,
+    matrices: Vec<String>,
+    config: &str,
+    interrupt: Option<Arc<AtomicBool>>,
+    progress: Option<ProgressCallback>,
+) -> Result<String, String> {
+    read_config(BufReader::new(config.as_bytes()))
+        .map_err(SolveError::Config)
+        .and_then(|config| get_pragmatic_solution_serialized(problem, matrices, config, interrupt, progress))
+        .map_err(|err| err.to_message(get_errors_serialized))
+}
+
+/// A token which is used to cancel running search from another python thread.
+#[pyclass]
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    interrupt: Arc<AtomicBool>,
+}
+
+#[pymethods]
+impl CancellationToken {
+    /// Creates a new instance of `CancellationToken`.
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation: the search is stopped and the best known solution is returned.
+    fn cancel(&self) {
+        self.interrupt.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns true if cancellation is requested.
+    fn is_cancelled(&self) -> bool {
+        self.interrupt.load(Ordering::Relaxed)
+    }
+}
+
+/// Solves problem passed in `pragmatic` format and returns solution serialized in json.
+/// Progress callback is called with generation number and the best known cost. GIL is released
+/// while the search is running.
+#[pyfunction]
+#[pyo3(name = "solve")]
+fn solve_py(
+    py: Python,
+    problem: String,
+    matrices: Vec<String>,
+    config: String,
+    progress: Option<PyObject>,
+    cancellation: Option<CancellationToken>,
+) -> PyResult<String> {
+    let progress = progress.map::<ProgressCallback, _>(|callback| {
+        Box::new(move |generation, cost| {
+            Python::with_gil(|py| callback.call1(py, (generation, cost)).map(|_| ()).map_err(|err| err.to_string()))
+        })
+    });
+    let interrupt = cancellation.map(|token| token.interrupt);
+
+    py.allow_threads(move || solve_pragmatic(problem, matrices, config.as_str(), interrupt, progress))
+        .map_err(PyValueError::new_err)
+}
+
+/// A python module to solve rich VRP.
+#[pymodule]
+fn vrp_py(_py: Python, module: &PyModule) -> PyResult<()> {
+    module.add_class::<CancellationToken>()?;
+    module.add_function(wrap_pyfunction!(solve_py, module)?)?;
+
+    Ok(())
+}
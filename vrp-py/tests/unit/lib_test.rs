@@ -0,0 +1,58 @@
+use super::*;
+use std::sync::Mutex;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const CONFIG: &str = r#"{"termination": {"maxGenerations": 10}}"#;
+
+fn read_problem() -> String {
+    std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap()
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_progress() {
+    let generations = Arc::new(Mutex::new(Vec::new()));
+    let progress: ProgressCallback = Box::new({
+        let generations = generations.clone();
+        move |generation, _| {
+            generations.lock().unwrap().push(generation);
+            Ok(())
+        }
+    });
+
+    let solution = solve_pragmatic(read_problem(), vec![], CONFIG, None, Some(progress)).unwrap();
+
+    assert!(solution.contains("\"tours\""));
+    let generations = generations.lock().unwrap();
+    assert!(!generations.is_empty());
+    assert_eq!(generations.first(), Some(&0));
+}
+
+#[test]
+fn can_cancel_search() {
+    let interrupt = Arc::new(AtomicBool::new(false));
+    let last_generation = Arc::new(Mutex::new(0));
+    let progress: ProgressCallback = Box::new({
+        let interrupt = interrupt.clone();
+        let last_generation = last_generation.clone();
+        move |generation, _| {
+            *last_generation.lock().unwrap() = generation;
+            if generation == 5 {
+                interrupt.store(true, Ordering::Relaxed);
+            }
+            Ok(())
+        }
+    });
+    let config = r#"{"termination": {"maxGenerations": 100000, "maxTime": 300}}"#;
+
+    let solution = solve_pragmatic(read_problem(), vec![], config, Some(interrupt), Some(progress)).unwrap();
+
+    assert!(solution.contains("\"tours\""));
+    assert_eq!(*last_generation.lock().unwrap(), 5);
+}
+
+#[test]
+fn can_return_error_for_invalid_problem() {
+    let result = solve_pragmatic("{}".to_string(), vec![], CONFIG, None, None);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+#[path = "../../tests/unit/common/best_known_test.rs"]
+mod best_known_test;
+
+use crate::common::read_line;
+use std::io::{BufReader, Read};
+use vrp_core::models::Solution;
+
+/// Keeps characteristics of best known solution used to evaluate quality of the found one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BestKnownSolution {
+    /// Amount of routes.
+    pub routes: usize,
+    /// Total cost.
+    pub cost: f64,
+}
+
+/// Keeps result of comparison between found and best known solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BestKnownComparison {
+    /// Difference in route amount: positive value means that more routes are used.
+    pub routes_gap: i64,
+    /// Relative difference in cost (percents): positive value means that solution is more expensive.
+    pub cost_gap: f64,
+}
+
+impl BestKnownComparison {
+    /// Checks whether solution is not worse than best known solution taking into account
+    /// cost tolerance specified in percents.
+    pub fn is_acceptable(&self, tolerance: f64) -> bool {
+        self.routes_gap <= 0 && self.cost_gap <= tolerance
+    }
+}
+
+/// Reads best known solution written in the same text format as solution produced by writer:
+/// one `Route N: ...` line per route followed by `Cost X` line.
+pub fn read_best_known_solution<R: Read>(mut reader: BufReader<R>) -> Result<BestKnownSolution, String> {
+    let mut buffer = String::new();
+    let mut routes = 0;
+    let mut cost = None;
+
+    while read_line(&mut reader, &mut buffer)? > 0 {
+        let line = buffer.trim();
+
+        if line.starts_with("Route") {
+            routes += 1;
+        } else if let Some(value) = line.strip_prefix("Cost") {
+            cost = Some(value.trim().parse::<f64>().map_err(|err| format!("cannot parse cost: {}", err))?);
+        }
+    }
+
+    cost.map(|cost| BestKnownSolution { routes, cost }).ok_or_else(|| "cannot find cost".to_string())
+}
+
+/// Compares found solution with its cost against best known solution.
+pub fn compare_with_best_known(solution: &Solution, cost: f64, best_known: &BestKnownSolution) -> BestKnownComparison {
+    let routes_gap = solution.routes.len() as i64 - best_known.routes as i64;
+    let cost_gap = if best_known.cost > 0. { (cost - best_known.cost) / best_known.cost * 100. } else { 0. };
+
+    BestKnownComparison { routes_gap, cost_gap }
+}
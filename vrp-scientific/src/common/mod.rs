@@ -1,5 +1,11 @@
 //! Contains common text reading and writing functionality.
 
+mod best_known;
+pub use self::best_known::*;
+
+mod initial_reader;
+pub(crate) use self::initial_reader::*;
+
 mod text_reader;
 pub(crate) use self::text_reader::*;
 
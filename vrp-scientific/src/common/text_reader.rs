@@ -45,6 +45,7 @@ pub(crate) fn create_fleet_with_distance_costs(
                 per_distance: 0.0,
                 per_driving_time: 0.0,
                 per_waiting_time: 0.0,
+                per_depot_waiting_time: 0.0,
                 per_service_time: 0.0,
             },
             dimens: create_dimens_with_id("driver", &0.to_string()),
@@ -61,6 +62,7 @@ pub(crate) fn create_fleet_with_distance_costs(
                         per_distance: 1.0,
                         per_driving_time: 0.0,
                         per_waiting_time: 0.0,
+                        per_depot_waiting_time: 0.0,
                         per_service_time: 0.0,
                     },
                     dimens,
@@ -0,0 +1,17 @@
+#[cfg(test)]
+#[path = "../../tests/unit/lilim/initial_reader_test.rs"]
+mod initial_reader_test;
+
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::prelude::*;
+
+/// Reads initial solution from a buffer. Routes are expected to be defined by customer ids.
+/// NOTE: Solution feasibility is not checked.
+pub fn read_init_solution<R: Read>(
+    reader: BufReader<R>,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Result<Solution, String> {
+    crate::common::read_init_solution(reader, problem, random)
+}
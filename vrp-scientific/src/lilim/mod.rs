@@ -1,5 +1,8 @@
 //! Contains functionality to read lilim problem and write its solution.
 
+mod initial_reader;
+pub use self::initial_reader::read_init_solution;
+
 mod reader;
 pub use self::reader::LilimProblem;
 
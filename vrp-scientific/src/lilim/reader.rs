@@ -121,7 +121,7 @@ impl<R: Read> LilimReader<R> {
     }
 
     fn create_single_job(&mut self, customer: &JobLine) -> Arc<Single> {
-        let mut dimens = create_dimens_with_id("c", &customer.id.to_string());
+        let mut dimens = create_dimens_with_id("", &customer.id.to_string());
         dimens.set_demand(if customer.demand > 0 {
             Demand::<SingleDimLoad> {
                 pickup: (SingleDimLoad::default(), SingleDimLoad::new(customer.demand as i32)),
@@ -130,7 +130,7 @@ impl<R: Read> LilimReader<R> {
         } else {
             Demand::<SingleDimLoad> {
                 pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
-                delivery: (SingleDimLoad::default(), SingleDimLoad::new(customer.demand as i32)),
+                delivery: (SingleDimLoad::default(), SingleDimLoad::new(-customer.demand as i32)),
             }
         });
 
@@ -140,7 +140,7 @@ impl<R: Read> LilimReader<R> {
                 duration: customer.service as f64,
                 times: vec![TimeSpan::Window(customer.tw.clone())],
             }],
-            dimens: Default::default(),
+            dimens,
         })
     }
 
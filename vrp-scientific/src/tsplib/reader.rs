@@ -44,7 +44,7 @@ impl<R: Read> TextReader for TsplibReader<R> {
 
         let (coordinates, demands) = self.read_customer_data()?;
         let depot_id = self.read_depot_data()?;
-        self.read_expected_line("EOF")?;
+        self.read_end()?;
 
         let dimension = self.dimension.unwrap();
 
@@ -95,28 +95,50 @@ impl<R: Read> TsplibReader<R> {
         }
     }
 
+    /// Reads specification part which ends with `NODE_COORD_SECTION`. Keys can be specified in any
+    /// order, unsupported keys (e.g. `NAME` or `COMMENT`) are ignored.
     fn read_meta(&mut self) -> Result<(), String> {
-        self.skip_lines(2)?;
+        loop {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Err("unexpected end of file, expecting NODE_COORD_SECTION".to_string());
+            }
 
-        let problem_type = self.read_key_value("TYPE")?;
-        if problem_type != "CVRP" {
-            return Err(format!("expecting 'CVRP' as TYPE, got '{}'", problem_type));
-        }
+            let line = self.buffer.trim();
+            if line.is_empty() {
+                continue;
+            }
 
-        self.dimension = Some(
-            self.read_key_value("DIMENSION")
-                .and_then(|dimen| parse_int(&dimen, "cannot parse DIMENSION").map(|v| v as usize))?,
-        );
+            if line == "NODE_COORD_SECTION" {
+                break;
+            }
 
-        let edge_type = self.read_key_value("EDGE_WEIGHT_TYPE")?;
-        if edge_type != "EUC_2D" {
-            return Err(format!("expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got '{}'", edge_type));
+            let (key, value) = line
+                .split_once(':')
+                .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                .ok_or_else(|| format!("expected colon separated string, got: '{}'", line))?;
+
+            match key.as_str() {
+                "TYPE" if value != "CVRP" => return Err(format!("expecting 'CVRP' as TYPE, got '{}'", value)),
+                "EDGE_WEIGHT_TYPE" if value != "EUC_2D" => {
+                    return Err(format!("expecting 'EUC_2D' as EDGE_WEIGHT_TYPE, got '{}'", value))
+                }
+                "DIMENSION" => {
+                    self.dimension = Some(parse_int(&value, "cannot parse DIMENSION").map(|v| v as usize)?);
+                }
+                "CAPACITY" => {
+                    self.vehicle_capacity = Some(parse_int(&value, "cannot parse CAPACITY").map(|v| v as usize)?);
+                }
+                _ => {}
+            }
         }
 
-        self.vehicle_capacity = Some(
-            self.read_key_value("CAPACITY")
-                .and_then(|capacity| parse_int(&capacity, "cannot parse CAPACITY").map(|v| v as usize))?,
-        );
+        if self.dimension.is_none() {
+            return Err("cannot find DIMENSION".to_string());
+        }
+
+        if self.vehicle_capacity.is_none() {
+            return Err("cannot find CAPACITY".to_string());
+        }
 
         Ok(())
     }
@@ -124,9 +146,7 @@ impl<R: Read> TsplibReader<R> {
     fn read_customer_data(&mut self) -> Result<ProblemData, String> {
         let dimension = self.dimension.unwrap();
 
-        // read coordinates
-        self.read_expected_line("NODE_COORD_SECTION")?;
-
+        // read coordinates, section header is consumed by meta reader
         let mut coordinates = HashMap::with_capacity(self.dimension.unwrap());
         for _ in 0..dimension {
             let line = self.read_line()?.trim();
@@ -167,22 +187,6 @@ impl<R: Read> TsplibReader<R> {
         Ok(depot_id)
     }
 
-    fn read_key_value(&mut self, expected_key: &str) -> Result<String, String> {
-        let line = self.read_line()?;
-        let key_value = line.split(':').map(|v| v.to_string()).collect::<Vec<_>>();
-
-        if key_value.len() != 2 {
-            return Err(format!("expected colon separated string, got: '{}'", line));
-        }
-
-        let actual_key = key_value[0].trim();
-        if actual_key.trim() != expected_key {
-            return Err(format!("unexpected key, expecting: '{}', got: '{}'", expected_key, actual_key));
-        }
-
-        Ok(key_value[1].trim().to_string())
-    }
-
     fn read_expected_line(&mut self, expected: &str) -> Result<(), String> {
         let line = self.read_line()?.trim();
         if line != expected {
@@ -192,15 +196,21 @@ impl<R: Read> TsplibReader<R> {
         }
     }
 
+    fn read_end(&mut self) -> Result<(), String> {
+        // NOTE some instances have no EOF line at the end
+        let line = self.read_line()?.trim();
+        if line.is_empty() || line == "EOF" {
+            Ok(())
+        } else {
+            Err(format!("expecting EOF, got: '{}'", line))
+        }
+    }
+
     fn read_line(&mut self) -> Result<&String, String> {
         read_line(&mut self.reader, &mut self.buffer)?;
         Ok(&self.buffer)
     }
 
-    fn skip_lines(&mut self, count: usize) -> Result<(), String> {
-        skip_lines(count, &mut self.reader, &mut self.buffer)
-    }
-
     fn create_job(&mut self, id: &str, location: (i32, i32), demand: i32) -> Job {
         let mut dimens = create_dimens_with_id("", id);
         dimens.set_demand(Demand::<SingleDimLoad> {
@@ -1,16 +1,53 @@
-use crate::common::write_text_solution;
-use std::io::{BufWriter, Write};
+#[cfg(test)]
+#[path = "../../tests/unit/tsplib/writer_test.rs"]
+mod writer_test;
+
+use std::cmp::Ordering;
+use std::io::{BufWriter, Error, ErrorKind, Write};
+use vrp_core::models::common::IdDimension;
 use vrp_core::models::Solution;
+use vrp_core::prelude::compare_floats;
 
 /// A trait to write tsplib95 solution.
 pub trait TsplibSolution<W: Write> {
-    /// Writes tsplib95 solution.
+    /// Writes tsplib95 solution in the `.sol` format used by CVRPLIB.
     fn write_tsplib(&self, writer: BufWriter<W>) -> Result<(), String>;
 }
 
 impl<W: Write> TsplibSolution<W> for (&Solution, f64) {
     fn write_tsplib(&self, writer: BufWriter<W>) -> Result<(), String> {
-        write_text_solution(writer, self.0, self.1).map_err(|err| err.to_string())?;
-        Ok(())
+        write_cvrplib_solution(writer, self.0, self.1).map_err(|err| err.to_string())
+    }
+}
+
+/// Writes solution as one `Route #N: ...` line per route followed by `Cost X` line. Customers are
+/// numbered from one excluding depot, so the output can be compared with CVRPLIB best known solutions.
+fn write_cvrplib_solution<W: Write>(writer: BufWriter<W>, solution: &Solution, cost: f64) -> Result<(), Error> {
+    let mut writer = writer;
+
+    if !solution.unassigned.is_empty() {
+        return Err(Error::new(ErrorKind::Other, "cannot write tsplib solution with unassigned jobs."));
     }
+
+    for (route, idx) in solution.routes.iter().zip(1..) {
+        let customers = route
+            .tour
+            .all_activities()
+            .filter_map(|activity| activity.job.as_ref())
+            .filter_map(|single| single.dimens.get_id())
+            .cloned()
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        writer.write_all(format!("Route #{}: {}\n", idx, customers).as_bytes())?;
+    }
+
+    // NOTE CVRPLIB uses integer costs for rounded distances
+    if compare_floats(cost.fract(), 0.) == Ordering::Equal {
+        writer.write_all(format!("Cost {:.0}", cost).as_bytes())?;
+    } else {
+        writer.write_all(format!("Cost {:.2}", cost).as_bytes())?;
+    }
+
+    Ok(())
 }
@@ -43,6 +43,6 @@ impl CoordIndex {
 
         let matrix_data = MatrixData::new(0, None, matrix_values.clone(), matrix_values);
 
-        create_matrix_transport_cost(vec![matrix_data])
+        create_matrix_transport_cost(vec![matrix_data]).map_err(String::from)
     }
 }
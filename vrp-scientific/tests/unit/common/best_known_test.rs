@@ -0,0 +1,43 @@
+use super::*;
+use crate::helpers::{create_c101_100_problem, get_test_resource};
+use crate::solomon::read_init_solution;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::rosomaxa::prelude::Objective;
+use vrp_core::utils::Environment;
+
+fn get_best_known_reader() -> BufReader<std::fs::File> {
+    BufReader::new(get_test_resource("../../examples/data/scientific/solomon/C101.100.best.txt").unwrap())
+}
+
+#[test]
+fn can_read_best_known_solution() {
+    let best_known = read_best_known_solution(get_best_known_reader()).unwrap();
+
+    assert_eq!(best_known, BestKnownSolution { routes: 10, cost: 828. });
+}
+
+#[test]
+fn can_return_error_when_cost_is_missing() {
+    let result = read_best_known_solution(BufReader::new("Route 1: 1 2 3\n".as_bytes()));
+
+    assert_eq!(result, Err("cannot find cost".to_string()));
+}
+
+#[test]
+fn can_compare_with_best_known_solution() {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(create_c101_100_problem());
+    let solution = read_init_solution(get_best_known_reader(), problem.clone(), environment.random.clone()).unwrap();
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment);
+    let cost = problem.objective.fitness(&insertion_ctx);
+    let solution = insertion_ctx.solution.to_solution(problem.extras.clone());
+    let best_known = BestKnownSolution { routes: 11, cost: 828. };
+
+    let comparison = compare_with_best_known(&solution, cost, &best_known);
+
+    assert_eq!(comparison.routes_gap, -1);
+    assert!(comparison.cost_gap > 0. && comparison.cost_gap < 0.2);
+    assert!(comparison.is_acceptable(0.2));
+    assert!(!comparison.is_acceptable(0.1));
+}
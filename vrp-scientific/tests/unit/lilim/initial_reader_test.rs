@@ -0,0 +1,68 @@
+use super::*;
+use crate::helpers::LilimBuilder;
+use crate::lilim::{LilimProblem, LilimSolution};
+use std::io::BufWriter;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::problem::Job;
+
+fn create_problem() -> Arc<Problem> {
+    Arc::new(
+        LilimBuilder::new()
+            .set_vehicle((2, 10))
+            .add_customer((0, 0, 0, 0, 0, 1000, 0, 0, 0))
+            .add_customer((1, 1, 0, 5, 0, 1000, 1, 0, 2))
+            .add_customer((2, 2, 0, -5, 0, 1000, 1, 1, 0))
+            .add_customer((3, 3, 0, 7, 0, 1000, 1, 0, 4))
+            .add_customer((4, 4, 0, -7, 0, 1000, 1, 3, 0))
+            .build()
+            .read_lilim(false)
+            .unwrap(),
+    )
+}
+
+#[test]
+fn can_read_init_solution_with_multi_jobs() {
+    let environment = Arc::new(Environment::default());
+    let problem = create_problem();
+
+    let solution = read_init_solution(
+        BufReader::new("Route 1: 1 2\nRoute 2: 3 4\n".as_bytes()),
+        problem.clone(),
+        environment.random.clone(),
+    )
+    .unwrap();
+
+    assert_eq!(solution.routes.len(), 2);
+    let jobs = solution.routes[0].tour.jobs().collect::<Vec<_>>();
+    assert_eq!(jobs.len(), 1);
+    assert!(matches!(jobs[0], Job::Multi(_)));
+
+    let insertion_ctx = InsertionContext::new_from_solution(problem, (solution, None), environment);
+    assert!(insertion_ctx.solution.required.is_empty());
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_write_solution_with_customer_ids() {
+    let environment = Arc::new(Environment::default());
+    let problem = create_problem();
+    let solution =
+        read_init_solution(BufReader::new("Route 1: 1 3 4 2\n".as_bytes()), problem, environment.random.clone())
+            .unwrap();
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    (&solution, 10.).write_lilim(writer).unwrap();
+
+    assert_eq!(buffer, "Route 1: 1 3 4 2\nCost 10.00");
+}
+
+#[test]
+fn can_return_error_for_unknown_customer() {
+    let environment = Arc::new(Environment::default());
+
+    let result =
+        read_init_solution(BufReader::new("Route 1: 1 5\n".as_bytes()), create_problem(), environment.random.clone());
+
+    assert_eq!(result.err(), Some("cannot find job with id: '5'".to_string()));
+}
@@ -1,4 +1,5 @@
 use crate::helpers::{create_lc101_problem, get_job_ids, get_vehicle_capacity};
+use vrp_core::models::common::{Demand, DemandDimension, SingleDimLoad};
 
 #[test]
 fn can_read_lilim_format_from_test_file() {
@@ -12,3 +13,15 @@ fn can_read_lilim_format_from_test_file() {
     assert_eq!(problem.fleet.vehicles.len(), 25);
     assert_eq!(get_vehicle_capacity(&problem), 200);
 }
+
+#[test]
+fn can_read_pickup_and_delivery_demand() {
+    let problem = create_lc101_problem();
+
+    let multi = problem.jobs.all().next().unwrap().to_multi().clone();
+    let pickup: &Demand<SingleDimLoad> = multi.jobs[0].dimens.get_demand().unwrap();
+    let delivery: &Demand<SingleDimLoad> = multi.jobs[1].dimens.get_demand().unwrap();
+
+    assert!(pickup.pickup.1.value > 0);
+    assert_eq!(pickup.pickup.1.value, delivery.delivery.1.value);
+}
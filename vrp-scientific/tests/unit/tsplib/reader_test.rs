@@ -36,6 +36,27 @@ fn can_read_meta_errors() {
     }
 }
 
+#[test]
+fn can_read_meta_in_any_order() {
+    let content = "NAME : A-n2-k1\nCOMMENT : (Augerat et al, No of trucks: 1, Optimal value: 10)\nCAPACITY : 100\n\
+                   EDGE_WEIGHT_TYPE : EUC_2D\nTYPE : CVRP\nDIMENSION : 2\nNODE_COORD_SECTION\n";
+    let mut reader = TsplibReader::new(BufReader::new(content.as_bytes()));
+
+    reader.read_meta().expect("cannot read meta");
+
+    assert_eq!(reader.dimension, Some(2));
+    assert_eq!(reader.vehicle_capacity, Some(100));
+}
+
+#[test]
+fn can_read_problem_without_eof() {
+    let content = get_example_problem_string().replace("EOF", "");
+
+    let problem = content.read_tsplib(false).expect("cannot read problem");
+
+    assert_eq!(problem.jobs.size(), 5);
+}
+
 #[test]
 fn can_read_meta_capacity_and_dimension() {
     let mut reader = TsplibReader::new(get_example_problem_reader());
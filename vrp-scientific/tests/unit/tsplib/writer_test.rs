@@ -0,0 +1,58 @@
+use super::*;
+use crate::helpers::get_test_resource;
+use crate::tsplib::TsplibProblem;
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::solver::search::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::{ElitismPopulation, RefinementContext};
+use vrp_core::utils::Environment;
+
+fn write_solution(cost: f64) -> String {
+    let environment = Arc::new(Environment::default());
+    let problem = Arc::new(
+        BufReader::new(get_test_resource("../../examples/data/scientific/tsplib/example.txt").unwrap())
+            .read_tsplib(true)
+            .unwrap(),
+    );
+    let mut refinement_ctx = RefinementContext::new(
+        problem.clone(),
+        Box::new(ElitismPopulation::new(problem.objective.clone(), environment.random.clone(), 1, 1)),
+        environment.clone(),
+    );
+    let solution = RecreateWithCheapest::new(environment.random.clone())
+        .run(&mut refinement_ctx, InsertionContext::new(problem.clone(), environment))
+        .solution
+        .to_solution(problem.extras.clone());
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    (&solution, cost).write_tsplib(writer).unwrap();
+
+    buffer
+}
+
+#[test]
+fn can_write_tsplib_solution_in_cvrplib_format() {
+    let buffer = write_solution(172.);
+
+    let lines = buffer.lines().collect::<Vec<_>>();
+    assert!(lines.len() > 1);
+    assert!(lines.iter().take(lines.len() - 1).all(|line| line.starts_with("Route #")));
+    assert_eq!(lines.last(), Some(&"Cost 172"));
+
+    let mut customers = lines
+        .iter()
+        .take(lines.len() - 1)
+        .flat_map(|line| line.split(':').nth(1).unwrap().split_whitespace())
+        .collect::<Vec<_>>();
+    customers.sort_unstable();
+    assert_eq!(customers, vec!["1", "2", "3", "4", "5"]);
+}
+
+#[test]
+fn can_write_tsplib_solution_with_fractional_cost() {
+    let buffer = write_solution(172.456);
+
+    assert!(buffer.ends_with("Cost 172.46"));
+}